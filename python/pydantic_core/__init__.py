@@ -4,13 +4,16 @@ import sys as _sys
 from typing import Any as _Any
 
 from ._pydantic_core import (
+    UNSET,
     ArgsKwargs,
     MultiHostUrl,
+    PathFilter,
     PydanticCustomError,
     PydanticKnownError,
     PydanticOmit,
     PydanticSerializationError,
     PydanticSerializationUnexpectedValue,
+    PydanticSerializationWarning,
     PydanticUndefined,
     PydanticUndefinedType,
     PydanticUseDefault,
@@ -19,10 +22,13 @@ from ._pydantic_core import (
     SchemaValidator,
     Some,
     TzInfo,
+    UnsetType,
     Url,
     ValidationError,
     __version__,
     from_json,
+    merge_config,
+    set_trace_subscriber,
     to_json,
     to_jsonable_python,
     validate_core_schema,
@@ -34,6 +40,11 @@ if _sys.version_info < (3, 11):
 else:
     from typing import NotRequired as _NotRequired
 
+if _sys.version_info < (3, 8):
+    from typing_extensions import Literal as _Literal
+else:
+    from typing import Literal as _Literal
+
 if _sys.version_info < (3, 9):
     from typing_extensions import TypedDict as _TypedDict
 else:
@@ -50,8 +61,11 @@ __all__ = [
     'Url',
     'MultiHostUrl',
     'ArgsKwargs',
+    'PathFilter',
     'PydanticUndefined',
     'PydanticUndefinedType',
+    'UNSET',
+    'UnsetType',
     'SchemaError',
     'ErrorDetails',
     'InitErrorDetails',
@@ -62,11 +76,15 @@ __all__ = [
     'PydanticUseDefault',
     'PydanticSerializationError',
     'PydanticSerializationUnexpectedValue',
+    'PydanticSerializationWarning',
+    'SerializationWarningDetails',
     'TzInfo',
     'to_json',
     'from_json',
     'to_jsonable_python',
     'validate_core_schema',
+    'merge_config',
+    'set_trace_subscriber',
 ]
 
 
@@ -105,6 +123,25 @@ class InitErrorDetails(_TypedDict):
     """
 
 
+class SerializationWarningDetails(_TypedDict):
+    type: _Literal['unexpected_type', 'unexpected_value']
+    """
+    `'unexpected_type'` if the value's type didn't match the schema, `'unexpected_value'` if a function serializer
+    raised [`PydanticSerializationUnexpectedValue`][pydantic_core.PydanticSerializationUnexpectedValue].
+    """
+    locs: tuple[tuple[int | str, ...], ...]
+    """
+    Each location where the problem occurred, as a tuple of strings and ints identifying where in the serialized
+    data the value is.
+    """
+    msg: str
+    """A human readable message, without the locations."""
+    expected: _NotRequired[str]
+    """The name of the serializer which didn't match the value, for `'unexpected_type'`."""
+    input_type: _NotRequired[str]
+    """The name of the value's type, for `'unexpected_type'`."""
+
+
 class ErrorTypeInfo(_TypedDict):
     """
     Gives information about errors.
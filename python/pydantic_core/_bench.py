@@ -0,0 +1,211 @@
+"""
+Standardized micro-benchmarks for pydantic-core, so performance can be measured in the environment
+pydantic-core is actually used in, e.g. to check for regressions after upgrading or to include
+in performance reports.
+
+Run from the command line with:
+
+    python -m pydantic_core._bench [--number N] [--repeat R] [--json] [name ...]
+
+or call `run()` to get the results as a list of `BenchResult`.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import platform
+import sys
+import timeit
+from dataclasses import asdict, dataclass
+from datetime import date, datetime
+from decimal import Decimal
+from typing import Any, Callable, Iterable, Sequence
+
+from . import SchemaSerializer, SchemaValidator, __version__, core_schema
+
+__all__ = 'BENCHMARKS', 'BenchResult', 'run', 'main'
+
+
+@dataclass
+class BenchCase:
+    """A representative schema with valid input data for it."""
+
+    name: str
+    schema: core_schema.CoreSchema
+    python_input: Any
+    json_input: str
+
+
+@dataclass
+class BenchResult:
+    """The timing of one operation on one case, times are in seconds per call."""
+
+    name: str
+    operation: str
+    number: int
+    best: float
+    mean: float
+
+
+def _simple_model_case() -> BenchCase:
+    schema = core_schema.typed_dict_schema(
+        {
+            'id': core_schema.typed_dict_field(core_schema.int_schema()),
+            'name': core_schema.typed_dict_field(core_schema.str_schema(max_length=100)),
+            'score': core_schema.typed_dict_field(core_schema.float_schema(ge=0)),
+            'active': core_schema.typed_dict_field(core_schema.bool_schema()),
+            'tags': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+        }
+    )
+    python_input = {'id': 1, 'name': 'Alice', 'score': 9.5, 'active': True, 'tags': ['a', 'b', 'c']}
+    return BenchCase('simple_model', schema, python_input, json.dumps(python_input))
+
+
+def _nested_model_case() -> BenchCase:
+    item = core_schema.typed_dict_schema(
+        {
+            'sku': core_schema.typed_dict_field(core_schema.str_schema(pattern=r'^[A-Z]{3}-\d+$')),
+            'quantity': core_schema.typed_dict_field(core_schema.int_schema(gt=0)),
+            'price': core_schema.typed_dict_field(core_schema.decimal_schema()),
+        }
+    )
+    schema = core_schema.typed_dict_schema(
+        {
+            'order_id': core_schema.typed_dict_field(core_schema.uuid_schema()),
+            'created': core_schema.typed_dict_field(core_schema.datetime_schema()),
+            'delivery': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.date_schema())),
+            'items': core_schema.typed_dict_field(core_schema.list_schema(item)),
+            'notes': core_schema.typed_dict_field(core_schema.dict_schema(core_schema.str_schema())),
+        }
+    )
+    items = [{'sku': f'ABC-{i}', 'quantity': i + 1, 'price': str(Decimal(i) / 4)} for i in range(20)]
+    json_data = {
+        'order_id': '12345678-1234-5678-1234-567812345678',
+        'created': '2024-01-02T03:04:05',
+        'delivery': '2024-01-05',
+        'items': items,
+        'notes': {'gift': 'yes', 'door': 'back'},
+    }
+    python_input = {
+        **json_data,
+        'created': datetime(2024, 1, 2, 3, 4, 5),
+        'delivery': date(2024, 1, 5),
+        'items': [{**item, 'price': Decimal(item['price'])} for item in items],
+    }
+    return BenchCase('nested_model', schema, python_input, json.dumps(json_data))
+
+
+def _tagged_union_case() -> BenchCase:
+    def shape(kind: str, **fields: core_schema.CoreSchema) -> core_schema.CoreSchema:
+        return core_schema.typed_dict_schema(
+            {
+                'kind': core_schema.typed_dict_field(core_schema.literal_schema([kind])),
+                **{k: core_schema.typed_dict_field(v) for k, v in fields.items()},
+            }
+        )
+
+    schema = core_schema.list_schema(
+        core_schema.tagged_union_schema(
+            {
+                'circle': shape('circle', radius=core_schema.float_schema()),
+                'rect': shape('rect', width=core_schema.float_schema(), height=core_schema.float_schema()),
+                'label': shape('label', text=core_schema.str_schema()),
+            },
+            discriminator='kind',
+        )
+    )
+    python_input = [
+        {'kind': 'circle', 'radius': 1.5},
+        {'kind': 'rect', 'width': 2.0, 'height': 3.0},
+        {'kind': 'label', 'text': 'hello'},
+    ] * 10
+    return BenchCase('tagged_union', schema, python_input, json.dumps(python_input))
+
+
+def _large_list_case() -> BenchCase:
+    schema = core_schema.list_schema(core_schema.int_schema())
+    python_input = list(range(1000))
+    return BenchCase('large_list', schema, python_input, json.dumps(python_input))
+
+
+BENCHMARKS: dict[str, Callable[[], BenchCase]] = {
+    'simple_model': _simple_model_case,
+    'nested_model': _nested_model_case,
+    'tagged_union': _tagged_union_case,
+    'large_list': _large_list_case,
+}
+
+
+def _operations(case: BenchCase) -> Iterable[tuple[str, Callable[[], Any]]]:
+    validator = SchemaValidator(case.schema)
+    serializer = SchemaSerializer(case.schema)
+    # check the bundled data is valid before timing anything
+    validated = validator.validate_python(case.python_input)
+    validator.validate_json(case.json_input)
+
+    yield 'build', lambda: SchemaValidator(case.schema)
+    yield 'validate_python', lambda: validator.validate_python(case.python_input)
+    yield 'validate_json', lambda: validator.validate_json(case.json_input)
+    yield 'to_python', lambda: serializer.to_python(validated)
+    yield 'to_json', lambda: serializer.to_json(validated)
+
+
+def run(names: Sequence[str] | None = None, *, number: int = 1000, repeat: int = 5) -> list[BenchResult]:
+    """
+    Run the benchmarks named in `names`, or all of them, returning the time taken per call for each operation.
+
+    Args:
+        names: The benchmarks to run, see `BENCHMARKS`, defaults to all of them
+        number: The number of calls to time in each repeat
+        repeat: The number of times to repeat the timing, the best and mean of the repeats are reported
+    """
+    if names is None:
+        names = list(BENCHMARKS)
+    unknown = [name for name in names if name not in BENCHMARKS]
+    if unknown:
+        raise ValueError(f'Unknown benchmarks {unknown}, expected some of {list(BENCHMARKS)}')
+
+    results: list[BenchResult] = []
+    for name in names:
+        case = BENCHMARKS[name]()
+        for operation, func in _operations(case):
+            times = [t / number for t in timeit.repeat(func, number=number, repeat=repeat)]
+            results.append(BenchResult(name, operation, number, min(times), sum(times) / len(times)))
+    return results
+
+
+def environment() -> dict[str, str]:
+    """Details of the environment the benchmarks ran in, to include in performance reports."""
+    return {
+        'pydantic_core': __version__,
+        'python': sys.version.split()[0],
+        'implementation': platform.python_implementation(),
+        'platform': platform.platform(),
+        'machine': platform.machine(),
+    }
+
+
+def main(argv: Sequence[str] | None = None) -> None:
+    parser = argparse.ArgumentParser(prog='python -m pydantic_core._bench', description='pydantic-core benchmarks')
+    parser.add_argument('names', nargs='*', help=f'benchmarks to run, from {", ".join(BENCHMARKS)}')
+    parser.add_argument('--number', type=int, default=1000, help='calls to time in each repeat')
+    parser.add_argument('--repeat', type=int, default=5, help='number of repeats')
+    parser.add_argument('--json', action='store_true', help='print results as JSON')
+    args = parser.parse_args(argv)
+
+    results = run(args.names or None, number=args.number, repeat=args.repeat)
+    if args.json:
+        print(json.dumps({'environment': environment(), 'results': [asdict(r) for r in results]}, indent=2))
+        return
+
+    for key, value in environment().items():
+        print(f'{key}: {value}')
+    print()
+    print(f'{"benchmark":<16} {"operation":<16} {"best (µs)":>12} {"mean (µs)":>12}')
+    for r in results:
+        print(f'{r.name:<16} {r.operation:<16} {r.best * 1e6:>12.2f} {r.mean * 1e6:>12.2f}')
+
+
+if __name__ == '__main__':
+    main()
@@ -1,11 +1,12 @@
 from __future__ import annotations
 
 import datetime
+import inspect
 import sys
-from typing import Any, Callable, Generic, Optional, Type, TypeVar
+from typing import Any, Callable, Generic, Iterable, Iterator, Optional, Type, TypeVar
 
-from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
-from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType
+from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost, SerializationWarningDetails
+from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorMessageTranslator, ErrorType
 
 if sys.version_info < (3, 8):
     from typing_extensions import final
@@ -25,6 +26,7 @@ __all__ = [
     'build_info',
     '_recursion_limit',
     'ArgsKwargs',
+    'PathFilter',
     'SchemaValidator',
     'SchemaSerializer',
     'Url',
@@ -37,8 +39,11 @@ __all__ = [
     'PydanticUseDefault',
     'PydanticSerializationError',
     'PydanticSerializationUnexpectedValue',
+    'PydanticSerializationWarning',
     'PydanticUndefined',
     'PydanticUndefinedType',
+    'UNSET',
+    'UnsetType',
     'Some',
     'to_json',
     'from_json',
@@ -46,11 +51,14 @@ __all__ = [
     'list_all_errors',
     'TzInfo',
     'validate_core_schema',
+    'merge_config',
+    'set_trace_subscriber',
 ]
 __version__: str
 build_profile: str
 build_info: str
 _recursion_limit: int
+_C_API: Any
 
 _T = TypeVar('_T', default=Any, covariant=True)
 
@@ -82,12 +90,13 @@ class SchemaValidator:
     `CombinedValidator` which may in turn own more `CombinedValidator`s which make up the full schema validator.
     """
 
-    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self:
+    def __new__(cls, schema: CoreSchema | str | bytes | bytearray, config: CoreConfig | None = None) -> Self:
         """
         Create a new SchemaValidator.
 
         Arguments:
-            schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for validation.
+            schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for validation, or the
+                schema serialized as JSON, which can't include functions or other Python objects.
             config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to configure validation.
         """
     @property
@@ -103,6 +112,14 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: 'dict[str, Any] | None' = None,
         self_instance: Any | None = None,
+        timeout: float | None = None,
+        coercion_report: list[dict[str, Any]] | None = None,
+        strict_fields: 'list[str | tuple[str | int, ...]] | None' = None,
+        lax_fields: 'list[str | tuple[str | int, ...]] | None' = None,
+        identity_map: dict[int, Any] | None = None,
+        union_choice_callback: Callable[[Any, dict[str, Any]], None] | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -117,6 +134,30 @@ class SchemaValidator:
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
                 validation from the `__init__` method of a model.
+            timeout: The maximum time in seconds validation may take, checked periodically while validating the items
+                of lists, tuples, sets and dicts. Validation is aborted with a `validation_timeout` error once it
+                has passed.
+            coercion_report: If a list is passed, then after successful validation a dict is appended to it for each
+                value which was only valid because of lax coercion, e.g. a `str` input to an `int` field.
+                Each dict has `loc`, `input_type` and `expected` keys, `expected` is the name of the validator.
+            strict_fields: Locations of fields, or items of lists, tuples and dicts, to validate in strict mode
+                regardless of `strict`, along with everything inside them. A location is a field name or a tuple
+                of field names and indices, union tags are not included.
+            lax_fields: Like `strict_fields`, but for locations to validate in lax mode.
+            identity_map: If a dict is passed, then after successful validation it maps `id(value)` of the input
+                and each field and item inside it to the output it was validated to, so outputs can be matched
+                up with inputs even when containers are rebuilt. Repeated inputs which are the same object,
+                e.g. small ints, share an entry with the last output.
+            union_choice_callback: If set, then after successful validation it's called with
+                `(output, info)` for the output of every union, where `info` is a dict with keys `union` (the
+                union's name), `index` and `choice` (the label or name of the choice which matched) and
+                `exactness` (`'exact'`, `'strict'` or `'lax'`, or `None` in `left_to_right` mode). Unions inside
+                choices which weren't used aren't reported, inner unions are reported before outer ones.
+                Tagged unions aren't reported as their choice is already known from the tag.
+            by_alias: Whether fields and arguments are looked up by their alias, defaults to `True`.
+            by_name: Whether fields and arguments are looked up by their name. If `None`,
+                [`CoreConfig.populate_by_name`][pydantic_core.core_schema.CoreConfig] is used, or `True` if
+                `by_alias=False`. Only one of `by_alias` and `by_name` may be `False`.
 
         Raises:
             ValidationError: If validation fails.
@@ -150,6 +191,14 @@ class SchemaValidator:
         strict: bool | None = None,
         context: 'dict[str, Any] | None' = None,
         self_instance: Any | None = None,
+        timeout: float | None = None,
+        coercion_report: list[dict[str, Any]] | None = None,
+        strict_fields: 'list[str | tuple[str | int, ...]] | None' = None,
+        lax_fields: 'list[str | tuple[str | int, ...]] | None' = None,
+        union_choice_callback: Callable[[Any, dict[str, Any]], None] | None = None,
+        source_spans: dict[tuple[str | int, ...], tuple[int, int]] | None = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -167,6 +216,24 @@ class SchemaValidator:
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation.
+            timeout: The maximum time in seconds validation may take, checked periodically while validating the items
+                of lists, tuples, sets and dicts. Validation is aborted with a `validation_timeout` error once it
+                has passed.
+            coercion_report: If a list is passed, then after successful validation a dict is appended to it for each
+                value which was only valid because of lax coercion, e.g. a `str` input to an `int` field.
+                Each dict has `loc`, `input_type` and `expected` keys, `expected` is the name of the validator.
+            strict_fields: Locations of fields, or items of lists, tuples and dicts, to validate in strict mode
+                regardless of `strict`, along with everything inside them. A location is a field name or a tuple
+                of field names and indices, union tags are not included.
+            lax_fields: Like `strict_fields`, but for locations to validate in lax mode.
+            union_choice_callback: See `validate_python`.
+            source_spans: If a dict is passed, it's filled with the `(start, end)` byte offsets in the JSON
+                document of every value in it, keyed by the value's path as a tuple of keys and indexes in the
+                same form as error locations, `()` being the whole document. For `str` input, offsets are into
+                its UTF-8 encoding. The dict is filled before validation, so it can be used to find the source of
+                any validation errors, but not if the JSON itself is invalid.
+            by_alias: See `validate_python`.
+            by_name: See `validate_python`.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -175,8 +242,43 @@ class SchemaValidator:
         Returns:
             The validated Python object.
         """
+    def validate_json_lines(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: 'dict[str, Any] | None' = None,
+    ) -> tuple[list[Any], list[tuple[int, ValidationError]]]:
+        """
+        Validate newline-delimited JSON (JSON Lines / NDJSON) against the schema, one value per line.
+
+        Each line is parsed and validated directly from the raw data, a line which fails is recorded in the
+        error report rather than stopping validation of the remaining lines. Blank lines are ignored.
+
+        Arguments:
+            input: The JSON Lines data to validate.
+            strict: Whether to validate the objects in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If the input is not `str`, `bytes` or `bytearray`.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            A tuple of `(results, errors)` where `results` is a list of the validated values of lines which
+            passed validation, in order, and `errors` is a list of `(line_number, ValidationError)` tuples for
+            lines which failed, line numbers start at 1.
+        """
     def validate_strings(
-        self, input: _StringInput, *, strict: bool | None = None, context: 'dict[str, Any] | None' = None
+        self,
+        input: _StringInput,
+        *,
+        strict: bool | None = None,
+        context: 'dict[str, Any] | None' = None,
+        by_alias: bool | None = None,
+        by_name: bool | None = None,
     ) -> Any:
         """
         Validate a string against the schema and return the validated Python object.
@@ -190,6 +292,8 @@ class SchemaValidator:
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            by_alias: See `validate_python`.
+            by_name: See `validate_python`.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -229,6 +333,71 @@ class SchemaValidator:
         Returns:
             Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
         """
+    def revalidate_fields(
+        self,
+        obj: Any,
+        field_names: list[str],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: 'dict[str, Any] | None' = None,
+    ) -> None:
+        """
+        Re-validate the current values of some fields on a model, e.g. after they've been changed in place.
+
+        The fields are validated together as by
+        [`validate_assignment_batch`][pydantic_core.SchemaValidator.validate_assignment_batch], so model
+        validators run once, other fields are not validated. Unlike an assignment, frozen fields and models
+        can be re-validated and `__pydantic_fields_set__` isn't changed.
+
+        Arguments:
+            obj: The model instance to re-validate.
+            field_names: The names of the fields to re-validate.
+            strict: Whether to validate the fields in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation of any of the fields fails, errors for all fields are included.
+            Exception: Other error types maybe raised if internal errors occur.
+        """
+    def validate_assignment_batch(
+        self,
+        obj: Any,
+        assignments: dict[str, Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: 'dict[str, Any] | None' = None,
+    ) -> Any:
+        """
+        Validate several assignments to a model or dataclass, all or nothing.
+
+        Fields are validated in order as by
+        [`validate_assignment`][pydantic_core.SchemaValidator.validate_assignment], so each sees the values
+        validated before it, but validators of the whole model or dataclass run once with all of the new values.
+        If validation fails, `obj` (including `__pydantic_fields_set__`) is left as it was before.
+
+        Arguments:
+            obj: The model or dataclass instance to assign to.
+            assignments: A mapping of field names to the values to assign.
+            strict: Whether to validate the values in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation of any of the assignments fails, errors for all of them are included.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            `obj`, with all the assignments applied.
+        """
     def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
         """
         Get the default value for the schema, including running default value validation.
@@ -246,8 +415,23 @@ class SchemaValidator:
         Returns:
             `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
         """
+    def coverage_stats(self, *, reset: bool = False) -> dict[str, int] | None:
+        """
+        Get the number of times each union branch, model field and model extra has been exercised by validation.
 
-_IncEx: TypeAlias = set[int] | set[str] | dict[int, _IncEx] | dict[str, _IncEx] | None
+        Keys are paths like `'union[int,str].int'` or `'MyModel.name'`, counters are only recorded if
+        [`CoreConfig.coverage_stats`][pydantic_core.core_schema.CoreConfig] is `True`.
+
+        Arguments:
+            reset: Whether to reset all counters to zero after reading them.
+
+        Returns:
+            `None` if coverage stats are not enabled, otherwise a dict of paths to counts.
+        """
+
+_IncEx: TypeAlias = (
+    set[int] | set[str] | dict[int, _IncEx] | dict[str, _IncEx] | Callable[[tuple[int | str, ...], Any], bool] | None
+)
 
 @final
 class SchemaSerializer:
@@ -278,16 +462,32 @@ class SchemaSerializer:
         round_trip: bool = False,
         warnings: bool = True,
         fallback: Callable[[Any], Any] | None = None,
+        keep_models: bool = False,
+        shared_refs: bool = False,
+        recursion_limit: int | None = None,
+        reveal_secrets: bool = False,
+        warnings_collector: list[SerializationWarningDetails] | None = None,
     ) -> Any:
         """
         Serialize/marshal a Python object to a Python object including transforming and filtering data.
 
         Arguments:
             value: The Python object to serialize.
-            mode: The serialization mode to use, either `'python'` or `'json'`, defaults to `'python'`. In JSON mode,
-                all values are converted to JSON compatible types, e.g. `None`, `int`, `float`, `str`, `list`, `dict`.
+            mode: The serialization mode to use, either `'python'`, `'json'` or `'json-compatible-dict'`, defaults to
+                `'python'`. In JSON mode, all values are converted to JSON compatible types, e.g. `None`, `int`,
+                `float`, `str`, `list`, `dict`. `'json-compatible-dict'` is like `'json'` but also converts `inf` and
+                `nan` to `None` when `ser_json_inf_nan='null'`, matching the output of `to_json`, so the result can
+                be passed directly to another JSON encoder.
             include: A set of fields to include, if `None` all fields are included.
+                Alternatively a [`PathFilter`][pydantic_core.PathFilter] of path patterns, e.g.
+                `PathFilter({'items.*.name'})`.
+                Alternatively a predicate `(path, value) -> bool` which is called for every field and item,
+                in which case a value is only included if the predicate returns true for it and everything
+                containing it.
             exclude: A set of fields to exclude, if `None` no fields are excluded.
+                Path patterns are supported as for `include`, e.g. `PathFilter({'**.password'})` excludes every
+                field named `password`. Alternatively a predicate `(path, value) -> bool`, returning true to
+                exclude the value.
             by_alias: Whether to use the alias names of fields.
             exclude_unset: Whether to exclude fields that are not set,
                 e.g. are not included in `__pydantic_fields_set__`.
@@ -297,6 +497,18 @@ class SchemaSerializer:
             warnings: Whether to log warnings when invalid fields are encountered.
             fallback: A function to call when an unknown value is encountered,
                 if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+                No warning is emitted when a field receives a value of a type that `fallback` handles.
+            keep_models: Whether to keep models nested within the top-level model as model instances rather than
+                converting them to dicts, can't be used with `mode='json'`.
+            shared_refs: Whether to serialize model instances which appear more than once as references, the first
+                occurrence of each model instance gets an extra `'$id'` key and any later occurrences, including
+                circular references, are serialized as `{'$ref': id}`.
+            recursion_limit: The maximum depth of recursive references (and values with inferred types) before
+                serialization fails, overriding [`CoreConfig.recursion_limit`][pydantic_core.core_schema.CoreConfig].
+            reveal_secrets: Whether to serialize the real values of
+                [`secret_schema`][pydantic_core.core_schema.secret_schema] fields, rather than `'**********'`.
+            warnings_collector: A list a [`SerializationWarningDetails`][pydantic_core.SerializationWarningDetails]
+                is appended to for each problem, whether or not `warnings` is enabled.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -318,6 +530,10 @@ class SchemaSerializer:
         round_trip: bool = False,
         warnings: bool = True,
         fallback: Callable[[Any], Any] | None = None,
+        shared_refs: bool = False,
+        recursion_limit: int | None = None,
+        reveal_secrets: bool = False,
+        warnings_collector: list[SerializationWarningDetails] | None = None,
     ) -> bytes:
         """
         Serialize a Python object to JSON including transforming and filtering data.
@@ -326,7 +542,9 @@ class SchemaSerializer:
             value: The Python object to serialize.
             indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
             include: A set of fields to include, if `None` all fields are included.
+                Path patterns and predicates are supported as for `to_python`.
             exclude: A set of fields to exclude, if `None` no fields are excluded.
+                Path patterns and predicates are supported as for `to_python`.
             by_alias: Whether to use the alias names of fields.
             exclude_unset: Whether to exclude fields that are not set,
                 e.g. are not included in `__pydantic_fields_set__`.
@@ -336,6 +554,16 @@ class SchemaSerializer:
             warnings: Whether to log warnings when invalid fields are encountered.
             fallback: A function to call when an unknown value is encountered,
                 if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+                No warning is emitted when a field receives a value of a type that `fallback` handles.
+            shared_refs: Whether to serialize model instances which appear more than once as references, the first
+                occurrence of each model instance gets an extra `'$id'` key and any later occurrences, including
+                circular references, are serialized as `{'$ref': id}`.
+            recursion_limit: The maximum depth of recursive references (and values with inferred types) before
+                serialization fails, overriding [`CoreConfig.recursion_limit`][pydantic_core.core_schema.CoreConfig].
+            reveal_secrets: Whether to serialize the real values of
+                [`secret_schema`][pydantic_core.core_schema.secret_schema] fields, rather than `'**********'`.
+            warnings_collector: A list a [`SerializationWarningDetails`][pydantic_core.SerializationWarningDetails]
+                is appended to for each problem, whether or not `warnings` is enabled.
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -343,6 +571,61 @@ class SchemaSerializer:
         Returns:
            JSON bytes.
         """
+    def to_cbor(
+        self,
+        value: Any,
+        *,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool = True,
+        fallback: Callable[[Any], Any] | None = None,
+        recursion_limit: int | None = None,
+        reveal_secrets: bool = False,
+        warnings_collector: list[SerializationWarningDetails] | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to [CBOR](https://cbor.io) including transforming and filtering data.
+
+        Values are serialized as for `to_json`, except:
+
+        * `bytes` are written as CBOR byte strings, ignoring `ser_json_bytes`
+        * integers too large for 64 bits are written as bignums
+        * `datetime` values with a UTC offset are tagged as date/time strings (tag 0), naive ones are plain strings
+        * `Decimal` values are written as decimal fractions (tag 4), except for `NaN` and infinities
+
+        Arguments:
+            value: The Python object to serialize.
+            include: A set of fields to include, if `None` all fields are included.
+                Path patterns and predicates are supported as for `to_python`.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+                Path patterns and predicates are supported as for `to_python`.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: Whether to log warnings when invalid fields are encountered.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            recursion_limit: The maximum depth of recursive references (and values with inferred types) before
+                serialization fails, overriding [`CoreConfig.recursion_limit`][pydantic_core.core_schema.CoreConfig].
+            reveal_secrets: Whether to serialize the real values of
+                [`secret_schema`][pydantic_core.core_schema.secret_schema] fields, rather than `'**********'`.
+            warnings_collector: A list a [`SerializationWarningDetails`][pydantic_core.SerializationWarningDetails]
+                is appended to for each problem, whether or not `warnings` is enabled.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           CBOR bytes.
+        """
 
 def to_json(
     value: Any,
@@ -353,8 +636,9 @@ def to_json(
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    timedelta_mode: Literal['iso8601', 'float', 'seconds_int'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    bigint_mode: Literal['number', 'string', 'error'] = 'number',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
 ) -> bytes:
@@ -371,8 +655,10 @@ def to_json(
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
-        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'float'` or `'seconds_int'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'` or `'base64'`.
+        bigint_mode: How to serialize integers which can't be represented exactly as a JSON number (beyond 2**53),
+            either `'number'`, `'string'` or `'error'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
@@ -412,8 +698,9 @@ def to_jsonable_python(
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
-    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    timedelta_mode: Literal['iso8601', 'float', 'seconds_int'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    bigint_mode: Literal['number', 'string', 'error'] = 'number',
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
 ) -> Any:
@@ -430,8 +717,10 @@ def to_jsonable_python(
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
-        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'float'` or `'seconds_int'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'` or `'base64'`.
+        bigint_mode: How to serialize integers which can't be represented exactly as a JSON number (beyond 2**53),
+            either `'number'`, `'string'` or `'error'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
@@ -444,6 +733,23 @@ def to_jsonable_python(
         The serialized Python object.
     """
 
+def register_ob_type(tp: type[Any], ob_type: str) -> None:
+    """
+    Register a type (and its subclasses) to be serialized as a known kind of object when inferring how to serialize
+    values, e.g. with `to_json`, `to_jsonable_python` or an `any_schema`.
+
+    This lets frequently serialized third-party types skip the slower `isinstance` checks otherwise performed
+    for unrecognised types.
+
+    Arguments:
+        tp: The type to register.
+        ob_type: The kind of object to serialize `tp` as, e.g. `'path'` to serialize it with `str(value)`,
+            or `'unknown'` to go straight to the `fallback` function.
+
+    Raises:
+        ValueError: If `ob_type` is not a valid kind of object.
+    """
+
 class Url(SupportsAllComparisons):
     """
     A URL type, internal logic uses the [url rust crate](https://docs.rs/url/latest/url/) originally developed
@@ -753,7 +1059,12 @@ class ValidationError(ValueError):
             The number of errors in the validation error.
         """
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        message_translator: ErrorMessageTranslator | None = None,
     ) -> list[ErrorDetails]:
         """
         Details about each error in the validation error.
@@ -762,10 +1073,27 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            message_translator: Used to translate error messages, overrides
+                [`CoreConfig.error_message_translator`][pydantic_core.core_schema.CoreConfig].
 
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
         """
+    def errors_raw(
+        self, *, include_context: bool = True, include_input: bool = True
+    ) -> list[tuple[str, tuple[int | str, ...], dict[str, Any] | None, Any]]:
+        """
+        A cheaper version of [`errors()`][pydantic_core.ValidationError.errors] for handling many errors,
+        error messages and URLs are not rendered.
+
+        Args:
+            include_context: Whether to include the context of each error, if `False` the context is always `None`.
+            include_input: Whether to include the input value of each error, if `False` the input is always `None`.
+
+        Returns:
+            A list of `(type, loc, ctx, input)` tuples for each error in the validation error, `ctx` is `None`
+            for errors without context.
+        """
     def json(
         self,
         *,
@@ -773,6 +1101,7 @@ class ValidationError(ValueError):
         include_url: bool = True,
         include_context: bool = True,
         include_input: bool = True,
+        message_translator: ErrorMessageTranslator | None = None,
     ) -> str:
         """
         Same as [`errors()`][pydantic_core.ValidationError.errors] but returns a JSON string.
@@ -782,6 +1111,8 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            message_translator: Used to translate error messages, overrides
+                [`CoreConfig.error_message_translator`][pydantic_core.core_schema.CoreConfig].
 
         Returns:
             a JSON string.
@@ -827,6 +1158,15 @@ class PydanticSerializationError(ValueError):
 class PydanticSerializationUnexpectedValue(ValueError):
     def __new__(cls, message: str | None = None) -> Self: ...
 
+class PydanticSerializationWarning(UserWarning):
+    """
+    The category of warnings emitted when values don't match the schema they're serialized with.
+
+    Each distinct problem is reported once per serialization call, with the path of the value
+    within the data when it's nested, e.g. `items.0.name`, pass `warnings_collector` to get the problems as
+    [`SerializationWarningDetails`][pydantic_core.SerializationWarningDetails] instead of parsing the message.
+    """
+
 @final
 class ArgsKwargs:
     def __new__(cls, args: tuple[Any, ...], kwargs: dict[str, Any] | None = None) -> Self: ...
@@ -834,6 +1174,42 @@ class ArgsKwargs:
     def args(self) -> tuple[Any, ...]: ...
     @property
     def kwargs(self) -> dict[str, Any] | None: ...
+    @staticmethod
+    def from_bound_arguments(bound_arguments: inspect.BoundArguments) -> ArgsKwargs:
+        """
+        Create an `ArgsKwargs` from the result of `inspect.Signature.bind()` or `bind_partial()`.
+        """
+    def as_call_args(self) -> tuple[tuple[Any, ...], dict[str, Any]]:
+        """
+        Returns `(args, kwargs)` to call a function with as `f(*args, **kwargs)`, `kwargs` is a new dict.
+        """
+    def __len__(self) -> int:
+        """
+        The number of positional and keyword arguments.
+        """
+    def __iter__(self) -> Iterator[int | str]:
+        """
+        The indexes of positional arguments, then the names of keyword arguments.
+        """
+    def __bool__(self) -> Literal[True]:
+        """
+        Always `True`, even without any arguments.
+        """
+    def __getitem__(self, key: int | str) -> Any:
+        """
+        A positional argument by index, or a keyword argument by name.
+        """
+
+@final
+class PathFilter:
+    """
+    Path patterns for the `include` and `exclude` arguments of serialization, e.g. `PathFilter({'items.*.secret'})`.
+
+    Patterns are keys separated by `.`, where `*` matches any run of characters in a key (or any key) and `**`
+    matches any number of keys. List and tuple indexes are matched as strings, e.g. `'items.0'`.
+    """
+
+    def __new__(cls, patterns: Iterable[str]) -> Self: ...
 
 @final
 class PydanticUndefinedType:
@@ -842,6 +1218,22 @@ class PydanticUndefinedType:
 
 PydanticUndefined: PydanticUndefinedType
 
+@final
+class UnsetType:
+    """
+    The type of [`UNSET`][pydantic_core.UNSET], which marks a field as not provided, as distinct from `None`.
+
+    Fields with the value `UNSET` aren't included in `__pydantic_fields_set__` when validating models,
+    and are left out of the output when serializing, e.g. use `with_default_schema(schema, default=UNSET)`
+    for fields of PATCH-style models which may be null, or may not be provided at all.
+    """
+
+    def __bool__(self) -> Literal[False]: ...
+    def __copy__(self) -> Self: ...
+    def __deepcopy__(self, memo: Any) -> Self: ...
+
+UNSET: UnsetType
+
 def list_all_errors() -> list[ErrorTypeInfo]:
     """
     Get information about all built-in errors.
@@ -864,3 +1256,42 @@ def validate_core_schema(schema: CoreSchema, *, strict: bool | None = None) -> C
     We may also remove this function altogether, do not rely on it being present if you are
     using pydantic-core directly.
     """
+
+def merge_config(parent: CoreConfig | None, child: CoreConfig | None) -> CoreConfig:
+    """
+    Merge a parent config with a child config, e.g. when a model inherits from another model.
+
+    Options set on the child take precedence over the parent's, except for `custom_error_messages`,
+    `ser_json_any_dispatch` and `error_message_translator`, which are merged key by key when they're dicts
+    in both configs. Neither input is modified.
+
+    Arguments:
+        parent: The config of the parent, if any.
+        child: The config of the child, if any.
+
+    Returns:
+        The merged config.
+    """
+
+def set_trace_subscriber(
+    subscriber: Callable[[str, str | None, float], None] | None, *, slow_threshold: float | None = None
+) -> None:
+    """
+    Set a callback to receive timing spans, for observability of pydantic-core in production.
+
+    The callback is called with `(name, target, duration)` when each span finishes, `duration` is in seconds.
+    Spans are recorded for building `SchemaValidator` and `SchemaSerializer` (with `target` `None`), and for each
+    top-level validation and serialization call (with the name of the validator or serializer as `target`),
+    e.g. `('SchemaValidator.validate_json', 'MyModel', 0.0012)`.
+    If `slow_threshold` is set, models, dataclasses and function validators which take at least that many seconds
+    are also reported with the name `'validate'`.
+
+    Spans are only recorded when pydantic-core is built with the `tracing` cargo feature, which emits them as
+    [`tracing`](https://docs.rs/tracing) spans, otherwise setting a subscriber raises a `RuntimeError`.
+    The first call installs the global `tracing` subscriber which calls `subscriber`, a `RuntimeError` is raised if
+    a global subscriber has already been set, e.g. by an application embedding pydantic-core.
+
+    Arguments:
+        subscriber: The callback, or `None` to stop recording spans.
+        slow_threshold: The minimum duration in seconds of nested validators to report.
+    """
@@ -42,6 +42,10 @@ else:
 
 
 ExtraBehavior = Literal['allow', 'forbid', 'ignore']
+ExtrasKeyTransform = Union[Literal['snake_case'], Callable[[str], str]]
+# either a dict of error types to message templates, or a callable taking `(error_type, message_template, context)`
+# and returning a message template, or `None` to use `message_template` unchanged
+ErrorMessageTranslator = Union[Dict[str, str], Callable[[str, str, Union[Dict[str, Any], None]], Union[str, None]]]
 
 
 class CoreConfig(TypedDict, total=False):
@@ -54,27 +58,80 @@ class CoreConfig(TypedDict, total=False):
         extra_fields_behavior: The behavior for handling extra fields.
         typed_dict_total: Whether the TypedDict should be considered total. Default is `True`.
         from_attributes: Whether to use attributes for models, dataclasses, and tagged union keys.
+        from_attributes_excluded_modules: Modules whose types are never read from attributes with `from_attributes`.
+            Default is `['builtins', 'datetime', 'collections']`.
+        from_attributes_included_types: Types, including their subclasses, which are always read from attributes
+            with `from_attributes`, even if their module is in `from_attributes_excluded_modules`.
         loc_by_alias: Whether to use the used alias (or first alias for "field required" errors) instead of
             `field_names` to construct error `loc`s. Default is `True`.
         revalidate_instances: Whether instances of models and dataclasses should re-validate. Default is 'never'.
         validate_default: Whether to validate default values during validation. Default is `False`.
         populate_by_name: Whether an aliased field may be populated by its name as given by the model attribute,
             as well as the alias. (Replaces 'allow_population_by_field_name' in Pydantic v1.) Default is `False`.
+        alias_generator: A function taking a field name and returning its alias, used for the validation and
+            serialization aliases of model and typed dict fields which don't have an explicit alias.
         str_max_length: The maximum length for string fields.
         str_min_length: The minimum length for string fields.
+        str_length_unit: How `str_max_length` and `str_min_length` count the length of strings, see `str_schema`.
         str_strip_whitespace: Whether to strip whitespace from string fields.
+        str_normalize: The Unicode normalization form to apply to string fields.
+        str_casefold: Whether to casefold string fields.
         str_to_lower: Whether to convert string fields to lowercase.
         str_to_upper: Whether to convert string fields to uppercase.
+        int_max_digits: The maximum number of decimal digits allowed in int fields, e.g. to reject very large
+            integers from JSON input, which are parsed without losing precision.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
+        multiple_of_mode: How `multiple_of` is checked on float and decimal fields, `'tolerant'` allows for
+            floating point error, `'exact'` requires an exact multiple. Default is `'tolerant'`.
+        number_precision_loss: What happens when a lax coercion loses precision, i.e. a float with a fractional
+            part validated as an int, or an int too large to be represented exactly validated as a float,
+            `'warn'` emits a `UserWarning` and `'truncate'` silently truncates (or rounds) the value.
+            Default is `'error'` for int fields and `'truncate'` for float fields.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
+        ser_json_bigint: The serialization option for integers larger than 2**53, which can't be represented
+            exactly as a JSON number. Default is 'number'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
-            in float fields. Default is 'null'.
+            in float fields, `'error'` raises an error rather than writing them. Default is 'null'.
+        ser_json_any_dispatch: A mapping of exact types to functions used to convert values of that type
+            before serializing them to JSON in `any` schemas, including values nested in lists, dicts etc. within
+            them, bypassing type inference for those types.
+        ser_circular_ref: What to serialize in place of a circular reference, `'error'` raises an error, `'null'`
+            emits `None`, `'marker'` emits `{'$circular_ref': id(value)}`. Default is 'error'.
+        ser_max_depth: The maximum depth of recursive references (and values with inferred types) to serialize,
+            values nested deeper are handled according to `ser_circular_ref`, with `'marker'` they're
+            serialized as `{'$max_depth': id(value)}`.
+        val_circular_ref: How to validate inputs which refer to themselves, e.g. ORM objects with parent and child
+            links, `'error'` raises a `recursion_loop` error, `'reuse'` links the reference to the model instance
+            being created for that input, and reuses the output for any input seen again within one call.
+            Default is 'error'.
+        val_json_bytes: The validation option for `bytes` values, complementing `ser_json_bytes`,
+            `'base64'` decodes (URL safe) base64 strings into bytes. Default is 'utf8'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
+        custom_error_messages: A mapping of error types to message templates, overriding the messages of errors
+            raised by this `SchemaValidator`. Templates are formatted with the error context, e.g.
+            `{'string_too_short': 'Must be at least {min_length} characters'}`. This is the dict form of
+            `error_message_translator`, and can't be combined with it.
+        error_message_translator: Used to translate error messages when a `ValidationError` raised by this
+            `SchemaValidator` is rendered, e.g. for localization. Either a dict of error types to message templates,
+            or a callable taking `(error_type, message_template, context)` and returning a message template
+            or `None` to keep the original message.
+        recursion_limit: The maximum depth of recursive references allowed while validating and serializing, before
+            a `recursion_loop` error or serialization error is raised. The default is 255 (lower on some platforms),
+            raising it allows deeper structures like ASTs and file trees. Values above twice the default are capped
+            to avoid overflowing the stack.
+        coverage_stats: Whether to count how often each union branch, model field and model extra is exercised,
+            counts are read with `SchemaValidator.coverage_stats()`. Default is `False`.
+        union_errors: Which errors to report when no choice in a union matches, `'all'` reports errors from every
+            choice, `'best-match'` only from the choice which got furthest into the input (deepest error location,
+            then fewest errors), `'first'` only from the first choice. Default is 'all'.
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
+        deterministic: Whether to sort the items of sets when validating them and serializing them to JSON, so error
+            locations and output don't depend on hash seeds, e.g. for golden-file tests. Defaults to `True` if the
+            `PYDANTIC_CORE_DETERMINISTIC` environment variable is `1` or `true`, otherwise `False`.
     """
 
     title: str
@@ -84,6 +141,8 @@ class CoreConfig(TypedDict, total=False):
     typed_dict_total: bool  # default: True
     # used for models, dataclasses, and tagged union keys
     from_attributes: bool
+    from_attributes_excluded_modules: List[str]
+    from_attributes_included_types: List[Type[Any]]
     # whether to use the used alias (or first alias for "field required" errors) instead of field_names
     # to construct error `loc`s, default True
     loc_by_alias: bool
@@ -93,23 +152,44 @@ class CoreConfig(TypedDict, total=False):
     validate_default: bool
     # used on typed-dicts and arguments
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    alias_generator: Callable[[str], str]
     # fields related to string fields only
     str_max_length: int
     str_min_length: int
+    str_length_unit: Literal['codepoints', 'bytes', 'graphemes']  # default: 'codepoints'
     str_strip_whitespace: bool
+    str_normalize: Literal['NFC', 'NFKC', 'NFD', 'NFKD']
+    str_casefold: bool
     str_to_lower: bool
     str_to_upper: bool
+    # fields related to int fields only
+    int_max_digits: int
     # fields related to float fields only
     allow_inf_nan: bool  # default: True
+    multiple_of_mode: Literal['tolerant', 'exact']  # default: 'tolerant'
+    # fields related to both int and float fields
+    number_precision_loss: Literal['error', 'warn', 'truncate']  # default: 'error' for ints, 'truncate' for floats
     # the config options are used to customise serialization to JSON
-    ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
+    ser_json_timedelta: Literal['iso8601', 'float', 'seconds_int']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
-    ser_json_inf_nan: Literal['null', 'constants']  # default: 'null'
+    ser_json_bigint: Literal['number', 'string', 'error']  # default: 'number'
+    ser_json_inf_nan: Literal['null', 'constants', 'error']  # default: 'null'
+    ser_json_any_dispatch: Dict[Type[Any], Callable[[Any], Any]]
+    ser_circular_ref: Literal['error', 'null', 'marker']  # default: 'error'
+    ser_max_depth: int
+    val_circular_ref: Literal['error', 'reuse']  # default: 'error'
+    val_json_bytes: Literal['utf8', 'base64']  # default: 'utf8'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
+    custom_error_messages: Dict[str, str]
+    error_message_translator: ErrorMessageTranslator
+    recursion_limit: int
+    coverage_stats: bool  # default: False
+    union_errors: Literal['all', 'best-match', 'first']  # default: 'all'
     coerce_numbers_to_str: bool  # default: False
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    deterministic: bool  # default: False
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -197,6 +277,16 @@ class ValidationInfo(Protocol):
         """
         ...
 
+    @property
+    def state(self) -> Dict[str, Any]:
+        """
+        A dict shared by every validator function called while validating one input, including functions
+        called from the handler of a wrap validator. Unlike `context`, it's meant to be mutated, e.g. to count
+        or deduplicate values across fields, and a new empty dict is used for each call to `validate_python`,
+        `validate_json` etc.
+        """
+        ...
+
 
 ExpectedSerializationTypes = Literal[
     'none',
@@ -553,6 +643,10 @@ class IntSchema(TypedDict, total=False):
     ge: int
     lt: int
     gt: int
+    bit_width: Literal[8, 16, 32, 64]
+    signed: bool  # default: True
+    max_digits: int
+    number_precision_loss: Literal['error', 'warn', 'truncate']  # default: 'error'
     strict: bool
     ref: str
     metadata: Any
@@ -566,6 +660,10 @@ def int_schema(
     ge: int | None = None,
     lt: int | None = None,
     gt: int | None = None,
+    bit_width: Literal[8, 16, 32, 64] | None = None,
+    signed: bool | None = None,
+    max_digits: int | None = None,
+    number_precision_loss: Literal['error', 'warn', 'truncate'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -588,6 +686,11 @@ def int_schema(
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        bit_width: The value must fit in a fixed width integer of this many bits, e.g. 32 for an int32
+        signed: Whether the fixed width integer set by `bit_width` is signed, defaults to `True`
+        max_digits: The maximum number of decimal digits allowed, e.g. to limit very large ints from JSON
+        number_precision_loss: Whether a number or numeric string with a fractional part is an error (`'error'`),
+            or is truncated towards zero with a warning (`'warn'`) or silently (`'truncate'`), in lax mode
         strict: Whether the value should be a int or a value that can be converted to a int
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -600,6 +703,214 @@ def int_schema(
         ge=ge,
         lt=lt,
         gt=gt,
+        bit_width=bit_width,
+        signed=signed,
+        max_digits=max_digits,
+        number_precision_loss=number_precision_loss,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class Int64Schema(TypedDict, total=False):
+    type: Required[Literal['int64']]
+    multiple_of: int
+    le: int
+    ge: int
+    lt: int
+    gt: int
+    number_precision_loss: Literal['error', 'warn', 'truncate']  # default: 'error'
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def int64_schema(
+    *,
+    multiple_of: int | None = None,
+    le: int | None = None,
+    ge: int | None = None,
+    lt: int | None = None,
+    gt: int | None = None,
+    number_precision_loss: Literal['error', 'warn', 'truncate'] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> Int64Schema:
+    """
+    Returns a schema that matches a signed 64 bit integer, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.int64_schema(ge=0)
+    v = SchemaValidator(schema)
+    assert v.validate_python('42') == 42
+    ```
+
+    Values outside `-2**63` to `2**63 - 1` are an `int_out_of_range` error. Constraints are checked without creating big
+    ints, which makes this faster than `int_schema` for values like database columns and binary protocol fields.
+
+    Args:
+        multiple_of: The value must be a multiple of this number
+        le: The value must be less than or equal to this number
+        ge: The value must be greater than or equal to this number
+        lt: The value must be strictly less than this number
+        gt: The value must be strictly greater than this number
+        number_precision_loss: Whether a float with a fractional part is an error (`'error'`), or is truncated
+            towards zero with a warning (`'warn'`) or silently (`'truncate'`), in lax mode
+        strict: Whether the value should be a int or a value that can be converted to a int
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='int64',
+        multiple_of=multiple_of,
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        number_precision_loss=number_precision_loss,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class UInt64Schema(TypedDict, total=False):
+    type: Required[Literal['uint64']]
+    multiple_of: int
+    le: int
+    ge: int
+    lt: int
+    gt: int
+    number_precision_loss: Literal['error', 'warn', 'truncate']  # default: 'error'
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def uint64_schema(
+    *,
+    multiple_of: int | None = None,
+    le: int | None = None,
+    ge: int | None = None,
+    lt: int | None = None,
+    gt: int | None = None,
+    number_precision_loss: Literal['error', 'warn', 'truncate'] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> UInt64Schema:
+    """
+    Returns a schema that matches an unsigned 64 bit integer, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.uint64_schema(ge=0)
+    v = SchemaValidator(schema)
+    assert v.validate_python('18446744073709551615') == 18446744073709551615
+    ```
+
+    Values outside `0` to `2**64 - 1` are an `int_out_of_range` error. Constraints are checked without creating big
+    ints, which makes this faster than `int_schema` for values like database columns and binary protocol fields.
+
+    Args:
+        multiple_of: The value must be a multiple of this number
+        le: The value must be less than or equal to this number
+        ge: The value must be greater than or equal to this number
+        lt: The value must be strictly less than this number
+        gt: The value must be strictly greater than this number
+        number_precision_loss: Whether a float with a fractional part is an error (`'error'`), or is truncated
+            towards zero with a warning (`'warn'`) or silently (`'truncate'`), in lax mode
+        strict: Whether the value should be a int or a value that can be converted to a int
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='uint64',
+        multiple_of=multiple_of,
+        le=le,
+        ge=ge,
+        lt=lt,
+        gt=gt,
+        number_precision_loss=number_precision_loss,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class IntFlagSchema(TypedDict, total=False):
+    type: Required[Literal['int-flag']]
+    cls: Required[Type[Any]]
+    unknown_bits: Literal['error', 'drop', 'keep']  # default: 'error'
+    serialize_as: Literal['int', 'names']  # default: 'int'
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def int_flag_schema(
+    cls: Type[Any],
+    *,
+    unknown_bits: Literal['error', 'drop', 'keep'] | None = None,
+    serialize_as: Literal['int', 'names'] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> IntFlagSchema:
+    """
+    Returns a schema that matches an `enum.IntFlag` value, e.g.:
+
+    ```py
+    from enum import IntFlag
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Perm(IntFlag):
+        R = 4
+        W = 2
+        X = 1
+
+    schema = core_schema.int_flag_schema(Perm)
+    v = SchemaValidator(schema)
+    assert v.validate_python(6) == Perm.R | Perm.W
+    assert v.validate_json('["R", "X"]') == Perm.R | Perm.X
+    ```
+
+    Inputs can be an instance of `cls`, an int, or a list of member names which are combined.
+    Member values must be non-negative and below `2**64`.
+
+    Args:
+        cls: The `IntFlag` subclass
+        unknown_bits: Whether bits in the input which aren't covered by any member are an error (`'error'`),
+            cleared (`'drop'`) or kept (`'keep'`), `'keep'` requires `cls` to allow values with unknown bits
+        serialize_as: Whether the value is serialized to JSON as an int (`'int'`), or a list of the names of the
+            single bit members which are set (`'names'`)
+        strict: Whether the value must be an instance of `cls` in python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='int-flag',
+        cls=cls,
+        unknown_bits=unknown_bits,
+        serialize_as=serialize_as,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -611,10 +922,12 @@ class FloatSchema(TypedDict, total=False):
     type: Required[Literal['float']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: True
     multiple_of: float
+    multiple_of_mode: Literal['tolerant', 'exact']  # default: 'tolerant'
     le: float
     ge: float
     lt: float
     gt: float
+    number_precision_loss: Literal['error', 'warn', 'truncate']  # default: 'truncate'
     strict: bool
     ref: str
     metadata: Any
@@ -625,10 +938,12 @@ def float_schema(
     *,
     allow_inf_nan: bool | None = None,
     multiple_of: float | None = None,
+    multiple_of_mode: Literal['tolerant', 'exact'] | None = None,
     le: float | None = None,
     ge: float | None = None,
     lt: float | None = None,
     gt: float | None = None,
+    number_precision_loss: Literal['error', 'warn', 'truncate'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -648,10 +963,13 @@ def float_schema(
     Args:
         allow_inf_nan: Whether to allow inf and nan values
         multiple_of: The value must be a multiple of this number
+        multiple_of_mode: Whether `multiple_of` allows for floating point error (`'tolerant'`) or must be exact
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
+        number_precision_loss: Whether an int which can't be represented exactly as a float is an error
+            (`'error'`), or is rounded to the nearest float with a warning (`'warn'`) or silently (`'truncate'`)
         strict: Whether the value should be a float or a value that can be converted to a float
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -661,10 +979,12 @@ def float_schema(
         type='float',
         allow_inf_nan=allow_inf_nan,
         multiple_of=multiple_of,
+        multiple_of_mode=multiple_of_mode,
         le=le,
         ge=ge,
         lt=lt,
         gt=gt,
+        number_precision_loss=number_precision_loss,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -676,6 +996,7 @@ class DecimalSchema(TypedDict, total=False):
     type: Required[Literal['decimal']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: False
     multiple_of: Decimal
+    multiple_of_mode: Literal['tolerant', 'exact']  # default: 'tolerant'
     le: Decimal
     ge: Decimal
     lt: Decimal
@@ -692,6 +1013,7 @@ def decimal_schema(
     *,
     allow_inf_nan: bool = None,
     multiple_of: Decimal | None = None,
+    multiple_of_mode: Literal['tolerant', 'exact'] | None = None,
     le: Decimal | None = None,
     ge: Decimal | None = None,
     lt: Decimal | None = None,
@@ -718,6 +1040,7 @@ def decimal_schema(
     Args:
         allow_inf_nan: Whether to allow inf and nan values
         multiple_of: The value must be a multiple of this number
+        multiple_of_mode: Whether `multiple_of` is checked by division (`'tolerant'`) or exactly (`'exact'`)
         le: The value must be less than or equal to this number
         ge: The value must be greater than or equal to this number
         lt: The value must be strictly less than this number
@@ -738,6 +1061,7 @@ def decimal_schema(
         max_digits=max_digits,
         decimal_places=decimal_places,
         multiple_of=multiple_of,
+        multiple_of_mode=multiple_of_mode,
         allow_inf_nan=allow_inf_nan,
         strict=strict,
         ref=ref,
@@ -749,9 +1073,13 @@ def decimal_schema(
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: str
+    format: Literal['e164']
     max_length: int
     min_length: int
+    length_unit: Literal['codepoints', 'bytes', 'graphemes']  # default: 'codepoints'
     strip_whitespace: bool
+    normalize: Literal['NFC', 'NFKC', 'NFD', 'NFKD']
+    casefold: bool
     to_lower: bool
     to_upper: bool
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
@@ -764,9 +1092,13 @@ class StringSchema(TypedDict, total=False):
 def str_schema(
     *,
     pattern: str | None = None,
+    format: Literal['e164'] | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    length_unit: Literal['codepoints', 'bytes', 'graphemes'] | None = None,
     strip_whitespace: bool | None = None,
+    normalize: Literal['NFC', 'NFKC', 'NFD', 'NFKD'] | None = None,
+    casefold: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
     regex_engine: Literal['rust-regex', 'python-re'] | None = None,
@@ -788,9 +1120,20 @@ def str_schema(
 
     Args:
         pattern: A regex pattern that the value must match
+        format: A common format the value must match, checked without a regex
+            - `e164` an international phone number, `+` followed by 2 to 15 digits without spaces or punctuation,
+              the first digit can't be 0, e.g. `+14155552671`
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        length_unit: How `max_length` and `min_length` count the length of the value. Default is 'codepoints'.
+            - `codepoints` counts Unicode code points, like `len()`
+            - `bytes` counts the bytes of the UTF-8 encoded value, e.g. for database columns limited in bytes
+            - `graphemes` counts grapheme clusters (user-perceived characters), so e.g. an emoji with a skin tone
+              modifier or a letter with a combining accent counts as one
+            With `bytes` or `graphemes`, length errors are `string_units_too_short` and `string_units_too_long`
         strip_whitespace: Whether to strip whitespace from the value
+        normalize: The Unicode normalization form to apply to the value, before checking length and pattern
+        casefold: Whether to casefold the value like `str.casefold()`, before checking length and pattern
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
         regex_engine: The regex engine to use for pattern validation. Default is 'rust-regex'.
@@ -807,9 +1150,13 @@ def str_schema(
     return _dict_not_none(
         type='str',
         pattern=pattern,
+        format=format,
         max_length=max_length,
         min_length=min_length,
+        length_unit=length_unit,
         strip_whitespace=strip_whitespace,
+        normalize=normalize,
+        casefold=casefold,
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
@@ -825,6 +1172,8 @@ class BytesSchema(TypedDict, total=False):
     max_length: int
     min_length: int
     strict: bool
+    preserve_bytearray: bool
+    encoding: Literal['base64', 'base64url', 'hex']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -835,6 +1184,8 @@ def bytes_schema(
     max_length: int | None = None,
     min_length: int | None = None,
     strict: bool | None = None,
+    preserve_bytearray: bool | None = None,
+    encoding: Literal['base64', 'base64url', 'hex'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -854,6 +1205,10 @@ def bytes_schema(
         max_length: The value must be at most this length
         min_length: The value must be at least this length
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
+        preserve_bytearray: Whether `bytearray` inputs should be returned as is, rather than copied into a `bytes`
+        encoding: How string inputs are decoded, and how the bytes are encoded when serializing to JSON,
+            overriding the `val_json_bytes` and `ser_json_bytes` config;
+            `'base64'` accepts both the standard and URL safe alphabets, but serializes with the standard alphabet
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -863,6 +1218,8 @@ def bytes_schema(
         max_length=max_length,
         min_length=min_length,
         strict=strict,
+        preserve_bytearray=preserve_bytearray,
+        encoding=encoding,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1011,11 +1368,12 @@ class DatetimeSchema(TypedDict, total=False):
     lt: datetime
     gt: datetime
     now_op: Literal['past', 'future']
-    tz_constraint: Union[Literal['aware', 'naive'], int]
+    tz_constraint: Union[Literal['aware', 'naive'], int, str]
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    convert_to_tz: Union[int, str]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1029,9 +1387,10 @@ def datetime_schema(
     lt: datetime | None = None,
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
-    tz_constraint: Literal['aware', 'naive'] | int | None = None,
+    tz_constraint: Literal['aware', 'naive'] | int | str | None = None,
     now_utc_offset: int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    convert_to_tz: int | str | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1056,10 +1415,12 @@ def datetime_schema(
         lt: The value must be strictly less than this datetime
         gt: The value must be strictly greater than this datetime
         now_op: The value must be in the past or future relative to the current datetime
-        tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
-            TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
+        tz_constraint: The value must be timezone aware or naive, an int to indicate required tz offset in seconds,
+            or an IANA timezone name (e.g. `'Europe/London'`) whose offset at that instant the value must match
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        convert_to_tz: Convert timezone aware values to this timezone, either an IANA timezone name
+            or an int offset in seconds, naive values are left unchanged
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1075,12 +1436,34 @@ def datetime_schema(
         tz_constraint=tz_constraint,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        convert_to_tz=convert_to_tz,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+TimedeltaMode = Literal['iso8601', 'float', 'seconds_int']
+
+
+class TimedeltaModeSerSchema(TypedDict, total=False):
+    type: Required[Literal['timedelta-mode']]
+    timedelta_mode: Required[TimedeltaMode]
+
+
+def timedelta_mode_ser_schema(timedelta_mode: TimedeltaMode) -> TimedeltaModeSerSchema:
+    """
+    Returns a schema for serialization of a timedelta field which overrides `ser_json_timedelta` from config.
+
+    Args:
+        timedelta_mode: How the timedelta should be serialized to JSON
+    """
+    return TimedeltaModeSerSchema(type='timedelta-mode', timedelta_mode=timedelta_mode)
+
+
+TimedeltaModeOrElseSerSchema = Union[TimedeltaModeSerSchema, SerSchema]
+
+
 class TimedeltaSchema(TypedDict, total=False):
     type: Required[Literal['timedelta']]
     strict: bool
@@ -1091,7 +1474,7 @@ class TimedeltaSchema(TypedDict, total=False):
     microseconds_precision: Literal['truncate', 'error']
     ref: str
     metadata: Any
-    serialization: SerSchema
+    serialization: TimedeltaModeOrElseSerSchema
 
 
 def timedelta_schema(
@@ -1104,7 +1487,7 @@ def timedelta_schema(
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
     ref: str | None = None,
     metadata: Any = None,
-    serialization: SerSchema | None = None,
+    serialization: TimedeltaModeOrElseSerSchema | None = None,
 ) -> TimedeltaSchema:
     """
     Returns a schema that matches a timedelta value, e.g.:
@@ -1155,166 +1538,550 @@ def literal_schema(
     expected: list[Any], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
 ) -> LiteralSchema:
     """
-    Returns a schema that matches a literal value, e.g.:
+    Returns a schema that matches a literal value, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.literal_schema(['hello', 'world'])
+    v = SchemaValidator(schema)
+    assert v.validate_python('hello') == 'hello'
+    ```
+
+    Args:
+        expected: The value must be one of these values
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+
+
+class ConstSchema(TypedDict, total=False):
+    type: Required[Literal['const']]
+    expected: Required[Any]
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def const_schema(
+    expected: Any, *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> ConstSchema:
+    """
+    Returns a schema that matches a single constant value, e.g. a tag or discriminator field.
+
+    Unlike `literal_schema`, the JSON serialization of the value is computed when the serializer is built,
+    so `expected` must be `None`, a `bool`, `int`, `float`, `str` or an `Enum` member with one of those as
+    its value.
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.const_schema('cat')
+    v = SchemaValidator(schema)
+    assert v.validate_python('cat') == 'cat'
+    s = SchemaSerializer(schema)
+    assert s.to_json('cat') == b'"cat"'
+    ```
+
+    Args:
+        expected: The value must be equal to this value
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    s = _dict_not_none(type='const', ref=ref, metadata=metadata, serialization=serialization)
+    # `expected` may be `None`, which `_dict_not_none` would drop
+    s['expected'] = expected
+    return s
+
+
+class EnumSchema(TypedDict, total=False):
+    type: Required[Literal['enum']]
+    cls: Required[Type[Any]]
+    match_mode: Literal['exact', 'fuzzy']  # default: 'exact'
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def enum_schema(
+    cls: Type[Any],
+    *,
+    match_mode: Literal['exact', 'fuzzy'] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> EnumSchema:
+    """
+    Returns a schema that matches a member of an `enum.Enum` subclass, e.g.:
+
+    ```py
+    from enum import Enum
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Color(Enum):
+        DARK_RED = 'dark-red'
+        LIGHT_BLUE = 'light-blue'
+
+    schema = core_schema.enum_schema(Color, match_mode='fuzzy')
+    v = SchemaValidator(schema)
+    assert v.validate_python('dark-red') is Color.DARK_RED
+    assert v.validate_json('"Light_Blue"') is Color.LIGHT_BLUE
+    ```
+
+    Inputs can be a member of `cls` or the value of a member. With `match_mode='fuzzy'`, strings are also
+    matched against member names and string values ignoring case and `-`/`_`, and when nothing matches the
+    error lists the closest spellings. Fuzzy matching is only used in lax mode.
+
+    Args:
+        cls: The `Enum` subclass
+        match_mode: Whether strings must match a member value exactly (`'exact'`) or are matched fuzzily
+            against member names and values (`'fuzzy'`)
+        strict: Whether the value must be a member of `cls` in python
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='enum',
+        cls=cls,
+        match_mode=match_mode,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+# must match input/parse_json.rs::JsonType::try_from
+JsonType = Literal['null', 'bool', 'int', 'float', 'str', 'list', 'dict']
+
+
+class IsInstanceSchema(TypedDict, total=False):
+    type: Required[Literal['is-instance']]
+    cls: Required[Any]
+    cls_repr: str
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def is_instance_schema(
+    cls: Any,
+    *,
+    cls_repr: str | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> IsInstanceSchema:
+    """
+    Returns a schema that checks if a value is an instance of a class, equivalent to python's `isinstance` method, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    class A:
+        pass
+
+    schema = core_schema.is_instance_schema(cls=A)
+    v = SchemaValidator(schema)
+    v.validate_python(A())
+    ```
+
+    Args:
+        cls: The value must be an instance of this class
+        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='is-instance', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class IsSubclassSchema(TypedDict, total=False):
+    type: Required[Literal['is-subclass']]
+    cls: Required[Type[Any]]
+    cls_repr: str
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def is_subclass_schema(
+    cls: Type[Any],
+    *,
+    cls_repr: str | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> IsInstanceSchema:
+    """
+    Returns a schema that checks if a value is a subtype of a class, equivalent to python's `issubclass` method, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    class A:
+        pass
+
+    class B(A):
+        pass
+
+    schema = core_schema.is_subclass_schema(cls=A)
+    v = SchemaValidator(schema)
+    v.validate_python(B)
+    ```
+
+    Args:
+        cls: The value must be a subclass of this class
+        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='is-subclass', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class CallableSchema(TypedDict, total=False):
+    type: Required[Literal['callable']]
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def callable_schema(
+    *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> CallableSchema:
+    """
+    Returns a schema that checks if a value is callable, equivalent to python's `callable` method, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.callable_schema()
+    v = SchemaValidator(schema)
+    v.validate_python(min)
+    ```
+
+    Args:
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='callable', ref=ref, metadata=metadata, serialization=serialization)
+
+
+class UuidSchema(TypedDict, total=False):
+    type: Required[Literal['uuid']]
+    version: Literal[1, 3, 4, 5, 7]
+    strict_hyphenation: bool
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def uuid_schema(
+    *,
+    version: Literal[1, 3, 4, 5, 7] | None = None,
+    strict_hyphenation: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> UuidSchema:
+    return _dict_not_none(
+        type='uuid',
+        version=version,
+        strict_hyphenation=strict_hyphenation,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class EmailSchema(TypedDict, total=False):
+    type: Required[Literal['email']]
+    allow_smtputf8: bool  # default: True
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def email_schema(
+    *,
+    allow_smtputf8: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> EmailSchema:
+    """
+    Returns a schema that matches an email address, following RFC 5321 and RFC 6531, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.email_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('john@Example.COM') == 'john@example.com'
+    ```
+
+    The domain is lowercased and internationalized domain names are converted to their Unicode form,
+    the part before the `@` is unchanged.
+
+    Args:
+        allow_smtputf8: Whether to allow non-ASCII characters before the `@`, which requires the SMTPUTF8
+            extension to deliver, default `True`
+        strict: Whether the value should be a string, rather than a value that can be converted to a string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='email',
+        allow_smtputf8=allow_smtputf8,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class NameEmailSchema(TypedDict, total=False):
+    type: Required[Literal['name-email']]
+    allow_smtputf8: bool  # default: True
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def name_email_schema(
+    *,
+    allow_smtputf8: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> NameEmailSchema:
+    """
+    Returns a schema that matches an email address with an optional name, e.g. `John Doe <john@example.com>`,
+    the output is a tuple of `(name, email)` where the name defaults to the part of the address before the `@`:
 
     ```py
     from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.literal_schema(['hello', 'world'])
+    schema = core_schema.name_email_schema()
     v = SchemaValidator(schema)
-    assert v.validate_python('hello') == 'hello'
+    assert v.validate_python('John Doe <john@example.com>') == ('John Doe', 'john@example.com')
+    assert v.validate_python('john@example.com') == ('john', 'john@example.com')
     ```
 
+    When serializing to JSON the tuple is formatted as `name <email>`.
+
     Args:
-        expected: The value must be one of these values
+        allow_smtputf8: Whether to allow non-ASCII characters before the `@`, which requires the SMTPUTF8
+            extension to deliver, default `True`
+        strict: Whether the value should be a string, rather than a value that can be converted to a string
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
-
-
-# must match input/parse_json.rs::JsonType::try_from
-JsonType = Literal['null', 'bool', 'int', 'float', 'str', 'list', 'dict']
+    return _dict_not_none(
+        type='name-email',
+        allow_smtputf8=allow_smtputf8,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
-class IsInstanceSchema(TypedDict, total=False):
-    type: Required[Literal['is-instance']]
-    cls: Required[Any]
-    cls_repr: str
+class IpV4Schema(TypedDict, total=False):
+    type: Required[Literal['ip-v4']]
+    strict: bool
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
-def is_instance_schema(
-    cls: Any,
+def ip_v4_schema(
     *,
-    cls_repr: str | None = None,
+    strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
-) -> IsInstanceSchema:
+) -> IpV4Schema:
     """
-    Returns a schema that checks if a value is an instance of a class, equivalent to python's `isinstance` method, e.g.:
+    Returns a schema that matches an `ipaddress.IPv4Address`, e.g.:
 
     ```py
-    from pydantic_core import SchemaValidator, core_schema
+    from ipaddress import IPv4Address
 
-    class A:
-        pass
+    from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.is_instance_schema(cls=A)
+    schema = core_schema.ip_v4_schema()
     v = SchemaValidator(schema)
-    v.validate_python(A())
+    assert v.validate_python('192.168.0.1') == IPv4Address('192.168.0.1')
     ```
 
+    In lax mode strings, integers and packed bytes are accepted, like `ipaddress.ip_address`.
+    When serializing to JSON the address is formatted in its canonical string form.
+
     Args:
-        cls: The value must be an instance of this class
-        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        strict: Whether the value should be an `ipaddress` object, rather than a value that can be converted to one
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(
-        type='is-instance', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
-    )
+    return _dict_not_none(type='ip-v4', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
 
 
-class IsSubclassSchema(TypedDict, total=False):
-    type: Required[Literal['is-subclass']]
-    cls: Required[Type[Any]]
-    cls_repr: str
+class IpV6Schema(TypedDict, total=False):
+    type: Required[Literal['ip-v6']]
+    strict: bool
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
-def is_subclass_schema(
-    cls: Type[Any],
+def ip_v6_schema(
     *,
-    cls_repr: str | None = None,
+    strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
-) -> IsInstanceSchema:
+) -> IpV6Schema:
     """
-    Returns a schema that checks if a value is a subtype of a class, equivalent to python's `issubclass` method, e.g.:
+    Returns a schema that matches an `ipaddress.IPv6Address`, e.g.:
 
     ```py
-    from pydantic_core import SchemaValidator, core_schema
-
-    class A:
-        pass
+    from ipaddress import IPv6Address
 
-    class B(A):
-        pass
+    from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.is_subclass_schema(cls=A)
+    schema = core_schema.ip_v6_schema()
     v = SchemaValidator(schema)
-    v.validate_python(B)
+    assert v.validate_python('::1') == IPv6Address('::1')
     ```
 
+    In lax mode strings, integers and packed bytes are accepted, like `ipaddress.ip_address`.
+    When serializing to JSON the address is formatted in its canonical string form.
+
     Args:
-        cls: The value must be a subclass of this class
-        cls_repr: If provided this string is used in the validator name instead of `repr(cls)`
+        strict: Whether the value should be an `ipaddress` object, rather than a value that can be converted to one
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(
-        type='is-subclass', cls=cls, cls_repr=cls_repr, ref=ref, metadata=metadata, serialization=serialization
-    )
+    return _dict_not_none(type='ip-v6', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
 
 
-class CallableSchema(TypedDict, total=False):
-    type: Required[Literal['callable']]
+class IpAnySchema(TypedDict, total=False):
+    type: Required[Literal['ip-any']]
+    strict: bool
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
-def callable_schema(
-    *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
-) -> CallableSchema:
+def ip_any_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> IpAnySchema:
     """
-    Returns a schema that checks if a value is callable, equivalent to python's `callable` method, e.g.:
+    Returns a schema that matches an `ipaddress.IPv4Address` or `ipaddress.IPv6Address`, e.g.:
 
     ```py
+    from ipaddress import IPv6Address
+
     from pydantic_core import SchemaValidator, core_schema
 
-    schema = core_schema.callable_schema()
+    schema = core_schema.ip_any_schema()
     v = SchemaValidator(schema)
-    v.validate_python(min)
+    assert v.validate_python('2001:DB8::1') == IPv6Address('2001:db8::1')
     ```
 
+    In lax mode strings, integers and packed bytes are accepted, like `ipaddress.ip_address`.
+    When serializing to JSON the address is formatted in its canonical string form.
+
     Args:
+        strict: Whether the value should be an `ipaddress` object, rather than a value that can be converted to one
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='callable', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(type='ip-any', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
 
 
-class UuidSchema(TypedDict, total=False):
-    type: Required[Literal['uuid']]
-    version: Literal[1, 3, 4, 5]
+class IpNetworkSchema(TypedDict, total=False):
+    type: Required[Literal['ip-network']]
+    version: Literal[4, 6]
+    allow_host_bits: bool  # default: False
     strict: bool
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
-def uuid_schema(
+def ip_network_schema(
     *,
-    version: Literal[1, 3, 4, 5] | None = None,
+    version: Literal[4, 6] | None = None,
+    allow_host_bits: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
-) -> UuidSchema:
+) -> IpNetworkSchema:
+    """
+    Returns a schema that matches an `ipaddress.IPv4Network` or `ipaddress.IPv6Network`, e.g.:
+
+    ```py
+    from ipaddress import IPv4Network
+
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_network_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.0/24') == IPv4Network('192.168.0.0/24')
+    ```
+
+    In lax mode strings like `address/prefix` are accepted, IPv4 networks may use a netmask instead of a prefix
+    length, a bare address, integer or packed bytes is a network with a single address.
+    When serializing to JSON the network is formatted in its canonical string form.
+
+    Args:
+        version: The IP version the network must have, either version is allowed if omitted
+        allow_host_bits: Whether to allow an address with host bits set, e.g. `192.168.0.1/24`, which are
+            removed, default `False` when it's an error like `ipaddress.ip_network` with `strict=True`
+        strict: Whether the value should be an `ipaddress` object, rather than a value that can be converted to one
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
     return _dict_not_none(
-        type='uuid', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='ip-network',
+        version=version,
+        allow_host_bits=allow_host_bits,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
     )
 
 
@@ -1336,6 +2103,9 @@ class ListSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    unique_items: bool
+    output_type: Literal['list', 'deque', 'input']  # default: 'list'
+    deque_maxlen: int
     strict: bool
     ref: str
     metadata: Any
@@ -1347,6 +2117,9 @@ def list_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items: bool | None = None,
+    output_type: Literal['list', 'deque', 'input'] | None = None,
+    deque_maxlen: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1367,6 +2140,12 @@ def list_schema(
         items_schema: The value must be a list of items that match this schema
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
+        unique_items: The items of the list must be unique after validation
+        output_type: The type built from the validated items, `'list'` (the default), `'deque'` for a
+            `collections.deque`, or `'input'` to keep the type of inputs which are subclasses of `list` or deques
+            (with the input's `maxlen`), other inputs give a `list`
+        deque_maxlen: The `maxlen` of the deque built when `output_type` is `'deque'`, as with `collections.deque`
+            when there are more validated items than `maxlen` the leading items are dropped
         strict: The value must be a list with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1377,6 +2156,9 @@ def list_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        unique_items=unique_items,
+        output_type=output_type,
+        deque_maxlen=deque_maxlen,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1388,6 +2170,7 @@ class TuplePositionalSchema(TypedDict, total=False):
     type: Required[Literal['tuple-positional']]
     items_schema: Required[List[CoreSchema]]
     extras_schema: CoreSchema
+    variadic_item_index: int
     strict: bool
     ref: str
     metadata: Any
@@ -1398,6 +2181,7 @@ def tuple_positional_schema(
     items_schema: list[CoreSchema],
     *,
     extras_schema: CoreSchema | None = None,
+    variadic_item_index: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1416,12 +2200,49 @@ def tuple_positional_schema(
     assert v.validate_python((1, 'hello')) == (1, 'hello')
     ```
 
+    Items in `items_schema` may have defaults
+    (see [`with_default_schema`][pydantic_core.core_schema.with_default_schema]), shorter inputs are then filled in
+    with those defaults, e.g. to accept older versions of a compact wire format:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.tuple_positional_schema(
+        [core_schema.int_schema(), core_schema.with_default_schema(core_schema.str_schema(), default='')]
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_json('[1]') == (1, '')
+    ```
+
+    With `variadic_item_index`, the item at that index matches any number of items and the items after it are
+    matched from the end of the input, like `Tuple[int, *Tuple[str, ...], float]`. Items missing from shorter inputs
+    are filled in with defaults, first those before the variadic item then those after it:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.tuple_positional_schema(
+        [
+            core_schema.int_schema(),
+            core_schema.str_schema(),
+            core_schema.with_default_schema(core_schema.float_schema(), default=0.0),
+        ],
+        variadic_item_index=1,
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python((1, 'a', 'b', 2.5)) == (1, 'a', 'b', 2.5)
+    assert v.validate_python((1,)) == (1, 0.0)
+    ```
+
     Args:
-        items_schema: The value must be a tuple with items that match these schemas
+        items_schema: The value must be a tuple with items that match these schemas, items missing from the end of
+            shorter inputs are filled in with their defaults
         extras_schema: The value must be a tuple with items that match this schema
             This was inspired by JSON schema's `prefixItems` and `items` fields.
             In python's `typing.Tuple`, you can't specify a type for "extra" items -- they must all be the same type
             if the length is variable. So this field won't be set from a `typing.Tuple` annotation on a pydantic model.
+        variadic_item_index: The index of the item in `items_schema` which matches any number of items, can't be
+            combined with `extras_schema`
         strict: The value must be a tuple with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1431,6 +2252,7 @@ def tuple_positional_schema(
         type='tuple-positional',
         items_schema=items_schema,
         extras_schema=extras_schema,
+        variadic_item_index=variadic_item_index,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1443,6 +2265,7 @@ class TupleVariableSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    unique_items: bool
     strict: bool
     ref: str
     metadata: Any
@@ -1454,6 +2277,7 @@ def tuple_variable_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1476,6 +2300,7 @@ def tuple_variable_schema(
         items_schema: The value must be a tuple with items that match this schema
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
+        unique_items: The items of the tuple must be unique after validation
         strict: The value must be a tuple with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1486,6 +2311,7 @@ def tuple_variable_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        unique_items=unique_items,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1498,6 +2324,7 @@ class SetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    unique_items: bool
     strict: bool
     ref: str
     metadata: Any
@@ -1509,6 +2336,7 @@ def set_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1531,6 +2359,7 @@ def set_schema(
         items_schema: The value must be a set with items that match this schema
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
+        unique_items: Duplicate items are an error rather than being dropped
         strict: The value must be a set with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1541,6 +2370,7 @@ def set_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        unique_items=unique_items,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1553,6 +2383,7 @@ class FrozenSetSchema(TypedDict, total=False):
     items_schema: CoreSchema
     min_length: int
     max_length: int
+    unique_items: bool
     strict: bool
     ref: str
     metadata: Any
@@ -1564,6 +2395,7 @@ def frozenset_schema(
     *,
     min_length: int | None = None,
     max_length: int | None = None,
+    unique_items: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1586,6 +2418,7 @@ def frozenset_schema(
         items_schema: The value must be a frozenset with items that match this schema
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
+        unique_items: Duplicate items are an error rather than being dropped
         strict: The value must be a frozenset with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1596,6 +2429,7 @@ def frozenset_schema(
         items_schema=items_schema,
         min_length=min_length,
         max_length=max_length,
+        unique_items=unique_items,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1683,6 +2517,7 @@ class DictSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    from_pairs: bool  # default: False
     ref: str
     metadata: Any
     serialization: IncExDictOrElseSerSchema
@@ -1695,6 +2530,7 @@ def dict_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    from_pairs: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1718,6 +2554,8 @@ def dict_schema(
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
         strict: Whether the keys and values should be validated with strict mode
+        from_pairs: Whether to accept a dict items view or an iterable of `(key, value)` pairs in lax mode,
+            note that an iterator is consumed even if validation fails
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1729,6 +2567,7 @@ def dict_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        from_pairs=from_pairs,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2172,9 +3011,12 @@ class WithDefaultSchema(TypedDict, total=False):
     type: Required[Literal['default']]
     schema: Required[CoreSchema]
     default: Any
-    default_factory: Callable[[], Any]
+    default_factory: Union[Callable[[], Any], Callable[[Dict[str, Any]], Any]]
+    default_factory_takes_data: bool  # default: False
     on_error: Literal['raise', 'omit', 'default']  # default: 'raise'
+    apply_on: Literal['missing', 'null', 'both']  # default: 'missing'
     validate_default: bool  # default: False
+    default_comparison: Literal['equality', 'identity', 'serialized']  # default: 'equality'
     strict: bool
     ref: str
     metadata: Any
@@ -2185,9 +3027,12 @@ def with_default_schema(
     schema: CoreSchema,
     *,
     default: Any = PydanticUndefined,
-    default_factory: Callable[[], Any] | None = None,
+    default_factory: Union[Callable[[], Any], Callable[[Dict[str, Any]], Any], None] = None,
+    default_factory_takes_data: bool | None = None,
     on_error: Literal['raise', 'omit', 'default'] | None = None,
+    apply_on: Literal['missing', 'null', 'both'] | None = None,
     validate_default: bool | None = None,
+    default_comparison: Literal['equality', 'identity', 'serialized'] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -2211,8 +3056,19 @@ def with_default_schema(
         schema: The schema to add a default value to
         default: The default value to use
         default_factory: A function that returns the default value to use
+        default_factory_takes_data: Whether `default_factory` is called with a dict of the data validated so far,
+            the fields or arguments before this one which passed validation, e.g. to derive a slug from a title.
+            An empty dict is passed where there's no surrounding data, and the default isn't used for
+            comparison when serializing with `exclude_defaults`
         on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default'
-        validate_default: Whether the default value should be validated
+        apply_on: When the default is used, when the value is `'missing'` (the default), when it's `None`
+            (`'null'`), or `'both'`, with `'null'` a missing value is treated as if there were no default
+        validate_default: Whether the default value should be validated, a `default` is only validated the first
+            time it's used if `schema` can't depend on anything but the value, e.g. it contains no function
+            validators, unless validation is run with a `context` or `strict` setting
+        default_comparison: How values are compared to the default when serializing with `exclude_defaults`,
+            by equality, by identity, or by equality of their serialized forms. `default_factory` is called
+            at most once to get the default to compare with
         strict: Whether the underlying schema should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2222,8 +3078,11 @@ def with_default_schema(
         type='default',
         schema=schema,
         default_factory=default_factory,
+        default_factory_takes_data=default_factory_takes_data,
         on_error=on_error,
+        apply_on=apply_on,
         validate_default=validate_default,
+        default_comparison=default_comparison,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -2274,6 +3133,41 @@ def nullable_schema(
     )
 
 
+class SecretSchema(TypedDict, total=False):
+    type: Required[Literal['secret']]
+    schema: Required[CoreSchema]
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def secret_schema(
+    schema: CoreSchema, *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+) -> SecretSchema:
+    """
+    Returns a schema for a secret value, validated by `schema` and serialized as `'**********'` unless
+    `reveal_secrets=True` is passed to `to_python` or `to_json`, e.g.:
+
+    ```py
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.secret_schema(core_schema.str_schema())
+    v = SchemaValidator(schema)
+    assert v.validate_python('hunter2') == 'hunter2'
+    s = SchemaSerializer(schema)
+    assert s.to_python('hunter2') == '**********'
+    assert s.to_python('hunter2', reveal_secrets=True) == 'hunter2'
+    ```
+
+    Args:
+        schema: The schema for the secret value, generally a `str` or `bytes` schema
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='secret', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class UnionSchema(TypedDict, total=False):
     type: Required[Literal['union']]
     choices: Required[List[Union[CoreSchema, Tuple[CoreSchema, str]]]]
@@ -2283,6 +3177,7 @@ class UnionSchema(TypedDict, total=False):
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
     mode: Literal['smart', 'left_to_right']  # default: 'smart'
+    priorities: List[int]
     strict: bool
     ref: str
     metadata: Any
@@ -2297,6 +3192,7 @@ def union_schema(
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
     mode: Literal['smart', 'left_to_right'] | None = None,
+    priorities: list[int] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -2323,6 +3219,10 @@ def union_schema(
         mode: How to select which choice to return
             * `smart` (default) will try to return the choice which is the closest match to the input value
             * `left_to_right` will return the first choice in `choices` which succeeds validation
+        priorities: A priority for each choice in `choices`, higher first, only with `smart` mode. Choices are
+            tried in priority order, so when several choices match equally well the one with the highest priority
+            wins rather than the first declared. Choices with the same priority keep their declared order, the
+            union's name lists the choices in the order they're tried.
         strict: Whether the underlying schemas should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2336,6 +3236,7 @@ def union_schema(
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         mode=mode,
+        priorities=priorities,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -2347,6 +3248,7 @@ class TaggedUnionSchema(TypedDict, total=False):
     type: Required[Literal['tagged-union']]
     choices: Required[Dict[Hashable, CoreSchema]]
     discriminator: Required[Union[str, List[Union[str, int]], List[List[Union[str, int]]], Callable[[Any], Hashable]]]
+    otherwise: CoreSchema
     custom_error_type: str
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
@@ -2361,6 +3263,7 @@ def tagged_union_schema(
     choices: Dict[Hashable, CoreSchema],
     discriminator: str | list[str | int] | list[list[str | int]] | Callable[[Any], Hashable],
     *,
+    otherwise: CoreSchema | None = None,
     custom_error_type: str | None = None,
     custom_error_message: str | None = None,
     custom_error_context: dict[str, int | str | float] | None = None,
@@ -2416,6 +3319,8 @@ def tagged_union_schema(
             * If `discriminator` is a list of lists, each inner list is a path, and the first path that exists is used
             * If `discriminator` is a callable, it should return the discriminator when called on the value to validate;
               the callable can return `None` to indicate that there is no matching discriminator present on the input
+        otherwise: The schema to use when the discriminator value doesn't match any of `choices`, e.g. a permissive
+            schema for kinds of input added after this schema was written, by default unknown values are an error
         custom_error_type: The custom error type to use if the validation fails
         custom_error_message: The custom error message to use if the validation fails
         custom_error_context: The custom error context to use if the validation fails
@@ -2429,6 +3334,7 @@ def tagged_union_schema(
         type='tagged-union',
         choices=choices,
         discriminator=discriminator,
+        otherwise=otherwise,
         custom_error_type=custom_error_type,
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
@@ -2603,8 +3509,9 @@ class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
     required: bool
+    required_if: Union[Dict[str, Any], List[Dict[str, Any]]]
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
-    serialization_alias: str
+    serialization_alias: Union[str, List[str]]
     serialization_exclude: bool  # default: False
     metadata: Any
 
@@ -2613,8 +3520,9 @@ def typed_dict_field(
     schema: CoreSchema,
     *,
     required: bool | None = None,
+    required_if: dict[str, Any] | list[dict[str, Any]] | None = None,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
-    serialization_alias: str | None = None,
+    serialization_alias: str | list[str] | None = None,
     serialization_exclude: bool | None = None,
     metadata: Any = None,
 ) -> TypedDictField:
@@ -2630,8 +3538,10 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required
+        required_if: Other fields' values which make the field required when it isn't otherwise, e.g.
+            `{'kind': 'card'}`, or a list of these where any one of them matching makes it required
         validation_alias: The alias(es) to use to find the field in the validation data
-        serialization_alias: The alias to use as a key when serializing
+        serialization_alias: The alias to use as a key when serializing, or a list of keys to nest the value under
         serialization_exclude: Whether to exclude the field when serializing
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
@@ -2639,6 +3549,7 @@ def typed_dict_field(
         type='typed-dict-field',
         schema=schema,
         required=required,
+        required_if=required_if,
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
@@ -2652,6 +3563,10 @@ class TypedDictSchema(TypedDict, total=False):
     computed_fields: List[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extras_keys_schema: CoreSchema
+    extras_key_transform: ExtrasKeyTransform
+    closed: bool  # default: False
+    from_pairs: bool  # default: False
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
@@ -2668,6 +3583,10 @@ def typed_dict_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_keys_schema: CoreSchema | None = None,
+    extras_key_transform: ExtrasKeyTransform | None = None,
+    closed: bool | None = None,
+    from_pairs: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     populate_by_name: bool | None = None,
@@ -2694,6 +3613,13 @@ def typed_dict_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extras_keys_schema: The validator to use for the keys of extra fields, requires `extra_behavior='allow'`
+        extras_key_transform: How to transform the keys of extra fields before they're stored, either
+            `'snake_case'` or a function taking and returning a `str`, requires `extra_behavior='allow'`
+        closed: Whether the typed dict is closed (PEP 728), extra keys are then only allowed if `extras_schema`
+            is set (equivalent to `__extra_items__`), and `extra_behavior` can't be set
+        from_pairs: Whether to accept a dict items view or an iterable of `(key, value)` pairs in lax mode,
+            note that an iterator is consumed even if validation fails
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
@@ -2707,6 +3633,10 @@ def typed_dict_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extras_keys_schema=extras_keys_schema,
+        extras_key_transform=extras_key_transform,
+        closed=closed,
+        from_pairs=from_pairs,
         extra_behavior=extra_behavior,
         total=total,
         populate_by_name=populate_by_name,
@@ -2721,9 +3651,11 @@ class ModelField(TypedDict, total=False):
     type: Required[Literal['model-field']]
     schema: Required[CoreSchema]
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
-    serialization_alias: str
+    serialization_alias: Union[str, List[str]]
     serialization_exclude: bool  # default: False
     frozen: bool
+    flatten: bool
+    required_if: Union[Dict[str, Any], List[Dict[str, Any]]]
     metadata: Any
 
 
@@ -2731,9 +3663,11 @@ def model_field(
     schema: CoreSchema,
     *,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
-    serialization_alias: str | None = None,
+    serialization_alias: str | list[str] | None = None,
     serialization_exclude: bool | None = None,
     frozen: bool | None = None,
+    flatten: bool | None = None,
+    required_if: dict[str, Any] | list[dict[str, Any]] | None = None,
     metadata: Any = None,
 ) -> ModelField:
     """
@@ -2748,9 +3682,13 @@ def model_field(
     Args:
         schema: The schema to use for the field
         validation_alias: The alias(es) to use to find the field in the validation data
-        serialization_alias: The alias to use as a key when serializing
+        serialization_alias: The alias to use as a key when serializing, or a list of keys to nest the value under
         serialization_exclude: Whether to exclude the field when serializing
         frozen: Whether the field is frozen
+        flatten: Whether the field is validated from the parent's input itself rather than from a key,
+            and its serialized items are inlined into the parent's output, requires `extra_behavior='ignore'`
+        required_if: Other fields' values which make the field required even though it has a default, e.g.
+            `{'kind': 'card'}`, or a list of these where any one of them matching makes it required
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2760,6 +3698,8 @@ def model_field(
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
         frozen=frozen,
+        flatten=flatten,
+        required_if=required_if,
         metadata=metadata,
     )
 
@@ -2771,6 +3711,9 @@ class ModelFieldsSchema(TypedDict, total=False):
     computed_fields: List[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extras_keys_schema: CoreSchema
+    extras_key_transform: ExtrasKeyTransform
+    from_pairs: bool  # default: False
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
@@ -2787,6 +3730,9 @@ def model_fields_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_keys_schema: CoreSchema | None = None,
+    extras_key_transform: ExtrasKeyTransform | None = None,
+    from_pairs: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
     populate_by_name: bool | None = None,
     from_attributes: bool | None = None,
@@ -2814,11 +3760,18 @@ def model_fields_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extras_keys_schema: The validator to use for the keys of extra fields, which must return a `str`,
+            requires `extra_behavior='allow'`
+        extras_key_transform: How to transform the keys of extra fields before they're stored, either
+            `'snake_case'` or a function taking and returning a `str`, requires `extra_behavior='allow'`
+        from_pairs: Whether to accept a dict items view or an iterable of `(key, value)` pairs in lax mode,
+            note that an iterator is consumed even if validation fails, can't be combined with flattened fields
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
         populate_by_name: Whether the typed dict should populate by name
-        from_attributes: Whether the typed dict should be populated from attributes
+        from_attributes: Whether the typed dict should be populated from attributes, when serializing, objects
+            which aren't dicts are read via their attributes
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2828,6 +3781,9 @@ def model_fields_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extras_keys_schema=extras_keys_schema,
+        extras_key_transform=extras_key_transform,
+        from_pairs=from_pairs,
         extra_behavior=extra_behavior,
         populate_by_name=populate_by_name,
         from_attributes=from_attributes,
@@ -2837,6 +3793,98 @@ def model_fields_schema(
     )
 
 
+
+class ModelConstraint(TypedDict, total=False):
+    left: Required[str]
+    op: Required[Literal['lt', 'le', 'gt', 'ge', 'eq', 'ne']]
+    right: Required[str]
+    loc: Union[str, List[Union[str, int]]]
+
+
+def model_constraint(
+    left: str,
+    op: Literal['lt', 'le', 'gt', 'ge', 'eq', 'ne'],
+    right: str,
+    *,
+    loc: str | list[str | int] | None = None,
+) -> ModelConstraint:
+    """
+    Returns a comparison between two fields for use in `model_constraint_schema`, e.g.:
+
+    ```py
+    from pydantic_core import core_schema
+
+    constraint = core_schema.model_constraint('start', 'le', 'end', loc='end')
+    assert constraint == {'left': 'start', 'op': 'le', 'right': 'end', 'loc': 'end'}
+    ```
+
+    Args:
+        left: The name of the field on the left of the comparison
+        op: The comparison, `left <op> right` must be true
+        right: The name of the field on the right of the comparison
+        loc: The location of the error if the comparison is false, relative to the model, defaults to the
+            model itself
+    """
+    return _dict_not_none(left=left, op=op, right=right, loc=loc)
+
+
+class ModelConstraintSchema(TypedDict, total=False):
+    type: Required[Literal['model-constraint']]
+    schema: Required[CoreSchema]
+    constraints: Required[List[ModelConstraint]]
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def model_constraint_schema(
+    schema: CoreSchema,
+    constraints: list[ModelConstraint],
+    *,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> ModelConstraintSchema:
+    """
+    Returns a schema that checks comparisons between fields once `schema` has validated them, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.model_constraint_schema(
+        core_schema.typed_dict_schema(
+            {
+                'start': core_schema.typed_dict_field(core_schema.int_schema()),
+                'end': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        [core_schema.model_constraint('start', 'le', 'end')],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'start': 1, 'end': 2}) == {'start': 1, 'end': 2}
+    ```
+
+    The comparisons are evaluated natively, without calling a Python function, so they're much cheaper than a
+    model validator. `schema` is usually a `model-fields`, `typed-dict` or `model` schema, a comparison is skipped
+    if either field is missing or `None`, and every comparison which is false is reported as a
+    `model_constraint` error. The comparisons are also checked when a field is assigned with `validate_assignment`.
+
+    Args:
+        schema: The schema validating the fields
+        constraints: The comparisons between fields, see `model_constraint`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='model-constraint',
+        schema=schema,
+        constraints=constraints,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
 class ModelSchema(TypedDict, total=False):
     type: Required[Literal['model']]
     cls: Required[Type[Any]]
@@ -2943,7 +3991,7 @@ class DataclassField(TypedDict, total=False):
     init_only: bool  # default: False
     frozen: bool  # default: False
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
-    serialization_alias: str
+    serialization_alias: Union[str, List[str]]
     serialization_exclude: bool  # default: False
     metadata: Any
 
@@ -2955,7 +4003,7 @@ def dataclass_field(
     kw_only: bool | None = None,
     init_only: bool | None = None,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
-    serialization_alias: str | None = None,
+    serialization_alias: str | list[str] | None = None,
     serialization_exclude: bool | None = None,
     metadata: Any = None,
     frozen: bool | None = None,
@@ -2980,7 +4028,7 @@ def dataclass_field(
         kw_only: Whether the field can be set with a positional argument as well as a keyword argument
         init_only: Whether the field should be omitted  from `__dict__` and passed to `__post_init__`
         validation_alias: The alias(es) to use to find the field in the validation data
-        serialization_alias: The alias to use as a key when serializing
+        serialization_alias: The alias to use as a key when serializing, or a list of keys to nest the value under
         serialization_exclude: Whether to exclude the field when serializing
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
@@ -3180,7 +4228,10 @@ class ArgumentsSchema(TypedDict, total=False):
     arguments_schema: Required[List[ArgumentsParameter]]
     populate_by_name: bool
     var_args_schema: CoreSchema
+    var_args_name: str
     var_kwargs_schema: CoreSchema
+    mutually_exclusive: List[List[str]]
+    at_least_one_of: List[List[str]]
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -3191,7 +4242,10 @@ def arguments_schema(
     *,
     populate_by_name: bool | None = None,
     var_args_schema: CoreSchema | None = None,
+    var_args_name: str | None = None,
     var_kwargs_schema: CoreSchema | None = None,
+    mutually_exclusive: list[list[str]] | None = None,
+    at_least_one_of: list[list[str]] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -3217,7 +4271,14 @@ def arguments_schema(
         arguments: The arguments to use for the arguments schema
         populate_by_name: Whether to populate by name
         var_args_schema: The variable args schema to use for the arguments schema
+        var_args_name: If set, extra positional arguments validated by `var_args_schema` are returned as a tuple
+            under this name in the kwargs, rather than after the other positional arguments, e.g. `'args'` for
+            `def f(a, *args)` so the original call can be reconstructed. Requires `var_args_schema`
         var_kwargs_schema: The variable kwargs schema to use for the arguments schema
+        mutually_exclusive: Groups of parameter names of which at most one may be provided, if more are
+            a `mutually_exclusive_arguments` error is raised for each parameter provided
+        at_least_one_of: Groups of parameter names of which at least one must be provided, if none are
+            a `missing_one_of_arguments` error is raised for each parameter in the group
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -3227,7 +4288,10 @@ def arguments_schema(
         arguments_schema=arguments,
         populate_by_name=populate_by_name,
         var_args_schema=var_args_schema,
+        var_args_name=var_args_name,
         var_kwargs_schema=var_kwargs_schema,
+        mutually_exclusive=mutually_exclusive,
+        at_least_one_of=at_least_one_of,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3282,7 +4346,8 @@ def call_schema(
         arguments: The arguments to use for the arguments schema
         function: The function to use for the call schema
         function_name: The function name to use for the call schema, if not provided `function.__name__` is used
-        return_schema: The return schema to use for the call schema
+        return_schema: The return schema to use for the call schema, if `function` is a coroutine function
+            the result is an awaitable which validates the value returned when it's awaited
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -3359,6 +4424,8 @@ def custom_error_schema(
 class JsonSchema(TypedDict, total=False):
     type: Required[Literal['json']]
     schema: CoreSchema
+    max_length: int
+    max_depth: int
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -3367,6 +4434,8 @@ class JsonSchema(TypedDict, total=False):
 def json_schema(
     schema: CoreSchema | None = None,
     *,
+    max_length: int | None = None,
+    max_depth: int | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -3401,13 +4470,27 @@ def json_schema(
     assert isinstance(m, MyModel)
     ```
 
+    Errors in the JSON, and from validating the parsed value, are located at the value inside the document
+    they apply to, e.g. `('field_a', 'items', 2)`.
+
     Args:
         schema: The schema to use for the JSON schema
+        max_length: The JSON must be at most this many bytes, checked before it's parsed
+        max_depth: Arrays and objects in the JSON must be nested at most this many levels deep, checked before
+            the JSON is parsed
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='json', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='json',
+        schema=schema,
+        max_length=max_length,
+        max_depth=max_depth,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class UrlSchema(TypedDict, total=False):
@@ -3576,12 +4659,21 @@ def definitions_schema(schema: CoreSchema, definitions: list[CoreSchema]) -> Def
 class DefinitionReferenceSchema(TypedDict, total=False):
     type: Required[Literal['definition-ref']]
     schema_ref: Required[str]
+    params: Dict[str, CoreSchema]
+    strict: bool
+    from_attributes: bool
     metadata: Any
     serialization: SerSchema
 
 
 def definition_reference_schema(
-    schema_ref: str, metadata: Any = None, serialization: SerSchema | None = None
+    schema_ref: str,
+    *,
+    params: dict[str, CoreSchema] | None = None,
+    strict: bool | None = None,
+    from_attributes: bool | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> DefinitionReferenceSchema:
     """
     Returns a schema that points to a schema stored in "definitions", this is useful for nested recursive
@@ -3603,10 +4695,89 @@ def definition_reference_schema(
 
     Args:
         schema_ref: The schema ref to use for the definition reference schema
+        params: Schemas for the params when `schema_ref` refers to a
+            [`definition_template_schema`][pydantic_core.core_schema.definition_template_schema]
+        strict: Override strict mode when validating the definition through this reference, like the `strict`
+            argument to `validate_python`, the definition itself is shared with other references
+        from_attributes: Override `from_attributes` when validating the definition through this reference
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='definition-ref', schema_ref=schema_ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='definition-ref',
+        schema_ref=schema_ref,
+        params=params,
+        strict=strict,
+        from_attributes=from_attributes,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DefinitionTemplateSchema(TypedDict, total=False):
+    type: Required[Literal['definition-template']]
+    ref: Required[str]
+    params: Required[List[str]]
+    schema: Required[CoreSchema]
+    metadata: Any
+
+
+def definition_template_schema(
+    ref: str, params: list[str], schema: CoreSchema, *, metadata: Any = None
+) -> DefinitionTemplateSchema:
+    """
+    Returns a parameterized definition for use in `definitions_schema`, `schema` uses
+    `definition_param_schema` placeholders for the params, which are replaced by the `params` of each
+    `definition_reference_schema` to it. References with the same params share one definition, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.definitions_schema(
+        core_schema.tuple_positional_schema(
+            [
+                core_schema.definition_reference_schema('Box', params={'T': core_schema.int_schema()}),
+                core_schema.definition_reference_schema('Box', params={'T': core_schema.str_schema()}),
+            ]
+        ),
+        [
+            core_schema.definition_template_schema(
+                'Box',
+                ['T'],
+                core_schema.list_schema(core_schema.definition_param_schema('T')),
+            ),
+        ],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python((['1'], ['a'])) == ([1], ['a'])
+    ```
+
+    Args:
+        ref: The ref used by `definition_reference_schema` to refer to the template
+        params: The names of the params
+        schema: The schema of the definition, using `definition_param_schema` for the params
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+    """
+    return _dict_not_none(type='definition-template', ref=ref, params=params, schema=schema, metadata=metadata)
+
+
+class DefinitionParamSchema(TypedDict, total=False):
+    type: Required[Literal['definition-param']]
+    name: Required[str]
+    metadata: Any
+
+
+def definition_param_schema(name: str, *, metadata: Any = None) -> DefinitionParamSchema:
+    """
+    Returns a placeholder for a param of a
+    [`definition_template_schema`][pydantic_core.core_schema.definition_template_schema], it can only be used
+    within the template's schema.
+
+    Args:
+        name: The name of the param
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+    """
+    return _dict_not_none(type='definition-param', name=name, metadata=metadata)
 
 
 MYPY = False
@@ -3618,6 +4789,9 @@ if not MYPY:
         NoneSchema,
         BoolSchema,
         IntSchema,
+        Int64Schema,
+        UInt64Schema,
+        IntFlagSchema,
         FloatSchema,
         DecimalSchema,
         StringSchema,
@@ -3627,6 +4801,8 @@ if not MYPY:
         DatetimeSchema,
         TimedeltaSchema,
         LiteralSchema,
+        ConstSchema,
+        EnumSchema,
         IsInstanceSchema,
         IsSubclassSchema,
         CallableSchema,
@@ -3643,6 +4819,7 @@ if not MYPY:
         PlainValidatorFunctionSchema,
         WithDefaultSchema,
         NullableSchema,
+        SecretSchema,
         UnionSchema,
         TaggedUnionSchema,
         ChainSchema,
@@ -3650,6 +4827,7 @@ if not MYPY:
         JsonOrPythonSchema,
         TypedDictSchema,
         ModelFieldsSchema,
+        ModelConstraintSchema,
         ModelSchema,
         DataclassArgsSchema,
         DataclassSchema,
@@ -3661,7 +4839,15 @@ if not MYPY:
         MultiHostUrlSchema,
         DefinitionsSchema,
         DefinitionReferenceSchema,
+        DefinitionTemplateSchema,
+        DefinitionParamSchema,
         UuidSchema,
+        EmailSchema,
+        NameEmailSchema,
+        IpV4Schema,
+        IpV6Schema,
+        IpAnySchema,
+        IpNetworkSchema,
     ]
 elif False:
     CoreSchema: TypeAlias = Mapping[str, Any]
@@ -3673,6 +4859,9 @@ CoreSchemaType = Literal[
     'none',
     'bool',
     'int',
+    'int64',
+    'uint64',
+    'int-flag',
     'float',
     'decimal',
     'str',
@@ -3682,6 +4871,8 @@ CoreSchemaType = Literal[
     'datetime',
     'timedelta',
     'literal',
+    'const',
+    'enum',
     'is-instance',
     'is-subclass',
     'callable',
@@ -3698,6 +4889,7 @@ CoreSchemaType = Literal[
     'function-plain',
     'default',
     'nullable',
+    'secret',
     'union',
     'tagged-union',
     'chain',
@@ -3705,6 +4897,7 @@ CoreSchemaType = Literal[
     'json-or-python',
     'typed-dict',
     'model-fields',
+    'model-constraint',
     'model',
     'dataclass-args',
     'dataclass',
@@ -3716,7 +4909,15 @@ CoreSchemaType = Literal[
     'multi-host-url',
     'definitions',
     'definition-ref',
+    'definition-template',
+    'definition-param',
     'uuid',
+    'email',
+    'name-email',
+    'ip-v4',
+    'ip-v6',
+    'ip-any',
+    'ip-network',
 ]
 
 CoreSchemaFieldType = Literal['model-field', 'dataclass-field', 'typed-dict-field', 'computed-field']
@@ -3728,15 +4929,21 @@ ErrorType = Literal[
     'no_such_attribute',
     'json_invalid',
     'json_type',
+    'json_too_long',
+    'json_too_deep',
     'recursion_loop',
+    'validation_timeout',
     'missing',
     'frozen_field',
     'frozen_instance',
     'extra_forbidden',
+    'closed_typed_dict_extra',
     'invalid_key',
+    'extra_key_collision',
     'get_attribute_error',
     'model_type',
     'model_attributes_type',
+    'model_constraint',
     'dataclass_type',
     'dataclass_exact_type',
     'none_required',
@@ -3746,17 +4953,23 @@ ErrorType = Literal[
     'less_than_equal',
     'multiple_of',
     'finite_number',
+    'int_out_of_range',
     'too_short',
     'too_long',
     'iterable_type',
     'iteration_error',
+    'duplicate_item',
     'string_type',
     'string_sub_type',
     'string_unicode',
     'string_too_short',
     'string_too_long',
+    'string_units_too_short',
+    'string_units_too_long',
     'string_pattern_mismatch',
+    'string_e164_format',
     'enum',
+    'enum_close_match',
     'dict_type',
     'mapping_type',
     'list_type',
@@ -3768,11 +4981,17 @@ ErrorType = Literal[
     'int_parsing',
     'int_parsing_size',
     'int_from_float',
+    'int_max_digits',
+    'int_flag_type',
+    'int_flag_name',
+    'int_flag_unknown_bits',
     'float_type',
     'float_parsing',
+    'float_from_int',
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
+    'bytes_invalid_encoding',
     'value_error',
     'assertion_error',
     'literal_error',
@@ -3807,6 +5026,8 @@ ErrorType = Literal[
     'unexpected_positional_argument',
     'missing_positional_only_argument',
     'multiple_argument_values',
+    'mutually_exclusive_arguments',
+    'missing_one_of_arguments',
     'url_type',
     'url_parsing',
     'url_syntax_violation',
@@ -3815,6 +5036,10 @@ ErrorType = Literal[
     'uuid_type',
     'uuid_parsing',
     'uuid_version',
+    'uuid_format',
+    'email_parsing',
+    'ip_address_parsing',
+    'ip_network_parsing',
     'decimal_type',
     'decimal_parsing',
     'decimal_max_digits',
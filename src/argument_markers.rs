@@ -1,8 +1,9 @@
 use pyo3::basic::CompareOp;
-use pyo3::exceptions::PyNotImplementedError;
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyNotImplementedError, PyTypeError};
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyTuple};
+use pyo3::types::{PyDict, PyIterator, PyList, PyString, PyTuple};
 
 use crate::tools::safe_repr;
 
@@ -40,6 +41,72 @@ impl ArgsKwargs {
         }
     }
 
+    /// From `inspect.Signature.bind(...)` or `bind_partial(...)`
+    #[staticmethod]
+    fn from_bound_arguments(bound_arguments: &PyAny) -> PyResult<Self> {
+        let py = bound_arguments.py();
+        let args = bound_arguments.getattr(intern!(py, "args"))?.downcast()?;
+        let kwargs = bound_arguments.getattr(intern!(py, "kwargs"))?.downcast()?;
+        Ok(Self::py_new(py, args, Some(kwargs)))
+    }
+
+    /// `(args, kwargs)` to call a function with, e.g. `f(*args, **kwargs)`, `kwargs` is always a new dict
+    fn as_call_args(&self, py: Python) -> PyResult<(Py<PyTuple>, Py<PyDict>)> {
+        let kwargs = match self.kwargs {
+            Some(ref kwargs) => kwargs.as_ref(py).copy()?,
+            None => PyDict::new(py),
+        };
+        Ok((self.args.clone_ref(py), kwargs.into()))
+    }
+
+    /// The number of positional and keyword arguments
+    fn __len__(&self, py: Python) -> usize {
+        self.args.as_ref(py).len() + self.kwargs.as_ref().map_or(0, |kwargs| kwargs.as_ref(py).len())
+    }
+
+    /// The indexes of positional arguments then the names of keyword arguments, the keys for `__getitem__`
+    fn __iter__(&self, py: Python) -> PyResult<Py<PyIterator>> {
+        let keys = PyList::new(py, 0..self.args.as_ref(py).len());
+        if let Some(ref kwargs) = self.kwargs {
+            for key in kwargs.as_ref(py).keys() {
+                keys.append(key)?;
+            }
+        }
+        Ok(PyIterator::from_object(keys)?.into())
+    }
+
+    /// Always true, even without any arguments, `__len__` doesn't make an empty `ArgsKwargs` falsy
+    fn __bool__(&self) -> bool {
+        true
+    }
+
+    /// Positional arguments by index, keyword arguments by name
+    fn __getitem__(&self, py: Python, key: &PyAny) -> PyResult<PyObject> {
+        if let Ok(name) = key.downcast::<PyString>() {
+            let value = match self.kwargs {
+                Some(ref kwargs) => kwargs.as_ref(py).get_item(name)?,
+                None => None,
+            };
+            value
+                .map(|value| value.into_py(py))
+                .ok_or_else(|| PyKeyError::new_err(name.to_object(py)))
+        } else if let Ok(index) = key.extract::<isize>() {
+            let args = self.args.as_ref(py);
+            let len = args.len() as isize;
+            let position = if index < 0 { index + len } else { index };
+            if (0..len).contains(&position) {
+                Ok(args.get_item(position as usize)?.into_py(py))
+            } else {
+                Err(PyIndexError::new_err("ArgsKwargs positional index out of range"))
+            }
+        } else {
+            Err(PyTypeError::new_err(format!(
+                "ArgsKwargs indices must be integers or strings, not {}",
+                key.get_type().name()?
+            )))
+        }
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
         match op {
             CompareOp::Eq => match self.eq(py, other) {
@@ -108,3 +175,55 @@ impl PydanticUndefinedType {
         Python::with_gil(PydanticUndefinedType::new)
     }
 }
+
+static UNSET_CELL: GILOnceCell<Py<UnsetType>> = GILOnceCell::new();
+
+/// The type of `UNSET`, a value for fields which weren't provided, distinct from `None`, fields with the
+/// value `UNSET` are left out of `__pydantic_fields_set__` when validating and out of the output when serializing
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct UnsetType {}
+
+#[pymethods]
+impl UnsetType {
+    #[new]
+    pub fn py_new(_py: Python) -> PyResult<Self> {
+        Err(PyNotImplementedError::new_err(
+            "Creating instances of \"UnsetType\" is not supported",
+        ))
+    }
+
+    #[staticmethod]
+    pub fn new(py: Python) -> Py<Self> {
+        UNSET_CELL
+            .get_or_init(py, || UnsetType {}.into_py(py).extract(py).unwrap())
+            .clone()
+    }
+
+    fn __repr__(&self) -> &'static str {
+        "UNSET"
+    }
+
+    fn __bool__(&self) -> bool {
+        false
+    }
+
+    fn __copy__(&self, py: Python) -> Py<Self> {
+        UNSET_CELL.get(py).unwrap().clone()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    fn __deepcopy__(&self, py: Python, _memo: &PyAny) -> Py<Self> {
+        self.__copy__(py)
+    }
+
+    fn __reduce__(&self) -> &'static str {
+        "UNSET"
+    }
+}
+
+impl UnsetType {
+    pub fn is_unset(value: &PyAny) -> bool {
+        UNSET_CELL.get(value.py()).map_or(false, |unset| value.is(unset))
+    }
+}
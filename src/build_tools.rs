@@ -1,13 +1,15 @@
 use std::error::Error;
 use std::fmt;
+use std::sync::OnceLock;
 
 use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
-use pyo3::{intern, FromPyObject, PyErrArguments};
+use pyo3::{intern, FromPyObject, PyErrArguments, PyTraverseError, PyVisit};
 
 use crate::errors::ValError;
 use crate::input::InputType;
+use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
 
@@ -45,6 +47,59 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
     Ok(schema_or_config_same(schema, config, intern!(py, "strict"))?.unwrap_or(false))
 }
 
+static DETERMINISTIC_ENV_VAR: OnceLock<bool> = OnceLock::new();
+
+/// Whether output which would otherwise depend on hash seeds, e.g. the order of set items, should be made stable
+/// across runs, from the `deterministic` config or globally via the `PYDANTIC_CORE_DETERMINISTIC` environment variable
+pub fn is_deterministic(config: Option<&PyDict>) -> PyResult<bool> {
+    if let Some(config) = config {
+        if let Some(deterministic) = config.get_as(intern!(config.py(), "deterministic"))? {
+            return Ok(deterministic);
+        }
+    }
+    Ok(*DETERMINISTIC_ENV_VAR.get_or_init(|| {
+        std::env::var("PYDANTIC_CORE_DETERMINISTIC").map_or(false, |v| matches!(v.as_str(), "1" | "true"))
+    }))
+}
+
+/// Config options whose values are dicts which are merged key by key, rather than replaced, when merging configs
+const MERGED_CONFIG_KEYS: [&str; 3] = [
+    "custom_error_messages",
+    "ser_json_any_dispatch",
+    "error_message_translator",
+];
+
+/// Merge a parent config with a child config, e.g. for model inheritance: options set on the child take
+/// precedence, except options in `MERGED_CONFIG_KEYS` which are merged if they're dicts on both
+#[pyfunction(signature = (parent, child))]
+pub fn merge_config<'py>(
+    py: Python<'py>,
+    parent: Option<&'py PyDict>,
+    child: Option<&'py PyDict>,
+) -> PyResult<&'py PyDict> {
+    let merged = match parent {
+        Some(parent) => parent.copy()?,
+        None => PyDict::new(py),
+    };
+    let Some(child) = child else {
+        return Ok(merged);
+    };
+    for (key, value) in child {
+        let key_str: &str = key.extract()?;
+        if MERGED_CONFIG_KEYS.contains(&key_str) {
+            let parent_value = merged.get_item(key)?.and_then(|v| v.downcast::<PyDict>().ok());
+            if let (Some(parent_value), Ok(child_value)) = (parent_value, value.downcast::<PyDict>()) {
+                let merged_value = parent_value.copy()?;
+                merged_value.update(child_value.as_mapping())?;
+                merged.set_item(key, merged_value)?;
+                continue;
+            }
+        }
+        merged.set_item(key, value)?;
+    }
+    Ok(merged)
+}
+
 enum SchemaErrorEnum {
     Message(String),
     ValidationError(ValidationError),
@@ -97,6 +152,7 @@ impl SchemaError {
             ValError::InternalErr(err) => err,
             ValError::Omit => Self::new_err("Unexpected Omit error."),
             ValError::UseDefault => Self::new_err("Unexpected UseDefault error."),
+            ValError::Timeout(_) => Self::new_err("Unexpected Timeout error."),
         }
     }
 
@@ -125,7 +181,7 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty(py).into_py(py)),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true),
+            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true, None),
         }
     }
 
@@ -164,6 +220,25 @@ macro_rules! py_schema_err {
 }
 pub(crate) use py_schema_err;
 
+/// Alias of a field without an explicit alias, as computed by the `alias_generator` from config, if it's set
+pub fn generate_alias<'py>(
+    py: Python<'py>,
+    config: Option<&'py PyDict>,
+    field_name: &str,
+) -> PyResult<Option<&'py PyString>> {
+    let alias_generator = match config {
+        Some(config) => config.get_item(intern!(py, "alias_generator"))?,
+        None => None,
+    };
+    let Some(alias_generator) = alias_generator else {
+        return Ok(None);
+    };
+    match alias_generator.call1((field_name,))?.downcast::<PyString>() {
+        Ok(alias) => Ok(Some(alias)),
+        Err(_) => py_schema_err!("alias_generator must return a str for field \"{}\"", field_name),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum ExtraBehavior {
     Allow,
@@ -194,4 +269,90 @@ impl ExtraBehavior {
         };
         Ok(res)
     }
+
+    /// Like `from_schema_or_config`, but for closed typed dicts (PEP 728) the behavior is implied:
+    /// extra keys are allowed if `extras_schema` is set, and forbidden otherwise
+    pub fn from_typed_dict_schema(py: Python, schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        if !schema.get_as(intern!(py, "closed"))?.unwrap_or(false) {
+            return Self::from_schema_or_config(py, schema, config, Self::Ignore);
+        }
+        if schema.contains(intern!(py, "extra_behavior"))? {
+            return py_schema_err!("extra_behavior can't be set on a closed typed dict");
+        }
+        if schema.contains(intern!(py, "extras_schema"))? {
+            Ok(Self::Allow)
+        } else {
+            Ok(Self::Forbid)
+        }
+    }
+}
+
+/// How the keys of extra fields are transformed before they're stored, when `extra_behavior='allow'`
+#[derive(Debug, Clone)]
+pub(crate) enum ExtrasKeyTransform {
+    SnakeCase,
+    Function(PyObject),
+}
+
+impl ExtrasKeyTransform {
+    pub fn from_schema(py: Python, schema: &PyDict, extra_behavior: &ExtraBehavior) -> PyResult<Option<Self>> {
+        let Some(transform) = schema.get_item(intern!(py, "extras_key_transform"))? else {
+            return Ok(None);
+        };
+        if !matches!(extra_behavior, ExtraBehavior::Allow) {
+            return py_schema_err!("extras_key_transform can only be used if extra_behavior=allow");
+        }
+        if let Ok(rule) = transform.extract::<&str>() {
+            match rule {
+                "snake_case" => Ok(Some(Self::SnakeCase)),
+                _ => py_schema_err!("Invalid extras_key_transform: `{}`, expected `snake_case`", rule),
+            }
+        } else if transform.is_callable() {
+            Ok(Some(Self::Function(transform.into())))
+        } else {
+            py_schema_err!("extras_key_transform must be `'snake_case'` or a callable")
+        }
+    }
+
+    pub fn apply<'py>(&self, key: &'py PyString) -> PyResult<&'py PyString> {
+        let py = key.py();
+        match self {
+            Self::SnakeCase => Ok(PyString::new(py, &to_snake_case(key.to_str()?))),
+            Self::Function(function) => Ok(function.call1(py, (key,))?.into_ref(py).downcast()?),
+        }
+    }
+}
+
+impl PyGcTraverse for ExtrasKeyTransform {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Self::Function(function) = self {
+            visit.call(function)?;
+        }
+        Ok(())
+    }
+}
+
+/// e.g. `fooBar`, `FooBar` and `foo-bar` all become `foo_bar`, and `HTTPServer` becomes `http_server`
+fn to_snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut snake = String::with_capacity(s.len() + 4);
+    for (index, &c) in chars.iter().enumerate() {
+        if c == '-' || c == ' ' {
+            snake.push('_');
+        } else if c.is_uppercase() {
+            let word_start = match index.checked_sub(1).map(|i| chars[i]) {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                // the last capital of an acronym starts a new word, e.g. the `S` in `HTTPServer`
+                Some(prev) if prev.is_uppercase() => chars.get(index + 1).map_or(false, |next| next.is_lowercase()),
+                _ => false,
+            };
+            if word_start && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
 }
@@ -0,0 +1,106 @@
+//! A small C API, exported as a `PyCapsule` named `pydantic_core._pydantic_core._C_API`, so that other
+//! extensions can run a `SchemaValidator` without going through Python-level method calls.
+//!
+//! Consumers should use `PyCapsule_Import("pydantic_core._pydantic_core._C_API", 0)` and cast the result to a
+//! pointer to a struct with the same layout as `PydanticCoreCApi`, checking `api_version` before use.
+//! All functions must be called with the GIL held.
+use std::any::Any;
+use std::ffi::{c_char, c_int, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::ffi;
+use pyo3::panic::PanicException;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+
+use crate::validators::SchemaValidator;
+
+const CAPSULE_NAME: &str = "pydantic_core._pydantic_core._C_API";
+
+/// Incremented whenever the layout of `PydanticCoreCApi` changes
+const CAPI_VERSION: c_int = 1;
+
+#[repr(C)]
+pub struct PydanticCoreCApi {
+    pub api_version: c_int,
+    /// `validate_json(validator, data, length, strict)`
+    ///
+    /// `validator` must be a `SchemaValidator` instance, `strict` is `-1` to use the schema's setting,
+    /// `0` for lax mode and `1` for strict mode.
+    /// The GIL may be released while parsing, so `data` must not be modified by other threads during the call.
+    /// Returns a new reference to the validated value, or `NULL` with an exception set on failure, including
+    /// when `validator` or `data` is `NULL`, `length` is negative, or validation panics.
+    pub validate_json:
+        unsafe extern "C" fn(*mut ffi::PyObject, *const c_char, ffi::Py_ssize_t, c_int) -> *mut ffi::PyObject,
+}
+
+unsafe extern "C" fn validate_json(
+    validator: *mut ffi::PyObject,
+    data: *const c_char,
+    length: ffi::Py_ssize_t,
+    strict: c_int,
+) -> *mut ffi::PyObject {
+    // the caller holds the GIL, but `with_gil` also gives us a pool so temporary references are released on return
+    Python::with_gil(|py| {
+        // unwinding across an `extern "C"` boundary is undefined behaviour, so panics become a `PanicException`
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            validate_json_impl(py, validator, data, length, strict)
+        }))
+        .unwrap_or_else(|payload| Err(panic_err(payload)));
+        match result {
+            Ok(value) => value.into_ptr(),
+            Err(err) => {
+                err.restore(py);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+unsafe fn validate_json_impl(
+    py: Python,
+    validator: *mut ffi::PyObject,
+    data: *const c_char,
+    length: ffi::Py_ssize_t,
+    strict: c_int,
+) -> PyResult<PyObject> {
+    if validator.is_null() || data.is_null() {
+        return Err(PyValueError::new_err(
+            "validate_json: `validator` and `data` must not be NULL",
+        ));
+    }
+    let Ok(length) = usize::try_from(length) else {
+        return Err(PyValueError::new_err("validate_json: `length` must not be negative"));
+    };
+    let strict = match strict {
+        s if s < 0 => None,
+        0 => Some(false),
+        _ => Some(true),
+    };
+    let validator = py
+        .from_borrowed_ptr::<PyAny>(validator)
+        .downcast::<PyCell<SchemaValidator>>()?;
+    let json_data = std::slice::from_raw_parts(data.cast::<u8>(), length);
+    validator.get().validate_json_buffer(py, json_data, strict)
+}
+
+fn panic_err(payload: Box<dyn Any + Send>) -> PyErr {
+    let msg = if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else if let Some(msg) = payload.downcast_ref::<&str>() {
+        (*msg).to_string()
+    } else {
+        "panic from Rust code".to_string()
+    };
+    PanicException::new_err(msg)
+}
+
+pub fn capi_capsule(py: Python) -> PyResult<&PyCapsule> {
+    let api = PydanticCoreCApi {
+        api_version: CAPI_VERSION,
+        validate_json,
+    };
+    PyCapsule::new(py, api, Some(CString::new(CAPSULE_NAME)?))
+}
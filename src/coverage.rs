@@ -0,0 +1,52 @@
+//! Opt-in counters recording how often parts of a schema are exercised during validation, e.g. which union
+//! branches are chosen and which fields are present in the input.
+//! Enabled with the `coverage_stats` config option and read via `SchemaValidator.coverage_stats()`.
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+#[derive(Debug, Default)]
+pub struct CoverageStats {
+    counters: Mutex<Vec<(String, CoverageCounter)>>,
+}
+
+impl CoverageStats {
+    /// Register a new counter for `path`, counters with the same path are summed when reported
+    pub fn counter(&self, path: String) -> CoverageCounter {
+        let counter = CoverageCounter::default();
+        self.counters
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((path, counter.clone()));
+        counter
+    }
+
+    pub fn to_dict<'py>(&self, py: Python<'py>, reset: bool) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        let counters = self.counters.lock().unwrap_or_else(PoisonError::into_inner);
+        for (path, counter) in counters.iter() {
+            let count = match reset {
+                true => counter.0.swap(0, Ordering::Relaxed),
+                false => counter.0.load(Ordering::Relaxed),
+            };
+            let total = match dict.get_item(path)? {
+                Some(existing) => existing.extract::<u64>()? + count,
+                None => count,
+            };
+            dict.set_item(path, total)?;
+        }
+        Ok(dict)
+    }
+}
+
+/// A single counter, shared between clones of the validator which owns it
+#[derive(Debug, Clone, Default)]
+pub struct CoverageCounter(Arc<AtomicU64>);
+
+impl CoverageCounter {
+    pub fn increment(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -12,11 +12,17 @@ use std::{
     },
 };
 
-use pyo3::{prelude::*, PyTraverseError, PyVisit};
+use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::{intern, prelude::*, PyTraverseError, PyVisit};
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 
-use crate::{build_tools::py_schema_err, py_gc::PyGcTraverse};
+use crate::{
+    build_tools::py_schema_err,
+    coverage::{CoverageCounter, CoverageStats},
+    py_gc::PyGcTraverse,
+    tools::SchemaDict,
+};
 
 /// Definitions are validators and serializers that are
 /// shared by reference.
@@ -134,15 +140,33 @@ impl<T: PyGcTraverse> PyGcTraverse for Definitions<T> {
 #[derive(Clone, Debug)]
 pub struct DefinitionsBuilder<T> {
     definitions: Definitions<T>,
+    coverage_stats: Option<Arc<CoverageStats>>,
+    // definitions which validating each definition always requires, see `set_required_refs`
+    required_refs: AHashMap<String, Vec<String>>,
+    // definitions which validating the root schema always requires, see `set_root_required_refs`
+    root_required_refs: Vec<String>,
 }
 
 impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
     pub fn new() -> Self {
         Self {
             definitions: Definitions(AHashMap::new()),
+            coverage_stats: None,
+            required_refs: AHashMap::new(),
+            root_required_refs: Vec::new(),
         }
     }
 
+    /// Record coverage stats for everything built with this builder from now on
+    pub fn enable_coverage_stats(&mut self) -> Arc<CoverageStats> {
+        self.coverage_stats.get_or_insert_with(Default::default).clone()
+    }
+
+    /// Register a coverage counter, `None` unless coverage stats are enabled
+    pub fn coverage_counter(&self, path: impl FnOnce() -> String) -> Option<CoverageCounter> {
+        self.coverage_stats.as_ref().map(|stats| stats.counter(path()))
+    }
+
     /// Get a ReferenceId for the given reference string.
     pub fn get_definition(&mut self, reference: &str) -> DefinitionRef<T> {
         // We either need a String copy or two hashmap lookups
@@ -183,6 +207,18 @@ impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
         Ok(DefinitionRef { name, value })
     }
 
+    /// Record the definitions which validating `reference` always requires whatever the input, e.g. through
+    /// required fields
+    pub fn set_required_refs(&mut self, reference: &str, required_refs: AHashSet<String>) {
+        self.required_refs.insert(reference.to_string(), sorted(required_refs));
+    }
+
+    /// Record the definitions which validating the root schema always requires, a cycle of definitions reachable
+    /// from them means the schema could only ever fail with a recursion error, so it's rejected by `finish`
+    pub fn set_root_required_refs(&mut self, required_refs: AHashSet<String>) {
+        self.root_required_refs = sorted(required_refs);
+    }
+
     /// Consume this Definitions into a vector of items, indexed by each items ReferenceId
     pub fn finish(self) -> PyResult<Definitions<T>> {
         for (reference, def) in &self.definitions.0 {
@@ -190,8 +226,55 @@ impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
                 return py_schema_err!("Definitions error: definition `{}` was never filled", reference);
             }
         }
+        if let Some(cycle) = self.required_cycle() {
+            return py_schema_err!(
+                "Definitions error: definition `{}` can never be validated, it always requires itself: {}",
+                cycle[0],
+                cycle.join(" -> ")
+            );
+        }
         Ok(self.definitions)
     }
+
+    /// Find a cycle of definitions which each always require the next, starting from those the root schema
+    /// always requires, checked in order of reference so the error is deterministic
+    fn required_cycle(&self) -> Option<Vec<&str>> {
+        let mut checked = AHashSet::new();
+        self.root_required_refs
+            .iter()
+            .find_map(|reference| self.find_required_cycle(reference, &mut Vec::new(), &mut checked))
+    }
+
+    fn find_required_cycle<'s>(
+        &'s self,
+        reference: &'s str,
+        path: &mut Vec<&'s str>,
+        checked: &mut AHashSet<&'s str>,
+    ) -> Option<Vec<&'s str>> {
+        if let Some(start) = path.iter().position(|r| *r == reference) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(reference);
+            return Some(cycle);
+        }
+        if checked.contains(reference) {
+            return None;
+        }
+        path.push(reference);
+        for required in self.required_refs.get(reference).into_iter().flatten() {
+            if let Some(cycle) = self.find_required_cycle(required, path, checked) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        checked.insert(reference);
+        None
+    }
+}
+
+fn sorted(refs: AHashSet<String>) -> Vec<String> {
+    let mut refs: Vec<String> = refs.into_iter().collect();
+    refs.sort_unstable();
+    refs
 }
 
 struct LazyName {
@@ -240,3 +323,169 @@ impl Clone for LazyName {
         }
     }
 }
+
+/// How deeply instantiating one parameterized definition can require instantiating another, this stops
+/// definitions like `Node[T] -> Node[list[T]]` from being expanded forever
+const MAX_TEMPLATE_DEPTH: usize = 100;
+
+/// Expand the parameterized definitions in a `definitions` schema.
+/// A `definition-template` declares `params` which are used in its schema as `definition-param` placeholders,
+/// each `definition-ref` to it gives concrete schemas for the params. Every distinct set of params gets a plain
+/// definition with the placeholders replaced, which all references with those params share. If there are no
+/// templates, `definitions` and `schema` are returned unchanged.
+pub fn expand_definition_templates<'py>(
+    definitions: &'py PyList,
+    schema: &'py PyAny,
+) -> PyResult<(&'py PyList, &'py PyAny)> {
+    let py = definitions.py();
+    let mut templates = AHashMap::new();
+    let mut plain_definitions = Vec::with_capacity(definitions.len());
+    for definition in definitions {
+        let definition_dict: &PyDict = definition.downcast()?;
+        if definition_dict.get_as::<&str>(intern!(py, "type"))? == Some("definition-template") {
+            let reference: String = definition_dict.get_as_req(intern!(py, "ref"))?;
+            templates.insert(reference, definition_dict);
+        } else {
+            plain_definitions.push(definition);
+        }
+    }
+    if templates.is_empty() {
+        return Ok((definitions, schema));
+    }
+
+    let mut expander = TemplateExpander {
+        templates,
+        instances: AHashSet::new(),
+        definitions: Vec::with_capacity(plain_definitions.len()),
+        depth: 0,
+    };
+    let schema = expander.walk(schema, None)?;
+    for definition in plain_definitions {
+        let definition = expander.walk(definition, None)?;
+        expander.definitions.push(definition);
+    }
+    Ok((PyList::new(py, expander.definitions), schema))
+}
+
+type TemplateParams<'py> = AHashMap<String, &'py PyAny>;
+
+struct TemplateExpander<'py> {
+    templates: AHashMap<String, &'py PyDict>,
+    // references of the instances already created
+    instances: AHashSet<String>,
+    definitions: Vec<&'py PyAny>,
+    depth: usize,
+}
+
+impl<'py> TemplateExpander<'py> {
+    /// Copy `value` with placeholders replaced by `params` and references to templates replaced by references
+    /// to their instances, creating the instances as needed
+    fn walk(&mut self, value: &'py PyAny, params: Option<&TemplateParams<'py>>) -> PyResult<&'py PyAny> {
+        let py = value.py();
+        if let Ok(list) = value.downcast::<PyList>() {
+            let items = list
+                .iter()
+                .map(|item| self.walk(item, params))
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(PyList::new(py, items).into());
+        }
+        if let Ok(tuple) = value.downcast::<PyTuple>() {
+            let items = tuple
+                .iter()
+                .map(|item| self.walk(item, params))
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(PyTuple::new(py, items).into());
+        }
+        let Ok(dict) = value.downcast::<PyDict>() else {
+            return Ok(value);
+        };
+
+        // not every dict is a schema, e.g. `fields` might have a field called "type"
+        let schema_type = match dict.get_item(intern!(py, "type"))? {
+            Some(schema_type) => schema_type.extract::<&str>().ok(),
+            None => None,
+        };
+        match schema_type {
+            Some("definition-param") => {
+                let name: &str = dict.get_as_req(intern!(py, "name"))?;
+                return match params.and_then(|params| params.get(name)) {
+                    Some(param) => Ok(*param),
+                    None => py_schema_err!("Unknown definition param `{}`", name),
+                };
+            }
+            Some("definition-ref") => {
+                if let Some(ref_params) = dict.get_as::<&PyDict>(intern!(py, "params"))? {
+                    let schema_ref: String = dict.get_as_req(intern!(py, "schema_ref"))?;
+                    let instance_ref = self.instantiate(&schema_ref, ref_params, params)?;
+                    let instance_dict = dict.copy()?;
+                    instance_dict.set_item(intern!(py, "schema_ref"), instance_ref)?;
+                    instance_dict.del_item(intern!(py, "params"))?;
+                    return Ok(instance_dict.into());
+                }
+            }
+            _ => (),
+        }
+
+        let new_dict = PyDict::new(py);
+        for (key, value) in dict {
+            // metadata isn't part of the schema, so is copied unchanged
+            if key.eq(intern!(py, "metadata"))? {
+                new_dict.set_item(key, value)?;
+            } else {
+                new_dict.set_item(key, self.walk(value, params)?)?;
+            }
+        }
+        Ok(new_dict.into())
+    }
+
+    /// Get the reference of the instance of the template `schema_ref` with `ref_params`, creating it if needed
+    fn instantiate(
+        &mut self,
+        schema_ref: &str,
+        ref_params: &'py PyDict,
+        params: Option<&TemplateParams<'py>>,
+    ) -> PyResult<String> {
+        let py = ref_params.py();
+        let Some(template) = self.templates.get(schema_ref).copied() else {
+            return py_schema_err!(
+                "Definition `{}` is not a definition template, it can't have params",
+                schema_ref
+            );
+        };
+        let names: Vec<String> = template.get_as_req(intern!(py, "params"))?;
+        if ref_params.len() != names.len() {
+            return py_schema_err!(
+                "Definition template `{}` expects params {:?}, got {} params",
+                schema_ref,
+                names,
+                ref_params.len()
+            );
+        }
+
+        let mut instance_params = TemplateParams::with_capacity(names.len());
+        let mut param_reprs = Vec::with_capacity(names.len());
+        for name in names {
+            let Some(param) = ref_params.get_item(&name)? else {
+                return py_schema_err!("Definition template `{}` is missing param `{}`", schema_ref, name);
+            };
+            let param = self.walk(param, params)?;
+            param_reprs.push(param.repr()?.to_string());
+            instance_params.insert(name, param);
+        }
+
+        let instance_ref = format!("{schema_ref}[{}]", param_reprs.join(", "));
+        if self.instances.insert(instance_ref.clone()) {
+            if self.depth >= MAX_TEMPLATE_DEPTH {
+                return py_schema_err!("Definition template `{}` is nested too deeply", schema_ref);
+            }
+            self.depth += 1;
+            let template_schema: &PyAny = template.get_as_req(intern!(py, "schema"))?;
+            let instance = self.walk(template_schema, Some(&instance_params));
+            self.depth -= 1;
+            let instance: &PyDict = instance?.downcast()?;
+            instance.set_item(intern!(py, "ref"), &instance_ref)?;
+            self.definitions.push(instance);
+        }
+        Ok(instance_ref)
+    }
+}
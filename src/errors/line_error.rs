@@ -27,6 +27,8 @@ pub enum ValError {
     InternalErr(PyErr),
     Omit,
     UseDefault,
+    // validation was aborted because its deadline passed, this isn't collected like `LineErrors`
+    Timeout(Box<ValLineError>),
 }
 
 impl From<PyErr> for ValError {
@@ -69,6 +71,10 @@ impl ValError {
                 }
                 Self::LineErrors(line_errors)
             }
+            Self::Timeout(mut line_error) => {
+                line_error.location.with_outer(loc_item);
+                Self::Timeout(line_error)
+            }
             other => other,
         }
     }
@@ -78,6 +84,7 @@ impl ValError {
 /// to eventually form a `ValidationError`.
 /// I don't like the name `ValLineError`, but it's the best I could come up with (for now).
 #[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone)]
 pub struct ValLineError {
     pub error_type: ErrorType,
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
@@ -6,13 +6,13 @@ use pyo3::prelude::*;
 use pyo3::types::{PyList, PyTuple};
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
+use smallvec::{smallvec, SmallVec};
 
 use crate::lookup_key::{LookupPath, PathItem};
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
-#[derive(Clone)]
-#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum LocItem {
     /// string type key, used to identify items from a dict or anything that implements `__getitem__`
     S(String),
@@ -111,10 +111,15 @@ pub enum Location {
     // no location, avoid creating an unnecessary vec
     Empty,
     // store the in a vec of LocItems, Note: this is the REVERSE of location, see above
-    // we could perhaps use a smallvec or similar here, probably only worth it if we store a Cow in LocItem
-    List(Vec<LocItem>),
+    // most locations are shallow, so they're stored inline to avoid allocating
+    List(LocItems),
 }
 
+/// Locations up to this depth are stored without a heap allocation
+const INLINE_LOC_ITEMS: usize = 3;
+
+type LocItems = SmallVec<[LocItem; INLINE_LOC_ITEMS]>;
+
 impl Default for Location {
     fn default() -> Self {
         Self::Empty
@@ -158,11 +163,17 @@ impl fmt::Display for Location {
 }
 
 impl Location {
-    /// create a new location vec with a value, 3 is plucked out of thin air, should it just be 1?
+    /// create a new location with a value
     pub fn new_some(item: LocItem) -> Self {
-        let mut loc = Vec::with_capacity(3);
-        loc.push(item);
-        Self::List(loc)
+        Self::List(smallvec![item])
+    }
+
+    /// number of items in the location
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::List(loc) => loc.len(),
+        }
     }
 
     pub fn with_outer(&mut self, loc_item: LocItem) {
@@ -200,7 +211,7 @@ impl TryFrom<Option<&PyAny>> for Location {
     /// Thus this expects the location to *not* be reversed and reverses it before storing it.
     fn try_from(location: Option<&PyAny>) -> PyResult<Self> {
         if let Some(location) = location {
-            let mut loc_vec: Vec<LocItem> = if let Ok(tuple) = location.downcast::<PyTuple>() {
+            let mut loc_vec: LocItems = if let Ok(tuple) = location.downcast::<PyTuple>() {
                 tuple.iter().map(AsLocItem::as_loc_item).collect()
             } else if let Ok(list) = location.downcast::<PyList>() {
                 list.iter().map(AsLocItem::as_loc_item).collect()
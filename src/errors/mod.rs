@@ -7,7 +7,7 @@ mod validation_exception;
 mod value_exception;
 
 pub use self::line_error::{AsErrorValue, InputValue, ValError, ValLineError, ValResult};
-pub use self::location::{AsLocItem, LocItem};
+pub use self::location::{AsLocItem, LocItem, Location};
 pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number};
 pub use self::validation_exception::ValidationError;
 pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault};
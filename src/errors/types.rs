@@ -169,16 +169,32 @@ error_types! {
         error: {ctx_type: String, ctx_fn: field_from_context},
     },
     JsonType {},
+    JsonTooLong {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    JsonTooDeep {
+        max_depth: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // ---------------------
     // recursion error
     RecursionLoop {},
     // ---------------------
+    // validation timeout
+    ValidationTimeout {
+        timeout: {ctx_type: Number, ctx_fn: field_from_context},
+        items_validated: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // typed dict specific errors
     Missing {},
     FrozenField {},
     FrozenInstance {},
     ExtraForbidden {},
+    ClosedTypedDictExtra {},
     InvalidKey {},
+    ExtraKeyCollision {
+        key: {ctx_type: String, ctx_fn: field_from_context},
+    },
     GetAttributeError {
         error: {ctx_type: String, ctx_fn: field_from_context},
     },
@@ -188,6 +204,11 @@ error_types! {
         class_name: {ctx_type: String, ctx_fn: field_from_context},
     },
     ModelAttributesType {},
+    ModelConstraint {
+        left: {ctx_type: String, ctx_fn: field_from_context},
+        op: {ctx_type: String, ctx_fn: field_from_context},
+        right: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // dataclass errors (we don't talk about ArgsKwargs here for simplicity)
     DataclassType {
@@ -217,6 +238,10 @@ error_types! {
         multiple_of: {ctx_type: Number, ctx_fn: field_from_context},
     },
     FiniteNumber {},
+    IntOutOfRange {
+        min: {ctx_type: Number, ctx_fn: field_from_context},
+        max: {ctx_type: Number, ctx_fn: field_from_context},
+    },
     // ---------------------
     // generic length errors - used for everything with a length except strings and bytes which need custom messages
     TooShort {
@@ -235,6 +260,9 @@ error_types! {
     IterationError {
         error: {ctx_type: String, ctx_fn: field_from_context},
     },
+    DuplicateItem {
+        field_type: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // string errors
     StringType {},
@@ -246,14 +274,28 @@ error_types! {
     StringTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    // used when the length of strings is counted in bytes or graphemes rather than characters
+    StringUnitsTooShort {
+        min_length: {ctx_type: usize, ctx_fn: field_from_context},
+        unit: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    StringUnitsTooLong {
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+        unit: {ctx_type: String, ctx_fn: field_from_context},
+    },
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringE164Format {},
     // ---------------------
     // enum errors
     Enum {
         expected: {ctx_type: String, ctx_fn: field_from_context},
     },
+    EnumCloseMatch {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+        candidates: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // dict errors
     DictType {},
@@ -279,10 +321,26 @@ error_types! {
     IntParsing {},
     IntParsingSize {},
     IntFromFloat {},
+    IntMaxDigits {
+        max_digits: {ctx_type: u64, ctx_fn: field_from_context},
+    },
+    // ---------------------
+    // int flag errors
+    IntFlagType {
+        class: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IntFlagName {
+        class: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IntFlagUnknownBits {
+        class: {ctx_type: String, ctx_fn: field_from_context},
+        unknown_bits: {ctx_type: u64, ctx_fn: field_from_context},
+    },
     // ---------------------
     // float errors
     FloatType {},
     FloatParsing {},
+    FloatFromInt {},
     // ---------------------
     // bytes errors
     BytesType {},
@@ -292,6 +350,10 @@ error_types! {
     BytesTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    BytesInvalidEncoding {
+        encoding: {ctx_type: String, ctx_fn: field_from_context},
+        encoding_error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // python errors from functions
     ValueError {
@@ -385,6 +447,12 @@ error_types! {
     UnexpectedPositionalArgument {},
     MissingPositionalOnlyArgument {},
     MultipleArgumentValues {},
+    MutuallyExclusiveArguments {
+        arguments: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    MissingOneOfArguments {
+        arguments: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // URL errors
     UrlType {},
@@ -409,6 +477,19 @@ error_types! {
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    UuidFormat {},
+    // Email errors
+    EmailParsing {
+        reason: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // IP address errors
+    IpAddressParsing {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IpNetworkParsing {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // Decimal errors
     DecimalType {},
     DecimalParsing {},
@@ -469,15 +550,21 @@ impl ErrorType {
             Self::NoSuchAttribute {..} => "Object has no attribute '{attribute}'",
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
+            Self::JsonTooLong {..} => "JSON input should have at most {max_length} byte{expected_plural}",
+            Self::JsonTooDeep {..} => "JSON input should be nested at most {max_depth} level{expected_plural} deep",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
+            Self::ValidationTimeout {..} => "Validation did not complete within {timeout} seconds, stopped after {items_validated} items",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
             Self::ExtraForbidden {..} => "Extra inputs are not permitted",
+            Self::ClosedTypedDictExtra {..} => "Extra keys are not permitted in a closed TypedDict",
             Self::InvalidKey {..} => "Keys should be strings",
+            Self::ExtraKeyCollision {..} => "Extra key '{key}' should not collide with a field or another extra key",
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
             Self::ModelAttributesType {..} => "Input should be a valid dictionary or object to extract fields from",
+            Self::ModelConstraint {..} => "{left} should be {comparison} {right}",
             Self::DataclassType {..} => "Input should be a dictionary or an instance of {class_name}",
             Self::DataclassExactType {..} => "Input should be an instance of {class_name}",
             Self::NoneRequired {..} => "Input should be None",
@@ -487,17 +574,23 @@ impl ErrorType {
             Self::LessThanEqual {..} => "Input should be less than or equal to {le}",
             Self::MultipleOf {..} => "Input should be a multiple of {multiple_of}",
             Self::FiniteNumber {..} => "Input should be a finite number",
+            Self::IntOutOfRange {..} => "Input should be between {min} and {max}",
             Self::TooShort {..} => "{field_type} should have at least {min_length} item{expected_plural} after validation, not {actual_length}",
             Self::TooLong {..} => "{field_type} should have at most {max_length} item{expected_plural} after validation, not {actual_length}",
             Self::IterableType {..} => "Input should be iterable",
             Self::IterationError {..} => "Error iterating over object, error: {error}",
+            Self::DuplicateItem {..} => "{field_type} items should be unique",
             Self::StringType {..} => "Input should be a valid string",
             Self::StringSubType {..} => "Input should be a string, not an instance of a subclass of str",
             Self::StringUnicode {..} => "Input should be a valid string, unable to parse raw data as a unicode string",
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
+            Self::StringUnitsTooShort {..} => "String should have at least {min_length} {unit}{expected_plural}",
+            Self::StringUnitsTooLong {..} => "String should have at most {max_length} {unit}{expected_plural}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringE164Format {..} => "String should be a phone number in E.164 format, e.g. '+14155552671'",
             Self::Enum {..} => "Input should be {expected}",
+            Self::EnumCloseMatch {..} => "Input should be {expected}, did you mean {candidates}?",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
             Self::ListType {..} => "Input should be a valid list",
@@ -509,11 +602,17 @@ impl ErrorType {
             Self::IntParsing {..} => "Input should be a valid integer, unable to parse string as an integer",
             Self::IntFromFloat {..} => "Input should be a valid integer, got a number with a fractional part",
             Self::IntParsingSize {..} => "Unable to parse input string as an integer, exceeded maximum size",
+            Self::IntMaxDigits {..} => "Integer should have no more than {max_digits} digit{expected_plural}",
+            Self::IntFlagType {..} => "Input should be an integer or a list of {class} member names",
+            Self::IntFlagName {..} => "Input should be a member name of {class}",
+            Self::IntFlagUnknownBits {..} => "Input should only have bits which are members of {class}, unknown bits: {unknown_bits}",
             Self::FloatType {..} => "Input should be a valid number",
             Self::FloatParsing {..} => "Input should be a valid number, unable to parse string as a number",
+            Self::FloatFromInt {..} => "Input should be a valid number, got an integer which can't be represented exactly as a float",
             Self::BytesType {..} => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
             Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
+            Self::BytesInvalidEncoding {..} => "Data should be valid {encoding}: {encoding_error}",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
@@ -549,6 +648,8 @@ impl ErrorType {
             Self::UnexpectedPositionalArgument {..} => "Unexpected positional argument",
             Self::MissingPositionalOnlyArgument {..} => "Missing required positional only argument",
             Self::MultipleArgumentValues {..} => "Got multiple values for argument",
+            Self::MutuallyExclusiveArguments {..} => "Only one of {arguments} may be provided",
+            Self::MissingOneOfArguments {..} => "At least one of {arguments} is required",
             Self::UrlType {..} => "URL input should be a string or URL",
             Self::UrlParsing {..} => "Input should be a valid URL, {error}",
             Self::UrlSyntaxViolation {..} => "Input violated strict URL syntax rules, {error}",
@@ -557,6 +658,10 @@ impl ErrorType {
             Self::UuidType {..} => "UUID input should be a string, bytes or UUID object",
             Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
             Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::UuidFormat {..} => "UUID should be in the hyphenated format xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+            Self::EmailParsing {..} => "Input should be a valid email address, {reason}",
+            Self::IpAddressParsing {..} => "Input should be a valid {expected} address",
+            Self::IpNetworkParsing {..} => "Input should be a valid {expected} network, {error}",
             Self::DecimalType {..} => "Decimal input should be an integer, float, string or Decimal object",
             Self::DecimalParsing {..} => "Input should be a valid decimal",
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
@@ -606,23 +711,61 @@ impl ErrorType {
         }
     }
 
-    pub fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
-        let tmpl = match input_type {
-            InputType::Python => self.message_template_python(),
+    /// The message template used to render this error for `input_type`
+    pub fn message_template(&self, input_type: InputType) -> &str {
+        match (self, input_type) {
+            (Self::CustomError { message_template, .. }, _) => message_template.as_str(),
+            (_, InputType::Python) => self.message_template_python(),
             _ => self.message_template_json(),
-        };
+        }
+    }
+
+    pub fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
+        self.render_template(py, self.message_template(input_type))
+    }
+
+    /// Render `tmpl` using the context of this error, `tmpl` may be the default message template or
+    /// a user supplied override
+    pub fn render_template(&self, py: Python, tmpl: &str) -> PyResult<String> {
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
+            Self::JsonTooLong { max_length, .. } => {
+                let expected_plural = plural_s(*max_length);
+                to_string_render!(tmpl, max_length, expected_plural)
+            }
+            Self::JsonTooDeep { max_depth, .. } => {
+                let expected_plural = plural_s(*max_depth);
+                to_string_render!(tmpl, max_depth, expected_plural)
+            }
+            Self::ExtraKeyCollision { key, .. } => render!(tmpl, key),
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
+            Self::ModelConstraint { left, op, right, .. } => {
+                let comparison = match op.as_str() {
+                    "lt" => "less than",
+                    "le" => "less than or equal to",
+                    "gt" => "greater than",
+                    "ge" => "greater than or equal to",
+                    "eq" => "equal to",
+                    "ne" => "not equal to",
+                    op => op,
+                };
+                render!(tmpl, left, comparison, right)
+            }
             Self::DataclassType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassExactType { class_name, .. } => render!(tmpl, class_name),
+            Self::ValidationTimeout {
+                timeout,
+                items_validated,
+                ..
+            } => to_string_render!(tmpl, timeout, items_validated),
             Self::GreaterThan { gt, .. } => to_string_render!(tmpl, gt),
             Self::GreaterThanEqual { ge, .. } => to_string_render!(tmpl, ge),
             Self::LessThan { lt, .. } => to_string_render!(tmpl, lt),
             Self::LessThanEqual { le, .. } => to_string_render!(tmpl, le),
             Self::MultipleOf { multiple_of, .. } => to_string_render!(tmpl, multiple_of),
+            Self::IntOutOfRange { min, max, .. } => to_string_render!(tmpl, min, max),
             Self::TooShort {
                 field_type,
                 min_length,
@@ -643,6 +786,7 @@ impl ErrorType {
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
             Self::IterationError { error, .. } => render!(tmpl, error),
+            Self::DuplicateItem { field_type, .. } => render!(tmpl, field_type),
             Self::StringTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
                 to_string_render!(tmpl, min_length, expected_plural)
@@ -651,8 +795,19 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::StringUnitsTooShort { min_length, unit, .. } => {
+                let expected_plural = plural_s(*min_length);
+                to_string_render!(tmpl, min_length, expected_plural, unit)
+            }
+            Self::StringUnitsTooLong { max_length, unit, .. } => {
+                let expected_plural = plural_s(*max_length);
+                to_string_render!(tmpl, max_length, expected_plural, unit)
+            }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
+            Self::EnumCloseMatch {
+                expected, candidates, ..
+            } => to_string_render!(tmpl, expected, candidates),
             Self::MappingType { error, .. } => render!(tmpl, error),
             Self::BytesTooShort { min_length, .. } => {
                 let expected_plural = plural_s(*min_length);
@@ -662,6 +817,11 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::BytesInvalidEncoding {
+                encoding,
+                encoding_error,
+                ..
+            } => render!(tmpl, encoding, encoding_error),
             Self::ValueError { error, .. } => {
                 let error = &error
                     .as_ref()
@@ -674,11 +834,9 @@ impl ErrorType {
                     .map_or(Cow::Borrowed("None"), |v| Cow::Owned(v.as_ref(py).to_string()));
                 render!(tmpl, error)
             }
-            Self::CustomError {
-                message_template,
-                context,
-                ..
-            } => PydanticCustomError::format_message(message_template, context.as_ref().map(|c| c.as_ref(py))),
+            Self::CustomError { context, .. } => {
+                PydanticCustomError::format_message(tmpl, context.as_ref().map(|c| c.as_ref(py)))
+            }
             Self::LiteralError { expected, .. } => render!(tmpl, expected),
             Self::DateParsing { error, .. } => render!(tmpl, error),
             Self::DateFromDatetimeParsing { error, .. } => render!(tmpl, error),
@@ -698,6 +856,8 @@ impl ErrorType {
                 ..
             } => render!(tmpl, discriminator, tag, expected_tags),
             Self::UnionTagNotFound { discriminator, .. } => render!(tmpl, discriminator),
+            Self::MutuallyExclusiveArguments { arguments, .. } => render!(tmpl, arguments),
+            Self::MissingOneOfArguments { arguments, .. } => render!(tmpl, arguments),
             Self::UrlParsing { error, .. } => render!(tmpl, error),
             Self::UrlSyntaxViolation { error, .. } => render!(tmpl, error),
             Self::UrlTooLong { max_length, .. } => {
@@ -707,6 +867,20 @@ impl ErrorType {
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
             Self::UuidParsing { error, .. } => render!(tmpl, error),
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
+            Self::EmailParsing { reason, .. } => render!(tmpl, reason),
+            Self::IpAddressParsing { expected, .. } => render!(tmpl, expected),
+            Self::IpNetworkParsing { expected, error, .. } => render!(tmpl, expected, error),
+            Self::IntMaxDigits { max_digits, .. } => {
+                let expected_plural = plural_s(*max_digits);
+                to_string_render!(tmpl, max_digits, expected_plural)
+            }
+            Self::IntFlagType { class, .. } => render!(tmpl, class),
+            Self::IntFlagName { class, .. } => render!(tmpl, class),
+            Self::IntFlagUnknownBits {
+                class, unknown_bits, ..
+            } => {
+                to_string_render!(tmpl, class, unknown_bits)
+            }
             Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
                 to_string_render!(tmpl, max_digits, expected_plural)
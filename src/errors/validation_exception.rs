@@ -34,6 +34,9 @@ pub struct ValidationError {
     title: PyObject,
     input_type: InputType,
     hide_input: bool,
+    // used to translate messages when rendering errors, from the `error_message_translator` or
+    // `custom_error_messages` config
+    message_translator: Option<PyObject>,
 }
 
 impl ValidationError {
@@ -43,9 +46,11 @@ impl ValidationError {
             title,
             input_type,
             hide_input,
+            message_translator: None,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn from_val_error(
         py: Python,
         title: PyObject,
@@ -54,6 +59,7 @@ impl ValidationError {
         outer_location: Option<LocItem>,
         hide_input: bool,
         validation_error_cause: bool,
+        message_translator: Option<&PyObject>,
     ) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
@@ -64,7 +70,8 @@ impl ValidationError {
                         .collect(),
                     None => raw_errors.into_iter().map(|e| e.into_py(py)).collect(),
                 };
-                let validation_error = Self::new(line_errors, title, input_type, hide_input);
+                let mut validation_error = Self::new(line_errors, title, input_type, hide_input);
+                validation_error.message_translator = message_translator.map(|t| t.clone_ref(py));
                 match Py::new(py, validation_error) {
                     Ok(err) => {
                         if validation_error_cause {
@@ -81,12 +88,30 @@ impl ValidationError {
             ValError::InternalErr(err) => err,
             ValError::Omit => Self::omit_error(),
             ValError::UseDefault => Self::use_default_error(),
+            ValError::Timeout(line_error) => Self::from_val_error(
+                py,
+                title,
+                input_type,
+                ValError::LineErrors(vec![*line_error]),
+                outer_location,
+                hide_input,
+                validation_error_cause,
+                message_translator,
+            ),
         }
     }
 
     pub fn display(&self, py: Python, prefix_override: Option<&'static str>, hide_input: bool) -> String {
         let url_prefix = get_url_prefix(py, include_url_env(py));
-        let line_errors = pretty_py_line_errors(py, self.input_type, self.line_errors.iter(), url_prefix, hide_input);
+        let translator = self.message_translator.as_ref().map(|t| t.as_ref(py));
+        let line_errors = pretty_py_line_errors(
+            py,
+            self.input_type,
+            self.line_errors.iter(),
+            url_prefix,
+            hide_input,
+            translator,
+        );
         if let Some(prefix) = prefix_override {
             format!("{prefix}\n{line_errors}")
         } else {
@@ -228,6 +253,11 @@ impl ValidationError {
     pub(crate) fn into_val_error(self) -> ValError {
         self.line_errors.into_iter().map(Into::into).collect::<Vec<_>>().into()
     }
+
+    /// The translator passed to a method call takes precedence over the one this error was created with
+    fn get_translator<'py>(&'py self, py: Python<'py>, message_translator: Option<&'py PyAny>) -> Option<&'py PyAny> {
+        message_translator.or_else(|| self.message_translator.as_ref().map(|t| t.as_ref(py)))
+    }
 }
 
 #[pymethods]
@@ -248,6 +278,7 @@ impl ValidationError {
                 title,
                 input_type: InputType::try_from(input_type)?,
                 hide_input,
+                message_translator: None,
             },
         )
     }
@@ -261,15 +292,18 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true,
+        message_translator = None))]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        message_translator: Option<&PyAny>,
     ) -> PyResult<Py<PyList>> {
         let url_prefix = get_url_prefix(py, include_url);
+        let translator = self.get_translator(py, message_translator);
         let mut iteration_error = None;
         let list = PyList::new(
             py,
@@ -280,11 +314,18 @@ impl ValidationError {
                 if iteration_error.is_some() {
                     return py.None();
                 }
-                e.as_dict(py, url_prefix, include_context, self.input_type, include_input)
-                    .unwrap_or_else(|err| {
-                        iteration_error = Some(err);
-                        py.None()
-                    })
+                e.as_dict(
+                    py,
+                    url_prefix,
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    translator,
+                )
+                .unwrap_or_else(|err| {
+                    iteration_error = Some(err);
+                    py.None()
+                })
             }),
         );
         if let Some(err) = iteration_error {
@@ -294,7 +335,17 @@ impl ValidationError {
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, include_context = true, include_input = true))]
+    pub fn errors_raw(&self, py: Python, include_context: bool, include_input: bool) -> PyResult<Py<PyList>> {
+        let list = PyList::empty(py);
+        for line_error in &self.line_errors {
+            list.append(line_error.as_tuple(py, include_context, include_input)?)?;
+        }
+        Ok(list.into())
+    }
+
+    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true,
+        message_translator = None))]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
@@ -302,8 +353,9 @@ impl ValidationError {
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        message_translator: Option<&'py PyAny>,
     ) -> PyResult<&'py PyString> {
-        let state = SerializationState::new("iso8601", "utf8")?;
+        let state = SerializationState::new("iso8601", "utf8", "number")?;
         let extra = state.extra(py, &SerMode::Json, true, false, false, true, None);
         let serializer = ValidationErrorSerializer {
             py,
@@ -313,6 +365,7 @@ impl ValidationError {
             include_input,
             extra: &extra,
             input_type: &self.input_type,
+            translator: self.get_translator(py, message_translator),
         };
 
         let writer: Vec<u8> = Vec::with_capacity(self.line_errors.len() * 200);
@@ -394,9 +447,10 @@ pub fn pretty_py_line_errors<'a>(
     line_errors_iter: impl Iterator<Item = &'a PyLineError>,
     url_prefix: Option<&str>,
     hide_input: bool,
+    translator: Option<&PyAny>,
 ) -> String {
     line_errors_iter
-        .map(|i| i.pretty(py, input_type, url_prefix, hide_input))
+        .map(|i| i.pretty(py, input_type, url_prefix, hide_input, translator))
         .collect::<Result<Vec<_>, _>>()
         .unwrap_or_else(|err| vec![format!("[error formatting line errors: {err}]")])
         .join("\n")
@@ -475,6 +529,26 @@ impl PyLineError {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
 
+    /// Render the message for this error, `translator` is either a dict of error types to message templates,
+    /// or a callable taking `(error_type, message_template, context)` and returning a template or `None`
+    fn render_message(&self, py: Python, input_type: InputType, translator: Option<&PyAny>) -> PyResult<String> {
+        let message_template = self.error_type.message_template(input_type);
+        if let Some(translator) = translator {
+            let error_type = self.error_type.type_string();
+            let translated = match translator.downcast::<PyDict>() {
+                Ok(translations) => translations.get_item(error_type)?,
+                Err(_) => {
+                    let context = self.error_type.py_dict(py)?;
+                    Some(translator.call1((error_type, message_template, context))?)
+                }
+            };
+            if let Some(translated) = translated.filter(|t| !t.is_none()) {
+                return self.error_type.render_template(py, translated.extract()?);
+            }
+        }
+        self.error_type.render_template(py, message_template)
+    }
+
     pub fn as_dict(
         &self,
         py: Python,
@@ -482,11 +556,12 @@ impl PyLineError {
         include_context: bool,
         input_type: InputType,
         include_input: bool,
+        translator: Option<&PyAny>,
     ) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("type", self.error_type.type_string())?;
         dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
+        dict.set_item("msg", self.render_message(py, input_type, translator)?)?;
         if include_input {
             dict.set_item("input", &self.input_value)?;
         }
@@ -508,17 +583,37 @@ impl PyLineError {
         Ok(dict.into_py(py))
     }
 
+    /// Like `as_dict` but returns `(type, loc, ctx, input)` and skips rendering the message
+    pub fn as_tuple(&self, py: Python, include_context: bool, include_input: bool) -> PyResult<PyObject> {
+        let context = match include_context {
+            true => self.error_type.py_dict(py)?.into_py(py),
+            false => py.None(),
+        };
+        let input_value = match include_input {
+            true => self.input_value.clone_ref(py),
+            false => py.None(),
+        };
+        Ok((
+            self.error_type.type_string(),
+            self.location.to_object(py),
+            context,
+            input_value,
+        )
+            .into_py(py))
+    }
+
     fn pretty(
         &self,
         py: Python,
         input_type: InputType,
         url_prefix: Option<&str>,
         hide_input: bool,
+        translator: Option<&PyAny>,
     ) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
         write!(output, "{}", self.location)?;
 
-        let message = match self.error_type.render_message(py, input_type) {
+        let message = match self.render_message(py, input_type, translator) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {err})"),
         };
@@ -573,6 +668,7 @@ struct ValidationErrorSerializer<'py> {
     include_input: bool,
     extra: &'py crate::serializers::Extra<'py>,
     input_type: &'py InputType,
+    translator: Option<&'py PyAny>,
 }
 
 impl<'py> Serialize for ValidationErrorSerializer<'py> {
@@ -590,6 +686,7 @@ impl<'py> Serialize for ValidationErrorSerializer<'py> {
                 include_input: self.include_input,
                 extra: self.extra,
                 input_type: self.input_type,
+                translator: self.translator,
             };
             seq.serialize_element(&line_s)?;
         }
@@ -605,6 +702,7 @@ struct PyLineErrorSerializer<'py> {
     include_input: bool,
     extra: &'py crate::serializers::Extra<'py>,
     input_type: &'py InputType,
+    translator: Option<&'py PyAny>,
 }
 
 impl<'py> Serialize for PyLineErrorSerializer<'py> {
@@ -625,8 +723,7 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
 
         let msg = self
             .line_error
-            .error_type
-            .render_message(py, *self.input_type)
+            .render_message(py, *self.input_type, self.translator)
             .map_err(py_err_json::<S>)?;
         map.serialize_entry("msg", &msg)?;
 
@@ -0,0 +1,98 @@
+use std::sync::{PoisonError, RwLock};
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyType};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+/// Types from these modules are not read from attributes unless `from_attributes_excluded_modules` is set.
+/// I don't think it's a very good list at all! But it doesn't have to be at perfect, it just needs to avoid
+/// the most egregious foot guns, it's mostly just to catch "builtins"
+const DEFAULT_EXCLUDED_MODULES: [&str; 3] = ["builtins", "datetime", "collections"];
+
+/// The cache is cleared when it reaches this many types, so types created dynamically aren't kept alive forever
+const MAX_CACHED_TYPES: usize = 256;
+
+/// Best effort check of whether it's likely to make sense to inspect an object for attributes and iterate
+/// over it with `obj.dir()`, configured with `from_attributes_excluded_modules` and
+/// `from_attributes_included_types`, the decision is cached per type.
+#[derive(Debug, Default)]
+pub struct FromAttributesPolicy {
+    excluded_modules: Option<Vec<String>>,
+    included_types: Vec<Py<PyType>>,
+    // the type object is kept to make sure the pointer can't be reused by another type
+    cache: RwLock<AHashMap<usize, (bool, Py<PyType>)>>,
+}
+
+impl FromAttributesPolicy {
+    pub fn from_config(py: Python, config: Option<&PyDict>) -> PyResult<Self> {
+        let included_types = match config.get_as::<&PyList>(intern!(py, "from_attributes_included_types"))? {
+            Some(types) => types
+                .iter()
+                .map(|t| Ok(t.downcast::<PyType>()?.into()))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        };
+        Ok(Self {
+            excluded_modules: config.get_as(intern!(py, "from_attributes_excluded_modules"))?,
+            included_types,
+            cache: RwLock::default(),
+        })
+    }
+
+    pub fn is_applicable(&self, obj: &PyAny) -> bool {
+        let py_type = obj.get_type();
+        let type_ptr = py_type.as_ptr() as usize;
+        let cache = self.cache.read().unwrap_or_else(PoisonError::into_inner);
+        if let Some((applicable, _)) = cache.get(&type_ptr) {
+            return *applicable;
+        }
+        drop(cache);
+
+        let applicable = self.type_applicable(py_type);
+        let mut cache = self.cache.write().unwrap_or_else(PoisonError::into_inner);
+        if cache.len() >= MAX_CACHED_TYPES {
+            cache.clear();
+        }
+        cache.insert(type_ptr, (applicable, py_type.into()));
+        applicable
+    }
+
+    fn type_applicable(&self, py_type: &PyType) -> bool {
+        let py = py_type.py();
+        if self
+            .included_types
+            .iter()
+            .any(|included| py_type.is_subclass(included.as_ref(py)).unwrap_or(false))
+        {
+            return true;
+        }
+        let Ok(module) = py_type.getattr(intern!(py, "__module__")) else {
+            return false;
+        };
+        let Ok(module_name) = module.extract::<&str>() else {
+            return false;
+        };
+        match self.excluded_modules {
+            Some(ref excluded_modules) => !excluded_modules.iter().any(|m| m == module_name),
+            None => !DEFAULT_EXCLUDED_MODULES.contains(&module_name),
+        }
+    }
+}
+
+impl PyGcTraverse for FromAttributesPolicy {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.included_types.py_gc_traverse(visit)?;
+        // if the cache is being written to it's skipped, this can only mean some references aren't visited
+        if let Ok(cache) = self.cache.try_read() {
+            for (_, py_type) in cache.values() {
+                py_type.py_gc_traverse(visit)?;
+            }
+        }
+        Ok(())
+    }
+}
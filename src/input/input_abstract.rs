@@ -6,11 +6,15 @@ use pyo3::{intern, prelude::*};
 
 use crate::errors::{AsLocItem, ErrorTypeDefaults, InputValue, ValError, ValResult};
 use crate::tools::py_err;
+use crate::validators::config::ValBytesMode;
 use crate::{PyMultiHostUrl, PyUrl};
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 use super::return_enums::{EitherBytes, EitherInt, EitherString};
-use super::{EitherFloat, GenericArguments, GenericIterable, GenericIterator, GenericMapping, ValidationMatch};
+use super::{
+    EitherFloat, FromAttributesPolicy, GenericArguments, GenericIterable, GenericIterator, GenericMapping,
+    ValidationMatch,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum InputType {
@@ -93,7 +97,7 @@ pub trait Input<'a>: fmt::Debug + ToPyObject + AsLocItem + Sized {
         coerce_numbers_to_str: bool,
     ) -> ValResult<ValidationMatch<EitherString<'a>>>;
 
-    fn validate_bytes(&'a self, strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>>;
+    fn validate_bytes(&'a self, strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>>;
 
     fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>>;
 
@@ -145,7 +149,18 @@ pub trait Input<'a>: fmt::Debug + ToPyObject + AsLocItem + Sized {
         self.strict_dict()
     }
 
-    fn validate_model_fields(&'a self, strict: bool, _from_attributes: bool) -> ValResult<GenericMapping<'a>> {
+    /// Dict items views and iterables of `(key, value)` pairs, only accepted in lax mode by schemas which
+    /// opt in with `from_pairs`, since iterators can only be consumed once
+    fn lax_pairs(&'a self) -> Option<GenericMapping<'a>> {
+        None
+    }
+
+    /// `from_attributes` is `None` unless reading values from attributes is enabled
+    fn validate_model_fields(
+        &'a self,
+        strict: bool,
+        _from_attributes: Option<&FromAttributesPolicy>,
+    ) -> ValResult<GenericMapping<'a>> {
         self.validate_dict(strict)
     }
 
@@ -7,6 +7,7 @@ use speedate::MicrosecondsPrecisionOverflowBehavior;
 use strum::EnumMessage;
 
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::validators::config::ValBytesMode;
 use crate::validators::decimal::create_decimal;
 
 use super::datetime::{
@@ -110,9 +111,12 @@ impl<'a> Input<'a> for JsonValue {
         }
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+    fn validate_bytes(&'a self, _strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
         match self {
-            JsonValue::Str(s) => Ok(ValidationMatch::strict(s.as_bytes().into())),
+            JsonValue::Str(s) => match mode.deserialize_string(s) {
+                Ok(b) => Ok(ValidationMatch::strict(b)),
+                Err(e) => Err(ValError::new(e, self)),
+            },
             _ => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
         }
     }
@@ -373,8 +377,11 @@ impl<'a> Input<'a> for String {
         Ok(ValidationMatch::strict(self.as_str().into()))
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
-        Ok(ValidationMatch::strict(self.as_bytes().into()))
+    fn validate_bytes(&'a self, _strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+        match mode.deserialize_string(self) {
+            Ok(b) => Ok(ValidationMatch::strict(b)),
+            Err(e) => Err(ValError::new(e, self)),
+        }
     }
 
     fn validate_bool(&self, _strict: bool) -> ValResult<ValidationMatch<bool>> {
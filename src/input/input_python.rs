@@ -14,6 +14,7 @@ use speedate::MicrosecondsPrecisionOverflowBehavior;
 
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
 use crate::tools::{extract_i64, safe_repr};
+use crate::validators::config::ValBytesMode;
 use crate::validators::decimal::{create_decimal, get_decimal_type};
 use crate::validators::Exactness;
 use crate::{ArgsKwargs, PyMultiHostUrl, PyUrl};
@@ -23,13 +24,15 @@ use super::datetime::{
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
     EitherTime,
 };
+#[cfg(PyPy)]
+use super::pypy_types::{is_dict_items_type, is_dict_keys_type, is_dict_values_type};
 use super::return_enums::ValidationMatch;
 use super::shared::{
     decimal_as_int, float_as_int, get_enum_meta_object, int_as_bool, str_as_bool, str_as_float, str_as_int,
 };
 use super::{
-    py_string_str, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments,
-    GenericIterable, GenericIterator, GenericMapping, Input, PyArgs,
+    py_string_str, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta,
+    FromAttributesPolicy, GenericArguments, GenericIterable, GenericIterator, GenericMapping, Input, PyArgs,
 };
 
 #[cfg(not(PyPy))]
@@ -260,7 +263,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn validate_bytes(&'a self, strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+    fn validate_bytes(&'a self, strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
         if let Ok(py_bytes) = self.downcast_exact::<PyBytes>() {
             return Ok(ValidationMatch::exact(py_bytes.into()));
         } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
@@ -271,9 +274,12 @@ impl<'a> Input<'a> for PyAny {
             if !strict {
                 return if let Ok(py_str) = self.downcast::<PyString>() {
                     let str = py_string_str(py_str)?;
-                    Ok(str.as_bytes().into())
+                    match mode.deserialize_string(str) {
+                        Ok(b) => Ok(b),
+                        Err(e) => Err(ValError::new(e, self)),
+                    }
                 } else if let Ok(py_byte_array) = self.downcast::<PyByteArray>() {
-                    Ok(py_byte_array.to_vec().into())
+                    Ok(py_byte_array.into())
                 } else {
                     break 'lax;
                 }
@@ -435,8 +441,27 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn validate_model_fields(&'a self, strict: bool, from_attributes: bool) -> ValResult<GenericMapping<'a>> {
-        if from_attributes {
+    fn lax_pairs(&'a self) -> Option<GenericMapping<'a>> {
+        let iter = if let Some(iter) = extract_dict_items!(self.py(), self) {
+            iter
+        } else if self.downcast::<PyIterator>().is_ok()
+            || self.downcast::<PyList>().is_ok()
+            || self.downcast::<PyTuple>().is_ok()
+        {
+            // e.g. a generator or list of `(key, value)` pairs
+            PyIterator::from_object(self).ok()?
+        } else {
+            return None;
+        };
+        Some(GenericMapping::PyPairs(self, iter))
+    }
+
+    fn validate_model_fields(
+        &'a self,
+        strict: bool,
+        from_attributes: Option<&FromAttributesPolicy>,
+    ) -> ValResult<GenericMapping<'a>> {
+        if let Some(from_attributes) = from_attributes {
             // if from_attributes, first try a dict, then mapping then from_attributes
             if let Ok(dict) = self.downcast::<PyDict>() {
                 return Ok(dict.into());
@@ -446,10 +471,10 @@ impl<'a> Input<'a> for PyAny {
                 }
             }
 
-            if from_attributes_applicable(self) {
+            if from_attributes.is_applicable(self) {
                 Ok(self.into())
             } else if let Ok((obj, kwargs)) = self.extract::<(&PyAny, &PyDict)>() {
-                if from_attributes_applicable(obj) {
+                if from_attributes.is_applicable(obj) {
                     Ok(GenericMapping::PyGetAttr(obj, Some(kwargs)))
                 } else {
                     Err(ValError::new(ErrorTypeDefaults::ModelAttributesType, self))
@@ -728,23 +753,6 @@ impl BorrowInput for &'_ PyAny {
     }
 }
 
-/// Best effort check of whether it's likely to make sense to inspect obj for attributes and iterate over it
-/// with `obj.dir()`
-fn from_attributes_applicable(obj: &PyAny) -> bool {
-    let module_name = match obj.get_type().getattr(intern!(obj.py(), "__module__")) {
-        Ok(module) => match module.extract::<&str>() {
-            Ok(s) => s,
-            Err(_) => return false,
-        },
-        Err(_) => return false,
-    };
-    // I don't think it's a very good list at all! But it doesn't have to be at perfect, it just needs to avoid
-    // the most egregious foot guns, it's mostly just to catch "builtins"
-    // still happy to add more or do something completely different if anyone has a better idea???
-    // dbg!(obj, module_name);
-    !matches!(module_name, "builtins" | "datetime" | "collections")
-}
-
 /// Utility for extracting a string from a PyAny, if possible.
 fn maybe_as_string(v: &PyAny, unicode_error: ErrorType) -> ValResult<Option<Cow<str>>> {
     if let Ok(py_string) = v.downcast::<PyString>() {
@@ -771,57 +779,3 @@ fn maybe_as_enum(v: &PyAny) -> Option<&PyAny> {
         None
     }
 }
-
-#[cfg(PyPy)]
-static DICT_KEYS_TYPE: pyo3::once_cell::GILOnceCell<Py<PyType>> = pyo3::once_cell::GILOnceCell::new();
-
-#[cfg(PyPy)]
-fn is_dict_keys_type(v: &PyAny) -> bool {
-    let py = v.py();
-    let keys_type = DICT_KEYS_TYPE
-        .get_or_init(py, || {
-            py.eval("type({}.keys())", None, None)
-                .unwrap()
-                .extract::<&PyType>()
-                .unwrap()
-                .into()
-        })
-        .as_ref(py);
-    v.is_instance(keys_type).unwrap_or(false)
-}
-
-#[cfg(PyPy)]
-static DICT_VALUES_TYPE: pyo3::once_cell::GILOnceCell<Py<PyType>> = pyo3::once_cell::GILOnceCell::new();
-
-#[cfg(PyPy)]
-fn is_dict_values_type(v: &PyAny) -> bool {
-    let py = v.py();
-    let values_type = DICT_VALUES_TYPE
-        .get_or_init(py, || {
-            py.eval("type({}.values())", None, None)
-                .unwrap()
-                .extract::<&PyType>()
-                .unwrap()
-                .into()
-        })
-        .as_ref(py);
-    v.is_instance(values_type).unwrap_or(false)
-}
-
-#[cfg(PyPy)]
-static DICT_ITEMS_TYPE: pyo3::once_cell::GILOnceCell<Py<PyType>> = pyo3::once_cell::GILOnceCell::new();
-
-#[cfg(PyPy)]
-fn is_dict_items_type(v: &PyAny) -> bool {
-    let py = v.py();
-    let items_type = DICT_ITEMS_TYPE
-        .get_or_init(py, || {
-            py.eval("type({}.items())", None, None)
-                .unwrap()
-                .extract::<&PyType>()
-                .unwrap()
-                .into()
-        })
-        .as_ref(py);
-    v.is_instance(items_type).unwrap_or(false)
-}
@@ -0,0 +1,149 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+use crate::errors::LocItem;
+
+use super::json_stack::JsonStack;
+
+/// Record the byte span `(start, end)` in `json_data` of every value in the document in `spans`, keyed by
+/// the value's path as a tuple of object keys and array indexes, e.g. `('items', 0, 'name')`, `()` for the
+/// document itself.
+///
+/// `json_data` must already have been parsed successfully, this doesn't check the JSON is valid, it only
+/// stops early rather than looping or panicking if it isn't.
+pub fn json_spans(py: Python, json_data: &[u8], spans: &PyDict) -> PyResult<()> {
+    let mut scanner = SpanScanner {
+        data: json_data,
+        index: 0,
+    };
+    // the start of each enclosing array and object
+    let mut stack: JsonStack<usize> = JsonStack::default();
+    loop {
+        // start the value, moving into the first item of arrays and objects which aren't empty
+        let mut start = scanner.peek_index();
+        match scanner.data.get(start) {
+            Some(b'{') => {
+                scanner.index += 1;
+                if scanner.data.get(scanner.peek_index()) == Some(&b'}') {
+                    scanner.index += 1;
+                } else {
+                    match scanner.member_key()? {
+                        Some(key) => {
+                            stack.push_object(key, start);
+                            continue;
+                        }
+                        None => return Ok(()),
+                    }
+                }
+            }
+            Some(b'[') => {
+                scanner.index += 1;
+                match scanner.data.get(scanner.peek_index()) {
+                    Some(b']') => scanner.index += 1,
+                    Some(_) => {
+                        stack.push_array(start);
+                        continue;
+                    }
+                    None => return Ok(()),
+                }
+            }
+            Some(b'"') => scanner.skip_string(),
+            Some(_) => scanner.skip_scalar(),
+            None => return Ok(()),
+        }
+        // the value is complete, record it and move to the next item of the innermost container which has one
+        loop {
+            let path: Vec<PyObject> = stack.locs().map(|loc| loc.to_object(py)).collect();
+            let path = PyTuple::new(py, path);
+            spans.set_item(path, (start, scanner.index))?;
+            let Some((loc, container_start)) = stack.last_mut() else {
+                return Ok(());
+            };
+            let end = match loc {
+                LocItem::I(_) => b']',
+                LocItem::S(_) => b'}',
+            };
+            match scanner.data.get(scanner.peek_index()) {
+                Some(b',') => {
+                    scanner.index += 1;
+                    match loc {
+                        LocItem::I(index) => *index += 1,
+                        LocItem::S(key) => match scanner.member_key()? {
+                            Some(next_key) => *key = next_key,
+                            None => return Ok(()),
+                        },
+                    }
+                    break;
+                }
+                Some(next) if *next == end => {
+                    scanner.index += 1;
+                    start = *container_start;
+                    stack.pop();
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+}
+
+struct SpanScanner<'j> {
+    data: &'j [u8],
+    index: usize,
+}
+
+impl SpanScanner<'_> {
+    /// Skip whitespace and return the index of the next byte
+    fn peek_index(&mut self) -> usize {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.data.get(self.index) {
+            self.index += 1;
+        }
+        self.index
+    }
+
+    fn skip_string(&mut self) {
+        self.index += 1;
+        while let Some(next) = self.data.get(self.index) {
+            match next {
+                b'\\' => self.index += 2,
+                b'"' => {
+                    self.index += 1;
+                    return;
+                }
+                _ => self.index += 1,
+            }
+        }
+    }
+
+    /// Numbers, `true`, `false`, `null`, `NaN` and `Infinity` all end at whitespace or punctuation
+    fn skip_scalar(&mut self) {
+        while let Some(next) = self.data.get(self.index) {
+            if matches!(next, b',' | b']' | b'}' | b' ' | b'\t' | b'\n' | b'\r') {
+                break;
+            }
+            self.index += 1;
+        }
+    }
+
+    /// Consume an object key and the `:` after it, `None` if they aren't found
+    fn member_key(&mut self) -> PyResult<Option<String>> {
+        if self.data.get(self.peek_index()) != Some(&b'"') {
+            return Ok(None);
+        }
+        let start = self.index;
+        self.skip_string();
+        let raw = &self.data[start..self.index.min(self.data.len())];
+        let inner = raw.get(1..raw.len().saturating_sub(1)).unwrap_or_default();
+        let key = if inner.contains(&b'\\') {
+            // escapes are rare in keys, so leave decoding them to serde
+            serde_json::from_slice::<String>(raw).map_err(|e| PyValueError::new_err(e.to_string()))?
+        } else {
+            String::from_utf8_lossy(inner).into_owned()
+        };
+        if self.data.get(self.peek_index()) != Some(&b':') {
+            return Ok(None);
+        }
+        self.index += 1;
+        Ok(Some(key))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::errors::LocItem;
+
+/// The arrays and objects enclosing the current value while a JSON document is walked without recursing, so
+/// arbitrarily deep documents can be walked.
+///
+/// Each entry holds the location of the current item in that container, an index for arrays or a key for
+/// objects, and whatever state the walker keeps for the container.
+#[derive(Debug)]
+pub(crate) struct JsonStack<T> {
+    frames: Vec<(LocItem, T)>,
+}
+
+impl<T> Default for JsonStack<T> {
+    fn default() -> Self {
+        Self { frames: Vec::new() }
+    }
+}
+
+impl<T> JsonStack<T> {
+    /// The number of containers enclosing the current value
+    pub fn depth(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Move into the first item of an array
+    pub fn push_array(&mut self, state: T) {
+        self.frames.push((LocItem::I(0), state));
+    }
+
+    /// Move into the value of the first key of an object
+    pub fn push_object(&mut self, key: String, state: T) {
+        self.frames.push((LocItem::S(key), state));
+    }
+
+    /// The innermost container and the location of the current item in it, `None` at the top level
+    pub fn last_mut(&mut self) -> Option<(&mut LocItem, &mut T)> {
+        self.frames.last_mut().map(|(loc, state)| (loc, state))
+    }
+
+    /// Leave the innermost container, returning its state
+    pub fn pop(&mut self) -> Option<T> {
+        self.frames.pop().map(|(_, state)| state)
+    }
+
+    /// The location of the current value, outermost first
+    pub fn locs(&self) -> impl Iterator<Item = &LocItem> {
+        self.frames.iter().map(|(loc, _)| loc)
+    }
+
+    pub fn into_path(self) -> Vec<LocItem> {
+        self.frames.into_iter().map(|(loc, _)| loc).collect()
+    }
+}
@@ -3,10 +3,15 @@ use std::os::raw::c_int;
 use pyo3::prelude::*;
 
 mod datetime;
+mod from_attributes;
 mod input_abstract;
 mod input_json;
 mod input_python;
 mod input_string;
+mod json_spans;
+mod json_stack;
+#[cfg(PyPy)]
+mod pypy_types;
 mod return_enums;
 mod shared;
 
@@ -15,12 +20,16 @@ pub(crate) use datetime::{
     duration_as_pytimedelta, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate, EitherDateTime,
     EitherTime, EitherTimedelta,
 };
+pub(crate) use from_attributes::FromAttributesPolicy;
 pub(crate) use input_abstract::{BorrowInput, Input, InputType};
 pub(crate) use input_string::StringMapping;
+pub(crate) use json_spans::json_spans;
+pub(crate) use json_stack::JsonStack;
 pub(crate) use return_enums::{
     py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherFloat, EitherInt, EitherString,
     GenericArguments, GenericIterable, GenericIterator, GenericMapping, Int, JsonArgs, JsonObjectGenericIterator,
-    MappingGenericIterator, PyArgs, StringMappingGenericIterator, ValidationMatch,
+    MappingGenericIterator, PairsGenericIterator, PairsMatches, PairsMatchesIterator, PyArgs,
+    StringMappingGenericIterator, ValidationMatch,
 };
 
 // Defined here as it's not exported by pyo3
@@ -0,0 +1,50 @@
+//! PyPy doesn't expose the dict view or function types via its C API, so they're looked up once and compared
+//! exactly, dict views and functions can't be subclassed so this is the same as `isinstance` but much cheaper
+
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+struct PyPyTypes {
+    dict_keys: Py<PyType>,
+    dict_values: Py<PyType>,
+    dict_items: Py<PyType>,
+    function: Py<PyType>,
+}
+
+static PYPY_TYPES: GILOnceCell<PyPyTypes> = GILOnceCell::new();
+
+fn pypy_types(py: Python<'_>) -> &PyPyTypes {
+    PYPY_TYPES.get_or_init(py, || {
+        let dict = PyDict::new(py);
+        let view_type = |method: &str| -> Py<PyType> { dict.call_method0(method).unwrap().get_type().into() };
+        PyPyTypes {
+            dict_keys: view_type("keys"),
+            dict_values: view_type("values"),
+            dict_items: view_type("items"),
+            function: py
+                .import("types")
+                .and_then(|types| types.getattr("FunctionType"))
+                .and_then(|function| Ok(function.downcast::<PyType>()?.into()))
+                .unwrap(),
+        }
+    })
+}
+
+pub fn is_dict_keys_type(v: &PyAny) -> bool {
+    v.get_type().is(&pypy_types(v.py()).dict_keys)
+}
+
+pub fn is_dict_values_type(v: &PyAny) -> bool {
+    v.get_type().is(&pypy_types(v.py()).dict_values)
+}
+
+pub fn is_dict_items_type(v: &PyAny) -> bool {
+    v.get_type().is(&pypy_types(v.py()).dict_items)
+}
+
+/// `is_instance_of::<PyFunction>` crashes with a null pointer on PyPy, see
+/// https://github.com/pydantic/pydantic-core/pull/161#discussion_r917257635
+pub fn is_function_type(v: &PyAny) -> bool {
+    v.get_type().is(&pypy_types(v.py()).function)
+}
@@ -4,6 +4,7 @@ use std::ops::Rem;
 use std::slice::Iter as SliceIter;
 use std::str::FromStr;
 
+use ahash::{AHashMap, AHashSet};
 use jiter::{JsonArray, JsonObject, JsonValue};
 use num_bigint::BigInt;
 
@@ -23,10 +24,12 @@ use pyo3::PyTypeInfo;
 use serde::{ser::Error, Serialize, Serializer};
 
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, InputValue, ValError, ValLineError, ValResult};
-use crate::tools::py_err;
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::tools::{py_err, sorted_items};
+use crate::validators::{CombinedValidator, Exactness, ValidationState};
 
 use super::input_string::StringMapping;
+#[cfg(PyPy)]
+use super::pypy_types::is_function_type;
 use super::{py_error_on_minusone, Input};
 
 pub struct ValidationMatch<T>(T, Exactness);
@@ -89,6 +92,16 @@ pub enum GenericIterable<'a> {
 }
 
 impl<'a, 'py: 'a> GenericIterable<'a> {
+    /// Replace sets with a sorted list of their items, so the order of validated items and the locations of
+    /// errors don't depend on hash seeds, used in `deterministic` mode
+    pub fn into_deterministic(self) -> PyResult<Self> {
+        match self {
+            GenericIterable::Set(set) => Ok(GenericIterable::List(sorted_items(set)?)),
+            GenericIterable::FrozenSet(set) => Ok(GenericIterable::List(sorted_items(set)?)),
+            other => Ok(other),
+        }
+    }
+
     pub fn as_sequence_iterator(
         &self,
         py: Python<'py>,
@@ -198,7 +211,8 @@ fn validate_iter_to_vec<'a, 's>(
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item, state) {
+        state.check_item(py, max_length_check.input, || index.into())?;
+        match state.validate_item(py, validator, item, || index.into()) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
@@ -208,7 +222,7 @@ fn validate_iter_to_vec<'a, 's>(
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
             }
             Err(ValError::Omit) => (),
-            Err(err) => return Err(err),
+            Err(err) => return Err(err.with_outer_location(index.into())),
         }
     }
 
@@ -257,37 +271,61 @@ fn validate_iter_to_set<'a, 's>(
     input: &'a (impl Input<'a> + 'a),
     field_type: &'static str,
     max_length: Option<usize>,
+    unique_items: bool,
     validator: &'s CombinedValidator,
     state: &mut ValidationState,
 ) -> ValResult<()> {
     let mut errors: Vec<ValLineError> = Vec::new();
+    // items which failed validation, counted towards `max_length` like lists so invalid input can't run forever
+    let mut invalid_items: usize = 0;
+    let too_long = |max_length: usize| {
+        ValError::new(
+            ErrorType::TooLong {
+                field_type: field_type.to_string(),
+                max_length,
+                // The logic here is that it doesn't matter how many elements the
+                // input actually had; all we know is it had more than the allowed
+                // number of deduplicated elements.
+                actual_length: None,
+                context: None,
+            },
+            input,
+        )
+    };
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validator.validate(py, item, state) {
+        state.check_item(py, input, || index.into())?;
+        match state.validate_item(py, validator, item, || index.into()) {
             Ok(item) => {
+                let len_before = set.build_len();
                 set.build_add(item)?;
+                if unique_items && set.build_len() == len_before {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::DuplicateItem {
+                            field_type: field_type.to_string(),
+                            context: None,
+                        },
+                        input,
+                        index,
+                    ));
+                }
                 if let Some(max_length) = max_length {
-                    if set.build_len() > max_length {
-                        return Err(ValError::new(
-                            ErrorType::TooLong {
-                                field_type: field_type.to_string(),
-                                max_length,
-                                // The logic here is that it doesn't matter how many elements the
-                                // input actually had; all we know is it had more than the allowed
-                                // number of deduplicated elements.
-                                actual_length: None,
-                                context: None,
-                            },
-                            input,
-                        ));
+                    if set.build_len() + invalid_items > max_length {
+                        return Err(too_long(max_length));
                     }
                 }
             }
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                invalid_items += 1;
+                if let Some(max_length) = max_length {
+                    if set.build_len() + invalid_items > max_length {
+                        return Err(too_long(max_length));
+                    }
+                }
             }
             Err(ValError::Omit) => (),
-            Err(err) => return Err(err),
+            Err(err) => return Err(err.with_outer_location(index.into())),
         }
     }
 
@@ -378,13 +416,24 @@ impl<'a> GenericIterable<'a> {
         set: impl BuildSet,
         input: &'a impl Input<'a>,
         max_length: Option<usize>,
+        unique_items: bool,
         field_type: &'static str,
         validator: &'s CombinedValidator,
         state: &mut ValidationState,
     ) -> ValResult<()> {
         macro_rules! validate_set {
             ($iter:expr) => {
-                validate_iter_to_set(py, set, $iter, input, field_type, max_length, validator, state)
+                validate_iter_to_set(
+                    py,
+                    set,
+                    $iter,
+                    input,
+                    field_type,
+                    max_length,
+                    unique_items,
+                    validator,
+                    state,
+                )
             };
         }
 
@@ -444,6 +493,8 @@ pub enum GenericMapping<'a> {
     StringMapping(&'a PyDict),
     PyGetAttr(&'a PyAny, Option<&'a PyDict>),
     JsonObject(&'a JsonObject),
+    // an iterator of `(key, value)` pairs, e.g. `dict.items()` or a generator, along with the original input
+    PyPairs(&'a PyAny, &'a PyIterator),
 }
 
 derive_from!(GenericMapping, PyDict, PyDict);
@@ -499,6 +550,7 @@ impl<'py> MappingGenericIterator<'py> {
 }
 
 const MAPPING_TUPLE_ERROR: &str = "Mapping items must be tuples of (key, value) pairs";
+const PAIRS_TUPLE_ERROR: &str = "Items must be tuples of (key, value) pairs";
 
 impl<'py> Iterator for MappingGenericIterator<'py> {
     type Item = ValResult<(&'py PyAny, &'py PyAny)>;
@@ -519,6 +571,98 @@ impl<'py> Iterator for MappingGenericIterator<'py> {
     }
 }
 
+pub struct PairsGenericIterator<'py> {
+    input: &'py PyAny,
+    iter: &'py PyIterator,
+}
+
+impl<'py> PairsGenericIterator<'py> {
+    pub fn new(input: &'py PyAny, iter: &'py PyIterator) -> Self {
+        Self { input, iter }
+    }
+}
+
+impl<'py> Iterator for PairsGenericIterator<'py> {
+    type Item = ValResult<(&'py PyAny, &'py PyAny)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(match self.iter.next()? {
+            Ok(item) => item.extract().map_err(|_| {
+                ValError::new(
+                    ErrorType::MappingType {
+                        error: PAIRS_TUPLE_ERROR.into(),
+                        context: None,
+                    },
+                    self.input,
+                )
+            }),
+            Err(e) => Err(mapping_err(e, self.iter.py(), self.input)),
+        })
+    }
+}
+
+/// `(key, value)` pairs read in a single pass, keeping only the pairs whose keys fields look up, plus the
+/// others if they're needed as extras, so fields can be looked up without collecting the whole input into a dict
+pub struct PairsMatches<'py> {
+    // pairs in input order, a repeated key replaces the earlier value in place like a dict
+    pairs: Vec<(&'py PyAny, &'py PyAny)>,
+    // index into `pairs` of each key fields look up
+    matched: AHashMap<&'py str, usize>,
+}
+
+impl<'py> PairsMatches<'py> {
+    pub fn new(
+        input: &'py PyAny,
+        iter: &'py PyIterator,
+        field_keys: &AHashSet<&str>,
+        keep_unmatched: bool,
+    ) -> ValResult<Self> {
+        let mut pairs: Vec<(&PyAny, &PyAny)> = Vec::with_capacity(field_keys.len());
+        let mut matched: AHashMap<&str, usize> = AHashMap::with_capacity(field_keys.len());
+        for item in PairsGenericIterator::new(input, iter) {
+            let (key, value) = item?;
+            let str_key = key.downcast::<PyString>().ok().and_then(|k| k.to_str().ok());
+            match str_key {
+                Some(str_key) if field_keys.contains(str_key) => match matched.get(str_key) {
+                    Some(&index) => pairs[index] = (key, value),
+                    None => {
+                        matched.insert(str_key, pairs.len());
+                        pairs.push((key, value));
+                    }
+                },
+                _ if keep_unmatched => pairs.push((key, value)),
+                _ => (),
+            }
+        }
+        Ok(Self { pairs, matched })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&'py PyAny> {
+        self.matched.get(key).map(|&index| self.pairs[index].1)
+    }
+}
+
+/// Iterates over the pairs kept by `PairsMatches` to find extras
+pub struct PairsMatchesIterator<'a, 'py> {
+    iter: SliceIter<'a, (&'py PyAny, &'py PyAny)>,
+}
+
+impl<'a, 'py> PairsMatchesIterator<'a, 'py> {
+    pub fn new(matches: &'a PairsMatches<'py>) -> ValResult<Self> {
+        Ok(Self {
+            iter: matches.pairs.iter(),
+        })
+    }
+}
+
+impl<'py> Iterator for PairsMatchesIterator<'_, 'py> {
+    type Item = ValResult<(&'py PyAny, &'py PyAny)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|&pair| Ok(pair))
+    }
+}
+
 pub struct StringMappingGenericIterator<'py> {
     dict_iter: PyDictIterator<'py>,
 }
@@ -591,11 +735,8 @@ impl<'py> Iterator for AttributesGenericIterator<'py> {
                 if !is_bound && !PyFunction::is_type_of(attr) {
                     return Some(Ok((name, attr)));
                 }
-                // MASSIVE HACK! PyFunction doesn't exist for PyPy,
-                // is_instance_of::<PyFunction> crashes with a null pointer, hence this hack, see
-                // https://github.com/pydantic/pydantic-core/pull/161#discussion_r917257635
                 #[cfg(PyPy)]
-                if !is_bound && attr.get_type().to_string() != "<class 'function'>" {
+                if !is_bound && !is_function_type(attr) {
                     return Some(Ok((name, attr)));
                 }
             }
@@ -807,6 +948,8 @@ pub fn py_string_str(py_str: &PyString) -> ValResult<&str> {
 pub enum EitherBytes<'a> {
     Cow(Cow<'a, [u8]>),
     Py(&'a PyBytes),
+    // copied lazily so the length can be checked first
+    PyByteArray(&'a PyByteArray),
 }
 
 impl<'a> From<Vec<u8>> for EitherBytes<'a> {
@@ -827,11 +970,18 @@ impl<'a> From<&'a PyBytes> for EitherBytes<'a> {
     }
 }
 
+impl<'a> From<&'a PyByteArray> for EitherBytes<'a> {
+    fn from(byte_array: &'a PyByteArray) -> Self {
+        Self::PyByteArray(byte_array)
+    }
+}
+
 impl<'a> EitherBytes<'a> {
-    pub fn as_slice(&'a self) -> &[u8] {
+    pub fn as_slice(&'a self) -> Cow<'a, [u8]> {
         match self {
-            EitherBytes::Cow(bytes) => bytes,
-            EitherBytes::Py(py_bytes) => py_bytes.as_bytes(),
+            EitherBytes::Cow(bytes) => Cow::Borrowed(bytes),
+            EitherBytes::Py(py_bytes) => Cow::Borrowed(py_bytes.as_bytes()),
+            EitherBytes::PyByteArray(py_byte_array) => Cow::Owned(py_byte_array.to_vec()),
         }
     }
 
@@ -839,6 +989,7 @@ impl<'a> EitherBytes<'a> {
         match self {
             EitherBytes::Cow(bytes) => Ok(bytes.len()),
             EitherBytes::Py(py_bytes) => py_bytes.len(),
+            EitherBytes::PyByteArray(py_byte_array) => Ok(py_byte_array.len()),
         }
     }
 }
@@ -848,6 +999,7 @@ impl<'a> IntoPy<PyObject> for EitherBytes<'a> {
         match self {
             EitherBytes::Cow(bytes) => PyBytes::new(py, &bytes).into_py(py),
             EitherBytes::Py(py_bytes) => py_bytes.into_py(py),
+            EitherBytes::PyByteArray(py_byte_array) => PyBytes::new(py, &py_byte_array.to_vec()).into_py(py),
         }
     }
 }
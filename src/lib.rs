@@ -13,6 +13,8 @@ mod py_gc;
 
 mod argument_markers;
 mod build_tools;
+mod capi;
+mod coverage;
 mod definitions;
 mod errors;
 mod input;
@@ -20,31 +22,35 @@ mod lookup_key;
 mod recursion_guard;
 mod serializers;
 mod tools;
+mod trace;
 mod url;
 mod validators;
 
 // required for benchmarks
 pub use self::input::TzInfo;
 pub use self::url::{PyMultiHostUrl, PyUrl};
-pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
-pub use build_tools::SchemaError;
+pub use argument_markers::{ArgsKwargs, PydanticUndefinedType, UnsetType};
+pub use build_tools::{merge_config, SchemaError};
 pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
 };
 pub use serializers::{
-    to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
+    register_ob_type, to_json, to_jsonable_python, PathFilter, PydanticSerializationError,
+    PydanticSerializationUnexpectedValue, PydanticSerializationWarning, SchemaSerializer,
 };
 pub use validators::{validate_core_schema, PySome, SchemaValidator};
 
 use crate::input::Input;
+use crate::validators::config::ValBytesMode;
 
 #[pyfunction(signature = (data, *, allow_inf_nan=true, cache_strings=true))]
 pub fn from_json(py: Python, data: &PyAny, allow_inf_nan: bool, cache_strings: bool) -> PyResult<PyObject> {
     let v_match = data
-        .validate_bytes(false)
+        .validate_bytes(false, ValBytesMode::Utf8)
         .map_err(|_| PyTypeError::new_err("Expected bytes, bytearray or str"))?;
     let json_either_bytes = v_match.into_inner();
     let json_bytes = json_either_bytes.as_slice();
+    let json_bytes = json_bytes.as_ref();
     jiter::python_parse(py, json_bytes, allow_inf_nan, cache_strings).map_err(|e| jiter::map_json_error(json_bytes, &e))
 }
 
@@ -89,8 +95,11 @@ fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add("build_profile", env!("PROFILE"))?;
     m.add("build_info", build_info())?;
     m.add("_recursion_limit", recursion_guard::RECURSION_GUARD_LIMIT)?;
+    m.add("_C_API", capi::capi_capsule(py)?)?;
     m.add("PydanticUndefined", PydanticUndefinedType::new(py))?;
     m.add_class::<PydanticUndefinedType>()?;
+    m.add("UNSET", UnsetType::new(py))?;
+    m.add_class::<UnsetType>()?;
     m.add_class::<PySome>()?;
     m.add_class::<SchemaValidator>()?;
     m.add_class::<ValidationError>()?;
@@ -101,15 +110,23 @@ fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PydanticUseDefault>()?;
     m.add_class::<PydanticSerializationError>()?;
     m.add_class::<PydanticSerializationUnexpectedValue>()?;
+    m.add(
+        "PydanticSerializationWarning",
+        py.get_type::<PydanticSerializationWarning>(),
+    )?;
     m.add_class::<PyUrl>()?;
     m.add_class::<PyMultiHostUrl>()?;
     m.add_class::<ArgsKwargs>()?;
     m.add_class::<SchemaSerializer>()?;
+    m.add_class::<PathFilter>()?;
     m.add_class::<TzInfo>()?;
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
     m.add_function(wrap_pyfunction!(from_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
+    m.add_function(wrap_pyfunction!(register_ob_type, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
     m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
+    m.add_function(wrap_pyfunction!(merge_config, m)?)?;
+    m.add_function(wrap_pyfunction!(trace::set_trace_subscriber, m)?)?;
     Ok(())
 }
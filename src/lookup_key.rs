@@ -9,7 +9,7 @@ use jiter::{JsonObject, JsonValue};
 
 use crate::build_tools::py_schema_err;
 use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
-use crate::input::{Input, StringMapping};
+use crate::input::{Input, PairsMatches, StringMapping};
 use crate::tools::{extract_i64, py_err};
 
 /// Used for getting items from python dicts, python objects, or JSON objects, in different ways
@@ -304,6 +304,47 @@ impl LookupKey {
         }
     }
 
+    /// Look up a key in pairs read by `PairsMatches`, which only keeps the keys from `first_keys`
+    pub fn pairs_get<'data, 's>(
+        &'s self,
+        pairs: &PairsMatches<'data>,
+    ) -> ValResult<Option<(&'s LookupPath, &'data PyAny)>> {
+        match self {
+            Self::Simple { key, path, .. } => Ok(pairs.get(key).map(|value| (path, value))),
+            Self::Choice {
+                key1,
+                path1,
+                key2,
+                path2,
+                ..
+            } => match pairs.get(key1) {
+                Some(value) => Ok(Some((path1, value))),
+                None => Ok(pairs.get(key2).map(|value| (path2, value))),
+            },
+            Self::PathChoices(path_choices) => {
+                for path in path_choices {
+                    let Some(v) = pairs.get(path.first_key()) else {
+                        continue;
+                    };
+                    // the first step is the key of the pair, the rest are looked up in the value
+                    if let Some(v) = path.iter().skip(1).try_fold(v, |d, loc| loc.py_get_item(d)) {
+                        return Ok(Some((path, v)));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
+    /// The top level keys this lookup key may read
+    pub fn first_keys(&self) -> Vec<&str> {
+        match self {
+            Self::Simple { key, .. } => vec![key],
+            Self::Choice { key1, key2, .. } => vec![key1, key2],
+            Self::PathChoices(path_choices) => path_choices.iter().map(LookupPath::first_key).collect(),
+        }
+    }
+
     pub fn error<'d>(
         &self,
         error_type: ErrorType,
@@ -324,6 +365,61 @@ impl LookupKey {
     }
 }
 
+/// Whether fields are looked up by alias, by name or both, from `by_alias` and `by_name` set at validation time,
+/// falling back to `populate_by_name` from the schema
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LookupType {
+    by_alias: bool,
+    by_name: bool,
+}
+
+impl LookupType {
+    pub fn new(by_alias: Option<bool>, by_name: Option<bool>, populate_by_name: bool) -> Self {
+        let by_alias = by_alias.unwrap_or(true);
+        Self {
+            by_alias,
+            // turning off lookup by alias means fields are looked up by name unless that's also turned off
+            by_name: by_name.unwrap_or(populate_by_name || !by_alias),
+        }
+    }
+}
+
+/// The lookup keys for a field with each `LookupType`, so the keys used can be chosen at validation time
+#[derive(Debug, Clone)]
+pub(crate) struct LookupKeyCollection {
+    name_key: LookupKey,
+    // these are `None` if the field has no alias
+    alias: Option<LookupKey>,
+    alias_then_name: Option<LookupKey>,
+}
+
+impl LookupKeyCollection {
+    pub fn new(py: Python, validation_alias: Option<&PyAny>, name: &str) -> PyResult<Self> {
+        let name_key = LookupKey::from_string(py, name);
+        match validation_alias {
+            Some(alias) => Ok(Self {
+                name_key,
+                alias: Some(LookupKey::from_py(py, alias, None)?),
+                alias_then_name: Some(LookupKey::from_py(py, alias, Some(name))?),
+            }),
+            None => Ok(Self {
+                name_key,
+                alias: None,
+                alias_then_name: None,
+            }),
+        }
+    }
+
+    pub fn select(&self, lookup_type: LookupType) -> &LookupKey {
+        match (lookup_type.by_alias, lookup_type.by_name) {
+            (true, true) => self.alias_then_name.as_ref(),
+            (true, false) => self.alias.as_ref(),
+            (false, _) => None,
+        }
+        .unwrap_or(&self.name_key)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct LookupPath(Vec<PathItem>);
 
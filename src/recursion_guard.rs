@@ -17,6 +17,8 @@ pub struct RecursionGuard {
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
     depth: u16,
+    // the `recursion_limit` setting, `RECURSION_GUARD_LIMIT` if not set
+    limit: Option<u16>,
 }
 
 // A hard limit to avoid stack overflows when rampant recursion occurs
@@ -30,7 +32,20 @@ pub const RECURSION_GUARD_LIMIT: u16 = if cfg!(any(target_family = "wasm", all(w
     255
 };
 
+/// The highest `recursion_limit` which is used, higher settings are capped at this so deep input still results in
+/// an error rather than overflowing the stack
+pub const MAX_RECURSION_LIMIT: u16 = RECURSION_GUARD_LIMIT * 2;
+
 impl RecursionGuard {
+    /// A guard with a custom depth limit instead of `RECURSION_GUARD_LIMIT`, raising the limit allows deeper
+    /// structures, up to `MAX_RECURSION_LIMIT`
+    pub fn with_limit(limit: Option<u16>) -> Self {
+        Self {
+            limit: limit.map(|limit| limit.min(MAX_RECURSION_LIMIT)),
+            ..Self::default()
+        }
+    }
+
     // insert a new id into the set, return whether the set already had the id in it
     pub fn contains_or_insert(&mut self, obj_id: usize, node_id: usize) -> bool {
         match self.ids {
@@ -50,7 +65,11 @@ impl RecursionGuard {
     #[must_use]
     pub fn incr_depth(&mut self) -> bool {
         self.depth += 1;
-        self.depth >= RECURSION_GUARD_LIMIT
+        self.depth >= self.limit.unwrap_or(RECURSION_GUARD_LIMIT)
+    }
+
+    pub fn depth(&self) -> u16 {
+        self.depth
     }
 
     pub fn decr_depth(&mut self) {
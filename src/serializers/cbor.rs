@@ -0,0 +1,562 @@
+use std::str::FromStr;
+
+use num_bigint::{BigInt, Sign};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use serde::ser::{self, Error, Serialize, Serializer};
+
+use super::errors::{py_err_se_err, PythonSerializerError};
+
+type Result<T> = std::result::Result<T, PythonSerializerError>;
+
+/// the token `serde_json` uses for arbitrary precision numbers, used here for big ints
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// `serialize_newtype_struct` names starting with this prefix are written as CBOR tags, other serializers
+/// ignore the name and serialize the inner value as is
+const TAG_TOKEN_PREFIX: &str = "$pydantic_core::cbor::tag::";
+/// standard date/time string, RFC 8949 section 3.4.1
+pub(crate) const DATETIME_TAG: &str = "$pydantic_core::cbor::tag::0";
+/// decimal fraction `[exponent, mantissa]`, RFC 8949 section 3.4.4
+pub(crate) const DECIMAL_FRACTION_TAG: &str = "$pydantic_core::cbor::tag::4";
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+
+const TAG_POSITIVE_BIGNUM: u64 = 2;
+const TAG_NEGATIVE_BIGNUM: u64 = 3;
+
+const FALSE: u8 = 0xf4;
+const TRUE: u8 = 0xf5;
+const NULL: u8 = 0xf6;
+const FLOAT64: u8 = 0xfb;
+
+/// Serde serializer writing CBOR (RFC 8949), containers are written with definite lengths.
+///
+/// The number of items in a container is only known once it's been serialized, since fields may be omitted,
+/// so container heads are kept aside, in the order the containers start, and written before their items
+/// in one pass by `into_inner`.
+pub(crate) struct CborSerializer {
+    writer: Vec<u8>,
+    // the position in `writer` of each container, its major type and its number of items
+    heads: Vec<(usize, u8, u64)>,
+}
+
+impl CborSerializer {
+    pub fn new(writer: Vec<u8>) -> Self {
+        Self {
+            writer,
+            heads: Vec::new(),
+        }
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        if self.heads.is_empty() {
+            return self.writer;
+        }
+        let mut output = Vec::with_capacity(self.writer.len() + self.heads.len() * 9);
+        let mut copied = 0;
+        for (position, major, count) in self.heads {
+            output.extend_from_slice(&self.writer[copied..position]);
+            encode_head(&mut output, major, count);
+            copied = position;
+        }
+        output.extend_from_slice(&self.writer[copied..]);
+        output
+    }
+
+    fn write_head(&mut self, major: u8, value: u64) {
+        encode_head(&mut self.writer, major, value);
+    }
+
+    fn write_i128(&mut self, value: i128) {
+        if value >= 0 {
+            match u64::try_from(value) {
+                Ok(v) => self.write_head(MAJOR_UNSIGNED, v),
+                Err(_) => self.write_bignum(TAG_POSITIVE_BIGNUM, &value.to_be_bytes()),
+            }
+        } else {
+            // negative integers are encoded as `-1 - n`
+            let n = -1 - value;
+            match u64::try_from(n) {
+                Ok(v) => self.write_head(MAJOR_NEGATIVE, v),
+                Err(_) => self.write_bignum(TAG_NEGATIVE_BIGNUM, &n.to_be_bytes()),
+            }
+        }
+    }
+
+    fn write_bigint(&mut self, value: &BigInt) {
+        if let Ok(v) = i128::try_from(value) {
+            self.write_i128(v);
+        } else if value.sign() == Sign::Minus {
+            // as above, `-1 - value`
+            let n: BigInt = -(value + BigInt::from(1));
+            self.write_bignum(TAG_NEGATIVE_BIGNUM, &n.to_bytes_be().1);
+        } else {
+            self.write_bignum(TAG_POSITIVE_BIGNUM, &value.to_bytes_be().1);
+        }
+    }
+
+    fn write_bignum(&mut self, tag: u64, be_bytes: &[u8]) {
+        let first_non_zero = be_bytes.iter().position(|b| *b != 0).unwrap_or(be_bytes.len());
+        let be_bytes = &be_bytes[first_non_zero..];
+        self.write_head(MAJOR_TAG, tag);
+        self.write_head(MAJOR_BYTES, be_bytes.len() as u64);
+        self.writer.extend_from_slice(be_bytes);
+    }
+
+    fn write_f64(&mut self, value: f64) {
+        self.writer.push(FLOAT64);
+        self.writer.extend_from_slice(&value.to_be_bytes());
+    }
+
+    /// Write a number which `serde_json` has represented as a string, see `NUMBER_TOKEN`
+    fn write_number(&mut self, number: &str) -> Result<()> {
+        if let Ok(v) = i128::from_str(number) {
+            self.write_i128(v);
+        } else if let Ok(v) = BigInt::from_str(number) {
+            self.write_bigint(&v);
+        } else if let Ok(v) = f64::from_str(number) {
+            self.write_f64(v);
+        } else {
+            return Err(PythonSerializerError::custom(format!("invalid number: {number}")));
+        }
+        Ok(())
+    }
+
+    fn write_variant_key(&mut self, variant: &str) {
+        self.write_head(MAJOR_MAP, 1);
+        self.write_str(variant);
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_head(MAJOR_TEXT, value.len() as u64);
+        self.writer.extend_from_slice(value.as_bytes());
+    }
+
+    fn container(&mut self, major: u8) -> Compound<'_> {
+        let head = self.heads.len();
+        self.heads.push((self.writer.len(), major, 0));
+        Compound::Container {
+            ser: self,
+            head,
+            count: 0,
+        }
+    }
+}
+
+/// Values below 24 are stored in the head itself, otherwise additional information `0x18` to `0x1b` says the
+/// value follows in 1, 2, 4 or 8 bytes
+fn encode_head(buf: &mut Vec<u8>, major: u8, value: u64) {
+    let major = major << 5;
+    if value < 24 {
+        buf.push(major | value as u8);
+    } else if let Ok(v) = u8::try_from(value) {
+        buf.push(major | 0x18);
+        buf.push(v);
+    } else if let Ok(v) = u16::try_from(value) {
+        buf.push(major | 0x19);
+        buf.extend_from_slice(&v.to_be_bytes());
+    } else if let Ok(v) = u32::try_from(value) {
+        buf.push(major | 0x1a);
+        buf.extend_from_slice(&v.to_be_bytes());
+    } else {
+        buf.push(major | 0x1b);
+        buf.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+impl<'a> Serializer for &'a mut CborSerializer {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, value: bool) -> Result<()> {
+        self.writer.push(if value { TRUE } else { FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<()> {
+        self.serialize_i64(value.into())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<()> {
+        self.serialize_i64(value.into())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<()> {
+        self.serialize_i64(value.into())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<()> {
+        self.write_i128(value.into());
+        Ok(())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<()> {
+        self.write_i128(value);
+        Ok(())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<()> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<()> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<()> {
+        self.serialize_u64(value.into())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<()> {
+        self.write_head(MAJOR_UNSIGNED, value);
+        Ok(())
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<()> {
+        match u64::try_from(value) {
+            Ok(v) => self.write_head(MAJOR_UNSIGNED, v),
+            Err(_) => self.write_bignum(TAG_POSITIVE_BIGNUM, &value.to_be_bytes()),
+        }
+        Ok(())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<()> {
+        self.serialize_f64(value.into())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<()> {
+        self.write_f64(value);
+        Ok(())
+    }
+
+    fn serialize_char(self, value: char) -> Result<()> {
+        self.serialize_str(value.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, value: &str) -> Result<()> {
+        self.write_str(value);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<()> {
+        self.write_head(MAJOR_BYTES, value.len() as u64);
+        self.writer.extend_from_slice(value);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.writer.push(NULL);
+        Ok(())
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, variant: &'static str) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        if let Some(tag) = name.strip_prefix(TAG_TOKEN_PREFIX) {
+            let tag = u64::from_str(tag).map_err(PythonSerializerError::custom)?;
+            self.write_head(MAJOR_TAG, tag);
+        }
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.write_variant_key(variant);
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(self.container(MAJOR_ARRAY))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        self.write_variant_key(variant);
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(self.container(MAJOR_MAP))
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        match name {
+            NUMBER_TOKEN => Ok(Compound::Number { ser: self }),
+            _ => self.serialize_map(Some(len)),
+        }
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        self.write_variant_key(variant);
+        self.serialize_map(Some(len))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+pub(crate) enum Compound<'a> {
+    Container {
+        ser: &'a mut CborSerializer,
+        // the index of this container's head in `CborSerializer::heads`
+        head: usize,
+        count: u64,
+    },
+    Number {
+        ser: &'a mut CborSerializer,
+    },
+}
+
+impl Compound<'_> {
+    fn item<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self {
+            Compound::Container { ser, count, .. } => {
+                value.serialize(&mut **ser)?;
+                *count += 1;
+                Ok(())
+            }
+            Compound::Number { .. } => unreachable!(),
+        }
+    }
+
+    fn entry<T>(&mut self, key: &str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self {
+            Compound::Container { ser, count, .. } => {
+                ser.write_str(key);
+                value.serialize(&mut **ser)?;
+                *count += 1;
+                Ok(())
+            }
+            Compound::Number { ser } => match serde_json::to_value(value) {
+                Ok(serde_json::Value::String(number)) => ser.write_number(&number),
+                _ => Err(PythonSerializerError::custom("invalid number")),
+            },
+        }
+    }
+
+    fn finish(self) -> Result<()> {
+        if let Compound::Container { ser, head, count } = self {
+            ser.heads[head].2 = count;
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.item(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        match self {
+            Compound::Container { ser, .. } => key.serialize(&mut **ser),
+            Compound::Number { .. } => unreachable!(),
+        }
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        // the key and value together are one item
+        self.item(value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = PythonSerializerError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.entry(key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        self.finish()
+    }
+}
+
+/// Serialize a `Decimal` as a decimal fraction, falling back to a string for `NaN` and infinities which
+/// can't be represented that way
+pub(crate) fn serialize_decimal_fraction<S: Serializer>(
+    value: &PyAny,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let py = value.py();
+    let (sign, digits, exponent): (u8, &PyTuple, &PyAny) = value
+        .call_method0(intern!(py, "as_tuple"))
+        .and_then(PyAny::extract)
+        .map_err(py_err_se_err)?;
+    let Ok(exponent) = exponent.extract::<i64>() else {
+        return serializer.serialize_str(&value.to_string());
+    };
+    let mut mantissa = String::with_capacity(digits.len() + 1);
+    if sign == 1 {
+        mantissa.push('-');
+    }
+    for digit in digits {
+        let digit: u8 = digit.extract().map_err(py_err_se_err)?;
+        mantissa.push(char::from(b'0' + digit));
+    }
+    let mantissa = serde_json::Number::from_str(&mantissa).map_err(py_err_se_err)?;
+    serializer.serialize_newtype_struct(DECIMAL_FRACTION_TAG, &(exponent, mantissa))
+}
@@ -7,7 +7,7 @@ use serde::Serialize;
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
-use crate::serializers::filter::SchemaFilter;
+use crate::serializers::filter::{filter_needs_value, SchemaFilter};
 use crate::serializers::shared::{BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
 use crate::tools::SchemaDict;
 
@@ -17,6 +17,9 @@ use super::Extra;
 #[derive(Debug, Clone)]
 pub(super) struct ComputedFields(Vec<ComputedField>);
 
+/// The value of a computed field which wasn't excluded, and the `include` and `exclude` for inside it
+type FilteredValue<'py> = (&'py PyAny, Option<&'py PyAny>, Option<&'py PyAny>);
+
 impl ComputedFields {
     pub fn new(
         schema: &PyDict,
@@ -72,28 +75,24 @@ impl ComputedFields {
             return Ok(());
         }
         for computed_field in &self.0 {
-            let property_name_py = computed_field.property_name_py.as_ref(model.py());
-            let value = model.getattr(property_name_py).map_err(py_err_se_err)?;
-            if extra.exclude_none && value.is_none() {
-                return Ok(());
-            }
-            if let Some((next_include, next_exclude)) = filter
-                .key_filter(property_name_py, include, exclude)
+            let Some((value, next_include, next_exclude)) = computed_field
+                .filtered_value(model, filter, include, exclude)
                 .map_err(py_err_se_err)?
-            {
-                let cfs = ComputedFieldSerializer {
-                    model,
-                    computed_field,
-                    include: next_include,
-                    exclude: next_exclude,
-                    extra,
-                };
-                let key = match extra.by_alias {
-                    true => computed_field.alias.as_str(),
-                    false => computed_field.property_name.as_str(),
-                };
-                map.serialize_entry(key, &cfs)?;
+            else {
+                continue;
+            };
+            if extra.exclude_none && value.is_none() {
+                continue;
             }
+            // the value the filter was applied to is serialized, rather than calling the property again
+            let cfs = ComputedFieldSerializer {
+                value,
+                computed_field,
+                include: next_include,
+                exclude: next_exclude,
+                extra,
+            };
+            map.serialize_entry(computed_field.key(extra), &cfs)?;
         }
         Ok(())
     }
@@ -140,11 +139,7 @@ impl ComputedField {
         extra: &Extra,
     ) -> PyResult<()> {
         let py = model.py();
-        let property_name_py = self.property_name_py.as_ref(py);
-
-        if let Some((next_include, next_exclude)) = filter.key_filter(property_name_py, include, exclude)? {
-            let next_value = model.getattr(property_name_py)?;
-
+        if let Some((next_value, next_include, next_exclude)) = self.filtered_value(model, filter, include, exclude)? {
             let value = self
                 .serializer
                 .to_python(next_value, next_include, next_exclude, extra)?;
@@ -153,16 +148,52 @@ impl ComputedField {
             }
             let key = match extra.by_alias {
                 true => self.alias_py.as_ref(py),
-                false => property_name_py,
+                false => self.property_name_py.as_ref(py),
             };
             output_dict.set_item(key, value)?;
         }
         Ok(())
     }
+
+    /// Apply `include` and `exclude` to this field, returning the property's value and the filters for
+    /// inside it, or `None` if it's excluded, the property is only got before filtering if a predicate
+    /// needs its value, so an excluded property isn't called
+    fn filtered_value<'py>(
+        &self,
+        model: &'py PyAny,
+        filter: &SchemaFilter<isize>,
+        include: Option<&'py PyAny>,
+        exclude: Option<&'py PyAny>,
+    ) -> PyResult<Option<FilteredValue<'py>>> {
+        let py = model.py();
+        let property_name_py = self.property_name_py.as_ref(py);
+        let value = match filter_needs_value(include, exclude) {
+            true => Some(model.getattr(property_name_py)?),
+            false => None,
+        };
+        let filter_value = value.unwrap_or_else(|| py.None().into_ref(py));
+        let Some((next_include, next_exclude)) = filter.key_filter(property_name_py, filter_value, include, exclude)?
+        else {
+            return Ok(None);
+        };
+        let value = match value {
+            Some(value) => value,
+            None => model.getattr(property_name_py)?,
+        };
+        Ok(Some((value, next_include, next_exclude)))
+    }
+
+    /// The key in the output, the alias or the property name depending on `by_alias` for this call
+    fn key(&self, extra: &Extra) -> &str {
+        match extra.by_alias {
+            true => self.alias.as_str(),
+            false => self.property_name.as_str(),
+        }
+    }
 }
 
 pub(crate) struct ComputedFieldSerializer<'py> {
-    model: &'py PyAny,
+    value: &'py PyAny,
     computed_field: &'py ComputedField,
     include: Option<&'py PyAny>,
     exclude: Option<&'py PyAny>,
@@ -181,11 +212,8 @@ impl_py_gc_traverse!(ComputedFieldSerializer<'_> { computed_field });
 
 impl<'py> Serialize for ComputedFieldSerializer<'py> {
     fn serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let py = self.model.py();
-        let property_name_py = self.computed_field.property_name_py.as_ref(py);
-        let next_value = self.model.getattr(property_name_py).map_err(py_err_se_err)?;
         let s = PydanticSerializer::new(
-            next_value,
+            self.value,
             &self.computed_field.serializer,
             self.include,
             self.exclude,
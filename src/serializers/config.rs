@@ -6,34 +6,69 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDelta, PyDict};
 use pyo3::{intern, PyNativeType};
 
-use serde::ser::Error;
+use serde::ser::{Error, SerializeMap};
+use serde::Serialize;
 
-use crate::build_tools::py_schema_err;
-use crate::input::EitherTimedelta;
+use crate::build_tools::{is_deterministic, py_schema_err};
+use crate::input::{EitherTimedelta, Int};
 use crate::tools::SchemaDict;
 
-use super::errors::py_err_se_err;
+use super::errors::{py_err_se_err, PydanticSerializationError, SERIALIZATION_ERR_MARKER};
+use super::extra::RecursionStop;
 
 #[derive(Debug, Clone)]
 pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
     pub bytes_mode: BytesMode,
+    pub bigint_mode: BigIntMode,
+    pub inf_nan_mode: InfNanMode,
+    pub circular_ref_mode: CircularRefMode,
+    pub max_depth: Option<u16>,
+    pub recursion_limit: Option<u16>,
+    pub deterministic: bool,
 }
 
 impl SerializationConfig {
     pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
+        let bigint_mode = BigIntMode::from_config(config)?;
+        let circular_ref_mode = CircularRefMode::from_config(config)?;
+        let deterministic = is_deterministic(config)?;
+        let (inf_nan_mode, max_depth, recursion_limit) = match config {
+            Some(config_dict) => {
+                let py = config_dict.py();
+                (
+                    config_dict.get_as(intern!(py, "ser_json_inf_nan"))?.unwrap_or_default(),
+                    config_dict.get_as(intern!(py, "ser_max_depth"))?,
+                    config_dict.get_as(intern!(py, "recursion_limit"))?,
+                )
+            }
+            None => (InfNanMode::default(), None, None),
+        };
         Ok(Self {
             timedelta_mode,
             bytes_mode,
+            bigint_mode,
+            inf_nan_mode,
+            circular_ref_mode,
+            max_depth,
+            recursion_limit,
+            deterministic,
         })
     }
 
-    pub fn from_args(timedelta_mode: &str, bytes_mode: &str) -> PyResult<Self> {
+    pub fn from_args(timedelta_mode: &str, bytes_mode: &str, bigint_mode: &str) -> PyResult<Self> {
         Ok(Self {
             timedelta_mode: TimedeltaMode::from_str(timedelta_mode)?,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
+            bigint_mode: BigIntMode::from_str(bigint_mode)?,
+            // `to_json` has always written `Infinity` and `NaN` for non-finite floats
+            inf_nan_mode: InfNanMode::Constants,
+            circular_ref_mode: CircularRefMode::default(),
+            max_depth: None,
+            recursion_limit: None,
+            deterministic: is_deterministic(None)?,
         })
     }
 }
@@ -43,6 +78,7 @@ pub(crate) enum TimedeltaMode {
     #[default]
     Iso8601,
     Float,
+    SecondsInt,
 }
 
 impl FromStr for TimedeltaMode {
@@ -52,8 +88,9 @@ impl FromStr for TimedeltaMode {
         match s {
             "iso8601" => Ok(Self::Iso8601),
             "float" => Ok(Self::Float),
+            "seconds_int" => Ok(Self::SecondsInt),
             s => py_schema_err!(
-                "Invalid timedelta serialization mode: `{}`, expected `iso8601` or `float`",
+                "Invalid timedelta serialization mode: `{}`, expected `iso8601`, `float` or `seconds_int`",
                 s
             ),
         }
@@ -86,6 +123,10 @@ impl TimedeltaMode {
                 let seconds = Self::total_seconds(py_timedelta)?;
                 Ok(seconds.into_py(py))
             }
+            Self::SecondsInt => {
+                let d = either_delta.to_duration()?;
+                Ok(d.signed_total_seconds().into_py(py))
+            }
         }
     }
 
@@ -100,6 +141,10 @@ impl TimedeltaMode {
                 let seconds: f64 = Self::total_seconds(py_timedelta)?.extract()?;
                 Ok(seconds.to_string().into())
             }
+            Self::SecondsInt => {
+                let d = either_delta.to_duration()?;
+                Ok(d.signed_total_seconds().to_string().into())
+            }
         }
     }
 
@@ -120,6 +165,10 @@ impl TimedeltaMode {
                 let seconds: f64 = seconds.extract().map_err(py_err_se_err)?;
                 serializer.serialize_f64(seconds)
             }
+            Self::SecondsInt => {
+                let d = either_delta.to_duration().map_err(py_err_se_err)?;
+                serializer.serialize_i64(d.signed_total_seconds())
+            }
         }
     }
 }
@@ -129,6 +178,7 @@ pub(crate) enum BytesMode {
     #[default]
     Utf8,
     Base64,
+    Base64Url,
     Hex,
 }
 
@@ -138,7 +188,8 @@ impl FromStr for BytesMode {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "utf8" => Ok(Self::Utf8),
-            "base64" => Ok(Self::Base64),
+            // `ser_json_bytes='base64'` has always used the URL safe alphabet
+            "base64" => Ok(Self::Base64Url),
             "hex" => Ok(Self::Hex),
             s => py_schema_err!(
                 "Invalid bytes serialization mode: `{}`, expected `utf8`, `base64` or `hex`",
@@ -157,12 +208,27 @@ impl BytesMode {
         raw_mode.map_or_else(|| Ok(Self::default()), Self::from_str)
     }
 
+    /// `encoding` on a bytes schema, which overrides `ser_json_bytes`
+    pub fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<&str>(intern!(schema.py(), "encoding"))? {
+            Some("base64") => Ok(Some(Self::Base64)),
+            Some("base64url") => Ok(Some(Self::Base64Url)),
+            Some("hex") => Ok(Some(Self::Hex)),
+            Some(s) => py_schema_err!(
+                "Invalid bytes encoding: `{}`, expected `base64`, `base64url` or `hex`",
+                s
+            ),
+            None => Ok(None),
+        }
+    }
+
     pub fn bytes_to_string<'py>(&self, py: Python, bytes: &'py [u8]) -> PyResult<Cow<'py, str>> {
         match self {
             Self::Utf8 => from_utf8(bytes)
                 .map_err(|err| utf8_py_error(py, err, bytes))
                 .map(Cow::Borrowed),
-            Self::Base64 => Ok(Cow::Owned(base64::engine::general_purpose::URL_SAFE.encode(bytes))),
+            Self::Base64 => Ok(Cow::Owned(base64::engine::general_purpose::STANDARD.encode(bytes))),
+            Self::Base64Url => Ok(Cow::Owned(base64::engine::general_purpose::URL_SAFE.encode(bytes))),
             Self::Hex => Ok(Cow::Owned(
                 bytes.iter().fold(String::new(), |acc, b| acc + &format!("{b:02x}")),
             )),
@@ -170,12 +236,17 @@ impl BytesMode {
     }
 
     pub fn serialize_bytes<S: serde::ser::Serializer>(&self, bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        // binary formats like CBOR can represent bytes directly
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(bytes);
+        }
         match self {
             Self::Utf8 => match from_utf8(bytes) {
                 Ok(s) => serializer.serialize_str(s),
                 Err(e) => Err(Error::custom(e.to_string())),
             },
-            Self::Base64 => serializer.serialize_str(&base64::engine::general_purpose::URL_SAFE.encode(bytes)),
+            Self::Base64 => serializer.serialize_str(&base64::engine::general_purpose::STANDARD.encode(bytes)),
+            Self::Base64Url => serializer.serialize_str(&base64::engine::general_purpose::URL_SAFE.encode(bytes)),
             Self::Hex => {
                 serializer.serialize_str(&bytes.iter().fold(String::new(), |acc, b| acc + &format!("{b:02x}")))
             }
@@ -183,6 +254,165 @@ impl BytesMode {
     }
 }
 
+/// What to output in place of a value which can't be serialized because it contains a circular reference,
+/// or because it's nested deeper than `ser_max_depth`
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CircularRefMode {
+    #[default]
+    Error,
+    Null,
+    Marker,
+}
+
+impl FromStr for CircularRefMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "null" => Ok(Self::Null),
+            "marker" => Ok(Self::Marker),
+            s => py_schema_err!(
+                "Invalid circular reference serialization mode: `{}`, expected `error`, `null` or `marker`",
+                s
+            ),
+        }
+    }
+}
+
+impl CircularRefMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let raw_mode = config_dict.get_as::<&str>(intern!(config_dict.py(), "ser_circular_ref"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), Self::from_str)
+    }
+
+    /// The marker is a dict with a single key, `$circular_ref` or `$max_depth`, and the `id()` of the value
+    fn marker_key(stop: RecursionStop) -> &'static str {
+        match stop {
+            RecursionStop::Circular => "$circular_ref",
+            RecursionStop::DepthExceeded => "$max_depth",
+        }
+    }
+
+    pub fn to_python(&self, value: &PyAny, stop: RecursionStop) -> PyResult<PyObject> {
+        let py = value.py();
+        match self {
+            Self::Error => Err(stop.to_py_err()),
+            Self::Null => Ok(py.None()),
+            Self::Marker => {
+                let marker = PyDict::new(py);
+                marker.set_item(Self::marker_key(stop), value.as_ptr() as usize)?;
+                Ok(marker.into_py(py))
+            }
+        }
+    }
+
+    pub fn serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        stop: RecursionStop,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Error => Err(py_err_se_err(stop.to_py_err())),
+            Self::Null => serializer.serialize_none(),
+            Self::Marker => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(Self::marker_key(stop), &(value.as_ptr() as usize))?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// The largest integer which can be represented exactly by an IEEE 754 double, e.g. `Number.MAX_SAFE_INTEGER`
+/// in JavaScript
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) enum BigIntMode {
+    #[default]
+    Number,
+    String,
+    Error,
+}
+
+impl FromStr for BigIntMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "number" => Ok(Self::Number),
+            "string" => Ok(Self::String),
+            "error" => Ok(Self::Error),
+            s => py_schema_err!(
+                "Invalid bigint serialization mode: `{}`, expected `number`, `string` or `error`",
+                s
+            ),
+        }
+    }
+}
+
+impl BigIntMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let raw_mode = config_dict.get_as::<&str>(intern!(config_dict.py(), "ser_json_bigint"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), Self::from_str)
+    }
+
+    fn is_safe(int: &Int) -> bool {
+        match int {
+            Int::I64(i) => (-MAX_SAFE_INTEGER..=MAX_SAFE_INTEGER).contains(i),
+            Int::Big(_) => false,
+        }
+    }
+
+    fn int_to_string(int: &Int) -> String {
+        match int {
+            Int::I64(i) => i.to_string(),
+            Int::Big(b) => b.to_string(),
+        }
+    }
+
+    fn unsafe_int_error(int: &Int) -> String {
+        let int = Self::int_to_string(int);
+        format!("Integer {int} is too large to be represented exactly in JSON, set `ser_json_bigint` to allow it")
+    }
+
+    /// Convert an int to the value used for it in JSON mode, `value` is returned unchanged unless it's too large
+    /// to be represented exactly as a JSON number
+    pub fn int_to_json(&self, py: Python, value: &PyAny) -> PyResult<PyObject> {
+        if *self == Self::Number {
+            return Ok(value.into_py(py));
+        }
+        let int: Int = value.extract()?;
+        if Self::is_safe(&int) {
+            Ok(value.into_py(py))
+        } else if *self == Self::String {
+            Ok(Self::int_to_string(&int).into_py(py))
+        } else {
+            Err(PydanticSerializationError::new_err(Self::unsafe_int_error(&int)))
+        }
+    }
+
+    pub fn serialize_int<S: serde::ser::Serializer>(&self, int: &Int, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            _ if Self::is_safe(int) => int.serialize(serializer),
+            Self::Number => int.serialize(serializer),
+            Self::String => serializer.serialize_str(&Self::int_to_string(int)),
+            Self::Error => Err(Error::custom(format!(
+                "{SERIALIZATION_ERR_MARKER}{}",
+                Self::unsafe_int_error(int)
+            ))),
+        }
+    }
+}
+
 pub fn utf8_py_error(py: Python, err: Utf8Error, data: &[u8]) -> PyErr {
     match pyo3::exceptions::PyUnicodeDecodeError::new_utf8(py, data, err) {
         Ok(decode_err) => PyErr::from_value(decode_err),
@@ -195,6 +425,7 @@ pub(crate) enum InfNanMode {
     #[default]
     Null,
     Constants,
+    Error,
 }
 
 impl FromStr for InfNanMode {
@@ -204,14 +435,21 @@ impl FromStr for InfNanMode {
         match s {
             "null" => Ok(Self::Null),
             "constants" => Ok(Self::Constants),
+            "error" => Ok(Self::Error),
             s => py_schema_err!(
-                "Invalid inf_nan serialization mode: `{}`, expected `null` or `constants`",
+                "Invalid inf_nan serialization mode: `{}`, expected `null`, `constants` or `error`",
                 s
             ),
         }
     }
 }
 
+impl InfNanMode {
+    pub fn non_finite_error(value: f64) -> String {
+        format!("Float {value} is not finite and can't be serialized to JSON, set `ser_json_inf_nan` to allow it")
+    }
+}
+
 impl FromPyObject<'_> for InfNanMode {
     fn extract(ob: &'_ PyAny) -> PyResult<Self> {
         let s = ob.extract::<&str>()?;
@@ -1,6 +1,7 @@
 use std::fmt;
 
-use pyo3::exceptions::PyValueError;
+use pyo3::create_exception;
+use pyo3::exceptions::{PyUserWarning, PyValueError};
 use pyo3::prelude::*;
 
 use serde::ser;
@@ -120,3 +121,10 @@ impl PydanticSerializationUnexpectedValue {
         format!("PydanticSerializationUnexpectedValue({})", self.__str__())
     }
 }
+
+create_exception!(
+    pydantic_core._pydantic_core,
+    PydanticSerializationWarning,
+    PyUserWarning,
+    "Emitted when values don't match the types they're serialized as, can be filtered with `warnings.filterwarnings`."
+);
@@ -1,15 +1,18 @@
 use std::cell::RefCell;
 use std::fmt;
 
+use ahash::{AHashMap, AHashSet};
 use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
 
 use serde::ser::Error;
 
 use super::config::SerializationConfig;
-use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
-use super::ob_type::ObTypeLookup;
+use super::errors::{PydanticSerializationUnexpectedValue, PydanticSerializationWarning, UNEXPECTED_TYPE_SER_MARKER};
+use super::ob_type::{ObType, ObTypeLookup};
+use crate::errors::{AsLocItem, LocItem};
 use crate::recursion_guard::RecursionGuard;
 
 /// this is ugly, would be much better if extra could be stored in `SerializationState`
@@ -21,10 +24,10 @@ pub(crate) struct SerializationState {
 }
 
 impl SerializationState {
-    pub fn new(timedelta_mode: &str, bytes_mode: &str) -> PyResult<Self> {
-        let warnings = CollectWarnings::new(false);
+    pub fn new(timedelta_mode: &str, bytes_mode: &str, bigint_mode: &str) -> PyResult<Self> {
+        let warnings = CollectWarnings::new(false, None);
         let rec_guard = SerRecursionGuard::default();
-        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode)?;
+        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, bigint_mode)?;
         Ok(Self {
             warnings,
             rec_guard,
@@ -87,6 +90,16 @@ pub(crate) struct Extra<'a> {
     pub field_name: Option<&'a str>,
     pub serialize_unknown: bool,
     pub fallback: Option<&'a PyAny>,
+    // keep models nested within another model as instances instead of serializing them
+    pub keep_models: bool,
+    // serialize model instances which appear more than once as references, see `SharedRefs`
+    pub shared_refs: Option<&'a SharedRefs>,
+    // `ser_json_any_dispatch` of the innermost `any` schema, applied to every value serialized by inference
+    pub any_dispatch: Option<&'a PyDict>,
+    // serialize the real values of `secret` schemas instead of masking them
+    pub reveal_secrets: bool,
+    // where the value being serialized is, used in warnings
+    pub path: Option<&'a SerPath<'a>>,
 }
 
 impl<'a> Extra<'a> {
@@ -121,12 +134,66 @@ impl<'a> Extra<'a> {
             field_name: None,
             serialize_unknown,
             fallback,
+            keep_models: false,
+            shared_refs: None,
+            any_dispatch: None,
+            reveal_secrets: false,
+            path: None,
         }
     }
 
     pub fn serialize_infer<'py>(&'py self, value: &'py PyAny) -> super::infer::SerializeInfer<'py> {
         super::infer::SerializeInfer::new(value, None, None, self)
     }
+
+    /// Whether a model should be returned as is, because it's nested within another model and `keep_models` is set
+    pub fn keep_nested_model(&self) -> bool {
+        self.keep_models && self.model.is_some()
+    }
+
+    /// The path of an item within the current value, to be set as `path` on the `Extra` used to serialize it
+    pub fn path_item(&self, item: PathItem<'a>) -> SerPath<'a> {
+        SerPath {
+            parent: self.path,
+            item,
+        }
+    }
+}
+
+/// A step into a field, item or dict value
+#[derive(Clone, Copy)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) enum PathItem<'a> {
+    Field(&'a str),
+    Index(usize),
+    Key(&'a PyAny),
+}
+
+/// The location of the value being serialized, as a linked list on the stack from the innermost item outwards
+/// so nothing is allocated unless a warning needs it
+#[derive(Clone, Copy)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct SerPath<'a> {
+    parent: Option<&'a SerPath<'a>>,
+    item: PathItem<'a>,
+}
+
+impl SerPath<'_> {
+    /// Location items, outermost first
+    fn loc(&self) -> Vec<LocItem> {
+        let mut loc = Vec::new();
+        let mut next = Some(self);
+        while let Some(path) = next {
+            loc.push(match path.item {
+                PathItem::Field(name) => name.into(),
+                PathItem::Index(index) => index.into(),
+                PathItem::Key(key) => key.as_loc_item(),
+            });
+            next = path.parent;
+        }
+        loc.reverse();
+        loc
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -163,13 +230,16 @@ pub(crate) struct ExtraOwned {
     field_name: Option<String>,
     serialize_unknown: bool,
     fallback: Option<PyObject>,
+    keep_models: bool,
+    any_dispatch: Option<Py<PyDict>>,
+    reveal_secrets: bool,
 }
 
 impl ExtraOwned {
     pub fn new(extra: &Extra) -> Self {
         Self {
             mode: extra.mode.clone(),
-            warnings: extra.warnings.clone(),
+            warnings: extra.warnings.new_collection(),
             by_alias: extra.by_alias,
             exclude_unset: extra.exclude_unset,
             exclude_defaults: extra.exclude_defaults,
@@ -182,6 +252,9 @@ impl ExtraOwned {
             field_name: extra.field_name.map(ToString::to_string),
             serialize_unknown: extra.serialize_unknown,
             fallback: extra.fallback.map(Into::into),
+            keep_models: extra.keep_models,
+            any_dispatch: extra.any_dispatch.map(Into::into),
+            reveal_secrets: extra.reveal_secrets,
         }
     }
 
@@ -202,6 +275,13 @@ impl ExtraOwned {
             field_name: self.field_name.as_deref(),
             serialize_unknown: self.serialize_unknown,
             fallback: self.fallback.as_ref().map(|m| m.as_ref(py)),
+            keep_models: self.keep_models,
+            // shared references aren't tracked through function serializers
+            shared_refs: None,
+            any_dispatch: self.any_dispatch.as_ref().map(|d| d.as_ref(py)),
+            // nor is the path, warnings from within a function serializer are reported without it
+            path: None,
+            reveal_secrets: self.reveal_secrets,
         }
     }
 }
@@ -211,6 +291,8 @@ impl ExtraOwned {
 pub(crate) enum SerMode {
     Python,
     Json,
+    /// like `Json`, but stops at JSON compatible python objects rather than encoding them
+    JsonCompatible,
     Other(String),
 }
 
@@ -219,6 +301,7 @@ impl fmt::Display for SerMode {
         match self {
             SerMode::Python => write!(f, "python"),
             SerMode::Json => write!(f, "json"),
+            SerMode::JsonCompatible => write!(f, "json-compatible-dict"),
             SerMode::Other(s) => write!(f, "{s}"),
         }
     }
@@ -226,7 +309,7 @@ impl fmt::Display for SerMode {
 
 impl SerMode {
     pub fn is_json(&self) -> bool {
-        matches!(self, SerMode::Json)
+        matches!(self, SerMode::Json | SerMode::JsonCompatible)
     }
 }
 
@@ -234,6 +317,7 @@ impl From<Option<&str>> for SerMode {
     fn from(s: Option<&str>) -> Self {
         match s {
             Some("json") => SerMode::Json,
+            Some("json-compatible-dict") => SerMode::JsonCompatible,
             Some("python") => SerMode::Python,
             Some(other) => SerMode::Other(other.to_string()),
             None => SerMode::Python,
@@ -246,29 +330,132 @@ impl ToPyObject for SerMode {
         match self {
             SerMode::Python => intern!(py, "python").to_object(py),
             SerMode::Json => intern!(py, "json").to_object(py),
+            SerMode::JsonCompatible => intern!(py, "json-compatible-dict").to_object(py),
             SerMode::Other(s) => s.to_object(py),
         }
     }
 }
 
+/// A warning collected during serialization, exposed to Python as a dict in the `warnings_collector` list,
+/// the same problem at several locations is reported once with all its locations
 #[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+struct SerWarning {
+    kind: SerWarningKind,
+    msg: String,
+    locs: Vec<Vec<LocItem>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+enum SerWarningKind {
+    // the value's type didn't match the schema, so it was serialized by inferring its type
+    UnexpectedType { expected: String, input_type: String },
+    // a function serializer raised `PydanticSerializationUnexpectedValue`
+    UnexpectedValue,
+}
+
+/// Warnings are deduplicated on their kind, including `expected` and `input_type`, and message
+type SerWarningKey = (SerWarningKind, String);
+
+impl SerWarning {
+    /// The message including the locations, if the value is nested
+    fn message(&self) -> String {
+        let locs: Vec<String> = self
+            .locs
+            .iter()
+            .filter(|loc| !loc.is_empty())
+            .map(|loc| {
+                let loc: Vec<String> = loc.iter().map(ToString::to_string).collect();
+                format!("`{}`", loc.join("."))
+            })
+            .collect();
+        if locs.is_empty() {
+            self.msg.clone()
+        } else {
+            format!("{} (at {})", self.msg, locs.join(", "))
+        }
+    }
+
+    fn as_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let dict = PyDict::new(py);
+        match self.kind {
+            SerWarningKind::UnexpectedType {
+                ref expected,
+                ref input_type,
+            } => {
+                dict.set_item("type", "unexpected_type")?;
+                dict.set_item("expected", expected)?;
+                dict.set_item("input_type", input_type)?;
+            }
+            SerWarningKind::UnexpectedValue => dict.set_item("type", "unexpected_value")?,
+        }
+        let locs = self
+            .locs
+            .iter()
+            .map(|loc| PyTuple::new(py, loc.iter().map(|item| item.to_object(py))));
+        dict.set_item("locs", PyTuple::new(py, locs))?;
+        dict.set_item("msg", &self.msg)?;
+        Ok(dict)
+    }
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub(crate) struct CollectWarnings {
     active: bool,
-    warnings: RefCell<Option<Vec<String>>>,
+    // whether to emit a `PydanticSerializationWarning`
+    emit: bool,
+    // a list the details of each warning are appended to
+    collector: Option<Py<PyList>>,
+    warnings: RefCell<Vec<SerWarning>>,
+    // index of each distinct warning in `warnings`
+    seen: RefCell<AHashMap<SerWarningKey, usize>>,
+    // the locations already recorded for each warning index
+    seen_locs: RefCell<AHashSet<(usize, Vec<LocItem>)>>,
+}
+
+// the collector is cloned with the GIL held so its reference count is updated immediately
+impl Clone for CollectWarnings {
+    fn clone(&self) -> Self {
+        Self {
+            active: self.active,
+            emit: self.emit,
+            collector: Python::with_gil(|py| self.collector.as_ref().map(|collector| collector.clone_ref(py))),
+            warnings: self.warnings.clone(),
+            seen: self.seen.clone(),
+            seen_locs: self.seen_locs.clone(),
+        }
+    }
 }
 
 impl CollectWarnings {
-    pub(crate) fn new(active: bool) -> Self {
+    pub(crate) fn new(warnings: bool, collector: Option<&PyList>) -> Self {
         Self {
-            active,
-            warnings: RefCell::new(None),
+            active: warnings || collector.is_some(),
+            emit: warnings,
+            collector: collector.map(Into::into),
+            warnings: RefCell::new(Vec::new()),
+            seen: RefCell::new(AHashMap::new()),
+            seen_locs: RefCell::new(AHashSet::new()),
         }
     }
 
-    pub fn custom_warning(&self, warning: String) {
+    /// The same settings and collector without the warnings collected so far, used for function serializers which
+    /// report their own warnings
+    pub(crate) fn new_collection(&self) -> Self {
+        Self {
+            active: self.active,
+            emit: self.emit,
+            collector: self.collector.clone(),
+            warnings: RefCell::new(Vec::new()),
+            seen: RefCell::new(AHashMap::new()),
+            seen_locs: RefCell::new(AHashSet::new()),
+        }
+    }
+
+    pub fn custom_warning(&self, warning: String, extra: &Extra) {
         if self.active {
-            self.add_warning(warning);
+            self.add_warning(SerWarningKind::UnexpectedValue, warning, extra);
         }
     }
 
@@ -279,7 +466,7 @@ impl CollectWarnings {
         } else if extra.check.enabled() {
             Err(PydanticSerializationUnexpectedValue::new_err(None))
         } else {
-            self.fallback_warning(field_type, value);
+            self.fallback_warning(field_type, value, extra);
             Ok(())
         }
     }
@@ -299,42 +486,67 @@ impl CollectWarnings {
             // in particular, in future we could allow errors instead of warnings on fallback
             Err(S::Error::custom(UNEXPECTED_TYPE_SER_MARKER))
         } else {
-            self.fallback_warning(field_type, value);
+            self.fallback_warning(field_type, value, extra);
             Ok(())
         }
     }
 
-    fn fallback_warning(&self, field_type: &str, value: &PyAny) {
-        if self.active {
+    fn fallback_warning(&self, field_type: &str, value: &PyAny, extra: &Extra) {
+        // unknown types are passed to the user's `fallback` function, which is expected to handle them
+        let handled_by_fallback =
+            || extra.fallback.is_some() && matches!(extra.ob_type_lookup.get_type(value), ObType::Unknown);
+        if self.active && !handled_by_fallback() {
             let type_name = value.get_type().name().unwrap_or("<unknown python object>");
-            self.add_warning(format!(
-                "Expected `{field_type}` but got `{type_name}` - serialized value may not be as expected"
-            ));
+            let msg =
+                format!("Expected `{field_type}` but got `{type_name}` - serialized value may not be as expected");
+            let kind = SerWarningKind::UnexpectedType {
+                expected: field_type.to_string(),
+                input_type: type_name.to_string(),
+            };
+            self.add_warning(kind, msg, extra);
         }
     }
 
-    fn add_warning(&self, message: String) {
-        let mut op_warnings = self.warnings.borrow_mut();
-        if let Some(ref mut warnings) = *op_warnings {
-            warnings.push(message);
+    fn add_warning(&self, kind: SerWarningKind, msg: String, extra: &Extra) {
+        let loc = extra.path.map(SerPath::loc).unwrap_or_default();
+        let mut warnings = self.warnings.borrow_mut();
+        let mut seen = self.seen.borrow_mut();
+        let key = (kind, msg);
+        if let Some(&index) = seen.get(&key) {
+            // the same problem is reported once per call, with every location it occurs at
+            if self.seen_locs.borrow_mut().insert((index, loc.clone())) {
+                warnings[index].locs.push(loc);
+            }
         } else {
-            *op_warnings = Some(vec![message]);
+            let index = warnings.len();
+            self.seen_locs.borrow_mut().insert((index, loc.clone()));
+            let (kind, msg) = key.clone();
+            seen.insert(key, index);
+            warnings.push(SerWarning {
+                kind,
+                msg,
+                locs: vec![loc],
+            });
         }
     }
 
     pub fn final_check(&self, py: Python) -> PyResult<()> {
-        if self.active {
-            match *self.warnings.borrow() {
-                Some(ref warnings) => {
-                    let message = format!("Pydantic serializer warnings:\n  {}", warnings.join("\n  "));
-                    let user_warning_type = py.import("builtins")?.getattr("UserWarning")?;
-                    PyErr::warn(py, user_warning_type, &message, 0)
-                }
-                _ => Ok(()),
+        let warnings = self.warnings.borrow();
+        if warnings.is_empty() {
+            return Ok(());
+        }
+        if let Some(ref collector) = self.collector {
+            let collector = collector.as_ref(py);
+            for warning in warnings.iter() {
+                collector.append(warning.as_dict(py)?)?;
             }
-        } else {
-            Ok(())
         }
+        if self.emit {
+            let messages: Vec<String> = warnings.iter().map(SerWarning::message).collect();
+            let message = format!("Pydantic serializer warnings:\n  {}", messages.join("\n  "));
+            PyErr::warn(py, py.get_type::<PydanticSerializationWarning>(), &message, 0)?;
+        }
+        Ok(())
     }
 }
 
@@ -344,17 +556,45 @@ pub struct SerRecursionGuard {
     guard: RefCell<RecursionGuard>,
 }
 
+/// Why serialization can't recurse into a value, what happens next depends on `ser_circular_ref`
+#[derive(Clone, Copy)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) enum RecursionStop {
+    Circular,
+    DepthExceeded,
+}
+
+impl RecursionStop {
+    pub fn to_py_err(self) -> PyErr {
+        match self {
+            Self::Circular => PyValueError::new_err("Circular reference detected (id repeated)"),
+            Self::DepthExceeded => PyValueError::new_err("Circular reference detected (depth exceeded)"),
+        }
+    }
+}
+
 impl SerRecursionGuard {
-    pub fn add(&self, value: &PyAny, def_ref_id: usize) -> PyResult<usize> {
+    /// `limit` is the `recursion_limit` setting, see `RecursionGuard::with_limit`
+    pub fn with_limit(limit: Option<u16>) -> Self {
+        Self {
+            guard: RefCell::new(RecursionGuard::with_limit(limit)),
+        }
+    }
+
+    /// `max_depth` is the `ser_max_depth` config setting, it only applies if it's lower than the recursion limit
+    pub fn add(&self, value: &PyAny, def_ref_id: usize, max_depth: Option<u16>) -> Result<usize, RecursionStop> {
         // https://doc.rust-lang.org/std/collections/struct.HashSet.html#method.insert
         // "If the set did not have this value present, `true` is returned."
         let id = value.as_ptr() as usize;
         let mut guard = self.guard.borrow_mut();
 
         if guard.contains_or_insert(id, def_ref_id) {
-            Err(PyValueError::new_err("Circular reference detected (id repeated)"))
-        } else if guard.incr_depth() {
-            Err(PyValueError::new_err("Circular reference detected (depth exceeded)"))
+            Err(RecursionStop::Circular)
+        } else if guard.incr_depth() || max_depth.map_or(false, |max_depth| guard.depth() > max_depth) {
+            // serialization may continue, so undo the insert
+            guard.decr_depth();
+            guard.remove(id, def_ref_id);
+            Err(RecursionStop::DepthExceeded)
         } else {
             Ok(id)
         }
@@ -366,3 +606,41 @@ impl SerRecursionGuard {
         guard.remove(id, def_ref_id);
     }
 }
+
+/// Used with `shared_refs`, the first occurrence of a model instance is serialized in full with an extra `"$id"`
+/// key and any later occurrences, including circular ones, as `{"$ref": id}`
+#[derive(Default)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct SharedRefs {
+    ids: RefCell<AHashMap<usize, usize>>,
+    // the instances are kept so their pointers can't be reused, in the order ids were assigned
+    seen: RefCell<Vec<PyObject>>,
+}
+
+impl SharedRefs {
+    /// The id of `value` if it has been serialized already
+    pub fn get(&self, value: &PyAny) -> Option<usize> {
+        self.ids.borrow().get(&(value.as_ptr() as usize)).copied()
+    }
+
+    /// Assign the next id to `value`, ids start at 1
+    pub fn add(&self, value: &PyAny) -> usize {
+        let mut seen = self.seen.borrow_mut();
+        seen.push(value.into());
+        let id = seen.len();
+        self.ids.borrow_mut().insert(value.as_ptr() as usize, id);
+        id
+    }
+
+    pub fn mark(&self) -> usize {
+        self.seen.borrow().len()
+    }
+
+    /// Forget ids assigned since `mark`, used when a union choice fails part way through serialization
+    pub fn rollback(&self, mark: usize) {
+        let mut ids = self.ids.borrow_mut();
+        for value in self.seen.borrow_mut().drain(mark..) {
+            ids.remove(&(value.as_ptr() as usize));
+        }
+    }
+}
@@ -1,21 +1,51 @@
 use std::borrow::Cow;
 
+use pyo3::exceptions::PyAttributeError;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
 use ahash::AHashMap;
 use serde::ser::SerializeMap;
 
+use crate::build_tools::py_schema_err;
 use crate::serializers::extra::SerCheck;
-use crate::PydanticSerializationUnexpectedValue;
+use crate::tools::SchemaDict;
+use crate::{PydanticSerializationError, PydanticSerializationUnexpectedValue, UnsetType};
 
 use super::computed_fields::ComputedFields;
 use super::errors::py_err_se_err;
-use super::extra::Extra;
+use super::extra::{Extra, PathItem};
 use super::filter::SchemaFilter;
 use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeInfer};
 use super::shared::PydanticSerializer;
 use super::shared::{CombinedSerializer, TypeSerializer};
+use super::type_serializers::with_default::SerDefault;
+
+/// A field's `serialization_alias`, either a key, or a path of keys to nest the value under in the output
+#[derive(Debug, Clone)]
+pub(super) enum SerAlias {
+    Key(String),
+    Path(Vec<String>),
+}
+
+impl SerAlias {
+    pub fn from_field(field_info: &PyDict) -> PyResult<Option<Self>> {
+        let py = field_info.py();
+        let Some(alias) = field_info.get_item(intern!(py, "serialization_alias"))? else {
+            return Ok(None);
+        };
+        if let Ok(key) = alias.extract::<String>() {
+            return Ok(Some(Self::Key(key)));
+        }
+        let mut path: Vec<String> = alias.extract()?;
+        match path.len() {
+            0 => py_schema_err!("serialization_alias paths should have at least one element"),
+            1 => Ok(path.pop().map(Self::Key)),
+            _ => Ok(Some(Self::Path(path))),
+        }
+    }
+}
 
 /// representation of a field for serialization
 #[derive(Debug, Clone)]
@@ -23,29 +53,59 @@ pub(super) struct SerField {
     pub key_py: Py<PyString>,
     pub alias: Option<String>,
     pub alias_py: Option<Py<PyString>>,
+    pub alias_path: Option<Vec<String>>,
+    // the serialized value's items are inlined into the parent's output
+    pub flatten: bool,
     // None serializer means exclude
     pub serializer: Option<CombinedSerializer>,
     pub required: bool,
+    // the default from the field's `default` schema when custom serialization replaces `WithDefaultSerializer`
+    pub default: Option<SerDefault>,
 }
 
-impl_py_gc_traverse!(SerField { serializer });
+impl_py_gc_traverse!(SerField { serializer, default });
 
 impl SerField {
     pub fn new(
         py: Python,
         key_py: Py<PyString>,
-        alias: Option<String>,
+        alias: Option<SerAlias>,
         serializer: Option<CombinedSerializer>,
         required: bool,
     ) -> Self {
+        let (alias, alias_path) = match alias {
+            Some(SerAlias::Key(key)) => (Some(key), None),
+            Some(SerAlias::Path(path)) => (None, Some(path)),
+            None => (None, None),
+        };
         let alias_py = alias.as_ref().map(|alias| PyString::new(py, alias.as_str()).into());
         Self {
             key_py,
             alias,
             alias_py,
+            alias_path,
+            flatten: false,
             serializer,
             required,
+            default: None,
+        }
+    }
+
+    pub fn with_flatten(mut self, flatten: bool) -> Self {
+        self.flatten = flatten;
+        self
+    }
+
+    /// Keep the default from the field's schema for `exclude_defaults` if it's a `default` schema whose
+    /// serializer is replaced by custom serialization, e.g. `function-plain`
+    pub fn with_schema_default(mut self, schema: &PyDict) -> PyResult<Self> {
+        let py = schema.py();
+        if schema.get_as::<&str>(intern!(py, "type"))? == Some("default")
+            && !matches!(self.serializer, Some(CombinedSerializer::WithDefault(_)))
+        {
+            self.default = Some(SerDefault::from_schema(schema)?);
         }
+        Ok(self)
     }
 
     pub fn get_key_py<'py>(&'py self, py: Python<'py>, extra: &Extra) -> &'py PyAny {
@@ -65,17 +125,115 @@ impl SerField {
         }
         Cow::Borrowed(key_str)
     }
+
+    pub fn get_alias_path(&self, extra: &Extra) -> Option<&[String]> {
+        match self.alias_path {
+            Some(ref path) if extra.by_alias => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Inline the items of a flattened field's serialized value into the parent's output
+fn set_flattened_items(output_dict: &PyDict, value: &PyObject, field: &SerField) -> PyResult<()> {
+    let py = output_dict.py();
+    let items = value.as_ref(py).downcast::<PyDict>().map_err(|_| {
+        PydanticSerializationError::new_err(format!(
+            "Flattened field \"{}\" should serialize to a dict",
+            field.key_py.as_ref(py)
+        ))
+    })?;
+    for (key, value) in items {
+        set_new_item(output_dict, key, value)?;
+    }
+    Ok(())
 }
 
-fn exclude_default(value: &PyAny, extra: &Extra, serializer: &CombinedSerializer) -> PyResult<bool> {
-    if extra.exclude_defaults {
-        if let Some(default) = serializer.get_default(value.py())? {
-            if value.eq(default)? {
-                return Ok(true);
+/// Set an item in the output, raising an error rather than overwriting a key which is already set,
+/// used when flattened fields mean keys can collide
+fn set_new_item(output_dict: &PyDict, key: &PyAny, value: impl ToPyObject) -> PyResult<()> {
+    if output_dict.contains(key)? {
+        return Err(PydanticSerializationError::new_err(format!(
+            "Key {} is set by more than one field, including a flattened field",
+            key.repr()?
+        )));
+    }
+    output_dict.set_item(key, value)
+}
+
+/// Set `value` in `dict` under a path of keys, creating intermediate dicts as required
+fn set_path_item(dict: &PyDict, path: &[String], value: PyObject) -> PyResult<()> {
+    let py = dict.py();
+    let Some((last, parents)) = path.split_last() else {
+        return Ok(());
+    };
+    let mut dict = dict;
+    for key in parents {
+        dict = match dict.get_item(key)? {
+            Some(existing) => existing.downcast::<PyDict>().map_err(|_| {
+                PydanticSerializationError::new_err(format!(
+                    "serialization_alias path {path:?} conflicts with the non-dict value at \"{key}\""
+                ))
+            })?,
+            None => {
+                let child = PyDict::new(py);
+                dict.set_item(key, child)?;
+                child
+            }
+        };
+    }
+    dict.set_item(last, value)
+}
+
+/// Check values nested by `serialization_alias` paths can't overwrite each other, or other fields
+fn check_alias_paths(fields: &AHashMap<String, SerField>) -> PyResult<()> {
+    let mut paths: Vec<(&str, &[String])> = fields
+        .iter()
+        .filter_map(|(name, field)| Some((name.as_str(), field.alias_path.as_deref()?)))
+        .collect();
+    if paths.is_empty() {
+        return Ok(());
+    }
+    // sorted so the error is the same every time
+    paths.sort_unstable();
+    let mut keys: Vec<(&str, &str)> = fields
+        .iter()
+        .filter(|(_, field)| field.alias_path.is_none() && field.serializer.is_some())
+        .map(|(name, field)| (name.as_str(), field.alias.as_deref().unwrap_or(name)))
+        .collect();
+    keys.sort_unstable();
+    for (index, &(name, path)) in paths.iter().enumerate() {
+        if let Some((other_name, _)) = keys.iter().find(|(_, key)| *key == path[0]) {
+            return py_schema_err!(
+                "Field `{}`: serialization_alias path {:?} conflicts with field `{}`",
+                name,
+                path,
+                other_name
+            );
+        }
+        for &(other_name, other_path) in &paths[index + 1..] {
+            if path.starts_with(other_path) || other_path.starts_with(path) {
+                return py_schema_err!(
+                    "Field `{}`: serialization_alias path {:?} conflicts with path {:?} of field `{}`",
+                    name,
+                    path,
+                    other_path,
+                    other_name
+                );
             }
         }
     }
-    Ok(false)
+    Ok(())
+}
+
+fn exclude_default(value: &PyAny, extra: &Extra, field: &SerField, serializer: &CombinedSerializer) -> PyResult<bool> {
+    if !extra.exclude_defaults {
+        Ok(false)
+    } else if let Some(ref default) = field.default {
+        default.is_default(value, serializer, extra)
+    } else {
+        serializer.is_default(value, extra)
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -98,6 +256,12 @@ pub struct GeneralFieldsSerializer {
     // isize because we look up filter via `.hash()` which returns an isize
     filter: SchemaFilter<isize>,
     required_fields: usize,
+    // when set, values which aren't dicts are serialized by reading these attributes, in order
+    attribute_keys: Option<Vec<Py<PyString>>>,
+    // whether any field has a `serialization_alias` path
+    has_alias_paths: bool,
+    // whether any field is flattened, in which case keys are checked for collisions
+    has_flatten: bool,
 }
 
 impl GeneralFieldsSerializer {
@@ -106,35 +270,55 @@ impl GeneralFieldsSerializer {
         mode: FieldsMode,
         extra_serializer: Option<CombinedSerializer>,
         computed_fields: Option<ComputedFields>,
-    ) -> Self {
+        attribute_keys: Option<Vec<Py<PyString>>>,
+    ) -> PyResult<Self> {
+        check_alias_paths(&fields)?;
         let required_fields = fields.values().filter(|f| f.required).count();
-        Self {
+        let has_alias_paths = fields.values().any(|f| f.alias_path.is_some());
+        let has_flatten = fields.values().any(|f| f.flatten);
+        Ok(Self {
             fields,
             mode,
             extra_serializer: extra_serializer.map(Box::new),
             filter: SchemaFilter::default(),
             computed_fields,
             required_fields,
-        }
+            attribute_keys,
+            has_alias_paths,
+            has_flatten,
+        })
     }
 
-    fn extract_dicts<'a>(&self, value: &'a PyAny) -> Option<(&'a PyDict, Option<&'a PyDict>)> {
+    fn extract_dicts<'a>(&self, value: &'a PyAny) -> PyResult<Option<(&'a PyDict, Option<&'a PyDict>)>> {
         match self.mode {
             FieldsMode::ModelExtra => {
                 if let Ok((main_dict, extra_dict)) = value.extract::<(&PyDict, &PyDict)>() {
-                    Some((main_dict, Some(extra_dict)))
-                } else {
-                    None
+                    return Ok(Some((main_dict, Some(extra_dict))));
                 }
             }
             _ => {
                 if let Ok(main_dict) = value.downcast::<PyDict>() {
-                    Some((main_dict, None))
-                } else {
-                    None
+                    return Ok(Some((main_dict, None)));
                 }
             }
         }
+        match self.attribute_keys {
+            Some(ref attribute_keys) => {
+                let py = value.py();
+                let main_dict = PyDict::new(py);
+                for key in attribute_keys {
+                    let key = key.as_ref(py);
+                    match value.getattr(key) {
+                        Ok(attr) => main_dict.set_item(key, attr)?,
+                        // missing attributes are treated like missing keys
+                        Err(err) if err.is_instance_of::<PyAttributeError>(py) => (),
+                        Err(err) => return Err(err),
+                    }
+                }
+                Ok(Some((main_dict, None)))
+            }
+            None => Ok(None),
+        }
     }
 }
 
@@ -168,7 +352,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
             model: extra.model.map_or_else(|| Some(value), Some),
             ..*extra
         };
-        let (main_dict, extra_dict) = if let Some(main_extra_dict) = self.extract_dicts(value) {
+        let (main_dict, extra_dict) = if let Some(main_extra_dict) = self.extract_dicts(value)? {
             main_extra_dict
         } else {
             td_extra.warnings.on_fallback_py(self.get_name(), value, &td_extra)?;
@@ -182,7 +366,8 @@ impl TypeSerializer for GeneralFieldsSerializer {
         for (key, value) in main_dict {
             let key_str = key_str(key)?;
             let op_field = self.fields.get(key_str);
-            if extra.exclude_none && value.is_none() {
+            // `UNSET` fields weren't provided, so are always left out
+            if (extra.exclude_none && value.is_none()) || UnsetType::is_unset(value) {
                 if let Some(field) = op_field {
                     if field.required {
                         used_req_fields += 1;
@@ -190,17 +375,27 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 }
                 continue;
             }
+            let field_path = td_extra.path_item(PathItem::Field(key_str));
             let extra = Extra {
                 field_name: Some(key_str),
+                path: Some(&field_path),
                 ..td_extra
             };
-            if let Some((next_include, next_exclude)) = self.filter.key_filter(key, include, exclude)? {
+            if let Some((next_include, next_exclude)) = self.filter.key_filter(key, value, include, exclude)? {
                 if let Some(field) = op_field {
                     if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(value, &extra, serializer)? {
+                        if !exclude_default(value, &extra, field, serializer)? {
                             let value = serializer.to_python(value, next_include, next_exclude, &extra)?;
-                            let output_key = field.get_key_py(output_dict.py(), &extra);
-                            output_dict.set_item(output_key, value)?;
+                            if field.flatten {
+                                set_flattened_items(output_dict, &value, field)?;
+                            } else if let Some(path) = field.get_alias_path(&extra) {
+                                set_path_item(output_dict, path, value)?;
+                            } else if self.has_flatten {
+                                set_new_item(output_dict, field.get_key_py(py, &extra), value)?;
+                            } else {
+                                let output_key = field.get_key_py(output_dict.py(), &extra);
+                                output_dict.set_item(output_key, value)?;
+                            }
                         }
                     }
 
@@ -232,7 +427,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 if extra.exclude_none && value.is_none() {
                     continue;
                 }
-                if let Some((next_include, next_exclude)) = self.filter.key_filter(key, include, exclude)? {
+                if let Some((next_include, next_exclude)) = self.filter.key_filter(key, value, include, exclude)? {
                     let value = match &self.extra_serializer {
                         Some(serializer) => serializer.to_python(value, next_include, next_exclude, extra)?,
                         None => infer_to_python(value, next_include, next_exclude, extra)?,
@@ -261,12 +456,18 @@ impl TypeSerializer for GeneralFieldsSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        let (main_dict, extra_dict) = if let Some(main_extra_dict) = self.extract_dicts(value) {
+        let (main_dict, extra_dict) = if let Some(main_extra_dict) = self.extract_dicts(value).map_err(py_err_se_err)? {
             main_extra_dict
         } else {
             extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
             return infer_serialize(value, serializer, include, exclude, extra);
         };
+        if self.has_flatten || (self.has_alias_paths && extra.by_alias) {
+            // flattened and nested values have to be gathered and checked before they're written, so build
+            // the same output as `to_python`
+            let output = self.to_python(value, include, exclude, extra).map_err(py_err_se_err)?;
+            return infer_serialize(output.as_ref(value.py()), serializer, None, None, extra);
+        }
 
         // If there is already a model registered (from a dataclass, BaseModel)
         // then do not touch it
@@ -284,20 +485,25 @@ impl TypeSerializer for GeneralFieldsSerializer {
         let mut map = serializer.serialize_map(Some(expected_len))?;
 
         for (key, value) in main_dict {
-            if extra.exclude_none && value.is_none() {
+            if (extra.exclude_none && value.is_none()) || UnsetType::is_unset(value) {
                 continue;
             }
             let key_str = key_str(key).map_err(py_err_se_err)?;
+            let field_path = td_extra.path_item(PathItem::Field(key_str));
             let extra = Extra {
                 field_name: Some(key_str),
+                path: Some(&field_path),
                 ..td_extra
             };
 
-            let filter = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
+            let filter = self
+                .filter
+                .key_filter(key, value, include, exclude)
+                .map_err(py_err_se_err)?;
             if let Some((next_include, next_exclude)) = filter {
                 if let Some(field) = self.fields.get(key_str) {
                     if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(value, &extra, serializer).map_err(py_err_se_err)? {
+                        if !exclude_default(value, &extra, field, serializer).map_err(py_err_se_err)? {
                             let s = PydanticSerializer::new(value, serializer, next_include, next_exclude, &extra);
                             let output_key = field.get_key_json(key_str, &extra);
                             map.serialize_entry(&output_key, &s)?;
@@ -317,7 +523,10 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 if extra.exclude_none && value.is_none() {
                     continue;
                 }
-                let filter = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
+                let filter = self
+                    .filter
+                    .key_filter(key, value, include, exclude)
+                    .map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = filter {
                     let output_key = infer_json_key(key, &td_extra).map_err(py_err_se_err)?;
                     let s = SerializeInfer::new(value, next_include, next_exclude, &td_extra);
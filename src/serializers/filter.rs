@@ -1,11 +1,12 @@
 use ahash::AHashSet;
 use pyo3::exceptions::PyValueError;
 use std::hash::Hash;
+use std::sync::Arc;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyDict, PySet};
+use pyo3::types::{PyBool, PyDict, PySet, PyString, PyTuple};
 
 use crate::tools::SchemaDict;
 
@@ -90,13 +91,14 @@ impl SchemaFilter<usize> {
     pub fn index_filter<'py>(
         &self,
         index: usize,
+        value: &PyAny,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
         len: Option<usize>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         let include = include.map(|v| map_negative_indices(v, len)).transpose()?;
         let exclude = exclude.map(|v| map_negative_indices(v, len)).transpose()?;
-        self.filter(index, index, include, exclude)
+        self.filter(index, index, value, include, exclude)
     }
 }
 
@@ -129,11 +131,12 @@ impl SchemaFilter<isize> {
     pub fn key_filter<'py>(
         &self,
         key: &PyAny,
+        value: &PyAny,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         let hash = key.hash()?;
-        self.filter(key, hash, include, exclude)
+        self.filter(key, hash, value, include, exclude)
     }
 }
 
@@ -150,6 +153,7 @@ trait FilterLogic<T: Eq + Copy> {
         &self,
         py_key: impl ToPyObject + Copy,
         int_key: T,
+        value: &PyAny,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
@@ -173,6 +177,12 @@ trait FilterLogic<T: Eq + Copy> {
                     // index is in the exclude set, we return Ok(None) to omit this index
                     return Ok(None);
                 }
+            } else if let Ok(path_filter) = exclude.downcast::<PyCell<PathFilter>>() {
+                match path_filter.get().step(path_filter.py(), py_key, value, false)? {
+                    PathMatch::All => return Ok(None),
+                    PathMatch::Inside(inner) => next_exclude = Some(inner),
+                    PathMatch::Nothing => (),
+                }
             } else if let Some(contains) = check_contains(exclude, py_key)? {
                 if contains {
                     return Ok(None);
@@ -208,6 +218,15 @@ trait FilterLogic<T: Eq + Copy> {
                     // this index should be omitted
                     return Ok(None);
                 }
+            } else if let Ok(path_filter) = include.downcast::<PyCell<PathFilter>>() {
+                match path_filter.get().step(path_filter.py(), py_key, value, true)? {
+                    PathMatch::All => return Ok(Some((None, next_exclude))),
+                    PathMatch::Inside(inner) => return Ok(Some((Some(inner), next_exclude))),
+                    // if the index is not matched, include exists, AND it's not in schema include,
+                    // this index should be omitted
+                    PathMatch::Nothing if !self.explicit_include(int_key) => return Ok(None),
+                    PathMatch::Nothing => (),
+                }
             } else if let Some(contains) = check_contains(include, py_key)? {
                 if contains {
                     return Ok(Some((None, next_exclude)));
@@ -263,23 +282,25 @@ impl AnyFilter {
     pub fn key_filter<'py>(
         &self,
         key: &PyAny,
+        value: &PyAny,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         // just use 0 for the int_key, it's always ignored in the implementation here
-        self.filter(key, 0, include, exclude)
+        self.filter(key, 0, value, include, exclude)
     }
 
     pub fn index_filter<'py>(
         &self,
         index: usize,
+        value: &PyAny,
         include: Option<&'py PyAny>,
         exclude: Option<&'py PyAny>,
         len: Option<usize>,
     ) -> PyResult<Option<(Option<&'py PyAny>, Option<&'py PyAny>)>> {
         let include = include.map(|v| map_negative_indices(v, len)).transpose()?;
         let exclude = exclude.map(|v| map_negative_indices(v, len)).transpose()?;
-        self.filter(index, index, include, exclude)
+        self.filter(index, index, value, include, exclude)
     }
 }
 
@@ -391,3 +412,219 @@ fn merge_dicts<'py>(item_dict: &'py PyDict, all_value: &'py PyAny) -> PyResult<&
     }
     Ok(item_dict)
 }
+
+/// Convert a top level `include` or `exclude` argument to a `PathFilter` if it's a predicate, otherwise return it
+/// unchanged, path patterns are only used when given explicitly as a `PathFilter`
+pub(crate) fn path_filter_arg(arg: Option<&PyAny>) -> PyResult<Option<&PyAny>> {
+    match arg {
+        Some(arg) if arg.is_callable() => {
+            let py = arg.py();
+            let filter = PathFilter {
+                patterns: Arc::default(),
+                positions: Vec::new(),
+                predicate: Some(arg.into_py(py)),
+                path: Vec::new(),
+            };
+            Ok(Some(Py::new(py, filter)?.into_ref(py)))
+        }
+        _ => Ok(arg),
+    }
+}
+
+/// Whether filtering on `include` or `exclude` needs the value as well as the key, i.e. either is a predicate
+pub(crate) fn filter_needs_value(include: Option<&PyAny>, exclude: Option<&PyAny>) -> bool {
+    let has_predicate = |arg: Option<&PyAny>| {
+        arg.and_then(|arg| arg.downcast::<PyCell<PathFilter>>().ok())
+            .map_or(false, |path_filter| path_filter.get().predicate.is_some())
+    };
+    has_predicate(include) || has_predicate(exclude)
+}
+
+/// A segment of a path pattern, separated by `.`
+#[derive(Debug)]
+enum PathSegment {
+    /// `**`, matches any number of keys, including none
+    AnyDepth,
+    /// matches a single key, `*` matches any run of characters within the key
+    Key(String),
+}
+
+#[derive(Debug)]
+struct PathPattern {
+    raw: String,
+    segments: Vec<PathSegment>,
+}
+
+impl PathPattern {
+    fn new(raw: String) -> Self {
+        let segments = raw
+            .split('.')
+            .map(|segment| match segment {
+                "**" => PathSegment::AnyDepth,
+                "__all__" => PathSegment::Key("*".to_string()),
+                _ => PathSegment::Key(segment.to_string()),
+            })
+            .collect();
+        Self { raw, segments }
+    }
+}
+
+/// The result of matching a key against a `PathFilter`
+enum PathMatch<'py> {
+    /// the whole value at the key is matched
+    All,
+    /// parts of the value at the key may be matched, by the filter given
+    Inside(&'py PyAny),
+    Nothing,
+}
+
+/// Call time `include` or `exclude` given as wildcard path patterns like `PathFilter({"items.*.secret"})` or as a
+/// predicate `(path, value) -> bool`, passed down in place of nested dicts and sets as serialization descends
+/// into fields and items.
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct PathFilter {
+    patterns: Arc<Vec<PathPattern>>,
+    /// (pattern index, segment index) of every pattern which matches the path so far
+    positions: Vec<(usize, usize)>,
+    predicate: Option<PyObject>,
+    /// keys from the top level value, only tracked for the predicate
+    path: Vec<PyObject>,
+}
+
+impl PathFilter {
+    fn step<'py>(
+        &self,
+        py: Python<'py>,
+        py_key: impl ToPyObject,
+        value: &PyAny,
+        include: bool,
+    ) -> PyResult<PathMatch<'py>> {
+        let py_key = py_key.to_object(py);
+        if let Some(ref predicate) = self.predicate {
+            let mut path = self.path.clone();
+            path.push(py_key);
+            let matched = predicate.call1(py, (PyTuple::new(py, &path), value))?.is_true(py)?;
+            if include && !matched {
+                return Ok(PathMatch::Nothing);
+            } else if !include && matched {
+                return Ok(PathMatch::All);
+            }
+            let inner = Self {
+                patterns: Arc::default(),
+                positions: Vec::new(),
+                predicate: Some(predicate.clone_ref(py)),
+                path,
+            };
+            return Ok(PathMatch::Inside(Py::new(py, inner)?.into_ref(py)));
+        }
+
+        let key = key_string(py_key.as_ref(py))?;
+        let mut matched = false;
+        let mut positions = Vec::new();
+        for &(pattern_index, segment_index) in &self.positions {
+            self.advance(pattern_index, segment_index, &key, &mut matched, &mut positions);
+        }
+        if matched {
+            Ok(PathMatch::All)
+        } else if positions.is_empty() {
+            Ok(PathMatch::Nothing)
+        } else {
+            positions.sort_unstable();
+            positions.dedup();
+            let inner = Self {
+                patterns: self.patterns.clone(),
+                positions,
+                predicate: None,
+                path: Vec::new(),
+            };
+            Ok(PathMatch::Inside(Py::new(py, inner)?.into_ref(py)))
+        }
+    }
+
+    fn advance(
+        &self,
+        pattern_index: usize,
+        segment_index: usize,
+        key: &str,
+        matched: &mut bool,
+        positions: &mut Vec<(usize, usize)>,
+    ) {
+        let segments = &self.patterns[pattern_index].segments;
+        let last = segment_index + 1 == segments.len();
+        match segments[segment_index] {
+            PathSegment::AnyDepth if last => *matched = true,
+            PathSegment::AnyDepth => {
+                // either `**` takes this key and stays put, or it matches no keys at all
+                positions.push((pattern_index, segment_index));
+                self.advance(pattern_index, segment_index + 1, key, matched, positions);
+            }
+            PathSegment::Key(ref glob) if glob_match(glob, key) => {
+                if last {
+                    *matched = true;
+                } else {
+                    positions.push((pattern_index, segment_index + 1));
+                }
+            }
+            PathSegment::Key(_) => (),
+        }
+    }
+}
+
+#[pymethods]
+impl PathFilter {
+    #[new]
+    fn py_new(patterns: &PyAny) -> PyResult<Self> {
+        let patterns = patterns
+            .iter()?
+            .map(|item| Ok(PathPattern::new(item?.downcast::<PyString>()?.to_str()?.to_string())))
+            .collect::<PyResult<Vec<_>>>()?;
+        let positions = (0..patterns.len()).map(|index| (index, 0)).collect();
+        Ok(Self {
+            patterns: Arc::new(patterns),
+            positions,
+            predicate: None,
+            path: Vec::new(),
+        })
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        match self.predicate {
+            Some(ref predicate) => Ok(format!(
+                "PathFilter({}, path={})",
+                predicate.as_ref(py).repr()?,
+                PyTuple::new(py, &self.path).repr()?
+            )),
+            None => {
+                let mut patterns: Vec<&str> = self
+                    .positions
+                    .iter()
+                    .map(|&(pattern_index, _)| self.patterns[pattern_index].raw.as_str())
+                    .collect();
+                patterns.dedup();
+                Ok(format!("PathFilter({patterns:?})"))
+            }
+        }
+    }
+}
+
+/// keys are matched as strings, so list indexes can be matched with e.g. `items.0`
+fn key_string(key: &PyAny) -> PyResult<String> {
+    match key.downcast::<PyString>() {
+        Ok(py_str) => Ok(py_str.to_str()?.to_string()),
+        Err(_) => Ok(key.str()?.to_str()?.to_string()),
+    }
+}
+
+/// match `value` against `glob`, where `*` matches any run of characters
+fn glob_match(glob: &str, value: &str) -> bool {
+    match glob.split_once('*') {
+        None => glob == value,
+        Some((prefix, rest)) => match value.strip_prefix(prefix) {
+            Some(value) => (0..=value.len())
+                .filter(|index| value.is_char_boundary(*index))
+                .any(|index| glob_match(rest, &value[index..])),
+            None => false,
+        },
+    }
+}
@@ -14,9 +14,11 @@ use crate::serializers::errors::SERIALIZATION_ERR_MARKER;
 use crate::serializers::filter::SchemaFilter;
 use crate::serializers::shared::{PydanticSerializer, TypeSerializer};
 use crate::serializers::SchemaSerializer;
-use crate::tools::{extract_i64, py_err, safe_repr};
+use crate::tools::{extract_i64, py_err, safe_repr, sorted_items};
 use crate::url::{PyMultiHostUrl, PyUrl};
 
+use super::cbor::serialize_decimal_fraction;
+use super::config::CircularRefMode;
 use super::errors::{py_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, SerMode};
 use super::filter::AnyFilter;
@@ -29,9 +31,21 @@ pub(crate) fn infer_to_python(
     exclude: Option<&PyAny>,
     extra: &Extra,
 ) -> PyResult<PyObject> {
+    let value = any_dispatch(value, extra)?.unwrap_or(value);
     infer_to_python_known(extra.ob_type_lookup.get_type(value), value, include, exclude, extra)
 }
 
+/// Call the `ser_json_any_dispatch` function registered for the exact type of `value`, if any, in JSON modes
+fn any_dispatch<'py>(value: &'py PyAny, extra: &Extra<'py>) -> PyResult<Option<&'py PyAny>> {
+    match extra.any_dispatch {
+        Some(dispatch) if extra.mode.is_json() => match dispatch.get_item(value.get_type())? {
+            Some(function) => function.call1((value,)).map(Some),
+            None => Ok(None),
+        },
+        _ => Ok(None),
+    }
+}
+
 // arbitrary ids to identify that we recursed through infer_to_{python,json}_known
 // We just need them to be different from definition ref slot ids, which start at 0
 const INFER_DEF_REF_ID: usize = usize::MAX;
@@ -44,13 +58,15 @@ pub(crate) fn infer_to_python_known(
     extra: &Extra,
 ) -> PyResult<PyObject> {
     let py = value.py();
-    let value_id = match extra.rec_guard.add(value, INFER_DEF_REF_ID) {
+    let value_id = match extra.rec_guard.add(value, INFER_DEF_REF_ID, extra.config.max_depth) {
         Ok(id) => id,
-        Err(e) => {
-            return match extra.mode {
-                SerMode::Json => Err(e),
-                // if recursion is detected by we're serializing to python, we just return the value
-                _ => Ok(value.into_py(py)),
+        Err(stop) => {
+            let circular_ref_mode = &extra.config.circular_ref_mode;
+            // if recursion is detected but we're serializing to python, by default we just return the value
+            return if extra.mode.is_json() || *circular_ref_mode != CircularRefMode::Error {
+                circular_ref_mode.to_python(value, stop)
+            } else {
+                Ok(value.into_py(py))
             };
         }
     };
@@ -73,7 +89,7 @@ pub(crate) fn infer_to_python_known(
             let len = value.len().ok();
 
             for (index, element) in py_seq.iter().enumerate() {
-                let op_next = filter.index_filter(index, include, exclude, len)?;
+                let op_next = filter.index_filter(index, element, include, exclude, len)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     items.push(infer_to_python(element, next_include, next_exclude, extra)?);
                 }
@@ -87,7 +103,7 @@ pub(crate) fn infer_to_python_known(
         let filter = AnyFilter::new();
 
         for (k, v) in dict {
-            let op_next = filter.key_filter(k, include, exclude)?;
+            let op_next = filter.key_filter(k, v, include, exclude)?;
             if let Some((next_include, next_exclude)) = op_next {
                 let k_str = infer_json_key(k, extra)?;
                 let k = PyString::new(py, &k_str);
@@ -99,9 +115,14 @@ pub(crate) fn infer_to_python_known(
     };
 
     let serialize_with_serializer = || {
+        if extra.keep_nested_model() {
+            return Ok(value.into_py(py));
+        }
         let py_serializer = value.getattr(intern!(py, "__pydantic_serializer__"))?;
         let serializer: PyRef<SchemaSerializer> = py_serializer.extract()?;
-        let extra = serializer.build_extra(
+        let keep_models = extra.keep_models;
+        let shared_refs = extra.shared_refs;
+        let mut extra = serializer.build_extra(
             py,
             extra.mode,
             extra.by_alias,
@@ -114,16 +135,39 @@ pub(crate) fn infer_to_python_known(
             extra.serialize_unknown,
             extra.fallback,
         );
+        extra.keep_models = keep_models;
+        extra.shared_refs = shared_refs;
         serializer.serializer.to_python(value, include, exclude, &extra)
     };
 
     let value = match extra.mode {
-        SerMode::Json => match ob_type {
+        SerMode::Json | SerMode::JsonCompatible => match ob_type {
             // `bool` and `None` can't be subclasses, `ObType::Int`, `ObType::Float`, `ObType::Str` refer to exact types
-            ObType::None | ObType::Bool | ObType::Int | ObType::Float | ObType::Str => value.into_py(py),
+            ObType::None | ObType::Bool | ObType::Str => value.into_py(py),
+            ObType::Float => {
+                let v = value.extract::<f64>()?;
+                super::type_serializers::float::check_finite(v, &extra.config.inf_nan_mode)?;
+                if super::type_serializers::float::is_json_compatible_null(v, extra.mode, &extra.config.inf_nan_mode) {
+                    py.None()
+                } else {
+                    value.into_py(py)
+                }
+            }
+            ObType::Int => extra.config.bigint_mode.int_to_json(py, value)?,
             // have to do this to make sure subclasses of for example str are upcast to `str`
-            ObType::IntSubclass => extract_i64(value)?.into_py(py),
-            ObType::FloatSubclass => value.extract::<f64>()?.into_py(py),
+            ObType::IntSubclass => {
+                let int = extract_i64(value)?.into_py(py);
+                extra.config.bigint_mode.int_to_json(py, int.as_ref(py))?
+            }
+            ObType::FloatSubclass => {
+                let v = value.extract::<f64>()?;
+                super::type_serializers::float::check_finite(v, &extra.config.inf_nan_mode)?;
+                if super::type_serializers::float::is_json_compatible_null(v, extra.mode, &extra.config.inf_nan_mode) {
+                    py.None()
+                } else {
+                    v.into_py(py)
+                }
+            }
             ObType::Decimal => value.to_string().into_py(py),
             ObType::StrSubclass => value.extract::<&str>()?.into_py(py),
             ObType::Bytes => extra
@@ -150,6 +194,13 @@ pub(crate) fn infer_to_python_known(
                 let elements = serialize_seq_filter!(PyList);
                 PyList::new(py, elements).into_py(py)
             }
+            ObType::Set | ObType::Frozenset if extra.config.deterministic => {
+                let elements = sorted_items(value)?
+                    .iter()
+                    .map(|v| infer_to_python(v, None, None, extra))
+                    .collect::<PyResult<Vec<PyObject>>>()?;
+                PyList::new(py, elements).into_py(py)
+            }
             ObType::Set => {
                 let elements = serialize_seq!(PySet);
                 PyList::new(py, elements).into_py(py)
@@ -207,7 +258,7 @@ pub(crate) fn infer_to_python_known(
 
                 for (index, r) in py_seq.iter()?.enumerate() {
                     let element = r?;
-                    let op_next = filter.index_filter(index, include, exclude, None)?;
+                    let op_next = filter.index_filter(index, element, include, exclude, None)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         items.push(infer_to_python(element, next_include, next_exclude, extra)?);
                     }
@@ -252,7 +303,7 @@ pub(crate) fn infer_to_python_known(
                 let filter = AnyFilter::new();
 
                 for (k, v) in dict {
-                    let op_next = filter.key_filter(k, include, exclude)?;
+                    let op_next = filter.key_filter(k, v, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let v = infer_to_python(v, next_include, next_exclude, extra)?;
                         new_dict.set_item(k, v)?;
@@ -265,7 +316,7 @@ pub(crate) fn infer_to_python_known(
             ObType::Generator => {
                 let iter = super::type_serializers::generator::SerializationIterator::new(
                     value.downcast()?,
-                    super::type_serializers::any::AnySerializer.into(),
+                    super::type_serializers::any::AnySerializer::default().into(),
                     SchemaFilter::default(),
                     include,
                     exclude,
@@ -314,8 +365,7 @@ impl<'py> SerializeInfer<'py> {
 
 impl<'py> Serialize for SerializeInfer<'py> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let ob_type = self.extra.ob_type_lookup.get_type(self.value);
-        infer_serialize_known(ob_type, self.value, serializer, self.include, self.exclude, self.extra)
+        infer_serialize(self.value, serializer, self.include, self.exclude, self.extra)
     }
 }
 
@@ -326,6 +376,7 @@ pub(crate) fn infer_serialize<S: Serializer>(
     exclude: Option<&PyAny>,
     extra: &Extra,
 ) -> Result<S::Ok, S::Error> {
+    let value = any_dispatch(value, extra).map_err(py_err_se_err)?.unwrap_or(value);
     infer_serialize_known(
         extra.ob_type_lookup.get_type(value),
         value,
@@ -344,13 +395,13 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     exclude: Option<&PyAny>,
     extra: &Extra,
 ) -> Result<S::Ok, S::Error> {
-    let value_id = match extra.rec_guard.add(value, INFER_DEF_REF_ID).map_err(py_err_se_err) {
+    let value_id = match extra.rec_guard.add(value, INFER_DEF_REF_ID, extra.config.max_depth) {
         Ok(v) => v,
-        Err(e) => {
+        Err(stop) => {
             return if extra.serialize_unknown {
                 serializer.serialize_str("...")
             } else {
-                Err(e)
+                extra.config.circular_ref_mode.serialize(value, stop, serializer)
             }
         }
     };
@@ -384,7 +435,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
 
             for (index, element) in py_seq.iter().enumerate() {
                 let op_next = filter
-                    .index_filter(index, include, exclude, len)
+                    .index_filter(index, element, include, exclude, len)
                     .map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     let item_serializer = SerializeInfer::new(element, next_include, next_exclude, extra);
@@ -401,7 +452,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             let filter = AnyFilter::new();
 
             for (key, value) in $py_dict {
-                let op_next = filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
+                let op_next = filter.key_filter(key, value, include, exclude).map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     let key = infer_json_key(key, extra).map_err(py_err_se_err)?;
                     let value_serializer = SerializeInfer::new(value, next_include, next_exclude, extra);
@@ -414,10 +465,27 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
 
     let ser_result = match ob_type {
         ObType::None => serializer.serialize_none(),
-        ObType::Int | ObType::IntSubclass => serialize!(Int),
+        ObType::Int | ObType::IntSubclass => {
+            let int: Int = value.extract().map_err(py_err_se_err)?;
+            extra.config.bigint_mode.serialize_int(&int, serializer)
+        }
         ObType::Bool => serialize!(bool),
-        ObType::Float | ObType::FloatSubclass => serialize!(f64),
-        ObType::Decimal => value.to_string().serialize(serializer),
+        ObType::Float | ObType::FloatSubclass => {
+            let v: f64 = value.extract().map_err(py_err_se_err)?;
+            if serializer.is_human_readable() {
+                super::type_serializers::float::serialize_f64(v, serializer, &extra.config.inf_nan_mode)
+            } else {
+                // CBOR can represent non-finite floats
+                serializer.serialize_f64(v)
+            }
+        }
+        ObType::Decimal => {
+            if serializer.is_human_readable() {
+                value.to_string().serialize(serializer)
+            } else {
+                serialize_decimal_fraction(value, serializer)
+            }
+        }
         ObType::Str | ObType::StrSubclass => {
             let py_str: &PyString = value.downcast().map_err(py_err_se_err)?;
             super::type_serializers::string::serialize_py_str(py_str, serializer)
@@ -438,12 +506,29 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Dict => serialize_dict!(value.downcast::<PyDict>().map_err(py_err_se_err)?),
         ObType::List => serialize_seq_filter!(PyList),
         ObType::Tuple => serialize_seq_filter!(PyTuple),
+        ObType::Set | ObType::Frozenset if extra.config.deterministic => {
+            let items = sorted_items(value).map_err(py_err_se_err)?;
+            let mut seq = serializer.serialize_seq(Some(items.len()))?;
+            for element in items {
+                let item_serializer = SerializeInfer::new(element, include, exclude, extra);
+                seq.serialize_element(&item_serializer)?;
+            }
+            seq.end()
+        }
         ObType::Set => serialize_seq!(PySet),
         ObType::Frozenset => serialize_seq!(PyFrozenSet),
         ObType::Datetime => {
             let py_dt: &PyDateTime = value.downcast().map_err(py_err_se_err)?;
             let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_dt)
+            let cbor_tag = if serializer.is_human_readable() {
+                None
+            } else {
+                super::type_serializers::datetime_etc::datetime_cbor_tag(py_dt).map_err(py_err_se_err)?
+            };
+            match cbor_tag {
+                Some(tag) => serializer.serialize_newtype_struct(tag, &iso_dt),
+                None => serializer.serialize_str(&iso_dt),
+            }
         }
         ObType::Date => {
             let py_date: &PyDate = value.downcast().map_err(py_err_se_err)?;
@@ -476,7 +561,8 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 .getattr(intern!(py, "__pydantic_serializer__"))
                 .map_err(py_err_se_err)?;
             let extracted_serializer: PyRef<SchemaSerializer> = py_serializer.extract().map_err(py_err_se_err)?;
-            let extra = extracted_serializer.build_extra(
+            let shared_refs = extra.shared_refs;
+            let mut extra = extracted_serializer.build_extra(
                 py,
                 extra.mode,
                 extra.by_alias,
@@ -489,6 +575,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 extra.serialize_unknown,
                 extra.fallback,
             );
+            extra.shared_refs = shared_refs;
             let pydantic_serializer =
                 PydanticSerializer::new(value, &extracted_serializer.serializer, include, exclude, &extra);
             pydantic_serializer.serialize(serializer)
@@ -510,7 +597,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             for (index, r) in py_seq.iter().map_err(py_err_se_err)?.enumerate() {
                 let element = r.map_err(py_err_se_err)?;
                 let op_next = filter
-                    .index_filter(index, include, exclude, None)
+                    .index_filter(index, element, include, exclude, None)
                     .map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     let item_serializer = SerializeInfer::new(element, next_include, next_exclude, extra);
@@ -563,6 +650,11 @@ fn serialize_unknown(value: &PyAny) -> Cow<str> {
 }
 
 pub(crate) fn infer_json_key<'py>(key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+    if let Some(key) = any_dispatch(key, extra)? {
+        // the converted key is only borrowed for the lifetime of `extra`, so return an owned string
+        let ob_type = extra.ob_type_lookup.get_type(key);
+        return infer_json_key_known(ob_type, key, extra).map(|key| Cow::Owned(key.into_owned()));
+    }
     let ob_type = extra.ob_type_lookup.get_type(key);
     infer_json_key_known(ob_type, key, extra)
 }
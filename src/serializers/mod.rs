@@ -1,20 +1,27 @@
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict};
+use pyo3::types::{PyBytes, PyDict, PyList, PyType};
 use pyo3::{PyTraverseError, PyVisit};
 
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::py_gc::PyGcTraverse;
+use crate::trace::TraceSpan;
 
 use config::SerializationConfig;
-pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
-use extra::{CollectWarnings, SerRecursionGuard};
+pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue, PydanticSerializationWarning};
+use extra::{CollectWarnings, SerRecursionGuard, SharedRefs};
 pub(crate) use extra::{Extra, SerMode, SerializationState};
+use filter::path_filter_arg;
+pub use filter::PathFilter;
+use ob_type::{ObType, ObTypeLookup};
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_cbor_bytes, to_json_bytes, BuildSerializer, TypeSerializer};
 
+mod cbor;
 mod computed_fields;
 mod config;
 mod errors;
@@ -77,6 +84,7 @@ impl SchemaSerializer {
 impl SchemaSerializer {
     #[new]
     pub fn py_new(py: Python, schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let _span = TraceSpan::enter("SchemaSerializer.__init__", None);
         let mut definitions_builder = DefinitionsBuilder::new();
         let serializer = CombinedSerializer::build(schema.downcast()?, config, &mut definitions_builder)?;
         Ok(Self {
@@ -95,7 +103,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+        fallback = None, keep_models = false, shared_refs = false, recursion_limit = None, reveal_secrets = false,
+        warnings_collector = None))]
     pub fn to_python(
         &self,
         py: Python,
@@ -110,11 +119,25 @@ impl SchemaSerializer {
         round_trip: bool,
         warnings: bool,
         fallback: Option<&PyAny>,
+        keep_models: bool,
+        shared_refs: bool,
+        recursion_limit: Option<u16>,
+        reveal_secrets: bool,
+        warnings_collector: Option<&PyList>,
     ) -> PyResult<PyObject> {
+        let _span = TraceSpan::enter("SchemaSerializer.to_python", Some(self.serializer.get_name()));
         let mode: SerMode = mode.into();
-        let warnings = CollectWarnings::new(warnings);
-        let rec_guard = SerRecursionGuard::default();
-        let extra = self.build_extra(
+        if keep_models && mode.is_json() {
+            return Err(PyValueError::new_err(format!(
+                "`keep_models` cannot be used with mode='{mode}'"
+            )));
+        }
+        let include = path_filter_arg(include)?;
+        let exclude = path_filter_arg(exclude)?;
+        let warnings = CollectWarnings::new(warnings, warnings_collector);
+        let rec_guard = SerRecursionGuard::with_limit(recursion_limit.or(self.config.recursion_limit));
+        let shared = SharedRefs::default();
+        let mut extra = self.build_extra(
             py,
             &mode,
             by_alias,
@@ -127,6 +150,9 @@ impl SchemaSerializer {
             false,
             fallback,
         );
+        extra.keep_models = keep_models;
+        extra.shared_refs = shared_refs.then_some(&shared);
+        extra.reveal_secrets = reveal_secrets;
         let v = self.serializer.to_python(value, include, exclude, &extra)?;
         warnings.final_check(py)?;
         Ok(v)
@@ -135,7 +161,8 @@ impl SchemaSerializer {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
         exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+        fallback = None, shared_refs = false, recursion_limit = None, reveal_secrets = false,
+        warnings_collector = None))]
     pub fn to_json(
         &self,
         py: Python,
@@ -150,10 +177,18 @@ impl SchemaSerializer {
         round_trip: bool,
         warnings: bool,
         fallback: Option<&PyAny>,
+        shared_refs: bool,
+        recursion_limit: Option<u16>,
+        reveal_secrets: bool,
+        warnings_collector: Option<&PyList>,
     ) -> PyResult<PyObject> {
-        let warnings = CollectWarnings::new(warnings);
-        let rec_guard = SerRecursionGuard::default();
-        let extra = self.build_extra(
+        let _span = TraceSpan::enter("SchemaSerializer.to_json", Some(self.serializer.get_name()));
+        let include = path_filter_arg(include)?;
+        let exclude = path_filter_arg(exclude)?;
+        let warnings = CollectWarnings::new(warnings, warnings_collector);
+        let rec_guard = SerRecursionGuard::with_limit(recursion_limit.or(self.config.recursion_limit));
+        let shared = SharedRefs::default();
+        let mut extra = self.build_extra(
             py,
             &SerMode::Json,
             by_alias,
@@ -166,6 +201,8 @@ impl SchemaSerializer {
             false,
             fallback,
         );
+        extra.shared_refs = shared_refs.then_some(&shared);
+        extra.reveal_secrets = reveal_secrets;
         let bytes = to_json_bytes(
             value,
             &self.serializer,
@@ -183,6 +220,53 @@ impl SchemaSerializer {
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true, fallback = None,
+        recursion_limit = None, reveal_secrets = false, warnings_collector = None))]
+    pub fn to_cbor(
+        &self,
+        py: Python,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: bool,
+        fallback: Option<&PyAny>,
+        recursion_limit: Option<u16>,
+        reveal_secrets: bool,
+        warnings_collector: Option<&PyList>,
+    ) -> PyResult<PyObject> {
+        let include = path_filter_arg(include)?;
+        let exclude = path_filter_arg(exclude)?;
+        let warnings = CollectWarnings::new(warnings, warnings_collector);
+        let rec_guard = SerRecursionGuard::with_limit(recursion_limit.or(self.config.recursion_limit));
+        let mut extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+        );
+        extra.reveal_secrets = reveal_secrets;
+        let bytes = to_cbor_bytes(value, &self.serializer, include, exclude, &extra)?;
+
+        warnings.final_check(py)?;
+
+        let py_bytes = PyBytes::new(py, &bytes);
+        Ok(py_bytes.into())
+    }
+
     pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -213,7 +297,7 @@ impl SchemaSerializer {
 #[pyfunction]
 #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
-    serialize_unknown = false, fallback = None))]
+    bigint_mode = "number", serialize_unknown = false, fallback = None))]
 pub fn to_json(
     py: Python,
     value: &PyAny,
@@ -225,10 +309,13 @@ pub fn to_json(
     round_trip: bool,
     timedelta_mode: &str,
     bytes_mode: &str,
+    bigint_mode: &str,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode)?;
+    let include = path_filter_arg(include)?;
+    let exclude = path_filter_arg(exclude)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, bigint_mode)?;
     let extra = state.extra(
         py,
         &SerMode::Json,
@@ -238,7 +325,7 @@ pub fn to_json(
         serialize_unknown,
         fallback,
     );
-    let serializer = type_serializers::any::AnySerializer.into();
+    let serializer = type_serializers::any::AnySerializer::default().into();
     let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
     state.final_check(py)?;
     let py_bytes = PyBytes::new(py, &bytes);
@@ -248,7 +335,8 @@ pub fn to_json(
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
-    timedelta_mode = "iso8601", bytes_mode = "utf8", serialize_unknown = false, fallback = None))]
+    timedelta_mode = "iso8601", bytes_mode = "utf8", bigint_mode = "number", serialize_unknown = false,
+    fallback = None))]
 pub fn to_jsonable_python(
     py: Python,
     value: &PyAny,
@@ -259,10 +347,13 @@ pub fn to_jsonable_python(
     round_trip: bool,
     timedelta_mode: &str,
     bytes_mode: &str,
+    bigint_mode: &str,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode)?;
+    let include = path_filter_arg(include)?;
+    let exclude = path_filter_arg(exclude)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, bigint_mode)?;
     let extra = state.extra(
         py,
         &SerMode::Json,
@@ -276,3 +367,11 @@ pub fn to_jsonable_python(
     state.final_check(py)?;
     Ok(v)
 }
+
+#[pyfunction]
+pub fn register_ob_type(py: Python, tp: &PyType, ob_type: &str) -> PyResult<()> {
+    let ob_type =
+        ObType::from_str(ob_type).map_err(|_| PyValueError::new_err(format!("Invalid ob_type: {ob_type:?}")))?;
+    ObTypeLookup::cached(py).register(tp, ob_type);
+    Ok(())
+}
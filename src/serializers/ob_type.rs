@@ -1,5 +1,8 @@
+use std::cell::RefCell;
+
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
+use pyo3::sync::GILProtected;
 use pyo3::types::{
     PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator, PyList,
     PySet, PyString, PyTime, PyTuple, PyType,
@@ -49,6 +52,10 @@ pub struct ObTypeLookup {
 }
 
 static TYPE_LOOKUP: GILOnceCell<ObTypeLookup> = GILOnceCell::new();
+// types registered at runtime via `register_ob_type`, the type object is kept to make sure the pointer
+// can't be reused by another type, there are rarely more than a few so they're searched linearly
+static REGISTERED: GILProtected<RefCell<Vec<(usize, ObType, Py<PyType>)>>> =
+    GILProtected::new(RefCell::new(Vec::new()));
 
 #[derive(Debug)]
 pub enum IsType {
@@ -95,6 +102,25 @@ impl ObTypeLookup {
         TYPE_LOOKUP.get_or_init(py, || Self::new(py))
     }
 
+    /// Register `py_type` (and its subclasses) to be serialized as `ob_type` when inferring types,
+    /// this avoids falling back to the `isinstance` checks in `fallback_isinstance` for that type.
+    pub fn register(&self, py_type: &PyType, ob_type: ObType) {
+        let ptr = py_type.as_ptr() as usize;
+        let mut registered = REGISTERED.get(py_type.py()).borrow_mut();
+        match registered.iter_mut().find(|(p, ..)| *p == ptr) {
+            Some(entry) => entry.1 = ob_type,
+            None => registered.push((ptr, ob_type, py_type.into())),
+        }
+    }
+
+    fn lookup_registered(&self, py: Python, ob_type: usize) -> Option<ObType> {
+        let registered = REGISTERED.get(py).borrow();
+        registered
+            .iter()
+            .find(|(p, ..)| *p == ob_type)
+            .map(|(_, ob_type, _)| *ob_type)
+    }
+
     pub fn is_type(&self, value: &PyAny, expected_ob_type: ObType) -> IsType {
         match self.ob_type_is_expected(Some(value), value.get_type(), &expected_ob_type) {
             IsType::False => {
@@ -173,16 +199,16 @@ impl ObTypeLookup {
 
     pub fn get_type(&self, value: &PyAny) -> ObType {
         match self.lookup_by_ob_type(Some(value), value.get_type()) {
-            ObType::Unknown => self.fallback_isinstance(value),
-            ob_type => ob_type,
+            Some(ob_type) => ob_type,
+            None => self.fallback_isinstance(value),
         }
     }
 
-    fn lookup_by_ob_type(&self, op_value: Option<&PyAny>, py_type: &PyType) -> ObType {
+    fn lookup_by_ob_type(&self, op_value: Option<&PyAny>, py_type: &PyType) -> Option<ObType> {
         let ob_type = py_type.as_ptr() as usize;
         // this should be pretty fast, but still order is a bit important, so the most common types should come first
         // thus we don't follow the order of ObType
-        if ob_type == self.none {
+        let found = if ob_type == self.none {
             ObType::None
         } else if ob_type == self.int {
             // op_value is None on recursive calls, e.g. hence the original value would be a subclass
@@ -232,6 +258,9 @@ impl ObTypeLookup {
             ObType::MultiHostUrl
         } else if ob_type == self.uuid_object.as_ptr() as usize {
             ObType::Uuid
+        } else if let Some(registered) = self.lookup_registered(py_type.py(), ob_type) {
+            // registered types may be `ObType::Unknown`, in which case we shouldn't try `fallback_isinstance`
+            registered
         } else if is_pydantic_serializable(op_value) {
             ObType::PydanticSerializable
         } else if is_dataclass(op_value) {
@@ -245,12 +274,10 @@ impl ObTypeLookup {
         } else {
             // this allows for subtypes of the supported class types,
             // if `ob_type` didn't match any member of self, we try again with the next base type pointer
-            match get_base_type(py_type) {
-                // we don't want to tests for dataclass etc. again, so we pass None as op_value
-                Some(base_type) => self.lookup_by_ob_type(None, base_type),
-                None => ObType::Unknown,
-            }
-        }
+            // we don't want to tests for dataclass etc. again, so we pass None as op_value
+            return get_base_type(py_type).and_then(|base_type| self.lookup_by_ob_type(None, base_type));
+        };
+        Some(found)
     }
 
     fn is_enum(&self, op_value: Option<&PyAny>, py_type: &PyType) -> bool {
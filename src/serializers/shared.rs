@@ -18,6 +18,7 @@ use crate::py_gc::PyGcTraverse;
 use crate::serializers::ser::PythonSerializer;
 use crate::tools::{py_err, SchemaDict};
 
+use super::cbor::CborSerializer;
 use super::errors::se_err_py_err;
 use super::extra::Extra;
 use super::infer::infer_json_key;
@@ -84,6 +85,8 @@ combined_serializer! {
         Function: super::type_serializers::function::FunctionPlainSerializer;
         FunctionWrap: super::type_serializers::function::FunctionWrapSerializer;
         Fields: super::fields::GeneralFieldsSerializer;
+        // `ip-v4`, `ip-v6`, `ip-any` and `ip-network` share a serializer, built by the builders below
+        Ip: super::type_serializers::ip::IpSerializer;
     }
     // `find_only` is for type_serializers which are built directly via the `type` key and `find_serializer`
     // but aren't actually used for serialization, e.g. their `build` method must return another serializer
@@ -91,6 +94,7 @@ combined_serializer! {
         super::type_serializers::union::TaggedUnionBuilder;
         super::type_serializers::other::ChainBuilder;
         super::type_serializers::other::CustomErrorBuilder;
+        super::type_serializers::other::ModelConstraintBuilder;
         super::type_serializers::other::CallBuilder;
         super::type_serializers::other::LaxOrStrictBuilder;
         super::type_serializers::other::ArgumentsBuilder;
@@ -105,13 +109,23 @@ combined_serializer! {
         super::type_serializers::function::FunctionWrapSerializerBuilder;
         super::type_serializers::model::ModelFieldsBuilder;
         super::type_serializers::typed_dict::TypedDictBuilder;
+        super::type_serializers::email::EmailBuilder;
+        super::type_serializers::simple::Int64Builder;
+        super::type_serializers::simple::UInt64Builder;
+        super::type_serializers::ip::IpV4Builder;
+        super::type_serializers::ip::IpV6Builder;
+        super::type_serializers::ip::IpAnyBuilder;
+        super::type_serializers::ip::IpNetworkBuilder;
     }
     // `both` means the struct is added to both the `CombinedSerializer` enum and the match statement in
     // `find_serializer` so they can be used via a `type` str.
     both: {
         None: super::type_serializers::simple::NoneSerializer;
         Nullable: super::type_serializers::nullable::NullableSerializer;
+        Secret: super::type_serializers::secret::SecretSerializer;
         Int: super::type_serializers::simple::IntSerializer;
+        IntFlag: super::type_serializers::int_flag::IntFlagSerializer;
+        Enum: super::type_serializers::enum_::EnumSerializer;
         Bool: super::type_serializers::simple::BoolSerializer;
         Float: super::type_serializers::float::FloatSerializer;
         Decimal: super::type_serializers::decimal::DecimalSerializer;
@@ -131,6 +145,7 @@ combined_serializer! {
         Url: super::type_serializers::url::UrlSerializer;
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
         Uuid: super::type_serializers::uuid::UuidSerializer;
+        NameEmail: super::type_serializers::email::NameEmailSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FormatSerializer;
         ToString: super::type_serializers::format::ToStringSerializer;
@@ -139,6 +154,7 @@ combined_serializer! {
         JsonOrPython: super::type_serializers::json_or_python::JsonOrPythonSerializer;
         Union: super::type_serializers::union::UnionSerializer;
         Literal: super::type_serializers::literal::LiteralSerializer;
+        Const: super::type_serializers::literal::ConstSerializer;
         Recursive: super::type_serializers::definitions::DefinitionRefSerializer;
         TuplePositional: super::type_serializers::tuple::TuplePositionalSerializer;
         TupleVariable: super::type_serializers::tuple::TupleVariableSerializer;
@@ -183,6 +199,8 @@ impl CombinedSerializer {
                 Some("include-exclude-sequence" | "include-exclude-dict") => (),
                 // applies specifically to bytes, does not override the main schema `type`
                 Some("base64") => (),
+                // applies specifically to timedelta, does not override the main schema `type`
+                Some("timedelta-mode") => (),
                 Some(ser_type) => {
                     // otherwise if `schema.serialization.type` is defined, use that with `find_serializer`
                     // instead of `schema.type`. In this case it's an error if a serializer isn't found.
@@ -218,9 +236,13 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Function(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::FunctionWrap(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Fields(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Ip(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::None(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Nullable(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Secret(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Int(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IntFlag(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Enum(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Bool(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Float(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Decimal(inner) => inner.py_gc_traverse(visit),
@@ -247,10 +269,12 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::JsonOrPython(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Union(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Literal(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Const(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Recursive(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::TuplePositional(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::TupleVariable(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::NameEmail(inner) => inner.py_gc_traverse(visit),
         }
     }
 }
@@ -298,8 +322,9 @@ pub(crate) trait TypeSerializer: Send + Sync + Clone + Debug {
         false
     }
 
-    fn get_default(&self, _py: Python) -> PyResult<Option<PyObject>> {
-        Ok(None)
+    /// Used with `exclude_defaults` to decide if `value` is the default and should be excluded
+    fn is_default(&self, _value: &PyAny, _extra: &Extra) -> PyResult<bool> {
+        Ok(false)
     }
 }
 
@@ -366,6 +391,19 @@ pub(crate) fn to_json_bytes(
     Ok(bytes)
 }
 
+pub(crate) fn to_cbor_bytes(
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+) -> PyResult<Vec<u8>> {
+    let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
+    let mut ser = CborSerializer::new(Vec::new());
+    serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+    Ok(ser.into_inner())
+}
+
 static DC_FIELD_MARKER: GILOnceCell<PyObject> = GILOnceCell::new();
 
 /// needed to match the logic from dataclasses.fields `tuple(f for f in fields.values() if f._field_type is _FIELD)`
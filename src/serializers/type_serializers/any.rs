@@ -1,32 +1,75 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyType};
 
 use serde::ser::Serializer;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
 };
 
 #[derive(Debug, Clone, Default)]
-pub struct AnySerializer;
+pub struct AnySerializer {
+    // `ser_json_any_dispatch` from config, a dict of exact type -> function, used in JSON mode
+    // before inferring how to serialize the value or any value nested in it
+    dispatch: Option<Py<PyDict>>,
+}
 
 impl BuildSerializer for AnySerializer {
     const EXPECTED_TYPE: &'static str = "any";
 
     fn build(
         _schema: &PyDict,
-        _config: Option<&PyDict>,
+        config: Option<&PyDict>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let dispatch = match config {
+            Some(config) => config.get_as::<&PyDict>(intern!(config.py(), "ser_json_any_dispatch"))?,
+            None => None,
+        };
+        let dispatch = match dispatch {
+            Some(dispatch) => {
+                for (key, value) in dispatch {
+                    if !key.is_instance_of::<PyType>() {
+                        return py_schema_err!("`ser_json_any_dispatch` keys must be types, got {}", key.repr()?);
+                    }
+                    if !value.is_callable() {
+                        return py_schema_err!(
+                            "`ser_json_any_dispatch` values must be callable, got {}",
+                            value.repr()?
+                        );
+                    }
+                }
+                Some(dispatch.copy()?.into())
+            }
+            None => None,
+        };
+        Ok(Self { dispatch }.into())
     }
 }
 
-impl_py_gc_traverse!(AnySerializer {});
+impl_py_gc_traverse!(AnySerializer { dispatch });
+
+impl AnySerializer {
+    /// Call `f` with `extra` using this serializer's dispatch, which inference applies to nested values too
+    fn with_dispatch<R>(&self, py: Python, extra: &Extra, f: impl FnOnce(&Extra) -> R) -> R {
+        let any_dispatch = self.dispatch.as_ref().map(|d| d.as_ref(py));
+        if any_dispatch.is_none() && extra.any_dispatch.is_none() {
+            f(extra)
+        } else {
+            f(&Extra {
+                any_dispatch,
+                ..extra.clone()
+            })
+        }
+    }
+}
 
 impl TypeSerializer for AnySerializer {
     fn to_python(
@@ -36,11 +79,13 @@ impl TypeSerializer for AnySerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        infer_to_python(value, include, exclude, extra)
+        self.with_dispatch(value.py(), extra, |extra| {
+            infer_to_python(value, include, exclude, extra)
+        })
     }
 
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
-        infer_json_key(key, extra)
+        self.with_dispatch(key.py(), extra, |extra| infer_json_key(key, extra))
     }
 
     fn serde_serialize<S: Serializer>(
@@ -51,7 +96,9 @@ impl TypeSerializer for AnySerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        infer_serialize(value, serializer, include, exclude, extra)
+        self.with_dispatch(value.py(), extra, |extra| {
+            infer_serialize(value, serializer, include, exclude, extra)
+        })
     }
 
     fn get_name(&self) -> &str {
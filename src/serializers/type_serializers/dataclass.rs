@@ -11,8 +11,8 @@ use crate::tools::SchemaDict;
 
 use super::{
     infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerAlias, SerCheck,
+    SerField, TypeSerializer,
 };
 
 pub struct DataclassArgsBuilder;
@@ -48,14 +48,15 @@ impl BuildSerializer for DataclassArgsBuilder {
                 let serializer = CombinedSerializer::build(schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
-                let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
-                fields.insert(name, SerField::new(py, key_py, alias, Some(serializer), true));
+                let alias = SerAlias::from_field(field_info)?;
+                let field = SerField::new(py, key_py, alias, Some(serializer), true).with_schema_default(schema)?;
+                fields.insert(name, field);
             }
         }
 
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
-        Ok(GeneralFieldsSerializer::new(fields, fields_mode, None, computed_fields).into())
+        Ok(GeneralFieldsSerializer::new(fields, fields_mode, None, computed_fields, None)?.into())
     }
 }
 
@@ -5,6 +5,7 @@ use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
 
 use crate::definitions::DefinitionsBuilder;
 use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
+use crate::serializers::cbor::DATETIME_TAG;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
@@ -24,6 +25,16 @@ pub(crate) fn time_to_string(py_time: &PyTime) -> PyResult<String> {
     pytime_as_time(py_time, None).map(|dt| dt.to_string())
 }
 
+/// RFC 8949 only allows the date/time string tag on strings with a UTC offset, so naive datetimes are
+/// written to CBOR as plain strings
+pub(crate) fn datetime_cbor_tag(py_dt: &PyDateTime) -> PyResult<Option<&'static str>> {
+    pydatetime_as_datetime(py_dt).map(|dt| dt.time.tz_offset.map(|_| DATETIME_TAG))
+}
+
+fn no_cbor_tag<T>(_value: T) -> PyResult<Option<&'static str>> {
+    Ok(None)
+}
+
 fn downcast_date_reject_datetime(py_date: &PyAny) -> PyResult<&PyDate> {
     if let Ok(py_date) = py_date.downcast::<PyDate>() {
         // because `datetime` is a subclass of `date` we have to check that the value is not a
@@ -37,7 +48,14 @@ fn downcast_date_reject_datetime(py_date: &PyAny) -> PyResult<&PyDate> {
 }
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $downcast:path, $convert_func:ident $(, $json_check_func:ident)?) => {
+    (
+        $struct_name:ident,
+        $expected_type:literal,
+        $downcast:path,
+        $convert_func:ident,
+        $cbor_tag_func:ident
+        $(, $json_check_func:ident)?
+    ) => {
         #[derive(Debug, Clone)]
         pub struct $struct_name;
 
@@ -66,7 +84,7 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match $downcast(value) {
                     Ok(py_value) => match extra.mode {
-                        SerMode::Json => {
+                        SerMode::Json | SerMode::JsonCompatible => {
                             let s = $convert_func(py_value)?;
                             Ok(s.into_py(py))
                         }
@@ -100,7 +118,16 @@ macro_rules! build_serializer {
                 match $downcast(value) {
                     Ok(py_value) => {
                         let s = $convert_func(py_value).map_err(py_err_se_err)?;
-                        serializer.serialize_str(&s)
+                        // only the CBOR serializer isn't human readable
+                        let cbor_tag = if serializer.is_human_readable() {
+                            None
+                        } else {
+                            $cbor_tag_func(py_value).map_err(py_err_se_err)?
+                        };
+                        match cbor_tag {
+                            Some(tag) => serializer.serialize_newtype_struct(tag, &s),
+                            None => serializer.serialize_str(&s),
+                        }
                     }
                     Err(_) => {
                         extra
@@ -122,7 +149,20 @@ build_serializer!(
     DatetimeSerializer,
     "datetime",
     PyAny::downcast::<PyDateTime>,
-    datetime_to_string
+    datetime_to_string,
+    datetime_cbor_tag
+);
+build_serializer!(
+    DateSerializer,
+    "date",
+    downcast_date_reject_datetime,
+    date_to_string,
+    no_cbor_tag
+);
+build_serializer!(
+    TimeSerializer,
+    "time",
+    PyAny::downcast::<PyTime>,
+    time_to_string,
+    no_cbor_tag
 );
-build_serializer!(DateSerializer, "date", downcast_date_reject_datetime, date_to_string);
-build_serializer!(TimeSerializer, "time", PyAny::downcast::<PyTime>, time_to_string);
@@ -4,12 +4,13 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionRef;
-use crate::definitions::DefinitionsBuilder;
+use crate::definitions::{expand_definition_templates, DefinitionsBuilder};
 
 use crate::tools::SchemaDict;
 
-use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
+use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 
 #[derive(Debug, Clone)]
 pub struct DefinitionsSerializerBuilder;
@@ -25,6 +26,8 @@ impl BuildSerializer for DefinitionsSerializerBuilder {
         let py = schema.py();
 
         let schema_definitions: &PyList = schema.get_as_req(intern!(py, "definitions"))?;
+        let inner_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let (schema_definitions, inner_schema) = expand_definition_templates(schema_definitions, inner_schema)?;
 
         for schema_definition in schema_definitions {
             let reference = schema_definition
@@ -34,8 +37,7 @@ impl BuildSerializer for DefinitionsSerializerBuilder {
             definitions.add_definition(reference, serializer)?;
         }
 
-        let inner_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
-        CombinedSerializer::build(inner_schema, config, definitions)
+        CombinedSerializer::build(inner_schema.downcast()?, config, definitions)
     }
 }
 
@@ -52,7 +54,14 @@ impl BuildSerializer for DefinitionRefSerializer {
         _config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        let schema_ref = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
+        let py = schema.py();
+        let schema_ref = schema.get_as_req(intern!(py, "schema_ref"))?;
+        if schema.contains(intern!(py, "params"))? {
+            return py_schema_err!(
+                "Definition `{}` is not a definition template, it can't have params",
+                schema_ref
+            );
+        }
         let definition = definitions.get_definition(schema_ref);
         Ok(Self { definition }.into())
     }
@@ -69,7 +78,13 @@ impl TypeSerializer for DefinitionRefSerializer {
         extra: &Extra,
     ) -> PyResult<PyObject> {
         let comb_serializer = self.definition.get().unwrap();
-        let value_id = extra.rec_guard.add(value, self.definition.id())?;
+        if is_shared_ref(value, extra) {
+            return comb_serializer.to_python(value, include, exclude, extra);
+        }
+        let value_id = match extra.rec_guard.add(value, self.definition.id(), extra.config.max_depth) {
+            Ok(value_id) => value_id,
+            Err(stop) => return extra.config.circular_ref_mode.to_python(value, stop),
+        };
         let r = comb_serializer.to_python(value, include, exclude, extra);
         extra.rec_guard.pop(value_id, self.definition.id());
         r
@@ -88,10 +103,13 @@ impl TypeSerializer for DefinitionRefSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         let comb_serializer = self.definition.get().unwrap();
-        let value_id = extra
-            .rec_guard
-            .add(value, self.definition.id())
-            .map_err(py_err_se_err)?;
+        if is_shared_ref(value, extra) {
+            return comb_serializer.serde_serialize(value, serializer, include, exclude, extra);
+        }
+        let value_id = match extra.rec_guard.add(value, self.definition.id(), extra.config.max_depth) {
+            Ok(value_id) => value_id,
+            Err(stop) => return extra.config.circular_ref_mode.serialize(value, stop, serializer),
+        };
         let r = comb_serializer.serde_serialize(value, serializer, include, exclude, extra);
         extra.rec_guard.pop(value_id, self.definition.id());
         r
@@ -105,3 +123,9 @@ impl TypeSerializer for DefinitionRefSerializer {
         self.definition.get().unwrap().retry_with_lax_check()
     }
 }
+
+/// With `shared_refs`, a model instance which has already been serialized is serialized as a reference,
+/// so it can't recurse and there's no need to check for circular references
+fn is_shared_ref(value: &PyAny, extra: &Extra) -> bool {
+    matches!(extra.shared_refs, Some(shared_refs) if shared_refs.get(value).is_some())
+}
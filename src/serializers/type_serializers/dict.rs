@@ -11,8 +11,8 @@ use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, SerMode, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PathItem,
+    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -85,13 +85,20 @@ impl TypeSerializer for DictSerializer {
 
                 let new_dict = PyDict::new(py);
                 for (key, value) in py_dict {
-                    let op_next = self.filter.key_filter(key, include, exclude)?;
+                    let op_next = self.filter.key_filter(key, value, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let value_path = extra.path_item(PathItem::Key(key));
+                        let value_extra = Extra {
+                            path: Some(&value_path),
+                            ..*extra
+                        };
                         let key = match extra.mode {
-                            SerMode::Json => self.key_serializer.json_key(key, extra)?.into_py(py),
+                            SerMode::Json | SerMode::JsonCompatible => {
+                                self.key_serializer.json_key(key, extra)?.into_py(py)
+                            }
                             _ => self.key_serializer.to_python(key, None, None, extra)?,
                         };
-                        let value = value_serializer.to_python(value, next_include, next_exclude, extra)?;
+                        let value = value_serializer.to_python(value, next_include, next_exclude, &value_extra)?;
                         new_dict.set_item(key, value)?;
                     }
                 }
@@ -123,11 +130,19 @@ impl TypeSerializer for DictSerializer {
                 let value_serializer = self.value_serializer.as_ref();
 
                 for (key, value) in py_dict {
-                    let op_next = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
+                    let op_next = self
+                        .filter
+                        .key_filter(key, value, include, exclude)
+                        .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let value_path = extra.path_item(PathItem::Key(key));
+                        let value_extra = Extra {
+                            path: Some(&value_path),
+                            ..*extra
+                        };
                         let key = key_serializer.json_key(key, extra).map_err(py_err_se_err)?;
                         let value_serialize =
-                            PydanticSerializer::new(value, value_serializer, next_include, next_exclude, extra);
+                            PydanticSerializer::new(value, value_serializer, next_include, next_exclude, &value_extra);
                         map.serialize_entry(&key, &value_serialize)?;
                     }
                 }
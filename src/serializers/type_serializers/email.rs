@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+use crate::definitions::DefinitionsBuilder;
+
+use super::string::StrSerializer;
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    TypeSerializer,
+};
+
+pub struct EmailBuilder;
+
+impl BuildSerializer for EmailBuilder {
+    const EXPECTED_TYPE: &'static str = "email";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
+
+/// Serializes the `(name, email)` tuples from `name-email` validation, as `name <email>` in JSON
+#[derive(Debug, Clone)]
+pub struct NameEmailSerializer;
+
+impl_py_gc_traverse!(NameEmailSerializer {});
+
+impl BuildSerializer for NameEmailSerializer {
+    const EXPECTED_TYPE: &'static str = "name-email";
+
+    fn build(
+        _schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+/// Format a name and address like `email.utils.formataddr`, quoting the name if it contains special characters
+fn format_name_email(value: &PyAny) -> Option<String> {
+    let (name, email) = value.downcast::<PyTuple>().ok()?.extract::<(&str, &str)>().ok()?;
+    if name.chars().any(|c| "()<>@,:;.\"[]\\".contains(c)) {
+        let escaped = name.replace('\\', "\\\\").replace('"', "\\\"");
+        Some(format!("\"{escaped}\" <{email}>"))
+    } else {
+        Some(format!("{name} <{email}>"))
+    }
+}
+
+impl TypeSerializer for NameEmailSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match format_name_email(value) {
+            Some(formatted) => match extra.mode {
+                SerMode::Json | SerMode::JsonCompatible => Ok(formatted.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match format_name_email(key) {
+            Some(formatted) => Ok(Cow::Owned(formatted)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match format_name_email(value) {
+            Some(formatted) => serializer.serialize_str(&formatted),
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
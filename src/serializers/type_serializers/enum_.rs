@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    TypeSerializer,
+};
+
+/// Enum members are serialized the same as when their type is inferred, this only checks the class so
+/// values which aren't members produce a warning
+#[derive(Debug, Clone)]
+pub struct EnumSerializer {
+    class: Py<PyType>,
+    name: String,
+}
+
+impl BuildSerializer for EnumSerializer {
+    const EXPECTED_TYPE: &'static str = "enum";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let class: &PyType = schema.get_as_req(intern!(schema.py(), "cls"))?;
+        Ok(Self {
+            class: class.into(),
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class.name()?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EnumSerializer { class });
+
+impl EnumSerializer {
+    fn is_member(&self, value: &PyAny) -> PyResult<bool> {
+        value.is_instance(self.class.as_ref(value.py()))
+    }
+}
+
+impl TypeSerializer for EnumSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        if !self.is_member(value)? {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+        }
+        infer_to_python(value, include, exclude, extra)
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        if !self.is_member(key)? {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+        }
+        infer_json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if !self.is_member(value).map_err(py_err_se_err)? {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+        }
+        infer_serialize(value, serializer, include, exclude, extra)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -3,10 +3,12 @@ use pyo3::{intern, prelude::*};
 
 use std::borrow::Cow;
 
+use serde::ser::Error;
 use serde::Serializer;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::config::InfNanMode;
+use crate::serializers::errors::{PydanticSerializationError, SERIALIZATION_ERR_MARKER};
 use crate::tools::SchemaDict;
 
 use super::simple::to_str_json_key;
@@ -48,9 +50,28 @@ impl TypeSerializer for FloatSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Float) {
+            IsType::Exact | IsType::Subclass
+                if matches!(extra.mode, SerMode::JsonCompatible) && self.inf_nan_mode == InfNanMode::Null =>
+            {
+                // match the output of `to_json`, where `inf` and `nan` aren't valid
+                let v = value.extract::<f64>()?;
+                if v.is_nan() || v.is_infinite() {
+                    Ok(py.None())
+                } else {
+                    Ok(v.to_object(py))
+                }
+            }
+            IsType::Exact | IsType::Subclass
+                if matches!(extra.mode, SerMode::Json | SerMode::JsonCompatible)
+                    && self.inf_nan_mode == InfNanMode::Error =>
+            {
+                let v = value.extract::<f64>()?;
+                check_finite(v, &self.inf_nan_mode)?;
+                Ok(v.to_object(py))
+            }
             IsType::Exact => Ok(value.into_py(py)),
             IsType::Subclass => match extra.mode {
-                SerMode::Json => {
+                SerMode::Json | SerMode::JsonCompatible => {
                     let rust_value = value.extract::<f64>()?;
                     Ok(rust_value.to_object(py))
                 }
@@ -82,13 +103,7 @@ impl TypeSerializer for FloatSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => {
-                if (v.is_nan() || v.is_infinite()) && self.inf_nan_mode == InfNanMode::Null {
-                    serializer.serialize_none()
-                } else {
-                    serializer.serialize_f64(v)
-                }
-            }
+            Ok(v) => serialize_f64(v, serializer, &self.inf_nan_mode),
             Err(_) => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
@@ -100,3 +115,37 @@ impl TypeSerializer for FloatSerializer {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Errors if `value` isn't finite and `inf_nan_mode` is `error`, shared with inference for JSON modes
+pub(crate) fn check_finite(value: f64, inf_nan_mode: &InfNanMode) -> PyResult<()> {
+    if *inf_nan_mode == InfNanMode::Error && !value.is_finite() {
+        Err(PydanticSerializationError::new_err(InfNanMode::non_finite_error(value)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Whether `value` is converted to `None` in `json-compatible-dict` mode, to match the output of `to_json`,
+/// shared with inference
+pub(crate) fn is_json_compatible_null(value: f64, mode: &SerMode, inf_nan_mode: &InfNanMode) -> bool {
+    matches!(mode, SerMode::JsonCompatible) && *inf_nan_mode == InfNanMode::Null && !value.is_finite()
+}
+
+/// Serialize a float following `ser_json_inf_nan`, shared with inference
+pub(crate) fn serialize_f64<S: Serializer>(
+    value: f64,
+    serializer: S,
+    inf_nan_mode: &InfNanMode,
+) -> Result<S::Ok, S::Error> {
+    if value.is_finite() {
+        return serializer.serialize_f64(value);
+    }
+    match inf_nan_mode {
+        InfNanMode::Null => serializer.serialize_none(),
+        InfNanMode::Constants => serializer.serialize_f64(value),
+        InfNanMode::Error => Err(Error::custom(format!(
+            "{SERIALIZATION_ERR_MARKER}{}",
+            InfNanMode::non_finite_error(value)
+        ))),
+    }
+}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use pyo3::exceptions::{PyAttributeError, PyRecursionError, PyRuntimeError};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyTuple};
 
 use pyo3::types::PyString;
 
@@ -147,27 +147,41 @@ impl FunctionPlainSerializer {
     ) -> PyResult<(bool, PyObject)> {
         let py = value.py();
         if self.when_used.should_use(value, extra) {
-            let v = if self.is_field_serializer {
-                if let Some(model) = extra.model {
-                    if self.info_arg {
-                        let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
-                        self.func.call1(py, (model, value, info))?
-                    } else {
-                        self.func.call1(py, (model, value))?
-                    }
+            let args = self.call_args(value, include, exclude, extra)?;
+            Ok((true, self.func.call1(py, args.as_ref(py))?))
+        } else {
+            Ok((false, value.into_py(py)))
+        }
+    }
+
+    /// Arguments for the function, built separately from `call` so their temporaries aren't kept on the stack
+    /// while the function runs, since functions which serialize nested values recurse through `call`
+    fn call_args(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<Py<PyTuple>> {
+        let py = value.py();
+        let args = if self.is_field_serializer {
+            if let Some(model) = extra.model {
+                if self.info_arg {
+                    let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
+                    (model, value, info).into_py(py)
                 } else {
-                    return Err(PyRuntimeError::new_err("Function plain serializer expected to be run inside the context of a model field but no model was found"));
+                    (model, value).into_py(py)
                 }
-            } else if self.info_arg {
-                let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
-                self.func.call1(py, (value, info))?
             } else {
-                self.func.call1(py, (value,))?
-            };
-            Ok((true, v))
+                return Err(PyRuntimeError::new_err("Function plain serializer expected to be run inside the context of a model field but no model was found"));
+            }
+        } else if self.info_arg {
+            let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
+            (value, info).into_py(py)
         } else {
-            Ok((false, value.into_py(py)))
-        }
+            (value,).into_py(py)
+        };
+        Ok(args)
     }
 
     fn get_fallback_serializer(&self) -> &CombinedSerializer {
@@ -184,7 +198,7 @@ fn on_error(py: Python, err: PyErr, function_name: &str, extra: &Extra) -> PyRes
         if extra.check.enabled() {
             Err(err)
         } else {
-            extra.warnings.custom_warning(ser_err.__repr__());
+            extra.warnings.custom_warning(ser_err.__repr__(), extra);
             Ok(())
         }
     } else if let Ok(err) = exception.extract::<PydanticSerializationError>() {
@@ -376,28 +390,41 @@ impl FunctionWrapSerializer {
     ) -> PyResult<(bool, PyObject)> {
         let py = value.py();
         if self.when_used.should_use(value, extra) {
-            let serialize = SerializationCallable::new(py, &self.serializer, include, exclude, extra);
-            let v = if self.is_field_serializer {
-                if let Some(model) = extra.model {
-                    if self.info_arg {
-                        let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
-                        self.func.call1(py, (model, value, serialize, info))?
-                    } else {
-                        self.func.call1(py, (model, value, serialize))?
-                    }
+            let args = self.call_args(value, include, exclude, extra)?;
+            Ok((true, self.func.call1(py, args.as_ref(py))?))
+        } else {
+            Ok((false, value.into_py(py)))
+        }
+    }
+
+    /// Arguments for the function, see `FunctionPlainSerializer::call_args`
+    fn call_args(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<Py<PyTuple>> {
+        let py = value.py();
+        let serialize = SerializationCallable::new(py, &self.serializer, include, exclude, extra);
+        let args = if self.is_field_serializer {
+            if let Some(model) = extra.model {
+                if self.info_arg {
+                    let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
+                    (model, value, serialize, info).into_py(py)
                 } else {
-                    return Err(PyRuntimeError::new_err("Function wrap serializer expected to be run inside the context of a model field but no model was found"));
+                    (model, value, serialize).into_py(py)
                 }
-            } else if self.info_arg {
-                let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
-                self.func.call1(py, (value, serialize, info))?
             } else {
-                self.func.call1(py, (value, serialize))?
-            };
-            Ok((true, v))
+                return Err(PyRuntimeError::new_err("Function wrap serializer expected to be run inside the context of a model field but no model was found"));
+            }
+        } else if self.info_arg {
+            let info = SerializationInfo::new(py, include, exclude, extra, self.is_field_serializer)?;
+            (value, serialize, info).into_py(py)
         } else {
-            Ok((false, value.into_py(py)))
-        }
+            (value, serialize).into_py(py)
+        };
+        Ok(args)
     }
 
     fn get_fallback_serializer(&self) -> &CombinedSerializer {
@@ -451,9 +478,9 @@ impl SerializationCallable {
 
         if let Some(index_key) = index_key {
             let filter = if let Ok(index) = index_key.extract::<usize>() {
-                self.filter.index_filter(index, include, exclude, None)?
+                self.filter.index_filter(index, value, include, exclude, None)?
             } else {
-                self.filter.key_filter(index_key, include, exclude)?
+                self.filter.key_filter(index_key, value, include, exclude)?
             };
             if let Some((next_include, next_exclude)) = filter {
                 let v = self.serializer.to_python(value, next_include, next_exclude, &extra)?;
@@ -56,7 +56,7 @@ impl TypeSerializer for GeneratorSerializer {
             Ok(py_iter) => {
                 let py = value.py();
                 match extra.mode {
-                    SerMode::Json => {
+                    SerMode::Json | SerMode::JsonCompatible => {
                         let item_serializer = self.item_serializer.as_ref();
 
                         let mut items = match value.len() {
@@ -65,7 +65,7 @@ impl TypeSerializer for GeneratorSerializer {
                         };
                         for (index, iter_result) in py_iter.enumerate() {
                             let element = iter_result?;
-                            let op_next = self.filter.index_filter(index, include, exclude, None)?;
+                            let op_next = self.filter.index_filter(index, element, include, exclude, None)?;
                             if let Some((next_include, next_exclude)) = op_next {
                                 items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
                             }
@@ -117,7 +117,7 @@ impl TypeSerializer for GeneratorSerializer {
                     let element = iter_result.map_err(py_err_se_err)?;
                     let op_next = self
                         .filter
-                        .index_filter(index, include, exclude, None)
+                        .index_filter(index, element, include, exclude, None)
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
@@ -189,7 +189,7 @@ impl SerializationIterator {
 
         for iter_result in iterator {
             let element = iter_result?;
-            let filter = self.filter.index_filter(self.index, include, exclude, None)?;
+            let filter = self.filter.index_filter(self.index, element, include, exclude, None)?;
             self.index += 1;
             if let Some((next_include, next_exclude)) = filter {
                 let v = self
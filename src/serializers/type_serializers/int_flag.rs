@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyType};
+use serde::ser::SerializeSeq;
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+use crate::validators::FlagMembers;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    PydanticSerializationError, SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone)]
+pub struct IntFlagSerializer {
+    class: Py<PyType>,
+    members: FlagMembers,
+    // serialize as a list of member names in JSON rather than the int value
+    as_names: bool,
+    name: String,
+}
+
+impl BuildSerializer for IntFlagSerializer {
+    const EXPECTED_TYPE: &'static str = "int-flag";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let as_names = match schema.get_as::<&str>(intern!(py, "serialize_as"))? {
+            Some("int") | None => false,
+            Some("names") => true,
+            Some(s) => return py_schema_err!("Invalid serialize_as: `{}`, expected `int` or `names`", s),
+        };
+        Ok(Self {
+            class: class.into(),
+            members: FlagMembers::from_class(class)?,
+            as_names,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class.name()?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IntFlagSerializer { class });
+
+impl IntFlagSerializer {
+    /// The int value of `value` if it's an instance of the flag class
+    fn flag_value(&self, value: &PyAny) -> PyResult<Option<u64>> {
+        if value.is_instance(self.class.as_ref(value.py()))? {
+            value.extract().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn names(&self, py: Python, value: u64) -> PyResult<Vec<&str>> {
+        self.members.names(value).ok_or_else(|| {
+            let class = self.class.as_ref(py);
+            PydanticSerializationError::new_err(format!(
+                "`{}` value {value} has bits which aren't members and can't be serialized as names",
+                class.name().unwrap_or("IntFlag"),
+            ))
+        })
+    }
+}
+
+impl TypeSerializer for IntFlagSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match self.flag_value(value)? {
+            Some(flag_value) => match extra.mode {
+                SerMode::Json | SerMode::JsonCompatible if self.as_names => {
+                    Ok(PyList::new(py, self.names(py, flag_value)?).into_py(py))
+                }
+                SerMode::Json | SerMode::JsonCompatible => Ok(flag_value.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match self.flag_value(key)? {
+            Some(flag_value) if self.as_names => Ok(Cow::Owned(self.names(key.py(), flag_value)?.join("|"))),
+            Some(flag_value) => Ok(Cow::Owned(flag_value.to_string())),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match self.flag_value(value).map_err(py_err_se_err)? {
+            Some(flag_value) if self.as_names => {
+                let names = self.names(value.py(), flag_value).map_err(py_err_se_err)?;
+                let mut seq = serializer.serialize_seq(Some(names.len()))?;
+                for name in names {
+                    seq.serialize_element(name)?;
+                }
+                seq.end()
+            }
+            Some(flag_value) => serializer.serialize_u64(flag_value),
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,127 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+use crate::definitions::DefinitionsBuilder;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+static ADDRESS_TYPES: GILOnceCell<Py<PyTuple>> = GILOnceCell::new();
+static NETWORK_TYPES: GILOnceCell<Py<PyTuple>> = GILOnceCell::new();
+
+fn import_types<'py>(py: Python<'py>, cell: &'py GILOnceCell<Py<PyTuple>>, attrs: [&str; 2]) -> PyResult<&'py PyTuple> {
+    Ok(cell
+        .get_or_try_init(py, || {
+            let ipaddress = py.import("ipaddress")?;
+            let types = attrs
+                .iter()
+                .map(|attr| ipaddress.getattr(*attr)?.extract::<&PyType>())
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok::<_, PyErr>(PyTuple::new(py, types).into())
+        })?
+        .as_ref(py))
+}
+
+/// Serializes `ipaddress` addresses and networks, as their canonical string form in JSON
+#[derive(Debug, Clone)]
+pub struct IpSerializer {
+    name: &'static str,
+    network: bool,
+}
+
+impl_py_gc_traverse!(IpSerializer {});
+
+macro_rules! ip_serializer_builder {
+    ($builder:ident, $expected_type:literal, $network:literal) => {
+        pub struct $builder;
+
+        impl BuildSerializer for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                _schema: &PyDict,
+                _config: Option<&PyDict>,
+                _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                Ok(IpSerializer {
+                    name: Self::EXPECTED_TYPE,
+                    network: $network,
+                }
+                .into())
+            }
+        }
+    };
+}
+
+ip_serializer_builder!(IpV4Builder, "ip-v4", false);
+ip_serializer_builder!(IpV6Builder, "ip-v6", false);
+ip_serializer_builder!(IpAnyBuilder, "ip-any", false);
+ip_serializer_builder!(IpNetworkBuilder, "ip-network", true);
+
+impl IpSerializer {
+    fn is_ip_type(&self, value: &PyAny) -> PyResult<bool> {
+        let py = value.py();
+        let types = if self.network {
+            import_types(py, &NETWORK_TYPES, ["IPv4Network", "IPv6Network"])?
+        } else {
+            import_types(py, &ADDRESS_TYPES, ["IPv4Address", "IPv6Address"])?
+        };
+        value.is_instance(types)
+    }
+}
+
+impl TypeSerializer for IpSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if self.is_ip_type(value)? {
+            match extra.mode {
+                SerMode::Json | SerMode::JsonCompatible => Ok(value.str()?.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        if self.is_ip_type(key)? {
+            Ok(Cow::Owned(key.str()?.to_string()))
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if self.is_ip_type(value).map_err(py_err_se_err)? {
+            let s = value.str().map_err(py_err_se_err)?;
+            serializer.serialize_str(s.to_str().map_err(py_err_se_err)?)
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
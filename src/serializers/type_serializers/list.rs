@@ -8,11 +8,12 @@ use serde::ser::SerializeSeq;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
+use crate::validators::get_deque;
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
-    SchemaFilter, TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PathItem,
+    PydanticSerializer, SchemaFilter, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -47,6 +48,21 @@ impl BuildSerializer for ListSerializer {
 
 impl_py_gc_traverse!(ListSerializer { item_serializer });
 
+impl ListSerializer {
+    /// Lists (including subclasses) and deques are serialized item by item, `None` means the value should be
+    /// serialized by inference
+    fn items<'py>(&self, value: &'py PyAny) -> PyResult<Option<(usize, Vec<&'py PyAny>)>> {
+        if let Ok(py_list) = value.downcast::<PyList>() {
+            Ok(Some((py_list.len(), py_list.iter().collect())))
+        } else if value.is_instance(get_deque(value.py())?)? {
+            let items = value.iter()?.collect::<PyResult<Vec<_>>>()?;
+            Ok(Some((items.len(), items)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 impl TypeSerializer for ListSerializer {
     fn to_python(
         &self,
@@ -55,21 +71,26 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match self.items(value)? {
+            Some((len, elements)) => {
                 let py = value.py();
                 let item_serializer = self.item_serializer.as_ref();
 
-                let mut items = Vec::with_capacity(py_list.len());
-                for (index, element) in py_list.iter().enumerate() {
-                    let op_next = self.filter.index_filter(index, include, exclude, value.len().ok())?;
+                let mut items = Vec::with_capacity(len);
+                for (index, element) in elements.into_iter().enumerate() {
+                    let op_next = self.filter.index_filter(index, element, include, exclude, Some(len))?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
+                        let item_path = extra.path_item(PathItem::Index(index));
+                        let item_extra = Extra {
+                            path: Some(&item_path),
+                            ..*extra
+                        };
+                        items.push(item_serializer.to_python(element, next_include, next_exclude, &item_extra)?);
                     }
                 }
                 Ok(items.into_py(py))
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
             }
@@ -88,25 +109,30 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
-                let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
+        match self.items(value).map_err(py_err_se_err)? {
+            Some((len, elements)) => {
+                let mut seq = serializer.serialize_seq(Some(len))?;
                 let item_serializer = self.item_serializer.as_ref();
 
-                for (index, element) in py_list.iter().enumerate() {
+                for (index, element) in elements.into_iter().enumerate() {
                     let op_next = self
                         .filter
-                        .index_filter(index, include, exclude, Some(py_list.len()))
+                        .index_filter(index, element, include, exclude, Some(len))
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let item_path = extra.path_item(PathItem::Index(index));
+                        let item_extra = Extra {
+                            path: Some(&item_path),
+                            ..*extra
+                        };
                         let item_serialize =
-                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, extra);
+                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, &item_extra);
                         seq.serialize_element(&item_serialize)?;
                     }
                 }
                 seq.end()
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
             }
@@ -1,13 +1,14 @@
 use std::borrow::Cow;
 
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyDict, PyList, PyString};
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyString};
 use pyo3::{intern, PyTypeInfo};
 
 use ahash::AHashSet;
 use serde::Serialize;
+use serde_json::{Number, Value};
 
-use crate::build_tools::py_schema_err;
+use crate::build_tools::{py_schema_err, py_schema_error_type};
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::{extract_i64, SchemaDict};
 
@@ -119,11 +120,11 @@ impl TypeSerializer for LiteralSerializer {
         let py = value.py();
         match self.check(value, extra)? {
             OutputValue::OkInt(int) => match extra.mode {
-                SerMode::Json => Ok(int.to_object(py)),
+                SerMode::Json | SerMode::JsonCompatible => Ok(int.to_object(py)),
                 _ => Ok(value.to_object(py)),
             },
             OutputValue::OkStr(s) => match extra.mode {
-                SerMode::Json => Ok(s.to_object(py)),
+                SerMode::Json | SerMode::JsonCompatible => Ok(s.to_object(py)),
                 _ => Ok(value.to_object(py)),
             },
             OutputValue::Ok => infer_to_python(value, include, exclude, extra),
@@ -169,3 +170,127 @@ impl TypeSerializer for LiteralSerializer {
         &self.name
     }
 }
+
+/// Serializer for a single constant value, the JSON output is computed when the serializer is built
+#[derive(Debug, Clone)]
+pub struct ConstSerializer {
+    expected: PyObject,
+    // the value used in JSON mode, e.g. the `.value` of an enum member
+    json_py: PyObject,
+    json_value: Value,
+    json_key: String,
+    name: String,
+}
+
+impl BuildSerializer for ConstSerializer {
+    const EXPECTED_TYPE: &'static str = "const";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let expected: &PyAny = schema.get_as_req(intern!(py, "expected"))?;
+        let (json_py, json_value) = const_json_value(expected)?;
+        let json_key = match json_value {
+            Value::String(ref s) => s.clone(),
+            ref v => v.to_string(),
+        };
+        Ok(Self {
+            expected: expected.into_py(py),
+            json_py: json_py.into_py(py),
+            json_value,
+            json_key,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, expected.repr()?),
+        }
+        .into())
+    }
+}
+
+fn const_json_value(value: &PyAny) -> PyResult<(&PyAny, Value)> {
+    let py = value.py();
+    let json_value = if value.is_none() {
+        Value::Null
+    } else if let Ok(py_bool) = value.downcast::<PyBool>() {
+        Value::Bool(py_bool.is_true())
+    } else if let Ok(int) = extract_i64(value) {
+        Value::from(int)
+    } else if let Ok(py_float) = value.downcast::<PyFloat>() {
+        match Number::from_f64(py_float.value()) {
+            Some(number) => Value::Number(number),
+            None => return py_schema_err!("`const` expected value must be a finite float, got {}", value.repr()?),
+        }
+    } else if let Ok(py_str) = value.downcast::<PyString>() {
+        Value::String(py_str.to_str()?.to_string())
+    } else if value.is_instance(py.import(intern!(py, "enum"))?.getattr(intern!(py, "Enum"))?)? {
+        let repr = value.repr()?;
+        return const_json_value(value.getattr(intern!(py, "value"))?)
+            .map_err(|e| py_schema_error_type!("`const` enum member {}:\n  {}", repr, e));
+    } else {
+        return py_schema_err!(
+            "`const` expected value must be None, a bool, int, float, str or Enum member, got {}",
+            value.repr()?
+        );
+    };
+    Ok((value, json_value))
+}
+
+impl ConstSerializer {
+    fn check(&self, value: &PyAny) -> PyResult<bool> {
+        let expected = self.expected.as_ref(value.py());
+        Ok(value.is(expected) || value.eq(expected)?)
+    }
+}
+
+impl_py_gc_traverse!(ConstSerializer { expected, json_py });
+
+impl TypeSerializer for ConstSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if self.check(value)? {
+            match extra.mode {
+                SerMode::Json | SerMode::JsonCompatible => Ok(self.json_py.clone_ref(py)),
+                _ => Ok(value.into_py(py)),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        if self.check(key)? {
+            Ok(Cow::Owned(self.json_key.clone()))
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if self.check(value).map_err(py_err_se_err)? {
+            self.json_value.serialize(serializer)
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -5,10 +5,14 @@ pub mod datetime_etc;
 pub mod decimal;
 pub mod definitions;
 pub mod dict;
+pub mod email;
+pub mod enum_;
 pub mod float;
 pub mod format;
 pub mod function;
 pub mod generator;
+pub mod int_flag;
+pub mod ip;
 pub mod json;
 pub mod json_or_python;
 pub mod list;
@@ -16,6 +20,7 @@ pub mod literal;
 pub mod model;
 pub mod nullable;
 pub mod other;
+pub mod secret;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
@@ -30,8 +35,8 @@ pub mod with_default;
 use super::computed_fields::ComputedFields;
 use super::config::utf8_py_error;
 use super::errors::{py_err_se_err, PydanticSerializationError};
-use super::extra::{Extra, ExtraOwned, SerCheck, SerMode};
-use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
+use super::extra::{Extra, ExtraOwned, PathItem, SerCheck, SerMode, SharedRefs};
+use super::fields::{FieldsMode, GeneralFieldsSerializer, SerAlias, SerField};
 use super::filter::{AnyFilter, SchemaFilter};
 use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
 use super::ob_type::{IsType, ObType};
@@ -8,11 +8,11 @@ use ahash::AHashMap;
 
 use super::{
     infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerAlias, SerCheck,
+    SerField, SharedRefs, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{generate_alias, py_schema_error_type, schema_or_config_same, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 use crate::tools::SchemaDict;
@@ -38,6 +38,8 @@ impl BuildSerializer for ModelFieldsBuilder {
 
         let fields_dict: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_dict.len());
+        let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
+        let mut attribute_keys: Vec<Py<PyString>> = Vec::new();
 
         let extra_serializer = match (schema.get_item(intern!(py, "extras_schema"))?, &fields_mode) {
             (Some(v), FieldsMode::ModelExtra) => Some(CombinedSerializer::build(v.extract()?, config, definitions)?),
@@ -55,19 +57,33 @@ impl BuildSerializer for ModelFieldsBuilder {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(key, SerField::new(py, key_py, None, None, true));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias = match SerAlias::from_field(field_info)? {
+                    Some(alias) => Some(alias),
+                    None => generate_alias(py, config, &key)?.map(|alias| SerAlias::Key(alias.to_string())),
+                };
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
 
-                fields.insert(key, SerField::new(py, key_py, alias, Some(serializer), true));
+                if from_attributes {
+                    attribute_keys.push(key_py.clone_ref(py));
+                }
+                let flatten = field_info.get_as(intern!(py, "flatten"))?.unwrap_or(false);
+                let field = SerField::new(py, key_py, alias, Some(serializer), true)
+                    .with_flatten(flatten)
+                    .with_schema_default(schema)?;
+                fields.insert(key, field);
             }
         }
 
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
-        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into())
+        let attribute_keys = from_attributes.then_some(attribute_keys);
+        Ok(
+            GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields, attribute_keys)?
+                .into(),
+        )
     }
 }
 
@@ -149,6 +165,33 @@ impl ModelSerializer {
             Ok(attrs)
         }
     }
+
+    /// Serialize `model` as `{"$ref": id}` if it's been serialized already, otherwise in full with an `"$id"` key
+    fn shared_ref_to_python(
+        &self,
+        shared_refs: &SharedRefs,
+        model: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = model.py();
+        if let Some(id) = shared_refs.get(model) {
+            let ref_dict = PyDict::new(py);
+            ref_dict.set_item(intern!(py, "$ref"), id)?;
+            return Ok(ref_dict.into_py(py));
+        }
+        let id = shared_refs.add(model);
+        let inner_value = self.get_inner_value(model, extra)?;
+        let output = self.serializer.to_python(inner_value, include, exclude, extra)?;
+        let Ok(fields_dict) = output.downcast::<PyDict>(py) else {
+            return Ok(output);
+        };
+        let output_dict = PyDict::new(py);
+        output_dict.set_item(intern!(py, "$id"), id)?;
+        output_dict.update(fields_dict.as_mapping())?;
+        Ok(output_dict.into_py(py))
+    }
 }
 
 impl_py_gc_traverse!(ModelSerializer { class, serializer });
@@ -161,6 +204,9 @@ impl TypeSerializer for ModelSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
+        if extra.keep_nested_model() && self.allow_value(value, extra)? {
+            return Ok(value.into_py(value.py()));
+        }
         let mut extra = Extra {
             model: Some(value),
             field_name: None,
@@ -178,6 +224,9 @@ impl TypeSerializer for ModelSerializer {
             })?;
             self.serializer.to_python(root, include, exclude, &extra)
         } else if self.allow_value(value, &extra)? {
+            if let Some(shared_refs) = extra.shared_refs {
+                return self.shared_ref_to_python(shared_refs, value, include, exclude, &extra);
+            }
             let inner_value = self.get_inner_value(value, &extra)?;
             self.serializer.to_python(inner_value, include, exclude, &extra)
         } else {
@@ -215,6 +264,11 @@ impl TypeSerializer for ModelSerializer {
             self.serializer
                 .serde_serialize(root, serializer, include, exclude, &extra)
         } else if self.allow_value(value, &extra).map_err(py_err_se_err)? {
+            if extra.shared_refs.is_some() {
+                // `"$id"` and `"$ref"` are added by `to_python`, `extra.mode` is JSON here
+                let py_value = self.to_python(value, include, exclude, &extra).map_err(py_err_se_err)?;
+                return infer_serialize(py_value.as_ref(value.py()), serializer, None, None, &extra);
+            }
             let inner_value = self.get_inner_value(value, &extra).map_err(py_err_se_err)?;
             self.serializer
                 .serde_serialize(inner_value, serializer, include, exclude, &extra)
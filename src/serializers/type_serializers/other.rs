@@ -44,6 +44,21 @@ impl BuildSerializer for CustomErrorBuilder {
     }
 }
 
+pub struct ModelConstraintBuilder;
+
+impl BuildSerializer for ModelConstraintBuilder {
+    const EXPECTED_TYPE: &'static str = "model-constraint";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let sub_schema: &PyDict = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        CombinedSerializer::build(sub_schema, config, definitions)
+    }
+}
+
 pub struct CallBuilder;
 
 impl BuildSerializer for CallBuilder {
@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyString};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::serializers::errors::PydanticSerializationError;
+use crate::tools::SchemaDict;
+
+use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
+
+/// What secrets are serialized as unless `reveal_secrets` is set
+const SECRET_MASK: &str = "**********";
+
+#[derive(Debug, Clone)]
+pub struct SecretSerializer {
+    serializer: Box<CombinedSerializer>,
+}
+
+impl BuildSerializer for SecretSerializer {
+    const EXPECTED_TYPE: &'static str = "secret";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let sub_schema = schema.get_as_req::<&PyDict>(intern!(schema.py(), "schema"))?;
+        Ok(Self {
+            serializer: Box::new(CombinedSerializer::build(sub_schema, config, definitions)?),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(SecretSerializer { serializer });
+
+impl SecretSerializer {
+    /// Check the value is a secret we know how to mask, `false` for `None` which has nothing to hide
+    fn check_value(&self, value: &PyAny, extra: &Extra) -> PyResult<bool> {
+        if value.is_none() {
+            Ok(false)
+        } else if value.downcast::<PyString>().is_ok() || value.downcast::<PyBytes>().is_ok() {
+            Ok(true)
+        } else {
+            // other values are masked too, to be safe
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            Ok(true)
+        }
+    }
+}
+
+impl TypeSerializer for SecretSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if extra.reveal_secrets {
+            self.serializer.to_python(value, include, exclude, extra)
+        } else if !self.check_value(value, extra)? {
+            Ok(value.into_py(py))
+        } else if !extra.mode.is_json() && value.downcast::<PyBytes>().is_ok() {
+            Ok(PyBytes::new(py, SECRET_MASK.as_bytes()).into_py(py))
+        } else {
+            Ok(SECRET_MASK.into_py(py))
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        if extra.reveal_secrets {
+            self.serializer.json_key(key, extra)
+        } else {
+            // every key would be masked to the same string, giving duplicate keys
+            Err(PydanticSerializationError::new_err(
+                "Secrets can't be used as JSON object keys unless `reveal_secrets` is set".to_string(),
+            ))
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if extra.reveal_secrets {
+            self.serializer
+                .serde_serialize(value, serializer, include, exclude, extra)
+        } else if self.check_value(value, extra).map_err(py_err_se_err)? {
+            serializer.serialize_str(SECRET_MASK)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        self.serializer.retry_with_lax_check()
+    }
+}
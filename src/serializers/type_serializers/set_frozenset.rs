@@ -7,12 +7,12 @@ use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet};
 use serde::ser::SerializeSeq;
 
 use crate::definitions::DefinitionsBuilder;
-use crate::tools::SchemaDict;
+use crate::tools::{sorted_items, SchemaDict};
 
 use super::any::AnySerializer;
 use super::{
-    infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SerMode,
-    TypeSerializer,
+    infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
+    SerMode, TypeSerializer,
 };
 
 macro_rules! build_serializer {
@@ -60,12 +60,20 @@ macro_rules! build_serializer {
                     Ok(py_set) => {
                         let item_serializer = self.item_serializer.as_ref();
 
+                        let json_mode = matches!(extra.mode, SerMode::Json | SerMode::JsonCompatible);
+
                         let mut items = Vec::with_capacity(py_set.len());
-                        for element in py_set.iter() {
-                            items.push(item_serializer.to_python(element, include, exclude, extra)?);
+                        if json_mode && extra.config.deterministic {
+                            for element in sorted_items(py_set)? {
+                                items.push(item_serializer.to_python(element, include, exclude, extra)?);
+                            }
+                        } else {
+                            for element in py_set.iter() {
+                                items.push(item_serializer.to_python(element, include, exclude, extra)?);
+                            }
                         }
                         match extra.mode {
-                            SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
+                            SerMode::Json | SerMode::JsonCompatible => Ok(PyList::new(py, items).into_py(py)),
                             _ => Ok(<$py_type>::new(py, &items)?.into_py(py)),
                         }
                     }
@@ -93,10 +101,18 @@ macro_rules! build_serializer {
                         let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
                         let item_serializer = self.item_serializer.as_ref();
 
-                        for value in py_set.iter() {
-                            let item_serialize =
-                                PydanticSerializer::new(value, item_serializer, include, exclude, extra);
-                            seq.serialize_element(&item_serialize)?;
+                        if extra.config.deterministic {
+                            for value in sorted_items(py_set).map_err(py_err_se_err)? {
+                                let item_serialize =
+                                    PydanticSerializer::new(value, item_serializer, include, exclude, extra);
+                                seq.serialize_element(&item_serialize)?;
+                            }
+                        } else {
+                            for value in py_set.iter() {
+                                let item_serialize =
+                                    PydanticSerializer::new(value, item_serializer, include, exclude, extra);
+                                seq.serialize_element(&item_serialize)?;
+                            }
                         }
                         seq.end()
                     }
@@ -115,7 +115,7 @@ macro_rules! build_simple_serializer {
                 match extra.ob_type_lookup.is_type(value, $ob_type) {
                     IsType::Exact => Ok(value.into_py(py)),
                     IsType::Subclass => match extra.mode {
-                        SerMode::Json => {
+                        SerMode::Json | SerMode::JsonCompatible => {
                             let rust_value = value.extract::<$rust_type>()?;
                             Ok(rust_value.to_object(py))
                         }
@@ -168,7 +168,111 @@ pub(crate) fn to_str_json_key(key: &PyAny) -> PyResult<Cow<str>> {
     Ok(key.str()?.to_string_lossy())
 }
 
-build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, to_str_json_key);
+#[derive(Debug, Clone)]
+pub struct IntSerializer;
+
+impl BuildSerializer for IntSerializer {
+    const EXPECTED_TYPE: &'static str = "int";
+
+    fn build(
+        _schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl_py_gc_traverse!(IntSerializer {});
+
+impl TypeSerializer for IntSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match extra.ob_type_lookup.is_type(value, ObType::Int) {
+            IsType::Exact => match extra.mode {
+                SerMode::Json | SerMode::JsonCompatible => extra.config.bigint_mode.int_to_json(py, value),
+                _ => Ok(value.into_py(py)),
+            },
+            IsType::Subclass => match extra.mode {
+                SerMode::Json | SerMode::JsonCompatible => {
+                    let int = value.extract::<Int>()?.to_object(py);
+                    extra.config.bigint_mode.int_to_json(py, int.as_ref(py))
+                }
+                _ => infer_to_python(value, include, exclude, extra),
+            },
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match extra.ob_type_lookup.is_type(key, ObType::Int) {
+            IsType::Exact | IsType::Subclass => to_str_json_key(key),
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.extract::<Int>() {
+            Ok(v) => extra.config.bigint_mode.serialize_int(&v, serializer),
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// `int64` and `uint64` are serialized like any other int
+pub struct Int64Builder;
+
+impl BuildSerializer for Int64Builder {
+    const EXPECTED_TYPE: &'static str = "int64";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        IntSerializer::build(schema, config, definitions)
+    }
+}
+
+pub struct UInt64Builder;
+
+impl BuildSerializer for UInt64Builder {
+    const EXPECTED_TYPE: &'static str = "uint64";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        IntSerializer::build(schema, config, definitions)
+    }
+}
 
 pub(crate) fn bool_json_key(key: &PyAny) -> PyResult<Cow<str>> {
     let v = if key.is_true().unwrap_or(false) {
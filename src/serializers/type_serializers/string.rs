@@ -39,7 +39,7 @@ impl TypeSerializer for StrSerializer {
         match extra.ob_type_lookup.is_type(value, ObType::Str) {
             IsType::Exact => Ok(value.into_py(py)),
             IsType::Subclass => match extra.mode {
-                SerMode::Json => Ok(value.extract::<&str>()?.into_py(py)),
+                SerMode::Json | SerMode::JsonCompatible => Ok(value.extract::<&str>()?.into_py(py)),
                 _ => Ok(value.into_py(py)),
             },
             IsType::False => {
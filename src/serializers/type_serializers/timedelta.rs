@@ -1,10 +1,14 @@
 use std::borrow::Cow;
+use std::str::FromStr;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::input::EitherTimedelta;
+use crate::serializers::config::TimedeltaMode;
+use crate::tools::SchemaDict;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
@@ -12,17 +16,34 @@ use super::{
 };
 
 #[derive(Debug, Clone)]
-pub struct TimeDeltaSerializer;
+pub struct TimeDeltaSerializer {
+    // overrides `ser_json_timedelta` from config when set via a `timedelta-mode` serialization schema
+    timedelta_mode: Option<TimedeltaMode>,
+}
 
 impl BuildSerializer for TimeDeltaSerializer {
     const EXPECTED_TYPE: &'static str = "timedelta";
 
     fn build(
-        _schema: &PyDict,
+        schema: &PyDict,
         _config: Option<&PyDict>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let py = schema.py();
+        let timedelta_mode = match schema.get_as::<&PyDict>(intern!(py, "serialization"))? {
+            Some(ser) => match ser.get_as::<&str>(intern!(py, "timedelta_mode"))? {
+                Some(mode) => Some(TimedeltaMode::from_str(mode)?),
+                None => None,
+            },
+            None => None,
+        };
+        Ok(Self { timedelta_mode }.into())
+    }
+}
+
+impl TimeDeltaSerializer {
+    fn timedelta_mode<'a>(&'a self, extra: &'a Extra) -> &'a TimedeltaMode {
+        self.timedelta_mode.as_ref().unwrap_or(&extra.config.timedelta_mode)
     }
 }
 
@@ -37,10 +58,9 @@ impl TypeSerializer for TimeDeltaSerializer {
         extra: &Extra,
     ) -> PyResult<PyObject> {
         match extra.mode {
-            SerMode::Json => match EitherTimedelta::try_from(value) {
-                Ok(either_timedelta) => extra
-                    .config
-                    .timedelta_mode
+            SerMode::Json | SerMode::JsonCompatible => match EitherTimedelta::try_from(value) {
+                Ok(either_timedelta) => self
+                    .timedelta_mode(extra)
                     .either_delta_to_json(value.py(), &either_timedelta),
                 Err(_) => {
                     extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
@@ -53,7 +73,7 @@ impl TypeSerializer for TimeDeltaSerializer {
 
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
         match EitherTimedelta::try_from(key) {
-            Ok(either_timedelta) => extra.config.timedelta_mode.json_key(key.py(), &either_timedelta),
+            Ok(either_timedelta) => self.timedelta_mode(extra).json_key(key.py(), &either_timedelta),
             Err(_) => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -71,9 +91,7 @@ impl TypeSerializer for TimeDeltaSerializer {
     ) -> Result<S::Ok, S::Error> {
         match EitherTimedelta::try_from(value) {
             Ok(either_timedelta) => {
-                extra
-                    .config
-                    .timedelta_mode
+                self.timedelta_mode(extra)
                     .timedelta_serialize(value.py(), &either_timedelta, serializer)
             }
             Err(_) => {
@@ -5,13 +5,14 @@ use std::borrow::Cow;
 
 use serde::ser::SerializeSeq;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
-    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
+    PathItem, PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -66,13 +67,18 @@ impl TypeSerializer for TupleVariableSerializer {
                 for (index, element) in py_tuple.iter().enumerate() {
                     let op_next = self
                         .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))?;
+                        .index_filter(index, element, include, exclude, Some(py_tuple.len()))?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
+                        let item_path = extra.path_item(PathItem::Index(index));
+                        let item_extra = Extra {
+                            path: Some(&item_path),
+                            ..*extra
+                        };
+                        items.push(item_serializer.to_python(element, next_include, next_exclude, &item_extra)?);
                     }
                 }
                 match extra.mode {
-                    SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
+                    SerMode::Json | SerMode::JsonCompatible => Ok(PyList::new(py, items).into_py(py)),
                     _ => Ok(PyTuple::new(py, items).into_py(py)),
                 }
             }
@@ -118,11 +124,16 @@ impl TypeSerializer for TupleVariableSerializer {
                 for (index, element) in py_tuple.iter().enumerate() {
                     let op_next = self
                         .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))
+                        .index_filter(index, element, include, exclude, Some(py_tuple.len()))
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let item_path = extra.path_item(PathItem::Index(index));
+                        let item_extra = Extra {
+                            path: Some(&item_path),
+                            ..*extra
+                        };
                         let item_serialize =
-                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, extra);
+                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, &item_extra);
                         seq.serialize_element(&item_serialize)?;
                     }
                 }
@@ -144,6 +155,7 @@ impl TypeSerializer for TupleVariableSerializer {
 pub struct TuplePositionalSerializer {
     items_serializers: Vec<CombinedSerializer>,
     extra_serializer: Box<CombinedSerializer>,
+    variadic_item_index: Option<usize>,
     filter: SchemaFilter<usize>,
     name: String,
 }
@@ -168,6 +180,11 @@ impl BuildSerializer for TuplePositionalSerializer {
             .map(|item| CombinedSerializer::build(item.downcast()?, config, definitions))
             .collect::<PyResult<_>>()?;
 
+        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
+        if variadic_item_index.map_or(false, |index| index >= items_serializers.len()) {
+            return py_schema_err!("`variadic_item_index` is out of range");
+        }
+
         let descr = items_serializers
             .iter()
             .map(TypeSerializer::get_name)
@@ -176,6 +193,7 @@ impl BuildSerializer for TuplePositionalSerializer {
         Ok(Self {
             items_serializers,
             extra_serializer: Box::new(extra_serializer),
+            variadic_item_index,
             filter: SchemaFilter::from_schema(schema)?,
             name: format!("tuple[{descr}]"),
         }
@@ -188,6 +206,28 @@ impl_py_gc_traverse!(TuplePositionalSerializer {
     extra_serializer
 });
 
+impl TuplePositionalSerializer {
+    /// The serializer for the item at `index` of a tuple with `len` items
+    fn item_serializer(&self, index: usize, len: usize) -> &CombinedSerializer {
+        match self.variadic_item_index {
+            Some(variadic_item_index) if index >= variadic_item_index => {
+                // items after the variadic item are matched from the end of the tuple
+                let from_end = len - index;
+                let tail_len = self.items_serializers.len() - variadic_item_index - 1;
+                if from_end <= tail_len {
+                    &self.items_serializers[self.items_serializers.len() - from_end]
+                } else {
+                    &self.items_serializers[variadic_item_index]
+                }
+            }
+            _ => self
+                .items_serializers
+                .get(index)
+                .unwrap_or(self.extra_serializer.as_ref()),
+        }
+    }
+}
+
 impl TypeSerializer for TuplePositionalSerializer {
     fn to_python(
         &self,
@@ -200,34 +240,23 @@ impl TypeSerializer for TuplePositionalSerializer {
             Ok(py_tuple) => {
                 let py = value.py();
 
-                let mut py_tuple_iter = py_tuple.iter();
-                let mut items = Vec::with_capacity(py_tuple.len());
-                for (index, serializer) in self.items_serializers.iter().enumerate() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        items.push(serializer.to_python(element, next_include, next_exclude, extra)?);
-                    }
-                }
-                let expected_length = self.items_serializers.len();
-                let extra_serializer = self.extra_serializer.as_ref();
-                for (index2, element) in py_tuple_iter.enumerate() {
-                    let index = index2 + expected_length;
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))?;
+                let len = py_tuple.len();
+                let mut items = Vec::with_capacity(len);
+                for (index, element) in py_tuple.iter().enumerate() {
+                    let op_next = self.filter.index_filter(index, element, include, exclude, Some(len))?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(extra_serializer.to_python(element, next_include, next_exclude, extra)?);
+                        let item_path = extra.path_item(PathItem::Index(index));
+                        let item_extra = Extra {
+                            path: Some(&item_path),
+                            ..*extra
+                        };
+                        let serializer = self.item_serializer(index, len);
+                        items.push(serializer.to_python(element, next_include, next_exclude, &item_extra)?);
                     }
                 }
 
                 match extra.mode {
-                    SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
+                    SerMode::Json | SerMode::JsonCompatible => Ok(PyList::new(py, items).into_py(py)),
                     _ => Ok(PyTuple::new(py, items).into_py(py)),
                 }
             }
@@ -241,19 +270,10 @@ impl TypeSerializer for TuplePositionalSerializer {
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
         match key.downcast::<PyTuple>() {
             Ok(py_tuple) => {
-                let mut py_tuple_iter = py_tuple.iter();
-
+                let len = py_tuple.len();
                 let mut key_builder = KeyBuilder::new();
-                for serializer in &self.items_serializers {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
-                    key_builder.push(&serializer.json_key(element, extra)?);
-                }
-                let extra_serializer = self.extra_serializer.as_ref();
-                for element in py_tuple_iter {
-                    key_builder.push(&extra_serializer.json_key(element, extra)?);
+                for (index, element) in py_tuple.iter().enumerate() {
+                    key_builder.push(&self.item_serializer(index, len).json_key(element, extra)?);
                 }
                 Ok(Cow::Owned(key_builder.finish()))
             }
@@ -276,35 +296,22 @@ impl TypeSerializer for TuplePositionalSerializer {
             Ok(py_tuple) => {
                 let py_tuple: &PyTuple = py_tuple.downcast().map_err(py_err_se_err)?;
 
-                let mut py_tuple_iter = py_tuple.iter();
-                let mut seq = serializer.serialize_seq(Some(py_tuple.len()))?;
-                for (index, serializer) in self.items_serializers.iter().enumerate() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))
-                        .map_err(py_err_se_err)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let item_serialize =
-                            PydanticSerializer::new(element, serializer, next_include, next_exclude, extra);
-                        seq.serialize_element(&item_serialize)?;
-                    }
-                }
-
-                let expected_length = self.items_serializers.len();
-                let extra_serializer = self.extra_serializer.as_ref();
-                for (index2, element) in py_tuple_iter.enumerate() {
-                    let index = index2 + expected_length;
+                let len = py_tuple.len();
+                let mut seq = serializer.serialize_seq(Some(len))?;
+                for (index, element) in py_tuple.iter().enumerate() {
                     let op_next = self
                         .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))
+                        .index_filter(index, element, include, exclude, Some(len))
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let item_path = extra.path_item(PathItem::Index(index));
+                        let item_extra = Extra {
+                            path: Some(&item_path),
+                            ..*extra
+                        };
+                        let item_serializer = self.item_serializer(index, len);
                         let item_serialize =
-                            PydanticSerializer::new(element, extra_serializer, next_include, next_exclude, extra);
+                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, &item_extra);
                         seq.serialize_element(&item_serialize)?;
                     }
                 }
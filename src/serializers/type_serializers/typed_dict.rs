@@ -5,11 +5,13 @@ use pyo3::types::{PyDict, PyString};
 use ahash::AHashMap;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, schema_or_config, ExtraBehavior};
+use crate::build_tools::{generate_alias, py_schema_error_type, schema_or_config, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
-use super::{BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, SerField};
+use super::{
+    BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, SerAlias, SerField,
+};
 
 #[derive(Debug, Clone)]
 pub struct TypedDictBuilder;
@@ -27,7 +29,7 @@ impl BuildSerializer for TypedDictBuilder {
         let total =
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
 
-        let fields_mode = match ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)? {
+        let fields_mode = match ExtraBehavior::from_typed_dict_schema(py, schema, config)? {
             ExtraBehavior::Allow => FieldsMode::TypedDictAllow,
             _ => FieldsMode::SimpleDict,
         };
@@ -54,17 +56,21 @@ impl BuildSerializer for TypedDictBuilder {
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
                 fields.insert(key, SerField::new(py, key_py, None, None, required));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let alias = match SerAlias::from_field(field_info)? {
+                    Some(alias) => Some(alias),
+                    None => generate_alias(py, config, &key)?.map(|alias| SerAlias::Key(alias.to_string())),
+                };
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
-                fields.insert(key, SerField::new(py, key_py, alias, Some(serializer), required));
+                let field = SerField::new(py, key_py, alias, Some(serializer), required).with_schema_default(schema)?;
+                fields.insert(key, field);
             }
         }
 
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
-        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into())
+        Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields, None)?.into())
     }
 }
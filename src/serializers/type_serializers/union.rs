@@ -10,7 +10,7 @@ use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
-    SerCheck, TypeSerializer,
+    SerCheck, SharedRefs, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -80,10 +80,11 @@ impl TypeSerializer for UnionSerializer {
         new_extra.check = SerCheck::Strict;
 
         for comb_serializer in &self.choices {
+            let mark = shared_refs_mark(extra);
             match comb_serializer.to_python(value, include, exclude, &new_extra) {
                 Ok(v) => return Ok(v),
                 Err(err) => match err.is_instance_of::<PydanticSerializationUnexpectedValue>(value.py()) {
-                    true => (),
+                    true => shared_refs_rollback(extra, mark),
                     false => return Err(err),
                 },
             }
@@ -91,10 +92,11 @@ impl TypeSerializer for UnionSerializer {
         if self.retry_with_lax_check() {
             new_extra.check = SerCheck::Lax;
             for comb_serializer in &self.choices {
+                let mark = shared_refs_mark(extra);
                 match comb_serializer.to_python(value, include, exclude, &new_extra) {
                     Ok(v) => return Ok(v),
                     Err(err) => match err.is_instance_of::<PydanticSerializationUnexpectedValue>(value.py()) {
-                        true => (),
+                        true => shared_refs_rollback(extra, mark),
                         false => return Err(err),
                     },
                 }
@@ -146,10 +148,11 @@ impl TypeSerializer for UnionSerializer {
         let mut new_extra = extra.clone();
         new_extra.check = SerCheck::Strict;
         for comb_serializer in &self.choices {
+            let mark = shared_refs_mark(extra);
             match comb_serializer.to_python(value, include, exclude, &new_extra) {
                 Ok(v) => return infer_serialize(v.as_ref(py), serializer, None, None, extra),
                 Err(err) => match err.is_instance_of::<PydanticSerializationUnexpectedValue>(py) {
-                    true => (),
+                    true => shared_refs_rollback(extra, mark),
                     false => return Err(py_err_se_err(err)),
                 },
             }
@@ -157,10 +160,11 @@ impl TypeSerializer for UnionSerializer {
         if self.retry_with_lax_check() {
             new_extra.check = SerCheck::Lax;
             for comb_serializer in &self.choices {
+                let mark = shared_refs_mark(extra);
                 match comb_serializer.to_python(value, include, exclude, &new_extra) {
                     Ok(v) => return infer_serialize(v.as_ref(py), serializer, None, None, extra),
                     Err(err) => match err.is_instance_of::<PydanticSerializationUnexpectedValue>(py) {
-                        true => (),
+                        true => shared_refs_rollback(extra, mark),
                         false => return Err(py_err_se_err(err)),
                     },
                 }
@@ -180,6 +184,17 @@ impl TypeSerializer for UnionSerializer {
     }
 }
 
+fn shared_refs_mark(extra: &Extra) -> Option<usize> {
+    extra.shared_refs.map(SharedRefs::mark)
+}
+
+/// A failed choice may have assigned ids to models which aren't in the output
+fn shared_refs_rollback(extra: &Extra, mark: Option<usize>) {
+    if let (Some(shared_refs), Some(mark)) = (extra.shared_refs, mark) {
+        shared_refs.rollback(mark);
+    }
+}
+
 pub struct TaggedUnionBuilder;
 
 impl BuildSerializer for TaggedUnionBuilder {
@@ -198,6 +213,9 @@ impl BuildSerializer for TaggedUnionBuilder {
                 choices.push(CombinedSerializer::build(choice_schema, config, definitions)?);
             }
         }
+        if let Some(otherwise_schema) = schema.get_as::<&PyDict>(intern!(schema.py(), "otherwise"))? {
+            choices.push(CombinedSerializer::build(otherwise_schema, config, definitions)?);
+        }
         UnionSerializer::from_choices(choices)
     }
 }
@@ -42,7 +42,7 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match value.extract::<$extract>() {
                     Ok(py_url) => match extra.mode {
-                        SerMode::Json => Ok(py_url.__str__().into_py(py)),
+                        SerMode::Json | SerMode::JsonCompatible => Ok(py_url.__str__().into_py(py)),
                         _ => Ok(value.into_py(py)),
                     },
                     Err(_) => {
@@ -42,7 +42,7 @@ impl TypeSerializer for UuidSerializer {
         let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Uuid) {
             IsType::Exact | IsType::Subclass => match extra.mode {
-                SerMode::Json => Ok(uuid_to_string(value)?.into_py(py)),
+                SerMode::Json | SerMode::JsonCompatible => Ok(uuid_to_string(value)?.into_py(py)),
                 _ => Ok(value.into_py(py)),
             },
             IsType::False => {
@@ -1,18 +1,103 @@
 use std::borrow::Cow;
+use std::sync::OnceLock;
 
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 use crate::validators::DefaultType;
 
 use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 
+/// How values are compared to the default to decide if they should be excluded with `exclude_defaults`
+#[derive(Debug, Clone, Copy, Default)]
+enum DefaultComparison {
+    #[default]
+    Equality,
+    Identity,
+    Serialized,
+}
+
+impl DefaultComparison {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "default_comparison"))? {
+            Some("equality") | None => Ok(Self::Equality),
+            Some("identity") => Ok(Self::Identity),
+            Some("serialized") => Ok(Self::Serialized),
+            Some(s) => py_schema_err!(
+                "Invalid default_comparison: `{}`, expected `equality`, `identity` or `serialized`",
+                s
+            ),
+        }
+    }
+}
+
+/// A default which values are compared with to decide if they should be excluded with `exclude_defaults`
 #[derive(Debug, Clone)]
-pub struct WithDefaultSerializer {
+pub(crate) struct SerDefault {
     default: DefaultType,
+    comparison: DefaultComparison,
+    // the result of calling `default_factory`, it's called at most once to compare values with
+    factory_default: OnceLock<PyObject>,
+}
+
+impl_py_gc_traverse!(SerDefault {
+    default,
+    factory_default
+});
+
+impl SerDefault {
+    pub fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        Ok(Self {
+            default: DefaultType::new(schema)?,
+            comparison: DefaultComparison::from_schema(schema)?,
+            factory_default: OnceLock::new(),
+        })
+    }
+
+    fn default_value<'py>(&'py self, py: Python<'py>) -> PyResult<Option<&'py PyAny>> {
+        match self.default {
+            DefaultType::Default(ref default) => Ok(Some(default.as_ref(py))),
+            DefaultType::DefaultFactory(ref default_factory) => {
+                let factory_default = match self.factory_default.get() {
+                    Some(factory_default) => factory_default,
+                    None => {
+                        let factory_default = default_factory.call0(py)?;
+                        // if another thread has set the value in the meantime, we use theirs
+                        self.factory_default.get_or_init(|| factory_default)
+                    }
+                };
+                Ok(Some(factory_default.as_ref(py)))
+            }
+            // there's no data to call the factory with, so values are never treated as the default
+            DefaultType::DefaultFactoryTakesData(_) | DefaultType::None => Ok(None),
+        }
+    }
+
+    /// Whether `value` is the default, `serializer` is used to compare serialized values
+    pub fn is_default(&self, value: &PyAny, serializer: &CombinedSerializer, extra: &Extra) -> PyResult<bool> {
+        let Some(default) = self.default_value(value.py())? else {
+            return Ok(false);
+        };
+        match self.comparison {
+            DefaultComparison::Equality => value.eq(default),
+            DefaultComparison::Identity => Ok(value.is(default)),
+            DefaultComparison::Serialized => {
+                let py = value.py();
+                let value = serializer.to_python(value, None, None, extra)?;
+                let default = serializer.to_python(default, None, None, extra)?;
+                value.as_ref(py).eq(default)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WithDefaultSerializer {
+    default: SerDefault,
     serializer: Box<CombinedSerializer>,
 }
 
@@ -25,7 +110,7 @@ impl BuildSerializer for WithDefaultSerializer {
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
-        let default = DefaultType::new(schema)?;
+        let default = SerDefault::from_schema(schema)?;
 
         let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
         let serializer = Box::new(CombinedSerializer::build(sub_schema, config, definitions)?);
@@ -71,7 +156,7 @@ impl TypeSerializer for WithDefaultSerializer {
         self.serializer.retry_with_lax_check()
     }
 
-    fn get_default(&self, py: Python) -> PyResult<Option<PyObject>> {
-        self.default.default_value(py)
+    fn is_default(&self, value: &PyAny, extra: &Extra) -> PyResult<bool> {
+        self.default.is_default(value, &self.serializer, extra)
     }
 }
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use pyo3::exceptions::{PyKeyError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyInt, PyString};
+use pyo3::types::{PyDict, PyInt, PyList, PyString};
 use pyo3::{intern, FromPyObject, PyTypeInfo};
 
 pub trait SchemaDict<'py> {
@@ -99,6 +99,47 @@ pub fn safe_repr(v: &PyAny) -> Cow<str> {
     }
 }
 
+/// Sort the items of a set so iterating over them doesn't depend on hash seeds. If the items can't be compared
+/// with each other, e.g. ints and strs, they're ordered by the qualified name of their type first. Items of the same
+/// type which can't be compared raise a `TypeError`, since a fallback like `repr()` often includes memory addresses
+/// so wouldn't be stable across runs
+pub fn sorted_items(iterable: &PyAny) -> PyResult<&PyList> {
+    let py = iterable.py();
+    let items = PyList::new(py, iterable.iter()?.collect::<PyResult<Vec<_>>>()?);
+    match items.sort() {
+        Ok(()) => Ok(items),
+        Err(err) if err.is_instance_of::<PyTypeError>(py) => sorted_by_type(items),
+        Err(err) => Err(err),
+    }
+}
+
+fn sorted_by_type(items: &PyList) -> PyResult<&PyList> {
+    let py = items.py();
+    let mut by_type: Vec<(String, Vec<&PyAny>)> = Vec::new();
+    for item in items {
+        let item_type = item.get_type();
+        let module = item_type.getattr(intern!(py, "__module__"))?;
+        let type_name = format!("{}.{}", module.str()?.to_str()?, item_type.name()?);
+        match by_type.iter_mut().find(|(name, _)| *name == type_name) {
+            Some((_, group)) => group.push(item),
+            None => by_type.push((type_name, vec![item])),
+        }
+    }
+    by_type.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let sorted = PyList::empty(py);
+    for (type_name, group) in by_type {
+        let group = PyList::new(py, group);
+        if let Err(err) = group.sort() {
+            return py_err!(PyTypeError; "Items of type `{}` can't be ordered deterministically: {}", type_name, err);
+        }
+        for item in group {
+            sorted.append(item)?;
+        }
+    }
+    Ok(sorted)
+}
+
 pub fn extract_i64(v: &PyAny) -> PyResult<i64> {
     if PyInt::is_type_of(v) {
         v.extract()
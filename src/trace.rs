@@ -0,0 +1,233 @@
+//! Opt-in [`tracing`](https://docs.rs/tracing) spans for schema builds and top-level validation and serialization
+//! calls (at the `INFO` level), and nested models, dataclasses and function validators (at the `DEBUG` level).
+//! Spans are only emitted when pydantic-core is built with the `tracing` cargo feature, which enables the optional
+//! `tracing` dependency, otherwise `TraceSpan` compiles to nothing and `set_trace_subscriber` raises an error.
+//! Any `tracing` subscriber can collect the spans, `set_trace_subscriber` installs one which reports them to a
+//! Python callback.
+#[cfg(feature = "tracing")]
+use std::cell::RefCell;
+use std::marker::PhantomData;
+#[cfg(feature = "tracing")]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+#[cfg(feature = "tracing")]
+use std::time::{Duration, Instant};
+
+use pyo3::exceptions::PyRuntimeError;
+#[cfg(feature = "tracing")]
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+#[cfg(feature = "tracing")]
+use pyo3::sync::GILProtected;
+#[cfg(feature = "tracing")]
+use tracing::field::{Field, Visit};
+#[cfg(feature = "tracing")]
+use tracing::span::{Attributes, Id, Record};
+#[cfg(feature = "tracing")]
+use tracing::subscriber::Interest;
+#[cfg(feature = "tracing")]
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// The name of every span, the operation and the name of the validator or serializer are recorded as the
+/// `operation` and `schema` fields
+#[cfg(feature = "tracing")]
+const SPAN_NAME: &str = "pydantic_core";
+
+/// A `tracing` span, entered until it's dropped
+pub(crate) struct TraceSpan<'a> {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+    _target: PhantomData<&'a str>,
+}
+
+impl<'a> TraceSpan<'a> {
+    /// An `INFO` span, used for schema builds and top-level calls
+    #[inline]
+    pub fn enter(name: &'static str, target: Option<&'a str>) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            Self {
+                _span: tracing::info_span!(SPAN_NAME, operation = name, schema = target).entered(),
+                _target: PhantomData,
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = (name, target);
+            Self { _target: PhantomData }
+        }
+    }
+
+    /// A `DEBUG` span, which the Python subscriber only reports if it takes longer than its `slow_threshold`
+    #[inline]
+    pub fn enter_slow(name: &'static str, target: &'a str) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            Self {
+                _span: tracing::debug_span!(SPAN_NAME, operation = name, schema = target).entered(),
+                _target: PhantomData,
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = (name, target);
+            Self { _target: PhantomData }
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+struct Callback {
+    callback: PyObject,
+    slow_threshold: Option<Duration>,
+}
+
+#[cfg(feature = "tracing")]
+struct SpanData {
+    id: u64,
+    operation: Option<String>,
+    schema: Option<String>,
+    slow_only: bool,
+    start: Instant,
+}
+
+#[cfg(feature = "tracing")]
+#[derive(Default)]
+struct SpanFields {
+    operation: Option<String>,
+    schema: Option<String>,
+}
+
+#[cfg(feature = "tracing")]
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "operation" => self.operation = Some(value.to_string()),
+            "schema" => self.schema = Some(value.to_string()),
+            _ => (),
+        }
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+// whether a callback is set, checked without the GIL for every span
+#[cfg(feature = "tracing")]
+static ENABLED: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "tracing")]
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+#[cfg(feature = "tracing")]
+static CALLBACK: GILProtected<RefCell<Option<Callback>>> = GILProtected::new(RefCell::new(None));
+// open spans, pydantic-core only creates spans while holding the GIL and they're usually closed in reverse order
+#[cfg(feature = "tracing")]
+static SPANS: GILProtected<RefCell<Vec<SpanData>>> = GILProtected::new(RefCell::new(Vec::new()));
+#[cfg(feature = "tracing")]
+static INSTALLED: GILOnceCell<()> = GILOnceCell::new();
+
+/// The `tracing` subscriber installed by `set_trace_subscriber`, it reports pydantic-core's spans to the Python
+/// callback, spans are never cloned so each is closed when its `TraceSpan` is dropped
+#[cfg(feature = "tracing")]
+struct PythonSubscriber;
+
+#[cfg(feature = "tracing")]
+impl Subscriber for PythonSubscriber {
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if metadata.name() == SPAN_NAME {
+            // the callback can be removed and set again, so `enabled` is checked for every span
+            Interest::sometimes()
+        } else {
+            Interest::never()
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.name() == SPAN_NAME && ENABLED.load(Ordering::Relaxed)
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+        let span = SpanData {
+            id,
+            operation: fields.operation,
+            schema: fields.schema,
+            slow_only: *attrs.metadata().level() == Level::DEBUG,
+            start: Instant::now(),
+        };
+        Python::with_gil(|py| SPANS.get(py).borrow_mut().push(span));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+
+    fn try_close(&self, id: Id) -> bool {
+        Python::with_gil(|py| {
+            let span = {
+                let mut spans = SPANS.get(py).borrow_mut();
+                let Some(index) = spans.iter().rposition(|span| span.id == id.into_u64()) else {
+                    return;
+                };
+                spans.remove(index)
+            };
+            let elapsed = span.start.elapsed();
+            // the callback is cloned so it isn't borrowed while it runs, it might set a new callback
+            let callback = match CALLBACK.get(py).borrow().as_ref() {
+                Some(c) if !span.slow_only || c.slow_threshold.map_or(false, |t| elapsed >= t) => {
+                    c.callback.clone_ref(py)
+                }
+                _ => return,
+            };
+            if let Err(err) = callback.call1(py, (span.operation, span.schema, elapsed.as_secs_f64())) {
+                err.write_unraisable(py, Some(callback.as_ref(py)));
+            }
+        });
+        true
+    }
+}
+
+/// Set the callback which receives `(name, target, duration)` for every span, or `None` to stop tracing.
+/// Nested validators taking longer than `slow_threshold` seconds are also reported.
+#[pyfunction(signature = (subscriber, *, slow_threshold=None))]
+pub fn set_trace_subscriber(py: Python, subscriber: Option<PyObject>, slow_threshold: Option<f64>) -> PyResult<()> {
+    #[cfg(feature = "tracing")]
+    {
+        let slow_threshold = slow_threshold
+            .map(|t| {
+                Duration::try_from_secs_f64(t)
+                    .map_err(|_| pyo3::exceptions::PyValueError::new_err("slow_threshold must be a positive number"))
+            })
+            .transpose()?;
+        if subscriber.is_some() {
+            INSTALLED.get_or_try_init(py, || {
+                tracing::subscriber::set_global_default(PythonSubscriber).map_err(|_| {
+                    PyRuntimeError::new_err("a global `tracing` subscriber has already been set, spans are sent to it")
+                })
+            })?;
+        }
+        let mut current = CALLBACK.get(py).borrow_mut();
+        *current = subscriber.map(|callback| Callback {
+            callback,
+            slow_threshold,
+        });
+        ENABLED.store(current.is_some(), Ordering::Relaxed);
+        Ok(())
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (py, slow_threshold);
+        match subscriber {
+            Some(_) => Err(PyRuntimeError::new_err(
+                "pydantic-core was built without the `tracing` feature, spans can't be recorded",
+            )),
+            None => Ok(()),
+        }
+    }
+}
@@ -6,30 +6,99 @@ use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{schema_or_config_same, ExtraBehavior};
-use crate::errors::{AsLocItem, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{GenericArguments, Input, ValidationMatch};
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{LookupKeyCollection, LookupPath, LookupType};
 
 use crate::tools::SchemaDict;
 
 use super::validation_state::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, Validator};
 
 #[derive(Debug)]
 struct Parameter {
     positional: bool,
     name: String,
-    kw_lookup_key: Option<LookupKey>,
+    kw_lookup_keys: Option<LookupKeyCollection>,
     kwarg_key: Option<Py<PyString>>,
     validator: CombinedValidator,
 }
 
+/// How a parameter was provided in the input, used to locate errors from parameter groups
+enum Provided<'a> {
+    Positional,
+    Keyword(&'a LookupPath),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum GroupKind {
+    MutuallyExclusive,
+    AtLeastOneOf,
+}
+
+/// Parameters which are checked together once they've been validated individually
+#[derive(Debug)]
+struct ParameterGroup {
+    kind: GroupKind,
+    // indexes into `ArgumentsValidator::parameters`
+    parameters: Vec<usize>,
+    // e.g. `'a', 'b' or 'c'`
+    names_repr: String,
+}
+
+impl ParameterGroup {
+    fn build(kind: GroupKind, names: Vec<String>, parameters: &[Parameter], schema_key: &str) -> PyResult<Self> {
+        if names.len() < 2 {
+            return py_schema_err!("{} groups must contain at least two parameters", schema_key);
+        }
+        let mut indexes = Vec::with_capacity(names.len());
+        for name in &names {
+            match parameters.iter().position(|p| &p.name == name) {
+                Some(index) if indexes.contains(&index) => {
+                    return py_schema_err!("{}: parameter '{}' is repeated", schema_key, name)
+                }
+                Some(index) => indexes.push(index),
+                None => return py_schema_err!("{}: unknown parameter '{}'", schema_key, name),
+            }
+        }
+        let mut reprs: Vec<String> = names.iter().map(|name| format!("'{name}'")).collect();
+        let last_repr = reprs.pop().unwrap();
+        Ok(Self {
+            kind,
+            parameters: indexes,
+            names_repr: format!("{} or {last_repr}", reprs.join(", ")),
+        })
+    }
+
+    fn build_all(schema: &PyDict, parameters: &[Parameter]) -> PyResult<Vec<Self>> {
+        let py = schema.py();
+        let mut groups = Vec::new();
+        for (key, kind) in [
+            (intern!(py, "mutually_exclusive"), GroupKind::MutuallyExclusive),
+            (intern!(py, "at_least_one_of"), GroupKind::AtLeastOneOf),
+        ] {
+            if let Some(group_names) = schema.get_as::<Vec<Vec<String>>>(key)? {
+                for names in group_names {
+                    groups.push(Self::build(kind, names, parameters, key.to_str()?)?);
+                }
+            }
+        }
+        Ok(groups)
+    }
+}
+
 #[derive(Debug)]
 pub struct ArgumentsValidator {
     parameters: Vec<Parameter>,
+    groups: Vec<ParameterGroup>,
     positional_params_count: usize,
     var_args_validator: Option<Box<CombinedValidator>>,
+    // if set, extra positional arguments are returned as a tuple under this name in the kwargs
+    var_args_name: Option<String>,
     var_kwargs_validator: Option<Box<CombinedValidator>>,
+    // whether any parameter has a `default_factory` which takes the arguments validated so far
+    default_factory_takes_data: bool,
+    populate_by_name: bool,
     loc_by_alias: bool,
     extra: ExtraBehavior,
 }
@@ -51,6 +120,7 @@ impl BuildValidator for ArgumentsValidator {
 
         let mut positional_params_count = 0;
         let mut had_default_arg = false;
+        let mut default_factory_takes_data = false;
 
         for (arg_index, arg) in arguments_schema.iter().enumerate() {
             let arg: &PyDict = arg.downcast()?;
@@ -64,16 +134,11 @@ impl BuildValidator for ArgumentsValidator {
                 positional_params_count = arg_index + 1;
             }
 
-            let mut kw_lookup_key = None;
+            let mut kw_lookup_keys = None;
             let mut kwarg_key = None;
             if mode == "keyword_only" || mode == "positional_or_keyword" {
-                kw_lookup_key = match arg.get_item(intern!(py, "alias"))? {
-                    Some(alias) => {
-                        let alt_alias = if populate_by_name { Some(name.as_str()) } else { None };
-                        Some(LookupKey::from_py(py, alias, alt_alias)?)
-                    }
-                    None => Some(LookupKey::from_string(py, &name)),
-                };
+                let alias = arg.get_item(intern!(py, "alias"))?;
+                kw_lookup_keys = Some(LookupKeyCollection::new(py, alias, &name)?);
                 kwarg_key = Some(PyString::new(py, &name).into());
             }
 
@@ -89,6 +154,7 @@ impl BuildValidator for ArgumentsValidator {
                     if v.omit_on_error() {
                         return py_schema_err!("Parameter '{}': omit_on_error cannot be used with arguments", name);
                     }
+                    default_factory_takes_data |= v.default_factory_takes_data();
                     v.has_default()
                 }
                 _ => false,
@@ -102,23 +168,40 @@ impl BuildValidator for ArgumentsValidator {
             parameters.push(Parameter {
                 positional,
                 name,
-                kw_lookup_key,
+                kw_lookup_keys,
                 kwarg_key,
                 validator,
             });
         }
 
+        let groups = ParameterGroup::build_all(schema, &parameters)?;
+
+        let var_args_validator = match schema.get_item(intern!(py, "var_args_schema"))? {
+            Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
+            None => None,
+        };
+        let var_args_name: Option<String> = schema.get_as(intern!(py, "var_args_name"))?;
+        if let Some(ref var_args_name) = var_args_name {
+            if var_args_validator.is_none() {
+                return py_schema_err!("var_args_name can only be used with var_args_schema");
+            }
+            if parameters.iter().any(|p| &p.name == var_args_name) {
+                return py_schema_err!("var_args_name '{}' is also the name of a parameter", var_args_name);
+            }
+        }
+
         Ok(Self {
             parameters,
+            groups,
             positional_params_count,
-            var_args_validator: match schema.get_item(intern!(py, "var_args_schema"))? {
-                Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
-                None => None,
-            },
+            var_args_validator,
+            var_args_name,
             var_kwargs_validator: match schema.get_item(intern!(py, "var_kwargs_schema"))? {
                 Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
                 None => None,
             },
+            default_factory_takes_data,
+            populate_by_name,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             extra: ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Forbid)?,
         }
@@ -162,6 +245,80 @@ impl_py_gc_traverse!(ArgumentsValidator {
     var_kwargs_validator
 });
 
+impl ArgumentsValidator {
+    /// Check parameter groups once all parameters have been validated, groups only consider whether a parameter
+    /// was provided, not whether it was valid
+    fn check_groups<'data>(
+        &self,
+        provided: &[Option<Provided>],
+        lookup_type: LookupType,
+        input: &'data impl Input<'data>,
+        errors: &mut Vec<ValLineError>,
+    ) {
+        for group in &self.groups {
+            match group.kind {
+                GroupKind::MutuallyExclusive => {
+                    let given: Vec<usize> = group
+                        .parameters
+                        .iter()
+                        .copied()
+                        .filter(|&index| provided[index].is_some())
+                        .collect();
+                    if given.len() > 1 {
+                        for index in given {
+                            let error_type = ErrorType::MutuallyExclusiveArguments {
+                                arguments: group.names_repr.clone(),
+                                context: None,
+                            };
+                            errors.push(self.group_error(
+                                error_type,
+                                index,
+                                provided[index].as_ref(),
+                                lookup_type,
+                                input,
+                            ));
+                        }
+                    }
+                }
+                GroupKind::AtLeastOneOf => {
+                    if group.parameters.iter().all(|&index| provided[index].is_none()) {
+                        for &index in &group.parameters {
+                            let error_type = ErrorType::MissingOneOfArguments {
+                                arguments: group.names_repr.clone(),
+                                context: None,
+                            };
+                            errors.push(self.group_error(error_type, index, None, lookup_type, input));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// An error located at the parameter, using the same location as other errors for the parameter
+    fn group_error<'data>(
+        &self,
+        error_type: ErrorType,
+        index: usize,
+        provided: Option<&Provided>,
+        lookup_type: LookupType,
+        input: &'data impl Input<'data>,
+    ) -> ValLineError {
+        let parameter = &self.parameters[index];
+        match (provided, &parameter.kw_lookup_keys) {
+            (Some(Provided::Keyword(lookup_path)), _) => {
+                lookup_path.apply_error_loc(ValLineError::new(error_type, input), self.loc_by_alias, &parameter.name)
+            }
+            (None, Some(lookup_keys)) => {
+                lookup_keys
+                    .select(lookup_type)
+                    .error(error_type, input, self.loc_by_alias, &parameter.name)
+            }
+            _ => ValLineError::new_with_loc(error_type, input, index),
+        }
+    }
+}
+
 impl Validator for ArgumentsValidator {
     fn validate<'data>(
         &self,
@@ -175,6 +332,12 @@ impl Validator for ArgumentsValidator {
         let output_kwargs = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.parameters.len());
+        // only tracked when there are parameter groups to check
+        let mut provided: Vec<Option<Provided>> = Vec::new();
+        let lookup_type = LookupType::new(state.extra().by_alias, state.extra().by_name, self.populate_by_name);
+        // validated arguments by parameter name, passed to default factories which take data
+        let data = self.default_factory_takes_data.then(|| PyDict::new(py));
+        let mut var_args_output: Vec<PyObject> = Vec::new();
 
         macro_rules! process {
             ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
@@ -188,14 +351,22 @@ impl Validator for ArgumentsValidator {
                     }
                     let mut kw_value = None;
                     if let Some(kwargs) = $args.kwargs {
-                        if let Some(ref lookup_key) = parameter.kw_lookup_key {
-                            if let Some((lookup_path, value)) = lookup_key.$get_method(kwargs)? {
+                        if let Some(ref lookup_keys) = parameter.kw_lookup_keys {
+                            if let Some((lookup_path, value)) = lookup_keys.select(lookup_type).$get_method(kwargs)? {
                                 used_kwargs.insert(lookup_path.first_key());
                                 kw_value = Some((lookup_path, value));
                             }
                         }
                     }
 
+                    if !self.groups.is_empty() {
+                        provided.push(match (&pos_value, &kw_value) {
+                            (Some(_), _) => Some(Provided::Positional),
+                            (None, Some((lookup_path, _))) => Some(Provided::Keyword(*lookup_path)),
+                            (None, None) => None,
+                        });
+                    }
+
                     match (pos_value, kw_value) {
                         (Some(_), Some((_, kw_value))) => {
                             errors.push(ValLineError::new_with_loc(
@@ -207,7 +378,12 @@ impl Validator for ArgumentsValidator {
                         (Some(pos_value), None) => {
                             match parameter.validator.validate(py, pos_value, state)
                             {
-                                Ok(value) => output_args.push(value),
+                                Ok(value) => {
+                                    if let Some(data) = data {
+                                        data.set_item(&parameter.name, &value)?;
+                                    }
+                                    output_args.push(value);
+                                }
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
                                 }
@@ -217,7 +393,12 @@ impl Validator for ArgumentsValidator {
                         (None, Some((lookup_path, kw_value))) => {
                             match parameter.validator.validate(py, kw_value, state)
                             {
-                                Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
+                                Ok(value) => {
+                                    if let Some(data) = data {
+                                        data.set_item(&parameter.name, &value)?;
+                                    }
+                                    output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?;
+                                }
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(line_errors.into_iter().map(|err| {
                                         lookup_path.apply_error_loc(err, self.loc_by_alias, &parameter.name)
@@ -227,19 +408,32 @@ impl Validator for ArgumentsValidator {
                             }
                         }
                         (None, None) => {
-                            if let Some(value) = parameter.validator.default_value(py, Some(parameter.name.as_str()), state)? {
+                            let default_value = match data {
+                                Some(data) => state.with_new_extra(
+                                    Extra {
+                                        data: Some(data),
+                                        ..*state.extra()
+                                    },
+                                    |state| parameter.validator.default_value(py, Some(parameter.name.as_str()), state),
+                                ),
+                                None => parameter.validator.default_value(py, Some(parameter.name.as_str()), state),
+                            };
+                            if let Some(value) = default_value? {
+                                if let Some(data) = data {
+                                    data.set_item(&parameter.name, &value)?;
+                                }
                                 if let Some(ref kwarg_key) = parameter.kwarg_key {
                                     output_kwargs.set_item(kwarg_key, value)?;
                                 } else {
                                     output_args.push(value);
                                 }
-                            } else if let Some(ref lookup_key) = parameter.kw_lookup_key {
+                            } else if let Some(ref lookup_keys) = parameter.kw_lookup_keys {
                                 let error_type = if parameter.positional {
                                     ErrorTypeDefaults::MissingArgument
                                 } else {
                                     ErrorTypeDefaults::MissingKeywordOnlyArgument
                                 };
-                                errors.push(lookup_key.error(
+                                errors.push(lookup_keys.select(lookup_type).error(
                                     error_type,
                                     input,
                                     self.loc_by_alias,
@@ -258,7 +452,7 @@ impl Validator for ArgumentsValidator {
                         if let Some(ref validator) = self.var_args_validator {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
                                 match validator.validate(py, item, state) {
-                                    Ok(value) => output_args.push(value),
+                                    Ok(value) => var_args_output.push(value),
                                     Err(ValError::LineErrors(line_errors)) => {
                                         errors.extend(
                                             line_errors
@@ -298,6 +492,15 @@ impl Validator for ArgumentsValidator {
                                 Err(err) => return Err(err),
                             };
                             if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
+                                // the name extra positional arguments are returned under can't also be a keyword
+                                if self.var_args_name.as_deref() == Some(either_str.as_cow()?.as_ref()) {
+                                    errors.push(ValLineError::new_with_loc(
+                                        ErrorTypeDefaults::UnexpectedKeywordArgument,
+                                        value,
+                                        raw_key.as_loc_item(),
+                                    ));
+                                    continue;
+                                }
                                 match self.var_kwargs_validator {
                                     Some(ref validator) => match validator.validate(py, value, state) {
                                         Ok(value) => output_kwargs.set_item(either_str.as_py_string(py), value)?,
@@ -328,9 +531,16 @@ impl Validator for ArgumentsValidator {
             GenericArguments::Json(a) => process!(a, json_get, json_get, json_slice),
             GenericArguments::StringMapping(_) => unimplemented!(),
         }
+        if !self.groups.is_empty() {
+            self.check_groups(&provided, lookup_type, input, &mut errors);
+        }
         if !errors.is_empty() {
             Err(ValError::LineErrors(errors))
         } else {
+            match self.var_args_name {
+                Some(ref var_args_name) => output_kwargs.set_item(var_args_name, PyTuple::new(py, var_args_output))?,
+                None => output_args.extend(var_args_output),
+            }
             Ok((PyTuple::new(py, output_args), output_kwargs).to_object(py))
         }
     }
@@ -4,15 +4,18 @@ use pyo3::types::PyDict;
 
 use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherBytes, Input};
 
 use crate::tools::SchemaDict;
 
+use super::config::ValBytesMode;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    bytes_mode: ValBytesMode,
+    preserve_bytearray: bool,
 }
 
 impl BuildValidator for BytesValidator {
@@ -31,6 +34,8 @@ impl BuildValidator for BytesValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                bytes_mode: ValBytesMode::from_schema_or_config(schema, config)?,
+                preserve_bytearray: schema.get_as(intern!(py, "preserve_bytearray"))?.unwrap_or(false),
             }
             .into())
         }
@@ -47,8 +52,8 @@ impl Validator for BytesValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         input
-            .validate_bytes(state.strict_or(self.strict))
-            .map(|m| m.unpack(state).into_py(py))
+            .validate_bytes(state.strict_or(self.strict), self.bytes_mode)
+            .map(|m| bytes_to_py(py, m.unpack(state), self.preserve_bytearray))
     }
 
     fn get_name(&self) -> &str {
@@ -59,8 +64,10 @@ impl Validator for BytesValidator {
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
+    bytes_mode: ValBytesMode,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    preserve_bytearray: bool,
 }
 
 impl_py_gc_traverse!(BytesConstrainedValidator {});
@@ -72,7 +79,9 @@ impl Validator for BytesConstrainedValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        let either_bytes = input.validate_bytes(state.strict_or(self.strict))?.unpack(state);
+        let either_bytes = input
+            .validate_bytes(state.strict_or(self.strict), self.bytes_mode)?
+            .unpack(state);
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
@@ -97,7 +106,7 @@ impl Validator for BytesConstrainedValidator {
                 ));
             }
         }
-        Ok(either_bytes.into_py(py))
+        Ok(bytes_to_py(py, either_bytes, self.preserve_bytearray))
     }
 
     fn get_name(&self) -> &str {
@@ -110,9 +119,18 @@ impl BytesConstrainedValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            bytes_mode: ValBytesMode::from_schema_or_config(schema, config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            preserve_bytearray: schema.get_as(intern!(py, "preserve_bytearray"))?.unwrap_or(false),
         }
         .into())
     }
 }
+
+fn bytes_to_py(py: Python, either_bytes: EitherBytes, preserve_bytearray: bool) -> PyObject {
+    match either_bytes {
+        EitherBytes::PyByteArray(py_byte_array) if preserve_bytearray => py_byte_array.into_py(py),
+        either_bytes => either_bytes.into_py(py),
+    }
+}
@@ -1,6 +1,9 @@
-use pyo3::exceptions::PyTypeError;
+use std::sync::Arc;
+
+use pyo3::exceptions::{PyStopIteration, PyTypeError};
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::pyclass::IterNextOutput;
 use pyo3::types::{PyDict, PyTuple};
 
 use crate::errors::ValResult;
@@ -8,6 +11,7 @@ use crate::input::Input;
 
 use crate::tools::SchemaDict;
 
+use super::generator::InternalValidator;
 use super::validation_state::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -15,8 +19,12 @@ use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuild
 pub struct CallValidator {
     function: PyObject,
     arguments_validator: Box<CombinedValidator>,
-    return_validator: Option<Box<CombinedValidator>>,
+    return_validator: Option<Arc<CombinedValidator>>,
+    // the function is a coroutine function, so the return value is validated once it's awaited
+    is_async: bool,
     name: String,
+    hide_input_in_errors: bool,
+    validation_error_cause: bool,
 }
 
 impl BuildValidator for CallValidator {
@@ -34,7 +42,7 @@ impl BuildValidator for CallValidator {
 
         let return_schema = schema.get_item(intern!(py, "return_schema"))?;
         let return_validator = match return_schema {
-            Some(return_schema) => Some(Box::new(build_validator(return_schema, config, definitions)?)),
+            Some(return_schema) => Some(Arc::new(build_validator(return_schema, config, definitions)?)),
             None => None,
         };
         let function: &PyAny = schema.get_as_req(intern!(py, "function"))?;
@@ -55,12 +63,19 @@ impl BuildValidator for CallValidator {
             }
         };
         let name = format!("{}[{function_name}]", Self::EXPECTED_TYPE);
+        let is_async = py
+            .import(intern!(py, "inspect"))?
+            .call_method1(intern!(py, "iscoroutinefunction"), (function,))?
+            .is_true()?;
 
         Ok(Self {
             function: function.to_object(py),
             arguments_validator,
             return_validator,
+            is_async,
             name,
+            hide_input_in_errors: config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false),
+            validation_error_cause: config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false),
         }
         .into())
     }
@@ -91,6 +106,21 @@ impl Validator for CallValidator {
         };
 
         if let Some(return_validator) = &self.return_validator {
+            if self.is_async {
+                let validator = InternalValidator::new(
+                    py,
+                    &self.name,
+                    return_validator.clone(),
+                    state,
+                    self.hide_input_in_errors,
+                    self.validation_error_cause,
+                );
+                let awaitable = ValidatedAwaitable {
+                    iterator: return_value.call_method0(py, intern!(py, "__await__"))?,
+                    validator,
+                };
+                return Ok(awaitable.into_py(py));
+            }
             return_validator
                 .validate(py, return_value.into_ref(py), state)
                 .map_err(|e| e.with_outer_location("return".into()))
@@ -103,3 +133,75 @@ impl Validator for CallValidator {
         &self.name
     }
 }
+
+/// Returned by `call` for coroutine functions with a `return_schema`, awaiting it awaits the coroutine and
+/// validates its result, without a Python wrapper coroutine
+#[pyclass(module = "pydantic_core._pydantic_core")]
+struct ValidatedAwaitable {
+    // from the coroutine's `__await__`
+    iterator: PyObject,
+    validator: InternalValidator,
+}
+
+#[pymethods]
+impl ValidatedAwaitable {
+    fn __await__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<IterNextOutput<PyObject, PyObject>> {
+        let result = self.iterator.call_method1(py, intern!(py, "send"), (py.None(),));
+        self.finish(py, result)
+    }
+
+    fn send(&mut self, py: Python, value: &PyAny) -> PyResult<PyObject> {
+        let result = self.iterator.call_method1(py, intern!(py, "send"), (value,));
+        self.finish(py, result).and_then(raise_on_return)
+    }
+
+    #[pyo3(signature = (typ, val=None, tb=None))]
+    fn throw(&mut self, py: Python, typ: &PyAny, val: Option<&PyAny>, tb: Option<&PyAny>) -> PyResult<PyObject> {
+        // only pass the arguments we were given, the three argument form is deprecated
+        let args: Vec<&PyAny> = [Some(typ), val, tb].into_iter().flatten().collect();
+        let result = self
+            .iterator
+            .call_method1(py, intern!(py, "throw"), PyTuple::new(py, args));
+        self.finish(py, result).and_then(raise_on_return)
+    }
+
+    fn close(&self, py: Python) -> PyResult<()> {
+        self.iterator.call_method0(py, intern!(py, "close"))?;
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ValidatedAwaitable(schema={:?})", self.validator)
+    }
+}
+
+impl ValidatedAwaitable {
+    /// Values yielded by the coroutine are passed through, its return value is validated
+    fn finish(&mut self, py: Python, result: PyResult<PyObject>) -> PyResult<IterNextOutput<PyObject, PyObject>> {
+        match result {
+            Ok(value) => Ok(IterNextOutput::Yield(value)),
+            Err(err) if err.is_instance_of::<PyStopIteration>(py) => {
+                let return_value = err.value(py).getattr(intern!(py, "value"))?;
+                let output = self.validator.validate(py, return_value, Some("return".into()))?;
+                Ok(IterNextOutput::Return(output))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// `send` and `throw` aren't iterator slots, so like a generator they raise `StopIteration` with the return value
+fn raise_on_return(output: IterNextOutput<PyObject, PyObject>) -> PyResult<PyObject> {
+    match output {
+        IterNextOutput::Yield(value) => Ok(value),
+        IterNextOutput::Return(value) => Err(PyStopIteration::new_err((value,))),
+    }
+}
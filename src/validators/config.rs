@@ -0,0 +1,186 @@
+use std::str::FromStr;
+
+use base64::Engine;
+use pyo3::exceptions::PyUserWarning;
+use pyo3::types::PyDict;
+use pyo3::{intern, prelude::*};
+
+use crate::build_tools::{py_schema_err, schema_or_config_same};
+use crate::errors::ErrorType;
+use crate::input::EitherBytes;
+use crate::tools::SchemaDict;
+
+/// How strings are decoded when validating them as `bytes`, set via `encoding` on the schema or the
+/// `val_json_bytes` config
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValBytesMode {
+    #[default]
+    Utf8,
+    /// either the standard or URL safe alphabet, so both `encoding='base64'` and the URL safe output of
+    /// `ser_json_bytes='base64'` are accepted
+    Base64,
+    Base64Url,
+    Hex,
+}
+
+impl FromStr for ValBytesMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "utf8" => Ok(Self::Utf8),
+            "base64" => Ok(Self::Base64),
+            s => py_schema_err!("Invalid bytes validation mode: `{}`, expected `utf8` or `base64`", s),
+        }
+    }
+}
+
+impl ValBytesMode {
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let raw_mode = config_dict.get_as::<&str>(intern!(config_dict.py(), "val_json_bytes"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), Self::from_str)
+    }
+
+    /// `encoding` on the schema takes precedence over `val_json_bytes`, and applies to python strings too
+    pub fn from_schema_or_config(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        match schema.get_as::<&str>(intern!(schema.py(), "encoding"))? {
+            Some("base64") => Ok(Self::Base64),
+            Some("base64url") => Ok(Self::Base64Url),
+            Some("hex") => Ok(Self::Hex),
+            Some(s) => py_schema_err!(
+                "Invalid bytes encoding: `{}`, expected `base64`, `base64url` or `hex`",
+                s
+            ),
+            None => Self::from_config(config),
+        }
+    }
+
+    pub fn deserialize_string<'py>(self, s: &'py str) -> Result<EitherBytes<'py>, ErrorType> {
+        let result = match self {
+            Self::Utf8 => return Ok(s.as_bytes().into()),
+            Self::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(s)
+                .or_else(|err| match err {
+                    // retry invalid characters with the URL safe alphabet, other errors are the same in both
+                    base64::DecodeError::InvalidByte(..) => base64::engine::general_purpose::URL_SAFE.decode(s),
+                    err => Err(err),
+                })
+                .map_err(|err| err.to_string()),
+            Self::Base64Url => base64::engine::general_purpose::URL_SAFE
+                .decode(s)
+                .map_err(|err| err.to_string()),
+            Self::Hex => decode_hex(s),
+        };
+        result
+            .map(Into::into)
+            .map_err(|encoding_error| ErrorType::BytesInvalidEncoding {
+                encoding: self.encoding_name().to_string(),
+                encoding_error,
+                context: None,
+            })
+    }
+
+    fn encoding_name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf8",
+            Self::Base64 => "base64",
+            Self::Base64Url => "base64url",
+            Self::Hex => "hex",
+        }
+    }
+}
+
+/// Decode a hex string, errors match the format of `base64::DecodeError` so they include the byte offset
+fn decode_hex(s: &str) -> Result<Vec<u8>, String> {
+    let digits = s
+        .bytes()
+        .enumerate()
+        .map(|(offset, byte)| match byte {
+            b'0'..=b'9' => Ok(byte - b'0'),
+            b'a'..=b'f' => Ok(byte - b'a' + 10),
+            b'A'..=b'F' => Ok(byte - b'A' + 10),
+            _ => Err(format!("Invalid byte {byte}, offset {offset}.")),
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    if digits.len() % 2 != 0 {
+        return Err("Invalid input length.".to_string());
+    }
+    Ok(digits.chunks_exact(2).map(|pair| (pair[0] << 4) | pair[1]).collect())
+}
+
+/// How `multiple_of` is checked on floats and decimals, set via `multiple_of_mode` on the schema or config
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultipleOfMode {
+    /// allow for floating point error, relative to the size of the value
+    #[default]
+    Tolerant,
+    /// the value must be an exact multiple, comparing the decimal values of both numbers, e.g. `repr()` of floats
+    Exact,
+}
+
+impl FromStr for MultipleOfMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tolerant" => Ok(Self::Tolerant),
+            "exact" => Ok(Self::Exact),
+            s => py_schema_err!("Invalid multiple_of_mode: `{}`, expected `tolerant` or `exact`", s),
+        }
+    }
+}
+
+impl MultipleOfMode {
+    pub fn from_schema_or_config(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode = schema_or_config_same::<&str>(schema, config, intern!(schema.py(), "multiple_of_mode"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), Self::from_str)
+    }
+}
+
+/// What happens when a lax numeric coercion loses precision, i.e. a float with a fractional part validated as an
+/// int, or an int with more than 53 significant bits validated as a float, set via `number_precision_loss` on
+/// the schema or config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberPrecisionLoss {
+    /// raise a validation error
+    Error,
+    /// emit a `UserWarning`, then truncate or round the value
+    Warn,
+    /// silently truncate floats towards zero, or round ints to the nearest float
+    Truncate,
+}
+
+impl FromStr for NumberPrecisionLoss {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "warn" => Ok(Self::Warn),
+            "truncate" => Ok(Self::Truncate),
+            s => py_schema_err!(
+                "Invalid number_precision_loss: `{}`, expected `error`, `warn` or `truncate`",
+                s
+            ),
+        }
+    }
+}
+
+impl NumberPrecisionLoss {
+    /// `default` differs between ints and floats to match the behaviour before this could be configured
+    pub fn from_schema_or_config(schema: &PyDict, config: Option<&PyDict>, default: Self) -> PyResult<Self> {
+        let raw_mode = schema_or_config_same::<&str>(schema, config, intern!(schema.py(), "number_precision_loss"))?;
+        raw_mode.map_or(Ok(default), Self::from_str)
+    }
+
+    /// Emit a `UserWarning` with `message` if the mode is `Warn`
+    pub fn warn(self, py: Python, message: &str) -> PyResult<()> {
+        if self == Self::Warn {
+            PyErr::warn(py, py.get_type::<PyUserWarning>(), message, 0)?;
+        }
+        Ok(())
+    }
+}
@@ -10,8 +10,9 @@ use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::InputType;
 use crate::input::{BorrowInput, GenericArguments, Input, ValidationMatch};
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{LookupKeyCollection, LookupType};
 use crate::tools::SchemaDict;
+use crate::trace::TraceSpan;
 use crate::validators::function::convert_err;
 
 use super::arguments::{json_get, json_slice, py_get, py_slice};
@@ -27,7 +28,7 @@ struct Field {
     name: String,
     py_name: Py<PyString>,
     init_only: bool,
-    lookup_key: LookupKey,
+    lookup_keys: LookupKeyCollection,
     validator: CombinedValidator,
     frozen: bool,
 }
@@ -41,6 +42,7 @@ pub struct DataclassArgsValidator {
     validator_name: String,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    populate_by_name: bool,
     loc_by_alias: bool,
 }
 
@@ -75,13 +77,8 @@ impl BuildValidator for DataclassArgsValidator {
             let py_name: &PyString = field.get_as_req(intern!(py, "name"))?;
             let name: String = py_name.extract()?;
 
-            let lookup_key = match field.get_item(intern!(py, "validation_alias"))? {
-                Some(alias) => {
-                    let alt_alias = if populate_by_name { Some(name.as_str()) } else { None };
-                    LookupKey::from_py(py, alias, alt_alias)?
-                }
-                None => LookupKey::from_string(py, &name),
-            };
+            let validation_alias = field.get_item(intern!(py, "validation_alias"))?;
+            let lookup_keys = LookupKeyCollection::new(py, validation_alias, &name)?;
 
             let schema: &PyAny = field.get_as_req(intern!(py, "schema"))?;
 
@@ -105,7 +102,7 @@ impl BuildValidator for DataclassArgsValidator {
                 kw_only,
                 name,
                 py_name: py_name.into(),
-                lookup_key,
+                lookup_keys,
                 validator,
                 init_only: field.get_as(intern!(py, "init_only"))?.unwrap_or(false),
                 frozen: field.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
@@ -128,6 +125,7 @@ impl BuildValidator for DataclassArgsValidator {
             validator_name,
             extra_behavior,
             extras_validator,
+            populate_by_name,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -152,6 +150,7 @@ impl Validator for DataclassArgsValidator {
 
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_keys: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
+        let lookup_type = LookupType::new(state.extra().by_alias, state.extra().by_name, self.populate_by_name);
 
         state.with_new_extra(
             Extra {
@@ -183,9 +182,10 @@ impl Validator for DataclassArgsValidator {
                                 }
                             }
 
+                            let lookup_key = field.lookup_keys.select(lookup_type);
                             let mut kw_value = None;
                             if let Some(kwargs) = $args.kwargs {
-                                if let Some((lookup_path, value)) = field.lookup_key.$get_method(kwargs)? {
+                                if let Some((lookup_path, value)) = lookup_key.$get_method(kwargs)? {
                                     used_keys.insert(lookup_path.first_key());
                                     kw_value = Some((lookup_path, value));
                                 }
@@ -206,7 +206,12 @@ impl Validator for DataclassArgsValidator {
                                     );
                                 }
                                 // found a positional argument, validate it
-                                (Some(pos_value), None) => match field.validator.validate(py, pos_value, state) {
+                                (Some(pos_value), None) => match state.validate_item(
+                                    py,
+                                    &field.validator,
+                                    pos_value,
+                                    || index.into(),
+                                ) {
                                     Ok(value) => set_item!(field, value),
                                     Err(ValError::LineErrors(line_errors)) => {
                                         errors.extend(
@@ -219,7 +224,8 @@ impl Validator for DataclassArgsValidator {
                                 },
                                 // found a keyword argument, validate it
                                 (None, Some((lookup_path, kw_value))) => {
-                                    match field.validator.validate(py, kw_value, state) {
+                                    let field_loc = || field.name.as_loc_item();
+                                    match state.validate_item(py, &field.validator, kw_value, field_loc) {
                                         Ok(value) => set_item!(field, value),
                                         Err(ValError::LineErrors(line_errors)) => {
                                             errors.extend(line_errors.into_iter().map(|err| {
@@ -239,7 +245,7 @@ impl Validator for DataclassArgsValidator {
                                         },
                                         Ok(None) => {
                                             // This means there was no default value
-                                            errors.push(field.lookup_key.error(
+                                            errors.push(lookup_key.error(
                                                 ErrorTypeDefaults::Missing,
                                                 input,
                                                 self.loc_by_alias,
@@ -365,27 +371,53 @@ impl Validator for DataclassArgsValidator {
         }
     }
 
-    fn validate_assignment<'data>(
+    fn validate_assignments<'data>(
         &self,
         py: Python<'data>,
         obj: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let dict: &PyDict = obj.downcast()?;
 
-        let ok = |output: PyObject| {
-            dict.set_item(field_name, output)?;
-            // The second return value represents `init_only_args`
-            // which doesn't make much sense in this context but we need to put something there
-            // so that function validators that sit between DataclassValidator and DataclassArgsValidator
-            // always get called the same shape of data.
-            Ok(PyTuple::new(py, vec![dict.to_object(py), py.None()]).into_py(py))
-        };
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (field_name, field_value) in assignments {
+            let field_name: &str = field_name.extract()?;
+            match self.validate_field_assignment(py, dict, field_name, field_value, revalidate, state) {
+                Ok(output) => dict.set_item(field_name, output)?,
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => return Err(err),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        // The second return value represents `init_only_args`
+        // which doesn't make much sense in this context but we need to put something there
+        // so that function validators that sit between DataclassValidator and DataclassArgsValidator
+        // always get called the same shape of data.
+        Ok(PyTuple::new(py, vec![dict.to_object(py), py.None()]).into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.validator_name
+    }
+}
 
+impl DataclassArgsValidator {
+    /// Validate the new value of one field, the other values in `dict` are passed as `info.data`
+    fn validate_field_assignment<'data>(
+        &self,
+        py: Python<'data>,
+        dict: &'data PyDict,
+        field_name: &'data str,
+        field_value: &'data PyAny,
+        revalidate: bool,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
         if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
-            if field.frozen {
+            if field.frozen && !revalidate {
                 return Err(ValError::new_with_loc(
                     ErrorTypeDefaults::FrozenField,
                     field_value,
@@ -407,7 +439,6 @@ impl Validator for DataclassArgsValidator {
                 },
                 |state| field.validator.validate(py, field_value, state),
             ) {
-                Ok(output) => ok(output),
                 Err(ValError::LineErrors(line_errors)) => {
                     let errors = line_errors
                         .into_iter()
@@ -415,7 +446,7 @@ impl Validator for DataclassArgsValidator {
                         .collect();
                     Err(ValError::LineErrors(errors))
                 }
-                Err(err) => Err(err),
+                result => result,
             }
         } else {
             // Handle extra (unknown) field
@@ -424,7 +455,7 @@ impl Validator for DataclassArgsValidator {
             match self.extra_behavior {
                 // For dataclasses we allow assigning unknown fields
                 // to match stdlib dataclass behavior
-                ExtraBehavior::Allow => ok(field_value.to_object(py)),
+                ExtraBehavior::Allow => Ok(field_value.to_object(py)),
                 _ => Err(ValError::new_with_loc(
                     ErrorType::NoSuchAttribute {
                         attribute: field_name.to_string(),
@@ -436,10 +467,6 @@ impl Validator for DataclassArgsValidator {
             }
         }
     }
-
-    fn get_name(&self) -> &str {
-        &self.validator_name
-    }
 }
 
 #[derive(Debug)]
@@ -516,6 +543,7 @@ impl Validator for DataclassValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
+        let _span = TraceSpan::enter_slow("validate", &self.name);
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
             return self.validate_init(py, self_instance, input, state);
@@ -550,33 +578,40 @@ impl Validator for DataclassValidator {
         }
     }
 
-    fn validate_assignment<'data>(
+    fn validate_assignments<'data>(
         &self,
         py: Python<'data>,
         obj: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        if self.frozen {
-            return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, field_value));
+        if self.frozen && !revalidate {
+            let errors = assignments
+                .values()
+                .iter()
+                .map(|field_value| ValLineError::new(ErrorTypeDefaults::FrozenInstance, field_value))
+                .collect();
+            return Err(ValError::LineErrors(errors));
         }
 
         let new_dict = self.dataclass_to_dict(py, obj)?;
 
-        new_dict.set_item(field_name, field_value)?;
+        new_dict.update(assignments.as_mapping())?;
 
-        let val_assignment_result = self
-            .validator
-            .validate_assignment(py, new_dict, field_name, field_value, state)?;
+        let val_assignment_result =
+            self.validator
+                .validate_assignments(py, new_dict, assignments, revalidate, state)?;
 
         let (dc_dict, _): (&PyDict, PyObject) = val_assignment_result.extract(py)?;
 
         if self.slots {
-            let value = dc_dict
-                .get_item(field_name)?
-                .ok_or_else(|| PyKeyError::new_err(field_name.to_string()))?;
-            force_setattr(py, obj, field_name, value)?;
+            for field_name in assignments.keys() {
+                let value = dc_dict
+                    .get_item(field_name)?
+                    .ok_or_else(|| PyKeyError::new_err(field_name.to_object(py)))?;
+                force_setattr(py, obj, field_name, value)?;
+            }
         } else {
             force_setattr(py, obj, intern!(py, "__dict__"), dc_dict)?;
         }
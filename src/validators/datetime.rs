@@ -1,7 +1,8 @@
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyDateTime, PyDict, PyString};
+use pyo3::types::{PyDateTime, PyDict, PyString, PyTzInfo};
+use pyo3::{PyTraverseError, PyVisit};
 use speedate::DateTime;
 use std::cmp::Ordering;
 use strum::EnumMessage;
@@ -9,8 +10,9 @@ use strum::EnumMessage;
 use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{EitherDateTime, Input, TzInfo};
 
+use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -20,6 +22,7 @@ pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    convert_to_tz: Option<Py<PyTzInfo>>,
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -50,12 +53,16 @@ impl BuildValidator for DateTimeValidator {
             strict: is_strict(schema, config)?,
             constraints: DateTimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            convert_to_tz: extract_convert_to_tz(schema)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(DateTimeValidator {});
+impl_py_gc_traverse!(DateTimeValidator {
+    constraints,
+    convert_to_tz
+});
 
 impl Validator for DateTimeValidator {
     fn validate<'data>(
@@ -121,10 +128,18 @@ impl Validator for DateTimeValidator {
             }
 
             if let Some(ref tz_constraint) = constraints.tz {
-                tz_constraint.tz_check(speedate_dt.time.tz_offset, input)?;
+                tz_constraint.tz_check_datetime(py, &speedate_dt, input)?;
             }
         }
-        Ok(datetime.try_into_py(py)?)
+        let py_datetime = datetime.try_into_py(py)?;
+        if let Some(ref tz) = self.convert_to_tz {
+            // naive datetimes are left alone, `astimezone` would otherwise assume they're in local time
+            let dt = py_datetime.as_ref(py);
+            if !dt.call_method0(intern!(py, "utcoffset"))?.is_none() {
+                return Ok(dt.call_method1(intern!(py, "astimezone"), (tz,))?.into_py(py));
+            }
+        }
+        Ok(py_datetime)
     }
 
     fn get_name(&self) -> &str {
@@ -142,6 +157,8 @@ struct DateTimeConstraints {
     tz: Option<TZConstraint>,
 }
 
+impl_py_gc_traverse!(DateTimeConstraints { tz });
+
 impl DateTimeConstraints {
     fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
         let py = schema.py();
@@ -233,18 +250,63 @@ impl NowConstraint {
     }
 }
 
+fn extract_convert_to_tz(schema: &PyDict) -> PyResult<Option<Py<PyTzInfo>>> {
+    let py = schema.py();
+    match schema.get_item(intern!(py, "convert_to_tz"))? {
+        Some(tz) => {
+            if let Ok(s) = tz.downcast::<PyString>() {
+                let name = s.to_str()?;
+                let zone = zone_info(py, name)
+                    .map_err(|err| py_schema_error_type!("Invalid timezone {:?}: {}", name, py_err_string(py, err)))?;
+                Ok(Some(zone))
+            } else {
+                let tz_info: TzInfo = tz.extract::<i32>()?.try_into()?;
+                let py_tz_info = Py::new(py, tz_info)?.to_object(py).into_ref(py);
+                Ok(Some(py_tz_info.downcast::<PyTzInfo>()?.into()))
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+/// Look up an IANA timezone by name using `zoneinfo.ZoneInfo`
+fn zone_info(py: Python, name: &str) -> PyResult<Py<PyTzInfo>> {
+    let zone = py
+        .import("zoneinfo")?
+        .getattr(intern!(py, "ZoneInfo"))?
+        .call1((name,))?;
+    Ok(zone.downcast::<PyTzInfo>()?.into())
+}
+
 #[derive(Debug, Clone)]
 pub(super) enum TZConstraint {
     Naive,
     Aware(Option<i32>),
+    /// An IANA timezone, the offset required depends on the datetime being validated
+    Zone(Py<PyTzInfo>),
+}
+
+impl PyGcTraverse for TZConstraint {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        if let Self::Zone(zone) = self {
+            visit.call(zone)?;
+        }
+        Ok(())
+    }
 }
 
 impl TZConstraint {
-    pub(super) fn from_str(s: &str) -> PyResult<Self> {
+    pub(super) fn from_str(py: Python, s: &str) -> PyResult<Self> {
         match s {
             "naive" => Ok(TZConstraint::Naive),
             "aware" => Ok(TZConstraint::Aware(None)),
-            _ => py_schema_err!("Invalid tz_constraint {:?}", s),
+            _ => match zone_info(py, s) {
+                Ok(zone) => Ok(TZConstraint::Zone(zone)),
+                Err(_) => py_schema_err!(
+                    "Invalid tz_constraint {:?}, should be 'aware', 'naive', an offset or an IANA timezone name",
+                    s
+                ),
+            },
         }
     }
 
@@ -256,7 +318,7 @@ impl TZConstraint {
         };
         if let Ok(s) = tz_constraint.downcast::<PyString>() {
             let s = s.to_str()?;
-            Ok(Some(Self::from_str(s)?))
+            Ok(Some(Self::from_str(py, s)?))
         } else {
             let tz: i32 = tz_constraint.extract()?;
             Ok(Some(TZConstraint::Aware(Some(tz))))
@@ -265,7 +327,9 @@ impl TZConstraint {
 
     pub(super) fn tz_check<'d>(&self, tz_offset: Option<i32>, input: &'d impl Input<'d>) -> ValResult<()> {
         match (self, tz_offset) {
-            (TZConstraint::Aware(_), None) => return Err(ValError::new(ErrorTypeDefaults::TimezoneAware, input)),
+            (TZConstraint::Aware(_) | TZConstraint::Zone(_), None) => {
+                return Err(ValError::new(ErrorTypeDefaults::TimezoneAware, input))
+            }
             (TZConstraint::Aware(Some(tz_expected)), Some(tz_actual)) => {
                 let tz_expected = *tz_expected;
                 if tz_expected != tz_actual {
@@ -284,4 +348,24 @@ impl TZConstraint {
         }
         Ok(())
     }
+
+    /// Like `tz_check`, but resolves the offset required by an IANA timezone at the instant of `dt`
+    pub(super) fn tz_check_datetime<'d>(
+        &self,
+        py: Python<'d>,
+        dt: &DateTime,
+        input: &'d impl Input<'d>,
+    ) -> ValResult<()> {
+        match (self, dt.time.tz_offset) {
+            (TZConstraint::Zone(zone), Some(_)) => {
+                let zone_dt = PyDateTime::from_timestamp(py, dt.timestamp_tz() as f64, Some(zone.as_ref(py)))?;
+                let offset_seconds: f64 = zone_dt
+                    .call_method0(intern!(py, "utcoffset"))?
+                    .call_method0(intern!(py, "total_seconds"))?
+                    .extract()?;
+                TZConstraint::Aware(Some(offset_seconds.round() as i32)).tz_check(dt.time.tz_offset, input)
+            }
+            _ => self.tz_check(dt.time.tz_offset, input),
+        }
+    }
 }
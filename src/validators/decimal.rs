@@ -1,3 +1,4 @@
+use num_bigint::BigUint;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::sync::GILOnceCell;
@@ -12,6 +13,7 @@ use crate::errors::{ErrorTypeDefaults, Number};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::config::MultipleOfMode;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 static DECIMAL_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
@@ -35,6 +37,7 @@ pub struct DecimalValidator {
     allow_inf_nan: bool,
     check_digits: bool,
     multiple_of: Option<Py<PyAny>>,
+    multiple_of_mode: MultipleOfMode,
     le: Option<Py<PyAny>>,
     lt: Option<Py<PyAny>>,
     ge: Option<Py<PyAny>>,
@@ -65,6 +68,7 @@ impl BuildValidator for DecimalValidator {
             check_digits: decimal_places.is_some() || max_digits.is_some(),
             decimal_places,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            multiple_of_mode: MultipleOfMode::from_schema_or_config(schema, config)?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -83,6 +87,72 @@ impl_py_gc_traverse!(DecimalValidator {
     gt
 });
 
+/// The coefficient and exponent of a finite decimal, so `abs(decimal) == coefficient * 10**exponent`,
+/// or `None` for infinity and NaN
+fn decimal_parts(decimal: &PyAny) -> PyResult<Option<(BigUint, i64)>> {
+    let py = decimal.py();
+    let (_, digit_tuple, exponent): (&PyAny, &PyTuple, &PyAny) =
+        decimal.call_method0(intern!(py, "as_tuple"))?.extract()?;
+    let Ok(exponent) = exponent.extract::<i64>() else {
+        return Ok(None);
+    };
+    let mut coefficient = BigUint::default();
+    for digit in digit_tuple {
+        coefficient = coefficient * 10u32 + digit.extract::<u32>()?;
+    }
+    Ok(Some((coefficient, exponent)))
+}
+
+/// Check whether `decimal` is a multiple of `multiple_of` without rounding
+fn is_exact_multiple(decimal: &PyAny, multiple_of: &PyAny) -> PyResult<bool> {
+    // `multiple_of` might be an int or float in the schema, `Decimal()` converts those exactly
+    let py = decimal.py();
+    let decimal_type = get_decimal_type(py);
+    let multiple_of = decimal_type.as_ref(py).call1((multiple_of,))?;
+    match (decimal_parts(decimal)?, decimal_parts(multiple_of)?) {
+        (Some((value, value_exponent)), Some((multiple, multiple_exponent))) => Ok(is_decimal_multiple(
+            &value,
+            value_exponent,
+            &multiple,
+            multiple_exponent,
+        )),
+        _ => Ok(false),
+    }
+}
+
+/// Whether `value * 10**value_exponent` is an integer multiple of `multiple * 10**multiple_exponent`, without
+/// building powers of ten larger than the coefficients since exponents can be huge, e.g. `Decimal('1e999999999')`
+pub(crate) fn is_decimal_multiple(
+    value: &BigUint,
+    value_exponent: i64,
+    multiple: &BigUint,
+    multiple_exponent: i64,
+) -> bool {
+    let zero = BigUint::default();
+    if *multiple == zero {
+        return false;
+    }
+    if *value == zero {
+        return true;
+    }
+    let ten = BigUint::from(10u32);
+    if value_exponent >= multiple_exponent {
+        // `value * 10**k % multiple`, with the power reduced modulo `multiple`
+        let k = BigUint::from(value_exponent.abs_diff(multiple_exponent));
+        (value % multiple) * ten.modpow(&k, multiple) % multiple == zero
+    } else {
+        // `value % (multiple * 10**k)`, where `10**k > value` means `value` can't be a non-zero multiple
+        let k = multiple_exponent.abs_diff(value_exponent);
+        if k > value.bits() {
+            return false;
+        }
+        let Ok(k) = u32::try_from(k) else {
+            return false;
+        };
+        value % (multiple * ten.pow(k)) == zero
+    }
+}
+
 fn extract_decimal_digits_info(decimal: &PyAny, normalized: bool, py: Python<'_>) -> ValResult<(u64, u64)> {
     let mut normalized_decimal: Option<&PyAny> = None;
     if normalized {
@@ -180,17 +250,23 @@ impl Validator for DecimalValidator {
         }
 
         if let Some(multiple_of) = &self.multiple_of {
-            // fraction = (decimal / multiple_of) % 1
-            let fraction: &PyAny = unsafe {
-                let division = PyObject::from_owned_ptr_or_err(
-                    py,
-                    pyo3::ffi::PyNumber_TrueDivide(decimal.as_ptr(), multiple_of.as_ptr()),
-                )?;
-                let one = 1.to_object(py);
-                py.from_owned_ptr_or_err(pyo3::ffi::PyNumber_Remainder(division.as_ptr(), one.as_ptr()))?
+            let is_multiple = match self.multiple_of_mode {
+                MultipleOfMode::Tolerant => {
+                    // fraction = (decimal / multiple_of) % 1
+                    let fraction: &PyAny = unsafe {
+                        let division = PyObject::from_owned_ptr_or_err(
+                            py,
+                            pyo3::ffi::PyNumber_TrueDivide(decimal.as_ptr(), multiple_of.as_ptr()),
+                        )?;
+                        let one = 1.to_object(py);
+                        py.from_owned_ptr_or_err(pyo3::ffi::PyNumber_Remainder(division.as_ptr(), one.as_ptr()))?
+                    };
+                    let zero = 0.to_object(py);
+                    fraction.eq(&zero)?
+                }
+                MultipleOfMode::Exact => is_exact_multiple(decimal, multiple_of.as_ref(py))?,
             };
-            let zero = 0.to_object(py);
-            if !fraction.eq(&zero)? {
+            if !is_multiple {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.to_string().into(),
@@ -1,13 +1,16 @@
+use ahash::AHashSet;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
-use crate::definitions::DefinitionRef;
+use crate::build_tools::{py_schema_err, schema_or_config};
+use crate::definitions::{expand_definition_templates, DefinitionRef};
 use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
 
 use crate::tools::SchemaDict;
 
+use super::validation_state::ValidationStateWithReboundExtra;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -24,29 +27,143 @@ impl BuildValidator for DefinitionsValidatorBuilder {
         let py = schema.py();
 
         let schema_definitions: &PyList = schema.get_as_req(intern!(py, "definitions"))?;
+        let inner_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let (schema_definitions, inner_schema) = expand_definition_templates(schema_definitions, inner_schema)?;
 
         for schema_definition in schema_definitions {
             let reference = schema_definition
                 .extract::<&PyDict>()?
                 .get_as_req::<String>(intern!(py, "ref"))?;
+            definitions.set_required_refs(&reference, required_refs(schema_definition, config)?);
             let validator = build_validator(schema_definition, config, definitions)?;
             definitions.add_definition(reference, validator)?;
         }
 
-        let inner_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
         build_validator(inner_schema, config, definitions)
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct DefinitionRefValidator {
-    definition: DefinitionRef<CombinedValidator>,
+/// Definitions which validating `schema` always requires whatever the input, through required fields and
+/// validators which always call their inner validator. Anything optional, e.g. defaults, nullable schemas,
+/// container items and functions which might not call their handler, doesn't count, nor do definitions
+/// required by only some union choices. Models and dataclasses aren't followed since an existing instance
+/// can be accepted without validating its fields.
+pub fn required_refs(schema: &PyAny, config: Option<&PyDict>) -> PyResult<AHashSet<String>> {
+    let Ok(schema) = schema.downcast::<PyDict>() else {
+        return Ok(AHashSet::new());
+    };
+    let py = schema.py();
+    let sub_schema = |key: &PyString, config: Option<&PyDict>| match schema.get_item(key)? {
+        Some(sub_schema) => required_refs(sub_schema, config),
+        None => Ok(AHashSet::new()),
+    };
+    let schema_type: &str = schema.get_as_req(intern!(py, "type"))?;
+    match schema_type {
+        "definition-ref" => Ok(AHashSet::from([schema.get_as_req(intern!(py, "schema_ref"))?])),
+        "definitions" | "function-before" | "function-after" | "custom-error" | "json" | "secret" => {
+            sub_schema(intern!(py, "schema"), config)
+        }
+        "call" => sub_schema(intern!(py, "arguments_schema"), config),
+        "chain" => required_by_any(schema.get_as_req::<&PyList>(intern!(py, "steps"))?.iter(), config),
+        "tuple-positional" => required_by_any(
+            schema.get_as_req::<&PyList>(intern!(py, "items_schema"))?.iter(),
+            config,
+        ),
+        "lax-or-strict" => required_by_all(
+            [
+                schema.get_as_req(intern!(py, "lax_schema"))?,
+                schema.get_as_req(intern!(py, "strict_schema"))?,
+            ]
+            .into_iter(),
+            config,
+        ),
+        "json-or-python" => required_by_all(
+            [
+                schema.get_as_req(intern!(py, "json_schema"))?,
+                schema.get_as_req(intern!(py, "python_schema"))?,
+            ]
+            .into_iter(),
+            config,
+        ),
+        "union" => {
+            let choices = schema.get_as_req::<&PyList>(intern!(py, "choices"))?;
+            let choices = choices
+                .iter()
+                .map(|choice| match choice.downcast::<PyTuple>() {
+                    Ok(py_tuple) => py_tuple.get_item(0),
+                    Err(_) => Ok(choice),
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            required_by_all(choices.into_iter(), config)
+        }
+        "tagged-union" => {
+            let choices = schema.get_as_req::<&PyDict>(intern!(py, "choices"))?;
+            let otherwise = schema.get_item(intern!(py, "otherwise"))?;
+            required_by_all(choices.values().iter().chain(otherwise), config)
+        }
+        "model-fields" | "typed-dict" => {
+            let total = schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?
+                .unwrap_or(true);
+            let fields = schema.get_as_req::<&PyDict>(intern!(py, "fields"))?;
+            let mut required = Vec::with_capacity(fields.len());
+            for field in fields.values() {
+                let field: &PyDict = field.downcast()?;
+                if field.get_as(intern!(py, "required"))?.unwrap_or(total) {
+                    required.push(field.get_as_req(intern!(py, "schema"))?);
+                }
+            }
+            required_by_any(required.into_iter(), config)
+        }
+        "dataclass-args" | "arguments" => {
+            let key = match schema_type {
+                "dataclass-args" => intern!(py, "fields"),
+                _ => intern!(py, "arguments_schema"),
+            };
+            let fields = schema
+                .get_as_req::<&PyList>(key)?
+                .iter()
+                .map(|field| field.downcast::<PyDict>()?.get_as_req(intern!(py, "schema")))
+                .collect::<PyResult<Vec<_>>>()?;
+            required_by_any(fields.into_iter(), config)
+        }
+        _ => Ok(AHashSet::new()),
+    }
 }
 
-impl DefinitionRefValidator {
-    pub fn new(definition: DefinitionRef<CombinedValidator>) -> Self {
-        Self { definition }
+/// Definitions required by any of `schemas`, where all of them are validated
+fn required_by_any<'py>(
+    schemas: impl Iterator<Item = &'py PyAny>,
+    config: Option<&PyDict>,
+) -> PyResult<AHashSet<String>> {
+    let mut required = AHashSet::new();
+    for schema in schemas {
+        required.extend(required_refs(schema, config)?);
+    }
+    Ok(required)
+}
+
+/// Definitions required by all of `schemas`, where only one of them is validated
+fn required_by_all<'py>(
+    schemas: impl Iterator<Item = &'py PyAny>,
+    config: Option<&PyDict>,
+) -> PyResult<AHashSet<String>> {
+    let mut required: Option<AHashSet<String>> = None;
+    for schema in schemas {
+        let schema_required = required_refs(schema, config)?;
+        required = Some(match required {
+            Some(required) => required.intersection(&schema_required).cloned().collect(),
+            None => schema_required,
+        });
     }
+    Ok(required.unwrap_or_default())
+}
+
+#[derive(Debug, Clone)]
+pub struct DefinitionRefValidator {
+    definition: DefinitionRef<CombinedValidator>,
+    // overrides of the validation time settings, applied to the shared definition at this use only
+    strict: Option<bool>,
+    from_attributes: Option<bool>,
 }
 
 impl BuildValidator for DefinitionRefValidator {
@@ -57,10 +174,23 @@ impl BuildValidator for DefinitionRefValidator {
         _config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let schema_ref = schema.get_as_req(intern!(schema.py(), "schema_ref"))?;
+        let py = schema.py();
+        let schema_ref = schema.get_as_req(intern!(py, "schema_ref"))?;
+        if schema.contains(intern!(py, "params"))? {
+            // params are removed when the templates in the enclosing definitions are expanded
+            return py_schema_err!(
+                "Definition `{}` is not a definition template, it can't have params",
+                schema_ref
+            );
+        }
 
         let definition = definitions.get_definition(schema_ref);
-        Ok(Self::new(definition).into())
+        Ok(Self {
+            definition,
+            strict: schema.get_as(intern!(py, "strict"))?,
+            from_attributes: schema.get_as(intern!(py, "from_attributes"))?,
+        }
+        .into())
     }
 }
 
@@ -72,9 +202,46 @@ impl Validator for DefinitionRefValidator {
         py: Python<'data>,
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        if self.has_overrides() {
+            self.validate_ref(py, input, &mut self.rebind_overrides(state))
+        } else {
+            self.validate_ref(py, input, state)
+        }
+    }
+
+    fn validate_assignments<'data>(
+        &self,
+        py: Python<'data>,
+        obj: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        if self.has_overrides() {
+            self.validate_ref_assignments(py, obj, assignments, revalidate, &mut self.rebind_overrides(state))
+        } else {
+            self.validate_ref_assignments(py, obj, assignments, revalidate, state)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.definition.get_or_init_name(|v| v.get_name().into())
+    }
+}
+
+impl DefinitionRefValidator {
+    fn validate_ref<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let validator = self.definition.get().unwrap();
         if let Some(id) = input.identity() {
+            if state.shared_instances_enabled() {
+                return self.validate_shared(py, validator, id, input, state);
+            }
             if state.recursion_guard.contains_or_insert(id, self.definition.id()) {
                 // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
                 Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input))
@@ -92,12 +259,12 @@ impl Validator for DefinitionRefValidator {
         }
     }
 
-    fn validate_assignment<'data>(
+    fn validate_ref_assignments<'data>(
         &self,
         py: Python<'data>,
         obj: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let validator = self.definition.get().unwrap();
@@ -109,17 +276,71 @@ impl Validator for DefinitionRefValidator {
                 if state.recursion_guard.incr_depth() {
                     return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, obj));
                 }
-                let output = validator.validate_assignment(py, obj, field_name, field_value, state);
+                let output = validator.validate_assignments(py, obj, assignments, revalidate, state);
                 state.recursion_guard.remove(id, self.definition.id());
                 state.recursion_guard.decr_depth();
                 output
             }
         } else {
-            validator.validate_assignment(py, obj, field_name, field_value, state)
+            validator.validate_assignments(py, obj, assignments, revalidate, state)
         }
     }
 
-    fn get_name(&self) -> &str {
-        self.definition.get_or_init_name(|v| v.get_name().into())
+    fn has_overrides(&self) -> bool {
+        self.strict.is_some() || self.from_attributes.is_some()
+    }
+
+    /// Apply the `strict` and `from_attributes` overrides set on this reference, they last until the returned
+    /// state is dropped so only validation through this reference is affected
+    fn rebind_overrides<'state, 'a>(
+        &self,
+        state: &'state mut ValidationState<'a>,
+    ) -> ValidationStateWithReboundExtra<'state, 'a> {
+        state.rebind_extra(|extra| {
+            if let Some(strict) = self.strict {
+                extra.strict = Some(strict);
+            }
+            if let Some(from_attributes) = self.from_attributes {
+                extra.from_attributes = Some(from_attributes);
+            }
+        })
+    }
+
+    /// With `val_circular_ref='reuse'`, an input already validated against this definition in this call gets
+    /// the same output, models register their instance before validating fields so cycles are linked too
+    fn validate_shared<'data>(
+        &self,
+        py: Python<'data>,
+        validator: &CombinedValidator,
+        id: usize,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let key = (
+            id,
+            self.definition.id(),
+            state.extra().strict,
+            state.extra().from_attributes,
+        );
+        if let Some(output) = state.shared_instance(py, key) {
+            return Ok(output);
+        }
+        if state.recursion_guard.contains_or_insert(id, self.definition.id()) {
+            // a cycle through a definition which doesn't register its output early, e.g. a typed dict
+            return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
+        }
+        if state.recursion_guard.incr_depth() {
+            return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
+        }
+        state.set_pending_instance(Some(key));
+        let output = validator.validate(py, input, state);
+        state.set_pending_instance(None);
+        state.recursion_guard.remove(id, self.definition.id());
+        state.recursion_guard.decr_depth();
+        match output {
+            Ok(ref output) => state.add_shared_instance(key, input.to_object(py), output.clone_ref(py)),
+            Err(_) => state.remove_shared_instance(key),
+        }
+        output
     }
 }
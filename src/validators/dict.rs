@@ -2,19 +2,23 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use jiter::Peak;
+
 use crate::build_tools::is_strict;
-use crate::errors::{AsLocItem, ValError, ValLineError, ValResult};
+use crate::errors::{AsLocItem, ErrorType, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::{
     DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator, MappingGenericIterator,
-    StringMappingGenericIterator,
+    PairsGenericIterator, StringMappingGenericIterator,
 };
 
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
 use super::list::length_check;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, JsonDirect, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct DictValidator {
@@ -23,6 +27,8 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    // accept dict items views and iterables of `(key, value)` pairs in lax mode
+    from_pairs: bool,
     name: String,
 }
 
@@ -55,6 +61,7 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            from_pairs: schema.get_as(intern!(py, "from_pairs"))?.unwrap_or(false),
             name,
         }
         .into())
@@ -74,22 +81,94 @@ impl Validator for DictValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let dict = input.validate_dict(strict)?;
+        let dict = match input.validate_dict(strict) {
+            Err(err) if self.from_pairs && !strict => input.lax_pairs().ok_or(err)?,
+            result => result?,
+        };
         match dict {
             GenericMapping::PyDict(py_dict) => {
-                self.validate_generic_mapping(py, input, DictGenericIterator::new(py_dict)?, state)
+                self.validate_generic_mapping(py, input, DictGenericIterator::new(py_dict)?, true, state)
             }
             GenericMapping::PyMapping(mapping) => {
                 state.floor_exactness(super::Exactness::Lax);
-                self.validate_generic_mapping(py, input, MappingGenericIterator::new(mapping)?, state)
+                self.validate_generic_mapping(py, input, MappingGenericIterator::new(mapping)?, true, state)
             }
             GenericMapping::StringMapping(dict) => {
-                self.validate_generic_mapping(py, input, StringMappingGenericIterator::new(dict)?, state)
+                self.validate_generic_mapping(py, input, StringMappingGenericIterator::new(dict)?, true, state)
             }
             GenericMapping::PyGetAttr(_, _) => unreachable!(),
             GenericMapping::JsonObject(json_object) => {
-                self.validate_generic_mapping(py, input, JsonObjectGenericIterator::new(json_object)?, state)
+                self.validate_generic_mapping(py, input, JsonObjectGenericIterator::new(json_object)?, true, state)
+            }
+            GenericMapping::PyPairs(pairs_input, iter) => {
+                state.floor_exactness(super::Exactness::Lax);
+                self.validate_generic_mapping(py, input, PairsGenericIterator::new(pairs_input, iter), false, state)
+            }
+        }
+    }
+
+    fn validate_json_direct(
+        &self,
+        py: Python<'_>,
+        json: &mut JsonDirect,
+        peak: Peak,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        // length errors include the whole dict as their input, so it has to be parsed first
+        if peak != Peak::Object || self.min_length.is_some() || self.max_length.is_some() {
+            let value = json.value(peak)?;
+            return self.validate(py, &value, state);
+        }
+
+        let output = PyDict::new(py);
+        let mut errors: Vec<ValLineError> = Vec::new();
+
+        let key_validator = self.key_validator.as_ref();
+        let value_validator = self.value_validator.as_ref();
+        let mut op_key = json.object_first()?;
+        while let Some(key) = op_key {
+            state.check_signals(py)?;
+            let peak = json.peak()?;
+            let output_key = match key_validator.validate(py, &key, state) {
+                Ok(value) => Some(value),
+                Err(ValError::LineErrors(line_errors)) => {
+                    for err in line_errors {
+                        errors.push(
+                            err.with_outer_location("[key]".into())
+                                .with_outer_location(key.as_loc_item()),
+                        );
+                    }
+                    None
+                }
+                Err(ValError::Omit) => {
+                    // the value still has to be consumed
+                    json.value(peak)?;
+                    op_key = json.object_step()?;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+            let output_value = match value_validator.validate_json_direct(py, json, peak, state) {
+                Ok(value) => Some(value),
+                Err(ValError::LineErrors(line_errors)) => {
+                    for err in line_errors {
+                        errors.push(err.with_outer_location(key.as_loc_item()));
+                    }
+                    None
+                }
+                Err(ValError::Omit) => None,
+                Err(err) => return Err(err),
+            };
+            if let (Some(key), Some(value)) = (output_key, output_value) {
+                output.set_item(key, value)?;
             }
+            op_key = json.object_step()?;
+        }
+
+        if errors.is_empty() {
+            Ok(output.into())
+        } else {
+            Err(ValError::LineErrors(errors))
         }
     }
 
@@ -104,15 +183,20 @@ impl DictValidator {
         py: Python<'data>,
         input: &'data impl Input<'data>,
         mapping_iter: impl Iterator<Item = ValResult<(impl BorrowInput + AsLocItem + 'data, impl BorrowInput + 'data)>>,
+        // when the number of items isn't known up front, `max_length` is checked as items are consumed
+        // so huge or endless iterables of pairs fail early
+        length_known: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let output = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::new();
+        let mut invalid_items: usize = 0;
 
         let key_validator = self.key_validator.as_ref();
         let value_validator = self.value_validator.as_ref();
         for item_result in mapping_iter {
             let (key, value) = item_result?;
+            state.check_item(py, input, || key.as_loc_item())?;
             let output_key = match key_validator.validate(py, key.borrow_input(), state) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
@@ -128,19 +212,35 @@ impl DictValidator {
                 Err(ValError::Omit) => continue,
                 Err(err) => return Err(err),
             };
-            let output_value = match value_validator.validate(py, value.borrow_input(), state) {
-                Ok(value) => Some(value),
-                Err(ValError::LineErrors(line_errors)) => {
-                    for err in line_errors {
-                        errors.push(err.with_outer_location(key.as_loc_item()));
+            let output_value =
+                match state.validate_item(py, value_validator, value.borrow_input(), || key.as_loc_item()) {
+                    Ok(value) => Some(value),
+                    Err(ValError::LineErrors(line_errors)) => {
+                        for err in line_errors {
+                            errors.push(err.with_outer_location(key.as_loc_item()));
+                        }
+                        None
                     }
-                    None
-                }
-                Err(ValError::Omit) => continue,
-                Err(err) => return Err(err),
-            };
+                    Err(ValError::Omit) => continue,
+                    Err(err) => return Err(err.with_outer_location(key.as_loc_item())),
+                };
             if let (Some(key), Some(value)) = (output_key, output_value) {
                 output.set_item(key, value)?;
+            } else {
+                invalid_items += 1;
+            }
+            if let (false, Some(max_length)) = (length_known, self.max_length) {
+                if output.len() + invalid_items > max_length {
+                    return Err(ValError::new(
+                        ErrorType::TooLong {
+                            field_type: "Dictionary".to_string(),
+                            max_length,
+                            actual_length: None,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
             }
         }
 
@@ -0,0 +1,259 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// RFC 5321 limits the local part to 64 octets
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+/// RFC 5321 limits a path to 256 octets, including the surrounding angle brackets
+const MAX_ADDRESS_LENGTH: usize = 254;
+
+#[derive(Debug, Clone)]
+pub struct EmailValidator {
+    strict: bool,
+    allow_smtputf8: bool,
+}
+
+impl BuildValidator for EmailValidator {
+    const EXPECTED_TYPE: &'static str = "email";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            allow_smtputf8: allow_smtputf8(schema)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EmailValidator {});
+
+impl Validator for EmailValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let either_str = input.validate_str(state.strict_or(self.strict), false)?.unpack(state);
+        let str = either_str.as_cow()?;
+        let email = parse_email(&str, self.allow_smtputf8).map_err(|reason| email_error(reason, input))?;
+        Ok(email.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// Validates addresses with an optional display name, e.g. `John Doe <john@example.com>`, returning a tuple of
+/// `(name, email)`, the name defaults to the local part of the address
+#[derive(Debug, Clone)]
+pub struct NameEmailValidator {
+    strict: bool,
+    allow_smtputf8: bool,
+}
+
+impl BuildValidator for NameEmailValidator {
+    const EXPECTED_TYPE: &'static str = "name-email";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            allow_smtputf8: allow_smtputf8(schema)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NameEmailValidator {});
+
+impl Validator for NameEmailValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let either_str = input.validate_str(state.strict_or(self.strict), false)?.unpack(state);
+        let str = either_str.as_cow()?;
+        let (name, address) = split_name_email(str.trim()).map_err(|reason| email_error(reason, input))?;
+        let email = parse_email(address, self.allow_smtputf8).map_err(|reason| email_error(reason, input))?;
+        let name = match name {
+            Some(name) => name,
+            // `parse_email` has checked there's an `@`
+            None => email[..email.rfind('@').unwrap()].to_string(),
+        };
+        Ok(PyTuple::new(py, [name.into_py(py), email.into_py(py)]).into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+fn allow_smtputf8(schema: &PyDict) -> PyResult<bool> {
+    Ok(schema.get_as(intern!(schema.py(), "allow_smtputf8"))?.unwrap_or(true))
+}
+
+fn email_error<'data>(reason: String, input: &'data impl Input<'data>) -> ValError {
+    ValError::new(ErrorType::EmailParsing { reason, context: None }, input)
+}
+
+type EmailResult<T> = Result<T, String>;
+
+/// Split `Name <address>` into its name and address, the name may be quoted
+fn split_name_email(str: &str) -> EmailResult<(Option<String>, &str)> {
+    let Some(without_bracket) = str.strip_suffix('>') else {
+        return Ok((None, str));
+    };
+    let Some(open) = without_bracket.rfind('<') else {
+        return Err("the address has a `>` without a matching `<`".to_string());
+    };
+    let address = &without_bracket[open + 1..];
+    let name = without_bracket[..open].trim();
+    let name = match name.strip_prefix('"').and_then(|n| n.strip_suffix('"')) {
+        Some(quoted) => quoted.replace("\\\"", "\"").replace("\\\\", "\\"),
+        None => name.to_string(),
+    };
+    if name.is_empty() {
+        Ok((None, address))
+    } else {
+        Ok((Some(name), address))
+    }
+}
+
+/// Parse and normalize an email address following RFC 5321, and RFC 6531 for internationalized addresses,
+/// the domain is lowercased and internationalized domain names are converted to their Unicode form
+fn parse_email(str: &str, allow_smtputf8: bool) -> EmailResult<String> {
+    let Some(at) = str.rfind('@') else {
+        return Err("an email address must have an @-sign".to_string());
+    };
+    let (local_part, domain) = (&str[..at], &str[at + 1..]);
+
+    check_local_part(local_part, allow_smtputf8)?;
+    let domain = normalize_domain(domain)?;
+
+    let email = format!("{local_part}@{domain}");
+    if email.len() > MAX_ADDRESS_LENGTH {
+        return Err(format!(
+            "the email address is too long ({} bytes, at most {MAX_ADDRESS_LENGTH} are allowed)",
+            email.len()
+        ));
+    }
+    Ok(email)
+}
+
+fn check_local_part(local_part: &str, allow_smtputf8: bool) -> EmailResult<()> {
+    if local_part.is_empty() {
+        return Err("there must be something before the @-sign".to_string());
+    }
+    if local_part.len() > MAX_LOCAL_PART_LENGTH {
+        return Err(format!(
+            "the part before the @-sign is too long ({} bytes, at most {MAX_LOCAL_PART_LENGTH} are allowed)",
+            local_part.len()
+        ));
+    }
+    if !local_part.is_ascii() && !allow_smtputf8 {
+        return Err("the part before the @-sign contains non-ASCII characters, which requires SMTPUTF8".to_string());
+    }
+
+    if let Some(quoted) = local_part.strip_prefix('"').and_then(|l| l.strip_suffix('"')) {
+        // quoted-string, any printable character is allowed, `"` and `\` must be escaped with `\`
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some(' '..='~') => (),
+                    _ => return Err("the quoted part before the @-sign has an invalid escape".to_string()),
+                },
+                '"' => return Err("the quoted part before the @-sign has an unescaped quote".to_string()),
+                ' '..='~' => (),
+                c if !c.is_ascii() => (),
+                c => return Err(invalid_character(c, "before the @-sign")),
+            }
+        }
+        return Ok(());
+    }
+
+    // dot-atom
+    if local_part.starts_with('.') {
+        return Err("the part before the @-sign can't start with a period".to_string());
+    }
+    if local_part.ends_with('.') {
+        return Err("the part before the @-sign can't end with a period".to_string());
+    }
+    if local_part.contains("..") {
+        return Err("the part before the @-sign can't have two periods in a row".to_string());
+    }
+    match local_part.chars().find(|c| !is_atext(*c) && *c != '.') {
+        Some(c) => Err(invalid_character(c, "before the @-sign")),
+        None => Ok(()),
+    }
+}
+
+/// `atext` from RFC 5322, extended with any non-ASCII character by RFC 6531
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c) || (!c.is_ascii() && !c.is_control())
+}
+
+fn invalid_character(c: char, position: &str) -> String {
+    format!("the part {position} contains an invalid character: {c:?}")
+}
+
+fn normalize_domain(domain: &str) -> EmailResult<String> {
+    if domain.is_empty() {
+        return Err("there must be something after the @-sign".to_string());
+    }
+
+    if let Some(literal) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) {
+        let valid = match literal.strip_prefix("IPv6:") {
+            Some(ipv6) => ipv6.parse::<Ipv6Addr>().is_ok(),
+            None => literal.parse::<Ipv4Addr>().is_ok(),
+        };
+        return if valid {
+            Ok(domain.to_string())
+        } else {
+            Err(format!("the domain literal {domain} is not a valid IP address"))
+        };
+    }
+
+    if domain.starts_with('.') || domain.ends_with('.') || domain.contains("..") {
+        return Err("the part after the @-sign has an empty label".to_string());
+    }
+    let ascii = idna::domain_to_ascii_strict(domain)
+        .map_err(|_| format!("the part after the @-sign is not a valid domain name: {domain}"))?;
+
+    if let Some(label) = ascii
+        .split('.')
+        .find(|label| label.starts_with('-') || label.ends_with('-'))
+    {
+        return Err(format!("the domain label {label} can't start or end with a hyphen"));
+    }
+    match ascii.rsplit_once('.') {
+        Some((_, tld)) if tld.bytes().all(|b| b.is_ascii_digit()) => {
+            Err("the part after the @-sign can't end with a number".to_string())
+        }
+        Some(_) => {
+            let (unicode, _) = idna::domain_to_unicode(&ascii);
+            Ok(unicode)
+        }
+        None => Err("the part after the @-sign is not valid, it should have a period".to_string()),
+    }
+}
@@ -0,0 +1,275 @@
+use std::str::FromStr;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType};
+use crate::tools::SchemaDict;
+
+use super::literal::{expected_repr_name, LiteralLookup};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// How strings in the input are matched against member names and values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchMode {
+    Exact,
+    Fuzzy,
+}
+
+impl FromStr for MatchMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exact" => Ok(Self::Exact),
+            "fuzzy" => Ok(Self::Fuzzy),
+            s => py_schema_err!("Invalid match_mode: `{}`, expected `exact` or `fuzzy`", s),
+        }
+    }
+}
+
+/// Member names and string values normalized by `fuzzy_key`, used with `match_mode='fuzzy'`
+#[derive(Debug, Clone)]
+struct FuzzyLookup {
+    // normalized spelling -> index in `spellings`
+    by_key: AHashMap<String, usize>,
+    // (normalized spelling, spelling as defined, index of the member in `LiteralLookup::values`)
+    spellings: Vec<(String, String, usize)>,
+}
+
+impl FuzzyLookup {
+    fn new(class: &PyType, members: &[PyObject]) -> PyResult<Self> {
+        let py = class.py();
+        let mut lookup = Self {
+            by_key: AHashMap::new(),
+            spellings: Vec::new(),
+        };
+        let all_members: &PyDict = class
+            .getattr(intern!(py, "__members__"))?
+            .call_method0(intern!(py, "copy"))?
+            .downcast()?;
+        for (name, member) in all_members {
+            // aliases map to the member they're an alias of
+            let Some(member_id) = members.iter().position(|m| m.is(member)) else {
+                continue;
+            };
+            // values first so they're shown rather than names when both are close to the input
+            if let Ok(value) = member.getattr(intern!(py, "value"))?.extract::<String>() {
+                lookup.insert(value, member_id)?;
+            }
+            lookup.insert(name.extract()?, member_id)?;
+        }
+        Ok(lookup)
+    }
+
+    fn insert(&mut self, spelling: String, member_id: usize) -> PyResult<()> {
+        let key = fuzzy_key(&spelling);
+        if let Some(&existing) = self.by_key.get(&key) {
+            let (_, existing_spelling, existing_member_id) = &self.spellings[existing];
+            if *existing_member_id != member_id {
+                return py_schema_err!(
+                    "`{}` and `{}` are different members but can't be told apart with match_mode='fuzzy'",
+                    existing_spelling,
+                    spelling
+                );
+            }
+        } else {
+            self.by_key.insert(key.clone(), self.spellings.len());
+            self.spellings.push((key, spelling, member_id));
+        }
+        Ok(())
+    }
+
+    fn get(&self, s: &str) -> Option<usize> {
+        self.by_key.get(&fuzzy_key(s)).map(|&index| self.spellings[index].2)
+    }
+
+    /// Reprs of up to three spellings close to `s`, closest first, with at most one spelling per member
+    fn close_matches(&self, s: &str) -> Vec<String> {
+        let key = fuzzy_key(s);
+        let key_len = key.chars().count();
+        let mut close: Vec<(usize, usize, &str)> = self
+            .spellings
+            .iter()
+            .filter_map(|(spelling_key, spelling, member_id)| {
+                let spelling_len = spelling_key.chars().count();
+                let max_distance = (spelling_len / 3).max(1);
+                // the distance is at least the difference in length, so long inputs never reach the quadratic check
+                if key_len.abs_diff(spelling_len) > max_distance {
+                    return None;
+                }
+                let distance = edit_distance(&key, spelling_key);
+                (distance <= max_distance).then_some((distance, *member_id, spelling.as_str()))
+            })
+            .collect();
+        close.sort_by_key(|&(distance, member_id, _)| (distance, member_id));
+        let mut seen_members = Vec::new();
+        close
+            .into_iter()
+            .filter(|(_, member_id, _)| {
+                let new = !seen_members.contains(member_id);
+                seen_members.push(*member_id);
+                new
+            })
+            .take(3)
+            .map(|(_, _, spelling)| format!("'{spelling}'"))
+            .collect()
+    }
+}
+
+/// Lowercase and drop `-` and `_` so e.g. `dark-red`, `DARK_RED` and `DarkRed` are all the same
+fn fuzzy_key(s: &str) -> String {
+    s.chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Levenshtein distance between two strings, counted in chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a_char == *b_char {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumValidator {
+    strict: bool,
+    class: Py<PyType>,
+    // member values -> members
+    lookup: LiteralLookup<PyObject>,
+    fuzzy: Option<FuzzyLookup>,
+    expected_repr: String,
+    class_name: String,
+    name: String,
+}
+
+impl BuildValidator for EnumValidator {
+    const EXPECTED_TYPE: &'static str = "enum";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let class_name = class.name()?.to_string();
+        let match_mode = match schema.get_as::<&str>(intern!(py, "match_mode"))? {
+            Some(s) => MatchMode::from_str(s)?,
+            None => MatchMode::Exact,
+        };
+
+        let members: Vec<&PyAny> = class.iter()?.collect::<PyResult<_>>()?;
+        if members.is_empty() {
+            return py_schema_err!("`{}` should have at least one member", class_name);
+        }
+        let mut values = Vec::with_capacity(members.len());
+        let mut repr_args = Vec::with_capacity(members.len());
+        for member in &members {
+            let value = member.getattr(intern!(py, "value"))?;
+            repr_args.push(value.repr()?.extract()?);
+            values.push((value, member.to_object(py)));
+        }
+        let lookup = LiteralLookup::new(py, values.into_iter())?;
+        let fuzzy = match match_mode {
+            MatchMode::Exact => None,
+            MatchMode::Fuzzy => Some(FuzzyLookup::new(class, &lookup.values)?),
+        };
+        let (expected_repr, _) = expected_repr_name(repr_args, Self::EXPECTED_TYPE);
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            class: class.into(),
+            lookup,
+            fuzzy,
+            expected_repr,
+            name: format!("{}[{class_name}]", Self::EXPECTED_TYPE),
+            class_name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EnumValidator { class, lookup });
+
+impl Validator for EnumValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let class = self.class.as_ref(py);
+        if let Some(py_input) = input.input_is_instance(class) {
+            return Ok(py_input.into_py(py));
+        }
+        let strict = state.strict_or(self.strict);
+        if state.extra().input_type == InputType::Python {
+            if strict {
+                return Err(ValError::new(
+                    ErrorType::IsInstanceOf {
+                        class: self.class_name.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+            state.floor_exactness(Exactness::Lax);
+        }
+        if let Some((_, member)) = self.lookup.validate(py, input)? {
+            return Ok(member.clone_ref(py));
+        }
+
+        // fuzzy matching is only used in lax mode
+        if let Some(fuzzy) = self.fuzzy.as_ref().filter(|_| !strict) {
+            if let Ok(either_str) = input.validate_str(true, false) {
+                let either_str = either_str.into_inner();
+                let s = either_str.as_cow()?;
+                if let Some(member_id) = fuzzy.get(&s) {
+                    state.floor_exactness(Exactness::Lax);
+                    return Ok(self.lookup.values[member_id].clone_ref(py));
+                }
+                let close_matches = fuzzy.close_matches(&s);
+                if !close_matches.is_empty() {
+                    let (candidates, _) = expected_repr_name(close_matches, Self::EXPECTED_TYPE);
+                    return Err(ValError::new(
+                        ErrorType::EnumCloseMatch {
+                            expected: self.expected_repr.clone(),
+                            candidates,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
+            }
+        }
+        Err(ValError::new(
+            ErrorType::Enum {
+                expected: self.expected_repr.clone(),
+                context: None,
+            },
+            input,
+        ))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -1,14 +1,17 @@
 use std::cmp::Ordering;
 
+use num_bigint::BigUint;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::build_tools::{is_strict, schema_or_config_same};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{Input, Int};
 use crate::tools::SchemaDict;
 
+use super::config::{MultipleOfMode, NumberPrecisionLoss};
+use super::decimal::is_decimal_multiple;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 pub struct FloatBuilder;
@@ -32,6 +35,11 @@ impl BuildValidator for FloatBuilder {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                precision_loss: NumberPrecisionLoss::from_schema_or_config(
+                    schema,
+                    config,
+                    NumberPrecisionLoss::Truncate,
+                )?,
             }
             .into())
         }
@@ -42,6 +50,7 @@ impl BuildValidator for FloatBuilder {
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    precision_loss: NumberPrecisionLoss,
 }
 
 impl BuildValidator for FloatValidator {
@@ -56,6 +65,7 @@ impl BuildValidator for FloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            precision_loss: NumberPrecisionLoss::from_schema_or_config(schema, config, NumberPrecisionLoss::Truncate)?,
         }
         .into())
     }
@@ -71,9 +81,11 @@ impl Validator for FloatValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
-        if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
+        let float = either_float.as_f64();
+        if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
+        check_int_precision(py, input, float, self.precision_loss)?;
         Ok(either_float.into_py(py))
     }
 
@@ -86,7 +98,9 @@ impl Validator for FloatValidator {
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    precision_loss: NumberPrecisionLoss,
     multiple_of: Option<f64>,
+    multiple_of_mode: MultipleOfMode,
     le: Option<f64>,
     lt: Option<f64>,
     ge: Option<f64>,
@@ -107,10 +121,17 @@ impl Validator for ConstrainedFloatValidator {
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
+        check_int_precision(py, input, float, self.precision_loss)?;
         if let Some(multiple_of) = self.multiple_of {
-            let rem = float % multiple_of;
-            let threshold = float.abs() / 1e9;
-            if rem.abs() > threshold && (rem - multiple_of).abs() > threshold {
+            let is_multiple = match self.multiple_of_mode {
+                MultipleOfMode::Tolerant => {
+                    let rem = float % multiple_of;
+                    let threshold = float.abs() / 1e9;
+                    !(rem.abs() > threshold && (rem - multiple_of).abs() > threshold)
+                }
+                MultipleOfMode::Exact => is_exact_multiple(float, multiple_of),
+            };
+            if !is_multiple {
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.into(),
@@ -183,7 +204,9 @@ impl BuildValidator for ConstrainedFloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            precision_loss: NumberPrecisionLoss::from_schema_or_config(schema, config, NumberPrecisionLoss::Truncate)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            multiple_of_mode: MultipleOfMode::from_schema_or_config(schema, config)?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -192,3 +215,77 @@ impl BuildValidator for ConstrainedFloatValidator {
         .into())
     }
 }
+
+/// Every int with an absolute value below 2**53 can be represented exactly as a float
+const MAX_EXACT_INT: f64 = 9_007_199_254_740_992.0;
+
+/// Apply `precision_loss` to ints which couldn't be represented exactly as a float
+fn check_int_precision<'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    float: f64,
+    precision_loss: NumberPrecisionLoss,
+) -> ValResult<()> {
+    if precision_loss == NumberPrecisionLoss::Truncate || float.abs() < MAX_EXACT_INT {
+        return Ok(());
+    }
+    // strings have already been parsed as floats, so only ints can have lost precision here
+    let Ok(either_int) = input.exact_int() else {
+        return Ok(());
+    };
+    let int = either_int.as_int()?;
+    if !exceeds_float_precision(&int) {
+        return Ok(());
+    }
+    match precision_loss {
+        NumberPrecisionLoss::Error => Err(ValError::new(ErrorTypeDefaults::FloatFromInt, input)),
+        _ => {
+            let int = match int {
+                Int::I64(i) => i.to_string(),
+                Int::Big(b) => b.to_string(),
+            };
+            precision_loss.warn(
+                py,
+                &format!("{int} can't be represented exactly as a float, it was rounded to {float:?}"),
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// Whether `int` has more significant bits than the 53 bit mantissa of a float
+fn exceeds_float_precision(int: &Int) -> bool {
+    let significant_bits = match int {
+        Int::I64(i) => {
+            let abs = i.unsigned_abs();
+            u64::from((u64::BITS - abs.leading_zeros()).saturating_sub(abs.trailing_zeros()))
+        }
+        Int::Big(b) => b.bits() - b.trailing_zeros().unwrap_or(0),
+    };
+    significant_bits > u64::from(f64::MANTISSA_DIGITS)
+}
+
+/// Split a finite float into the coefficient and exponent of its shortest decimal representation, i.e. its
+/// `repr()`, so `abs(value) == coefficient * 10**exponent` for the number as it was written
+fn decimal_parts(value: f64) -> Option<(BigUint, i64)> {
+    let repr = format!("{:e}", value.abs());
+    let (mantissa, exponent) = repr.split_once('e')?;
+    let fraction_digits = mantissa.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+    let coefficient: BigUint = mantissa.replace('.', "").parse().ok()?;
+    let exponent = exponent.parse::<i64>().ok()? - i64::try_from(fraction_digits).ok()?;
+    Some((coefficient, exponent))
+}
+
+/// Whether `value` is exactly a multiple of `multiple_of`, comparing the decimal numbers the floats represent
+/// so the ratio of the two has to be an integer, e.g. `0.3` is a multiple of `0.1` but `0.3` isn't a multiple of `0.25`
+fn is_exact_multiple(value: f64, multiple_of: f64) -> bool {
+    if !value.is_finite() || !multiple_of.is_finite() {
+        return false;
+    }
+    match (decimal_parts(value), decimal_parts(multiple_of)) {
+        (Some((value, value_exponent)), Some((multiple, multiple_exponent))) => {
+            is_decimal_multiple(&value, value_exponent, &multiple, multiple_exponent)
+        }
+        _ => false,
+    }
+}
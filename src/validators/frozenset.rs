@@ -17,6 +17,8 @@ pub struct FrozenSetValidator {
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items: bool,
+    deterministic: bool,
     name: String,
 }
 
@@ -41,12 +43,18 @@ impl Validator for FrozenSetValidator {
             _ => Exactness::Lax,
         };
         state.floor_exactness(exactness);
+        let collection = if self.deterministic {
+            collection.into_deterministic()?
+        } else {
+            collection
+        };
         let f_set = PyFrozenSet::empty(py)?;
         collection.validate_to_set(
             py,
             f_set,
             input,
             self.max_length,
+            self.unique_items,
             "Frozenset",
             &self.item_validator,
             state,
@@ -11,12 +11,12 @@ use crate::errors::{
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
 use crate::tools::{function_name, safe_repr, SchemaDict};
+use crate::trace::TraceSpan;
 use crate::PydanticUseDefault;
 
 use super::generator::InternalValidator;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, InputType, ValidationState,
-    Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, InputType, ValidationState, Validator,
 };
 
 struct FunctionInfo {
@@ -95,20 +95,21 @@ macro_rules! impl_validator {
                 input: &'data impl Input<'data>,
                 state: &mut ValidationState<'_>,
             ) -> ValResult<PyObject> {
+                let _span = TraceSpan::enter_slow("validate", &self.name);
                 let validate = |v, s: &mut ValidationState<'_>| self.validator.validate(py, v, s);
                 self._validate(validate, py, input, state)
             }
-            fn validate_assignment<'data>(
+            fn validate_assignments<'data>(
                 &self,
                 py: Python<'data>,
                 obj: &'data PyAny,
-                field_name: &'data str,
-                field_value: &'data PyAny,
+                assignments: &'data PyDict,
+                revalidate: bool,
                 state: &mut ValidationState,
             ) -> ValResult<PyObject> {
                 let validate = move |v, s: &mut ValidationState<'_>| {
                     self.validator
-                        .validate_assignment(py, v, field_name, field_value, s)
+                        .validate_assignments(py, v, assignments, revalidate, s)
                 };
                 self._validate(validate, py, obj, state)
             }
@@ -141,7 +142,7 @@ impl FunctionBeforeValidator {
         state: &'s mut ValidationState<'_>,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
             self.func.call1(py, (input.to_object(py), info))
         } else {
             self.func.call1(py, (input.to_object(py),))
@@ -175,7 +176,7 @@ impl FunctionAfterValidator {
     ) -> ValResult<PyObject> {
         let v = call(input, state)?;
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
             self.func.call1(py, (v.to_object(py), info))
         } else {
             self.func.call1(py, (v.to_object(py),))
@@ -231,8 +232,9 @@ impl Validator for FunctionPlainValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
+        let _span = TraceSpan::enter_slow("validate", &self.name);
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
             self.func.call1(py, (input.to_object(py), info))
         } else {
             self.func.call1(py, (input.to_object(py),))
@@ -296,7 +298,7 @@ impl FunctionWrapValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
             self.func.call1(py, (input.to_object(py), handler, info))
         } else {
             self.func.call1(py, (input.to_object(py), handler))
@@ -318,30 +320,41 @@ impl Validator for FunctionWrapValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        let handler = ValidatorCallable {
-            validator: InternalValidator::new(
-                py,
-                "ValidatorCallable",
-                self.validator.clone(),
-                state,
-                self.hide_input_in_errors,
-                self.validation_error_cause,
-            ),
-        };
-        let handler = Py::new(py, handler)?.into_ref(py);
+        let _span = TraceSpan::enter_slow("validate", &self.name);
+        if self.info_arg {
+            // create the state dict now, so the function's `info.state` is the one the handler uses
+            state.call_state(py);
+        }
+        let mut validator = InternalValidator::new(
+            py,
+            "ValidatorCallable",
+            self.validator.clone(),
+            state,
+            self.hide_input_in_errors,
+            self.validation_error_cause,
+        );
+        validator.share_timeout(state);
+        let handler = Py::new(py, ValidatorCallable { validator })?.into_ref(py);
         let result = self._validate(handler, py, input.to_object(py).into_ref(py), state);
-        state.exactness = handler.borrow_mut().validator.exactness;
+        let mut handler = handler.borrow_mut();
+        state.exactness = handler.validator.exactness;
+        handler.validator.finish_call_state(py, state);
+        // a timeout in the handler stops validation even if the function caught it
+        handler.validator.finish_timeout(state)?;
         result
     }
 
-    fn validate_assignment<'data>(
+    fn validate_assignments<'data>(
         &self,
         py: Python<'data>,
         obj: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
+        if self.info_arg {
+            state.call_state(py);
+        }
         let handler = AssignmentValidatorCallable {
             validator: InternalValidator::new(
                 py,
@@ -351,10 +364,13 @@ impl Validator for FunctionWrapValidator {
                 self.hide_input_in_errors,
                 self.validation_error_cause,
             ),
-            updated_field_name: field_name.to_string(),
-            updated_field_value: field_value.to_object(py),
+            assignments: assignments.into(),
+            revalidate,
         };
-        self._validate(Py::new(py, handler)?.into_ref(py), py, obj, state)
+        let handler = Py::new(py, handler)?.into_ref(py);
+        let result = self._validate(handler, py, obj, state);
+        handler.borrow().validator.finish_call_state(py, state);
+        result
     }
 
     fn get_name(&self) -> &str {
@@ -391,8 +407,8 @@ impl ValidatorCallable {
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug)]
 struct AssignmentValidatorCallable {
-    updated_field_name: String,
-    updated_field_value: Py<PyAny>,
+    assignments: Py<PyDict>,
+    revalidate: bool,
     validator: InternalValidator,
 }
 
@@ -400,11 +416,11 @@ struct AssignmentValidatorCallable {
 impl AssignmentValidatorCallable {
     fn __call__(&mut self, py: Python, input_value: &PyAny, outer_location: Option<&PyAny>) -> PyResult<PyObject> {
         let outer_location = outer_location.map(AsLocItem::as_loc_item);
-        self.validator.validate_assignment(
+        self.validator.validate_assignments(
             py,
             input_value,
-            self.updated_field_name.as_str(),
-            self.updated_field_value.as_ref(py),
+            self.assignments.as_ref(py),
+            self.revalidate,
             outer_location,
         )
     }
@@ -416,6 +432,11 @@ impl AssignmentValidatorCallable {
     fn __str__(&self) -> String {
         self.__repr__()
     }
+
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        visit.call(&self.assignments)?;
+        self.validator.py_gc_traverse(&visit)
+    }
 }
 
 macro_rules! py_err_string {
@@ -468,16 +489,20 @@ pub struct ValidationInfo {
     data: Option<Py<PyDict>>,
     field_name: Option<Py<PyString>>,
     mode: InputType,
+    state: Py<PyDict>,
 }
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+    fn new(py: Python, state: &mut ValidationState, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+        let call_state = state.call_state(py);
+        let extra = state.extra();
         Self {
             config: config.clone_ref(py),
             context: extra.context.map(Into::into),
             field_name,
             data: extra.data.map(Into::into),
             mode: extra.input_type,
+            state: call_state,
         }
     }
 }
@@ -4,13 +4,14 @@ use std::sync::Arc;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::errors::{ErrorType, LocItem, ValError, ValResult};
+use crate::errors::{ErrorType, LocItem, ValError, ValLineError, ValResult};
 use crate::input::{GenericIterator, Input};
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
 
 use super::list::get_items_schema;
+use super::validation_state::TimeoutProgress;
 use super::{
     BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ValidationState, Validator,
 };
@@ -142,6 +143,7 @@ impl ValidatorIterator {
                                         None,
                                         hide_input_in_errors,
                                         validation_error_cause,
+                                        None,
                                     ));
                                 }
                             }
@@ -169,6 +171,7 @@ impl ValidatorIterator {
                                     None,
                                     hide_input_in_errors,
                                     validation_error_cause,
+                                    None,
                                 ));
                             }
                         }
@@ -210,13 +213,21 @@ pub struct InternalValidator {
     data: Option<Py<PyDict>>,
     strict: Option<bool>,
     from_attributes: Option<bool>,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
     context: Option<PyObject>,
     self_instance: Option<PyObject>,
+    // created by the first function to use it if the outer validation didn't have one yet
+    call_state: Option<Py<PyDict>>,
     recursion_guard: RecursionGuard,
     pub(crate) exactness: Option<Exactness>,
     validation_mode: InputType,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    // only shared with the outer validation by `share_timeout`, a generator is consumed after it's finished
+    timeout_progress: TimeoutProgress,
+    // a timeout raised during a call, kept so it can't be caught by the function making the call
+    timeout: Option<ValLineError>,
 }
 
 impl fmt::Debug for InternalValidator {
@@ -230,10 +241,11 @@ impl InternalValidator {
         py: Python,
         name: &str,
         validator: Arc<CombinedValidator>,
-        state: &ValidationState,
+        state: &mut ValidationState,
         hide_input_in_errors: bool,
         validation_error_cause: bool,
     ) -> Self {
+        let call_state = state.existing_call_state(py);
         let extra = state.extra();
         Self {
             name: name.to_string(),
@@ -241,50 +253,77 @@ impl InternalValidator {
             data: extra.data.map(|d| d.into_py(py)),
             strict: extra.strict,
             from_attributes: extra.from_attributes,
+            by_alias: extra.by_alias,
+            by_name: extra.by_name,
             context: extra.context.map(|d| d.into_py(py)),
             self_instance: extra.self_instance.map(|d| d.into_py(py)),
+            call_state,
             recursion_guard: state.recursion_guard.clone(),
             exactness: state.exactness,
             validation_mode: extra.input_type,
             hide_input_in_errors,
             validation_error_cause,
+            timeout_progress: TimeoutProgress::default(),
+            timeout: None,
+        }
+    }
+
+    /// Share a state dict created during a call with the outer validation, so later functions use the same dict
+    pub fn finish_call_state(&self, py: Python, state: &mut ValidationState) {
+        if let Some(ref call_state) = self.call_state {
+            state.set_call_state(call_state.clone_ref(py));
         }
     }
 
-    pub fn validate_assignment<'data>(
+    /// Apply the deadline of the outer validation to calls made with this validator
+    pub fn share_timeout(&mut self, state: &ValidationState) {
+        self.timeout_progress = state.timeout_progress();
+    }
+
+    /// Carry progress towards the deadline back to the outer validation, and raise the timeout again if a call
+    /// timed out, even if the function making the call caught the error
+    pub fn finish_timeout(&mut self, state: &mut ValidationState) -> ValResult<()> {
+        state.set_timeout_progress(self.timeout_progress);
+        match self.timeout.take() {
+            Some(line_error) => Err(ValError::Timeout(Box::new(line_error))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn validate_assignments<'data>(
         &mut self,
         py: Python<'data>,
         model: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         outer_location: Option<LocItem>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             strict: self.strict,
+            strict_overrides: None,
             from_attributes: self.from_attributes,
+            by_alias: self.by_alias,
+            by_name: self.by_name,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard);
         state.exactness = self.exactness;
+        state.set_timeout_progress(self.timeout_progress);
+        if let Some(ref call_state) = self.call_state {
+            state.set_call_state(call_state.clone_ref(py));
+        }
         let result = self
             .validator
-            .validate_assignment(py, model, field_name, field_value, &mut state)
-            .map_err(|e| {
-                ValidationError::from_val_error(
-                    py,
-                    self.name.to_object(py),
-                    InputType::Python,
-                    e,
-                    outer_location,
-                    self.hide_input_in_errors,
-                    self.validation_error_cause,
-                )
-            });
+            .validate_assignments(py, model, assignments, revalidate, &mut state);
+        if self.call_state.is_none() {
+            self.call_state = state.take_call_state();
+        }
         self.exactness = state.exactness;
-        result
+        self.timeout_progress = state.timeout_progress();
+        result.map_err(|e| self.validation_error(py, e, outer_location))
     }
 
     pub fn validate<'data>(
@@ -297,25 +336,46 @@ impl InternalValidator {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             strict: self.strict,
+            strict_overrides: None,
             from_attributes: self.from_attributes,
+            by_alias: self.by_alias,
+            by_name: self.by_name,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard);
         state.exactness = self.exactness;
-        let result = self.validator.validate(py, input, &mut state).map_err(|e| {
-            ValidationError::from_val_error(
-                py,
-                self.name.to_object(py),
-                InputType::Python,
-                e,
-                outer_location,
-                self.hide_input_in_errors,
-                self.validation_error_cause,
-            )
-        });
+        state.set_timeout_progress(self.timeout_progress);
+        if let Some(ref call_state) = self.call_state {
+            state.set_call_state(call_state.clone_ref(py));
+        }
+        let result = self.validator.validate(py, input, &mut state);
+        if self.call_state.is_none() {
+            self.call_state = state.take_call_state();
+        }
         self.exactness = state.exactness;
-        result
+        self.timeout_progress = state.timeout_progress();
+        result.map_err(|e| self.validation_error(py, e, outer_location))
+    }
+
+    fn validation_error(&mut self, py: Python, error: ValError, outer_location: Option<LocItem>) -> PyErr {
+        if let ValError::Timeout(ref line_error) = error {
+            let mut line_error = (**line_error).clone();
+            if let Some(ref loc) = outer_location {
+                line_error.location.with_outer(loc.clone());
+            }
+            self.timeout = Some(line_error);
+        }
+        ValidationError::from_val_error(
+            py,
+            self.name.to_object(py),
+            InputType::Python,
+            error,
+            outer_location,
+            self.hide_input_in_errors,
+            self.validation_error_cause,
+            None,
+        )
     }
 }
 
@@ -323,5 +383,6 @@ impl_py_gc_traverse!(InternalValidator {
     validator,
     data,
     context,
-    self_instance
+    self_instance,
+    call_state
 });
@@ -1,18 +1,20 @@
-use num_bigint::BigInt;
+use num_bigint::{BigInt, BigUint};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyString};
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{Input, Int};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config};
+use crate::errors::{ErrorType, Number, ValError, ValLineError, ValResult};
+use crate::input::{EitherInt, Input, Int};
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::config::NumberPrecisionLoss;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    precision_loss: NumberPrecisionLoss,
 }
 
 impl BuildValidator for IntValidator {
@@ -28,12 +30,17 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "le"))?.is_some()
             || schema.get_item(intern!(py, "lt"))?.is_some()
             || schema.get_item(intern!(py, "ge"))?.is_some()
-            || schema.get_item(intern!(py, "gt"))?.is_some();
+            || schema.get_item(intern!(py, "gt"))?.is_some()
+            || schema.get_item(intern!(py, "bit_width"))?.is_some()
+            // so `signed` without `bit_width` is rejected
+            || schema.get_item(intern!(py, "signed"))?.is_some()
+            || max_digits(schema, config)?.is_some();
         if use_constrained {
             ConstrainedIntValidator::build(schema, config)
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                precision_loss: NumberPrecisionLoss::from_schema_or_config(schema, config, NumberPrecisionLoss::Error)?,
             }
             .into())
         }
@@ -49,9 +56,8 @@ impl Validator for IntValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        input
-            .validate_int(state.strict_or(self.strict))
-            .map(|val_match| val_match.unpack(state).into_py(py))
+        let either_int = validate_int(py, input, state.strict_or(self.strict), self.precision_loss, state)?;
+        Ok(either_int.into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -62,6 +68,9 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    precision_loss: NumberPrecisionLoss,
+    max_digits: Option<u64>,
+    range: Option<IntRange>,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
@@ -78,9 +87,49 @@ impl Validator for ConstrainedIntValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        if let Some(max_digits) = self.max_digits {
+            // strings are checked before they're parsed, since parsing a huge int is the cost being limited
+            if !strict {
+                if let Ok(val_match) = input.validate_str(true, false) {
+                    if str_exceeds_digits(&val_match.into_inner().as_cow()?, max_digits) {
+                        return Err(ValError::new(
+                            ErrorType::IntMaxDigits {
+                                max_digits,
+                                context: None,
+                            },
+                            input,
+                        ));
+                    }
+                }
+            }
+        }
+        let either_int = validate_int(py, input, strict, self.precision_loss, state)?;
         let int_value = either_int.as_int()?;
 
+        if let Some(max_digits) = self.max_digits {
+            if exceeds_digits(&int_value, max_digits) {
+                return Err(ValError::new(
+                    ErrorType::IntMaxDigits {
+                        max_digits,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref range) = self.range {
+            if !range.contains(&int_value) {
+                return Err(ValError::new(
+                    ErrorType::IntOutOfRange {
+                        min: range.min_number(),
+                        max: range.max_number(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
         if let Some(ref multiple_of) = self.multiple_of {
             if &int_value % multiple_of != Int::Big(BigInt::from(0)) {
                 return Err(ValError::new(
@@ -147,9 +196,16 @@ impl Validator for ConstrainedIntValidator {
 impl ConstrainedIntValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<Int> = schema.get_as(intern!(py, "multiple_of"))?;
+        if multiple_of == Some(Int::I64(0)) {
+            return py_schema_err!("multiple_of must not be 0");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            precision_loss: NumberPrecisionLoss::from_schema_or_config(schema, config, NumberPrecisionLoss::Error)?,
+            max_digits: max_digits(schema, config)?,
+            range: IntRange::from_schema(schema)?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -158,3 +214,322 @@ impl ConstrainedIntValidator {
         .into())
     }
 }
+
+/// Validates into a machine width integer, `int64` or `uint64`, the value and constraints are compared as native
+/// integers rather than via `Int`, which could hold a big int
+#[derive(Debug, Clone)]
+pub struct MachineIntValidator {
+    strict: bool,
+    precision_loss: NumberPrecisionLoss,
+    range: IntRange,
+    multiple_of: Option<i128>,
+    le: Option<i128>,
+    lt: Option<i128>,
+    ge: Option<i128>,
+    gt: Option<i128>,
+    name: &'static str,
+}
+
+macro_rules! machine_int_builder {
+    ($builder:ident, $expected_type:literal, $int:ty) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                let py = schema.py();
+                let constraint = |key: &PyString| -> PyResult<Option<i128>> {
+                    let Some(value) = schema.get_as::<&PyAny>(key)? else {
+                        return Ok(None);
+                    };
+                    match value.extract::<$int>() {
+                        Ok(value) => Ok(Some(i128::from(value))),
+                        Err(_) => py_schema_err!("{} must fit in {}, got {}", key, $expected_type, value),
+                    }
+                };
+                let multiple_of = constraint(intern!(py, "multiple_of"))?;
+                if multiple_of == Some(0) {
+                    return py_schema_err!("multiple_of must not be 0");
+                }
+                Ok(MachineIntValidator {
+                    strict: is_strict(schema, config)?,
+                    precision_loss: NumberPrecisionLoss::from_schema_or_config(
+                        schema,
+                        config,
+                        NumberPrecisionLoss::Error,
+                    )?,
+                    range: IntRange {
+                        min: i128::from(<$int>::MIN),
+                        max: i128::from(<$int>::MAX),
+                    },
+                    multiple_of,
+                    le: constraint(intern!(py, "le"))?,
+                    lt: constraint(intern!(py, "lt"))?,
+                    ge: constraint(intern!(py, "ge"))?,
+                    gt: constraint(intern!(py, "gt"))?,
+                    name: Self::EXPECTED_TYPE,
+                }
+                .into())
+            }
+        }
+    };
+}
+
+machine_int_builder!(Int64Builder, "int64", i64);
+machine_int_builder!(UInt64Builder, "uint64", u64);
+
+impl_py_gc_traverse!(MachineIntValidator {});
+
+impl Validator for MachineIntValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let either_int = validate_int(py, input, state.strict_or(self.strict), self.precision_loss, state)?;
+        let value = match &either_int {
+            EitherInt::I64(i) => Some(i128::from(*i)),
+            EitherInt::U64(u) => Some(i128::from(*u)),
+            EitherInt::BigInt(b) => i128::try_from(b).ok(),
+            EitherInt::Py(py_int) => py_int.extract::<i128>().ok(),
+        };
+        let value = match value {
+            Some(value) if self.range.contains_i128(value) => value,
+            _ => {
+                return Err(ValError::new(
+                    ErrorType::IntOutOfRange {
+                        min: self.range.min_number(),
+                        max: self.range.max_number(),
+                        context: None,
+                    },
+                    input,
+                ))
+            }
+        };
+
+        if let Some(multiple_of) = self.multiple_of {
+            if value % multiple_of != 0 {
+                return Err(ValError::new(
+                    ErrorType::MultipleOf {
+                        multiple_of: IntRange::to_number(multiple_of),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(le) = self.le {
+            if value > le {
+                return Err(ValError::new(
+                    ErrorType::LessThanEqual {
+                        le: IntRange::to_number(le),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(lt) = self.lt {
+            if value >= lt {
+                return Err(ValError::new(
+                    ErrorType::LessThan {
+                        lt: IntRange::to_number(lt),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ge) = self.ge {
+            if value < ge {
+                return Err(ValError::new(
+                    ErrorType::GreaterThanEqual {
+                        ge: IntRange::to_number(ge),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(gt) = self.gt {
+            if value <= gt {
+                return Err(ValError::new(
+                    ErrorType::GreaterThan {
+                        gt: IntRange::to_number(gt),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        match either_int {
+            // an exact python int is returned unchanged, rather than creating a new one
+            EitherInt::Py(py_int) => Ok(py_int.into_py(py)),
+            _ => Ok(value.into_py(py)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
+
+/// Validate an int, in lax mode numbers and strings with a fractional part are handled according to
+/// `precision_loss`
+fn validate_int<'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    strict: bool,
+    precision_loss: NumberPrecisionLoss,
+    state: &mut ValidationState,
+) -> ValResult<EitherInt<'data>> {
+    match input.validate_int(strict) {
+        Ok(val_match) => Ok(val_match.unpack(state)),
+        Err(ValError::LineErrors(line_errors))
+            if !strict && precision_loss != NumberPrecisionLoss::Error && has_fractional_part(&line_errors) =>
+        {
+            let Some((decimal, truncated)) = truncate_decimal(py, input)? else {
+                return Err(ValError::LineErrors(line_errors));
+            };
+            precision_loss.warn(
+                py,
+                &format!("{decimal} has a fractional part, it was truncated to {truncated}"),
+            )?;
+            state.floor_exactness(Exactness::Lax);
+            Ok(EitherInt::Py(truncated))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Whether validation failed only because the input is a number, or a string, with a fractional part
+fn has_fractional_part(line_errors: &[ValLineError]) -> bool {
+    matches!(
+        line_errors,
+        [line_error] if matches!(line_error.error_type, ErrorType::IntFromFloat { .. } | ErrorType::IntParsing { .. })
+    )
+}
+
+/// Truncate `input` towards zero via a `Decimal`, so large values and long strings are truncated exactly,
+/// `None` if `input` isn't a finite number with a fractional part, e.g. `'1e3'` remains invalid
+fn truncate_decimal<'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+) -> ValResult<Option<(&'data PyAny, &'data PyAny)>> {
+    let decimal = match input.validate_decimal(false, py) {
+        Ok(decimal) => decimal,
+        Err(ValError::LineErrors(_)) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    if !decimal.call_method0(intern!(py, "is_finite"))?.extract::<bool>()? {
+        return Ok(None);
+    }
+    let truncated = decimal.call_method0(intern!(py, "__int__"))?;
+    if decimal.eq(truncated)? {
+        return Ok(None);
+    }
+    Ok(Some((decimal, truncated)))
+}
+
+fn max_digits(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Option<u64>> {
+    let py = schema.py();
+    schema_or_config(schema, config, intern!(py, "max_digits"), intern!(py, "int_max_digits"))
+}
+
+/// Whether `int` has more than `max_digits` decimal digits, ignoring the sign
+fn exceeds_digits(int: &Int, max_digits: u64) -> bool {
+    match int {
+        Int::I64(i) => u64::from(i.unsigned_abs().checked_ilog10().unwrap_or(0)) + 1 > max_digits,
+        Int::Big(b) => {
+            // `bits * log10(2) + 1` is an upper bound on the number of digits, only when that's over the limit
+            // do we need the exact comparison, so `10**max_digits` is never much bigger than `int`
+            let digits_bound = (b.bits() as f64 * std::f64::consts::LOG10_2) as u64 + 1;
+            digits_bound > max_digits && b.magnitude() >= &BigUint::from(10u32).pow(max_digits as u32)
+        }
+    }
+}
+
+/// Whether the integer part of a string has more than `max_digits` decimal digits, ignoring leading zeros, signs,
+/// underscores and whitespace, strings which aren't valid ints are left for parsing to reject
+fn str_exceeds_digits(s: &str, max_digits: u64) -> bool {
+    let int_part = s.split('.').next().unwrap_or_default();
+    let digits = int_part
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .skip_while(|&b| b == b'0')
+        .count();
+    digits as u64 > max_digits
+}
+
+/// The range of a fixed width integer, set with `bit_width` and `signed`, or by `int64` and `uint64` schemas
+#[derive(Debug, Clone)]
+struct IntRange {
+    min: i128,
+    max: i128,
+}
+
+impl IntRange {
+    fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let signed: Option<bool> = schema.get_as(intern!(py, "signed"))?;
+        let Some(bit_width) = schema.get_as::<u32>(intern!(py, "bit_width"))? else {
+            return match signed {
+                Some(_) => py_schema_err!("signed can only be used with bit_width"),
+                None => Ok(None),
+            };
+        };
+        if !matches!(bit_width, 8 | 16 | 32 | 64) {
+            return py_schema_err!("bit_width must be 8, 16, 32 or 64, got {}", bit_width);
+        }
+        let range = if signed.unwrap_or(true) {
+            Self {
+                min: -(1 << (bit_width - 1)),
+                max: (1 << (bit_width - 1)) - 1,
+            }
+        } else {
+            Self {
+                min: 0,
+                max: (1 << bit_width) - 1,
+            }
+        };
+        Ok(Some(range))
+    }
+
+    fn contains(&self, int: &Int) -> bool {
+        let value = match int {
+            Int::I64(i) => i128::from(*i),
+            Int::Big(b) => match i128::try_from(b) {
+                Ok(i) => i,
+                Err(_) => return false,
+            },
+        };
+        self.contains_i128(value)
+    }
+
+    fn contains_i128(&self, value: i128) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    fn min_number(&self) -> Number {
+        Self::to_number(self.min)
+    }
+
+    fn max_number(&self) -> Number {
+        Self::to_number(self.max)
+    }
+
+    /// A bound or constraint of a fixed width integer as a `Number` for error context
+    fn to_number(value: i128) -> Number {
+        match i64::try_from(value) {
+            Ok(i) => Number::Int(i),
+            Err(_) => Number::BigInt(BigInt::from(value)),
+        }
+    }
+}
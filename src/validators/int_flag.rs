@@ -0,0 +1,241 @@
+use std::str::FromStr;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType, Int};
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// What to do with bits in the input which aren't covered by any member of the flag class
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnknownBits {
+    Error,
+    Drop,
+    Keep,
+}
+
+impl FromStr for UnknownBits {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "drop" => Ok(Self::Drop),
+            "keep" => Ok(Self::Keep),
+            s => py_schema_err!("Invalid unknown_bits: `{}`, expected `error`, `drop` or `keep`", s),
+        }
+    }
+}
+
+/// The members of an `enum.IntFlag` class, shared by the validator and serializer
+#[derive(Debug, Clone)]
+pub struct FlagMembers {
+    // all names including aliases, used to look up names in the input
+    by_name: AHashMap<String, u64>,
+    // single bit members in definition order, used to decompose values into names
+    canonical: Vec<(String, u64)>,
+    mask: u64,
+}
+
+impl FlagMembers {
+    pub fn from_class(class: &PyType) -> PyResult<Self> {
+        let py = class.py();
+        let mut by_name = AHashMap::new();
+        let mut canonical = Vec::new();
+        let mut mask = 0;
+        let members: &PyDict = class
+            .getattr(intern!(py, "__members__"))?
+            .call_method0(intern!(py, "copy"))?
+            .downcast()?;
+        for (name, member) in members {
+            let name: String = name.extract()?;
+            let Ok(value) = member.extract::<u64>() else {
+                return py_schema_err!("int-flag member `{}` must be a non-negative integer below 2**64", name);
+            };
+            // aliases appear in `__members__` under their own name but the member keeps its original name
+            let member_name: String = member.getattr(intern!(py, "name"))?.extract()?;
+            if value.is_power_of_two() && member_name == name {
+                canonical.push((name.clone(), value));
+            }
+            mask |= value;
+            by_name.insert(name, value);
+        }
+        Ok(Self {
+            by_name,
+            canonical,
+            mask,
+        })
+    }
+
+    pub fn unknown_bits(&self, value: u64) -> u64 {
+        value & !self.mask
+    }
+
+    /// Names of the single bit members set in `value`, `None` if it has bits not covered by those members
+    pub fn names(&self, value: u64) -> Option<Vec<&str>> {
+        let mut remaining = value;
+        let names = self
+            .canonical
+            .iter()
+            .filter(|(_, bit)| value & bit != 0)
+            .map(|(name, bit)| {
+                remaining &= !bit;
+                name.as_str()
+            })
+            .collect();
+        (remaining == 0).then_some(names)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IntFlagValidator {
+    strict: bool,
+    class: Py<PyType>,
+    members: FlagMembers,
+    unknown_bits: UnknownBits,
+    class_name: String,
+    name: String,
+}
+
+impl BuildValidator for IntFlagValidator {
+    const EXPECTED_TYPE: &'static str = "int-flag";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let unknown_bits = match schema.get_as::<&str>(intern!(py, "unknown_bits"))? {
+            Some(s) => UnknownBits::from_str(s)?,
+            None => UnknownBits::Error,
+        };
+        let class_name = class.name()?.to_string();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            class: class.into(),
+            members: FlagMembers::from_class(class)?,
+            unknown_bits,
+            name: format!("{}[{class_name}]", Self::EXPECTED_TYPE),
+            class_name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IntFlagValidator { class });
+
+impl Validator for IntFlagValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let class = self.class.as_ref(py);
+        let (value, instance) = if let Some(py_input) = input.input_is_instance(class) {
+            let value = py_input
+                .extract::<u64>()
+                .map_err(|_| ValError::new(self.type_error(), input))?;
+            (value, Some(py_input))
+        } else if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+            return Err(ValError::new(
+                ErrorType::IsInstanceOf {
+                    class: self.class_name.clone(),
+                    context: None,
+                },
+                input,
+            ));
+        } else {
+            if state.extra().input_type == InputType::Python {
+                state.floor_exactness(Exactness::Lax);
+            }
+            (self.input_value(py, input, state)?, None)
+        };
+
+        let value = match self.members.unknown_bits(value) {
+            0 => value,
+            unknown_bits => match self.unknown_bits {
+                UnknownBits::Error => {
+                    return Err(ValError::new(
+                        ErrorType::IntFlagUnknownBits {
+                            class: self.class_name.clone(),
+                            unknown_bits,
+                            context: None,
+                        },
+                        input,
+                    ))
+                }
+                UnknownBits::Drop => value & !unknown_bits,
+                UnknownBits::Keep => value,
+            },
+        };
+        match instance {
+            Some(py_input) if py_input.extract::<u64>().ok() == Some(value) => Ok(py_input.into_py(py)),
+            _ => Ok(class.call1((value,))?.into_py(py)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl IntFlagValidator {
+    /// An int, or a list of member names or members which are combined into a single value
+    fn input_value<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<u64> {
+        let strict = state.strict_or(self.strict);
+        if let Ok(either_int) = input.validate_int(strict) {
+            return match either_int.into_inner().as_int()? {
+                Int::I64(i) => u64::try_from(i).ok(),
+                Int::Big(b) => u64::try_from(b).ok(),
+            }
+            .ok_or_else(|| ValError::new(self.type_error(), input));
+        }
+        let Ok(names) = input.validate_list(strict) else {
+            return Err(ValError::new(self.type_error(), input));
+        };
+        let mut value = 0;
+        for (index, item) in names.as_sequence_iterator(py)?.enumerate() {
+            let item = item?;
+            let member = match item.extract::<&str>() {
+                Ok(name) => self.members.by_name.get(name).copied(),
+                Err(_) if item.is_instance(self.class.as_ref(py))? => item.extract().ok(),
+                Err(_) => None,
+            };
+            match member {
+                Some(member) => value |= member,
+                None => {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::IntFlagName {
+                            class: self.class_name.clone(),
+                            context: None,
+                        },
+                        item,
+                        index,
+                    ))
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    fn type_error(&self) -> ErrorType {
+        ErrorType::IntFlagType {
+            class: self.class_name.clone(),
+            context: None,
+        }
+    }
+}
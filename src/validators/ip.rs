@@ -0,0 +1,365 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType, Int};
+use crate::tools::SchemaDict;
+
+use super::config::ValBytesMode;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static IPV4_ADDRESS_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static IPV6_ADDRESS_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static IPV4_NETWORK_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static IPV6_NETWORK_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn get_ipaddress_type<'py>(py: Python<'py>, cell: &'py GILOnceCell<Py<PyType>>, attr: &str) -> PyResult<&'py PyType> {
+    Ok(cell
+        .get_or_try_init(py, || py.import("ipaddress")?.getattr(attr)?.extract())?
+        .as_ref(py))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<u8>(intern!(schema.py(), "version"))? {
+            Some(4) => Ok(Some(Self::V4)),
+            Some(6) => Ok(Some(Self::V6)),
+            Some(v) => py_schema_err!("Invalid IP version: {}, expected 4 or 6", v),
+            None => Ok(None),
+        }
+    }
+
+    /// The versions allowed when `version` is the constraint from the schema, `None` allowing either
+    fn allowed(version: Option<Self>) -> &'static [Self] {
+        match version {
+            Some(Self::V4) => &[Self::V4],
+            Some(Self::V6) => &[Self::V6],
+            None => &[Self::V4, Self::V6],
+        }
+    }
+
+    /// Used in error messages, e.g. "Input should be a valid IPv4 or IPv6 address"
+    fn describe(version: Option<Self>) -> &'static str {
+        match version {
+            Some(Self::V4) => "IPv4",
+            Some(Self::V6) => "IPv6",
+            None => "IPv4 or IPv6",
+        }
+    }
+
+    fn max_prefix(self) -> u8 {
+        match self {
+            Self::V4 => 32,
+            Self::V6 => 128,
+        }
+    }
+
+    fn address_type(self, py: Python<'_>) -> PyResult<&PyType> {
+        match self {
+            Self::V4 => get_ipaddress_type(py, &IPV4_ADDRESS_TYPE, "IPv4Address"),
+            Self::V6 => get_ipaddress_type(py, &IPV6_ADDRESS_TYPE, "IPv6Address"),
+        }
+    }
+
+    fn network_type(self, py: Python<'_>) -> PyResult<&PyType> {
+        match self {
+            Self::V4 => get_ipaddress_type(py, &IPV4_NETWORK_TYPE, "IPv4Network"),
+            Self::V6 => get_ipaddress_type(py, &IPV6_NETWORK_TYPE, "IPv6Network"),
+        }
+    }
+}
+
+fn version_of(addr: &IpAddr) -> IpVersion {
+    match addr {
+        IpAddr::V4(_) => IpVersion::V4,
+        IpAddr::V6(_) => IpVersion::V6,
+    }
+}
+
+/// Shared by the builders below, `ip-v4`, `ip-v6` and `ip-any` only differ in the versions they allow
+#[derive(Debug, Clone)]
+pub struct IpAddressValidator {
+    strict: bool,
+    version: Option<IpVersion>,
+    name: &'static str,
+}
+
+macro_rules! ip_address_builder {
+    ($builder:ident, $expected_type:literal, $version:expr) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &PyDict,
+                config: Option<&PyDict>,
+                _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                Ok(IpAddressValidator {
+                    strict: is_strict(schema, config)?,
+                    version: $version,
+                    name: Self::EXPECTED_TYPE,
+                }
+                .into())
+            }
+        }
+    };
+}
+
+ip_address_builder!(IpV4Builder, "ip-v4", Some(IpVersion::V4));
+ip_address_builder!(IpV6Builder, "ip-v6", Some(IpVersion::V6));
+ip_address_builder!(IpAnyBuilder, "ip-any", None);
+
+impl_py_gc_traverse!(IpAddressValidator {});
+
+impl Validator for IpAddressValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        for version in IpVersion::allowed(self.version) {
+            if let Some(py_input) = input.input_is_instance(version.address_type(py)?) {
+                return Ok(py_input.to_object(py));
+            }
+        }
+        if state.extra().input_type == InputType::Python {
+            if state.strict_or(self.strict) {
+                return Err(instance_error(py, self.version, IpVersion::address_type, input));
+            }
+            state.floor_exactness(Exactness::Lax);
+        }
+
+        let (addr, scope_id) = parse_address(input, self.version).ok_or_else(|| {
+            ValError::new(
+                ErrorType::IpAddressParsing {
+                    expected: IpVersion::describe(self.version).to_string(),
+                    context: None,
+                },
+                input,
+            )
+        })?;
+        let class = version_of(&addr).address_type(py)?;
+        let address = match (addr, scope_id) {
+            (IpAddr::V4(v4), _) => class.call1((u32::from(v4),))?,
+            (IpAddr::V6(v6), None) => class.call1((u128::from(v6),))?,
+            // scope IDs can only be set from the string form
+            (IpAddr::V6(v6), Some(scope_id)) => class.call1((format!("{v6}%{scope_id}"),))?,
+        };
+        Ok(address.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpNetworkValidator {
+    strict: bool,
+    version: Option<IpVersion>,
+    allow_host_bits: bool,
+}
+
+impl BuildValidator for IpNetworkValidator {
+    const EXPECTED_TYPE: &'static str = "ip-network";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            version: IpVersion::from_schema(schema)?,
+            allow_host_bits: schema.get_as(intern!(schema.py(), "allow_host_bits"))?.unwrap_or(false),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IpNetworkValidator {});
+
+impl Validator for IpNetworkValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        for version in IpVersion::allowed(self.version) {
+            if let Some(py_input) = input.input_is_instance(version.network_type(py)?) {
+                return Ok(py_input.to_object(py));
+            }
+        }
+        if state.extra().input_type == InputType::Python {
+            if state.strict_or(self.strict) {
+                return Err(instance_error(py, self.version, IpVersion::network_type, input));
+            }
+            state.floor_exactness(Exactness::Lax);
+        }
+
+        let network_error = |error: &str| {
+            ValError::new(
+                ErrorType::IpNetworkParsing {
+                    expected: IpVersion::describe(self.version).to_string(),
+                    error: error.to_string(),
+                    context: None,
+                },
+                input,
+            )
+        };
+        let (addr, prefix) = match input.exact_str() {
+            Ok(either_str) => {
+                let str = either_str.as_cow()?;
+                let (addr_str, prefix_str) = match str.split_once('/') {
+                    Some((addr_str, prefix_str)) => (addr_str, Some(prefix_str)),
+                    None => (str.as_ref(), None),
+                };
+                let addr = parse_address_str(addr_str, self.version)
+                    .filter(|(_, scope_id)| scope_id.is_none())
+                    .map(|(addr, _)| addr)
+                    .ok_or_else(|| network_error("invalid address"))?;
+                let prefix = match prefix_str {
+                    Some(prefix_str) => {
+                        parse_prefix(prefix_str, &addr).ok_or_else(|| network_error("invalid prefix"))?
+                    }
+                    None => version_of(&addr).max_prefix(),
+                };
+                (addr, prefix)
+            }
+            Err(_) => {
+                let addr = parse_address(input, self.version)
+                    .map(|(addr, _)| addr)
+                    .ok_or_else(|| network_error("invalid address"))?;
+                (addr, version_of(&addr).max_prefix())
+            }
+        };
+
+        let network = match addr {
+            IpAddr::V4(v4) => {
+                let int = u32::from(v4);
+                let masked = int & u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+                if masked != int && !self.allow_host_bits {
+                    return Err(network_error("host bits set"));
+                }
+                IpVersion::V4.network_type(py)?.call1(((masked, prefix),))?
+            }
+            IpAddr::V6(v6) => {
+                let int = u128::from(v6);
+                let masked = int & u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+                if masked != int && !self.allow_host_bits {
+                    return Err(network_error("host bits set"));
+                }
+                IpVersion::V6.network_type(py)?.call1(((masked, prefix),))?
+            }
+        };
+        Ok(network.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+fn instance_error<'data>(
+    py: Python,
+    version: Option<IpVersion>,
+    get_type: fn(IpVersion, Python) -> PyResult<&PyType>,
+    input: &'data impl Input<'data>,
+) -> ValError {
+    let class = IpVersion::allowed(version)
+        .iter()
+        .map(|v| get_type(*v, py).and_then(|t| Ok(t.name()?.to_string())))
+        .collect::<PyResult<Vec<_>>>();
+    match class {
+        Ok(class) => ValError::new(
+            ErrorType::IsInstanceOf {
+                class: class.join(" | "),
+                context: None,
+            },
+            input,
+        ),
+        Err(err) => err.into(),
+    }
+}
+
+/// Parse an address from a string, an integer or packed bytes, like `ipaddress.ip_address`,
+/// an IPv6 scope ID is returned separately
+fn parse_address<'data>(
+    input: &'data impl Input<'data>,
+    version: Option<IpVersion>,
+) -> Option<(IpAddr, Option<String>)> {
+    if let Ok(either_str) = input.exact_str() {
+        let str = either_str.as_cow().ok()?;
+        parse_address_str(&str, version).map(|(addr, scope_id)| (addr, scope_id.map(ToString::to_string)))
+    } else if let Ok(either_int) = input.exact_int() {
+        let int = match either_int.as_int().ok()? {
+            Int::I64(i) => u128::try_from(i).ok()?,
+            Int::Big(big) => u128::try_from(&big).ok()?,
+        };
+        let addr = match (version, u32::try_from(int)) {
+            (Some(IpVersion::V4) | None, Ok(v4)) => IpAddr::V4(Ipv4Addr::from(v4)),
+            (Some(IpVersion::V6) | None, _) => IpAddr::V6(Ipv6Addr::from(int)),
+            (Some(IpVersion::V4), Err(_)) => return None,
+        };
+        Some((addr, None))
+    } else {
+        let either_bytes = input.validate_bytes(true, ValBytesMode::Utf8).ok()?.into_inner();
+        let bytes = either_bytes.as_slice();
+        let addr = match (version, bytes.len()) {
+            (Some(IpVersion::V4) | None, 4) => IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(bytes.as_ref()).ok()?)),
+            (Some(IpVersion::V6) | None, 16) => IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(bytes.as_ref()).ok()?)),
+            _ => return None,
+        };
+        Some((addr, None))
+    }
+}
+
+fn parse_address_str(str: &str, version: Option<IpVersion>) -> Option<(IpAddr, Option<&str>)> {
+    let (addr_str, scope_id) = match str.split_once('%') {
+        Some((_, "")) => return None,
+        Some((addr_str, scope_id)) if !scope_id.contains(['%', '/']) => (addr_str, Some(scope_id)),
+        Some(_) => return None,
+        None => (str, None),
+    };
+    let addr = match version {
+        Some(IpVersion::V4) => IpAddr::V4(addr_str.parse().ok()?),
+        Some(IpVersion::V6) => IpAddr::V6(addr_str.parse().ok()?),
+        None => addr_str.parse().ok()?,
+    };
+    match (addr, scope_id) {
+        (IpAddr::V4(_), Some(_)) => None,
+        _ => Some((addr, scope_id)),
+    }
+}
+
+/// Parse a prefix length, or for IPv4 a netmask like `255.255.255.0`
+fn parse_prefix(str: &str, addr: &IpAddr) -> Option<u8> {
+    let max_prefix = version_of(addr).max_prefix();
+    if !str.is_empty() && str.bytes().all(|b| b.is_ascii_digit()) {
+        return str.parse().ok().filter(|prefix| *prefix <= max_prefix);
+    }
+    match addr {
+        IpAddr::V4(_) => {
+            let mask = u32::from(str.parse::<Ipv4Addr>().ok()?);
+            // only contiguous netmasks have an equivalent prefix length
+            let prefix = mask.leading_ones();
+            (mask.checked_shl(prefix).unwrap_or(0) == 0).then_some(prefix as u8)
+        }
+        IpAddr::V6(_) => None,
+    }
+}
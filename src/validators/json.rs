@@ -1,18 +1,27 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use jiter::JsonValue;
+use jiter::{Jiter, JiterResult, JsonValue, LazyIndexMap, Peak};
 
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
-use crate::input::{EitherBytes, Input, ValidationMatch};
+use crate::errors::{ErrorType, ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
+use crate::input::{EitherBytes, Input, JsonStack, ValidationMatch};
 use crate::tools::SchemaDict;
 
+use super::config::ValBytesMode;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+/// Below this size parsing is quick enough that releasing and reacquiring the GIL would cost more than it saves
+const ALLOW_THREADS_MIN_LEN: usize = 16 * 1024;
+
 #[derive(Debug)]
 pub struct JsonValidator {
     validator: Option<Box<CombinedValidator>>,
+    max_length: Option<usize>,
+    max_depth: Option<usize>,
     name: String,
 }
 
@@ -24,7 +33,8 @@ impl BuildValidator for JsonValidator {
         config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        let validator = match schema.get_as(intern!(schema.py(), "schema"))? {
+        let py = schema.py();
+        let validator = match schema.get_as(intern!(py, "schema"))? {
             Some(schema) => {
                 let validator = build_validator(schema, config, definitions)?;
                 match validator {
@@ -39,7 +49,13 @@ impl BuildValidator for JsonValidator {
             Self::EXPECTED_TYPE,
             validator.as_ref().map_or("any", |v| v.get_name())
         );
-        Ok(Self { validator, name }.into())
+        Ok(Self {
+            validator,
+            max_length: schema.get_as(intern!(py, "max_length"))?,
+            max_depth: schema.get_as(intern!(py, "max_depth"))?,
+            name,
+        }
+        .into())
     }
 }
 
@@ -55,17 +71,41 @@ impl Validator for JsonValidator {
         let v_match = validate_json_bytes(input)?;
         let json_either_bytes = v_match.unpack(state);
         let json_bytes = json_either_bytes.as_slice();
-        match self.validator {
-            Some(ref validator) => {
-                let json_value = JsonValue::parse(json_bytes, true).map_err(|e| map_json_err(input, e, json_bytes))?;
-                validator.validate(py, &json_value, state)
-            }
-            None => {
-                let obj =
-                    jiter::python_parse(py, json_bytes, true, true).map_err(|e| map_json_err(input, e, json_bytes))?;
-                Ok(obj)
+        let json_bytes = json_bytes.as_ref();
+        if let Some(max_length) = self.max_length {
+            if json_bytes.len() > max_length {
+                return Err(ValError::new(
+                    ErrorType::JsonTooLong {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
             }
         }
+        let json_value = match self.max_depth {
+            Some(max_depth) => parse_json_checked(py, json_bytes, Some(max_depth)).map_err(|stop| {
+                let error_type = match stop.json_error {
+                    Some(error) => ErrorType::JsonInvalid { error, context: None },
+                    None => ErrorType::JsonTooDeep {
+                        max_depth,
+                        context: None,
+                    },
+                };
+                nested_error(error_type, input, stop.path)
+            })?,
+            None => match self.validator {
+                Some(_) => parse_json(py, json_bytes).map_err(|e| map_nested_json_err(py, input, e, json_bytes))?,
+                None => {
+                    return jiter::python_parse(py, json_bytes, true, true)
+                        .map_err(|e| map_nested_json_err(py, input, e, json_bytes));
+                }
+            },
+        };
+        match self.validator {
+            Some(ref validator) => validator.validate(py, &json_value, state),
+            None => Ok(json_value.to_object(py)),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -74,7 +114,7 @@ impl Validator for JsonValidator {
 }
 
 pub fn validate_json_bytes<'data>(input: &'data impl Input<'data>) -> ValResult<ValidationMatch<EitherBytes<'data>>> {
-    match input.validate_bytes(false) {
+    match input.validate_bytes(false, ValBytesMode::Utf8) {
         Ok(v_match) => Ok(v_match),
         Err(ValError::LineErrors(e)) => Err(ValError::LineErrors(
             e.into_iter().map(map_bytes_error).collect::<Vec<_>>(),
@@ -92,6 +132,19 @@ fn map_bytes_error(line_error: ValLineError) -> ValLineError {
     }
 }
 
+/// Parse JSON into a `JsonValue`, this touches no Python objects so the GIL is released for large inputs
+/// to let other threads make progress in the meantime.
+///
+/// `json_bytes` must not be mutated while the GIL is released, which holds since it's always borrowed from
+/// an immutable `bytes` or `str` object, or owned (e.g. when copied from a `bytearray`).
+pub fn parse_json(py: Python, json_bytes: &[u8]) -> Result<JsonValue, jiter::JsonError> {
+    if json_bytes.len() < ALLOW_THREADS_MIN_LEN {
+        JsonValue::parse(json_bytes, true)
+    } else {
+        py.allow_threads(|| JsonValue::parse(json_bytes, true))
+    }
+}
+
 pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: jiter::JsonError, json_bytes: &[u8]) -> ValError {
     ValError::new(
         ErrorType::JsonInvalid {
@@ -101,3 +154,219 @@ pub fn map_json_err<'a>(input: &'a impl Input<'a>, error: jiter::JsonError, json
         input,
     )
 }
+
+/// Like `map_json_err`, but the error is located at the value inside the document where parsing failed
+fn map_nested_json_err<'a>(
+    py: Python,
+    input: &'a impl Input<'a>,
+    error: jiter::JsonError,
+    json_bytes: &[u8],
+) -> ValError {
+    let error_type = ErrorType::JsonInvalid {
+        error: error.description(json_bytes),
+        context: None,
+    };
+    // the document is only parsed again to find the location once we know it's invalid
+    let path = parse_json_checked(py, json_bytes, None)
+        .err()
+        .map(|stop| stop.path)
+        .unwrap_or_default();
+    nested_error(error_type, input, path)
+}
+
+fn nested_error<'a>(error_type: ErrorType, input: &'a impl Input<'a>, path: Vec<LocItem>) -> ValError {
+    let line_error = path
+        .into_iter()
+        .rev()
+        .fold(ValLineError::new(error_type, input), ValLineError::with_outer_location);
+    ValError::LineErrors(vec![line_error])
+}
+
+/// Where `parse_json_checked` stopped
+struct JsonStop {
+    /// location of the value being parsed, an int for an array item or a string for the value of an object key
+    path: Vec<LocItem>,
+    /// `None` if the document is nested deeper than `max_depth`, otherwise the description of the JSON error
+    json_error: Option<String>,
+}
+
+/// Like `parse_json`, but stops at the first array or object nested deeper than `max_depth`, and reports the
+/// location of the value where parsing stopped.
+fn parse_json_checked(py: Python, json_bytes: &[u8], max_depth: Option<usize>) -> Result<JsonValue, JsonStop> {
+    let parse = || {
+        let mut jiter = Jiter::new(json_bytes, true);
+        let mut stack = JsonStack::default();
+        build_json(&mut jiter, &mut stack, max_depth).map_err(|json_error| JsonStop {
+            path: stack.into_path(),
+            json_error,
+        })
+    };
+    if json_bytes.len() < ALLOW_THREADS_MIN_LEN {
+        parse()
+    } else {
+        py.allow_threads(parse)
+    }
+}
+
+/// An array or object whose items are being parsed
+enum PartialJson {
+    Array(Vec<JsonValue>),
+    Object(Box<LazyIndexMap<String, JsonValue>>),
+}
+
+/// Build the `JsonValue` for the document, `stack` holds the arrays and objects enclosing the current value so
+/// arbitrarily deep documents can be parsed, and it's left where parsing stopped on error.
+///
+/// Errors with the description of the JSON error, or `None` if the document is nested deeper than `max_depth`.
+fn build_json(
+    jiter: &mut Jiter,
+    stack: &mut JsonStack<PartialJson>,
+    max_depth: Option<usize>,
+) -> Result<JsonValue, Option<String>> {
+    macro_rules! check {
+        ($result:expr) => {
+            match $result {
+                Ok(value) => value,
+                Err(err) => return Err(Some(err.description(jiter))),
+            }
+        };
+    }
+
+    let mut peak = check!(jiter.peak());
+    loop {
+        // start the value, moving into the first item of arrays and objects which aren't empty
+        let mut value = match peak {
+            Peak::Array | Peak::Object if max_depth.map_or(false, |max_depth| stack.depth() >= max_depth) => {
+                return Err(None);
+            }
+            Peak::Array => match check!(jiter.known_array()) {
+                Some(first) => {
+                    stack.push_array(PartialJson::Array(Vec::new()));
+                    peak = first;
+                    continue;
+                }
+                None => JsonValue::Array(Arc::default()),
+            },
+            Peak::Object => match check!(jiter.known_object().map(|op_key| op_key.map(ToOwned::to_owned))) {
+                Some(key) => {
+                    stack.push_object(key, PartialJson::Object(Box::new(LazyIndexMap::new())));
+                    peak = check!(jiter.peak());
+                    continue;
+                }
+                None => JsonValue::Object(Arc::new(LazyIndexMap::new())),
+            },
+            scalar => check!(jiter.known_value(scalar)),
+        };
+        // the value is complete, add it to the innermost container and move to its next item, or complete the
+        // container if there are no more
+        loop {
+            match stack.last_mut() {
+                None => {
+                    check!(jiter.finish());
+                    return Ok(value);
+                }
+                Some((LocItem::I(index), PartialJson::Array(items))) => {
+                    items.push(value);
+                    *index += 1;
+                    if let Some(next) = check!(jiter.array_step()) {
+                        peak = next;
+                        break;
+                    }
+                }
+                Some((LocItem::S(key), PartialJson::Object(object))) => {
+                    object.insert(key.clone(), value);
+                    if let Some(next_key) = check!(jiter.next_key().map(|op_key| op_key.map(ToOwned::to_owned))) {
+                        *key = next_key;
+                        peak = check!(jiter.peak());
+                        break;
+                    }
+                }
+                Some(_) => return Err(Some("unexpected JSON parser state".to_string())),
+            }
+            value = match stack.pop() {
+                Some(PartialJson::Array(items)) => JsonValue::Array(Arc::new(items.into_iter().collect())),
+                Some(PartialJson::Object(object)) => JsonValue::Object(Arc::new(*object)),
+                None => return Err(Some("unexpected JSON parser state".to_string())),
+            };
+        }
+    }
+}
+
+/// Wraps `Jiter` for validators which validate JSON as it's parsed rather than parsing it to a `JsonValue`
+/// first, see `Validator::validate_json_direct`.
+///
+/// JSON errors are returned as an internal error so they abort validation, while the description is kept
+/// so the caller can report it as a `json_invalid` error, the same as when the JSON is parsed up front.
+pub struct JsonDirect<'j> {
+    jiter: Jiter<'j>,
+    json_error: Option<String>,
+}
+
+impl<'j> JsonDirect<'j> {
+    pub fn new(json_bytes: &'j [u8]) -> Self {
+        Self {
+            jiter: Jiter::new(json_bytes, true),
+            json_error: None,
+        }
+    }
+
+    /// The description of the JSON error which aborted validation, if any
+    pub fn json_error(&mut self) -> Option<String> {
+        self.json_error.take()
+    }
+
+    pub fn peak(&mut self) -> ValResult<Peak> {
+        let result = self.jiter.peak();
+        self.check(result)
+    }
+
+    /// Parse the whole value starting with `peak`
+    pub fn value(&mut self, peak: Peak) -> ValResult<JsonValue> {
+        let result = self.jiter.known_value(peak);
+        self.check(result)
+    }
+
+    pub fn null(&mut self) -> ValResult<()> {
+        let result = self.jiter.known_null();
+        self.check(result)
+    }
+
+    /// Start an array, returning the peak of the first item, `None` if the array is empty
+    pub fn array_first(&mut self) -> ValResult<Option<Peak>> {
+        let result = self.jiter.known_array();
+        self.check(result)
+    }
+
+    /// The peak of the next item in an array, `None` at the end of the array
+    pub fn array_step(&mut self) -> ValResult<Option<Peak>> {
+        let result = self.jiter.array_step();
+        self.check(result)
+    }
+
+    /// Start an object, returning the first key, `None` if the object is empty
+    pub fn object_first(&mut self) -> ValResult<Option<String>> {
+        let result = self.jiter.known_object().map(|op_key| op_key.map(ToOwned::to_owned));
+        self.check(result)
+    }
+
+    /// The next key in an object, `None` at the end of the object
+    pub fn object_step(&mut self) -> ValResult<Option<String>> {
+        let result = self.jiter.next_key().map(|op_key| op_key.map(ToOwned::to_owned));
+        self.check(result)
+    }
+
+    /// Check there's nothing but whitespace after the value
+    pub fn finish(&mut self) -> ValResult<()> {
+        let result = self.jiter.finish();
+        self.check(result)
+    }
+
+    fn check<T>(&mut self, result: JiterResult<T>) -> ValResult<T> {
+        result.map_err(|e| {
+            let description = e.description(&self.jiter);
+            let err = ValError::InternalErr(PyValueError::new_err(description.clone()));
+            self.json_error = Some(description);
+            err
+        })
+    }
+}
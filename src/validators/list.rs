@@ -1,14 +1,22 @@
 use std::sync::OnceLock;
 
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PySet};
 
-use crate::errors::ValResult;
+use jiter::Peak;
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ValError, ValLineError, ValResult};
 use crate::input::{GenericIterable, Input};
 use crate::tools::SchemaDict;
 use crate::validators::Exactness;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, JsonDirect, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct ListValidator {
@@ -16,9 +24,70 @@ pub struct ListValidator {
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items: bool,
+    deterministic: bool,
+    output_type: ListOutputType,
     name: OnceLock<String>,
 }
 
+static COLLECTIONS_DEQUE: GILOnceCell<PyObject> = GILOnceCell::new();
+
+pub(crate) fn get_deque(py: Python<'_>) -> PyResult<&PyAny> {
+    let deque = COLLECTIONS_DEQUE.get_or_try_init(py, || {
+        Ok::<_, PyErr>(
+            py.import(intern!(py, "collections"))?
+                .getattr(intern!(py, "deque"))?
+                .into_py(py),
+        )
+    })?;
+    Ok(deque.as_ref(py))
+}
+
+/// The type built from the validated items, from `output_type`
+#[derive(Debug)]
+enum ListOutputType {
+    List,
+    Deque { maxlen: Option<usize> },
+    // the type of the input if it's a subclass of `list` or a `deque`, otherwise a `list`
+    Input,
+}
+
+impl ListOutputType {
+    fn from_schema(schema: &PyDict) -> PyResult<Self> {
+        let py = schema.py();
+        let maxlen: Option<usize> = schema.get_as(intern!(py, "deque_maxlen"))?;
+        let output_type = match schema.get_as::<&str>(intern!(py, "output_type"))? {
+            Some("list") | None => Self::List,
+            Some("deque") => Self::Deque { maxlen },
+            Some("input") => Self::Input,
+            // schema validation means other values are impossible
+            _ => unreachable!(),
+        };
+        if maxlen.is_some() && !matches!(output_type, Self::Deque { .. }) {
+            return py_schema_err!("'deque_maxlen' requires `output_type = 'deque'`");
+        }
+        Ok(output_type)
+    }
+
+    /// `input` is the python object being validated, `None` when validating JSON
+    fn build(&self, py: Python, input: Option<&PyAny>, items: Vec<PyObject>) -> PyResult<PyObject> {
+        match self {
+            Self::List => Ok(items.into_py(py)),
+            Self::Deque { maxlen } => Ok(get_deque(py)?.call1((items, *maxlen))?.into_py(py)),
+            Self::Input => match input {
+                Some(input) if input.is_instance(get_deque(py)?)? => {
+                    let maxlen = input.getattr(intern!(py, "maxlen"))?;
+                    Ok(input.get_type().call1((items, maxlen))?.into_py(py))
+                }
+                Some(input) if input.is_instance_of::<PyList>() && !input.is_exact_instance_of::<PyList>() => {
+                    Ok(input.get_type().call1((items,))?.into_py(py))
+                }
+                _ => Ok(items.into_py(py)),
+            },
+        }
+    }
+}
+
 pub fn get_items_schema(
     schema: &PyDict,
     config: Option<&PyDict>,
@@ -92,6 +161,59 @@ macro_rules! min_length_check {
 }
 pub(crate) use min_length_check;
 
+/// Check validated items are unique, using hashing where possible and falling back to comparison by equality
+/// for unhashable items, which are compared with every other item, the error location is the index of the first
+/// duplicate, errors raised by `__hash__` (other than `TypeError`) or `__eq__` are propagated
+pub(crate) fn check_unique_items<'data>(
+    py: Python<'data>,
+    items: &[PyObject],
+    input: &'data impl Input<'data>,
+    field_type: &str,
+) -> ValResult<()> {
+    let seen = PySet::empty(py)?;
+    let mut unhashable: Vec<&PyAny> = Vec::new();
+    for (index, item) in items.iter().enumerate() {
+        let item = item.as_ref(py);
+        let duplicate = match item.hash() {
+            Ok(_) => {
+                if seen.contains(item)? {
+                    true
+                } else {
+                    seen.add(item)?;
+                    any_eq(item, unhashable.iter().copied())?
+                }
+            }
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                let previous = items[..index].iter().map(|other| other.as_ref(py));
+                let duplicate = any_eq(item, previous)?;
+                unhashable.push(item);
+                duplicate
+            }
+            Err(err) => return Err(err.into()),
+        };
+        if duplicate {
+            return Err(ValError::new_with_loc(
+                ErrorType::DuplicateItem {
+                    field_type: field_type.to_string(),
+                    context: None,
+                },
+                input,
+                index,
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn any_eq<'a>(item: &PyAny, others: impl IntoIterator<Item = &'a PyAny>) -> PyResult<bool> {
+    for other in others {
+        if other.eq(item)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
 
@@ -107,6 +229,9 @@ impl BuildValidator for ListValidator {
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
+            unique_items: schema.get_as(pyo3::intern!(py, "unique_items"))?.unwrap_or(false),
+            deterministic: crate::build_tools::is_deterministic(config)?,
+            output_type: ListOutputType::from_schema(schema)?,
             name: OnceLock::new(),
         }
         .into())
@@ -129,11 +254,18 @@ impl Validator for ListValidator {
             _ => Exactness::Lax,
         };
         state.floor_exactness(exactness);
+        let seq = if self.deterministic {
+            seq.into_deterministic()?
+        } else {
+            seq
+        };
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(py, input, self.max_length, "List", v, state)?,
             None => match seq {
-                GenericIterable::List(list) => {
+                GenericIterable::List(list)
+                    if !self.unique_items && matches!(self.output_type, ListOutputType::List) =>
+                {
                     length_check!(input, "List", self.min_length, self.max_length, list);
                     let list_copy = list.get_slice(0, usize::MAX);
                     return Ok(list_copy.into_py(py));
@@ -142,7 +274,62 @@ impl Validator for ListValidator {
             },
         };
         min_length_check!(input, "List", self.min_length, output);
-        Ok(output.into_py(py))
+        if self.unique_items {
+            check_unique_items(py, &output, input, "List")?;
+        }
+        let input_object = match self.output_type {
+            ListOutputType::Input if input.is_python() => Some(input.to_object(py).into_ref(py)),
+            _ => None,
+        };
+        Ok(self.output_type.build(py, input_object, output)?)
+    }
+
+    fn validate_json_direct(
+        &self,
+        py: Python<'_>,
+        json: &mut JsonDirect,
+        peak: Peak,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let item_validator = match self.item_validator {
+            // length and uniqueness errors include the whole list as their input, so it has to be parsed first
+            Some(ref v)
+                if peak == Peak::Array
+                    && self.min_length.is_none()
+                    && self.max_length.is_none()
+                    && !self.unique_items =>
+            {
+                v
+            }
+            _ => {
+                let value = json.value(peak)?;
+                return self.validate(py, &value, state);
+            }
+        };
+
+        let mut output: Vec<PyObject> = Vec::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut op_peak = json.array_first()?;
+        let mut index: usize = 0;
+        while let Some(peak) = op_peak {
+            state.check_signals(py)?;
+            match item_validator.validate_json_direct(py, json, peak, state) {
+                Ok(item) => output.push(item),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(ValError::Omit) => (),
+                Err(err) => return Err(err),
+            }
+            op_peak = json.array_step()?;
+            index += 1;
+        }
+
+        if errors.is_empty() {
+            Ok(self.output_type.build(py, None, output)?)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -216,6 +216,60 @@ impl Validator for LiteralValidator {
     }
 }
 
+/// Validator for a single constant value, cheaper to build and check than a `literal` with one item
+#[derive(Debug, Clone)]
+pub struct ConstValidator {
+    lookup: LiteralLookup<PyObject>,
+    expected_repr: String,
+    name: String,
+}
+
+impl BuildValidator for ConstValidator {
+    const EXPECTED_TYPE: &'static str = "const";
+
+    fn build(
+        schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let expected: &PyAny = schema.get_as_req(intern!(py, "expected"))?;
+        let expected_repr: String = expected.repr()?.extract()?;
+        let lookup = LiteralLookup::new(py, std::iter::once((expected, expected.to_object(py))))?;
+        Ok(CombinedValidator::Const(Self {
+            lookup,
+            name: format!("{}[{expected_repr}]", Self::EXPECTED_TYPE),
+            expected_repr,
+        }))
+    }
+}
+
+impl_py_gc_traverse!(ConstValidator { lookup });
+
+impl Validator for ConstValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        _state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        match self.lookup.validate(py, input)? {
+            Some((_, v)) => Ok(v.clone_ref(py)),
+            None => Err(ValError::new(
+                ErrorType::LiteralError {
+                    expected: self.expected_repr.clone(),
+                    context: None,
+                },
+                input,
+            )),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
 pub fn expected_repr_name(mut repr_args: Vec<String>, base_name: &'static str) -> (String, String) {
     let name = format!("{base_name}[{}]", repr_args.join(","));
     // unwrap is okay since we check the length in build at the top of this file
@@ -1,20 +1,27 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
 
 use enum_dispatch::enum_dispatch;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyAttributeError, PyTypeError, PyValueError};
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyTuple, PyType};
+use pyo3::types::{PyAny, PyByteArray, PyBytes, PyDict, PyList, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
+use jiter::Peak;
+
 use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
+use crate::coverage::CoverageStats;
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::errors::{ErrorType, LocItem, ValError, ValResult, ValidationError};
+use crate::input::{json_spans, Input, InputType, StringMapping};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
+use crate::trace::TraceSpan;
 
 mod any;
 mod arguments;
@@ -23,6 +30,7 @@ mod bytes;
 mod call;
 mod callable;
 mod chain;
+pub(crate) mod config;
 mod custom_error;
 mod dataclass;
 mod date;
@@ -30,11 +38,15 @@ mod datetime;
 pub(crate) mod decimal;
 mod definitions;
 mod dict;
+mod email;
+mod enum_;
 mod float;
 mod frozenset;
 mod function;
 mod generator;
 mod int;
+mod int_flag;
+mod ip;
 mod is_instance;
 mod is_subclass;
 mod json;
@@ -43,10 +55,14 @@ mod lax_or_strict;
 mod list;
 mod literal;
 mod model;
+mod model_constraint;
 mod model_fields;
 mod none;
 mod nullable;
+mod required_if;
+mod secret;
 mod set;
+mod strict_overrides;
 mod string;
 mod time;
 mod timedelta;
@@ -58,6 +74,10 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
+pub use self::int_flag::FlagMembers;
+use self::json::JsonDirect;
+pub(crate) use self::list::get_deque;
+use self::strict_overrides::StrictOverrides;
 pub use self::validation_state::{Exactness, ValidationState};
 pub use with_default::DefaultType;
 
@@ -109,15 +129,44 @@ pub struct SchemaValidator {
     title: PyObject,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    error_message_translator: Option<PyObject>,
+    coverage_stats: Option<Arc<CoverageStats>>,
+    reuse_instances: bool,
+    recursion_limit: Option<u16>,
+}
+
+/// A core schema given as JSON in a `str`, `bytes` or `bytearray` is parsed into Python objects, anything else
+/// is returned unchanged
+fn schema_from_json<'py>(py: Python<'py>, schema: &'py PyAny) -> PyResult<&'py PyAny> {
+    let json_bytes: Cow<[u8]> = if let Ok(py_str) = schema.downcast::<PyString>() {
+        Cow::Borrowed(py_str.to_str()?.as_bytes())
+    } else if let Ok(py_bytes) = schema.downcast::<PyBytes>() {
+        Cow::Borrowed(py_bytes.as_bytes())
+    } else if let Ok(py_byte_array) = schema.downcast::<PyByteArray>() {
+        Cow::Owned(py_byte_array.to_vec())
+    } else {
+        return Ok(schema);
+    };
+    match jiter::python_parse(py, &json_bytes, false, true) {
+        Ok(schema) => Ok(schema.into_ref(py)),
+        Err(e) => py_schema_err!("Invalid JSON core schema: {}", e.description(&json_bytes)),
+    }
 }
 
 #[pymethods]
 impl SchemaValidator {
     #[new]
     pub fn py_new(py: Python, schema: &PyAny, config: Option<&PyDict>) -> PyResult<Self> {
+        let _span = TraceSpan::enter("SchemaValidator.__init__", None);
         let mut definitions_builder = DefinitionsBuilder::new();
+        let coverage_stats = match config.get_as(intern!(py, "coverage_stats"))? {
+            Some(true) => Some(definitions_builder.enable_coverage_stats()),
+            _ => None,
+        };
 
+        let schema = schema_from_json(py, schema)?;
         let validator = build_validator(schema, config, &mut definitions_builder)?;
+        definitions_builder.set_root_required_refs(definitions::required_refs(schema, config)?);
         let definitions = definitions_builder.finish()?;
         let py_schema = schema.into_py(py);
         let py_config = match config {
@@ -134,6 +183,29 @@ impl SchemaValidator {
         };
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         let validation_error_cause: bool = config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false);
+        // `custom_error_messages` is the dict form of `error_message_translator`, applied when errors are rendered
+        let error_message_translator: Option<PyObject> = match (
+            config.get_as::<&PyDict>(intern!(py, "custom_error_messages"))?,
+            config.get_as(intern!(py, "error_message_translator"))?,
+        ) {
+            (Some(_), Some(_)) => {
+                return py_schema_err!("`custom_error_messages` and `error_message_translator` can't both be set")
+            }
+            (Some(messages), None) => {
+                for (error_type, message_template) in messages {
+                    error_type.extract::<&str>()?;
+                    message_template.extract::<&str>()?;
+                }
+                Some(messages.copy()?.into_py(py))
+            }
+            (None, translator) => translator,
+        };
+        let reuse_instances = match config.get_as::<&str>(intern!(py, "val_circular_ref"))? {
+            None | Some("error") => false,
+            Some("reuse") => true,
+            Some(s) => return py_schema_err!("Invalid val_circular_ref: `{}`, expected `error` or `reuse`", s),
+        };
+        let recursion_limit = config.get_as(intern!(py, "recursion_limit"))?;
         Ok(Self {
             validator,
             definitions,
@@ -142,6 +214,10 @@ impl SchemaValidator {
             title,
             hide_input_in_errors,
             validation_error_cause,
+            error_message_translator,
+            coverage_stats,
+            reuse_instances,
+            recursion_limit,
         })
     }
 
@@ -153,7 +229,10 @@ impl SchemaValidator {
         Ok((cls, init_args))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, timeout=None,
+        coercion_report=None, strict_fields=None, lax_fields=None, identity_map=None, union_choice_callback=None,
+        by_alias=None, by_name=None))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -162,17 +241,32 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        timeout: Option<f64>,
+        coercion_report: Option<&PyList>,
+        strict_fields: Option<&PyAny>,
+        lax_fields: Option<&PyAny>,
+        identity_map: Option<&PyDict>,
+        union_choice_callback: Option<&PyAny>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
     ) -> PyResult<PyObject> {
-        self._validate(
-            py,
-            input,
-            InputType::Python,
+        let _span = TraceSpan::enter("SchemaValidator.validate_python", Some(self.validator.get_name()));
+        check_lookup_type(by_alias, by_name)?;
+        let options = ValidateOptions {
             strict,
+            strict_overrides: StrictOverrides::build(strict_fields, lax_fields)?,
             from_attributes,
             context,
             self_instance,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            timeout: timeout_duration(timeout)?,
+            coercion_report,
+            identity_map,
+            union_choice_callback,
+            by_alias,
+            by_name,
+        };
+        self._validate(py, input, InputType::Python, options)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
     #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
@@ -185,24 +279,26 @@ impl SchemaValidator {
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match self._validate(
-            py,
-            input,
-            InputType::Python,
+        let options = ValidateOptions {
             strict,
             from_attributes,
             context,
             self_instance,
-        ) {
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, input, InputType::Python, options) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
             Err(ValError::Omit) => Err(ValidationError::omit_error()),
             Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
-            Err(ValError::LineErrors(_)) => Ok(false),
+            Err(ValError::LineErrors(_) | ValError::Timeout(_)) => Ok(false),
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, timeout=None, coercion_report=None,
+        strict_fields=None, lax_fields=None, union_choice_callback=None, source_spans=None, by_alias=None,
+        by_name=None))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -210,33 +306,103 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        timeout: Option<f64>,
+        coercion_report: Option<&PyList>,
+        strict_fields: Option<&PyAny>,
+        lax_fields: Option<&PyAny>,
+        union_choice_callback: Option<&PyAny>,
+        source_spans: Option<&PyDict>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
     ) -> PyResult<PyObject> {
+        let _span = TraceSpan::enter("SchemaValidator.validate_json", Some(self.validator.get_name()));
+        check_lookup_type(by_alias, by_name)?;
+        let options = ValidateOptions {
+            strict,
+            strict_overrides: StrictOverrides::build(strict_fields, lax_fields)?,
+            context,
+            self_instance,
+            timeout: timeout_duration(timeout)?,
+            coercion_report,
+            union_choice_callback,
+            by_alias,
+            by_name,
+            ..ValidateOptions::default()
+        };
         let r = match json::validate_json_bytes(input) {
-            Ok(v_match) => self._validate_json(
-                py,
-                input,
-                v_match.into_inner().as_slice(),
-                strict,
-                context,
-                self_instance,
-            ),
+            Ok(v_match) => self._validate_json(py, input, &v_match.into_inner().as_slice(), options, source_spans),
             Err(err) => Err(err),
         };
         r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
     #[pyo3(signature = (input, *, strict=None, context=None))]
+    pub fn validate_json_lines(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let either_bytes = json::validate_json_bytes(input)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?
+            .into_inner();
+        let results = PyList::empty(py);
+        let errors = PyList::empty(py);
+        for (index, line) in either_bytes.as_slice().as_ref().split(|b| *b == b'\n').enumerate() {
+            if line.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let r = match json::parse_json(py, line) {
+                Ok(json_value) => {
+                    let options = ValidateOptions {
+                        strict,
+                        context,
+                        ..ValidateOptions::default()
+                    };
+                    self._validate(py, &json_value, InputType::Json, options)
+                }
+                Err(e) => {
+                    let line_input: &PyAny = PyBytes::new(py, line);
+                    Err(json::map_json_err(line_input, e, line))
+                }
+            };
+            match r {
+                Ok(value) => results.append(value)?,
+                // only validation errors are collected, anything else is raised immediately
+                Err(e @ ValError::LineErrors(_)) => {
+                    let err = self.prepare_validation_err(py, e, InputType::Json);
+                    errors.append((index + 1, err.into_py(py)))?;
+                }
+                Err(e) => return Err(self.prepare_validation_err(py, e, InputType::Json)),
+            }
+        }
+        Ok((results.into(), errors.into()))
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None, by_alias=None, by_name=None))]
     pub fn validate_strings(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        by_alias: Option<bool>,
+        by_name: Option<bool>,
     ) -> PyResult<PyObject> {
+        let _span = TraceSpan::enter("SchemaValidator.validate_strings", Some(self.validator.get_name()));
+        check_lookup_type(by_alias, by_name)?;
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None) {
+        let options = ValidateOptions {
+            strict,
+            context,
+            by_alias,
+            by_name,
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, &string_mapping, t, options) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
@@ -254,33 +420,92 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&PyAny>,
     ) -> PyResult<PyObject> {
+        let _span = TraceSpan::enter("SchemaValidator.validate_assignment", Some(self.validator.get_name()));
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
             strict,
+            strict_overrides: None,
             from_attributes,
+            by_alias: None,
+            by_name: None,
             context,
             self_instance: None,
         };
 
-        let guard = &mut RecursionGuard::default();
+        let assignments = PyDict::new(py);
+        assignments.set_item(field_name, field_value)?;
+        let guard = &mut RecursionGuard::with_limit(self.recursion_limit);
         let mut state = ValidationState::new(extra, guard);
         self.validator
-            .validate_assignment(py, obj, field_name, field_value, &mut state)
+            .validate_assignments(py, obj, assignments, false, &mut state)
             .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
+    #[pyo3(signature = (obj, field_names, *, strict=None, from_attributes=None, context=None))]
+    pub fn revalidate_fields(
+        &self,
+        py: Python,
+        obj: &PyAny,
+        field_names: Vec<&str>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<()> {
+        let assignments = PyDict::new(py);
+        for field_name in field_names {
+            assignments.set_item(field_name, obj.getattr(field_name)?)?;
+        }
+        let guard = &mut RecursionGuard::with_limit(self.recursion_limit);
+        let extra = Extra::new(strict, None, from_attributes, context, None, InputType::Python);
+        let mut state = ValidationState::new(extra, guard);
+        self.validator
+            .validate_assignments(py, obj, assignments, true, &mut state)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        Ok(())
+    }
+
+    #[pyo3(signature = (obj, assignments, *, strict=None, from_attributes=None, context=None))]
+    pub fn validate_assignment_batch(
+        &self,
+        py: Python,
+        obj: &PyAny,
+        assignments: &PyDict,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        // validators wrapping the model or dataclass run after the new values are set, so those might still fail
+        let snapshot = assignment_snapshot(py, obj, assignments)?;
+        let guard = &mut RecursionGuard::with_limit(self.recursion_limit);
+        let extra = Extra::new(strict, None, from_attributes, context, None, InputType::Python);
+        let mut state = ValidationState::new(extra, guard);
+        match self
+            .validator
+            .validate_assignments(py, obj, assignments, false, &mut state)
+        {
+            Ok(_) => Ok(obj.into_py(py)),
+            Err(err) => {
+                restore_snapshot(py, obj, snapshot)?;
+                Err(self.prepare_validation_err(py, err, InputType::Python))
+            }
+        }
+    }
+
     #[pyo3(signature = (*, strict=None, context=None))]
     pub fn get_default_value(&self, py: Python, strict: Option<bool>, context: Option<&PyAny>) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
             strict,
+            strict_overrides: None,
             from_attributes: None,
+            by_alias: None,
+            by_name: None,
             context,
             self_instance: None,
         };
-        let recursion_guard = &mut RecursionGuard::default();
+        let recursion_guard = &mut RecursionGuard::with_limit(self.recursion_limit);
         let mut state = ValidationState::new(extra, recursion_guard);
         let r = self.validator.default_value(py, None::<i64>, &mut state);
         match r {
@@ -292,6 +517,14 @@ impl SchemaValidator {
         }
     }
 
+    #[pyo3(signature = (*, reset=false))]
+    pub fn coverage_stats<'py>(&self, py: Python<'py>, reset: bool) -> PyResult<Option<&'py PyDict>> {
+        self.coverage_stats
+            .as_ref()
+            .map(|stats| stats.to_dict(py, reset))
+            .transpose()
+    }
+
     pub fn __repr__(&self, py: Python) -> String {
         format!(
             "SchemaValidator(title={:?}, validator={:#?}, definitions={:#?})",
@@ -307,45 +540,181 @@ impl SchemaValidator {
         if let Some(ref py_config) = self.py_config {
             visit.call(py_config)?;
         }
+        if let Some(ref error_message_translator) = self.error_message_translator {
+            visit.call(error_message_translator)?;
+        }
         Ok(())
     }
 }
 
+/// Arguments of one validation call which apply to the whole call, entry points which don't support
+/// an option leave it as its default
+#[derive(Default)]
+struct ValidateOptions<'py> {
+    strict: Option<bool>,
+    strict_overrides: Option<StrictOverrides>,
+    from_attributes: Option<bool>,
+    context: Option<&'py PyAny>,
+    self_instance: Option<&'py PyAny>,
+    timeout: Option<Duration>,
+    coercion_report: Option<&'py PyList>,
+    identity_map: Option<&'py PyDict>,
+    union_choice_callback: Option<&'py PyAny>,
+    by_alias: Option<bool>,
+    by_name: Option<bool>,
+}
+
 impl SchemaValidator {
-    #[allow(clippy::too_many_arguments)]
     fn _validate<'s, 'data>(
         &'data self,
         py: Python<'data>,
         input: &'data impl Input<'data>,
         input_type: InputType,
-        strict: Option<bool>,
-        from_attributes: Option<bool>,
-        context: Option<&'data PyAny>,
-        self_instance: Option<&PyAny>,
+        options: ValidateOptions<'data>,
     ) -> ValResult<PyObject>
     where
         's: 'data,
     {
-        let mut recursion_guard = RecursionGuard::default();
-        let mut state = ValidationState::new(
-            Extra::new(strict, from_attributes, context, self_instance, input_type),
-            &mut recursion_guard,
+        let mut recursion_guard = RecursionGuard::with_limit(self.recursion_limit);
+        let extra = Extra {
+            by_alias: options.by_alias,
+            by_name: options.by_name,
+            ..Extra::new(
+                options.strict,
+                options.strict_overrides.as_ref(),
+                options.from_attributes,
+                options.context,
+                options.self_instance,
+                input_type,
+            )
+        };
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        if let Some(timeout) = options.timeout {
+            state.set_timeout(timeout);
+        }
+        if self.reuse_instances {
+            state.enable_shared_instances();
+        }
+        if options.identity_map.is_some() {
+            state.enable_identity_map();
+        }
+        if options.union_choice_callback.is_some() {
+            state.enable_union_choices();
+        }
+        let output = match options.coercion_report {
+            Some(coercion_report) => {
+                state.enable_coercion_report();
+                let output = self.validator.validate(py, input, &mut state)?;
+                state.finish_coercion_report(py, coercion_report, input, self.validator.get_name())?;
+                output
+            }
+            None => self.validator.validate(py, input, &mut state)?,
+        };
+        state.record_identity(py, input, &output);
+        state.finish_output_records(py, options.identity_map, options.union_choice_callback)?;
+        Ok(output)
+    }
+
+    fn _validate_json<'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data PyAny,
+        json_data: &[u8],
+        options: ValidateOptions<'data>,
+        source_spans: Option<&PyDict>,
+    ) -> ValResult<PyObject> {
+        // only containers gain from validating as they parse, for anything else parsing up front is just as quick
+        // and releases the GIL for large inputs
+        let direct = matches!(
+            self.validator,
+            CombinedValidator::List(_) | CombinedValidator::Dict(_) | CombinedValidator::Nullable(_)
         );
-        self.validator.validate(py, input, &mut state)
+        // these options need the input of each item, or of the whole value, so it has to be parsed first
+        if direct
+            && options.strict_overrides.is_none()
+            && options.timeout.is_none()
+            && options.coercion_report.is_none()
+            && options.identity_map.is_none()
+            && source_spans.is_none()
+        {
+            return self._validate_json_direct(py, input, json_data, options);
+        }
+        let json_value = json::parse_json(py, json_data).map_err(|e| json::map_json_err(input, e, json_data))?;
+        // recorded before validating, so spans can be looked up for the locations of any errors
+        if let Some(source_spans) = source_spans {
+            json_spans(py, json_data, source_spans)?;
+        }
+        self._validate(py, &json_value, InputType::Json, options)
     }
 
-    fn _validate_json(
-        &self,
-        py: Python,
-        input: &PyAny,
+    /// Validate JSON in a single pass, driving the parser from the validators so containers are validated as
+    /// they're parsed rather than being parsed to a `JsonValue` first, see `_validate_json` for the options
+    /// which can't be used this way
+    fn _validate_json_direct<'data>(
+        &'data self,
+        py: Python<'data>,
+        input: &'data PyAny,
         json_data: &[u8],
-        strict: Option<bool>,
-        context: Option<&PyAny>,
-        self_instance: Option<&PyAny>,
+        options: ValidateOptions<'data>,
     ) -> ValResult<PyObject> {
-        let json_value =
-            jiter::JsonValue::parse(json_data, true).map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(py, &json_value, InputType::Json, strict, None, context, self_instance)
+        let mut recursion_guard = RecursionGuard::with_limit(self.recursion_limit);
+        let extra = Extra {
+            by_alias: options.by_alias,
+            by_name: options.by_name,
+            ..Extra::new(
+                options.strict,
+                None,
+                None,
+                options.context,
+                options.self_instance,
+                InputType::Json,
+            )
+        };
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        if self.reuse_instances {
+            state.enable_shared_instances();
+        }
+        if options.union_choice_callback.is_some() {
+            state.enable_union_choices();
+        }
+        let mut json = JsonDirect::new(json_data);
+        let result = json
+            .peak()
+            .and_then(|peak| self.validator.validate_json_direct(py, &mut json, peak, &mut state))
+            .and_then(|output| json.finish().map(|()| output));
+        if let Some(error) = json.json_error() {
+            return Err(ValError::new(ErrorType::JsonInvalid { error, context: None }, input));
+        }
+        match result {
+            Ok(output) => {
+                state.finish_output_records(py, None, options.union_choice_callback)?;
+                Ok(output)
+            }
+            Err(err) => {
+                // validation may have stopped part way through the JSON, invalid JSON takes precedence over
+                // validation errors, so check the rest of it
+                json::parse_json(py, json_data).map_err(|e| json::map_json_err(input, e, json_data))?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Validate raw JSON data, used by the C API where there's no Python object wrapping the input
+    pub fn validate_json_buffer(&self, py: Python, json_data: &[u8], strict: Option<bool>) -> PyResult<PyObject> {
+        let r = match json::parse_json(py, json_data) {
+            Ok(json_value) => {
+                let options = ValidateOptions {
+                    strict,
+                    ..ValidateOptions::default()
+                };
+                self._validate(py, &json_value, InputType::Json, options)
+            }
+            Err(e) => {
+                let input: &PyAny = PyBytes::new(py, json_data);
+                Err(json::map_json_err(input, e, json_data))
+            }
+        };
+        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -357,10 +726,78 @@ impl SchemaValidator {
             None,
             self.hide_input_in_errors,
             self.validation_error_cause,
+            self.error_message_translator.as_ref(),
         )
     }
 }
 
+fn check_lookup_type(by_alias: Option<bool>, by_name: Option<bool>) -> PyResult<()> {
+    if by_alias == Some(false) && by_name == Some(false) {
+        Err(PyValueError::new_err("`by_alias` and `by_name` cannot both be False"))
+    } else {
+        Ok(())
+    }
+}
+
+fn timeout_duration(timeout: Option<f64>) -> PyResult<Option<Duration>> {
+    timeout
+        .map(|t| {
+            Duration::try_from_secs_f64(t).map_err(|_| PyValueError::new_err("timeout must be a non-negative number"))
+        })
+        .transpose()
+}
+
+/// Copy the attributes of `obj` which `validate_assignments` changes, so they can be restored if validation fails,
+/// `None` means the attribute wasn't set
+fn assignment_snapshot<'py>(
+    py: Python<'py>,
+    obj: &'py PyAny,
+    assignments: &'py PyDict,
+) -> PyResult<Vec<(&'py PyString, Option<&'py PyAny>)>> {
+    let names: Vec<&PyString> = if obj.hasattr(intern!(py, "__dict__"))? {
+        vec![
+            intern!(py, "__dict__"),
+            intern!(py, "__pydantic_extra__"),
+            intern!(py, "__pydantic_fields_set__"),
+        ]
+    } else {
+        // e.g. dataclasses with `slots=True`, where fields are set individually
+        assignments
+            .keys()
+            .iter()
+            .map(|key| Ok(key.downcast::<PyString>()?))
+            .collect::<PyResult<_>>()?
+    };
+    let mut snapshot = Vec::with_capacity(names.len());
+    for name in names {
+        let value = match obj.getattr(name) {
+            // `__pydantic_fields_set__` is updated in place
+            Ok(value) if value.is_instance_of::<PyDict>() || value.is_instance_of::<PySet>() => {
+                Some(value.call_method0(intern!(py, "copy"))?)
+            }
+            Ok(value) => Some(value),
+            Err(err) if err.is_instance_of::<PyAttributeError>(py) => None,
+            Err(err) => return Err(err),
+        };
+        snapshot.push((name, value));
+    }
+    Ok(snapshot)
+}
+
+fn restore_snapshot(py: Python, obj: &PyAny, snapshot: Vec<(&PyString, Option<&PyAny>)>) -> PyResult<()> {
+    for (name, value) in snapshot {
+        match value {
+            Some(value) => model::force_setattr(py, obj, name, value)?,
+            // e.g. a slot which was empty, it has to be deleted again if it's been set
+            None => match model::force_delattr(py, obj, name) {
+                Err(err) if !err.is_instance_of::<PyAttributeError>(py) => return Err(err),
+                _ => (),
+            },
+        }
+    }
+    Ok(())
+}
+
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
 #[derive(Debug, Clone)]
@@ -380,7 +817,7 @@ impl<'py> SelfValidator<'py> {
     pub fn validate_schema(&self, py: Python<'py>, schema: &'py PyAny, strict: Option<bool>) -> PyResult<&'py PyAny> {
         let mut recursion_guard = RecursionGuard::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, None, None, None, InputType::Python),
+            Extra::new(strict, None, None, None, None, InputType::Python),
             &mut recursion_guard,
         );
         match self.validator.validator.validate(py, schema, &mut state) {
@@ -409,7 +846,11 @@ impl<'py> SelfValidator<'py> {
             py_config: None,
             title: "Self Schema".into_py(py),
             hide_input_in_errors: false,
+            error_message_translator: None,
+            coverage_stats: None,
             validation_error_cause: false,
+            reuse_instances: false,
+            recursion_limit: None,
         })
     }
 }
@@ -474,9 +915,12 @@ pub fn build_validator<'a>(
         union::TaggedUnionValidator,
         // nullables
         nullable::NullableValidator,
+        // secrets
+        secret::SecretValidator,
         // model classes
         model::ModelValidator,
         model_fields::ModelFieldsValidator,
+        model_constraint::ModelConstraintValidator,
         // dataclasses
         dataclass::DataclassArgsValidator,
         dataclass::DataclassValidator,
@@ -484,6 +928,12 @@ pub fn build_validator<'a>(
         string::StrValidator,
         // integers
         int::IntValidator,
+        int::Int64Builder,
+        int::UInt64Builder,
+        // int flags
+        int_flag::IntFlagValidator,
+        // enums
+        enum_::EnumValidator,
         // boolean
         bool::BoolValidator,
         // floats
@@ -510,6 +960,7 @@ pub fn build_validator<'a>(
         call::CallValidator,
         // literals
         literal::LiteralValidator,
+        literal::ConstValidator,
         // any
         any::AnyValidator,
         // bytes
@@ -549,6 +1000,14 @@ pub fn build_validator<'a>(
         url::MultiHostUrlValidator,
         // uuid types
         uuid::UuidValidator,
+        // email types
+        email::EmailValidator,
+        email::NameEmailValidator,
+        // ip address types
+        ip::IpV4Builder,
+        ip::IpV6Builder,
+        ip::IpAnyBuilder,
+        ip::IpNetworkValidator,
         // recursive (self-referencing) models
         definitions::DefinitionRefValidator,
         definitions::DefinitionsValidatorBuilder,
@@ -565,8 +1024,13 @@ pub struct Extra<'a> {
     pub data: Option<&'a PyDict>,
     /// whether we're in strict or lax mode
     pub strict: Option<bool>,
+    /// Validation time strictness of individual fields, from `strict_fields` and `lax_fields`
+    pub strict_overrides: Option<&'a StrictOverrides>,
     /// Validation time setting of `from_attributes`
     pub from_attributes: Option<bool>,
+    /// Validation time settings of whether fields and arguments are looked up by alias and by name
+    pub by_alias: Option<bool>,
+    pub by_name: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a PyAny>,
     /// This is an instance of the model or dataclass being validated, when validation is performed from `__init__`
@@ -576,6 +1040,7 @@ pub struct Extra<'a> {
 impl<'a> Extra<'a> {
     pub fn new(
         strict: Option<bool>,
+        strict_overrides: Option<&'a StrictOverrides>,
         from_attributes: Option<bool>,
         context: Option<&'a PyAny>,
         self_instance: Option<&'a PyAny>,
@@ -585,7 +1050,10 @@ impl<'a> Extra<'a> {
             input_type,
             data: None,
             strict,
+            strict_overrides,
             from_attributes,
+            by_alias: None,
+            by_name: None,
             context,
             self_instance,
         }
@@ -598,7 +1066,10 @@ impl<'a> Extra<'a> {
             input_type: self.input_type,
             data: self.data,
             strict: Some(true),
+            strict_overrides: self.strict_overrides,
             from_attributes: self.from_attributes,
+            by_alias: self.by_alias,
+            by_name: self.by_name,
             context: self.context,
             self_instance: self.self_instance,
         }
@@ -615,9 +1086,12 @@ pub enum CombinedValidator {
     TaggedUnion(union::TaggedUnionValidator),
     // nullables
     Nullable(nullable::NullableValidator),
+    // secrets
+    Secret(secret::SecretValidator),
     // create new model classes
     Model(model::ModelValidator),
     ModelFields(model_fields::ModelFieldsValidator),
+    ModelConstraint(model_constraint::ModelConstraintValidator),
     // dataclasses
     DataclassArgs(dataclass::DataclassArgsValidator),
     Dataclass(dataclass::DataclassValidator),
@@ -627,6 +1101,11 @@ pub enum CombinedValidator {
     // integers
     Int(int::IntValidator),
     ConstrainedInt(int::ConstrainedIntValidator),
+    MachineInt(int::MachineIntValidator),
+    // int flags
+    IntFlag(int_flag::IntFlagValidator),
+    // enums
+    Enum(enum_::EnumValidator),
     // booleans
     Bool(bool::BoolValidator),
     // floats
@@ -654,6 +1133,7 @@ pub enum CombinedValidator {
     FunctionCall(call::CallValidator),
     // literals
     Literal(literal::LiteralValidator),
+    Const(literal::ConstValidator),
     // any
     Any(any::AnyValidator),
     // bytes
@@ -692,6 +1172,12 @@ pub enum CombinedValidator {
     MultiHostUrl(url::MultiHostUrlValidator),
     // uuid types
     Uuid(uuid::UuidValidator),
+    // email types
+    Email(email::EmailValidator),
+    NameEmail(email::NameEmailValidator),
+    // ip address types
+    IpAddress(ip::IpAddressValidator),
+    IpNetwork(ip::IpNetworkValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
@@ -710,6 +1196,22 @@ pub trait Validator: Send + Sync + Debug {
         state: &mut ValidationState,
     ) -> ValResult<PyObject>;
 
+    /// Validate JSON as it's parsed, `peak` is the start of the value, by default the whole value is parsed
+    /// to a `JsonValue` and passed to `validate`, validators of containers override this to validate each
+    /// item as it's parsed and avoid building the intermediate value.
+    ///
+    /// This is only used when no per-item options are set, see `SchemaValidator::_validate_json`.
+    fn validate_json_direct(
+        &self,
+        py: Python<'_>,
+        json: &mut JsonDirect,
+        peak: Peak,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let value = json.value(peak)?;
+        self.validate(py, &value, state)
+    }
+
     /// Get a default value, currently only used by `WithDefaultValidator`
     fn default_value(
         &self,
@@ -720,14 +1222,15 @@ pub trait Validator: Send + Sync + Debug {
         Ok(None)
     }
 
-    /// Validate assignment to a field of a model
-    #[allow(clippy::too_many_arguments)]
-    fn validate_assignment<'data>(
+    /// Validate assignments to the fields of a model or dataclass, `assignments` maps field names to new values.
+    /// With `revalidate`, the values are the fields' current values being validated again, so frozen fields and
+    /// instances are allowed and `__pydantic_fields_set__` isn't changed
+    fn validate_assignments<'data>(
         &self,
         _py: Python<'data>,
         _obj: &'data PyAny,
-        _field_name: &'data str,
-        _field_value: &'data PyAny,
+        _assignments: &'data PyDict,
+        _revalidate: bool,
         _state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let py_err = PyTypeError::new_err(format!("validate_assignment is not supported for {}", self.get_name()));
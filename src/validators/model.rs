@@ -13,9 +13,10 @@ use super::{
 };
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{py_error_on_minusone, Input};
 use crate::tools::{py_err, SchemaDict};
+use crate::trace::TraceSpan;
 use crate::PydanticUndefinedType;
 
 const ROOT_FIELD: &str = "root";
@@ -110,6 +111,7 @@ impl Validator for ModelValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
+        let _span = TraceSpan::enter_slow("validate", &self.name);
         if let Some(self_instance) = state.extra().self_instance {
             // in the case that self_instance is Some, we're calling validation from within `BaseModel.__init__`
             return self.validate_init(py, self_instance, input, state);
@@ -150,32 +152,41 @@ impl Validator for ModelValidator {
         }
     }
 
-    fn validate_assignment<'data>(
+    fn validate_assignments<'data>(
         &self,
         py: Python<'data>,
         model: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        if self.frozen {
-            return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, field_value));
+        if self.frozen && !revalidate {
+            let errors = assignments
+                .values()
+                .iter()
+                .map(|field_value| ValLineError::new(ErrorTypeDefaults::FrozenInstance, field_value))
+                .collect();
+            return Err(ValError::LineErrors(errors));
         } else if self.root_model {
-            return if field_name != ROOT_FIELD {
-                Err(ValError::new_with_loc(
-                    ErrorType::NoSuchAttribute {
-                        attribute: field_name.to_string(),
-                        context: None,
-                    },
-                    field_value,
-                    field_name.to_string(),
-                ))
-            } else {
-                let output = self.validator.validate(py, field_value, state)?;
-
-                force_setattr(py, model, intern!(py, ROOT_FIELD), output)?;
-                Ok(model.into_py(py))
-            };
+            let mut root = None;
+            for (field_name, field_value) in assignments {
+                let field_name: &str = field_name.extract()?;
+                if field_name != ROOT_FIELD {
+                    return Err(ValError::new_with_loc(
+                        ErrorType::NoSuchAttribute {
+                            attribute: field_name.to_string(),
+                            context: None,
+                        },
+                        field_value,
+                        field_name.to_string(),
+                    ));
+                }
+                root = Some(self.validator.validate(py, field_value, state)?);
+            }
+            if let Some(root) = root {
+                force_setattr(py, model, intern!(py, ROOT_FIELD), root)?;
+            }
+            return Ok(model.into_py(py));
         }
         let old_dict: &PyDict = model.getattr(intern!(py, DUNDER_DICT))?.downcast()?;
 
@@ -184,18 +195,20 @@ impl Validator for ModelValidator {
         if let Some(old_extra) = old_extra {
             input_dict.update(old_extra.as_mapping())?;
         }
-        input_dict.set_item(field_name, field_value)?;
+        input_dict.update(assignments.as_mapping())?;
 
         let output = self
             .validator
-            .validate_assignment(py, input_dict, field_name, field_value, state)?;
+            .validate_assignments(py, input_dict, assignments, revalidate, state)?;
 
         let (validated_dict, validated_extra, validated_fields_set): (&PyDict, &PyAny, &PySet) = output.extract(py)?;
 
-        if let Ok(fields_set) = model.getattr(intern!(py, DUNDER_FIELDS_SET_KEY)) {
-            let fields_set: &PySet = fields_set.downcast()?;
-            for field_name in validated_fields_set {
-                fields_set.add(field_name)?;
+        if !revalidate {
+            if let Ok(fields_set) = model.getattr(intern!(py, DUNDER_FIELDS_SET_KEY)) {
+                let fields_set: &PySet = fields_set.downcast()?;
+                for field_name in validated_fields_set {
+                    fields_set.add(field_name)?;
+                }
             }
         }
 
@@ -264,9 +277,23 @@ impl ModelValidator {
             }
         }
 
+        // with `val_circular_ref='reuse'` the instance is registered before validating fields, so references back
+        // to the same input from inside them are linked to it
+        let early_instance = match state.take_pending_instance(input.identity()) {
+            Some(key) => {
+                let instance = create_class(self.class.as_ref(py))?;
+                state.add_shared_instance(key, input.to_object(py), instance.clone_ref(py));
+                Some(instance)
+            }
+            None => None,
+        };
+
         let output = self.validator.validate(py, input, state)?;
 
-        let instance = create_class(self.class.as_ref(py))?;
+        let instance = match early_instance {
+            Some(instance) => instance,
+            None => create_class(self.class.as_ref(py))?,
+        };
         let instance_ref = instance.as_ref(py);
 
         if self.root_model {
@@ -331,6 +358,17 @@ fn set_model_attrs(instance: &PyAny, model_dict: &PyAny, model_extra: &PyAny, fi
     Ok(())
 }
 
+/// Delete an attribute with `object.__delattr__`, bypassing any `__delattr__` override, like `force_setattr`
+pub(super) fn force_delattr<N: ToPyObject>(py: Python<'_>, obj: &PyAny, attr_name: N) -> PyResult<()> {
+    let attr_name = attr_name.to_object(py);
+    unsafe {
+        py_error_on_minusone(
+            py,
+            ffi::PyObject_GenericSetAttr(obj.as_ptr(), attr_name.as_ptr(), null_mut()),
+        )
+    }
+}
+
 pub(super) fn force_setattr<N, V>(py: Python<'_>, obj: &PyAny, attr_name: N, value: V) -> PyResult<()>
 where
     N: ToPyObject,
@@ -0,0 +1,198 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{AsLocItem, ErrorType, LocItem, ValError, ValLineError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// A comparison between two fields, e.g. `start <= end`
+#[derive(Debug)]
+struct Constraint {
+    left: Py<PyString>,
+    op: CompareOp,
+    op_str: String,
+    right: Py<PyString>,
+    // location of the error, outermost first
+    loc: Vec<LocItem>,
+}
+
+impl Constraint {
+    fn build(constraint: &PyDict) -> PyResult<Self> {
+        let py = constraint.py();
+        let op_str: String = constraint.get_as_req(intern!(py, "op"))?;
+        let op = match op_str.as_str() {
+            "lt" => CompareOp::Lt,
+            "le" => CompareOp::Le,
+            "gt" => CompareOp::Gt,
+            "ge" => CompareOp::Ge,
+            "eq" => CompareOp::Eq,
+            "ne" => CompareOp::Ne,
+            s => {
+                return py_schema_err!(
+                    "Invalid model constraint op: `{}`, expected `lt`, `le`, `gt`, `ge`, `eq` or `ne`",
+                    s
+                )
+            }
+        };
+        let loc = match constraint.get_item(intern!(py, "loc"))? {
+            None => Vec::new(),
+            Some(loc) if loc.is_instance_of::<PyString>() => vec![loc.as_loc_item()],
+            Some(loc) => loc.downcast::<PyList>()?.iter().map(AsLocItem::as_loc_item).collect(),
+        };
+        Ok(Self {
+            left: constraint.get_as_req(intern!(py, "left"))?,
+            op,
+            op_str,
+            right: constraint.get_as_req(intern!(py, "right"))?,
+            loc,
+        })
+    }
+
+    /// `None` if the constraint holds, or either field is missing or `None`, values which can't be compared,
+    /// e.g. naive and aware datetimes, don't satisfy the constraint
+    fn check<'data>(
+        &self,
+        py: Python<'data>,
+        output: &PyAny,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<Option<ValLineError>> {
+        let (Some(left), Some(right)) = (
+            field_value(output, self.left.as_ref(py))?,
+            field_value(output, self.right.as_ref(py))?,
+        ) else {
+            return Ok(None);
+        };
+        match left.rich_compare(right, self.op).and_then(PyAny::is_true) {
+            Ok(true) => return Ok(None),
+            Ok(false) => (),
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => (),
+            Err(err) => return Err(err.into()),
+        }
+        let error_type = ErrorType::ModelConstraint {
+            left: self.left.as_ref(py).to_str()?.to_string(),
+            op: self.op_str.clone(),
+            right: self.right.as_ref(py).to_str()?.to_string(),
+            context: None,
+        };
+        let line_error = self
+            .loc
+            .iter()
+            .rev()
+            .cloned()
+            .fold(ValLineError::new(error_type, input), ValLineError::with_outer_location);
+        Ok(Some(line_error))
+    }
+}
+
+/// The value of a field from the output of a `model-fields` (a tuple with the fields dict first), `typed-dict`
+/// or `model` schema, `None` if it's missing or `None`
+fn field_value<'py>(output: &'py PyAny, name: &PyString) -> PyResult<Option<&'py PyAny>> {
+    let fields = match output.downcast::<PyTuple>() {
+        Ok(tuple) => tuple.get_item(0)?,
+        Err(_) => output,
+    };
+    let value = match fields.downcast::<PyDict>() {
+        Ok(dict) => dict.get_item(name)?,
+        Err(_) => fields.getattr(name).ok(),
+    };
+    Ok(value.filter(|value| !value.is_none()))
+}
+
+/// Checks comparisons between fields once the inner schema has validated them, e.g. `start <= end`,
+/// without the overhead of calling a model validator
+#[derive(Debug)]
+pub struct ModelConstraintValidator {
+    validator: Box<CombinedValidator>,
+    constraints: Vec<Constraint>,
+    name: String,
+}
+
+impl BuildValidator for ModelConstraintValidator {
+    const EXPECTED_TYPE: &'static str = "model-constraint";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let validator = Box::new(build_validator(
+            schema.get_as_req(intern!(py, "schema"))?,
+            config,
+            definitions,
+        )?);
+        let constraints = schema
+            .get_as_req::<&PyList>(intern!(py, "constraints"))?
+            .iter()
+            .map(|constraint| Constraint::build(constraint.downcast()?))
+            .collect::<PyResult<_>>()?;
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self {
+            validator,
+            constraints,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(Constraint { left, right });
+
+impl_py_gc_traverse!(ModelConstraintValidator { validator, constraints });
+
+impl ModelConstraintValidator {
+    fn check<'data>(
+        &self,
+        py: Python<'data>,
+        output: PyObject,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<PyObject> {
+        let mut errors = Vec::new();
+        for constraint in &self.constraints {
+            if let Some(line_error) = constraint.check(py, output.as_ref(py), input)? {
+                errors.push(line_error);
+            }
+        }
+        if errors.is_empty() {
+            Ok(output)
+        } else {
+            Err(ValError::LineErrors(errors))
+        }
+    }
+}
+
+impl Validator for ModelConstraintValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let output = self.validator.validate(py, input, state)?;
+        self.check(py, output, input)
+    }
+
+    fn validate_assignments<'data>(
+        &self,
+        py: Python<'data>,
+        obj: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let output = self
+            .validator
+            .validate_assignments(py, obj, assignments, revalidate, state)?;
+        self.check(py, output, obj)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
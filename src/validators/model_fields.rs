@@ -6,15 +6,19 @@ use pyo3::types::{PyDict, PySet, PyString, PyType};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{generate_alias, is_strict, schema_or_config_same, ExtraBehavior, ExtrasKeyTransform};
+use crate::coverage::CoverageCounter;
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
-    AttributesGenericIterator, BorrowInput, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
-    MappingGenericIterator, StringMappingGenericIterator, ValidationMatch,
+    AttributesGenericIterator, BorrowInput, DictGenericIterator, FromAttributesPolicy, GenericMapping, Input,
+    JsonObjectGenericIterator, MappingGenericIterator, PairsMatches, PairsMatchesIterator,
+    StringMappingGenericIterator, ValidationMatch,
 };
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{LookupKeyCollection, LookupType};
 use crate::tools::SchemaDict;
+use crate::UnsetType;
 
+use super::required_if::RequiredIf;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
 };
@@ -24,13 +28,18 @@ use std::ops::ControlFlow;
 #[derive(Debug)]
 struct Field {
     name: String,
-    lookup_key: LookupKey,
+    lookup_keys: LookupKeyCollection,
     name_py: Py<PyString>,
     validator: CombinedValidator,
     frozen: bool,
+    // validated from the parent's input itself, rather than from a key
+    flatten: bool,
+    // required when other fields have certain values, even though there's a default
+    required_if: Option<RequiredIf>,
+    coverage_counter: Option<CoverageCounter>,
 }
 
-impl_py_gc_traverse!(Field { validator });
+impl_py_gc_traverse!(Field { validator, required_if });
 
 #[derive(Debug)]
 pub struct ModelFieldsValidator {
@@ -38,9 +47,19 @@ pub struct ModelFieldsValidator {
     model_name: String,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    extras_keys_validator: Option<Box<CombinedValidator>>,
+    extras_key_transform: Option<ExtrasKeyTransform>,
     strict: bool,
     from_attributes: bool,
+    from_attributes_policy: FromAttributesPolicy,
+    // accept dict items views and iterables of `(key, value)` pairs in lax mode
+    from_pairs: bool,
+    populate_by_name: bool,
     loc_by_alias: bool,
+    extra_coverage_counter: Option<CoverageCounter>,
+    // the names of all fields, copied as `fields_set` by `validate_exact_dict`, `None` when that fast path
+    // can't be used because fields have aliases or are flattened
+    all_fields_set: Option<Py<PySet>>,
 }
 
 impl BuildValidator for ModelFieldsValidator {
@@ -56,6 +75,7 @@ impl BuildValidator for ModelFieldsValidator {
 
         let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        let from_pairs: bool = schema.get_as(intern!(py, "from_pairs"))?.unwrap_or(false);
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
@@ -64,12 +84,20 @@ impl BuildValidator for ModelFieldsValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_keys_validator = match (schema.get_item(intern!(py, "extras_keys_schema"))?, &extra_behavior) {
+            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, definitions)?)),
+            (Some(_), _) => return py_schema_err!("extras_keys_schema can only be used if extra_behavior=allow"),
+            (_, _) => None,
+        };
+        let extras_key_transform = ExtrasKeyTransform::from_schema(py, schema, &extra_behavior)?;
         let model_name: String = schema
             .get_as(intern!(py, "model_name"))?
             .unwrap_or_else(|| "Model".to_string());
 
         let fields_dict: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<Field> = Vec::with_capacity(fields_dict.len());
+        let field_names: Vec<&str> = fields_dict.keys().extract()?;
+        let mut exact_dict_fast_path = true;
 
         for (key, value) in fields_dict {
             let field_info: &PyDict = value.downcast()?;
@@ -82,31 +110,63 @@ impl BuildValidator for ModelFieldsValidator {
                 Err(err) => return py_schema_err!("Field \"{}\":\n  {}", field_name, err),
             };
 
-            let lookup_key = match field_info.get_item(intern!(py, "validation_alias"))? {
-                Some(alias) => {
-                    let alt_alias = if populate_by_name { Some(field_name) } else { None };
-                    LookupKey::from_py(py, alias, alt_alias)?
-                }
-                None => LookupKey::from_string(py, field_name),
+            let validation_alias = match field_info.get_item(intern!(py, "validation_alias"))? {
+                Some(alias) => Some(alias),
+                None => generate_alias(py, config, field_name)?.map(PyString::as_ref),
             };
+            exact_dict_fast_path &= validation_alias.is_none();
+            let lookup_keys = LookupKeyCollection::new(py, validation_alias, field_name)?;
+
+            let flatten = field_info.get_as::<bool>(intern!(py, "flatten"))?.unwrap_or(false);
+            exact_dict_fast_path &= !flatten;
+            if flatten && !matches!(extra_behavior, ExtraBehavior::Ignore) {
+                return py_schema_err!(
+                    "Field \"{}\": flatten can only be used if extra_behavior=ignore",
+                    field_name
+                );
+            }
+            if flatten && from_pairs {
+                // flattened fields validate the whole input again, which a one-shot iterator of pairs can't support
+                return py_schema_err!("Field \"{}\": flatten can't be used with from_pairs", field_name);
+            }
 
             fields.push(Field {
                 name: field_name.to_string(),
-                lookup_key,
+                lookup_keys,
                 name_py: PyString::new(py, field_name).into(),
                 validator,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                flatten,
+                required_if: RequiredIf::from_field_info(field_info, field_name, &field_names)?,
+                coverage_counter: definitions.coverage_counter(|| format!("{model_name}.{field_name}")),
             });
         }
 
+        let extra_coverage_counter = match extra_behavior {
+            ExtraBehavior::Allow => definitions.coverage_counter(|| format!("{model_name}.__extra__")),
+            _ => None,
+        };
+        let all_fields_set = if exact_dict_fast_path {
+            Some(PySet::new(py, &field_names)?.into())
+        } else {
+            None
+        };
+
         Ok(Self {
             fields,
             model_name,
             extra_behavior,
             extras_validator,
+            extras_keys_validator,
+            extras_key_transform,
             strict,
             from_attributes,
+            from_attributes_policy: FromAttributesPolicy::from_config(py, config)?,
+            from_pairs,
+            populate_by_name,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            extra_coverage_counter,
+            all_fields_set,
         }
         .into())
     }
@@ -114,7 +174,11 @@ impl BuildValidator for ModelFieldsValidator {
 
 impl_py_gc_traverse!(ModelFieldsValidator {
     fields,
-    extras_validator
+    extras_validator,
+    extras_keys_validator,
+    extras_key_transform,
+    from_attributes_policy,
+    all_fields_set
 });
 
 impl Validator for ModelFieldsValidator {
@@ -127,33 +191,27 @@ impl Validator for ModelFieldsValidator {
         let strict = state.strict_or(self.strict);
         let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
 
-        // we convert the DictType error to a ModelType error
-        let dict = match input.validate_model_fields(strict, from_attributes) {
+        let dict = match input.validate_model_fields(strict, from_attributes.then_some(&self.from_attributes_policy)) {
             Ok(d) => d,
-            Err(ValError::LineErrors(errors)) => {
-                let errors: Vec<ValLineError> = errors
-                    .into_iter()
-                    .map(|e| match e.error_type {
-                        ErrorType::DictType { .. } => {
-                            let mut e = e;
-                            e.error_type = ErrorType::ModelType {
-                                class_name: self.model_name.clone(),
-                                context: None,
-                            };
-                            e
-                        }
-                        _ => e,
-                    })
-                    .collect();
-                return Err(ValError::LineErrors(errors));
-            }
-            Err(err) => return Err(err),
+            Err(err) if self.from_pairs && !strict => match input.lax_pairs() {
+                Some(d) => d,
+                None => return Err(self.model_type_error(err)),
+            },
+            Err(err) => return Err(self.model_type_error(err)),
         };
 
+        if let GenericMapping::PyDict(py_dict) = dict {
+            if let Some(output) = self.validate_exact_dict(py, py_dict, state)? {
+                return Ok(output);
+            }
+        }
+
         let model_dict = PyDict::new(py);
+        let lookup_type = LookupType::new(state.extra().by_alias, state.extra().by_name, self.populate_by_name);
         let mut model_extra_dict_op: Option<&PyDict> = None;
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
         let mut fields_set_vec: Vec<Py<PyString>> = Vec::with_capacity(self.fields.len());
+        let mut conditionally_missing: Vec<&Field> = Vec::new();
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
@@ -179,7 +237,28 @@ impl Validator for ModelFieldsValidator {
                     ..*state.extra()
                 }, |state| {
                     for field in &self.fields {
-                        let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
+                        if let Err(err) = state.check_item(py, input, || field.name.as_loc_item()) {
+                            return ControlFlow::Break(err);
+                        }
+                        if field.flatten {
+                            if let Some(ref coverage_counter) = field.coverage_counter {
+                                coverage_counter.increment();
+                            }
+                            // the whole input is validated, so nothing is added to the locations of errors
+                            // and coercions, which are already at the keys in the input
+                            match field.validator.validate(py, input, state) {
+                                Ok(value) => {
+                                    fields_set_vec.push(field.name_py.clone_ref(py));
+                                    control_flow!(model_dict.set_item(&field.name_py, value))?;
+                                }
+                                Err(ValError::Omit) => {}
+                                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                                Err(err) => return ControlFlow::Break(err),
+                            }
+                            continue;
+                        }
+                        let lookup_key = field.lookup_keys.select(lookup_type);
+                        let op_key_value = match lookup_key.$get_method($dict $(, $kwargs )? ) {
                             Ok(v) => v,
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
@@ -195,10 +274,17 @@ impl Validator for ModelFieldsValidator {
                                 // extra logic either way
                                 used_keys.insert(lookup_path.first_key());
                             }
-                            match field.validator.validate(py, value.borrow_input(), state) {
+                            if let Some(ref coverage_counter) = field.coverage_counter {
+                                coverage_counter.increment();
+                            }
+                            let field_loc = || field.name.as_loc_item();
+                            match state.validate_item(py, &field.validator, value.borrow_input(), field_loc) {
                                 Ok(value) => {
+                                    // `UNSET` fields count as not provided
+                                    if !UnsetType::is_unset(value.as_ref(py)) {
+                                        fields_set_vec.push(field.name_py.clone_ref(py));
+                                    }
                                     control_flow!(model_dict.set_item(&field.name_py, value))?;
-                                    fields_set_vec.push(field.name_py.clone_ref(py));
                                 }
                                 Err(ValError::Omit) => continue,
                                 Err(ValError::LineErrors(line_errors)) => {
@@ -209,7 +295,10 @@ impl Validator for ModelFieldsValidator {
                                         );
                                     }
                                 }
-                                Err(err) => return ControlFlow::Break(err),
+                                // a timeout isn't collected with other errors, so its location is added here
+                                Err(err) => {
+                                    return ControlFlow::Break(err.with_outer_location(field.name.as_loc_item()))
+                                }
                             }
                             continue;
                         }
@@ -217,11 +306,14 @@ impl Validator for ModelFieldsValidator {
                         match field.validator.default_value(py, Some(field.name.as_str()), state) {
                             Ok(Some(value)) => {
                                 // Default value exists, and passed validation if required
+                                if field.required_if.is_some() {
+                                    conditionally_missing.push(field);
+                                }
                                 control_flow!(model_dict.set_item(&field.name_py, value))?;
                             },
                             Ok(None) => {
                                 // This means there was no default value
-                                errors.push(field.lookup_key.error(
+                                errors.push(lookup_key.error(
                                     ErrorTypeDefaults::Missing,
                                     input,
                                     self.loc_by_alias,
@@ -248,67 +340,126 @@ impl Validator for ModelFieldsValidator {
                 }
 
                 if let Some(ref mut used_keys) = used_keys {
-                    let model_extra_dict = PyDict::new(py);
-                    for item_result in <$iter>::new($dict)? {
-                        let (raw_key, value) = item_result?;
-                        let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
-                            Ok(k) => k,
-                            Err(ValError::LineErrors(line_errors)) => {
-                                for err in line_errors {
-                                    errors.push(
-                                        err.with_outer_location(raw_key.as_loc_item())
-                                            .with_type(ErrorTypeDefaults::InvalidKey)
+                    // extras are validated in their own closure so their locals, repeated for each kind of mapping,
+                    // aren't in this function's stack frame, which is live while fields are validated and so
+                    // repeated for every level of recursive models
+                    let mut validate_extras = || -> ValResult<()> {
+                        let model_extra_dict = PyDict::new(py);
+                        for item_result in <$iter>::new($dict)? {
+                            let (raw_key, value) = item_result?;
+                            state.check_item(py, input, || raw_key.as_loc_item())?;
+                            let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
+                                Ok(k) => k,
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    for err in line_errors {
+                                        errors.push(
+                                            err.with_outer_location(raw_key.as_loc_item())
+                                                .with_type(ErrorTypeDefaults::InvalidKey)
 
-                                    );
+                                        );
+                                    }
+                                    continue;
                                 }
+                                Err(err) => return Err(err),
+                            };
+                            let cow = either_str.as_cow().map_err(|err| err)?;
+                            if used_keys.contains(cow.as_ref()) {
                                 continue;
                             }
-                            Err(err) => return Err(err),
-                        };
-                        let cow = either_str.as_cow().map_err(|err| err)?;
-                        if used_keys.contains(cow.as_ref()) {
-                            continue;
-                        }
 
-                        let value = value.borrow_input();
-                        // Unknown / extra field
-                        match self.extra_behavior {
-                            ExtraBehavior::Forbid => {
-                                errors.push(
-                                    ValLineError::new_with_loc(
-                                        ErrorTypeDefaults::ExtraForbidden,
-                                        value,
-                                        raw_key.as_loc_item(),
-                                    )
-
-                                );
-                            }
-                            ExtraBehavior::Ignore => {}
-                            ExtraBehavior::Allow => {
-                            let py_key = either_str.as_py_string(py);
-                                if let Some(ref validator) = self.extras_validator {
-                                    match validator.validate(py, value, state) {
-                                        Ok(value) => {
-                                            model_extra_dict.set_item(py_key, value)?;
-                                            fields_set_vec.push(py_key.into_py(py));
-                                        }
-                                        Err(ValError::LineErrors(line_errors)) => {
-                                            for err in line_errors {
-                                                errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                            let value = value.borrow_input();
+                            // Unknown / extra field
+                            match self.extra_behavior {
+                                ExtraBehavior::Forbid => {
+                                    errors.push(
+                                        ValLineError::new_with_loc(
+                                            ErrorTypeDefaults::ExtraForbidden,
+                                            value,
+                                            raw_key.as_loc_item(),
+                                        )
+
+                                    );
+                                }
+                                ExtraBehavior::Ignore => {}
+                                ExtraBehavior::Allow => {
+                                    let py_key = either_str.as_py_string(py);
+                                    let py_key = match self.extras_key_transform {
+                                        Some(ref transform) => transform.apply(py_key)?,
+                                        None => py_key,
+                                    };
+                                    let py_key = match self.extras_keys_validator {
+                                        Some(ref validator) => match validator.validate(py, py_key.as_ref(), state) {
+                                            Ok(key) => {
+                                                // extras are set as attributes, so their keys must be strings
+                                                let key = key.into_ref(py);
+                                                match key.downcast::<PyString>() {
+                                                    Ok(key) => key,
+                                                    Err(_) => {
+                                                        errors.push(
+                                                            ValLineError::new_with_loc(
+                                                                ErrorTypeDefaults::StringType,
+                                                                key,
+                                                                "[key]",
+                                                            )
+                                                            .with_outer_location(raw_key.as_loc_item()),
+                                                        );
+                                                        continue;
+                                                    }
+                                                }
                                             }
-                                        }
-                                        Err(err) => return Err(err),
+                                            Err(ValError::LineErrors(line_errors)) => {
+                                                for err in line_errors {
+                                                    errors.push(
+                                                        err.with_outer_location("[key]".into())
+                                                            .with_outer_location(raw_key.as_loc_item()),
+                                                    );
+                                                }
+                                                continue;
+                                            }
+                                            Err(err) => return Err(err),
+                                        },
+                                        None => py_key,
+                                    };
+                                    if self.key_collides(py_key, model_extra_dict)? {
+                                        errors.push(ValLineError::new_with_loc(
+                                            ErrorType::ExtraKeyCollision {
+                                                key: py_key.to_string_lossy().into_owned(),
+                                                context: None,
+                                            },
+                                            value,
+                                            raw_key.as_loc_item(),
+                                        ));
+                                        continue;
                                     }
-                                } else {
-                                    model_extra_dict.set_item(py_key, value.to_object(py))?;
-                                    fields_set_vec.push(py_key.into_py(py));
-                                };
+                                    if let Some(ref coverage_counter) = self.extra_coverage_counter {
+                                        coverage_counter.increment();
+                                    }
+                                    if let Some(ref validator) = self.extras_validator {
+                                        match state.validate_item(py, validator, value, || raw_key.as_loc_item()) {
+                                            Ok(value) => {
+                                                model_extra_dict.set_item(py_key, value)?;
+                                                fields_set_vec.push(py_key.into_py(py));
+                                            }
+                                            Err(ValError::LineErrors(line_errors)) => {
+                                                for err in line_errors {
+                                                    errors.push(err.with_outer_location(raw_key.as_loc_item()));
+                                                }
+                                            }
+                                            Err(err) => return Err(err),
+                                        }
+                                    } else {
+                                        model_extra_dict.set_item(py_key, value.to_object(py))?;
+                                        fields_set_vec.push(py_key.into_py(py));
+                                    };
+                                }
                             }
                         }
-                    }
-                    if matches!(self.extra_behavior, ExtraBehavior::Allow) {
-                        model_extra_dict_op = Some(model_extra_dict);
-                    }
+                        if matches!(self.extra_behavior, ExtraBehavior::Allow) {
+                            model_extra_dict_op = Some(model_extra_dict);
+                        }
+                        Ok(())
+                    };
+                    validate_extras()?;
                 }
             }};
         }
@@ -318,6 +469,30 @@ impl Validator for ModelFieldsValidator {
             GenericMapping::StringMapping(d) => process!(d, py_get_string_mapping_item, StringMappingGenericIterator),
             GenericMapping::PyGetAttr(d, kwargs) => process!(d, py_get_attr, AttributesGenericIterator, kwargs),
             GenericMapping::JsonObject(d) => process!(d, json_get, JsonObjectGenericIterator),
+            GenericMapping::PyPairs(pairs_input, iter) => {
+                state.floor_exactness(super::Exactness::Lax);
+                let field_keys: AHashSet<&str> = self
+                    .fields
+                    .iter()
+                    .flat_map(|field| field.lookup_keys.select(lookup_type).first_keys())
+                    .collect();
+                let matches = &PairsMatches::new(pairs_input, iter, &field_keys, used_keys.is_some())?;
+                process!(matches, pairs_get, PairsMatchesIterator);
+            }
+        }
+
+        // fields only required depending on the values of other fields are checked once all fields are validated
+        for field in conditionally_missing {
+            if let Some(ref required_if) = field.required_if {
+                if required_if.applies(model_dict)? {
+                    errors.push(field.lookup_keys.select(lookup_type).error(
+                        ErrorTypeDefaults::Missing,
+                        input,
+                        self.loc_by_alias,
+                        &field.name,
+                    ));
+                }
+            }
         }
 
         if !errors.is_empty() {
@@ -335,23 +510,100 @@ impl Validator for ModelFieldsValidator {
         }
     }
 
-    fn validate_assignment<'data>(
+    fn validate_assignments<'data>(
         &self,
         py: Python<'data>,
         obj: &'data PyAny,
-        field_name: &'data str,
-        field_value: &'data PyAny,
+        assignments: &'data PyDict,
+        revalidate: bool,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        let dict: &PyDict = obj.downcast()?;
+        let new_data: &PyDict = obj.downcast()?;
+
+        // each field sees the values validated before it as `info.data`
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (field_name, field_value) in assignments {
+            let field_name: &str = field_name.extract()?;
+            match self.validate_field_assignment(py, new_data, field_name, field_value, revalidate, state) {
+                Ok(output) => new_data.set_item(field_name, output)?,
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => return Err(err),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
 
-        let get_updated_dict = |output: PyObject| {
-            dict.set_item(field_name, output)?;
-            Ok(dict)
+        let new_extra = match &self.extra_behavior {
+            ExtraBehavior::Allow => {
+                let non_extra_data = PyDict::new(py);
+                self.fields.iter().for_each(|f| {
+                    let popped_value = PyAny::get_item(new_data, &f.name).unwrap();
+                    new_data.del_item(&f.name).unwrap();
+                    non_extra_data.set_item(&f.name, popped_value).unwrap();
+                });
+                let new_extra = new_data.copy()?;
+                new_data.clear();
+                new_data.update(non_extra_data.as_mapping())?;
+                new_extra.to_object(py)
+            }
+            _ => py.None(),
         };
 
-        let prepare_result = |result: ValResult<PyObject>| match result {
-            Ok(output) => get_updated_dict(output),
+        let fields_set: &PySet = PySet::new(py, assignments.keys())?;
+        Ok((new_data.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl ModelFieldsValidator {
+    /// We convert the `DictType` error to a `ModelType` error
+    fn model_type_error(&self, err: ValError) -> ValError {
+        match err {
+            ValError::LineErrors(errors) => ValError::LineErrors(
+                errors
+                    .into_iter()
+                    .map(|e| match e.error_type {
+                        ErrorType::DictType { .. } => {
+                            let mut e = e;
+                            e.error_type = ErrorType::ModelType {
+                                class_name: self.model_name.clone(),
+                                context: None,
+                            };
+                            e
+                        }
+                        _ => e,
+                    })
+                    .collect(),
+            ),
+            err => err,
+        }
+    }
+
+    /// Whether a transformed or validated extra key would overwrite a field or another extra, keys can only
+    /// collide when they're changed before they're stored
+    fn key_collides(&self, key: &PyString, model_extra_dict: &PyDict) -> PyResult<bool> {
+        if self.extras_key_transform.is_none() && self.extras_keys_validator.is_none() {
+            return Ok(false);
+        }
+        let key_str = key.to_str()?;
+        Ok(self.fields.iter().any(|field| field.name == key_str) || model_extra_dict.contains(key)?)
+    }
+
+    /// Validate the new value of one field, the other values in `dict` are passed as `info.data`
+    fn validate_field_assignment<'data>(
+        &self,
+        py: Python<'data>,
+        dict: &'data PyDict,
+        field_name: &'data str,
+        field_value: &'data PyAny,
+        revalidate: bool,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let with_field_loc = |result: ValResult<PyObject>| match result {
             Err(ValError::LineErrors(line_errors)) => {
                 let errors = line_errors
                     .into_iter()
@@ -359,7 +611,7 @@ impl Validator for ModelFieldsValidator {
                     .collect();
                 Err(ValError::LineErrors(errors))
             }
-            Err(err) => Err(err),
+            result => result,
         };
 
         // by using dict but removing the field in question, we match V1 behaviour
@@ -376,15 +628,15 @@ impl Validator for ModelFieldsValidator {
             ..*state.extra()
         };
 
-        let new_data = if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
-            if field.frozen {
+        if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
+            if field.frozen && !revalidate {
                 Err(ValError::new_with_loc(
                     ErrorTypeDefaults::FrozenField,
                     field_value,
                     field.name.to_string(),
                 ))
             } else {
-                prepare_result(
+                with_field_loc(
                     state.with_new_extra(new_extra, |state| field.validator.validate(py, field_value, state)),
                 )
             }
@@ -396,45 +648,94 @@ impl Validator for ModelFieldsValidator {
             // unless the user explicitly set extra_behavior to 'allow'
             match self.extra_behavior {
                 ExtraBehavior::Allow => match self.extras_validator {
-                    Some(ref validator) => prepare_result(
+                    Some(ref validator) => with_field_loc(
                         state.with_new_extra(new_extra, |state| validator.validate(py, field_value, state)),
                     ),
-                    None => get_updated_dict(field_value.to_object(py)),
+                    None => Ok(field_value.to_object(py)),
                 },
-                ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
-                    return Err(ValError::new_with_loc(
-                        ErrorType::NoSuchAttribute {
-                            attribute: field_name.to_string(),
-                            context: None,
-                        },
-                        field_value,
-                        field_name.to_string(),
-                    ))
-                }
+                ExtraBehavior::Forbid | ExtraBehavior::Ignore => Err(ValError::new_with_loc(
+                    ErrorType::NoSuchAttribute {
+                        attribute: field_name.to_string(),
+                        context: None,
+                    },
+                    field_value,
+                    field_name.to_string(),
+                )),
             }
-        }?;
+        }
+    }
 
-        let new_extra = match &self.extra_behavior {
-            ExtraBehavior::Allow => {
-                let non_extra_data = PyDict::new(py);
-                self.fields.iter().for_each(|f| {
-                    let popped_value = PyAny::get_item(new_data, &f.name).unwrap();
-                    new_data.del_item(&f.name).unwrap();
-                    non_extra_data.set_item(&f.name, popped_value).unwrap();
-                });
-                let new_extra = new_data.copy()?;
-                new_data.clear();
-                new_data.update(non_extra_data.as_mapping())?;
-                new_extra.to_object(py)
-            }
-            _ => py.None(),
+    /// Fast path for a dict whose keys are exactly the field names, where lookup keys and extra handling
+    /// aren't needed, returns `None` if the fast path doesn't apply
+    fn validate_exact_dict<'data>(
+        &self,
+        py: Python<'data>,
+        dict: &'data PyDict,
+        state: &mut ValidationState,
+    ) -> ValResult<Option<PyObject>> {
+        let Some(ref all_fields_set) = self.all_fields_set else {
+            return Ok(None);
         };
+        if dict.len() != self.fields.len() {
+            return Ok(None);
+        }
+        let mut values: Vec<&PyAny> = Vec::with_capacity(self.fields.len());
+        for field in &self.fields {
+            match dict.get_item(&field.name_py)? {
+                Some(value) => values.push(value),
+                None => return Ok(None),
+            }
+        }
 
-        let fields_set: &PySet = PySet::new(py, &[field_name.to_string()])?;
-        Ok((new_data.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
-    }
+        let model_dict = PyDict::new(py);
+        let fields_set: &PySet = all_fields_set
+            .as_ref(py)
+            .call_method0(intern!(py, "copy"))?
+            .downcast()?;
+        let mut errors: Vec<ValLineError> = Vec::new();
+
+        state.with_new_extra(
+            Extra {
+                data: Some(model_dict),
+                ..*state.extra()
+            },
+            |state| -> ValResult<()> {
+                for (field, value) in self.fields.iter().zip(values) {
+                    state.check_item(py, dict.as_ref(), || field.name.as_loc_item())?;
+                    if let Some(ref coverage_counter) = field.coverage_counter {
+                        coverage_counter.increment();
+                    }
+                    match state.validate_item(py, &field.validator, value, || field.name.as_loc_item()) {
+                        Ok(value) => {
+                            // `UNSET` fields count as not provided
+                            if UnsetType::is_unset(value.as_ref(py)) {
+                                fields_set.discard(&field.name_py)?;
+                            }
+                            model_dict.set_item(&field.name_py, value)?;
+                        }
+                        Err(ValError::Omit) => {
+                            fields_set.discard(&field.name_py)?;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            for err in line_errors {
+                                errors.push(err.with_outer_location(field.name.as_loc_item()));
+                            }
+                        }
+                        // as in `validate`, a timeout's location is added here
+                        Err(err) => return Err(err.with_outer_location(field.name.as_loc_item())),
+                    }
+                }
+                Ok(())
+            },
+        )?;
 
-    fn get_name(&self) -> &str {
-        Self::EXPECTED_TYPE
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        let model_extra_dict_op = match self.extra_behavior {
+            ExtraBehavior::Allow => Some(PyDict::new(py)),
+            _ => None,
+        };
+        Ok(Some((model_dict, model_extra_dict_op, fields_set).to_object(py)))
     }
 }
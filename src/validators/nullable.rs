@@ -2,12 +2,14 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use jiter::Peak;
+
 use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::ValidationState;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, JsonDirect, Validator};
 
 #[derive(Debug)]
 pub struct NullableValidator {
@@ -45,6 +47,22 @@ impl Validator for NullableValidator {
         }
     }
 
+    fn validate_json_direct(
+        &self,
+        py: Python<'_>,
+        json: &mut JsonDirect,
+        peak: Peak,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        match peak {
+            Peak::Null => {
+                json.null()?;
+                Ok(py.None())
+            }
+            _ => self.validator.validate_json_direct(py, json, peak, state),
+        }
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -0,0 +1,88 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString};
+
+use crate::build_tools::py_schema_err;
+
+/// Conditions on sibling fields under which a field which would otherwise be optional must be provided,
+/// used by the `typed-dict` and `model-fields` validators
+#[derive(Debug)]
+pub(super) struct RequiredIf {
+    // the field is required if all the fields of any one condition have the given values
+    conditions: Vec<Vec<FieldEquals>>,
+}
+
+#[derive(Debug)]
+struct FieldEquals {
+    field: Py<PyString>,
+    value: PyObject,
+}
+
+impl FieldEquals {
+    fn matches(&self, output: &PyDict) -> PyResult<bool> {
+        let py = output.py();
+        match output.get_item(self.field.as_ref(py))? {
+            Some(value) => value.eq(self.value.as_ref(py)),
+            None => Ok(false),
+        }
+    }
+}
+
+impl RequiredIf {
+    /// `required_if` from a field's schema, checking it only refers to other fields in `field_names`
+    pub fn from_field_info(field_info: &PyDict, field_name: &str, field_names: &[&str]) -> PyResult<Option<Self>> {
+        let py = field_info.py();
+        let Some(required_if) = field_info.get_item(intern!(py, "required_if"))? else {
+            return Ok(None);
+        };
+        let condition_dicts: Vec<&PyDict> = match required_if.downcast::<PyList>() {
+            Ok(list) => list.iter().map(PyAny::downcast).collect::<Result<_, _>>()?,
+            Err(_) => vec![required_if.downcast()?],
+        };
+        if condition_dicts.is_empty() || condition_dicts.iter().any(|condition| condition.is_empty()) {
+            return py_schema_err!("Field '{}': required_if conditions can't be empty", field_name);
+        }
+        let mut conditions = Vec::with_capacity(condition_dicts.len());
+        for condition in condition_dicts {
+            let mut items = Vec::with_capacity(condition.len());
+            for (other_field, value) in condition {
+                let other_field: &PyString = other_field.downcast()?;
+                let other_field_str = other_field.to_str()?;
+                if other_field_str == field_name {
+                    return py_schema_err!("Field '{}': required_if can't refer to the field itself", field_name);
+                }
+                if !field_names.contains(&other_field_str) {
+                    return py_schema_err!(
+                        "Field '{}': required_if refers to unknown field '{}'",
+                        field_name,
+                        other_field_str
+                    );
+                }
+                items.push(FieldEquals {
+                    field: other_field.into_py(py),
+                    value: value.into_py(py),
+                });
+            }
+            conditions.push(items);
+        }
+        Ok(Some(Self { conditions }))
+    }
+
+    /// Whether the field is required given the validated values of the other fields, fields which are missing
+    /// or failed validation never match
+    pub fn applies(&self, output: &PyDict) -> PyResult<bool> {
+        'conditions: for condition in &self.conditions {
+            for field_equals in condition {
+                if !field_equals.matches(output)? {
+                    continue 'conditions;
+                }
+            }
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+impl_py_gc_traverse!(FieldEquals { field, value });
+
+impl_py_gc_traverse!(RequiredIf { conditions });
@@ -0,0 +1,50 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::ValidationState;
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
+
+/// Validates the raw value with the inner `str` or `bytes` schema, the difference from the inner schema is
+/// only in serialization where the value is masked unless `reveal_secrets` is set
+#[derive(Debug)]
+pub struct SecretValidator {
+    validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for SecretValidator {
+    const EXPECTED_TYPE: &'static str = "secret";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
+        let validator = Box::new(build_validator(schema, config, definitions)?);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+        Ok(Self { validator, name }.into())
+    }
+}
+
+impl_py_gc_traverse!(SecretValidator { validator });
+
+impl Validator for SecretValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        self.validator.validate(py, input, state)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
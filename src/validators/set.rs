@@ -15,6 +15,8 @@ pub struct SetValidator {
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items: bool,
+    deterministic: bool,
     name: String,
 }
 
@@ -42,6 +44,8 @@ macro_rules! set_build {
                 item_validator,
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
+                unique_items: schema.get_as(pyo3::intern!(py, "unique_items"))?.unwrap_or(false),
+                deterministic: crate::build_tools::is_deterministic(config)?,
                 name,
             }
             .into())
@@ -71,8 +75,22 @@ impl Validator for SetValidator {
             _ => Exactness::Lax,
         };
         state.floor_exactness(exactness);
+        let collection = if self.deterministic {
+            collection.into_deterministic()?
+        } else {
+            collection
+        };
         let set = PySet::empty(py)?;
-        collection.validate_to_set(py, set, input, self.max_length, "Set", &self.item_validator, state)?;
+        collection.validate_to_set(
+            py,
+            set,
+            input,
+            self.max_length,
+            self.unique_items,
+            "Set",
+            &self.item_validator,
+            state,
+        )?;
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
     }
@@ -0,0 +1,75 @@
+use ahash::AHashMap;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyList, PyString, PyTuple};
+
+use crate::errors::{AsLocItem, LocItem};
+
+/// Call time overrides of strictness for individual fields, built from the `strict_fields` and `lax_fields`
+/// arguments, stored as a tree keyed by location items so it can be walked as validation descends into
+/// fields and items.
+#[derive(Debug, Default)]
+pub struct StrictOverrides {
+    /// strictness of this location and everything inside it, unless overridden again further down
+    strict: Option<bool>,
+    children: AHashMap<LocItem, StrictOverrides>,
+}
+
+impl StrictOverrides {
+    pub fn build(strict_fields: Option<&PyAny>, lax_fields: Option<&PyAny>) -> PyResult<Option<Self>> {
+        if strict_fields.is_none() && lax_fields.is_none() {
+            return Ok(None);
+        }
+        let mut root = Self::default();
+        for (locs, strict) in [(lax_fields, false), (strict_fields, true)] {
+            if let Some(locs) = locs {
+                for loc in locs.iter()? {
+                    root.insert(loc_items(loc?)?, strict)?;
+                }
+            }
+        }
+        Ok(Some(root))
+    }
+
+    fn insert(&mut self, loc: Vec<LocItem>, strict: bool) -> PyResult<()> {
+        if loc.is_empty() {
+            return Err(PyTypeError::new_err("Field locations must not be empty"));
+        }
+        let node = loc
+            .into_iter()
+            .fold(self, |node, item| node.children.entry(item).or_default());
+        match node.strict {
+            Some(existing) if existing != strict => Err(PyTypeError::new_err(
+                "The same field location cannot be in both `strict_fields` and `lax_fields`",
+            )),
+            _ => {
+                node.strict = Some(strict);
+                Ok(())
+            }
+        }
+    }
+
+    /// The overrides for the item at `loc` inside this location, if any
+    pub fn get(&self, loc: &LocItem) -> Option<&Self> {
+        self.children.get(loc)
+    }
+
+    pub fn strict(&self) -> Option<bool> {
+        self.strict
+    }
+}
+
+/// A location is either a single field name, or a list or tuple of strings and ints
+fn loc_items(loc: &PyAny) -> PyResult<Vec<LocItem>> {
+    if loc.downcast::<PyString>().is_ok() {
+        Ok(vec![loc.as_loc_item()])
+    } else if let Ok(tuple) = loc.downcast::<PyTuple>() {
+        Ok(tuple.iter().map(AsLocItem::as_loc_item).collect())
+    } else if let Ok(list) = loc.downcast::<PyList>() {
+        Ok(list.iter().map(AsLocItem::as_loc_item).collect())
+    } else {
+        Err(PyTypeError::new_err(
+            "Field locations must be strings, or lists or tuples of strings and ints",
+        ))
+    }
+}
@@ -1,9 +1,14 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config};
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
@@ -62,9 +67,13 @@ impl Validator for StrValidator {
 pub struct StrConstrainedValidator {
     strict: bool,
     pattern: Option<Pattern>,
+    format: Option<StrFormat>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    length_unit: LengthUnit,
     strip_whitespace: bool,
+    normalize: Option<NormalizationForm>,
+    casefold: bool,
     to_lower: bool,
     to_upper: bool,
     coerce_numbers_to_str: bool,
@@ -83,41 +92,44 @@ impl Validator for StrConstrainedValidator {
             .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
             .unpack(state);
         let cow = either_str.as_cow()?;
-        let mut str = cow.as_ref();
-        if self.strip_whitespace {
-            str = str.trim();
+        let mut str = if self.strip_whitespace {
+            Cow::Borrowed(cow.trim())
+        } else {
+            Cow::Borrowed(cow.as_ref())
+        };
+        if let Some(normalize) = self.normalize {
+            str = Cow::Owned(normalize.apply(&str));
+        }
+        if self.casefold {
+            // Rust has no full case folding, Python's `str.casefold` is used so the result matches it exactly
+            let folded = PyString::new(py, &str).call_method0(intern!(py, "casefold"))?;
+            str = Cow::Owned(folded.extract()?);
         }
 
         let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
-            Some(str.chars().count())
+            Some(self.length_unit.length(&str))
         } else {
             None
         };
         if let Some(min_length) = self.min_length {
             if str_len.unwrap() < min_length {
-                return Err(ValError::new(
-                    ErrorType::StringTooShort {
-                        min_length,
-                        context: None,
-                    },
-                    input,
-                ));
+                return Err(ValError::new(self.length_unit.too_short(min_length), input));
             }
         }
         if let Some(max_length) = self.max_length {
             if str_len.unwrap() > max_length {
-                return Err(ValError::new(
-                    ErrorType::StringTooLong {
-                        max_length,
-                        context: None,
-                    },
-                    input,
-                ));
+                return Err(ValError::new(self.length_unit.too_long(max_length), input));
+            }
+        }
+
+        if let Some(format) = self.format {
+            if !format.is_match(&str) {
+                return Err(ValError::new(format.error_type(), input));
             }
         }
 
         if let Some(pattern) = &self.pattern {
-            if !pattern.is_match(py, str)? {
+            if !pattern.is_match(py, &str)? {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
                         pattern: pattern.pattern.clone(),
@@ -132,8 +144,10 @@ impl Validator for StrConstrainedValidator {
             PyString::new(py, &str.to_lowercase())
         } else if self.to_upper {
             PyString::new(py, &str.to_uppercase())
+        } else if let Cow::Owned(str) = str {
+            PyString::new(py, &str)
         } else if self.strip_whitespace {
-            PyString::new(py, str)
+            PyString::new(py, &str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
             either_str.as_py_string(py)
@@ -159,11 +173,25 @@ impl StrConstrainedValidator {
                 Pattern::compile(py, s, regex_engine)
             })
             .transpose()?;
+        let format = schema
+            .get_as::<&str>(intern!(py, "format"))?
+            .map(StrFormat::from_str)
+            .transpose()?;
         let min_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "min_length"), intern!(py, "str_min_length"))?;
         let max_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?;
 
+        let length_unit = schema_or_config::<&str>(
+            schema,
+            config,
+            intern!(py, "length_unit"),
+            intern!(py, "str_length_unit"),
+        )?
+        .map(LengthUnit::from_str)
+        .transpose()?
+        .unwrap_or_default();
+
         let strip_whitespace: bool = schema_or_config(
             schema,
             config,
@@ -171,6 +199,12 @@ impl StrConstrainedValidator {
             intern!(py, "str_strip_whitespace"),
         )?
         .unwrap_or(false);
+        let normalize =
+            schema_or_config::<&str>(schema, config, intern!(py, "normalize"), intern!(py, "str_normalize"))?
+                .map(NormalizationForm::from_str)
+                .transpose()?;
+        let casefold: bool =
+            schema_or_config(schema, config, intern!(py, "casefold"), intern!(py, "str_casefold"))?.unwrap_or(false);
         let to_lower: bool =
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
@@ -184,9 +218,13 @@ impl StrConstrainedValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             pattern,
+            format,
             min_length,
             max_length,
+            length_unit,
             strip_whitespace,
+            normalize,
+            casefold,
             to_lower,
             to_upper,
             coerce_numbers_to_str,
@@ -197,14 +235,167 @@ impl StrConstrainedValidator {
     // except strict which can be set on StrValidator
     fn has_constraints_set(&self) -> bool {
         self.pattern.is_some()
+            || self.format.is_some()
             || self.max_length.is_some()
             || self.min_length.is_some()
             || self.strip_whitespace
+            || self.normalize.is_some()
+            || self.casefold
             || self.to_lower
             || self.to_upper
     }
 }
 
+/// A common string format checked natively with `format`, rather than with a much slower `pattern`
+#[derive(Debug, Clone, Copy)]
+enum StrFormat {
+    /// an international phone number, `+` followed by a country code and subscriber number, e.g. `+14155552671`
+    E164,
+}
+
+/// E.164 numbers have at most 15 digits, the country code can't start with 0
+const E164_MAX_DIGITS: usize = 15;
+
+impl FromStr for StrFormat {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e164" => Ok(Self::E164),
+            s => py_schema_err!("Invalid str format: `{}`, expected `e164`", s),
+        }
+    }
+}
+
+impl StrFormat {
+    fn is_match(self, value: &str) -> bool {
+        match self {
+            Self::E164 => match value.strip_prefix('+').map(str::as_bytes) {
+                Some([b'1'..=b'9', rest @ ..]) => {
+                    !rest.is_empty() && rest.len() < E164_MAX_DIGITS && rest.iter().all(u8::is_ascii_digit)
+                }
+                _ => false,
+            },
+        }
+    }
+
+    fn error_type(self) -> ErrorType {
+        match self {
+            Self::E164 => ErrorType::StringE164Format { context: None },
+        }
+    }
+}
+
+/// Unicode normalization form applied with `normalize`
+#[derive(Debug, Clone, Copy)]
+enum NormalizationForm {
+    Nfc,
+    Nfkc,
+    Nfd,
+    Nfkd,
+}
+
+impl FromStr for NormalizationForm {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NFC" => Ok(Self::Nfc),
+            "NFKC" => Ok(Self::Nfkc),
+            "NFD" => Ok(Self::Nfd),
+            "NFKD" => Ok(Self::Nfkd),
+            s => py_schema_err!(
+                "Invalid normalize form: `{}`, expected `NFC`, `NFKC`, `NFD` or `NFKD`",
+                s
+            ),
+        }
+    }
+}
+
+impl NormalizationForm {
+    fn apply(self, str: &str) -> String {
+        match self {
+            Self::Nfc => str.nfc().collect(),
+            Self::Nfkc => str.nfkc().collect(),
+            Self::Nfd => str.nfd().collect(),
+            Self::Nfkd => str.nfkd().collect(),
+        }
+    }
+}
+
+/// How `min_length` and `max_length` count the length of a string
+#[derive(Debug, Clone, Copy, Default)]
+enum LengthUnit {
+    #[default]
+    Codepoints,
+    Bytes,
+    Graphemes,
+}
+
+impl FromStr for LengthUnit {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "codepoints" => Ok(Self::Codepoints),
+            "bytes" => Ok(Self::Bytes),
+            "graphemes" => Ok(Self::Graphemes),
+            s => py_schema_err!(
+                "Invalid length_unit: `{}`, expected `codepoints`, `bytes` or `graphemes`",
+                s
+            ),
+        }
+    }
+}
+
+impl LengthUnit {
+    fn length(self, str: &str) -> usize {
+        match self {
+            Self::Codepoints => str.chars().count(),
+            // the length of the UTF-8 encoding
+            Self::Bytes => str.len(),
+            Self::Graphemes => str.graphemes(true).count(),
+        }
+    }
+
+    /// The name of the unit in error messages, `None` for code points which are described as characters
+    fn name(self) -> Option<&'static str> {
+        match self {
+            Self::Codepoints => None,
+            Self::Bytes => Some("byte"),
+            Self::Graphemes => Some("grapheme"),
+        }
+    }
+
+    fn too_short(self, min_length: usize) -> ErrorType {
+        match self.name() {
+            Some(unit) => ErrorType::StringUnitsTooShort {
+                min_length,
+                unit: unit.to_string(),
+                context: None,
+            },
+            None => ErrorType::StringTooShort {
+                min_length,
+                context: None,
+            },
+        }
+    }
+
+    fn too_long(self, max_length: usize) -> ErrorType {
+        match self.name() {
+            Some(unit) => ErrorType::StringUnitsTooLong {
+                max_length,
+                unit: unit.to_string(),
+                context: None,
+            },
+            None => ErrorType::StringTooLong {
+                max_length,
+                context: None,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Pattern {
     pattern: String,
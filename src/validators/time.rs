@@ -4,7 +4,7 @@ use pyo3::types::{PyDict, PyString, PyTime};
 
 use speedate::Time;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{EitherTime, Input};
 use crate::tools::SchemaDict;
@@ -109,7 +109,12 @@ impl TimeConstraints {
             lt: convert_pytime(schema, intern!(py, "lt"))?,
             ge: convert_pytime(schema, intern!(py, "ge"))?,
             gt: convert_pytime(schema, intern!(py, "gt"))?,
-            tz: TZConstraint::from_py(schema)?,
+            tz: match TZConstraint::from_py(schema)? {
+                Some(TZConstraint::Zone(_)) => {
+                    return py_schema_err!("IANA timezone names are not supported as a time `tz_constraint`")
+                }
+                tz => tz,
+            },
         };
         if c.le.is_some() || c.lt.is_some() || c.ge.is_some() || c.gt.is_some() || c.tz.is_some() {
             Ok(Some(c))
@@ -2,13 +2,13 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_deterministic, is_strict, py_schema_err};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{GenericIterable, Input};
 use crate::tools::SchemaDict;
 use crate::validators::Exactness;
 
-use super::list::{get_items_schema, min_length_check};
+use super::list::{check_unique_items, get_items_schema, min_length_check};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -17,6 +17,8 @@ pub struct TupleVariableValidator {
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    unique_items: bool,
+    deterministic: bool,
     name: String,
 }
 
@@ -36,6 +38,8 @@ impl BuildValidator for TupleVariableValidator {
             item_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            unique_items: schema.get_as(intern!(py, "unique_items"))?.unwrap_or(false),
+            deterministic: is_deterministic(config)?,
             name,
         }
         .into())
@@ -58,12 +62,20 @@ impl Validator for TupleVariableValidator {
             _ => Exactness::Lax,
         };
         state.floor_exactness(exactness);
+        let seq = if self.deterministic {
+            seq.into_deterministic()?
+        } else {
+            seq
+        };
 
         let output = match self.item_validator {
             Some(ref v) => seq.validate_to_vec(py, input, self.max_length, "Tuple", v, state)?,
             None => seq.to_vec(py, input, "Tuple", self.max_length)?,
         };
         min_length_check!(input, "Tuple", self.min_length, output);
+        if self.unique_items {
+            check_unique_items(py, &output, input, "Tuple")?;
+        }
         Ok(PyTuple::new(py, &output).into_py(py))
     }
 
@@ -77,6 +89,8 @@ pub struct TuplePositionalValidator {
     strict: bool,
     items_validators: Vec<CombinedValidator>,
     extras_validator: Option<Box<CombinedValidator>>,
+    variadic_item_index: Option<usize>,
+    deterministic: bool,
     name: String,
 }
 
@@ -94,18 +108,41 @@ impl BuildValidator for TuplePositionalValidator {
             .map(|item| build_validator(item, config, definitions))
             .collect::<PyResult<_>>()?;
 
+        let extras_validator = match schema.get_item(intern!(py, "extras_schema"))? {
+            Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
+            None => None,
+        };
+        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
+        if let Some(index) = variadic_item_index {
+            if extras_validator.is_some() {
+                return py_schema_err!("`variadic_item_index` can't be combined with `extras_schema`");
+            }
+            if index >= validators.len() {
+                return py_schema_err!(
+                    "`variadic_item_index` {} is out of range for {} items",
+                    index,
+                    validators.len()
+                );
+            }
+        }
+
         let descr = validators
             .iter()
-            .map(Validator::get_name)
+            .enumerate()
+            .map(|(index, validator)| match variadic_item_index {
+                Some(variadic_item_index) if index == variadic_item_index => {
+                    format!("*tuple[{}, ...]", validator.get_name())
+                }
+                _ => validator.get_name().to_string(),
+            })
             .collect::<Vec<_>>()
             .join(", ");
         Ok(Self {
             strict: is_strict(schema, config)?,
             items_validators: validators,
-            extras_validator: match schema.get_item(intern!(py, "extras_schema"))? {
-                Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
-                None => None,
-            },
+            extras_validator,
+            variadic_item_index,
+            deterministic: is_deterministic(config)?,
             name: format!("tuple[{descr}]"),
         }
         .into())
@@ -126,13 +163,17 @@ fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>,
 ) -> ValResult<()> {
     for (index, validator) in items_validators.iter().enumerate() {
         match collection_iter.next() {
-            Some(result) => match validator.validate(py, result?, state) {
-                Ok(item) => output.push(item),
-                Err(ValError::LineErrors(line_errors)) => {
-                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+            Some(result) => {
+                let item = result?;
+                state.check_item(py, input, || index.into())?;
+                match state.validate_item(py, validator, item, || index.into()) {
+                    Ok(item) => output.push(item),
+                    Err(ValError::LineErrors(line_errors)) => {
+                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                    }
+                    Err(err) => return Err(err.with_outer_location(index.into())),
                 }
-                Err(err) => return Err(err),
-            },
+            }
             None => {
                 if let Some(value) = validator.default_value(py, Some(index), state)? {
                     output.push(value);
@@ -144,19 +185,22 @@ fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>,
     }
     for (index, result) in collection_iter.enumerate() {
         let item = result?;
+        state.check_item(py, input, || (index + items_validators.len()).into())?;
         match extras_validator {
-            Some(ref extras_validator) => match extras_validator.validate(py, item, state) {
-                Ok(item) => output.push(item),
-                Err(ValError::LineErrors(line_errors)) => {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location((index + items_validators.len()).into())),
-                    );
+            Some(ref extras_validator) => {
+                match state.validate_item(py, extras_validator, item, || (index + items_validators.len()).into()) {
+                    Ok(item) => output.push(item),
+                    Err(ValError::LineErrors(line_errors)) => {
+                        errors.extend(
+                            line_errors
+                                .into_iter()
+                                .map(|err| err.with_outer_location((index + items_validators.len()).into())),
+                        );
+                    }
+                    Err(ValError::Omit) => (),
+                    Err(err) => return Err(err.with_outer_location((index + items_validators.len()).into())),
                 }
-                Err(ValError::Omit) => (),
-                Err(err) => return Err(err),
-            },
+            }
             None => {
                 errors.push(ValLineError::new(
                     ErrorType::TooLong {
@@ -175,6 +219,64 @@ fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>,
     Ok(())
 }
 
+/// Like `validate_tuple_positional`, but the item at `variadic_item_index` matches any number of items, so the
+/// items after it are matched from the end of the input. Items missing from shorter inputs are filled in with
+/// their defaults, those before the variadic item first.
+#[allow(clippy::too_many_arguments)]
+fn validate_tuple_variadic<'data, T: Iterator<Item = PyResult<&'data I>>, I: Input<'data> + 'data>(
+    py: Python<'data>,
+    input: &'data impl Input<'data>,
+    state: &mut ValidationState,
+    output: &mut Vec<PyObject>,
+    errors: &mut Vec<ValLineError>,
+    items_validators: &[CombinedValidator],
+    variadic_item_index: usize,
+    collection_iter: T,
+) -> ValResult<()> {
+    let items = collection_iter.collect::<PyResult<Vec<_>>>()?;
+    let variadic_count = items.len().saturating_sub(items_validators.len() - 1);
+    let mut items = items.into_iter().enumerate();
+
+    macro_rules! validate_item {
+        ($validator:expr, $index:expr, $item:expr) => {{
+            let index: usize = $index;
+            state.check_item(py, input, || index.into())?;
+            match state.validate_item(py, $validator, $item, || index.into()) {
+                Ok(item) => output.push(item),
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                }
+                Err(ValError::Omit) => (),
+                Err(err) => return Err(err),
+            }
+        }};
+    }
+
+    // the index in the output of the next item, where a missing item is reported
+    let mut position = 0;
+    for (validator_index, validator) in items_validators.iter().enumerate() {
+        if validator_index == variadic_item_index {
+            for (index, item) in items.by_ref().take(variadic_count) {
+                validate_item!(validator, index, item);
+            }
+            position += variadic_count;
+            continue;
+        }
+        match items.next() {
+            Some((index, item)) => validate_item!(validator, index, item),
+            None => {
+                if let Some(value) = validator.default_value(py, Some(position), state)? {
+                    output.push(value);
+                } else {
+                    errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, input, position));
+                }
+            }
+        }
+        position += 1;
+    }
+    Ok(())
+}
+
 impl_py_gc_traverse!(TuplePositionalValidator {
     items_validators,
     extras_validator
@@ -194,6 +296,11 @@ impl Validator for TuplePositionalValidator {
             _ => Exactness::Lax,
         };
         state.floor_exactness(exactness);
+        let collection = if self.deterministic {
+            collection.into_deterministic()?
+        } else {
+            collection
+        };
 
         let actual_length = collection.generic_len();
         let expected_length = if self.extras_validator.is_some() {
@@ -207,17 +314,29 @@ impl Validator for TuplePositionalValidator {
 
         macro_rules! iter {
             ($collection_iter:expr) => {{
-                validate_tuple_positional(
-                    py,
-                    input,
-                    state,
-                    &mut output,
-                    &mut errors,
-                    &self.extras_validator,
-                    &self.items_validators,
-                    &mut $collection_iter,
-                    actual_length,
-                )?
+                match self.variadic_item_index {
+                    Some(variadic_item_index) => validate_tuple_variadic(
+                        py,
+                        input,
+                        state,
+                        &mut output,
+                        &mut errors,
+                        &self.items_validators,
+                        variadic_item_index,
+                        $collection_iter,
+                    )?,
+                    None => validate_tuple_positional(
+                        py,
+                        input,
+                        state,
+                        &mut output,
+                        &mut errors,
+                        &self.extras_validator,
+                        &self.items_validators,
+                        &mut $collection_iter,
+                        actual_length,
+                    )?,
+                }
             }};
         }
 
@@ -7,15 +7,18 @@ use pyo3::types::{PyDict, PyString};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
-use crate::errors::{AsLocItem, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::build_tools::{
+    generate_alias, is_strict, schema_or_config, schema_or_config_same, ExtraBehavior, ExtrasKeyTransform,
+};
+use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
     AttributesGenericIterator, BorrowInput, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
-    MappingGenericIterator, StringMappingGenericIterator, ValidationMatch,
+    MappingGenericIterator, PairsMatches, PairsMatchesIterator, StringMappingGenericIterator, ValidationMatch,
 };
-use crate::lookup_key::LookupKey;
+use crate::lookup_key::{LookupKeyCollection, LookupType};
 use crate::tools::SchemaDict;
 
+use super::required_if::RequiredIf;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
 };
@@ -23,20 +26,28 @@ use super::{
 #[derive(Debug)]
 struct TypedDictField {
     name: String,
-    lookup_key: LookupKey,
+    lookup_keys: LookupKeyCollection,
     name_py: Py<PyString>,
     required: bool,
+    // required when other fields have certain values, even though `required` is false or there's a default
+    required_if: Option<RequiredIf>,
     validator: CombinedValidator,
 }
 
-impl_py_gc_traverse!(TypedDictField { validator });
+impl_py_gc_traverse!(TypedDictField { validator, required_if });
 
 #[derive(Debug)]
 pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    extras_keys_validator: Option<Box<CombinedValidator>>,
+    extras_key_transform: Option<ExtrasKeyTransform>,
+    closed: bool,
     strict: bool,
+    // accept dict items views and iterables of `(key, value)` pairs in lax mode
+    from_pairs: bool,
+    populate_by_name: bool,
     loc_by_alias: bool,
 }
 
@@ -59,16 +70,24 @@ impl BuildValidator for TypedDictValidator {
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
 
-        let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
+        let closed = schema.get_as(intern!(py, "closed"))?.unwrap_or(false);
+        let extra_behavior = ExtraBehavior::from_typed_dict_schema(py, schema, config)?;
 
         let extras_validator = match (schema.get_item(intern!(py, "extras_schema"))?, &extra_behavior) {
             (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, definitions)?)),
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let extras_keys_validator = match (schema.get_item(intern!(py, "extras_keys_schema"))?, &extra_behavior) {
+            (Some(v), ExtraBehavior::Allow) => Some(Box::new(build_validator(v, config, definitions)?)),
+            (Some(_), _) => return py_schema_err!("extras_keys_schema can only be used if extra_behavior=allow"),
+            (_, _) => None,
+        };
+        let extras_key_transform = ExtrasKeyTransform::from_schema(py, schema, &extra_behavior)?;
 
         let fields_dict: &PyDict = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
+        let field_names: Vec<&str> = fields_dict.keys().extract()?;
 
         for (key, value) in fields_dict {
             let field_info: &PyDict = value.downcast()?;
@@ -109,20 +128,17 @@ impl BuildValidator for TypedDictValidator {
                 }
             }
 
-            let lookup_key = match field_info.get_item(intern!(py, "validation_alias"))? {
-                Some(alias) => {
-                    let alt_alias = if populate_by_name { Some(field_name) } else { None };
-                    LookupKey::from_py(py, alias, alt_alias)?
-                }
-                None => LookupKey::from_string(py, field_name),
+            let validation_alias = match field_info.get_item(intern!(py, "validation_alias"))? {
+                Some(alias) => Some(alias),
+                None => generate_alias(py, config, field_name)?.map(PyString::as_ref),
             };
-
             fields.push(TypedDictField {
                 name: field_name.to_string(),
-                lookup_key,
+                lookup_keys: LookupKeyCollection::new(py, validation_alias, field_name)?,
                 name_py: PyString::new(py, field_name).into(),
                 validator,
                 required,
+                required_if: RequiredIf::from_field_info(field_info, field_name, &field_names)?,
             });
         }
 
@@ -130,7 +146,12 @@ impl BuildValidator for TypedDictValidator {
             fields,
             extra_behavior,
             extras_validator,
+            extras_keys_validator,
+            extras_key_transform,
+            closed,
             strict,
+            from_pairs: schema.get_as(intern!(py, "from_pairs"))?.unwrap_or(false),
+            populate_by_name,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -139,7 +160,9 @@ impl BuildValidator for TypedDictValidator {
 
 impl_py_gc_traverse!(TypedDictValidator {
     fields,
-    extras_validator
+    extras_validator,
+    extras_keys_validator,
+    extras_key_transform
 });
 
 impl Validator for TypedDictValidator {
@@ -150,10 +173,15 @@ impl Validator for TypedDictValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let dict = input.validate_dict(strict)?;
+        let dict = match input.validate_dict(strict) {
+            Err(err) if self.from_pairs && !strict => input.lax_pairs().ok_or(err)?,
+            result => result?,
+        };
 
         let output_dict = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
+        let mut conditionally_missing: Vec<&TypedDictField> = Vec::new();
+        let lookup_type = LookupType::new(state.extra().by_alias, state.extra().by_name, self.populate_by_name);
 
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
@@ -179,7 +207,11 @@ impl Validator for TypedDictValidator {
                     ..*state.extra()
                 }, |state| {
                     for field in &self.fields {
-                        let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
+                        if let Err(err) = state.check_item(py, input, || field.name.as_loc_item()) {
+                            return ControlFlow::Break(err);
+                        }
+                        let lookup_key = field.lookup_keys.select(lookup_type);
+                        let op_key_value = match lookup_key.$get_method($dict $(, $kwargs )? ) {
                             Ok(v) => v,
                             Err(ValError::LineErrors(line_errors)) => {
                                 for err in line_errors {
@@ -195,7 +227,8 @@ impl Validator for TypedDictValidator {
                                 // extra logic either way
                                 used_keys.insert(lookup_path.first_key());
                             }
-                            match field.validator.validate(py, value.borrow_input(), state) {
+                            let field_loc = || field.name.as_loc_item();
+                            match state.validate_item(py, &field.validator, value.borrow_input(), field_loc) {
                                 Ok(value) => {
                                     control_flow!(output_dict.set_item(&field.name_py, value))?;
                                 }
@@ -208,7 +241,10 @@ impl Validator for TypedDictValidator {
                                         );
                                     }
                                 }
-                                Err(err) => return ControlFlow::Break(err),
+                                // a timeout isn't collected with other errors, so its location is added here
+                                Err(err) => {
+                                    return ControlFlow::Break(err.with_outer_location(field.name.as_loc_item()))
+                                }
                             }
                             continue;
                         }
@@ -216,17 +252,22 @@ impl Validator for TypedDictValidator {
                         match field.validator.default_value(py, Some(field.name.as_str()), state) {
                             Ok(Some(value)) => {
                                 // Default value exists, and passed validation if required
+                                if field.required_if.is_some() {
+                                    conditionally_missing.push(field);
+                                }
                                 control_flow!(output_dict.set_item(&field.name_py, value))?;
                             },
                             Ok(None) => {
                                 // This means there was no default value
                                 if (field.required) {
-                                    errors.push(field.lookup_key.error(
+                                    errors.push(lookup_key.error(
                                         ErrorTypeDefaults::Missing,
                                         input,
                                         self.loc_by_alias,
                                         &field.name
                                     ));
+                                } else if field.required_if.is_some() {
+                                    conditionally_missing.push(field);
                                 }
                             },
                             Err(ValError::Omit) => continue,
@@ -249,65 +290,108 @@ impl Validator for TypedDictValidator {
                 }
 
                 if let Some(ref mut used_keys) = used_keys {
-                    for item_result in <$iter>::new($dict)? {
-                        let (raw_key, value) = item_result?;
-                        let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
-                            Ok(k) => k,
-                            Err(ValError::LineErrors(line_errors)) => {
-                                for err in line_errors {
-                                    errors.push(
-                                        err.with_outer_location(raw_key.as_loc_item())
-                                            .with_type(ErrorTypeDefaults::InvalidKey)
+                    // see `ModelFieldsValidator::validate`, extras are validated in their own closure to keep this
+                    // function's stack frame small
+                    let mut validate_extras = || -> ValResult<()> {
+                        for item_result in <$iter>::new($dict)? {
+                            let (raw_key, value) = item_result?;
+                            state.check_item(py, input, || raw_key.as_loc_item())?;
+                            let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
+                                Ok(k) => k,
+                                Err(ValError::LineErrors(line_errors)) => {
+                                    for err in line_errors {
+                                        errors.push(
+                                            err.with_outer_location(raw_key.as_loc_item())
+                                                .with_type(ErrorTypeDefaults::InvalidKey)
 
-                                    );
+                                        );
+                                    }
+                                    continue;
                                 }
+                                Err(err) => return Err(err),
+                            };
+                            let cow = either_str.as_cow().map_err(|err| err)?;
+                            if used_keys.contains(cow.as_ref()) {
                                 continue;
                             }
-                            Err(err) => return Err(err),
-                        };
-                        let cow = either_str.as_cow().map_err(|err| err)?;
-                        if used_keys.contains(cow.as_ref()) {
-                            continue;
-                        }
-
-                        let value = value.borrow_input();
-                        // Unknown / extra field
-                        match self.extra_behavior {
-                            ExtraBehavior::Forbid => {
-                                errors.push(
-                                    ValLineError::new_with_loc(
-                                        ErrorTypeDefaults::ExtraForbidden,
-                                        value,
-                                        raw_key.as_loc_item(),
-                                    )
 
-                                );
-                            }
-                            ExtraBehavior::Ignore => {}
-                            ExtraBehavior::Allow => {
-                            let py_key = either_str.as_py_string(py);
-                                if let Some(ref validator) = self.extras_validator {
-                                    match validator.validate(py, value, state) {
-                                        Ok(value) => {
-                                            output_dict.set_item(py_key, value)?;
-                                        }
-                                        Err(ValError::LineErrors(line_errors)) => {
-                                            for err in line_errors {
-                                                errors.push(
-                                                    err
-                                                    .with_outer_location(raw_key.as_loc_item())
+                            let value = value.borrow_input();
+                            // Unknown / extra field
+                            match self.extra_behavior {
+                                ExtraBehavior::Forbid => {
+                                    let error_type = if self.closed {
+                                        ErrorTypeDefaults::ClosedTypedDictExtra
+                                    } else {
+                                        ErrorTypeDefaults::ExtraForbidden
+                                    };
+                                    errors.push(
+                                        ValLineError::new_with_loc(
+                                            error_type,
+                                            value,
+                                            raw_key.as_loc_item(),
+                                        )
 
-                                                );
+                                    );
+                                }
+                                ExtraBehavior::Ignore => {}
+                                ExtraBehavior::Allow => {
+                                    let py_key = either_str.as_py_string(py);
+                                    let py_key = match self.extras_key_transform {
+                                        Some(ref transform) => transform.apply(py_key)?,
+                                        None => py_key,
+                                    };
+                                    let py_key = match self.extras_keys_validator {
+                                        Some(ref validator) => match validator.validate(py, py_key.as_ref(), state) {
+                                            Ok(key) => key.into_ref(py),
+                                            Err(ValError::LineErrors(line_errors)) => {
+                                                for err in line_errors {
+                                                    errors.push(
+                                                        err.with_outer_location("[key]".into())
+                                                            .with_outer_location(raw_key.as_loc_item()),
+                                                    );
+                                                }
+                                                continue;
                                             }
-                                        }
-                                        Err(err) => return Err(err),
+                                            Err(err) => return Err(err),
+                                        },
+                                        None => py_key.as_ref(),
+                                    };
+                                    if self.key_collides(py_key, output_dict)? {
+                                        errors.push(ValLineError::new_with_loc(
+                                            ErrorType::ExtraKeyCollision {
+                                                key: py_key.str()?.to_string_lossy().into_owned(),
+                                                context: None,
+                                            },
+                                            value,
+                                            raw_key.as_loc_item(),
+                                        ));
+                                        continue;
                                     }
-                                } else {
-                                    output_dict.set_item(py_key, value.to_object(py))?;
-                                };
+                                    if let Some(ref validator) = self.extras_validator {
+                                        match state.validate_item(py, validator, value, || raw_key.as_loc_item()) {
+                                            Ok(value) => {
+                                                output_dict.set_item(py_key, value)?;
+                                            }
+                                            Err(ValError::LineErrors(line_errors)) => {
+                                                for err in line_errors {
+                                                    errors.push(
+                                                        err
+                                                        .with_outer_location(raw_key.as_loc_item())
+
+                                                    );
+                                                }
+                                            }
+                                            Err(err) => return Err(err),
+                                        }
+                                    } else {
+                                        output_dict.set_item(py_key, value.to_object(py))?;
+                                    };
+                                }
                             }
                         }
-                    }
+                        Ok(())
+                    };
+                    validate_extras()?;
                 }
             }};
         }
@@ -317,6 +401,30 @@ impl Validator for TypedDictValidator {
             GenericMapping::StringMapping(d) => process!(d, py_get_string_mapping_item, StringMappingGenericIterator),
             GenericMapping::PyGetAttr(d, kwargs) => process!(d, py_get_attr, AttributesGenericIterator, kwargs),
             GenericMapping::JsonObject(d) => process!(d, json_get, JsonObjectGenericIterator),
+            GenericMapping::PyPairs(pairs_input, iter) => {
+                state.floor_exactness(super::Exactness::Lax);
+                let field_keys: AHashSet<&str> = self
+                    .fields
+                    .iter()
+                    .flat_map(|field| field.lookup_keys.select(lookup_type).first_keys())
+                    .collect();
+                let matches = &PairsMatches::new(pairs_input, iter, &field_keys, used_keys.is_some())?;
+                process!(matches, pairs_get, PairsMatchesIterator);
+            }
+        }
+
+        // fields only required depending on the values of other fields are checked once all fields are validated
+        for field in conditionally_missing {
+            if let Some(ref required_if) = field.required_if {
+                if required_if.applies(output_dict)? {
+                    errors.push(field.lookup_keys.select(lookup_type).error(
+                        ErrorTypeDefaults::Missing,
+                        input,
+                        self.loc_by_alias,
+                        &field.name,
+                    ));
+                }
+            }
         }
 
         if !errors.is_empty() {
@@ -330,3 +438,21 @@ impl Validator for TypedDictValidator {
         Self::EXPECTED_TYPE
     }
 }
+
+impl TypedDictValidator {
+    /// Whether a transformed or validated extra key would overwrite a field or another extra, keys can only
+    /// collide when they're changed before they're stored
+    fn key_collides(&self, key: &PyAny, output_dict: &PyDict) -> PyResult<bool> {
+        if self.extras_key_transform.is_none() && self.extras_keys_validator.is_none() {
+            return Ok(false);
+        }
+        let is_field = match key.downcast::<PyString>() {
+            Ok(key) => {
+                let key_str = key.to_str()?;
+                self.fields.iter().any(|field| field.name == key_str)
+            }
+            Err(_) => false,
+        };
+        Ok(is_field || output_dict.contains(key)?)
+    }
+}
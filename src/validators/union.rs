@@ -1,4 +1,6 @@
+use std::cmp::Reverse;
 use std::fmt::Write;
+use std::ops::Range;
 use std::str::FromStr;
 
 use pyo3::prelude::*;
@@ -8,8 +10,9 @@ use smallvec::SmallVec;
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::{is_strict, schema_or_config};
+use crate::coverage::CoverageCounter;
 use crate::errors::{AsLocItem, ErrorType, ValError, ValLineError, ValResult};
-use crate::input::{GenericMapping, Input};
+use crate::input::{FromAttributesPolicy, GenericMapping, Input};
 use crate::lookup_key::LookupKey;
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
@@ -38,13 +41,42 @@ impl FromStr for UnionMode {
     }
 }
 
+/// Which choices' errors are reported when no choice in a union succeeds
+#[derive(Debug, Clone, Copy)]
+enum UnionErrors {
+    /// errors from every choice
+    All,
+    /// errors from the choice which got furthest into the input, see `ChoiceLineErrors::match_score`
+    BestMatch,
+    /// errors from the first choice only
+    First,
+}
+
+impl FromStr for UnionErrors {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "best-match" => Ok(Self::BestMatch),
+            "first" => Ok(Self::First),
+            s => py_schema_err!("Invalid union_errors: `{}`, expected `all`, `best-match` or `first`", s),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct UnionValidator {
     mode: UnionMode,
+    errors_mode: UnionErrors,
     choices: Vec<(CombinedValidator, Option<String>)>,
+    // indices into `choices` in the order they're tried in smart mode, highest priority first
+    smart_order: Vec<usize>,
     custom_error: Option<CustomError>,
     strict: bool,
     name: String,
+    // one counter per choice, only if coverage stats are enabled
+    choice_counters: Option<Vec<CoverageCounter>>,
 }
 
 impl BuildValidator for UnionValidator {
@@ -77,22 +109,53 @@ impl BuildValidator for UnionValidator {
         let mode = schema
             .get_as::<&str>(intern!(py, "mode"))?
             .map_or(Ok(UnionMode::Smart), UnionMode::from_str)?;
+        let errors_mode = config
+            .get_as::<&str>(intern!(py, "union_errors"))?
+            .map_or(Ok(UnionErrors::All), UnionErrors::from_str)?;
+        let mut smart_order: Vec<usize> = (0..choices.len()).collect();
+        if let Some(priorities) = schema.get_as::<Vec<i64>>(intern!(py, "priorities"))? {
+            if matches!(mode, UnionMode::LeftToRight) {
+                return py_schema_err!("`priorities` can only be used with mode='smart'");
+            }
+            if priorities.len() != choices.len() {
+                return py_schema_err!(
+                    "`priorities` should have the same length as `choices`, got {} and {}",
+                    priorities.len(),
+                    choices.len()
+                );
+            }
+            // stable, so choices with the same priority keep their declared order
+            smart_order.sort_by_key(|&index| Reverse(priorities[index]));
+        }
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
             1 if auto_collapse() => Ok(choices.into_iter().next().unwrap().0),
             _ => {
-                let descr = choices
+                // choices are listed in the order they're tried, so the effective priority is visible in the name
+                let descr = smart_order
                     .iter()
+                    .map(|&index| &choices[index])
                     .map(|(choice, label)| label.as_deref().unwrap_or(choice.get_name()))
                     .collect::<Vec<_>>()
                     .join(",");
+                let name = format!("{}[{descr}]", Self::EXPECTED_TYPE);
+                let choice_counters = choices
+                    .iter()
+                    .map(|(choice, label)| {
+                        definitions
+                            .coverage_counter(|| format!("{name}.{}", label.as_deref().unwrap_or(choice.get_name())))
+                    })
+                    .collect();
 
                 Ok(Self {
                     mode,
+                    errors_mode,
                     choices,
+                    smart_order,
                     custom_error: CustomError::build(schema, config, definitions)?,
                     strict: is_strict(schema, config)?,
-                    name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
+                    name,
+                    choice_counters,
                 }
                 .into())
             }
@@ -101,6 +164,29 @@ impl BuildValidator for UnionValidator {
 }
 
 impl UnionValidator {
+    fn record_choice(
+        &self,
+        py: Python,
+        state: &mut ValidationState,
+        output: &PyObject,
+        index: usize,
+        exactness: Option<Exactness>,
+    ) -> PyResult<()> {
+        if let Some(ref choice_counters) = self.choice_counters {
+            choice_counters[index].increment();
+        }
+        if state.union_choices_enabled() {
+            let (choice, label) = &self.choices[index];
+            let info = PyDict::new(py);
+            info.set_item(intern!(py, "union"), &self.name)?;
+            info.set_item(intern!(py, "index"), index)?;
+            info.set_item(intern!(py, "choice"), label.as_deref().unwrap_or(choice.get_name()))?;
+            info.set_item(intern!(py, "exactness"), exactness.map(Exactness::as_str))?;
+            state.record_union_choice(py, output, info);
+        }
+        Ok(())
+    }
+
     fn validate_smart<'data>(
         &self,
         py: Python<'data>,
@@ -111,16 +197,24 @@ impl UnionValidator {
         let strict = state.strict_or(self.strict);
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
-        let mut success = None;
+        let mut success: Option<SmartSuccess> = None;
+        // only the coercions, identity map entries and union choices recorded by the successful choice are kept
+        let coercions_start = state.coercion_report_len();
+        let records_start = state.output_records_len();
 
-        for (choice, label) in &self.choices {
+        for &index in &self.smart_order {
+            let (choice, label) = &self.choices[index];
             let state = &mut state.rebind_extra(|extra| {
                 if strict {
                     extra.strict = Some(strict);
                 }
             });
             state.exactness = Some(Exactness::Exact);
+            let choice_coercions_start = state.coercion_report_len();
+            let choice_records_start = state.output_records_len();
             let result = choice.validate(py, input, state);
+            let choice_coercions = choice_coercions_start..state.coercion_report_len();
+            let choice_records = choice_records_start..state.output_records_len();
             match result {
                 Ok(new_success) => match state.exactness {
                     // exact match, return
@@ -128,6 +222,9 @@ impl UnionValidator {
                         return {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
+                            state.retain_coercion_report(coercions_start, choice_coercions);
+                            state.retain_output_records(records_start, choice_records);
+                            self.record_choice(py, state, &new_success, index, Some(Exactness::Exact))?;
                             Ok(new_success)
                         };
                     }
@@ -138,19 +235,25 @@ impl UnionValidator {
                         // if the new result has higher exactness than the current success, replace it
                         if success
                             .as_ref()
-                            .map_or(true, |(_, current_exactness)| *current_exactness < new_exactness)
+                            .map_or(true, |current| current.exactness < new_exactness)
                         {
                             // TODO: is there a possible optimization here, where once there has
                             // been one success, we turn on strict mode, to avoid unnecessary
                             // coercions for further validation?
-                            success = Some((new_success, new_exactness));
+                            success = Some(SmartSuccess {
+                                output: new_success,
+                                exactness: new_exactness,
+                                index,
+                                coercions: choice_coercions,
+                                records: choice_records,
+                            });
                         }
                     }
                 },
                 Err(ValError::LineErrors(lines)) => {
                     // if we don't yet know this validation will succeed, record the error
                     if success.is_none() {
-                        errors.push(choice, label.as_deref(), lines);
+                        errors.push(self.errors_mode, choice, label.as_deref(), lines);
                     }
                 }
                 otherwise => return otherwise,
@@ -158,10 +261,15 @@ impl UnionValidator {
         }
         state.exactness = old_exactness;
 
-        if let Some((success, exactness)) = success {
-            state.floor_exactness(exactness);
-            return Ok(success);
+        if let Some(success) = success {
+            state.floor_exactness(success.exactness);
+            state.retain_coercion_report(coercions_start, success.coercions);
+            state.retain_output_records(records_start, success.records);
+            self.record_choice(py, state, &success.output, success.index, Some(success.exactness))?;
+            return Ok(success.output);
         }
+        state.retain_coercion_report(coercions_start, coercions_start..coercions_start);
+        state.retain_output_records(records_start, records_start..records_start);
 
         // no matches, build errors
         Err(errors.into_val_error(input))
@@ -183,9 +291,20 @@ impl UnionValidator {
             state
         };
 
-        for (validator, label) in &self.choices {
+        for (index, (validator, label)) in self.choices.iter().enumerate() {
+            let coercions_start = state.coercion_report_len();
+            let records_start = state.output_records_len();
             match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
+                Err(ValError::LineErrors(lines)) => {
+                    state.retain_coercion_report(coercions_start, coercions_start..coercions_start);
+                    state.retain_output_records(records_start, records_start..records_start);
+                    errors.push(self.errors_mode, validator, label.as_deref(), lines);
+                }
+                Ok(output) => {
+                    // exactness isn't tracked per choice in left to right mode
+                    self.record_choice(py, state, &output, index, None)?;
+                    return Ok(output);
+                }
                 otherwise => return otherwise,
             };
         }
@@ -219,12 +338,38 @@ impl Validator for UnionValidator {
     }
 }
 
+/// The most exact successful choice so far in smart mode
+struct SmartSuccess {
+    output: PyObject,
+    exactness: Exactness,
+    index: usize,
+    /// coercions recorded while validating this choice, see `ValidationState::retain_coercion_report`
+    coercions: Range<usize>,
+    /// identity map entries and union choices recorded while validating this choice, see
+    /// `ValidationState::retain_output_records`
+    records: Range<usize>,
+}
+
 struct ChoiceLineErrors<'a> {
     choice: &'a CombinedValidator,
     label: Option<&'a str>,
     line_errors: Vec<ValLineError>,
 }
 
+impl ChoiceLineErrors<'_> {
+    /// How closely the input matched this choice, the deepest error location wins since the choice got further
+    /// into the input before failing, then the fewest errors.
+    fn match_score(&self) -> (usize, Reverse<usize>) {
+        let depth = self
+            .line_errors
+            .iter()
+            .map(|line_error| line_error.location.depth())
+            .max()
+            .unwrap_or(0);
+        (depth, Reverse(self.line_errors.len()))
+    }
+}
+
 enum MaybeErrors<'a> {
     Custom(&'a CustomError),
     Errors(SmallVec<[ChoiceLineErrors<'a>; 4]>),
@@ -238,14 +383,36 @@ impl<'a> MaybeErrors<'a> {
         }
     }
 
-    fn push(&mut self, choice: &'a CombinedValidator, label: Option<&'a str>, line_errors: Vec<ValLineError>) {
-        match self {
-            Self::Custom(_) => {}
-            Self::Errors(errors) => errors.push(ChoiceLineErrors {
-                choice,
-                label,
-                line_errors,
-            }),
+    fn push(
+        &mut self,
+        errors_mode: UnionErrors,
+        choice: &'a CombinedValidator,
+        label: Option<&'a str>,
+        line_errors: Vec<ValLineError>,
+    ) {
+        let new_errors = ChoiceLineErrors {
+            choice,
+            label,
+            line_errors,
+        };
+        let Self::Errors(errors) = self else {
+            return;
+        };
+        match errors_mode {
+            UnionErrors::All => errors.push(new_errors),
+            UnionErrors::First => {
+                if errors.is_empty() {
+                    errors.push(new_errors);
+                }
+            }
+            // only keep the best match so far, the earlier choice wins a tie
+            UnionErrors::BestMatch => match errors.first() {
+                Some(best) if best.match_score() >= new_errors.match_score() => {}
+                _ => {
+                    errors.clear();
+                    errors.push(new_errors);
+                }
+            },
         }
     }
 
@@ -320,12 +487,18 @@ impl PyGcTraverse for Discriminator {
 pub struct TaggedUnionValidator {
     discriminator: Discriminator,
     lookup: LiteralLookup<CombinedValidator>,
+    // used for tags which aren't in `lookup`
+    otherwise: Option<Box<CombinedValidator>>,
     from_attributes: bool,
+    from_attributes_policy: FromAttributesPolicy,
     strict: bool,
     custom_error: Option<CustomError>,
     tags_repr: String,
     discriminator_repr: String,
     name: String,
+    // one counter per tag, in the same order as `lookup.values`, only if coverage stats are enabled
+    tag_counters: Option<Vec<CoverageCounter>>,
+    otherwise_counter: Option<CoverageCounter>,
 }
 
 impl BuildValidator for TaggedUnionValidator {
@@ -347,10 +520,12 @@ impl BuildValidator for TaggedUnionValidator {
         let mut discriminators = Vec::with_capacity(choices.len());
         let schema_choices: &PyDict = schema.get_as_req(intern!(py, "choices"))?;
         let mut lookup_map = Vec::with_capacity(choices.len());
+        let mut tag_reprs = Vec::with_capacity(choices.len());
         for (choice_key, choice_schema) in schema_choices {
             discriminators.push(choice_key);
             let validator = build_validator(choice_schema, config, definitions)?;
             let tag_repr = choice_key.repr()?.to_string();
+            tag_reprs.push(tag_repr.clone());
             if first {
                 first = false;
                 write!(tags_repr, "{tag_repr}").unwrap();
@@ -364,6 +539,10 @@ impl BuildValidator for TaggedUnionValidator {
         }
 
         let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
+        let otherwise = match schema.get_item(intern!(py, "otherwise"))? {
+            Some(otherwise_schema) => Some(Box::new(build_validator(otherwise_schema, config, definitions)?)),
+            None => None,
+        };
 
         let key = intern!(py, "from_attributes");
         let from_attributes = schema_or_config(schema, config, key, key)?.unwrap_or(true);
@@ -372,22 +551,40 @@ impl BuildValidator for TaggedUnionValidator {
             Discriminator::SelfSchema => "self-schema".to_string(),
             _ => descr,
         };
+        let name = format!("{}[{descr}]", Self::EXPECTED_TYPE);
+        let tag_counters = tag_reprs
+            .iter()
+            .map(|tag_repr| definitions.coverage_counter(|| format!("{name}.{tag_repr}")))
+            .collect();
+        let otherwise_counter = match otherwise {
+            Some(_) => definitions.coverage_counter(|| format!("{name}.otherwise")),
+            None => None,
+        };
 
         Ok(Self {
             discriminator,
             lookup,
+            otherwise,
             from_attributes,
+            from_attributes_policy: FromAttributesPolicy::from_config(py, config)?,
             strict: is_strict(schema, config)?,
             custom_error: CustomError::build(schema, config, definitions)?,
             tags_repr,
             discriminator_repr,
-            name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
+            name,
+            tag_counters,
+            otherwise_counter,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionValidator { discriminator, lookup });
+impl_py_gc_traverse!(TaggedUnionValidator {
+    discriminator,
+    lookup,
+    otherwise,
+    from_attributes_policy
+});
 
 impl Validator for TaggedUnionValidator {
     fn validate<'data>(
@@ -411,13 +608,17 @@ impl Validator for TaggedUnionValidator {
                     }};
                 }
                 let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
-                let dict = input.validate_model_fields(self.strict, from_attributes)?;
+                let dict = input
+                    .validate_model_fields(self.strict, from_attributes.then_some(&self.from_attributes_policy))?;
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(py_get_dict_item, dict),
                     GenericMapping::PyMapping(mapping) => find_validator!(py_get_mapping_item, mapping),
                     GenericMapping::StringMapping(d) => find_validator!(py_get_dict_item, d),
                     GenericMapping::PyGetAttr(obj, kwargs) => find_validator!(py_get_attr, obj, kwargs),
                     GenericMapping::JsonObject(mapping) => find_validator!(json_get, mapping),
+                    // pairs may come from a one-shot iterator, so can't be read to find the tag
+                    // and then again by the chosen validator
+                    GenericMapping::PyPairs(_, _) => Err(self.tag_not_found(input)),
                 }?;
                 self.find_call_validator(py, tag, input, state)
             }
@@ -494,11 +695,24 @@ impl TaggedUnionValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
+            if let Some(ref tag_counters) = self.tag_counters {
+                if let Some(index) = self.lookup.values.iter().position(|v| std::ptr::eq(v, validator)) {
+                    tag_counters[index].increment();
+                }
+            }
             return match validator.validate(py, input, state) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(err.with_outer_location(tag.as_loc_item())),
             };
         }
+        if let Some(ref otherwise) = self.otherwise {
+            if let Some(ref otherwise_counter) = self.otherwise_counter {
+                otherwise_counter.increment();
+            }
+            return otherwise
+                .validate(py, input, state)
+                .map_err(|err| err.with_outer_location(tag.as_loc_item()));
+        }
         match self.custom_error {
             Some(ref custom_error) => Err(custom_error.as_val_error(input)),
             None => Err(ValError::new(
@@ -6,12 +6,13 @@ use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyType};
 use uuid::Uuid;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
 use crate::input::InputType;
 use crate::tools::SchemaDict;
 
+use super::config::ValBytesMode;
 use super::model::create_class;
 use super::model::force_setattr;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
@@ -37,6 +38,7 @@ enum Version {
     UUIDv3 = 3,
     UUIDv4 = 4,
     UUIDv5 = 5,
+    UUIDv7 = 7,
 }
 
 impl From<Version> for usize {
@@ -45,22 +47,35 @@ impl From<Version> for usize {
     }
 }
 
-impl From<u8> for Version {
-    fn from(u: u8) -> Self {
+impl TryFrom<u8> for Version {
+    type Error = PyErr;
+
+    fn try_from(u: u8) -> PyResult<Self> {
         match u {
-            1 => Version::UUIDv1,
-            3 => Version::UUIDv3,
-            4 => Version::UUIDv4,
-            5 => Version::UUIDv5,
-            _ => unreachable!(),
+            1 => Ok(Version::UUIDv1),
+            3 => Ok(Version::UUIDv3),
+            4 => Ok(Version::UUIDv4),
+            5 => Ok(Version::UUIDv5),
+            7 => Ok(Version::UUIDv7),
+            _ => py_schema_err!("Invalid UUID version: {}, expected one of 1, 3, 4, 5 or 7", u),
         }
     }
 }
 
+/// Whether `s` is in the canonical hyphenated form, e.g. `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`
+fn is_hyphenated(s: &str) -> bool {
+    s.len() == 36
+        && s.bytes().enumerate().all(|(i, b)| match i {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
 #[derive(Debug, Clone)]
 pub struct UuidValidator {
     strict: bool,
     version: Option<usize>,
+    strict_hyphenation: bool,
 }
 
 impl BuildValidator for UuidValidator {
@@ -73,10 +88,14 @@ impl BuildValidator for UuidValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         // Note(lig): let's keep this conversion through the Version enum just for the sake of validation
-        let version = schema.get_as::<u8>(intern!(py, "version"))?.map(Version::from);
+        let version = schema
+            .get_as::<u8>(intern!(py, "version"))?
+            .map(Version::try_from)
+            .transpose()?;
         Ok(Self {
             strict: is_strict(schema, config)?,
             version: version.map(usize::from),
+            strict_hyphenation: schema.get_as(intern!(py, "strict_hyphenation"))?.unwrap_or(false),
         }
         .into())
     }
@@ -140,7 +159,7 @@ impl UuidValidator {
             Some(either_string) => {
                 let cow = either_string.as_cow()?;
                 let uuid_str = cow.as_ref();
-                Uuid::parse_str(uuid_str).map_err(|e| {
+                let uuid = Uuid::parse_str(uuid_str).map_err(|e| {
                     ValError::new(
                         ErrorType::UuidParsing {
                             error: e.to_string(),
@@ -148,18 +167,22 @@ impl UuidValidator {
                         },
                         input,
                     )
-                })?
+                })?;
+                self.check_hyphenation(uuid_str, input)?;
+                uuid
             }
             None => {
                 let either_bytes = input
-                    .validate_bytes(true)
+                    .validate_bytes(true, ValBytesMode::Utf8)
                     .map_err(|_| ValError::new(ErrorTypeDefaults::UuidType, input))?
                     .into_inner();
                 let bytes_slice = either_bytes.as_slice();
+                let bytes_slice = bytes_slice.as_ref();
                 'parse: {
                     // Try parsing as utf8, but don't care if it fails
                     if let Ok(utf8_str) = from_utf8(bytes_slice) {
                         if let Ok(uuid) = Uuid::parse_str(utf8_str) {
+                            self.check_hyphenation(utf8_str, input)?;
                             break 'parse uuid;
                         }
                     }
@@ -191,6 +214,14 @@ impl UuidValidator {
         Ok(uuid)
     }
 
+    fn check_hyphenation<'data>(&self, uuid_str: &str, input: &'data impl Input<'data>) -> ValResult<()> {
+        if self.strict_hyphenation && !is_hyphenated(uuid_str) {
+            Err(ValError::new(ErrorTypeDefaults::UuidFormat, input))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Sets the attributes in a Python type object (`py_type`) to represent a UUID class.
     /// The function creates the python class and converts the UUID to a u128 integer and
     /// sets the corresponding attributes in the dictionary object to the converted value
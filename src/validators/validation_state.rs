@@ -1,6 +1,16 @@
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use ahash::AHashMap;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::errors::{AsErrorValue, ErrorType, LocItem, Location, ValError, ValLineError, ValResult};
+use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
-use super::Extra;
+use super::strict_overrides::StrictOverrides;
+use super::{CombinedValidator, Extra, Validator};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum Exactness {
@@ -9,11 +19,100 @@ pub enum Exactness {
     Exact,
 }
 
+impl Exactness {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lax => "lax",
+            Self::Strict => "strict",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+/// How many container items are validated between checks of the clock
+const DEADLINE_CHECK_INTERVAL: usize = 64;
+/// How many container items are validated between checks for pending signals, e.g. Ctrl-C
+const SIGNAL_CHECK_INTERVAL: usize = 1024;
+
+#[derive(Clone, Copy, Debug)]
+struct Deadline {
+    at: Instant,
+    timeout: f64,
+    expired: bool,
+}
+
+/// Progress towards the deadline, carried into and back out of validation done by validator functions,
+/// e.g. via the handler of a wrap validator
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeoutProgress {
+    items_validated: usize,
+    deadline: Option<Deadline>,
+}
+
+/// State belonging to the whole validation call rather than to one `Extra`, moved into the state used
+/// by `with_new_extra` and back out again afterwards
+#[derive(Default)]
+struct CallScope {
+    items_validated: usize,
+    deadline: Option<Deadline>,
+    coercion_report: Option<Vec<Coercion>>,
+    shared_instances: Option<SharedInstances>,
+    output_records: Option<OutputRecords>,
+    // dict validator functions can use to share state for the whole call, created when it's first used
+    call_state: Option<Py<PyDict>>,
+}
+
+/// A location where validation only succeeded by lax coercion, e.g. a `str` input to an `int` field
+#[derive(Debug)]
+struct Coercion {
+    location: Location,
+    input_type: String,
+    expected: String,
+}
+
+/// Returned by `ValidationState::coercion_mark`, before validating an item of a container
+struct CoercionMark {
+    exactness: Option<Exactness>,
+    report_len: usize,
+}
+
+/// Identity of an input object, the definition it was validated against, and the `strict` and `from_attributes`
+/// settings it was validated with, so an output validated in lax mode isn't reused where strict mode applies
+type InstanceKey = (usize, usize, Option<bool>, Option<bool>);
+
+/// Outputs of definitions validated so far in one call, enabled with `val_circular_ref='reuse'`, so an input
+/// seen again (including inside itself) is linked to the same output rather than validated again.
+#[derive(Default)]
+struct SharedInstances {
+    // the input is kept alive so its id can't be reused by another object during the call
+    instances: AHashMap<InstanceKey, (PyObject, PyObject)>,
+    // set by a definition reference just before validating, taken by the model validator which creates
+    // the instance so it can be registered before its fields are validated
+    pending: Option<InstanceKey>,
+}
+
+/// Recorded against an output during validation, discarded again if that output isn't used, e.g. when the
+/// union choice which produced it fails
+enum OutputRecord {
+    /// (input, output), the input is kept alive so its id can't be reused by another object during the call
+    Identity(PyObject, PyObject),
+    /// output of a union and the details of the choice which produced it
+    UnionChoice(PyObject, Py<PyDict>),
+}
+
+#[derive(Default)]
+struct OutputRecords {
+    records: Vec<OutputRecord>,
+    identities: bool,
+    union_choices: bool,
+}
+
 pub struct ValidationState<'a> {
     pub recursion_guard: &'a mut RecursionGuard,
     pub exactness: Option<Exactness>,
     // deliberately make Extra readonly
     extra: Extra<'a>,
+    call: CallScope,
 }
 
 impl<'a> ValidationState<'a> {
@@ -22,6 +121,350 @@ impl<'a> ValidationState<'a> {
             recursion_guard, // Don't care about exactness unless doing union validation
             exactness: None,
             extra,
+            call: CallScope::default(),
+        }
+    }
+
+    /// The dict exposed to validator functions as `ValidationInfo.state`, shared by every function called
+    /// during this validation call
+    pub fn call_state(&mut self, py: Python) -> Py<PyDict> {
+        self.call
+            .call_state
+            .get_or_insert_with(|| PyDict::new(py).into())
+            .clone_ref(py)
+    }
+
+    /// The state dict if a validator function has already used it, without creating it
+    pub fn existing_call_state(&self, py: Python) -> Option<Py<PyDict>> {
+        self.call.call_state.as_ref().map(|call_state| call_state.clone_ref(py))
+    }
+
+    /// Share the state dict of an outer call, e.g. when validating via the handler of a wrap validator
+    pub fn set_call_state(&mut self, call_state: Py<PyDict>) {
+        self.call.call_state = Some(call_state);
+    }
+
+    pub fn take_call_state(&mut self) -> Option<Py<PyDict>> {
+        self.call.call_state.take()
+    }
+
+    /// Record the location of every value which required lax coercion, this requires tracking exactness
+    /// everywhere, not just in unions
+    pub fn enable_coercion_report(&mut self) {
+        self.call.coercion_report = Some(Vec::new());
+        self.exactness = Some(Exactness::Exact);
+    }
+
+    /// `None` unless the coercion report is enabled
+    fn coercion_mark(&mut self) -> Option<CoercionMark> {
+        let report_len = self.call.coercion_report.as_ref()?.len();
+        let mark = CoercionMark {
+            exactness: self.exactness,
+            report_len,
+        };
+        self.exactness = Some(Exactness::Exact);
+        Some(mark)
+    }
+
+    /// Validate an item of a container, `loc` is only called if the coercion report is enabled or
+    /// strictness is overridden for some fields
+    pub fn validate_item<'data>(
+        &mut self,
+        py: Python<'data>,
+        validator: &CombinedValidator,
+        input: &'data impl Input<'data>,
+        loc: impl Fn() -> LocItem,
+    ) -> ValResult<PyObject> {
+        let Some(overrides) = self.extra.strict_overrides else {
+            return self.validate_item_inner(py, validator, input, loc);
+        };
+        let item_overrides = overrides.get(&loc());
+        let mut state = self.rebind_extra(|extra| {
+            extra.strict_overrides = item_overrides;
+            if let Some(strict) = item_overrides.and_then(StrictOverrides::strict) {
+                extra.strict = Some(strict);
+            }
+        });
+        state.validate_item_inner(py, validator, input, loc)
+    }
+
+    fn validate_item_inner<'data>(
+        &mut self,
+        py: Python<'data>,
+        validator: &CombinedValidator,
+        input: &'data impl Input<'data>,
+        loc: impl FnOnce() -> LocItem,
+    ) -> ValResult<PyObject> {
+        let mark = self.coercion_mark();
+        let result = validator.validate(py, input, self);
+        if let Some(mark) = mark {
+            self.record_coercion(py, mark, result.is_ok(), loc, input, validator.get_name());
+        }
+        if let Ok(ref output) = result {
+            self.record_identity(py, input, output);
+        }
+        result
+    }
+
+    /// If the item required lax coercion and nothing inside it was recorded, record the item itself,
+    /// otherwise add `loc` to the location of the coercions recorded inside it
+    fn record_coercion<'data>(
+        &mut self,
+        py: Python<'data>,
+        mark: CoercionMark,
+        valid: bool,
+        loc: impl FnOnce() -> LocItem,
+        input: &impl Input<'data>,
+        expected: &str,
+    ) {
+        let item_exactness = self.exactness;
+        self.exactness = mark.exactness.map(|e| e.min(item_exactness.unwrap_or(Exactness::Lax)));
+        let Some(ref mut report) = self.call.coercion_report else {
+            return;
+        };
+        if !valid {
+            report.truncate(mark.report_len);
+        } else if report.len() > mark.report_len {
+            let loc = loc();
+            for coercion in &mut report[mark.report_len..] {
+                coercion.location.with_outer(loc.clone());
+            }
+        } else if item_exactness == Some(Exactness::Lax) {
+            report.push(Coercion {
+                location: Location::new_some(loc()),
+                input_type: input_type_name(py, input),
+                expected: expected.to_string(),
+            });
+        }
+    }
+
+    /// Zero unless the coercion report is enabled
+    pub fn coercion_report_len(&self) -> usize {
+        self.call.coercion_report.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Discard coercions recorded after `start` except those in `keep`, e.g. coercions recorded by union
+    /// choices which weren't used
+    pub fn retain_coercion_report(&mut self, start: usize, keep: Range<usize>) {
+        if let Some(ref mut report) = self.call.coercion_report {
+            report.truncate(keep.end);
+            report.drain(start..keep.start);
+        }
+    }
+
+    /// Add coercions to `list` as dicts with `loc`, `input_type` and `expected` keys, `input` and `expected`
+    /// describe the top level value in case it required coercion itself
+    pub fn finish_coercion_report<'data>(
+        &mut self,
+        py: Python,
+        list: &PyList,
+        input: &impl Input<'data>,
+        expected: &str,
+    ) -> PyResult<()> {
+        let Some(report) = self.call.coercion_report.take() else {
+            return Ok(());
+        };
+        if report.is_empty() && self.exactness == Some(Exactness::Lax) {
+            list.append(coercion_dict(
+                py,
+                &Location::default(),
+                &input_type_name(py, input),
+                expected,
+            )?)?;
+        }
+        for coercion in &report {
+            list.append(coercion_dict(
+                py,
+                &coercion.location,
+                &coercion.input_type,
+                &coercion.expected,
+            )?)?;
+        }
+        Ok(())
+    }
+
+    /// Record which output each input object was validated to, for every item of a container and the top
+    /// level value
+    pub fn enable_identity_map(&mut self) {
+        self.call
+            .output_records
+            .get_or_insert_with(OutputRecords::default)
+            .identities = true;
+    }
+
+    /// Record which choice each union picked, reported once validation has succeeded so choices made inside
+    /// union choices which weren't used are never reported
+    pub fn enable_union_choices(&mut self) {
+        self.call
+            .output_records
+            .get_or_insert_with(OutputRecords::default)
+            .union_choices = true;
+    }
+
+    pub fn record_identity<'data>(&mut self, py: Python<'data>, input: &impl Input<'data>, output: &PyObject) {
+        if let Some(ref mut output_records) = self.call.output_records {
+            if output_records.identities && input.identity().is_some() {
+                let record = OutputRecord::Identity(input.to_object(py), output.clone_ref(py));
+                output_records.records.push(record);
+            }
+        }
+    }
+
+    pub fn union_choices_enabled(&self) -> bool {
+        self.call.output_records.as_ref().map_or(false, |r| r.union_choices)
+    }
+
+    pub fn record_union_choice(&mut self, py: Python, output: &PyObject, info: &PyDict) {
+        if let Some(ref mut output_records) = self.call.output_records {
+            if output_records.union_choices {
+                let record = OutputRecord::UnionChoice(output.clone_ref(py), info.into());
+                output_records.records.push(record);
+            }
+        }
+    }
+
+    /// Zero if neither the identity map nor union choices are enabled
+    pub fn output_records_len(&self) -> usize {
+        self.call.output_records.as_ref().map_or(0, |r| r.records.len())
+    }
+
+    /// Discard records made after `start` except those in `keep`, e.g. records made by union choices
+    /// which weren't used
+    pub fn retain_output_records(&mut self, start: usize, keep: Range<usize>) {
+        if let Some(ref mut output_records) = self.call.output_records {
+            output_records.records.truncate(keep.end);
+            output_records.records.drain(start..keep.start);
+        }
+    }
+
+    /// Set `id(input)` to the output in `identity_map` for every identity recorded, and call
+    /// `union_choice_callback(output, info)` for every union choice, in the order they were made
+    pub fn finish_output_records(
+        &mut self,
+        py: Python,
+        identity_map: Option<&PyDict>,
+        union_choice_callback: Option<&PyAny>,
+    ) -> PyResult<()> {
+        let Some(output_records) = self.call.output_records.take() else {
+            return Ok(());
+        };
+        for record in output_records.records {
+            match (record, identity_map, union_choice_callback) {
+                (OutputRecord::Identity(input, output), Some(identity_map), _) => {
+                    identity_map.set_item(input.as_ptr() as usize, output)?;
+                }
+                (OutputRecord::UnionChoice(output, info), _, Some(callback)) => {
+                    callback.call1((output, info.as_ref(py)))?;
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Reuse the output for inputs which are validated against the same definition more than once,
+    /// this also allows cyclic inputs to produce cyclic models instead of a `recursion_loop` error
+    pub fn enable_shared_instances(&mut self) {
+        self.call.shared_instances = Some(SharedInstances::default());
+    }
+
+    pub fn shared_instances_enabled(&self) -> bool {
+        self.call.shared_instances.is_some()
+    }
+
+    pub fn shared_instance(&self, py: Python, key: InstanceKey) -> Option<PyObject> {
+        let shared = self.call.shared_instances.as_ref()?;
+        shared.instances.get(&key).map(|(_, output)| output.clone_ref(py))
+    }
+
+    /// Register the output for `input`, possibly before validation of it has finished
+    pub fn add_shared_instance(&mut self, key: InstanceKey, input: PyObject, output: PyObject) {
+        if let Some(ref mut shared) = self.call.shared_instances {
+            shared.instances.insert(key, (input, output));
+        }
+    }
+
+    pub fn remove_shared_instance(&mut self, key: InstanceKey) {
+        if let Some(ref mut shared) = self.call.shared_instances {
+            shared.instances.remove(&key);
+        }
+    }
+
+    pub fn set_pending_instance(&mut self, key: Option<InstanceKey>) {
+        if let Some(ref mut shared) = self.call.shared_instances {
+            shared.pending = key;
+        }
+    }
+
+    /// Take the pending key if it's for `input_id`, the caller should then register the instance it creates
+    pub fn take_pending_instance(&mut self, input_id: Option<usize>) -> Option<InstanceKey> {
+        let shared = self.call.shared_instances.as_mut()?;
+        match shared.pending {
+            Some(key) if Some(key.0) == input_id => shared.pending.take(),
+            _ => None,
+        }
+    }
+
+    /// Abort validation with a `validation_timeout` error if it takes longer than `timeout`
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.call.deadline = Some(Deadline {
+            at: Instant::now() + timeout,
+            timeout: timeout.as_secs_f64(),
+            expired: false,
+        });
+    }
+
+    pub fn timeout_progress(&self) -> TimeoutProgress {
+        TimeoutProgress {
+            items_validated: self.call.items_validated,
+            deadline: self.call.deadline,
+        }
+    }
+
+    pub fn set_timeout_progress(&mut self, progress: TimeoutProgress) {
+        self.call.items_validated = progress.items_validated;
+        self.call.deadline = progress.deadline;
+    }
+
+    /// Count an item towards `SIGNAL_CHECK_INTERVAL`, checking for pending signals each time the interval is
+    /// reached, used without `check_item` where there's no deadline
+    pub fn check_signals(&mut self, py: Python) -> PyResult<()> {
+        self.call.items_validated += 1;
+        if self.call.items_validated % SIGNAL_CHECK_INTERVAL == 0 {
+            py.check_signals()?;
+        }
+        Ok(())
+    }
+
+    /// Called before validating each item of a container, checks for pending signals so a long validation
+    /// can be interrupted, and checks the deadline if there is one, both only periodically
+    pub fn check_item(
+        &mut self,
+        py: Python,
+        input: &impl AsErrorValue,
+        loc: impl FnOnce() -> LocItem,
+    ) -> ValResult<()> {
+        self.check_signals(py)?;
+        let call = &mut self.call;
+        let Some(ref mut deadline) = call.deadline else {
+            return Ok(());
+        };
+        if !deadline.expired && call.items_validated % DEADLINE_CHECK_INTERVAL == 0 {
+            deadline.expired = Instant::now() >= deadline.at;
+        }
+        if deadline.expired {
+            let error_type = ErrorType::ValidationTimeout {
+                timeout: deadline.timeout.into(),
+                items_validated: call.items_validated - 1,
+                context: None,
+            };
+            Err(ValError::Timeout(Box::new(ValLineError::new_with_loc(
+                error_type,
+                input,
+                loc(),
+            ))))
+        } else {
+            Ok(())
         }
     }
 
@@ -36,9 +479,13 @@ impl<'a> ValidationState<'a> {
             recursion_guard: self.recursion_guard,
             exactness: self.exactness,
             extra,
+            call: std::mem::take(&mut self.call),
         };
         let result = f(&mut new_state);
-        match new_state.exactness {
+        // destructure to end the borrow of `self.recursion_guard` before updating `self`
+        let ValidationState { exactness, call, .. } = new_state;
+        self.call = call;
+        match exactness {
             Some(exactness) => self.floor_exactness(exactness),
             None => self.exactness = None,
         }
@@ -84,6 +531,20 @@ impl<'a> ValidationState<'a> {
     }
 }
 
+fn input_type_name<'data>(py: Python, input: &impl Input<'data>) -> String {
+    let input = input.to_object(py);
+    let name = input.as_ref(py).get_type().name().map(ToString::to_string);
+    name.unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn coercion_dict<'py>(py: Python<'py>, location: &Location, input_type: &str, expected: &str) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(py);
+    dict.set_item("loc", location.to_object(py))?;
+    dict.set_item("input_type", input_type)?;
+    dict.set_item("expected", expected)?;
+    Ok(dict)
+}
+
 pub struct ValidationStateWithReboundExtra<'state, 'a> {
     state: &'state mut ValidationState<'a>,
     old_extra: Extra<'a>,
@@ -1,18 +1,20 @@
+use std::sync::OnceLock;
+
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 use pyo3::PyTraverseError;
 use pyo3::PyVisit;
 
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
-use crate::errors::{LocItem, ValError, ValResult};
+use crate::errors::{ErrorTypeDefaults, LocItem, ValError, ValResult};
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
-use crate::PydanticUndefinedType;
+use crate::{PydanticUndefinedType, UnsetType};
 
 static COPY_DEEPCOPY: GILOnceCell<PyObject> = GILOnceCell::new();
 
@@ -20,31 +22,92 @@ fn get_deepcopy(py: Python) -> PyResult<PyObject> {
     Ok(py.import("copy")?.getattr("deepcopy")?.into_py(py))
 }
 
+/// Whether validating with this schema only depends on the input and gives an immutable value, so a validated
+/// default can be reused: function validators may read `info.data`, `info.context` or return mutable values, and
+/// date and datetime `now_op` checks depend on the current time
+fn is_pure_schema(schema: &PyAny) -> PyResult<bool> {
+    let py = schema.py();
+    let Ok(schema) = schema.downcast::<PyDict>() else {
+        return Ok(false);
+    };
+    if schema.contains(intern!(py, "now_op"))? {
+        return Ok(false);
+    }
+    let items_pure = |key: &PyString| -> PyResult<bool> {
+        match schema.get_item(key)? {
+            Some(items_schema) => is_pure_schema(items_schema),
+            None => Ok(false),
+        }
+    };
+    match schema.get_as_req::<&str>(intern!(py, "type"))? {
+        "none" | "bool" | "int" | "float" | "decimal" | "str" | "bytes" | "date" | "time" | "datetime"
+        | "timedelta" | "uuid" | "url" | "multi-host-url" | "literal" => Ok(true),
+        "nullable" => items_pure(intern!(py, "schema")),
+        "frozenset" | "tuple-variable" => items_pure(intern!(py, "items_schema")),
+        "tuple-positional" => {
+            let items_schema: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
+            for item_schema in items_schema {
+                if !is_pure_schema(item_schema)? {
+                    return Ok(false);
+                }
+            }
+            Ok(!schema.contains(intern!(py, "extras_schema"))? || items_pure(intern!(py, "extras_schema"))?)
+        }
+        "union" => {
+            let choices: &PyList = schema.get_as_req(intern!(py, "choices"))?;
+            for choice in choices {
+                // choices may be `(schema, label)` tuples
+                let choice = match choice.downcast::<PyTuple>() {
+                    Ok(choice_tuple) => choice_tuple.get_item(0)?,
+                    Err(_) => choice,
+                };
+                if !is_pure_schema(choice)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DefaultType {
     None,
     Default(PyObject),
     DefaultFactory(PyObject),
+    // called with the data validated so far, from `default_factory_takes_data`
+    DefaultFactoryTakesData(PyObject),
 }
 
 impl DefaultType {
     pub fn new(schema: &PyDict) -> PyResult<Self> {
         let py = schema.py();
+        let takes_data = schema
+            .get_as(intern!(py, "default_factory_takes_data"))?
+            .unwrap_or(false);
         match (
             schema.get_as(intern!(py, "default"))?,
             schema.get_as(intern!(py, "default_factory"))?,
         ) {
             (Some(_), Some(_)) => py_schema_err!("'default' and 'default_factory' cannot be used together"),
+            (_, None) if takes_data => py_schema_err!("'default_factory_takes_data' requires a `default_factory`"),
             (Some(default), None) => Ok(Self::Default(default)),
+            (None, Some(default_factory)) if takes_data => Ok(Self::DefaultFactoryTakesData(default_factory)),
             (None, Some(default_factory)) => Ok(Self::DefaultFactory(default_factory)),
             (None, None) => Ok(Self::None),
         }
     }
 
-    pub fn default_value(&self, py: Python) -> PyResult<Option<PyObject>> {
+    /// `data` is passed to a factory which takes data, an empty dict is used if there's no data to pass
+    pub fn default_value(&self, py: Python, data: Option<&PyDict>) -> PyResult<Option<PyObject>> {
         match self {
             Self::Default(ref default) => Ok(Some(default.clone_ref(py))),
             Self::DefaultFactory(ref default_factory) => Ok(Some(default_factory.call0(py)?)),
+            Self::DefaultFactoryTakesData(ref default_factory) => {
+                let data = data.unwrap_or_else(|| PyDict::new(py));
+                Ok(Some(default_factory.call1(py, (data,))?))
+            }
             Self::None => Ok(None),
         }
     }
@@ -52,7 +115,7 @@ impl DefaultType {
 
 impl PyGcTraverse for DefaultType {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
-        if let Self::Default(obj) | Self::DefaultFactory(obj) = self {
+        if let Self::Default(obj) | Self::DefaultFactory(obj) | Self::DefaultFactoryTakesData(obj) = self {
             visit.call(obj)?;
         }
         Ok(())
@@ -66,13 +129,36 @@ enum OnError {
     Default,
 }
 
+/// Whether the default is used when the value is missing, when it's `None`, or both
+#[derive(Debug, Clone)]
+enum ApplyOn {
+    Missing,
+    Null,
+    Both,
+}
+
+impl ApplyOn {
+    fn missing(&self) -> bool {
+        matches!(self, Self::Missing | Self::Both)
+    }
+
+    fn null(&self) -> bool {
+        matches!(self, Self::Null | Self::Both)
+    }
+}
+
 #[derive(Debug)]
 pub struct WithDefaultValidator {
     default: DefaultType,
     on_error: OnError,
+    apply_on: ApplyOn,
     validator: Box<CombinedValidator>,
     validate_default: bool,
     copy_default: bool,
+    // whether the inner schema is pure, see `is_pure_schema`
+    pure_validator: bool,
+    // the validated `default` if the inner schema is pure, so it's only validated once, see `cache_default`
+    validated_default: OnceLock<PyObject>,
     name: String,
 }
 
@@ -100,6 +186,17 @@ impl BuildValidator for WithDefaultValidator {
             _ => unreachable!(),
         };
 
+        let apply_on = match schema.get_as::<&str>(intern!(py, "apply_on"))? {
+            Some("missing") | None => ApplyOn::Missing,
+            Some("null") => ApplyOn::Null,
+            Some("both") => ApplyOn::Both,
+            // schema validation means other values are impossible
+            _ => unreachable!(),
+        };
+        if apply_on.null() && matches!(default, DefaultType::None) {
+            return py_schema_err!("'apply_on = null' or 'both' requires a `default` or `default_factory`");
+        }
+
         let sub_schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
         let validator = Box::new(build_validator(sub_schema, config, definitions)?);
 
@@ -114,16 +211,23 @@ impl BuildValidator for WithDefaultValidator {
         Ok(Self {
             default,
             on_error,
+            apply_on,
             validator,
             validate_default: schema_or_config_same(schema, config, intern!(py, "validate_default"))?.unwrap_or(false),
             copy_default,
+            pure_validator: is_pure_schema(sub_schema)?,
+            validated_default: OnceLock::new(),
             name,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(WithDefaultValidator { default, validator });
+impl_py_gc_traverse!(WithDefaultValidator {
+    default,
+    validator,
+    validated_default
+});
 
 impl Validator for WithDefaultValidator {
     fn validate<'data>(
@@ -132,20 +236,18 @@ impl Validator for WithDefaultValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        if input.to_object(py).is(&PydanticUndefinedType::py_undefined()) {
-            Ok(self.default_value(py, None::<usize>, state)?.unwrap())
-        } else {
-            match self.validator.validate(py, input, state) {
-                Ok(v) => Ok(v),
-                Err(e) => match e {
-                    ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
-                    e => match self.on_error {
-                        OnError::Raise => Err(e),
-                        OnError::Default => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
-                        OnError::Omit => Err(ValError::Omit),
-                    },
-                },
+        let input_obj = input.to_object(py);
+        // `UNSET` is treated as missing, so it's still `UNSET` after validation if that's the default
+        if input_obj.is(&PydanticUndefinedType::py_undefined()) || UnsetType::is_unset(input_obj.as_ref(py)) {
+            if self.apply_on.missing() {
+                Ok(self.get_default(py, None::<usize>, state)?.unwrap())
+            } else {
+                Err(ValError::new(ErrorTypeDefaults::Missing, input))
             }
+        } else if self.apply_on.null() && input.is_none() {
+            Ok(self.get_default(py, None::<usize>, state)?.unwrap())
+        } else {
+            self.validate_value(py, input, state)
         }
     }
 
@@ -155,7 +257,78 @@ impl Validator for WithDefaultValidator {
         outer_loc: Option<impl Into<LocItem>>,
         state: &mut ValidationState,
     ) -> ValResult<Option<PyObject>> {
-        match self.default.default_value(py)? {
+        if self.apply_on.missing() {
+            self.get_default(py, outer_loc, state)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl WithDefaultValidator {
+    /// Whether there's a default for when the value is missing, a default only applied to `None` doesn't count
+    pub fn has_default(&self) -> bool {
+        self.apply_on.missing() && !matches!(self.default, DefaultType::None)
+    }
+
+    pub fn default_factory_takes_data(&self) -> bool {
+        matches!(self.default, DefaultType::DefaultFactoryTakesData(_))
+    }
+
+    pub fn omit_on_error(&self) -> bool {
+        matches!(self.on_error, OnError::Omit)
+    }
+
+    fn validate_value<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        match self.validator.validate(py, input, state) {
+            Ok(v) => Ok(v),
+            Err(e) => match e {
+                ValError::UseDefault => Ok(self.get_default(py, None::<usize>, state)?.ok_or(e)?),
+                ValError::Timeout(_) => Err(e),
+                e => match self.on_error {
+                    OnError::Raise => Err(e),
+                    OnError::Default => Ok(self.get_default(py, None::<usize>, state)?.ok_or(e)?),
+                    OnError::Omit => Err(ValError::Omit),
+                },
+            },
+        }
+    }
+
+    /// Whether the validated default can be reused: it has to be a `default` rather than from a `default_factory`,
+    /// validated by a pure schema, and without settings which could change the result
+    fn cache_default(&self, state: &ValidationState) -> bool {
+        let extra = state.extra();
+        self.validate_default
+            && self.pure_validator
+            && matches!(self.default, DefaultType::Default(_))
+            && !self.copy_default
+            && extra.context.is_none()
+            && extra.strict.is_none()
+            && extra.strict_overrides.is_none()
+    }
+
+    fn get_default(
+        &self,
+        py: Python<'_>,
+        outer_loc: Option<impl Into<LocItem>>,
+        state: &mut ValidationState,
+    ) -> ValResult<Option<PyObject>> {
+        let cache_default = self.cache_default(state);
+        if cache_default {
+            if let Some(validated_default) = self.validated_default.get() {
+                return Ok(Some(validated_default.clone_ref(py)));
+            }
+        }
+        match self.default.default_value(py, state.extra().data)? {
             Some(stored_dft) => {
                 let dft: Py<PyAny> = if self.copy_default {
                     let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
@@ -163,9 +336,16 @@ impl Validator for WithDefaultValidator {
                 } else {
                     stored_dft
                 };
-                if self.validate_default {
-                    match self.validate(py, dft.into_ref(py), state) {
-                        Ok(v) => Ok(Some(v)),
+                if self.validate_default && !UnsetType::is_unset(dft.as_ref(py)) {
+                    // validated without checking for `None`, which would apply the default again
+                    match self.validate_value(py, dft.into_ref(py), state) {
+                        Ok(v) => {
+                            if cache_default {
+                                // if another thread has set the value in the meantime, we use theirs
+                                return Ok(Some(self.validated_default.get_or_init(|| v).clone_ref(py)));
+                            }
+                            Ok(Some(v))
+                        }
                         Err(e) => {
                             if let Some(outer_loc) = outer_loc {
                                 Err(e.with_outer_location(outer_loc.into()))
@@ -181,18 +361,4 @@ impl Validator for WithDefaultValidator {
             None => Ok(None),
         }
     }
-
-    fn get_name(&self) -> &str {
-        &self.name
-    }
-}
-
-impl WithDefaultValidator {
-    pub fn has_default(&self) -> bool {
-        !matches!(self.default, DefaultType::None)
-    }
-
-    pub fn omit_on_error(&self) -> bool {
-        matches!(self.on_error, OnError::Omit)
-    }
 }
@@ -13,7 +13,16 @@ import pytest
 from dirty_equals import HasRepr, IsList
 
 import pydantic_core
-from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema, to_json
+from pydantic_core import (
+    PathFilter,
+    PydanticSerializationError,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    core_schema,
+    to_json,
+    to_jsonable_python,
+)
 
 from ..conftest import plain_repr
 from .test_dataclasses import IsStrictDict, on_pypy
@@ -31,7 +40,7 @@ def any_serializer():
 
 
 def test_repr(any_serializer):
-    assert plain_repr(any_serializer) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(any_serializer) == 'SchemaSerializer(serializer=Any(AnySerializer{dispatch:None}),definitions=[])'
 
 
 @dataclasses.dataclass(frozen=True)
@@ -130,7 +139,7 @@ def test_any_json(any_serializer, value, expected_json):
 def test_other_type():
     """Types with no serializer, fall back to any serializer"""
     v = SchemaSerializer(core_schema.is_instance_schema(int))
-    assert plain_repr(v) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(v) == 'SchemaSerializer(serializer=Any(AnySerializer{dispatch:None}),definitions=[])'
     assert v.to_json('foobar') == b'"foobar"'
 
 
@@ -263,6 +272,87 @@ def test_exclude_dict(any_serializer):
     assert any_serializer.to_python({1: 2, 3: 4}, exclude={-1}) == {1: 2, 3: 4}
 
 
+path_data = {
+    'items': [{'name': 'a', 'secret': 1}, {'name': 'b', 'secret': 2}],
+    'meta': {'secret_key': 'k', 'secret_value': 'v', 'size': 2},
+    'secret': 3,
+}
+
+
+@pytest.mark.parametrize(
+    'kwargs,expected',
+    [
+        (
+            {'exclude': PathFilter({'items.*.secret'})},
+            {'items': [{'name': 'a'}, {'name': 'b'}], 'meta': path_data['meta'], 'secret': 3},
+        ),
+        (
+            {'exclude': PathFilter({'**.secret', 'meta'})},
+            {'items': [{'name': 'a'}, {'name': 'b'}]},
+        ),
+        (
+            {'exclude': PathFilter({'meta.secret_*', 'items.1'})},
+            {'items': [{'name': 'a', 'secret': 1}], 'meta': {'size': 2}, 'secret': 3},
+        ),
+        ({'include': PathFilter({'items.*.name'})}, {'items': [{'name': 'a'}, {'name': 'b'}]}),
+        ({'include': PathFilter({'items.0', 'secret'})}, {'items': [{'name': 'a', 'secret': 1}], 'secret': 3}),
+        ({'include': PathFilter({'meta.*_key', 'secret'}), 'exclude': {'secret'}}, {'meta': {'secret_key': 'k'}}),
+    ],
+)
+def test_path_patterns(any_serializer, kwargs, expected):
+    assert any_serializer.to_python(path_data, **kwargs) == expected
+    assert json.loads(any_serializer.to_json(path_data, **kwargs)) == expected
+    assert to_jsonable_python(path_data, **kwargs) == expected
+
+
+def test_path_pattern_literal_key(any_serializer):
+    # strings in a set are always matched as whole keys, only `PathFilter` patterns are split on `.`
+    assert any_serializer.to_python({'a.b': 1, 'c': 2}, exclude={'a.b'}) == {'c': 2}
+    assert any_serializer.to_python({'a.b': 1, 'c': 2}, include={'a.b'}) == {'a.b': 1}
+    assert any_serializer.to_python({'a*': 1, 'ab': 2}, exclude={'a*'}) == {'ab': 2}
+    assert any_serializer.to_python({'a': {'b': 1}, 'a.b': 2}, exclude=PathFilter({'a.b'})) == {'a': {}, 'a.b': 2}
+
+
+def test_path_filter_repr():
+    assert repr(PathFilter(['a.b'])) == 'PathFilter(["a.b"])'
+    with pytest.raises(TypeError):
+        PathFilter([1])
+
+
+def test_exclude_predicate(any_serializer):
+    data = {'user': {'name': 'x', 'password': 'p'}, 'tokens': [{'password': 'q'}]}
+    calls = []
+
+    def exclude(path, value):
+        calls.append((path, value))
+        return path[-1] == 'password'
+
+    assert any_serializer.to_python(data, exclude=exclude) == {'user': {'name': 'x'}, 'tokens': [{}]}
+    assert calls == [
+        (('user',), data['user']),
+        (('user', 'name'), 'x'),
+        (('user', 'password'), 'p'),
+        (('tokens',), data['tokens']),
+        (('tokens', 0), {'password': 'q'}),
+        (('tokens', 0, 'password'), 'q'),
+    ]
+    assert any_serializer.to_json(data, exclude=exclude) == b'{"user":{"name":"x"},"tokens":[{}]}'
+
+
+def test_include_predicate(any_serializer):
+    data = {'a': [1, 2, 3], 'b': 4}
+    assert any_serializer.to_python(data, include=lambda path, value: path[0] == 'a' and value != 2) == {'a': [1, 3]}
+    assert to_jsonable_python(data, include=lambda path, value: path[0] == 'b') == {'b': 4}
+
+
+def test_predicate_error(any_serializer):
+    def exclude(path, value):
+        raise RuntimeError('predicate failed')
+
+    with pytest.raises(RuntimeError, match='predicate failed'):
+        any_serializer.to_python({'a': 1}, exclude=exclude)
+
+
 class FieldsSetModel:
     __pydantic_serializer__ = 42
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
@@ -325,6 +415,22 @@ def test_unknown_type_fallback(any_serializer: SchemaSerializer):
     assert any_serializer.to_json(f, fallback=fallback_func) == b'"fallback:<Foobar repr>"'
 
 
+def test_unknown_type_fallback_no_warning(recwarn):
+    def fallback_func(obj):
+        return f'fallback:{obj!r}'
+
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    f = Foobar()
+    assert s.to_python([f], fallback=fallback_func) == ['fallback:<Foobar repr>']
+    assert s.to_python([f], mode='json', fallback=fallback_func) == ['fallback:<Foobar repr>']
+    assert s.to_json([f], fallback=fallback_func) == b'["fallback:<Foobar repr>"]'
+    assert len(recwarn) == 0
+
+    # types the fallback function isn't called for still warn
+    with pytest.warns(UserWarning, match='Expected `str` but got `int`'):
+        assert s.to_python([1], fallback=fallback_func) == [1]
+
+
 def test_fallback_cycle_same(any_serializer: SchemaSerializer):
     def fallback_func(obj):
         return obj
@@ -433,6 +539,9 @@ def test_base64():
         (lambda: b'this is bytes', dict(bytes_mode='base64'), b'"dGhpcyBpcyBieXRlcw=="'),
         (lambda: bytearray(b'this is bytes'), {}, b'"this is bytes"'),
         (lambda: bytearray(b'this is bytes'), dict(bytes_mode='base64'), b'"dGhpcyBpcyBieXRlcw=="'),
+        (lambda: 2**60, {}, b'1152921504606846976'),
+        (lambda: 2**60, dict(bigint_mode='string'), b'"1152921504606846976"'),
+        (lambda: [2**53 - 1, -(2**70)], dict(bigint_mode='string'), b'[9007199254740991,"-1180591620717411303424"]'),
         (lambda: Decimal('12.34'), {}, b'"12.34"'),
         (lambda: MyEnum.a, {}, b'1'),
         (lambda: MyEnum.b, {}, b'"b"'),
@@ -602,3 +711,107 @@ def test_numpy_float(any_serializer):
         any_serializer.to_python(numpy.float16(1.0), mode='json')
     with pytest.raises(PydanticSerializationError, match=r"Unable to serialize unknown type: <class 'numpy\.float16'>"):
         any_serializer.to_json(numpy.float16(1.0))
+
+
+def test_register_ob_type_path(any_serializer):
+    class ObjectId:
+        def __init__(self, oid: str):
+            self.oid = oid
+
+        def __str__(self):
+            return self.oid
+
+    class SubObjectId(ObjectId):
+        pass
+
+    with pytest.raises(PydanticSerializationError):
+        to_json(ObjectId('abc'))
+
+    pydantic_core._pydantic_core.register_ob_type(ObjectId, 'path')
+    assert to_json(ObjectId('abc')) == b'"abc"'
+    assert to_json({ObjectId('abc'): SubObjectId('def')}) == b'{"abc":"def"}'
+    assert to_jsonable_python([ObjectId('abc'), SubObjectId('def')]) == ['abc', 'def']
+    assert any_serializer.to_python(ObjectId('abc')).oid == 'abc'
+    assert any_serializer.to_python(ObjectId('abc'), mode='json') == 'abc'
+
+
+def test_register_ob_type_unknown():
+    class Foo(Decimal):
+        pass
+
+    class Bar(Foo):
+        pass
+
+    pydantic_core._pydantic_core.register_ob_type(Foo, 'unknown')
+    # `Bar` would normally be serialized as a decimal, but is now passed straight to `fallback`
+    assert to_json(Bar('1.5'), fallback=lambda v: f'fallback: {v}') == b'"fallback: 1.5"'
+
+
+def test_register_ob_type_invalid():
+    with pytest.raises(ValueError, match='Invalid ob_type: "foobar"'):
+        pydantic_core._pydantic_core.register_ob_type(int, 'foobar')
+
+
+def test_any_dispatch():
+    class Point:
+        def __init__(self, x: int, y: int):
+            self.x = x
+            self.y = y
+
+    class SubPoint(Point):
+        pass
+
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.any_schema()),
+        {'ser_json_any_dispatch': {Point: lambda p: [p.x, p.y], Decimal: float}},
+    )
+    p = Point(1, 2)
+    assert s.to_json([p, Decimal('1.5'), 'foo']) == b'[[1,2],1.5,"foo"]'
+    assert s.to_python([p, Decimal('1.5'), 'foo'], mode='json') == [[1, 2], 1.5, 'foo']
+    # dispatch is only used in JSON mode
+    assert s.to_python([p, Decimal('1.5')]) == [p, Decimal('1.5')]
+    # and only for exact types
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.to_json([SubPoint(1, 2)])
+
+    # values nested within `any` values are dispatched too
+    nested = [{'p': p, 'items': (Decimal('2.5'), [p])}]
+    assert s.to_json(nested) == b'[{"p":[1,2],"items":[2.5,[[1,2]]]}]'
+    assert s.to_python(nested, mode='json') == [{'p': [1, 2], 'items': [2.5, [[1, 2]]]}]
+
+
+def test_any_dispatch_keys():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.any_schema(), core_schema.int_schema()),
+        {'ser_json_any_dispatch': {Decimal: lambda d: f'd{d}'}},
+    )
+    assert s.to_json({Decimal('1.5'): 1}) == b'{"d1.5":1}'
+
+    s = SchemaSerializer(core_schema.any_schema(), {'ser_json_any_dispatch': {Decimal: lambda d: f'd{d}'}})
+    assert s.to_json({'a': {Decimal('1.5'): 1}}) == b'{"a":{"d1.5":1}}'
+
+
+def test_any_dispatch_invalid():
+    with pytest.raises(SchemaError, match='`ser_json_any_dispatch` keys must be types, got 1'):
+        SchemaSerializer(core_schema.any_schema(), {'ser_json_any_dispatch': {1: str}})
+    with pytest.raises(SchemaError, match='`ser_json_any_dispatch` values must be callable, got 1'):
+        SchemaSerializer(core_schema.any_schema(), {'ser_json_any_dispatch': {int: 1}})
+
+
+@pytest.mark.parametrize('value', [float('inf'), float('-inf'), float('nan')])
+def test_any_inf_nan(value):
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_json([value]) == b'[null]'
+    # `json-compatible-dict` matches `to_json`
+    assert s.to_python({'a': [value]}, mode='json-compatible-dict') == {'a': [None]}
+    assert s.to_python([value], mode='json')[0] is value
+
+    s = SchemaSerializer(core_schema.any_schema(), {'ser_json_inf_nan': 'error'})
+    with pytest.raises(PydanticSerializationError, match="is not finite and can't be serialized to JSON"):
+        s.to_json({'a': value})
+    with pytest.raises(PydanticSerializationError, match="is not finite and can't be serialized to JSON"):
+        s.to_python([value], mode='json')
+    assert s.to_python([value])[0] is value
+
+    # `to_json` keeps writing the constants
+    assert to_json(value) in (b'Infinity', b'-Infinity', b'NaN')
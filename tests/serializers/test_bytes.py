@@ -4,7 +4,7 @@ from enum import Enum
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema
 
 
 def test_bytes():
@@ -126,3 +126,28 @@ def test_any_bytes_base64():
     assert s.to_json(b'foobar') == b'"Zm9vYmFy"'
     assert s.to_json({b'foobar': 123}) == b'{"Zm9vYmFy":123}'
     assert s.to_python({b'foobar': 123}, mode='json') == {'Zm9vYmFy': 123}
+
+
+@pytest.mark.parametrize(
+    'encoding,expected',
+    [('base64', 'aGk+Pz8='), ('base64url', 'aGk-Pz8='), ('hex', '68693e3f3f')],
+)
+def test_bytes_encoding(encoding, expected):
+    s = SchemaSerializer(core_schema.bytes_schema(encoding=encoding), {'ser_json_bytes': 'utf8'})
+    assert s.to_python(b'hi>??') == b'hi>??'
+    assert s.to_python(b'hi>??', mode='json') == expected
+    assert s.to_json(b'hi>??') == f'"{expected}"'.encode()
+
+
+def test_bytes_encoding_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.bytes_schema(encoding='hex'), core_schema.int_schema()))
+    assert s.to_json({b'\xca\xfe': 1}) == b'{"cafe":1}'
+
+
+def test_bytes_encoding_round_trip():
+    schema = core_schema.bytes_schema(encoding='base64')
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    data = b'\xd8\x07\xc1\xf5\xc0'
+    assert v.validate_json(s.to_json(data)) == data
+    assert base64.b64decode(s.to_python(data, mode='json')) == data
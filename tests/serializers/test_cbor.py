@@ -0,0 +1,110 @@
+import struct
+from datetime import date, datetime, timedelta, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (None, b'\xf6'),
+        (True, b'\xf5'),
+        (False, b'\xf4'),
+        (0, b'\x00'),
+        (23, b'\x17'),
+        (24, b'\x18\x18'),
+        (500, b'\x19\x01\xf4'),
+        (2**32, b'\x1b\x00\x00\x00\x01\x00\x00\x00\x00'),
+        (-1, b'\x20'),
+        (-500, b'\x39\x01\xf3'),
+        (2**64, b'\xc2\x49\x01' + b'\x00' * 8),
+        (-(2**64) - 1, b'\xc3\x49\x01' + b'\x00' * 8),
+        (2**130, b'\xc2\x51\x04' + b'\x00' * 16),
+        (1.5, b'\xfb' + struct.pack('>d', 1.5)),
+        ('abc', b'\x63abc'),
+        (b'\x00\x01', b'\x42\x00\x01'),
+        ([1, 2], b'\x82\x01\x02'),
+        ((), b'\x80'),
+        ({'a': 1}, b'\xa1\x61a\x01'),
+        (datetime(2022, 12, 2, 12, 13, 14, tzinfo=timezone.utc), b'\xc0\x742022-12-02T12:13:14Z'),
+        (
+            datetime(2022, 12, 2, 12, 13, 14, tzinfo=timezone(timedelta(hours=2))),
+            b'\xc0\x78\x192022-12-02T12:13:14+02:00',
+        ),
+        # only datetimes with an offset can be tagged
+        (datetime(2022, 12, 2, 12, 13, 14), b'\x732022-12-02T12:13:14'),
+        (date(2022, 12, 2), b'\x6a2022-12-02'),
+        (Decimal('1.23'), b'\xc4\x82\x21\x18\x7b'),
+        (Decimal('-5'), b'\xc4\x82\x00\x24'),
+        (Decimal('NaN'), b'\x63NaN'),
+        ([[], {}, [[1]]], b'\x83\x80\xa0\x81\x81\x01'),
+    ],
+)
+def test_any(value, expected):
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_cbor(value) == expected
+
+
+def test_typed_schemas():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.bytes_schema()),
+                'dt': core_schema.typed_dict_field(core_schema.datetime_schema()),
+                'd': core_schema.typed_dict_field(core_schema.decimal_schema()),
+            }
+        ),
+        core_schema.CoreConfig(ser_json_bytes='base64'),
+    )
+    value = {'b': b'\xff', 'dt': datetime(2022, 12, 2, 12, 13, 14, tzinfo=timezone.utc), 'd': Decimal('0.5')}
+    assert s.to_cbor(value) == (
+        b'\xa3'
+        b'\x61b\x41\xff'
+        b'\x62dt\xc0\x742022-12-02T12:13:14Z'
+        b'\x61d\xc4\x82\x20\x05'
+    )
+    # JSON output is unchanged
+    assert s.to_json(value) == b'{"b":"_w==","dt":"2022-12-02T12:13:14Z","d":"0.5"}'
+
+    value['dt'] = datetime(2022, 12, 2, 12, 13, 14)
+    assert b'\x62dt\x732022-12-02T12:13:14\x61d' in s.to_cbor(value)
+
+
+def test_omitted_fields_length():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    assert s.to_cbor({'a': 1, 'b': None}) == b'\xa2\x61a\x01\x61b\xf6'
+    assert s.to_cbor({'a': 1, 'b': None}, exclude_none=True) == b'\xa1\x61a\x01'
+    assert s.to_cbor({'a': 1, 'b': 2}, exclude={'a'}) == b'\xa1\x61b\x02'
+
+
+def test_large_container():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    expected_items = bytes(range(24)) + b''.join(b'\x18' + bytes([i]) for i in range(24, 30))
+    assert s.to_cbor(list(range(30))) == b'\x98\x1e' + expected_items
+
+
+@pytest.mark.parametrize('ser_json_bytes', ['utf8', 'base64', 'hex'])
+def test_bytes_fields(ser_json_bytes):
+    """
+    `bytes` are written as CBOR byte strings whatever `ser_json_bytes` is, rather than as encoded text
+    """
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.bytes_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.bytes_schema())),
+            }
+        ),
+        core_schema.CoreConfig(ser_json_bytes=ser_json_bytes),
+    )
+    assert s.to_cbor({'a': b'abc', 'b': [b'', b'\x00\xff']}) == b'\xa2\x61a\x43abc\x61b\x82\x40\x42\x00\xff'
@@ -71,6 +71,41 @@ def test_serialization_exclude():
         assert j == b'{"a":"hello"}'
 
 
+def test_exclude_default_factory():
+    @dataclasses.dataclass
+    class Bar:
+        a: str
+        b: list = dataclasses.field(default_factory=list)
+        c: dict = dataclasses.field(default_factory=lambda: {'x': 1})
+
+    schema = core_schema.dataclass_schema(
+        Bar,
+        core_schema.dataclass_args_schema(
+            'Bar',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.str_schema()),
+                core_schema.dataclass_field(
+                    name='b', schema=core_schema.with_default_schema(core_schema.list_schema(), default_factory=list)
+                ),
+                core_schema.dataclass_field(
+                    name='c',
+                    schema=core_schema.with_default_schema(
+                        core_schema.dict_schema(),
+                        default_factory=lambda: {'x': 1},
+                        serialization=core_schema.plain_serializer_function_ser_schema(lambda v: sorted(v)),
+                    ),
+                ),
+            ],
+        ),
+        ['a', 'b', 'c'],
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(Bar('x')) == {'a': 'x', 'b': [], 'c': ['x']}
+    assert s.to_python(Bar('x'), exclude_defaults=True) == {'a': 'x'}
+    assert s.to_python(Bar('x', [1], {'y': 2}), exclude_defaults=True) == {'a': 'x', 'b': [1], 'c': ['y']}
+    assert s.to_json(Bar('x'), exclude_defaults=True) == b'{"a":"x"}'
+
+
 def test_serialization_alias():
     schema = core_schema.dataclass_schema(
         Foo,
@@ -134,3 +134,24 @@ def test_defs_with_dict():
 
     assert s.to_json({'foo': {'key': 'val'}}) == b'{"foo":{"key":"val"}}'
     assert s.to_python({'foo': {'key': 'val'}}) == {'foo': {'key': 'val'}}
+
+
+def test_definition_template():
+    int_as_str = core_schema.int_schema(serialization=core_schema.to_string_ser_schema(when_used='always'))
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.tuple_positional_schema(
+                [
+                    core_schema.definition_reference_schema('Box', params={'T': core_schema.int_schema()}),
+                    core_schema.definition_reference_schema('Box', params={'T': int_as_str}),
+                ]
+            ),
+            [
+                core_schema.definition_template_schema(
+                    'Box', ['T'], core_schema.list_schema(core_schema.definition_param_schema('T'))
+                )
+            ],
+        )
+    )
+    assert s.to_python(([1], [2])) == ([1], ['2'])
+    assert s.to_json(([1], [2])) == b'[[1],["2"]]'
@@ -1,6 +1,9 @@
+import json
+
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+import pydantic_core
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_branch_nullable():
@@ -57,6 +60,65 @@ def test_cyclic_recursion():
         s.to_json(v)
 
 
+
+def branch_schema():
+    return core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Branch'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'sub_branch': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                    ),
+                },
+                ref='Branch',
+            )
+        ],
+    )
+
+
+def test_cyclic_recursion_null():
+    s = SchemaSerializer(branch_schema(), {'ser_circular_ref': 'null'})
+    v = {'name': 'root'}
+    v['sub_branch'] = v
+    assert s.to_python(v) == {'name': 'root', 'sub_branch': None}
+    assert s.to_json(v) == b'{"name":"root","sub_branch":null}'
+
+
+def test_cyclic_recursion_marker():
+    s = SchemaSerializer(branch_schema(), {'ser_circular_ref': 'marker'})
+    v = {'name': 'root'}
+    v['sub_branch'] = {'name': 'branch', 'sub_branch': v}
+    expected = {'name': 'root', 'sub_branch': {'name': 'branch', 'sub_branch': {'$circular_ref': id(v)}}}
+    assert s.to_python(v) == expected
+    assert s.to_python(v, mode='json') == expected
+    assert json.loads(s.to_json(v)) == expected
+
+
+@pytest.mark.parametrize('mode,truncated', [('null', None), ('marker', 'marker')])
+def test_max_depth(mode, truncated):
+    s = SchemaSerializer(branch_schema(), {'ser_circular_ref': mode, 'ser_max_depth': 2})
+    leaf = {'name': 'c', 'sub_branch': None}
+    v = {'name': 'a', 'sub_branch': {'name': 'b', 'sub_branch': leaf}}
+    if truncated == 'marker':
+        truncated = {'$max_depth': id(leaf)}
+    expected = {'name': 'a', 'sub_branch': {'name': 'b', 'sub_branch': truncated}}
+    assert s.to_python(v) == expected
+    assert json.loads(s.to_json(v)) == expected
+
+
+def test_max_depth_error():
+    s = SchemaSerializer(branch_schema(), {'ser_max_depth': 1})
+    assert s.to_python({'name': 'a', 'sub_branch': None}) == {'name': 'a', 'sub_branch': None}
+    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+        s.to_python({'name': 'a', 'sub_branch': {'name': 'b', 'sub_branch': None}})
+
+
+def test_invalid_circular_ref_mode():
+    with pytest.raises(SchemaError, match='Invalid circular reference serialization mode: `foo`'):
+        SchemaSerializer(branch_schema(), {'ser_circular_ref': 'foo'})
+
 def test_custom_ser():
     s = SchemaSerializer(
         core_schema.definitions_schema(
@@ -122,3 +184,39 @@ def test_recursive_function_deeper_ref():
         )
     )
     assert s.to_python({'a': {'b': {'b': {}}}}) == {'a': {'b': {'b': {}}}}
+
+
+def nested_branches(depth):
+    branch = {'name': 'leaf', 'sub_branch': None}
+    for i in range(depth - 1):
+        branch = {'name': str(i), 'sub_branch': branch}
+    return branch
+
+
+def test_recursion_limit():
+    v = nested_branches(10)
+    s = SchemaSerializer(branch_schema(), {'recursion_limit': 5})
+    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+        s.to_python(v)
+    assert s.to_python(v, recursion_limit=11) == v
+    assert json.loads(s.to_json(v, recursion_limit=11)) == v
+    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+        s.to_json(v, recursion_limit=5)
+
+
+def test_recursion_limit_raised():
+    default_limit = pydantic_core._pydantic_core._recursion_limit
+    v = nested_branches(default_limit + default_limit // 2)
+    s = SchemaSerializer(branch_schema())
+    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+        s.to_python(v)
+    assert s.to_python(v, recursion_limit=default_limit * 2) == v
+
+
+def test_recursion_limit_capped():
+    v = nested_branches(10_000)
+    s = SchemaSerializer(branch_schema(), {'recursion_limit': 65_535})
+    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+        s.to_python(v)
+    with pytest.raises(ValueError, match=r'Circular reference detected \(depth exceeded\)'):
+        s.to_json(v, recursion_limit=65_535)
@@ -0,0 +1,34 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_email():
+    s = SchemaSerializer(core_schema.email_schema())
+    assert s.to_python('john@example.com') == 'john@example.com'
+    assert s.to_json('john@example.com') == b'"john@example.com"'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (('John Doe', 'john@example.com'), 'John Doe <john@example.com>'),
+        (('Doe, John', 'john@example.com'), '"Doe, John" <john@example.com>'),
+        (('Say "hi"', 'john@example.com'), '"Say \\"hi\\"" <john@example.com>'),
+    ],
+)
+def test_name_email(value, expected):
+    s = SchemaSerializer(core_schema.name_email_schema())
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == expected
+    assert json.loads(s.to_json(value)) == expected
+
+
+def test_name_email_fallback():
+    s = SchemaSerializer(core_schema.name_email_schema())
+    with pytest.warns(
+        UserWarning, match='Expected `name-email` but got `int` - serialized value may not be as expected'
+    ):
+        assert s.to_python(123, mode='json') == 123
@@ -0,0 +1,31 @@
+import json
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class Color(Enum):
+    RED = 'red'
+    BLUE = 'blue'
+
+
+def test_enum():
+    s = SchemaSerializer(core_schema.enum_schema(Color))
+    assert s.to_python(Color.RED) is Color.RED
+    assert s.to_python(Color.RED, mode='json') == 'red'
+    assert json.loads(s.to_json(Color.BLUE)) == 'blue'
+
+
+def test_enum_dict_keys():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.enum_schema(Color), core_schema.int_schema()))
+    assert s.to_json({Color.RED: 1, Color.BLUE: 2}) == b'{"red":1,"blue":2}'
+
+
+def test_enum_fallback():
+    s = SchemaSerializer(core_schema.enum_schema(Color))
+    with pytest.warns(
+        UserWarning, match=r'Expected `enum\[Color\]` but got `str` - serialized value may not be as expected'
+    ):
+        assert s.to_python('red') == 'red'
@@ -0,0 +1,64 @@
+import json
+from enum import IntFlag
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema
+
+
+class Perm(IntFlag):
+    R = 4
+    W = 2
+    X = 1
+    RW = 6
+
+
+def test_int_flag():
+    s = SchemaSerializer(core_schema.int_flag_schema(Perm))
+    value = Perm.R | Perm.W
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == 6
+    assert type(s.to_python(value, mode='json')) is int
+    assert s.to_json(value) == b'6'
+
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.int_flag_schema(Perm), core_schema.int_schema()))
+    assert s.to_json({value: 1}) == b'{"6":1}'
+
+
+def test_int_flag_names():
+    s = SchemaSerializer(core_schema.int_flag_schema(Perm, serialize_as='names'))
+    assert s.to_python(Perm.R | Perm.W) == Perm.RW
+    assert s.to_python(Perm.RW | Perm.X, mode='json') == ['R', 'W', 'X']
+    assert s.to_python(Perm(0), mode='json') == []
+    assert s.to_json(Perm.X | Perm.R) == b'["R","X"]'
+
+    key_schema = core_schema.int_flag_schema(Perm, serialize_as='names')
+    s = SchemaSerializer(core_schema.dict_schema(key_schema, core_schema.int_schema()))
+    assert s.to_json({Perm.RW: 1}) == b'{"R|W":1}'
+
+
+def test_int_flag_names_round_trip():
+    schema = core_schema.int_flag_schema(Perm, serialize_as='names')
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    assert v.validate_json(s.to_json(Perm.R | Perm.X)) == Perm.R | Perm.X
+    assert v.validate_python(s.to_python(Perm.W, mode='json')) == Perm.W
+
+
+def test_int_flag_names_unknown_bits():
+    s = SchemaSerializer(core_schema.int_flag_schema(Perm, serialize_as='names'))
+    msg = "`Perm` value 9 has bits which aren't members and can't be serialized as names"
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(Perm(9), mode='json')
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(Perm(9))
+    # as an int the unknown bits are kept
+    assert json.loads(SchemaSerializer(core_schema.int_flag_schema(Perm)).to_json(Perm(9))) == 9
+
+
+def test_int_flag_fallback():
+    s = SchemaSerializer(core_schema.int_flag_schema(Perm))
+    with pytest.warns(
+        UserWarning, match=r'Expected `int-flag\[Perm\]` but got `int` - serialized value may not be as expected'
+    ):
+        assert s.to_python(6) == 6
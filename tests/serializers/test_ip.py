@@ -0,0 +1,36 @@
+import json
+from ipaddress import IPv4Address, IPv4Network, IPv6Address, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,value,expected',
+    [
+        (core_schema.ip_v4_schema(), IPv4Address('192.168.0.1'), '192.168.0.1'),
+        (core_schema.ip_v6_schema(), IPv6Address('2001:0db8::0001'), '2001:db8::1'),
+        (core_schema.ip_any_schema(), IPv6Address('fe80::1%eth0'), 'fe80::1%eth0'),
+        (core_schema.ip_network_schema(), IPv4Network('10.0.0.0/8'), '10.0.0.0/8'),
+        (core_schema.ip_network_schema(), IPv6Network('2001:db8::/32'), '2001:db8::/32'),
+    ],
+)
+def test_ip(schema, value, expected):
+    s = SchemaSerializer(schema)
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert json.loads(s.to_json(value)) == expected
+
+
+def test_ip_dict_keys():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.ip_any_schema(), core_schema.int_schema()))
+    assert s.to_json({IPv4Address('10.0.0.1'): 1, IPv6Address('::1'): 2}) == b'{"10.0.0.1":1,"::1":2}'
+
+
+def test_ip_fallback():
+    s = SchemaSerializer(core_schema.ip_network_schema())
+    with pytest.warns(
+        UserWarning, match='Expected `ip-network` but got `IPv4Address` - serialized value may not be as expected'
+    ):
+        assert s.to_python(IPv4Address('10.0.0.1')) == IPv4Address('10.0.0.1')
@@ -1,5 +1,6 @@
 import json
 import re
+from collections import deque
 from functools import partial
 
 import pytest
@@ -48,12 +49,25 @@ def test_list_str_fallback():
         assert v.to_json([1, 2, 3]) == b'[1,2,3]'
     assert [w.message.args[0] for w in warning_info.list] == [
         'Pydantic serializer warnings:\n'
-        '  Expected `str` but got `int` - serialized value may not be as expected\n'
-        '  Expected `str` but got `int` - serialized value may not be as expected\n'
-        '  Expected `str` but got `int` - serialized value may not be as expected'
+        '  Expected `str` but got `int` - serialized value may not be as expected (at `0`, `1`, `2`)'
     ]
 
 
+class MyList(list):
+    pass
+
+
+@pytest.mark.filterwarnings('error')
+@pytest.mark.parametrize('value', [deque([1, 2, 3]), deque([1, 2, 3], maxlen=5), MyList([1, 2, 3])])
+def test_list_deque_and_subclass(value):
+    v = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    assert v.to_python(value) == [1, 2, 3]
+    assert v.to_python(value, mode='json') == [1, 2, 3]
+    assert v.to_json(value) == b'[1,2,3]'
+    assert v.to_python(value, include={0, 2}) == [1, 3]
+    assert v.to_json(value, exclude={1}) == b'[1,3]'
+
+
 def test_tuple_any():
     v = SchemaSerializer(core_schema.tuple_variable_schema(core_schema.any_schema()))
     assert v.to_python(('a', 'b', 'c')) == ('a', 'b', 'c')
@@ -345,6 +359,22 @@ def test_positional_tuple():
     assert s.to_json((1, b'2')) == b'[1,"2"]'
 
 
+def test_positional_tuple_variadic():
+    tuple_schema = core_schema.tuple_positional_schema(
+        [core_schema.int_schema(), core_schema.bytes_schema(), core_schema.float_schema()], variadic_item_index=1
+    )
+    s = SchemaSerializer(tuple_schema)
+    assert s.to_python((1, b'a', b'b', 2.0), mode='json') == [1, 'a', 'b', 2.0]
+    assert s.to_python((1, 2.0), mode='json') == [1, 2.0]
+    assert s.to_json((1, b'a', 2.0)) == b'[1,"a",2.0]'
+
+    s = SchemaSerializer(core_schema.dict_schema(tuple_schema, core_schema.int_schema()))
+    assert s.to_json({(1, b'a', b'b', 2.0): 1}) == b'{"1,a,b,2.0":1}'
+
+    with pytest.raises(SchemaError, match='`variadic_item_index` is out of range'):
+        SchemaSerializer(core_schema.tuple_positional_schema([core_schema.int_schema()], variadic_item_index=1))
+
+
 def test_function_positional_tuple():
     def f(prefix, value, _info):
         return f'{prefix}{value}'
@@ -1,3 +1,5 @@
+from enum import Enum
+
 import pytest
 
 from pydantic_core import SchemaError, SchemaSerializer, core_schema
@@ -71,3 +73,60 @@ def test_bool_literal():
     assert s.to_python(False, mode='json') is False
     assert s.to_python(True) is True
     assert s.to_json(False) == b'false'
+
+
+def test_const():
+    s = SchemaSerializer(core_schema.const_schema('cat'))
+    assert s.to_python('cat') == 'cat'
+    assert s.to_python('cat', mode='json') == 'cat'
+    assert s.to_json('cat') == b'"cat"'
+
+    with pytest.warns(UserWarning, match=r"Expected `const\['cat'\]` but got `str` - serialized value may not"):
+        assert s.to_json('dog') == b'"dog"'
+
+
+def test_const_types():
+    assert SchemaSerializer(core_schema.const_schema(None)).to_json(None) == b'null'
+    assert SchemaSerializer(core_schema.const_schema(True)).to_json(True) == b'true'
+    assert SchemaSerializer(core_schema.const_schema(42)).to_json(42) == b'42'
+    assert SchemaSerializer(core_schema.const_schema(1.5)).to_json(1.5) == b'1.5'
+
+
+def test_const_enum():
+    class Tag(Enum):
+        cat = 'cat-tag'
+
+    s = SchemaSerializer(core_schema.const_schema(Tag.cat))
+    assert s.to_python(Tag.cat) is Tag.cat
+    assert s.to_python(Tag.cat, mode='json') == 'cat-tag'
+    assert s.to_json(Tag.cat) == b'"cat-tag"'
+
+
+def test_const_dict_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.const_schema(1), core_schema.int_schema()))
+    assert s.to_json({1: 2}) == b'{"1":2}'
+
+
+def test_const_in_tagged_model():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'kind': core_schema.typed_dict_field(core_schema.const_schema('cat')),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    assert s.to_json({'kind': 'cat', 'name': 'Tom'}) == b'{"kind":"cat","name":"Tom"}'
+
+
+@pytest.mark.parametrize(
+    'expected,message',
+    [
+        ([1, 2], '`const` expected value must be None, a bool, int, float, str or Enum member, got \\[1, 2\\]'),
+        (float('nan'), '`const` expected value must be a finite float, got nan'),
+        (2**64, 'expected value must be None, a bool, int, float, str or Enum member, got 18446744073709551616'),
+    ],
+)
+def test_const_invalid(expected, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaSerializer(core_schema.const_schema(expected))
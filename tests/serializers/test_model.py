@@ -12,7 +12,14 @@ except ImportError:
 import pytest
 from dirty_equals import IsJson
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema
+from pydantic_core import (
+    PathFilter,
+    PydanticSerializationError,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    core_schema,
+)
 
 from ..conftest import plain_repr
 
@@ -214,6 +221,49 @@ def test_alias():
     assert s.to_python(value) == IsStrictDict(Meow=0, Woof=1, bird=2)
 
 
+def test_alias_path():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'id': core_schema.model_field(core_schema.int_schema()),
+                    'name': core_schema.model_field(core_schema.str_schema(), serialization_alias=['user', 'name']),
+                    'bird': core_schema.model_field(core_schema.int_schema()),
+                    'age': core_schema.model_field(
+                        core_schema.nullable_schema(core_schema.int_schema()), serialization_alias=['user', 'age']
+                    ),
+                }
+            ),
+        )
+    )
+    value = BasicModel(id=1, name='sam', bird=2, age=42)
+    assert s.to_python(value) == IsStrictDict(id=1, user=IsStrictDict(name='sam', age=42), bird=2)
+    assert s.to_python(value, mode='json') == IsStrictDict(id=1, user=IsStrictDict(name='sam', age=42), bird=2)
+    # nested values are written where the first field in them is, like `to_python`, with no duplicate keys
+    assert s.to_json(value) == b'{"id":1,"user":{"name":"sam","age":42},"bird":2}'
+    assert s.to_json(value, exclude={'name'}) == b'{"id":1,"bird":2,"user":{"age":42}}'
+    assert s.to_json(BasicModel(id=1, name='sam', bird=2, age=None), exclude_none=True) == (
+        b'{"id":1,"user":{"name":"sam"},"bird":2}'
+    )
+    assert s.to_json(value, by_alias=False) == b'{"id":1,"name":"sam","bird":2,"age":42}'
+
+
+def test_alias_path_conflict():
+    with pytest.raises(SchemaError, match=r'Field `name`: serialization_alias path \["id", "name"\] conflicts with'):
+        SchemaSerializer(
+            core_schema.model_schema(
+                BasicModel,
+                core_schema.model_fields_schema(
+                    {
+                        'id': core_schema.model_field(core_schema.int_schema()),
+                        'name': core_schema.model_field(core_schema.str_schema(), serialization_alias=['id', 'name']),
+                    }
+                ),
+            )
+        )
+
+
 def test_model_wrong_warn():
     s = SchemaSerializer(
         core_schema.model_schema(
@@ -765,6 +815,108 @@ def test_property_include_exclude():
     assert s.to_json(Model(1), exclude={'b': [0]}) == b'{"a":1,"b":[2,"3"]}'
 
 
+def test_property_nested_include_exclude():
+    @dataclasses.dataclass
+    class Model:
+        a: int
+
+        @property
+        def b(self):
+            return {'x': 1, 'items': [{'id': 1, 'secret': 's1'}, {'id': 2, 'secret': 's2'}]}
+
+        @property
+        def c(self):
+            return None
+
+        @property
+        def d(self):
+            return 4
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(core_schema.int_schema())},
+                computed_fields=[
+                    core_schema.computed_field('b', core_schema.any_schema(), alias='B'),
+                    core_schema.computed_field('c', core_schema.nullable_schema(core_schema.int_schema())),
+                    core_schema.computed_field('d', core_schema.int_schema(), alias='D'),
+                ],
+            ),
+        )
+    )
+    items = [{'id': 1}, {'id': 2}]
+    for exclude in ({'b': {'x': ..., 'items': {'__all__': {'secret'}}}}, PathFilter({'b.x', 'b.items.*.secret'})):
+        assert s.to_python(Model(1), exclude=exclude) == {'a': 1, 'B': {'items': items}, 'c': None, 'D': 4}
+        assert s.to_json(Model(1), exclude=exclude, by_alias=False) == (
+            b'{"a":1,"b":{"items":[{"id":1},{"id":2}]},"c":null,"d":4}'
+        )
+
+    # later computed fields are still serialized after one is excluded for being `None`
+    assert s.to_python(Model(1), include={'b': {'x'}, 'c': ..., 'd': ...}, exclude_none=True) == {
+        'B': {'x': 1},
+        'D': 4,
+    }
+    assert s.to_json(Model(1), include={'b': {'x'}, 'c': ..., 'd': ...}, exclude_none=True) == b'{"B":{"x":1},"D":4}'
+
+    def exclude_secrets(path, value):
+        return path[-1] == 'secret'
+
+    assert s.to_json(Model(1), exclude=exclude_secrets, by_alias=False) == (
+        b'{"a":1,"b":{"x":1,"items":[{"id":1},{"id":2}]},"c":null,"d":4}'
+    )
+
+
+def test_excluded_property_not_called():
+    @dataclasses.dataclass
+    class Model:
+        a: int
+
+        @property
+        def b(self):
+            raise RuntimeError('b should not be called')
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(core_schema.int_schema())},
+                computed_fields=[core_schema.computed_field('b', core_schema.int_schema())],
+            ),
+        )
+    )
+    assert s.to_python(Model(1), exclude={'b'}) == {'a': 1}
+    assert s.to_python(Model(1), include={'a'}) == {'a': 1}
+    assert s.to_python(Model(1), exclude=PathFilter({'b'})) == {'a': 1}
+    assert s.to_json(Model(1), exclude={'b'}) == b'{"a":1}'
+    assert s.to_json(Model(1), exclude=PathFilter({'b'})) == b'{"a":1}'
+
+
+def test_property_called_once():
+    calls = []
+
+    @dataclasses.dataclass
+    class Model:
+        a: int
+
+        @property
+        def b(self):
+            calls.append(1)
+            return [1, 2]
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'a': core_schema.model_field(core_schema.int_schema())},
+                computed_fields=[core_schema.computed_field('b', core_schema.list_schema(core_schema.int_schema()))],
+            ),
+        )
+    )
+    assert s.to_json(Model(1)) == b'{"a":1,"b":[1,2]}'
+    assert len(calls) == 1
+
+
 @pytest.mark.skipif(cached_property is None, reason='cached_property is not available')
 def test_property_setter():
     class Square:
@@ -944,3 +1096,220 @@ def test_extra_custom_serializer():
     m.__pydantic_extra__ = {'extra': 'extra'}
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+def test_keep_models():
+    class Inner(BasicModel):
+        pass
+
+    class Outer(BasicModel):
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner,
+        core_schema.model_fields_schema(
+            {'inner_field': core_schema.model_field(core_schema.int_schema(), serialization_alias='innerField')}
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {
+                    'sub_model': core_schema.model_field(inner_schema, serialization_alias='subModel'),
+                    'sub_models': core_schema.model_field(core_schema.list_schema(inner_schema)),
+                    'other': core_schema.model_field(core_schema.any_schema()),
+                }
+            ),
+        )
+    )
+    inner = Inner(inner_field=1)
+    outer = Outer(sub_model=inner, sub_models=[inner], other={'x': inner})
+
+    assert s.to_python(outer) == {
+        'subModel': {'innerField': 1},
+        'sub_models': [{'innerField': 1}],
+        'other': {'x': inner},
+    }
+    v = s.to_python(outer, keep_models=True)
+    assert v == {'subModel': inner, 'sub_models': [inner], 'other': {'x': inner}}
+    assert v['subModel'] is inner
+    assert v['sub_models'][0] is inner
+
+    with pytest.raises(ValueError, match="`keep_models` cannot be used with mode='json'"):
+        s.to_python(outer, mode='json', keep_models=True)
+    with pytest.raises(ValueError, match="`keep_models` cannot be used with mode='json-compatible-dict'"):
+        s.to_python(outer, mode='json-compatible-dict', keep_models=True)
+
+
+def test_model_fields_from_attributes():
+    class Orm:
+        def __init__(self, **kwargs):
+            for key, value in kwargs.items():
+                setattr(self, key, value)
+
+        @property
+        def c(self):
+            return self.a * 2
+
+    s = SchemaSerializer(
+        core_schema.model_fields_schema(
+            {
+                'b': core_schema.model_field(core_schema.bytes_schema(), serialization_alias='B'),
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'c': core_schema.model_field(core_schema.int_schema()),
+                'd': core_schema.model_field(core_schema.int_schema(), serialization_exclude=True),
+            },
+            from_attributes=True,
+        )
+    )
+    orm = Orm(a=1, b=b'foo', d=4, other=5)
+    assert s.to_python(orm) == IsStrictDict(B=b'foo', a=1, c=2)
+    assert s.to_python(orm, by_alias=False, exclude={'c'}) == IsStrictDict(b=b'foo', a=1)
+    assert s.to_python(orm, mode='json') == IsStrictDict(B='foo', a=1, c=2)
+    assert s.to_json(orm) == b'{"B":"foo","a":1,"c":2}'
+    # dicts are still serialized as before
+    assert s.to_python({'a': 1, 'b': b'foo'}) == IsStrictDict(a=1, B=b'foo')
+
+    # missing attributes are omitted, other errors are raised
+    assert s.to_python(Orm(a=1)) == {'a': 1, 'c': 2}
+    with pytest.raises(TypeError, match="unsupported operand type"):
+        s.to_python(Orm(a=None, b=b'foo'))
+
+
+def test_model_fields_without_from_attributes():
+    s = SchemaSerializer(core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}))
+    m = BasicModel(a=1)
+    with pytest.warns(UserWarning, match='Expected `general-fields` but got `BasicModel`'):
+        assert s.to_python(m) is m
+
+
+def test_shared_refs():
+    class Inner(BasicModel):
+        pass
+
+    class Outer(BasicModel):
+        pass
+
+    inner_schema = core_schema.model_schema(
+        Inner, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(inner_schema),
+                    'b': core_schema.model_field(core_schema.list_schema(inner_schema)),
+                    'c': core_schema.model_field(
+                        core_schema.union_schema([core_schema.int_schema(), inner_schema])
+                    ),
+                }
+            ),
+        )
+    )
+    inner = Inner(x=1)
+    other = Inner(x=2)
+    outer = Outer(a=inner, b=[other, inner], c=other)
+
+    assert s.to_python(outer) == {'a': {'x': 1}, 'b': [{'x': 2}, {'x': 1}], 'c': {'x': 2}}
+    expected = {
+        '$id': 1,
+        'a': {'$id': 2, 'x': 1},
+        'b': [{'$id': 3, 'x': 2}, {'$ref': 2}],
+        'c': {'$ref': 3},
+    }
+    assert s.to_python(outer, shared_refs=True) == expected
+    assert s.to_python(outer, mode='json', shared_refs=True) == expected
+    assert json.loads(s.to_json(outer, shared_refs=True)) == expected
+
+
+def test_shared_refs_circular():
+    class Node(BasicModel):
+        pass
+
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Node'),
+            [
+                core_schema.model_schema(
+                    Node,
+                    core_schema.model_fields_schema(
+                        {
+                            'name': core_schema.model_field(core_schema.str_schema()),
+                            'next': core_schema.model_field(
+                                core_schema.nullable_schema(core_schema.definition_reference_schema('Node'))
+                            ),
+                        }
+                    ),
+                    ref='Node',
+                )
+            ],
+        )
+    )
+    a = Node(name='a', next=None)
+    b = Node(name='b', next=a)
+    a.next = b
+
+    with pytest.raises(ValueError, match='Circular reference detected'):
+        s.to_python(a)
+
+    expected = {'$id': 1, 'name': 'a', 'next': {'$id': 2, 'name': 'b', 'next': {'$ref': 1}}}
+    assert s.to_python(a, shared_refs=True) == expected
+    assert json.loads(s.to_json(a, shared_refs=True)) == expected
+
+
+def test_flatten():
+    class Address(BasicModel):
+        pass
+
+    address_schema = core_schema.model_schema(
+        Address,
+        core_schema.model_fields_schema(
+            {
+                'city': core_schema.model_field(core_schema.str_schema()),
+                'zip': core_schema.model_field(core_schema.str_schema()),
+            }
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'name': core_schema.model_field(core_schema.str_schema()),
+                    'address': core_schema.model_field(address_schema, flatten=True),
+                }
+            ),
+        )
+    )
+    m = BasicModel(name='sam', address=Address(city='London', zip='N1'))
+    assert s.to_python(m) == {'name': 'sam', 'city': 'London', 'zip': 'N1'}
+    assert s.to_python(m, mode='json') == {'name': 'sam', 'city': 'London', 'zip': 'N1'}
+    assert s.to_json(m) == b'{"name":"sam","city":"London","zip":"N1"}'
+    assert s.to_python(m, exclude={'address'}) == {'name': 'sam'}
+
+
+def test_flatten_key_collision():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'name': core_schema.model_field(core_schema.str_schema()),
+                    'extra': core_schema.model_field(core_schema.dict_schema(), flatten=True),
+                }
+            ),
+        )
+    )
+    assert s.to_json(BasicModel(name='sam', extra={'x': 1})) == b'{"name":"sam","x":1}'
+    # the flattened field would overwrite `name`, or write it twice in JSON
+    m = BasicModel(name='sam', extra={'name': 'other'})
+    with pytest.raises(PydanticSerializationError, match="Key 'name' is set by more than one field"):
+        s.to_python(m)
+    with pytest.raises(PydanticSerializationError, match="Key 'name' is set by more than one field"):
+        s.to_json(m)
+    # the same if the flattened field comes first
+    m = BasicModel(extra={'name': 'other'}, name='sam')
+    with pytest.raises(PydanticSerializationError, match="Key 'name' is set by more than one field"):
+        s.to_python(m)
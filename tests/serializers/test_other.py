@@ -19,7 +19,7 @@ def test_function_plain():
     s = SchemaSerializer(core_schema.with_info_plain_validator_function(lambda v, info: v + 1))
     # can't infer the type from plain function validators
     # insert_assert(plain_repr(s))
-    assert plain_repr(s) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(s) == 'SchemaSerializer(serializer=Any(AnySerializer{dispatch:None}),definitions=[])'
 
 
 def test_function_before():
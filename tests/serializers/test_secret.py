@@ -0,0 +1,67 @@
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+def test_str():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.str_schema()))
+    assert s.to_python('hunter2') == '**********'
+    assert s.to_python('hunter2', mode='json') == '**********'
+    assert s.to_json('hunter2') == b'"**********"'
+    assert s.to_python('hunter2', reveal_secrets=True) == 'hunter2'
+    assert s.to_json('hunter2', reveal_secrets=True) == b'"hunter2"'
+
+
+def test_bytes():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.bytes_schema()))
+    assert s.to_python(b'hunter2') == b'**********'
+    assert s.to_python(b'hunter2', mode='json') == '**********'
+    assert s.to_json(b'hunter2') == b'"**********"'
+    assert s.to_python(b'hunter2', reveal_secrets=True) == b'hunter2'
+    assert s.to_json(b'hunter2', reveal_secrets=True) == b'"hunter2"'
+
+
+def test_none():
+    s = SchemaSerializer(core_schema.nullable_schema(core_schema.secret_schema(core_schema.str_schema())))
+    assert s.to_python(None) is None
+    assert s.to_json(None) == b'null'
+
+
+def test_unexpected_type():
+    s = SchemaSerializer(core_schema.secret_schema(core_schema.str_schema()))
+    with pytest.warns(UserWarning, match='Expected `secret` but got `int` - serialized value may not be as expected'):
+        assert s.to_python(123) == '**********'
+
+
+def test_typed_dict():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'user': core_schema.typed_dict_field(core_schema.str_schema()),
+                'password': core_schema.typed_dict_field(core_schema.secret_schema(core_schema.str_schema())),
+            }
+        )
+    )
+    value = {'user': 'alice', 'password': 'hunter2'}
+    assert s.to_python(value) == {'user': 'alice', 'password': '**********'}
+    assert s.to_json(value) == b'{"user":"alice","password":"**********"}'
+    assert s.to_python(value, reveal_secrets=True) == value
+    assert s.to_json(value, reveal_secrets=True) == b'{"user":"alice","password":"hunter2"}'
+
+
+def test_list():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.secret_schema(core_schema.str_schema())))
+    assert s.to_json(['a', 'b']) == b'["**********","**********"]'
+    assert s.to_python(['a', 'b'], reveal_secrets=True) == ['a', 'b']
+
+
+def test_dict_keys():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.secret_schema(core_schema.str_schema()), core_schema.int_schema())
+    )
+    value = {'a': 1, 'b': 2}
+    with pytest.raises(PydanticSerializationError, match="Secrets can't be used as JSON object keys"):
+        s.to_json(value)
+    with pytest.raises(PydanticSerializationError, match="Secrets can't be used as JSON object keys"):
+        s.to_python(value, mode='json')
+    assert s.to_json(value, reveal_secrets=True) == b'{"a":1,"b":2}'
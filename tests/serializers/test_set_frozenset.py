@@ -45,3 +45,32 @@ def test_set_fallback(input_value, json_output, warning_type):
 
     with pytest.warns(UserWarning, match=f'Expected {warning_type} - serialized value may not be as expected'):
         assert json.loads(v.to_json(input_value)) == json_output
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.set_schema(), {'d', 'b', 'c', 'a'}),
+        (core_schema.frozenset_schema(), frozenset({'d', 'b', 'c', 'a'})),
+        (core_schema.any_schema(), {'d', 'b', 'c', 'a'}),
+        (core_schema.any_schema(), frozenset({'d', 'b', 'c', 'a'})),
+    ],
+)
+def test_deterministic(schema, value):
+    s = SchemaSerializer(schema, core_schema.CoreConfig(deterministic=True))
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == ['a', 'b', 'c', 'd']
+    assert s.to_json(value) == b'["a","b","c","d"]'
+
+
+def test_deterministic_mixed_types():
+    s = SchemaSerializer(core_schema.set_schema(), core_schema.CoreConfig(deterministic=True))
+    # items which can't be compared are grouped by the qualified name of their type
+    assert s.to_json({'a', 2, 1, None}) == b'[null,1,2,"a"]'
+    assert s.to_python({'b', 2, 'a', 1}, mode='json') == [1, 2, 'a', 'b']
+
+
+def test_deterministic_unorderable():
+    s = SchemaSerializer(core_schema.set_schema(), core_schema.CoreConfig(deterministic=True))
+    with pytest.raises(TypeError, match=r"Items of type `builtins.object` can't be ordered deterministically"):
+        s.to_python({object(), object()}, mode='json')
@@ -3,7 +3,14 @@ from enum import IntEnum
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaError,
+    SchemaSerializer,
+    core_schema,
+    to_json,
+    to_jsonable_python,
+)
 
 try:
     import numpy
@@ -163,3 +170,85 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+    # as does the JSON compatible python value
+    if expected_json == 'null':
+        assert s.to_python(value, mode='json-compatible-dict') is None
+    else:
+        assert s.to_python(value, mode='json-compatible-dict') is value
+
+
+@pytest.mark.parametrize('value', [float('inf'), float('-inf'), float('nan')])
+def test_float_inf_and_nan_error(value):
+    s = SchemaSerializer(core_schema.float_schema(), {'ser_json_inf_nan': 'error'})
+
+    assert s.to_python(value) is value
+    with pytest.raises(PydanticSerializationError, match="Float -?(inf|NaN) is not finite and can't be serialized"):
+        s.to_json(value)
+    with pytest.raises(PydanticSerializationError, match='set `ser_json_inf_nan` to allow it'):
+        s.to_python(value, mode='json')
+
+    assert s.to_json(1.5) == b'1.5'
+    assert s.to_python(1.5, mode='json') == 1.5
+
+
+def test_json_compatible_dict():
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.int_schema(), core_schema.tuple_variable_schema(core_schema.float_schema())
+        )
+    )
+    value = {1: (1.5, float('inf')), 2: ()}
+    assert s.to_python(value, mode='json-compatible-dict') == {'1': [1.5, None], '2': []}
+    assert json.loads(s.to_json(value)) == s.to_python(value, mode='json-compatible-dict')
+
+
+@pytest.mark.parametrize(
+    'value,expected_json,config',
+    [
+        (2**53 - 1, '9007199254740991', {}),
+        (2**53, '9007199254740992', {}),
+        (2**70, '1180591620717411303424', {'ser_json_bigint': 'number'}),
+        (2**53 - 1, '9007199254740991', {'ser_json_bigint': 'string'}),
+        (-(2**53) + 1, '-9007199254740991', {'ser_json_bigint': 'string'}),
+        (2**53, '"9007199254740992"', {'ser_json_bigint': 'string'}),
+        (-(2**53), '"-9007199254740992"', {'ser_json_bigint': 'string'}),
+        (2**70, '"1180591620717411303424"', {'ser_json_bigint': 'string'}),
+        (IntSubClass(2**60), '"1152921504606846976"', {'ser_json_bigint': 'string'}),
+        (2**53 - 1, '9007199254740991', {'ser_json_bigint': 'error'}),
+    ],
+)
+def test_int_bigint(value, expected_json, config):
+    s = SchemaSerializer(core_schema.int_schema(), config)
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == json.loads(expected_json)
+    assert s.to_json(value).decode() == expected_json
+
+
+def test_uint64_bigint():
+    s = SchemaSerializer(core_schema.uint64_schema(), {'ser_json_bigint': 'string'})
+    assert s.to_python(2**64 - 1) == 2**64 - 1
+    assert s.to_json(2**64 - 1) == b'"18446744073709551615"'
+    assert s.to_json(42) == b'42'
+
+
+def test_int_bigint_error():
+    s = SchemaSerializer(core_schema.int_schema(), {'ser_json_bigint': 'error'})
+    assert s.to_python(2**70) == 2**70
+
+    msg = 'Integer 1180591620717411303424 is too large to be represented exactly in JSON'
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_python(2**70, mode='json')
+    with pytest.raises(PydanticSerializationError, match=msg):
+        s.to_json(2**70)
+
+
+def test_int_bigint_any():
+    assert to_jsonable_python([1, 2**60], bigint_mode='string') == [1, '1152921504606846976']
+    with pytest.raises(PydanticSerializationError, match='Integer 1152921504606846976 is too large'):
+        to_json({'a': 2**60}, bigint_mode='error')
+
+
+def test_int_bigint_invalid():
+    with pytest.raises(SchemaError, match='Invalid bigint serialization mode: `foo`'):
+        to_json(1, bigint_mode='foo')
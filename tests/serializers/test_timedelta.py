@@ -36,6 +36,46 @@ def test_timedelta_float():
     assert v.to_json(timedelta(seconds=4, microseconds=500_000)) == b'4.5'
 
 
+def test_timedelta_seconds_int():
+    v = SchemaSerializer(core_schema.timedelta_schema(), config={'ser_json_timedelta': 'seconds_int'})
+    assert v.to_python(timedelta(seconds=4, microseconds=500_000), mode='json') == 4
+    assert v.to_json(timedelta(days=1, seconds=4, microseconds=500_000)) == b'86404'
+    assert v.to_json(-timedelta(seconds=4, microseconds=500_000)) == b'-4'
+
+
+def test_timedelta_mode_override():
+    s = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.timedelta_schema()),
+            'b': core_schema.typed_dict_field(
+                core_schema.timedelta_schema(serialization=core_schema.timedelta_mode_ser_schema('float'))
+            ),
+            'c': core_schema.typed_dict_field(
+                core_schema.timedelta_schema(serialization=core_schema.timedelta_mode_ser_schema('seconds_int'))
+            ),
+        }
+    )
+    v = SchemaSerializer(s)
+    value = {'a': timedelta(minutes=1, seconds=30), 'b': timedelta(seconds=1.5), 'c': timedelta(hours=1, seconds=0.5)}
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == {'a': 'PT90S', 'b': 1.5, 'c': 3600}
+    assert v.to_json(value) == b'{"a":"PT90S","b":1.5,"c":3600}'
+
+    v = SchemaSerializer(s, config={'ser_json_timedelta': 'float'})
+    assert v.to_json(value) == b'{"a":90.0,"b":1.5,"c":3600}'
+
+
+def test_timedelta_mode_override_key():
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.timedelta_schema(serialization=core_schema.timedelta_mode_ser_schema('seconds_int')),
+            core_schema.int_schema(),
+        )
+    )
+    assert v.to_python({timedelta(minutes=2): 1}, mode='json') == {'120': 1}
+    assert v.to_json({timedelta(minutes=2): 1}) == b'{"120":1}'
+
+
 def test_timedelta_key():
     v = SchemaSerializer(core_schema.dict_schema(core_schema.timedelta_schema(), core_schema.int_schema()))
     assert v.to_python({timedelta(days=2, hours=3, minutes=4): 1}) == {timedelta(days=2, hours=3, minutes=4): 1}
@@ -1,11 +1,13 @@
+import dataclasses
 import json
+import re
 from typing import Any, Dict
 
 import pytest
 from dirty_equals import IsStrictDict
 from typing_extensions import TypedDict
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 @pytest.mark.parametrize('extra_behavior_kw', [{}, {'extra_behavior': 'ignore'}, {'extra_behavior': None}])
@@ -170,6 +172,102 @@ def test_exclude_default():
     assert v.to_json({'foo': 1, 'bar': b'[default]'}, exclude_defaults=True) == b'{"foo":1}'
 
 
+def test_exclude_default_factory():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return [1, 2]
+
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.list_schema(), default_factory=factory)
+                )
+            }
+        )
+    )
+    assert v.to_python({'foo': [1, 2]}, exclude_defaults=True) == {}
+    assert v.to_python({'foo': [1]}, exclude_defaults=True) == {'foo': [1]}
+    assert v.to_json({'foo': [1, 2]}, exclude_defaults=True) == b'{}'
+    assert len(calls) == 1
+
+
+def test_exclude_default_custom_serialization():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return [1, 2]
+
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.list_schema(),
+                        default_factory=factory,
+                        serialization=core_schema.plain_serializer_function_ser_schema(lambda v: len(v)),
+                    )
+                )
+            }
+        )
+    )
+    assert v.to_python({'foo': [1, 2]}) == {'foo': 2}
+    assert v.to_python({'foo': [1, 2]}, exclude_defaults=True) == {}
+    assert v.to_python({'foo': [3, 4]}, exclude_defaults=True) == {'foo': 2}
+    assert v.to_json({'foo': [1, 2]}, exclude_defaults=True) == b'{}'
+    assert len(calls) == 1
+
+
+def test_exclude_default_identity():
+    default = [1, 2]
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.list_schema(), default=default, default_comparison='identity'
+                    )
+                )
+            }
+        )
+    )
+    assert v.to_python({'foo': default}, exclude_defaults=True) == {}
+    assert v.to_python({'foo': [1, 2]}, exclude_defaults=True) == {'foo': [1, 2]}
+
+
+def test_exclude_default_serialized():
+    @dataclasses.dataclass(eq=False)
+    class Point:
+        x: int
+        y: int
+
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.any_schema(), default=Point(1, 2), default_comparison='serialized'
+                    )
+                )
+            }
+        )
+    )
+    # `Point` doesn't implement `__eq__`, but equal points serialize to the same value
+    assert v.to_python({'foo': Point(1, 2)}, exclude_defaults=True) == {}
+    assert v.to_json({'foo': Point(1, 2)}, exclude_defaults=True) == b'{}'
+    assert v.to_python({'foo': Point(2, 1)}, exclude_defaults=True, mode='json') == {'foo': {'x': 2, 'y': 1}}
+
+
+def test_exclude_default_comparison_invalid():
+    with pytest.raises(SchemaError, match='Invalid default_comparison: `foobar`'):
+        SchemaSerializer(
+            core_schema.with_default_schema(core_schema.int_schema(), default=1, default_comparison='foobar')
+        )
+
+
 def test_function_plain_field_serializer_to_python():
     class Model(TypedDict):
         x: int
@@ -333,3 +431,91 @@ def test_extra_custom_serializer():
     m = {'extra': 'extra'}
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+def test_closed_extra_items():
+    schema = core_schema.typed_dict_schema(
+        {},
+        closed=True,
+        extras_schema=core_schema.any_schema(
+            serialization=core_schema.plain_serializer_function_ser_schema(lambda v: v + ' bam!')
+        ),
+    )
+    s = SchemaSerializer(schema)
+
+    assert s.to_python({'extra': 'extra'}) == {'extra': 'extra bam!'}
+
+
+def test_alias_generator():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'field_b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='b'),
+            }
+        ),
+        core_schema.CoreConfig(alias_generator=str.upper),
+    )
+    value = {'field_a': 1, 'field_b': 2}
+    assert s.to_python(value) == {'FIELD_A': 1, 'b': 2}
+    assert s.to_json(value) == b'{"FIELD_A":1,"b":2}'
+    assert s.to_python(value, by_alias=False) == value
+
+
+def test_alias_path():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias=['user', 'name']),
+                'age': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias=['user', 'age']),
+                'other': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias=['other']),
+            }
+        )
+    )
+    value = {'id': 1, 'name': 'sam', 'age': 42, 'other': 2}
+    assert s.to_python(value) == {'id': 1, 'user': {'name': 'sam', 'age': 42}, 'other': 2}
+    assert s.to_python(value, mode='json') == {'id': 1, 'user': {'name': 'sam', 'age': 42}, 'other': 2}
+    assert s.to_json(value) == b'{"id":1,"user":{"name":"sam","age":42},"other":2}'
+    assert s.to_python(value, by_alias=False) == value
+    assert s.to_python(value, exclude={'age'}) == {'id': 1, 'user': {'name': 'sam'}, 'other': 2}
+
+
+@pytest.mark.parametrize(
+    'fields,message',
+    [
+        (
+            {
+                'user': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias=['user', 'name']),
+            },
+            'Field `name`: serialization_alias path ["user", "name"] conflicts with field `user`',
+        ),
+        (
+            {
+                'user_id': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='user'),
+                'name': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias=['user', 'name']),
+            },
+            'Field `name`: serialization_alias path ["user", "name"] conflicts with field `user_id`',
+        ),
+        (
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias=['x', 'y']),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias=['x', 'y', 'z']),
+            },
+            'Field `a`: serialization_alias path ["x", "y"] conflicts with path ["x", "y", "z"] of field `b`',
+        ),
+    ],
+)
+def test_alias_path_conflict(fields, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaSerializer(core_schema.typed_dict_schema(fields))
+
+
+def test_alias_path_empty():
+    with pytest.raises(SchemaError, match='serialization_alias paths should have at least one element'):
+        SchemaSerializer(
+            core_schema.typed_dict_schema(
+                {'name': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias=[])}
+            )
+        )
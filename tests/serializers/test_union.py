@@ -510,3 +510,17 @@ def test_union_serializes_list_of_model_subclass_from_definition() -> None:
     )
 
     assert s.to_python([DBUser(name='John', password='secret')]) == [{'name': 'John'}]
+
+
+def test_tagged_union_otherwise():
+    s = SchemaSerializer(
+        core_schema.tagged_union_schema(
+            choices={'int': core_schema.int_schema()},
+            discriminator=lambda v: 'int' if isinstance(v, int) else None,
+            otherwise=core_schema.list_schema(core_schema.int_schema()),
+        )
+    )
+    assert s.to_python(1) == 1
+    # without `otherwise` this would warn about an unexpected value
+    assert s.to_python([1, 2]) == [1, 2]
+    assert s.to_json([1, 2]) == b'[1,2]'
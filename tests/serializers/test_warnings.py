@@ -0,0 +1,137 @@
+import warnings
+
+import pytest
+
+from pydantic_core import (
+    PydanticSerializationUnexpectedValue,
+    PydanticSerializationWarning,
+    SchemaSerializer,
+    core_schema,
+)
+
+
+def test_category():
+    s = SchemaSerializer(core_schema.int_schema())
+    assert issubclass(PydanticSerializationWarning, UserWarning)
+    with pytest.warns(PydanticSerializationWarning, match='Expected `int` but got `str`'):
+        assert s.to_python('a') == 'a'
+
+    with warnings.catch_warnings():
+        warnings.simplefilter('error', PydanticSerializationWarning)
+        with pytest.raises(PydanticSerializationWarning, match='Expected `int` but got `str`'):
+            s.to_json('a')
+
+    with warnings.catch_warnings():
+        warnings.simplefilter('error', UserWarning)
+        warnings.simplefilter('ignore', PydanticSerializationWarning)
+        assert s.to_json('a') == b'"a"'
+
+
+def test_duplicates():
+    s = SchemaSerializer(core_schema.set_schema(core_schema.int_schema()))
+    with pytest.warns(PydanticSerializationWarning) as warning_info:
+        assert s.to_python({'a', 'b', 'c'}) == {'a', 'b', 'c'}
+    assert [w.message.args[0] for w in warning_info.list] == [
+        'Pydantic serializer warnings:\n  Expected `int` but got `str` - serialized value may not be as expected'
+    ]
+
+
+@pytest.mark.parametrize('mode', ['python', 'json'])
+def test_path(mode):
+    item_schema = core_schema.typed_dict_schema({'name': core_schema.typed_dict_field(core_schema.str_schema())})
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'items': core_schema.typed_dict_field(core_schema.list_schema(item_schema)),
+                'tags': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                ),
+            }
+        )
+    )
+    value = {'items': [{'name': 'a'}, {'name': 1}], 'tags': {'x': 1, 'y': 'two'}}
+    with pytest.warns(PydanticSerializationWarning) as warning_info:
+        if mode == 'json':
+            s.to_json(value)
+        else:
+            s.to_python(value)
+    assert [w.message.args[0] for w in warning_info.list] == [
+        'Pydantic serializer warnings:\n'
+        '  Expected `str` but got `int` - serialized value may not be as expected (at `items.1.name`)\n'
+        '  Expected `int` but got `str` - serialized value may not be as expected (at `tags.y`)'
+    ]
+
+
+def test_paths_aggregated():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    collector = []
+    with pytest.warns(PydanticSerializationWarning) as warning_info:
+        s.to_python({'a': 'x', 'b': 1, 'c': 'y', 'd': [1]}, warnings_collector=collector)
+    [warning] = warning_info.list
+    assert warning.message.args[0] == (
+        'Pydantic serializer warnings:\n'
+        '  Expected `int` but got `str` - serialized value may not be as expected (at `a`, `c`)\n'
+        '  Expected `int` but got `list` - serialized value may not be as expected (at `d`)'
+    )
+    assert [d['locs'] for d in collector] == [(('a',), ('c',)), (('d',),)]
+
+
+def test_stack_level():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.warns(PydanticSerializationWarning) as warning_info:
+        s.to_python('a')
+    assert warning_info.list[0].filename == __file__
+
+
+def test_tuple_path():
+    s = SchemaSerializer(core_schema.tuple_positional_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    with pytest.warns(PydanticSerializationWarning, match=r'Expected `str` but got `int` .+ \(at `1`\)'):
+        assert s.to_python((1, 2)) == (1, 2)
+
+
+@pytest.mark.filterwarnings('error')
+@pytest.mark.parametrize('method', ['to_python', 'to_json', 'to_cbor'])
+def test_details(method):
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.bool_schema())),
+            }
+        )
+    )
+    collector = []
+    getattr(s, method)({'a': 'x', 'b': [True, 'y']}, warnings=False, warnings_collector=collector)
+    assert collector == [
+        {
+            'type': 'unexpected_type',
+            'expected': 'int',
+            'input_type': 'str',
+            'locs': (('a',),),
+            'msg': 'Expected `int` but got `str` - serialized value may not be as expected',
+        },
+        {
+            'type': 'unexpected_type',
+            'expected': 'bool',
+            'input_type': 'str',
+            'locs': (('b', 1),),
+            'msg': 'Expected `bool` but got `str` - serialized value may not be as expected',
+        },
+    ]
+
+
+def test_details_unexpected_value():
+    def f(value):
+        raise PydanticSerializationUnexpectedValue('not a valid colour')
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(f))
+        )
+    )
+    collector = []
+    with pytest.warns(PydanticSerializationWarning):
+        s.to_python(['red'], warnings_collector=collector)
+    assert collector == [
+        {'type': 'unexpected_value', 'locs': ((0,),), 'msg': 'PydanticSerializationUnexpectedValue(not a valid colour)'}
+    ]
@@ -79,7 +79,9 @@ a = A()
             let schema: &PyDict = locals.get_item("schema").unwrap().unwrap().extract().unwrap();
             let serialized: Vec<u8> = SchemaSerializer::py_new(py, schema, None)
                 .unwrap()
-                .to_json(py, a, None, None, None, true, false, false, false, false, true, None)
+                .to_json(
+                    py, a, None, None, None, true, false, false, false, false, true, None, false, None, false, None,
+                )
                 .unwrap()
                 .extract(py)
                 .unwrap();
@@ -110,7 +112,9 @@ json_input = '{"a": "something"}'
             let json_input: &PyAny = locals.get_item("json_input").unwrap().unwrap().extract().unwrap();
             let binding = SchemaValidator::py_new(py, schema, None)
                 .unwrap()
-                .validate_json(py, json_input, None, None, None)
+                .validate_json(
+                    py, json_input, None, None, None, None, None, None, None, None, None, None, None,
+                )
                 .unwrap();
             let validation_result: &PyAny = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -0,0 +1,30 @@
+import json
+
+import pytest
+
+from pydantic_core import _bench
+
+
+def test_run():
+    results = _bench.run(number=1, repeat=1)
+    assert {r.name for r in results} == set(_bench.BENCHMARKS)
+    assert {r.operation for r in results} == {'build', 'validate_python', 'validate_json', 'to_python', 'to_json'}
+    assert all(r.best > 0 and r.mean >= r.best for r in results)
+
+
+def test_run_unknown():
+    with pytest.raises(ValueError, match=r"Unknown benchmarks \['nope'\]"):
+        _bench.run(['nope'])
+
+
+def test_main_json(capsys):
+    _bench.main(['large_list', '--number', '1', '--repeat', '1', '--json'])
+    output = json.loads(capsys.readouterr().out)
+    assert output['environment']['pydantic_core'] == _bench.__version__
+    assert [r['operation'] for r in output['results']] == [
+        'build',
+        'validate_python',
+        'validate_json',
+        'to_python',
+        'to_json',
+    ]
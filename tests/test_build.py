@@ -2,7 +2,7 @@ import pickle
 
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, validate_core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, validate_core_schema
 from pydantic_core import core_schema as cs
 
 
@@ -32,6 +32,35 @@ def test_schema_as_string():
     assert v.validate_python('tRuE') is True
 
 
+@pytest.mark.parametrize(
+    'schema',
+    [
+        '{"type": "list", "items_schema": {"type": "int", "ge": 0}}',
+        b'{"type": "list", "items_schema": {"type": "int", "ge": 0}}',
+        bytearray(b'{"type": "list", "items_schema": {"type": "int", "ge": 0}}'),
+    ],
+)
+def test_schema_from_json(schema):
+    v = SchemaValidator(schema, {'strict': True})
+    assert v.validate_python([1, 2]) == [1, 2]
+    assert v.validate_json('[1, 2]') == [1, 2]
+    with pytest.raises(ValidationError, match='Input should be greater than or equal to 0'):
+        v.validate_python([-1])
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(['1'])
+
+
+def test_schema_from_json_pickle():
+    v1 = SchemaValidator('{"type": "bool"}')
+    v2 = pickle.loads(pickle.dumps(v1))
+    assert v2.validate_python('tRuE') is True
+
+
+def test_schema_from_json_invalid():
+    with pytest.raises(SchemaError, match='^Invalid JSON core schema: EOF while parsing an object at line 1'):
+        SchemaValidator('{"type": "bool"')
+
+
 def test_schema_wrong_type(pydantic_version):
     with pytest.raises(SchemaError) as exc_info:
         validate_core_schema(1)
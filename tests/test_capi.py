@@ -0,0 +1,63 @@
+import ctypes
+import platform
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core._pydantic_core import _C_API
+
+pytestmark = pytest.mark.skipif(
+    platform.python_implementation() != 'CPython', reason='ctypes.pythonapi is only available on CPython'
+)
+
+ValidateJson = ctypes.PYFUNCTYPE(ctypes.py_object, ctypes.py_object, ctypes.c_char_p, ctypes.c_ssize_t, ctypes.c_int)
+
+
+class CApi(ctypes.Structure):
+    _fields_ = [('api_version', ctypes.c_int), ('validate_json', ValidateJson)]
+
+
+@pytest.fixture(scope='module')
+def capi() -> CApi:
+    get_pointer = ctypes.pythonapi.PyCapsule_GetPointer
+    get_pointer.restype = ctypes.c_void_p
+    get_pointer.argtypes = [ctypes.py_object, ctypes.c_char_p]
+    pointer = get_pointer(_C_API, b'pydantic_core._pydantic_core._C_API')
+    return ctypes.cast(pointer, ctypes.POINTER(CApi)).contents
+
+
+def test_capi_version(capi: CApi):
+    assert capi.api_version == 1
+
+
+def test_capi_validate_json(capi: CApi):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    data = b'[1, 2, "3"]'
+    assert capi.validate_json(v, data, len(data), -1) == [1, 2, 3]
+
+    with pytest.raises(ValidationError) as exc_info:
+        capi.validate_json(v, data, len(data), 1)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': (2,), 'msg': 'Input should be a valid integer', 'input': '3'}
+    ]
+
+
+def test_capi_validate_json_invalid(capi: CApi):
+    v = SchemaValidator(core_schema.int_schema())
+    data = b'[1, 2'
+    with pytest.raises(ValidationError, match='Invalid JSON: EOF while parsing a list'):
+        capi.validate_json(v, data, len(data), -1)
+
+
+def test_capi_not_a_validator(capi: CApi):
+    with pytest.raises(TypeError, match="'int' object cannot be converted to 'SchemaValidator'"):
+        capi.validate_json(1, b'1', 1, -1)
+
+
+def test_capi_invalid_arguments(capi: CApi):
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match='validate_json: `validator` and `data` must not be NULL'):
+        capi.validate_json(v, None, 1, -1)
+    with pytest.raises(ValueError, match='validate_json: `length` must not be negative'):
+        capi.validate_json(v, b'1', -1, -1)
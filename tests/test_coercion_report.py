@@ -0,0 +1,105 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def typed_dict_validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'c': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.float_schema())
+                ),
+            }
+        )
+    )
+
+
+def test_exact(typed_dict_validator):
+    report = []
+    assert typed_dict_validator.validate_python({'a': 1, 'b': [2], 'c': {'x': 1.5}}, coercion_report=report) == {
+        'a': 1,
+        'b': [2],
+        'c': {'x': 1.5},
+    }
+    assert report == []
+
+
+def test_python(typed_dict_validator):
+    report = []
+    output = typed_dict_validator.validate_python(
+        {'a': '1', 'b': [1, '2', 3], 'c': {'x': 1.5, 'y': '2.5'}}, coercion_report=report
+    )
+    assert output == {'a': 1, 'b': [1, 2, 3], 'c': {'x': 1.5, 'y': 2.5}}
+    assert report == [
+        {'loc': ('a',), 'input_type': 'str', 'expected': 'int'},
+        {'loc': ('b', 1), 'input_type': 'str', 'expected': 'int'},
+        {'loc': ('c', 'y'), 'input_type': 'str', 'expected': 'float'},
+    ]
+
+
+def test_json(typed_dict_validator):
+    report = []
+    data = json.dumps({'a': 1, 'b': ['1'], 'c': {}})
+    assert typed_dict_validator.validate_json(data, coercion_report=report) == {'a': 1, 'b': [1], 'c': {}}
+    assert report == [{'loc': ('b', 0), 'input_type': 'str', 'expected': 'int'}]
+
+
+def test_top_level():
+    v = SchemaValidator(core_schema.int_schema())
+    report = []
+    assert v.validate_python('42', coercion_report=report) == 42
+    assert report == [{'loc': (), 'input_type': 'str', 'expected': 'int'}]
+
+
+def test_union():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()]))
+    )
+    report = []
+    assert v.validate_python([1, '2', 3.5], coercion_report=report) == [1, 2, 3.5]
+    assert report == [{'loc': (1,), 'input_type': 'str', 'expected': 'union[int,float]'}]
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right'])
+def test_union_choice_coercions(mode):
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [
+                    core_schema.list_schema(core_schema.int_schema()),
+                    core_schema.list_schema(core_schema.str_schema()),
+                ],
+                mode=mode,
+            )
+        )
+    )
+    report = []
+    assert v.validate_python([[1, '2'], ['x']], coercion_report=report) == [[1, 2], ['x']]
+    # coercions inside the winning choice are kept, with the location of the union added
+    assert report == [{'loc': (0, 1), 'input_type': 'str', 'expected': 'int'}]
+
+
+def test_union_losing_choice_coercions():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.union_schema(
+                [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.str_schema())]
+            )
+        )
+    )
+    report = []
+    assert v.validate_python([['1']], coercion_report=report) == [['1']]
+    assert report == []
+
+
+def test_validation_error(typed_dict_validator):
+    report = []
+    with pytest.raises(ValidationError):
+        typed_dict_validator.validate_python({'a': '1', 'b': ['x'], 'c': {}}, coercion_report=report)
+    assert report == []
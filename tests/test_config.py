@@ -4,7 +4,7 @@ import re
 import pytest
 from dirty_equals import FunctionCheck, HasAttributes, IsInstance
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, SchemaValidator, ValidationError, merge_config
 
 from .conftest import Err, plain_repr
 
@@ -137,3 +137,30 @@ def test_hide_input_in_errors(config, input_str):
 
     with pytest.raises(ValidationError, match=re.escape(f'Input should be a valid string [{input_str}]')):
         assert v.validate_python({'f': 123})
+
+
+def test_merge_config():
+    parent = CoreConfig(
+        strict=True, str_max_length=5, custom_error_messages={'int_parsing': 'parent', 'missing': 'parent'}
+    )
+    child = CoreConfig(str_max_length=10, custom_error_messages={'int_parsing': 'child'})
+    assert merge_config(parent, child) == {
+        'strict': True,
+        'str_max_length': 10,
+        'custom_error_messages': {'int_parsing': 'child', 'missing': 'parent'},
+    }
+    # inputs aren't modified
+    assert parent['custom_error_messages'] == {'int_parsing': 'parent', 'missing': 'parent'}
+    assert merge_config(None, child) == child
+    assert merge_config(parent, None) == parent
+
+
+def test_merge_config_callable_replaces_dict():
+    def translator(error_type, message_template, context):
+        return None
+
+    parent = CoreConfig(error_message_translator={'int_parsing': 'parent'})
+    assert merge_config(parent, CoreConfig(error_message_translator=translator)) == {
+        'error_message_translator': translator
+    }
+
@@ -0,0 +1,93 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_disabled():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.validate_python(1) == 1
+    assert v.coverage_stats() is None
+
+
+@pytest.mark.parametrize('mode', ['smart', 'left_to_right'])
+def test_union(mode):
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode=mode),
+        {'coverage_stats': True},
+    )
+    assert v.coverage_stats() == {'union[int,str].int': 0, 'union[int,str].str': 0}
+
+    assert v.validate_python(1) == 1
+    assert v.validate_python(2) == 2
+    assert v.validate_python('x') == 'x'
+    with pytest.raises(ValidationError):
+        v.validate_python(None)
+
+    assert v.coverage_stats() == {'union[int,str].int': 2, 'union[int,str].str': 1}
+
+
+def test_union_labels():
+    v = SchemaValidator(
+        core_schema.union_schema([(core_schema.int_schema(), 'Int'), (core_schema.str_schema(), 'Str')]),
+        {'coverage_stats': True},
+    )
+    assert v.validate_json('"foo"') == 'foo'
+    assert v.coverage_stats() == {'union[Int,Str].Int': 0, 'union[Int,Str].Str': 1}
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            choices={
+                'apple': core_schema.typed_dict_schema(
+                    {'kind': core_schema.typed_dict_field(core_schema.str_schema())}
+                ),
+                'banana': core_schema.typed_dict_schema(
+                    {'kind': core_schema.typed_dict_field(core_schema.str_schema())}
+                ),
+            },
+            discriminator='kind',
+        ),
+        {'coverage_stats': True},
+    )
+    assert v.validate_python({'kind': 'apple'}) == {'kind': 'apple'}
+    assert v.validate_python({'kind': 'apple'}) == {'kind': 'apple'}
+    with pytest.raises(ValidationError):
+        v.validate_python({'kind': 'cherry'})
+
+    assert v.coverage_stats() == {
+        "tagged-union[typed-dict,typed-dict].'apple'": 2,
+        "tagged-union[typed-dict,typed-dict].'banana'": 0,
+    }
+
+
+def test_model_fields_and_reset():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+            },
+            model_name='MyModel',
+            extra_behavior='allow',
+        ),
+        {'coverage_stats': True},
+    )
+    v.validate_python({'a': 1})
+    v.validate_python({'a': 1, 'b': 2, 'c': 3})
+    with pytest.raises(ValidationError):
+        v.validate_python({'a': 'x'})
+
+    assert v.coverage_stats(reset=True) == {'MyModel.a': 3, 'MyModel.b': 1, 'MyModel.__extra__': 1}
+    assert v.coverage_stats() == {'MyModel.a': 0, 'MyModel.b': 0, 'MyModel.__extra__': 0}
+
+
+def test_shared_paths_are_summed():
+    field = core_schema.model_field(core_schema.int_schema())
+    inner = core_schema.model_fields_schema({'x': field}, model_name='Point')
+    v = SchemaValidator(
+        core_schema.tuple_positional_schema([inner, inner]),
+        {'coverage_stats': True},
+    )
+    v.validate_python([{'x': 1}, {'x': 2}])
+    assert v.coverage_stats() == {'Point.x': 2}
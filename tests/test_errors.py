@@ -13,6 +13,7 @@ from pydantic_core import (
     PydanticCustomError,
     PydanticKnownError,
     PydanticOmit,
+    SchemaError,
     SchemaValidator,
     ValidationError,
     core_schema,
@@ -254,19 +255,44 @@ all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
     ('json_type', 'JSON input should be string, bytes or bytearray', None),
+    ('json_too_long', 'JSON input should have at most 42 bytes', {'max_length': 42}),
+    ('json_too_long', 'JSON input should have at most 1 byte', {'max_length': 1}),
+    ('json_too_deep', 'JSON input should be nested at most 42 levels deep', {'max_depth': 42}),
+    ('json_too_deep', 'JSON input should be nested at most 1 level deep', {'max_depth': 1}),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    (
+        'validation_timeout',
+        'Validation did not complete within 0.5 seconds, stopped after 128 items',
+        {'timeout': 0.5, 'items_validated': 128},
+    ),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
+    (
+        'model_constraint',
+        'start should be less than or equal to end',
+        {'left': 'start', 'op': 'le', 'right': 'end'},
+    ),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
     ('dataclass_type', 'Input should be a dictionary or an instance of Foobar', {'class_name': 'Foobar'}),
     ('missing', 'Field required', None),
     ('frozen_field', 'Field is frozen', None),
     ('frozen_instance', 'Instance is frozen', None),
     ('extra_forbidden', 'Extra inputs are not permitted', None),
+    ('closed_typed_dict_extra', 'Extra keys are not permitted in a closed TypedDict', None),
     ('invalid_key', 'Keys should be strings', None),
+    (
+        'extra_key_collision',
+        "Extra key 'foo' should not collide with a field or another extra key",
+        {'key': 'foo'},
+    ),
     ('get_attribute_error', 'Error extracting attribute: foo', {'error': 'foo'}),
     ('none_required', 'Input should be None', None),
     ('enum', 'Input should be foo', {'expected': 'foo'}),
+    (
+        'enum_close_match',
+        "Input should be 'red' or 'blue', did you mean 'red'?",
+        {'expected': "'red' or 'blue'", 'candidates': "'red'"},
+    ),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': '42.1'}),
     ('greater_than', 'Input should be greater than 2020-01-01', {'gt': '2020-01-01'}),
@@ -274,6 +300,7 @@ all_errors = [
     ('less_than', 'Input should be less than 42.1', {'lt': 42.1}),
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('finite_number', 'Input should be a finite number', None),
+    ('int_out_of_range', 'Input should be between -128 and 127', {'min': -128, 'max': 127}),
     (
         'too_short',
         'Foobar should have at least 42 items after validation, not 40',
@@ -288,14 +315,20 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    ('string_e164_format', "String should be a phone number in E.164 format, e.g. '+14155552671'", None),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
     ('string_too_long', 'String should have at most 1 character', {'max_length': 1}),
+    ('string_units_too_short', 'String should have at least 42 bytes', {'min_length': 42, 'unit': 'byte'}),
+    ('string_units_too_short', 'String should have at least 1 grapheme', {'min_length': 1, 'unit': 'grapheme'}),
+    ('string_units_too_long', 'String should have at most 42 graphemes', {'max_length': 42, 'unit': 'grapheme'}),
+    ('string_units_too_long', 'String should have at most 1 byte', {'max_length': 1, 'unit': 'byte'}),
     ('dict_type', 'Input should be a valid dictionary', None),
     ('mapping_type', 'Input should be a valid mapping, error: foobar', {'error': 'foobar'}),
     ('iterable_type', 'Input should be iterable', None),
     ('iteration_error', 'Error iterating over object, error: foobar', {'error': 'foobar'}),
+    ('duplicate_item', 'Foobar items should be unique', {'field_type': 'Foobar'}),
     ('list_type', 'Input should be a valid list', None),
     ('tuple_type', 'Input should be a valid tuple', None),
     ('set_type', 'Input should be a valid set', None),
@@ -304,7 +337,16 @@ all_errors = [
     ('int_type', 'Input should be a valid integer', None),
     ('int_parsing', 'Input should be a valid integer, unable to parse string as an integer', None),
     ('int_parsing_size', 'Unable to parse input string as an integer, exceeded maximum size', None),
+    ('int_max_digits', 'Integer should have no more than 42 digits', {'max_digits': 42}),
+    ('int_max_digits', 'Integer should have no more than 1 digit', {'max_digits': 1}),
     ('int_from_float', 'Input should be a valid integer, got a number with a fractional part', None),
+    ('int_flag_type', 'Input should be an integer or a list of Perm member names', {'class': 'Perm'}),
+    ('int_flag_name', 'Input should be a member name of Perm', {'class': 'Perm'}),
+    (
+        'int_flag_unknown_bits',
+        'Input should only have bits which are members of Perm, unknown bits: 8',
+        {'class': 'Perm', 'unknown_bits': 8},
+    ),
     ('multiple_of', 'Input should be a multiple of 42.1', {'multiple_of': 42.1}),
     ('greater_than', 'Input should be greater than 42.1', {'gt': 42.1}),
     ('greater_than_equal', 'Input should be greater than or equal to 42.1', {'ge': 42.1}),
@@ -312,11 +354,21 @@ all_errors = [
     ('less_than_equal', 'Input should be less than or equal to 42.1', {'le': 42.1}),
     ('float_type', 'Input should be a valid number', None),
     ('float_parsing', 'Input should be a valid number, unable to parse string as a number', None),
+    (
+        'float_from_int',
+        "Input should be a valid number, got an integer which can't be represented exactly as a float",
+        None,
+    ),
     ('bytes_type', 'Input should be a valid bytes', None),
     ('bytes_too_short', 'Data should have at least 42 bytes', {'min_length': 42}),
     ('bytes_too_short', 'Data should have at least 1 byte', {'min_length': 1}),
     ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
     ('bytes_too_long', 'Data should have at most 1 byte', {'max_length': 1}),
+    (
+        'bytes_invalid_encoding',
+        'Data should be valid base64: Invalid byte 33, offset 0.',
+        {'encoding': 'base64', 'encoding_error': 'Invalid byte 33, offset 0.'},
+    ),
     ('value_error', 'Value error, foobar', {'error': ValueError('foobar')}),
     ('assertion_error', 'Assertion failed, foobar', {'error': AssertionError('foobar')}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
@@ -356,6 +408,12 @@ all_errors = [
     ('unexpected_positional_argument', 'Unexpected positional argument', None),
     ('missing_positional_only_argument', 'Missing required positional only argument', None),
     ('multiple_argument_values', 'Got multiple values for argument', None),
+    (
+        'mutually_exclusive_arguments',
+        "Only one of 'a' or 'b' may be provided",
+        {'arguments': "'a' or 'b'"},
+    ),
+    ('missing_one_of_arguments', "At least one of 'a' or 'b' is required", {'arguments': "'a' or 'b'"}),
     ('url_type', 'URL input should be a string or URL', None),
     ('url_parsing', 'Input should be a valid URL, Foobar', {'error': 'Foobar'}),
     ('url_syntax_violation', 'Input violated strict URL syntax rules, Foobar', {'error': 'Foobar'}),
@@ -365,6 +423,18 @@ all_errors = [
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
     ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    ('uuid_format', 'UUID should be in the hyphenated format xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx', None),
+    (
+        'email_parsing',
+        'Input should be a valid email address, there must be something after the @-sign',
+        {'reason': 'there must be something after the @-sign'},
+    ),
+    ('ip_address_parsing', 'Input should be a valid IPv4 address', {'expected': 'IPv4'}),
+    (
+        'ip_network_parsing',
+        'Input should be a valid IPv4 or IPv6 network, host bits set',
+        {'expected': 'IPv4 or IPv6', 'error': 'host bits set'},
+    ),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -482,10 +552,10 @@ def test_all_errors():
             'example_context': None,
         },
         {
-            'type': 'recursion_loop',
-            'message_template_python': 'Recursion error - cyclic reference detected',
-            'example_message_python': 'Recursion error - cyclic reference detected',
-            'example_context': None,
+            'type': 'json_too_long',
+            'message_template_python': 'JSON input should have at most {max_length} byte{expected_plural}',
+            'example_message_python': 'JSON input should have at most 0 bytes',
+            'example_context': {'max_length': 0},
         },
     ]
 
@@ -778,6 +848,38 @@ def test_error_json(pydantic_version):
     assert exc_info.value.json(indent=2).startswith('[\n  {\n    "type": "string_too_short",')
 
 
+def test_errors_raw():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.str_schema(min_length=3))})
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 'x'}, {'a': 'abc'}, {}])
+
+    assert exc_info.value.errors_raw() == [
+        ('string_too_short', (0, 'a'), {'min_length': 3}, 'x'),
+        ('missing', (2, 'a'), None, {}),
+    ]
+    assert exc_info.value.errors_raw(include_context=False, include_input=False) == [
+        ('string_too_short', (0, 'a'), None, None),
+        ('missing', (2, 'a'), None, None),
+    ]
+    assert [(e['type'], e['loc'], e.get('ctx'), e['input']) for e in exc_info.value.errors()] == (
+        exc_info.value.errors_raw()
+    )
+
+
+def test_errors_raw_custom_error():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error {foo}', {'foo': 'bar'})
+
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(42)
+    assert exc_info.value.errors_raw() == [('my_error', (), {'foo': 'bar'}, 42)]
+
+
 def test_error_json_python_error(pydantic_version: str):
     def raise_py_error(v: Any) -> Any:
         try:
@@ -1074,3 +1176,106 @@ def test_hide_input_in_json() -> None:
 
     for error in exc_info.value.errors(include_input=False):
         assert 'input' not in error
+
+
+def test_custom_error_messages():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error {foo}', {'foo': 'bar'})
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema(min_length=3)),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.with_info_plain_validator_function(f)),
+            }
+        ),
+        config=CoreConfig(
+            custom_error_messages={
+                'string_too_short': 'Zu kurz, mindestens {min_length} Zeichen',
+                'missing': 'Pflichtfeld',
+                'my_error': 'Eigener Fehler {foo}',
+            }
+        ),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'c': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': ('a',),
+            'msg': 'Zu kurz, mindestens 3 Zeichen',
+            'input': 'x',
+            'ctx': {'min_length': 3},
+        },
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Pflichtfeld', 'input': {'a': 'x', 'c': 1}},
+        {'type': 'my_error', 'loc': ('c',), 'msg': 'Eigener Fehler bar', 'input': 1, 'ctx': {'foo': 'bar'}},
+    ]
+    assert 'Zu kurz, mindestens 3 Zeichen [type=string_too_short' in str(exc_info.value)
+    assert '"msg":"Pflichtfeld"' in exc_info.value.json()
+
+    # errors without an override are unchanged
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "abc", "b": "x", "c": 1}')
+    assert [e['msg'] for e in exc_info.value.errors()] == [
+        'Input should be a valid integer, unable to parse string as an integer',
+        'Eigener Fehler bar',
+    ]
+
+
+def test_custom_error_messages_with_translator():
+    with pytest.raises(SchemaError, match="`custom_error_messages` and `error_message_translator` can't both be set"):
+        SchemaValidator(
+            core_schema.int_schema(),
+            config=CoreConfig(
+                custom_error_messages={'int_parsing': 'a'}, error_message_translator={'int_parsing': 'b'}
+            ),
+        )
+
+
+def test_error_message_translator():
+    calls = []
+
+    def translate(error_type, message_template, context):
+        calls.append((error_type, message_template, context))
+        if error_type == 'string_too_short':
+            return 'Zu kurz, mindestens {min_length} Zeichen'
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.str_schema(min_length=3)),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        ),
+        config=CoreConfig(error_message_translator=translate),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    # messages are only translated when they're rendered
+    assert calls == []
+
+    assert [e['msg'] for e in exc_info.value.errors()] == ['Zu kurz, mindestens 3 Zeichen', 'Field required']
+    assert calls == [
+        ('string_too_short', 'String should have at least {min_length} character{expected_plural}', {'min_length': 3}),
+        ('missing', 'Field required', None),
+    ]
+    assert 'Zu kurz, mindestens 3 Zeichen [type=string_too_short' in str(exc_info.value)
+    assert '"msg":"Zu kurz, mindestens 3 Zeichen"' in exc_info.value.json()
+
+    # a translator passed when rendering takes precedence, and may be a dict of templates
+    errors = exc_info.value.errors(message_translator={'missing': 'Pflichtfeld'})
+    assert [e['msg'] for e in errors] == ['String should have at least 3 characters', 'Pflichtfeld']
+    assert '"msg":"Pflichtfeld"' in exc_info.value.json(message_translator={'missing': 'Pflichtfeld'})
+
+
+def test_error_message_translator_error():
+    def translate(error_type, message_template, context):
+        raise RuntimeError('translation failed')
+
+    v = SchemaValidator(core_schema.int_schema(), config=CoreConfig(error_message_translator=translate))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    with pytest.raises(RuntimeError, match='translation failed'):
+        exc_info.value.errors()
+    assert '(error rendering message: RuntimeError: translation failed)' in str(exc_info.value)
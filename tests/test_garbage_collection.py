@@ -79,3 +79,39 @@ def test_gc_schema_validator() -> None:
     gc.collect(2)
 
     assert len(cache) == 0
+
+
+@pytest.mark.xfail(
+    condition=platform.python_implementation() in {'PyPy', 'GraalVM'},
+    reason='https://foss.heptapod.net/pypy/pypy/-/issues/3899',
+)
+def test_gc_from_attributes_types() -> None:
+    # the validator remembers the types it's inspected for attributes, which mustn't keep them alive
+    class Base:
+        x = 1
+        __validator__: SchemaValidator
+
+        def __init_subclass__(cls) -> None:
+            cls.__validator__ = SchemaValidator(
+                core_schema.model_fields_schema(
+                    {'x': core_schema.model_field(core_schema.int_schema())}, from_attributes=True
+                )
+            )
+            cls.__validator__.validate_python(cls())
+
+    cache: 'WeakValueDictionary[int, Any]' = WeakValueDictionary()
+
+    for _ in range(1_000):
+
+        class MyObject(Base):
+            pass
+
+        cache[id(MyObject)] = MyObject
+
+        del MyObject
+
+    gc.collect(0)
+    gc.collect(1)
+    gc.collect(2)
+
+    assert len(cache) == 0
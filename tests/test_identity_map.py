@@ -0,0 +1,73 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture(scope='module')
+def model_list_validator():
+    return SchemaValidator(
+        core_schema.list_schema(
+            core_schema.model_schema(
+                Model,
+                core_schema.model_fields_schema(
+                    {
+                        'name': core_schema.model_field(core_schema.str_schema()),
+                        'tags': core_schema.model_field(core_schema.tuple_variable_schema(core_schema.str_schema())),
+                    }
+                ),
+            )
+        )
+    )
+
+
+def test_identity_map(model_list_validator):
+    tags = ['x', 'y']
+    a = {'name': 'a', 'tags': tags}
+    b = {'name': 'b', 'tags': []}
+    input_value = [a, b]
+    identity_map = {}
+    output = model_list_validator.validate_python(input_value, identity_map=identity_map)
+
+    assert identity_map[id(input_value)] is output
+    assert identity_map[id(a)] is output[0]
+    assert identity_map[id(b)] is output[1]
+    assert identity_map[id(tags)] is output[0].tags
+    assert output[0].tags == ('x', 'y')
+
+
+def test_identity_map_not_passed(model_list_validator):
+    assert model_list_validator.validate_python([{'name': 'a', 'tags': []}])[0].name == 'a'
+
+
+def test_identity_map_validation_error(model_list_validator):
+    identity_map = {}
+    with pytest.raises(ValidationError):
+        model_list_validator.validate_python([{'name': 'a', 'tags': []}, {'name': 1}], identity_map=identity_map)
+    assert identity_map == {}
+
+
+def test_identity_map_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema({'b': core_schema.typed_dict_field(core_schema.str_schema())}),
+            ]
+        )
+    )
+    a = [1]
+    input_value = {'a': a, 'b': 'x'}
+    identity_map = {}
+    assert v.validate_python(input_value, identity_map=identity_map) == {'b': 'x'}
+    # the entry for `a` recorded by the first choice is discarded when it fails
+    assert id(a) not in identity_map
+    assert identity_map[id(input_value)] == {'b': 'x'}
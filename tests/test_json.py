@@ -1,6 +1,7 @@
 import json
 import platform
 import re
+from concurrent.futures import ThreadPoolExecutor
 from typing import List
 
 import pytest
@@ -202,6 +203,53 @@ def test_json_invalid():
     ]
 
 
+def test_validate_json_lines():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    data = '{"id": 1, "name": "a"}\n{"id": "x", "name": "b"}\n\n{"id": 3, "name": "c"}\r\n{"id": 4,\n'
+    results, errors = v.validate_json_lines(data)
+    assert results == [{'id': 1, 'name': 'a'}, {'id': 3, 'name': 'c'}]
+    assert [line for line, _ in errors] == [2, 5]
+    assert all(isinstance(e, ValidationError) for _, e in errors)
+    assert errors[0][1].errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('id',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert errors[1][1].errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': 'Invalid JSON: EOF while parsing a value at line 1 column 9',
+            'input': b'{"id": 4,',
+            'ctx': {'error': 'EOF while parsing a value at line 1 column 9'},
+        }
+    ]
+
+
+def test_validate_json_lines_input_types():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_json_lines(b'1\n2\n') == ([1, 2], [])
+    assert v.validate_json_lines(bytearray(b'1\n"2"')) == ([1, 2], [])
+    assert v.validate_json_lines('') == ([], [])
+
+    results, errors = v.validate_json_lines('1\n"2"', strict=True)
+    assert results == [1]
+    assert [line for line, _ in errors] == [2]
+
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type'):
+        v.validate_json_lines(1)
+
+
 class Foobar:
     def __str__(self):
         return 'Foobar.__str__'
@@ -365,3 +413,141 @@ def test_inf_nan_allow():
     assert v.validate_json('Infinity') == float('inf')
     assert v.validate_json('-Infinity') == float('-inf')
     assert v.validate_json('NaN') == IsFloatNan()
+
+
+@pytest.mark.parametrize('input_type', [str, bytes, bytearray])
+def test_large_json_threads(input_type):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    data = json.dumps(list(range(50_000)))
+    json_input = data if input_type is str else input_type(data.encode())
+    with ThreadPoolExecutor(4) as executor:
+        results = list(executor.map(v.validate_json, [json_input] * 8))
+    assert results == [list(range(50_000))] * 8
+
+
+def test_large_json_invalid():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    data = json.dumps(list(range(50_000)))[:-1]
+    with pytest.raises(ValidationError, match='Invalid JSON: EOF while parsing a list'):
+        v.validate_json(data)
+
+
+def test_direct_errors():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.str_schema(max_length=3),
+            core_schema.nullable_schema(core_schema.list_schema(core_schema.int_schema())),
+        )
+    )
+    assert v.validate_json('{"a": [1, 2], "b": null, "c": []}') == {'a': [1, 2], 'b': None, 'c': []}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": [1, "x", 3], "long": [4], "c": "y"}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'string_too_long',
+            'loc': ('long', '[key]'),
+            'msg': 'String should have at most 3 characters',
+            'input': 'long',
+            'ctx': {'max_length': 3},
+        },
+        {'type': 'list_type', 'loc': ('c',), 'msg': 'Input should be a valid array', 'input': 'y'},
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        ('[[1, "x"], [2', 'EOF while parsing a list at line 1 column 13'),
+        ('[[1, "x"], [2]] []', 'trailing characters at line 1 column 17'),
+        ('[["x", 1, 2, ]]', 'trailing comma at line 1 column 14'),
+    ],
+)
+def test_direct_invalid_json(input_value, error):
+    """
+    Invalid JSON is reported the same way when it's validated as it's parsed, even after validation errors
+    """
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (),
+            'msg': f'Invalid JSON: {error}',
+            'input': input_value,
+            'ctx': {'error': error},
+        }
+    ]
+
+
+def test_direct_matches_parsed():
+    """
+    Timeouts need the input parsed up front, so compare the two ways of validating JSON
+    """
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.dict_schema(core_schema.int_schema(), core_schema.union_schema([core_schema.int_schema()]))
+        )
+    )
+    data = '[{"1": 1, "1": 2, "x": "3", "4": "y"}, {}]'
+    with pytest.raises(ValidationError) as direct_exc_info:
+        v.validate_json(data)
+    with pytest.raises(ValidationError) as parsed_exc_info:
+        v.validate_json(data, timeout=60)
+    assert direct_exc_info.value.errors() == parsed_exc_info.value.errors()
+    assert v.validate_json('[{"1": 1, "1": 2}]') == v.validate_json('[{"1": 1, "1": 2}]', timeout=60) == [{1: 2}]
+
+
+def test_direct_per_item_options():
+    """
+    Options which need the input of each item still apply to schemas which are otherwise validated as they're parsed
+    """
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.int_schema()))
+    )
+    data = '{"a": ["1", 2], "b": [3, "4"]}'
+
+    report = []
+    assert v.validate_json(data, coercion_report=report) == {'a': [1, 2], 'b': [3, 4]}
+    assert report == [
+        {'loc': ('a', 0), 'input_type': 'str', 'expected': 'int'},
+        {'loc': ('b', 1), 'input_type': 'str', 'expected': 'int'},
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data, strict_fields=[('b',)])
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('b', 1), 'msg': 'Input should be a valid integer', 'input': '4'}
+    ]
+
+    spans = {}
+    assert v.validate_json(data, source_spans=spans) == {'a': [1, 2], 'b': [3, 4]}
+    assert spans[('b', 1)] == (25, 28)
+
+
+def test_direct_union_choice_callback():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.dict_schema(
+                core_schema.str_schema(), core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+            )
+        )
+    )
+    data = '[{"a": "1", "b": 2}, {"c": "x"}]'
+    choices = []
+    assert v.validate_json(data, union_choice_callback=lambda output, info: choices.append(output)) == [
+        {'a': '1', 'b': 2},
+        {'c': 'x'},
+    ]
+    assert choices == ['1', 2, 'x']
+
+    # the same choices are reported when the JSON is parsed up front
+    parsed_choices = []
+    v.validate_json(data, timeout=60, union_choice_callback=lambda output, info: parsed_choices.append(output))
+    assert parsed_choices == choices
@@ -1,6 +1,7 @@
 import dataclasses
 import re
 from datetime import date
+from enum import Enum, IntFlag
 from typing import Any
 
 import pytest
@@ -26,6 +27,16 @@ class MyDataclass:
     y: str
 
 
+class MyFlag(IntFlag):
+    A = 1
+    B = 2
+
+
+class MyEnum(Enum):
+    A = 'a'
+    B = 'b'
+
+
 def ids_function(val):
     if callable(val):
         return val.__name__
@@ -48,12 +59,20 @@ all_schema_functions = [
     (core_schema.int_schema, args(), {'type': 'int'}),
     (core_schema.int_schema, args(metadata={'fred'}), {'type': 'int', 'metadata': {'fred'}}),
     (core_schema.int_schema, args(multiple_of=5, gt=10, lt=20), {'type': 'int', 'multiple_of': 5, 'gt': 10, 'lt': 20}),
+    (core_schema.int64_schema, args(), {'type': 'int64'}),
+    (core_schema.uint64_schema, args(le=100, strict=True), {'type': 'uint64', 'le': 100, 'strict': True}),
+    (
+        core_schema.int_flag_schema,
+        args(MyFlag, unknown_bits='drop', serialize_as='names'),
+        {'type': 'int-flag', 'cls': MyFlag, 'unknown_bits': 'drop', 'serialize_as': 'names'},
+    ),
     (core_schema.float_schema, args(), {'type': 'float'}),
     (core_schema.float_schema, args(multiple_of=5, gt=1.2), {'type': 'float', 'multiple_of': 5, 'gt': 1.2}),
     (core_schema.str_schema, args(), {'type': 'str'}),
     (core_schema.str_schema, args(min_length=5, max_length=10), {'type': 'str', 'min_length': 5, 'max_length': 10}),
     (core_schema.bytes_schema, args(), {'type': 'bytes'}),
     (core_schema.bytes_schema, args(min_length=5, ref='xx'), {'type': 'bytes', 'min_length': 5, 'ref': 'xx'}),
+    (core_schema.bytes_schema, args(encoding='hex'), {'type': 'bytes', 'encoding': 'hex'}),
     (core_schema.date_schema, args(), {'type': 'date'}),
     (core_schema.date_schema, args(gt=date(2020, 1, 1)), {'type': 'date', 'gt': date(2020, 1, 1)}),
     (core_schema.time_schema, args(), {'type': 'time', 'microseconds_precision': 'truncate'}),
@@ -75,6 +94,12 @@ all_schema_functions = [
         {'type': 'timedelta', 'microseconds_precision': 'error'},
     ),
     (core_schema.literal_schema, args(['a', 'b']), {'type': 'literal', 'expected': ['a', 'b']}),
+    (core_schema.const_schema, args('a'), {'type': 'const', 'expected': 'a'}),
+    (
+        core_schema.enum_schema,
+        args(MyEnum, match_mode='fuzzy', strict=True),
+        {'type': 'enum', 'cls': MyEnum, 'match_mode': 'fuzzy', 'strict': True},
+    ),
     (core_schema.is_instance_schema, args(int), {'type': 'is-instance', 'cls': int}),
     (core_schema.callable_schema, args(), {'type': 'callable'}),
     (core_schema.list_schema, args(), {'type': 'list'}),
@@ -155,11 +180,17 @@ all_schema_functions = [
         {'type': 'default', 'schema': {'type': 'int'}, 'default_factory': make_5},
     ),
     (core_schema.nullable_schema, args({'type': 'int'}), {'type': 'nullable', 'schema': {'type': 'int'}}),
+    (core_schema.secret_schema, args({'type': 'str'}), {'type': 'secret', 'schema': {'type': 'str'}}),
     (
         core_schema.union_schema,
         args([{'type': 'int'}, {'type': 'str'}]),
         {'type': 'union', 'choices': [{'type': 'int'}, {'type': 'str'}]},
     ),
+    (
+        core_schema.union_schema,
+        args([{'type': 'int'}, {'type': 'str'}], priorities=[0, 1]),
+        {'type': 'union', 'choices': [{'type': 'int'}, {'type': 'str'}], 'priorities': [0, 1]},
+    ),
     (
         core_schema.union_schema,
         args([{'type': 'int'}, {'type': 'str'}], custom_error_type='foobar', custom_error_message='This is Foobar'),
@@ -200,6 +231,16 @@ all_schema_functions = [
         args({'foo': core_schema.model_field({'type': 'int'})}),
         {'type': 'model-fields', 'fields': {'foo': {'type': 'model-field', 'schema': {'type': 'int'}}}},
     ),
+    (core_schema.model_constraint, args('a', 'le', 'b'), {'left': 'a', 'op': 'le', 'right': 'b'}),
+    (
+        core_schema.model_constraint_schema,
+        args({'type': 'int'}, [core_schema.model_constraint('a', 'lt', 'b', loc=['b', 0])]),
+        {
+            'type': 'model-constraint',
+            'schema': {'type': 'int'},
+            'constraints': [{'left': 'a', 'op': 'lt', 'right': 'b', 'loc': ['b', 0]}],
+        },
+    ),
     (
         core_schema.model_schema,
         args(MyModel, {'type': 'int'}),
@@ -244,6 +285,7 @@ all_schema_functions = [
         },
     ),
     (core_schema.json_schema, args({'type': 'int'}), {'type': 'json', 'schema': {'type': 'int'}}),
+    (core_schema.json_schema, args(max_length=10, max_depth=2), {'type': 'json', 'max_length': 10, 'max_depth': 2}),
     (core_schema.url_schema, args(), {'type': 'url'}),
     (core_schema.multi_host_url_schema, args(), {'type': 'multi-host-url'}),
     (
@@ -267,6 +309,17 @@ all_schema_functions = [
         },
     ),
     (core_schema.definition_reference_schema, args('foo'), {'type': 'definition-ref', 'schema_ref': 'foo'}),
+    (
+        core_schema.definition_template_schema,
+        args('foo', ['T'], {'type': 'definition-param', 'name': 'T'}),
+        {
+            'type': 'definition-template',
+            'ref': 'foo',
+            'params': ['T'],
+            'schema': {'type': 'definition-param', 'name': 'T'},
+        },
+    ),
+    (core_schema.definition_param_schema, args('T'), {'type': 'definition-param', 'name': 'T'}),
     (
         core_schema.dataclass_args_schema,
         args('Foo', [{'name': 'foo', 'type': 'dataclass-field', 'schema': {'type': 'int'}}]),
@@ -287,6 +340,16 @@ all_schema_functions = [
         {'type': 'dataclass', 'schema': {'type': 'int'}, 'fields': ['foobar'], 'cls': MyDataclass, 'slots': True},
     ),
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.email_schema, args(), {'type': 'email'}),
+    (core_schema.name_email_schema, args(allow_smtputf8=False), {'type': 'name-email', 'allow_smtputf8': False}),
+    (core_schema.ip_v4_schema, args(), {'type': 'ip-v4'}),
+    (core_schema.ip_v6_schema, args(strict=True), {'type': 'ip-v6', 'strict': True}),
+    (core_schema.ip_any_schema, args(), {'type': 'ip-any'}),
+    (
+        core_schema.ip_network_schema,
+        args(version=4, allow_host_bits=True),
+        {'type': 'ip-network', 'version': 4, 'allow_host_bits': True},
+    ),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
 ]
@@ -297,7 +360,14 @@ def test_schema_functions(function, args_kwargs, expected_schema):
     args, kwargs = args_kwargs
     schema = function(*args, **kwargs)
     assert schema == expected_schema
-    if schema.get('type') in {None, 'definition-ref', 'typed-dict-field', 'model-field'}:
+    if schema.get('type') in {
+        None,
+        'definition-ref',
+        'definition-template',
+        'definition-param',
+        'typed-dict-field',
+        'model-field',
+    }:
         return
 
     v = SchemaValidator(schema)
@@ -0,0 +1,41 @@
+import signal
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+pytestmark = pytest.mark.skipif(not hasattr(signal, 'setitimer'), reason='requires signal.setitimer')
+
+
+class Interrupted(Exception):
+    pass
+
+
+def raise_interrupted(signum, frame):
+    raise Interrupted()
+
+
+def test_list_validation_interrupted():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    data = ['1'] * 10_000_000
+    previous = signal.signal(signal.SIGALRM, raise_interrupted)
+    try:
+        signal.setitimer(signal.ITIMER_REAL, 0.001)
+        with pytest.raises(Interrupted):
+            v.validate_python(data)
+    finally:
+        signal.setitimer(signal.ITIMER_REAL, 0)
+        signal.signal(signal.SIGALRM, previous)
+
+
+def test_dict_validation_interrupted():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    data = {str(i): '1' for i in range(2_000_000)}
+    previous = signal.signal(signal.SIGALRM, raise_interrupted)
+    try:
+        signal.setitimer(signal.ITIMER_REAL, 0.001)
+        with pytest.raises(Interrupted):
+            v.validate_python(data)
+    finally:
+        signal.setitimer(signal.ITIMER_REAL, 0)
+        signal.signal(signal.SIGALRM, previous)
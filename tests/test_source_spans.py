@@ -0,0 +1,76 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_spans():
+    v = SchemaValidator(core_schema.any_schema())
+    data = '{"a": [1, {"b": "x\\"y"}], "c": null}'
+    spans = {}
+    assert v.validate_json(data, source_spans=spans) == {'a': [1, {'b': 'x"y'}], 'c': None}
+    assert spans == {
+        (): (0, 36),
+        ('a',): (6, 24),
+        ('a', 0): (7, 8),
+        ('a', 1): (10, 23),
+        ('a', 1, 'b'): (16, 22),
+        ('c',): (31, 35),
+    }
+    assert data[slice(*spans['a', 1])] == '{"b": "x\\"y"}'
+
+
+def test_whitespace():
+    v = SchemaValidator(core_schema.any_schema())
+    data = '\n[ 1.5e3 ,\n  true,\t"x" ]\n'
+    spans = {}
+    v.validate_json(data, source_spans=spans)
+    assert {path: data[start:end] for path, (start, end) in spans.items()} == {
+        (): '[ 1.5e3 ,\n  true,\t"x" ]',
+        (0,): '1.5e3',
+        (1,): 'true',
+        (2,): '"x"',
+    }
+
+
+def test_error_locations():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'scores': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    data = b'{\n  "name": "x",\n  "scores": [1, "two", 3]\n}'
+    spans = {}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data, source_spans=spans)
+    (error,) = exc_info.value.errors()
+    assert error['loc'] == ('scores', 1)
+    start, end = spans[error['loc']]
+    assert data[start:end] == b'"two"'
+
+
+def test_unicode():
+    v = SchemaValidator(core_schema.any_schema())
+    data = '{"é": "ü", "n": 1}'
+    spans = {}
+    v.validate_json(data, source_spans=spans)
+    encoded = data.encode()
+    assert encoded[slice(*spans[('é',)])].decode() == '"ü"'
+    assert encoded[slice(*spans[('n',)])] == b'1'
+
+
+def test_escaped_key():
+    v = SchemaValidator(core_schema.any_schema())
+    spans = {}
+    v.validate_json('{"a\\nb": 1}', source_spans=spans)
+    assert spans == {(): (0, 11), ('a\nb',): (9, 10)}
+
+
+def test_invalid_json():
+    v = SchemaValidator(core_schema.any_schema())
+    spans = {}
+    with pytest.raises(ValidationError, match='Invalid JSON'):
+        v.validate_json('{"a": [1,', source_spans=spans)
+    assert spans == {}
@@ -0,0 +1,81 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def validator():
+    inner = core_schema.typed_dict_schema(
+        {
+            'x': core_schema.typed_dict_field(core_schema.int_schema()),
+            'y': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.list_schema(inner)),
+            }
+        )
+    )
+
+
+def test_no_overrides(validator):
+    assert validator.validate_python({'a': '1', 'b': '2', 'c': [{'x': '3', 'y': '4'}]}) == {
+        'a': 1,
+        'b': 2,
+        'c': [{'x': 3, 'y': 4}],
+    }
+
+
+def test_strict_fields(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python({'a': '1', 'b': '2', 'c': [{'x': '3', 'y': '4'}]}, strict_fields=['a', ('c', 0, 'y')])
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('a',), 'msg': 'Input should be a valid integer', 'input': '1'},
+        {'type': 'int_type', 'loc': ('c', 0, 'y'), 'msg': 'Input should be a valid integer', 'input': '4'},
+    ]
+
+
+def test_strict_fields_nested(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_python({'a': '1', 'b': 2, 'c': [{'x': 3, 'y': 4}, {'x': '5', 'y': 6}]}, strict_fields=['c'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [('c', 1, 'x')]
+
+
+def test_lax_fields(validator):
+    data = {'a': '1', 'b': 2, 'c': [{'x': 3, 'y': 4}]}
+    with pytest.raises(ValidationError):
+        validator.validate_python(data, strict=True)
+    assert validator.validate_python(data, strict=True, lax_fields=['a']) == {'a': 1, 'b': 2, 'c': [{'x': 3, 'y': 4}]}
+
+
+def test_lax_inside_strict(validator):
+    data = {'a': 1, 'b': 2, 'c': [{'x': 3, 'y': '4'}]}
+    assert validator.validate_python(data, strict_fields=['c'], lax_fields=[('c', 0, 'y')]) == {
+        'a': 1,
+        'b': 2,
+        'c': [{'x': 3, 'y': 4}],
+    }
+
+
+def test_json(validator):
+    data = json.dumps({'a': '1', 'b': '2', 'c': []})
+    assert validator.validate_json(data, lax_fields=['a']) == {'a': 1, 'b': 2, 'c': []}
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json(data, strict_fields=['b'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [('b',)]
+
+
+def test_invalid_locations(validator):
+    with pytest.raises(TypeError, match='Field locations must be strings, or lists or tuples of strings and ints'):
+        validator.validate_python({}, strict_fields=[1])
+    with pytest.raises(TypeError, match='Field locations must not be empty'):
+        validator.validate_python({}, strict_fields=[()])
+    with pytest.raises(TypeError, match='cannot be in both `strict_fields` and `lax_fields`'):
+        validator.validate_python({}, strict_fields=['a'], lax_fields=['a'])
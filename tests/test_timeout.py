@@ -0,0 +1,97 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_no_timeout():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_python(list(range(1000)), timeout=60) == list(range(1000))
+    assert v.validate_json(json.dumps(list(range(1000))), timeout=60) == list(range(1000))
+
+
+def test_timeout_python():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(list(range(1000)), timeout=0)
+    # insert_assert(exc_info.value.errors(include_url=False, include_input=False))
+    assert exc_info.value.errors(include_url=False, include_input=False) == [
+        {
+            'type': 'validation_timeout',
+            'loc': (63,),
+            'msg': 'Validation did not complete within 0 seconds, stopped after 63 items',
+            'ctx': {'timeout': 0.0, 'items_validated': 63},
+        }
+    ]
+
+
+def test_timeout_json_nested():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.int_schema()))
+    )
+    data = json.dumps({str(i): list(range(10)) for i in range(100)})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data, timeout=0)
+    errors = exc_info.value.errors(include_url=False, include_input=False)
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'validation_timeout'
+    assert errors[0]['loc'] == ('5', 7)
+    assert errors[0]['ctx'] == {'timeout': 0.0, 'items_validated': 63}
+
+
+def test_timeout_typed_dict_fields():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {f'f{i}': core_schema.typed_dict_field(core_schema.int_schema()) for i in range(100)}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({f'f{i}': i for i in range(100)}, timeout=0)
+    errors = exc_info.value.errors(include_url=False, include_input=False)
+    assert [(e['type'], e['loc']) for e in errors] == [('validation_timeout', ('f63',))]
+
+
+def test_timeout_not_caught_by_wrap_validator():
+    def f(input_value, handler):
+        try:
+            return handler(input_value)
+        except ValidationError:
+            return []
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.no_info_wrap_validator_function(f, core_schema.list_schema(core_schema.int_schema()))
+                )
+            }
+        )
+    )
+    assert v.validate_python({'x': ['a']}) == {'x': []}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': list(range(1000))}, timeout=0)
+    errors = exc_info.value.errors(include_url=False, include_input=False)
+    assert [(e['type'], e['loc']) for e in errors] == [('validation_timeout', ('x', 62))]
+
+
+def test_timeout_not_caught():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.with_default_schema(
+                    core_schema.list_schema(core_schema.int_schema()), default=[], on_error='default'
+                ),
+                core_schema.any_schema(),
+            ]
+        )
+    )
+    assert v.validate_python(['x']) == []
+    with pytest.raises(ValidationError, match='Validation did not complete within 0 seconds'):
+        v.validate_python(list(range(1000)), timeout=0)
+
+
+def test_invalid_timeout():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match='timeout must be a non-negative number'):
+        v.validate_python(1, timeout=-1)
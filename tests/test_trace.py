@@ -0,0 +1,67 @@
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema, set_trace_subscriber
+
+
+def tracing_enabled() -> bool:
+    try:
+        set_trace_subscriber(lambda *args: None)
+    except RuntimeError:
+        return False
+    else:
+        set_trace_subscriber(None)
+        return True
+
+
+def test_without_feature():
+    if tracing_enabled():
+        pytest.skip('pydantic-core was built with the `tracing` feature')
+    with pytest.raises(RuntimeError, match='pydantic-core was built without the `tracing` feature'):
+        set_trace_subscriber(print)
+    # removing the subscriber is always allowed
+    set_trace_subscriber(None)
+
+
+@pytest.fixture
+def spans():
+    if not tracing_enabled():
+        pytest.skip('pydantic-core was built without the `tracing` feature')
+    spans = []
+    set_trace_subscriber(lambda name, target, duration: spans.append((name, target, duration)))
+    yield spans
+    set_trace_subscriber(None)
+
+
+def test_top_level_spans(spans):
+    schema = core_schema.list_schema(core_schema.int_schema())
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    v.validate_python([1, 2])
+    v.validate_json('[1, 2]')
+    s.to_python([1, 2])
+    s.to_json([1, 2])
+
+    assert [(name, target) for name, target, _ in spans] == [
+        ('SchemaValidator.__init__', None),
+        ('SchemaSerializer.__init__', None),
+        ('SchemaValidator.validate_python', 'list[int]'),
+        ('SchemaValidator.validate_json', 'list[int]'),
+        ('SchemaSerializer.to_python', 'list[int]'),
+        ('SchemaSerializer.to_json', 'list[int]'),
+    ]
+    assert all(duration >= 0 for _, _, duration in spans)
+
+
+def test_slow_spans(spans):
+    v = SchemaValidator(core_schema.list_schema(core_schema.no_info_plain_validator_function(lambda x: x)))
+    v.validate_python([1, 2])
+    assert [name for name, _, _ in spans] == ['SchemaValidator.__init__', 'SchemaValidator.validate_python']
+
+    spans.clear()
+    set_trace_subscriber(lambda name, target, duration: spans.append((name, target, duration)), slow_threshold=0)
+    v.validate_python([1, 2])
+    assert [(name, target) for name, target, _ in spans] == [
+        ('validate', 'function-plain[<lambda>()]'),
+        ('validate', 'function-plain[<lambda>()]'),
+        ('SchemaValidator.validate_python', 'list[function-plain[<lambda>()]]'),
+    ]
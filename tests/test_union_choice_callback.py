@@ -0,0 +1,129 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture(scope='module')
+def list_validator():
+    return SchemaValidator(
+        core_schema.list_schema(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    )
+
+
+def test_smart(list_validator):
+    choices = []
+    output = list_validator.validate_python([1, 'a'], union_choice_callback=lambda *args: choices.append(args))
+    assert output == [1, 'a']
+    assert choices == [
+        (1, {'union': 'union[int,str]', 'index': 0, 'choice': 'int', 'exactness': 'exact'}),
+        ('a', {'union': 'union[int,str]', 'index': 1, 'choice': 'str', 'exactness': 'exact'}),
+    ]
+
+
+def test_lax():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()]))
+    choices = []
+    assert v.validate_python('1.5', union_choice_callback=lambda *args: choices.append(args)) == 1.5
+    assert choices == [(1.5, {'union': 'union[int,float]', 'index': 1, 'choice': 'float', 'exactness': 'lax'})]
+
+
+def test_labels_and_models():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                (core_schema.int_schema(), 'Int'),
+                (
+                    core_schema.model_schema(
+                        Model, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+                    ),
+                    'Model',
+                ),
+            ]
+        )
+    )
+    choices = []
+    m = v.validate_python({'x': 1}, union_choice_callback=lambda *args: choices.append(args))
+    assert isinstance(m, Model)
+    assert len(choices) == 1
+    output, info = choices[0]
+    assert output is m
+    assert info == {'union': 'union[Int,Model]', 'index': 1, 'choice': 'Model', 'exactness': 'strict'}
+
+
+def test_left_to_right():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    )
+    choices = []
+    assert v.validate_python('1', union_choice_callback=lambda *args: choices.append(args)) == 1
+    assert choices == [(1, {'union': 'union[int,str]', 'index': 0, 'choice': 'int', 'exactness': None})]
+
+
+def test_nested():
+    inner = core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+    v = SchemaValidator(core_schema.union_schema([core_schema.list_schema(inner), inner]))
+    choices = []
+    assert v.validate_python(['x'], union_choice_callback=lambda *args: choices.append(args)) == ['x']
+    # the inner union is reported before the outer one
+    assert [(output, info['union'], info['index']) for output, info in choices] == [
+        ('x', 'union[int,str]', 1),
+        (['x'], 'union[list[union[int,str]],union[int,str]]', 0),
+    ]
+
+
+def test_unused_choice():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(
+                            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+                        ),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.any_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.str_schema()),
+                    }
+                ),
+            ]
+        )
+    )
+    choices = []
+    output = v.validate_python({'a': 1, 'b': 'x'}, union_choice_callback=lambda *args: choices.append(args))
+    assert output == {'a': 1, 'b': 'x'}
+    # the first choice failed on `b`, so the union choice made for `a` inside it isn't reported
+    assert choices == [
+        (output, {'union': 'union[typed-dict,typed-dict]', 'index': 1, 'choice': 'typed-dict', 'exactness': 'strict'})
+    ]
+
+
+def test_json(list_validator):
+    choices = []
+    output = list_validator.validate_json('[1, "2"]', union_choice_callback=lambda *args: choices.append(args))
+    assert output == [1, '2']
+    assert [info['choice'] for _, info in choices] == ['int', 'str']
+
+
+def test_validation_error(list_validator):
+    choices = []
+    with pytest.raises(ValidationError):
+        list_validator.validate_python([1, None], union_choice_callback=lambda *args: choices.append(args))
+    assert choices == []
+
+
+def test_callback_error():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+
+    def callback(output, info):
+        raise RuntimeError('callback failed')
+
+    with pytest.raises(RuntimeError, match='callback failed'):
+        v.validate_python(1, union_choice_callback=callback)
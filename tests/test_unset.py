@@ -0,0 +1,69 @@
+import copy
+import pickle
+
+import pytest
+
+from pydantic_core import UNSET, SchemaSerializer, SchemaValidator, UnsetType, core_schema
+
+
+def test_unset_singleton():
+    assert repr(UNSET) == 'UNSET'
+    assert not UNSET
+    assert copy.copy(UNSET) is UNSET
+    assert copy.deepcopy(UNSET) is UNSET
+    assert pickle.loads(pickle.dumps(UNSET)) is UNSET
+    assert isinstance(UNSET, UnsetType)
+    with pytest.raises(NotImplementedError):
+        UnsetType()
+
+
+@pytest.fixture(scope='module')
+def model_fields_schema():
+    return core_schema.model_fields_schema(
+        fields={
+            'a': core_schema.model_field(core_schema.int_schema()),
+            'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=UNSET)),
+        }
+    )
+
+
+def test_model_fields_default(model_fields_schema):
+    v = SchemaValidator(model_fields_schema)
+    assert v.validate_python({'a': 1}) == ({'a': 1, 'b': UNSET}, None, {'a'})
+    assert v.validate_python({'a': 1, 'b': 2}) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+
+
+def test_model_fields_explicit_unset(model_fields_schema):
+    v = SchemaValidator(model_fields_schema)
+    assert v.validate_python({'a': 1, 'b': UNSET}) == ({'a': 1, 'b': UNSET}, None, {'a'})
+
+
+def test_validate_default():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=UNSET, validate_default=True)
+                )
+            }
+        )
+    )
+    assert v.validate_python({}) == {'x': UNSET}
+    assert v.validate_python({'x': '1'}) == {'x': 1}
+
+
+def test_serialization_omits_unset():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=UNSET)
+                ),
+            }
+        )
+    )
+    assert s.to_python({'a': 1, 'b': UNSET}) == {'a': 1}
+    assert s.to_python({'a': 1, 'b': UNSET}, mode='json') == {'a': 1}
+    assert s.to_json({'a': 1, 'b': UNSET}) == b'{"a":1}'
+    assert s.to_python({'a': 1, 'b': 2}) == {'a': 1, 'b': 2}
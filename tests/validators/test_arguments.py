@@ -34,6 +34,49 @@ def test_args_kwargs():
     assert repr(ArgsKwargs((1,))) == 'ArgsKwargs((1,))'
 
 
+def test_args_kwargs_views():
+    ak = ArgsKwargs(('hello', True), {'a': 123})
+    assert len(ak) == 3
+    assert list(ak) == [0, 1, 'a']
+    assert [ak[key] for key in ak] == ['hello', True, 123]
+    assert len(ArgsKwargs(())) == 0
+    assert list(ArgsKwargs(())) == []
+    assert ArgsKwargs(())
+    assert ak[0] == 'hello'
+    assert ak[-1] is True
+    assert ak['a'] == 123
+    with pytest.raises(IndexError, match='ArgsKwargs positional index out of range'):
+        ak[2]
+    with pytest.raises(KeyError, match="'b'"):
+        ak['b']
+    with pytest.raises(KeyError, match="'a'"):
+        ArgsKwargs(())['a']
+    with pytest.raises(TypeError, match='ArgsKwargs indices must be integers or strings, not float'):
+        ak[1.5]
+
+
+def test_args_kwargs_call_args():
+    def f(*args, **kwargs):
+        return args, kwargs
+
+    ak = ArgsKwargs((1, 2), {'c': 3})
+    args, kwargs = ak.as_call_args()
+    assert f(*args, **kwargs) == ((1, 2), {'c': 3})
+    # the kwargs returned can be changed without changing `ak`
+    kwargs['d'] = 4
+    assert ak.kwargs == {'c': 3}
+    assert ArgsKwargs((1,)).as_call_args() == ((1,), {})
+
+
+def test_args_kwargs_from_bound_arguments():
+    def f(a, b=2, *args, c, **kwargs):
+        pass
+
+    bound = signature(f).bind(1, 2, 3, c=4, d=5)
+    assert ArgsKwargs.from_bound_arguments(bound) == ArgsKwargs((1, 2, 3), {'c': 4, 'd': 5})
+    assert ArgsKwargs.from_bound_arguments(signature(f).bind_partial(1)) == ArgsKwargs((1,))
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -1026,3 +1069,203 @@ def test_error_display(pydantic_version):
         '[{"type":"missing_argument","loc":["b"],"msg":"Missing required argument",'
         '"input":"ArgsKwargs((), {\'a\': 1})"}]'
     )
+
+
+def groups_validator(**kwargs):
+    return SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter(
+                    'a', core_schema.with_default_schema(core_schema.int_schema(), default=None)
+                ),
+                core_schema.arguments_parameter(
+                    'b', core_schema.with_default_schema(core_schema.int_schema(), default=None), alias='bb'
+                ),
+                core_schema.arguments_parameter(
+                    'c', core_schema.with_default_schema(core_schema.int_schema(), default=None), mode='keyword_only'
+                ),
+            ],
+            **kwargs,
+        )
+    )
+
+
+def test_mutually_exclusive():
+    v = groups_validator(mutually_exclusive=[['a', 'b', 'c']])
+    assert v.validate_python(ArgsKwargs(())) == ((), {'a': None, 'b': None, 'c': None})
+    assert v.validate_python(ArgsKwargs((1,))) == ((1,), {'b': None, 'c': None})
+    assert v.validate_python(ArgsKwargs((), {'c': 3})) == ((), {'a': None, 'b': None, 'c': 3})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1,), {'bb': 'x', 'c': 3}))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('bb',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'mutually_exclusive_arguments',
+            'loc': (0,),
+            'msg': "Only one of 'a', 'b' or 'c' may be provided",
+            'input': ArgsKwargs((1,), {'bb': 'x', 'c': 3}),
+            'ctx': {'arguments': "'a', 'b' or 'c'"},
+        },
+        {
+            'type': 'mutually_exclusive_arguments',
+            'loc': ('bb',),
+            'msg': "Only one of 'a', 'b' or 'c' may be provided",
+            'input': ArgsKwargs((1,), {'bb': 'x', 'c': 3}),
+            'ctx': {'arguments': "'a', 'b' or 'c'"},
+        },
+        {
+            'type': 'mutually_exclusive_arguments',
+            'loc': ('c',),
+            'msg': "Only one of 'a', 'b' or 'c' may be provided",
+            'input': ArgsKwargs((1,), {'bb': 'x', 'c': 3}),
+            'ctx': {'arguments': "'a', 'b' or 'c'"},
+        },
+    ]
+
+
+def test_at_least_one_of():
+    v = groups_validator(at_least_one_of=[['a', 'b']])
+    assert v.validate_python(ArgsKwargs((), {'bb': 2})) == ((), {'a': None, 'b': 2, 'c': None})
+    assert v.validate_python(ArgsKwargs((1, 2))) == ((1, 2), {'c': None})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((), {'c': 3}))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'missing_one_of_arguments',
+            'loc': ('a',),
+            'msg': "At least one of 'a' or 'b' is required",
+            'input': ArgsKwargs((), {'c': 3}),
+            'ctx': {'arguments': "'a' or 'b'"},
+        },
+        {
+            'type': 'missing_one_of_arguments',
+            'loc': ('bb',),
+            'msg': "At least one of 'a' or 'b' is required",
+            'input': ArgsKwargs((), {'c': 3}),
+            'ctx': {'arguments': "'a' or 'b'"},
+        },
+    ]
+
+
+def test_groups_combined():
+    v = groups_validator(mutually_exclusive=[['a', 'b']], at_least_one_of=[['a', 'b']])
+    assert v.validate_json('{"bb": 2}') == ((), {'a': None, 'b': 2, 'c': None})
+    with pytest.raises(ValidationError, match='mutually_exclusive_arguments'):
+        v.validate_json('{"a": 1, "bb": 2}')
+    with pytest.raises(ValidationError, match='missing_one_of_arguments'):
+        v.validate_json('{}')
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'mutually_exclusive': [['a']]}, 'mutually_exclusive groups must contain at least two parameters'),
+        ({'at_least_one_of': [['a', 'd']]}, "at_least_one_of: unknown parameter 'd'"),
+        ({'mutually_exclusive': [['a', 'b', 'a']]}, "mutually_exclusive: parameter 'a' is repeated"),
+    ],
+)
+def test_groups_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=message):
+        groups_validator(**kwargs)
+
+
+def test_default_factory_takes_data():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter('b', core_schema.int_schema()),
+                core_schema.arguments_parameter(
+                    'c',
+                    core_schema.with_default_schema(
+                        core_schema.int_schema(),
+                        default_factory=lambda data: sum(data.values()),
+                        default_factory_takes_data=True,
+                    ),
+                    mode='keyword_only',
+                ),
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1, 2))) == ((1, 2), {'c': 3})
+    assert v.validate_python(ArgsKwargs((1,), {'b': 3})) == ((1,), {'b': 3, 'c': 4})
+    assert v.validate_python(ArgsKwargs((1,), {'b': 3, 'c': 0})) == ((1,), {'b': 3, 'c': 0})
+
+
+def test_by_alias_by_name():
+    v = SchemaValidator(
+        core_schema.arguments_schema([core_schema.arguments_parameter('a', core_schema.int_schema(), alias='A')])
+    )
+    assert v.validate_python(ArgsKwargs((), {'A': 1})) == ((), {'a': 1})
+    assert v.validate_python(ArgsKwargs((), {'a': 1}), by_alias=False) == ((), {'a': 1})
+    assert v.validate_python(ArgsKwargs((), {'a': 1}), by_name=True) == ((), {'a': 1})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((), {'A': 1}), by_alias=False)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'missing_argument',
+            'loc': ('a',),
+            'msg': 'Missing required argument',
+            'input': ArgsKwargs((), {'A': 1}),
+        },
+        {
+            'type': 'unexpected_keyword_argument',
+            'loc': ('A',),
+            'msg': 'Unexpected keyword argument',
+            'input': 1,
+        },
+    ]
+
+
+def test_var_args_name():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [core_schema.arguments_parameter('a', core_schema.int_schema())],
+            var_args_schema=core_schema.int_schema(),
+            var_args_name='args',
+            var_kwargs_schema=core_schema.any_schema(),
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1, '2', 3))) == ((1,), {'args': (2, 3)})
+    assert v.validate_python(ArgsKwargs((1,), {'b': 4})) == ((1,), {'b': 4, 'args': ()})
+    assert v.validate_json('[1, 2]') == ((1,), {'args': (2,)})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1, 'x'), {'args': (2,)}))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {'type': 'unexpected_keyword_argument', 'loc': ('args',), 'msg': 'Unexpected keyword argument', 'input': (2,)},
+    ]
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({}, 'var_args_name can only be used with var_args_schema'),
+        ({'var_args_schema': core_schema.any_schema(), 'var_args_name': 'a'}, "var_args_name 'a' is also the name"),
+    ],
+)
+def test_var_args_name_invalid(kwargs, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(
+            core_schema.arguments_schema(
+                [core_schema.arguments_parameter('a', core_schema.int_schema())], **{'var_args_name': 'args', **kwargs}
+            )
+        )
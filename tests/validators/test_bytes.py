@@ -1,9 +1,10 @@
 import re
+from functools import partial
 from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -53,6 +54,9 @@ def test_lax_bytes_validator():
         ({'min_length': 2}, b'foo', b'foo'),
         ({'min_length': 2}, b'f', Err('Data should have at least 2 bytes')),
         ({'min_length': 1, 'max_length': 6, 'strict': True}, b'bytes?', b'bytes?'),
+        ({'max_length': 5}, bytearray(b'foo'), b'foo'),
+        ({'max_length': 5}, bytearray(b'foobar'), Err('Data should have at most 5 bytes')),
+        ({'min_length': 2}, bytearray(b'f'), Err('Data should have at least 2 bytes')),
     ],
 )
 def test_constrained_bytes_python_bytes(opts: Dict[str, Any], input, expected):
@@ -121,3 +125,102 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+@pytest.mark.parametrize('schema', [core_schema.bytes_schema(), core_schema.bytes_schema(max_length=10)])
+def test_json_bytes_base64_round_trip(schema):
+    config = {'ser_json_bytes': 'base64', 'val_json_bytes': 'base64'}
+    v = SchemaValidator(schema, config)
+    s = SchemaSerializer(schema, config)
+    data = b'\xd8\x07\xc1\xf5\xc0'
+
+    encoded = s.to_json(data)
+    assert encoded == b'"2AfB9cA="'
+    assert v.validate_json(encoded) == data
+    assert v.validate_python(s.to_python(data, mode='json')) == data
+    # bytes are still accepted as is in python
+    assert v.validate_python(data) == data
+
+
+def test_json_bytes_base64_invalid():
+    v = SchemaValidator(core_schema.bytes_schema(), {'val_json_bytes': 'base64'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"!@#"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': 'Data should be valid base64: Invalid byte 33, offset 0.',
+            'input': '!@#',
+            'ctx': {'encoding': 'base64', 'encoding_error': 'Invalid byte 33, offset 0.'},
+        }
+    ]
+
+
+
+@pytest.mark.parametrize(
+    'encoding,input_value,expected',
+    [
+        ('base64', 'aGk+Pz8=', b'hi>??'),
+        ('base64', 'aGk-Pz8=', b'hi>??'),
+        ('base64', 'aGk+Pz8', Err('Data should be valid base64: Invalid padding')),
+        ('base64', 'aG!+Pz8=', Err('Data should be valid base64: Invalid byte 33, offset 2.')),
+        ('base64url', 'aGk-Pz8=', b'hi>??'),
+        ('base64url', 'aGk+Pz8=', Err('Data should be valid base64url: Invalid byte 43, offset 3.')),
+        ('hex', 'DEADbeef', b'\xde\xad\xbe\xef'),
+        ('hex', '', b''),
+        ('hex', 'deadbeefz0', Err('Data should be valid hex: Invalid byte 122, offset 8.')),
+        ('hex', 'abc', Err('Data should be valid hex: Invalid input length.')),
+    ],
+)
+def test_bytes_encoding(py_and_json: PyAndJson, encoding, input_value, expected):
+    v = py_and_json(core_schema.bytes_schema(encoding=encoding))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_bytes_encoding_overrides_config():
+    schema = core_schema.bytes_schema(encoding='hex', max_length=2)
+    v = SchemaValidator(schema, {'val_json_bytes': 'base64'})
+    assert v.validate_json('"cafe"') == b'\xca\xfe'
+    # bytes are still accepted as is in python
+    assert v.validate_python(b'ca') == b'ca'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('cafe0x')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'bytes_invalid_encoding',
+            'loc': (),
+            'msg': 'Data should be valid hex: Invalid byte 120, offset 5.',
+            'input': 'cafe0x',
+            'ctx': {'encoding': 'hex', 'encoding_error': 'Invalid byte 120, offset 5.'},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Data should have at most 2 bytes'):
+        v.validate_python('cafe00')
+
+
+def test_bytes_encoding_invalid():
+    with pytest.raises(SchemaError, match='Invalid bytes encoding: `base32`, expected `base64`, `base64url` or `hex`'):
+        SchemaValidator(core_schema.bytes_schema(encoding='base32'))
+
+def test_json_bytes_invalid_mode():
+    with pytest.raises(SchemaError, match='Invalid bytes validation mode: `foobar`, expected `utf8` or `base64`'):
+        SchemaValidator(core_schema.bytes_schema(), {'val_json_bytes': 'foobar'})
+
+
+@pytest.mark.parametrize('schema', [core_schema.bytes_schema, partial(core_schema.bytes_schema, max_length=5)])
+def test_preserve_bytearray(schema):
+    v = SchemaValidator(schema(preserve_bytearray=True))
+    input_value = bytearray(b'foo')
+    assert v.validate_python(input_value) is input_value
+    assert v.validate_python(b'foo') == b'foo'
+    assert v.validate_python('foo') == b'foo'
+
+    v = SchemaValidator(schema())
+    output = v.validate_python(input_value)
+    assert output == b'foo'
+    assert type(output) is bytes
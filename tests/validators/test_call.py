@@ -1,11 +1,13 @@
+import asyncio
 import dataclasses
 import re
+import types
 from collections import namedtuple
 from functools import partial
 
 import pytest
 
-from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError
+from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -221,3 +223,113 @@ def test_custom_name():
     assert 'name:"call[foobar]"' in plain_repr(v)
     assert v.validate_python((1,)) == 1
     assert v.validate_python(('2',)) == 2
+
+
+def test_async_function():
+    async def my_function(a, b):
+        await asyncio.sleep(0)
+        return a + b
+
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema(
+                [
+                    core_schema.arguments_parameter('a', core_schema.int_schema()),
+                    core_schema.arguments_parameter('b', core_schema.any_schema()),
+                ]
+            ),
+            my_function,
+            return_schema=core_schema.int_schema(le=10),
+        )
+    )
+    awaitable = v.validate_python(('1', 2))
+    # awaitable without a wrapper coroutine
+    assert not isinstance(awaitable, types.CoroutineType)
+    assert asyncio.iscoroutine(awaitable)
+    with pytest.raises(TypeError, match='unsupported operand'):
+        asyncio.run(wrap(v.validate_python((1, 'x'))))
+    assert asyncio.run(wrap(awaitable)) == 3
+
+    with pytest.raises(ValidationError) as exc_info:
+        asyncio.run(wrap(v.validate_python((1, 20))))
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'less_than_equal',
+            'loc': ('return',),
+            'msg': 'Input should be less than or equal to 10',
+            'input': 21,
+            'ctx': {'le': 10},
+        }
+    ]
+
+    # arguments are still validated immediately
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(('x', 1))
+
+
+def test_async_function_iterate():
+    class Yield:
+        def __await__(self):
+            return (yield 'yielded')
+
+    async def my_function(a):
+        return a + await Yield()
+
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema([core_schema.arguments_parameter('a', core_schema.int_schema())]),
+            my_function,
+            return_schema=core_schema.str_schema(),
+        )
+    )
+    iterator = v.validate_python(('1',)).__await__()
+    assert next(iterator) == 'yielded'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        iterator.send(1)
+
+    iterator = v.validate_python(('1',)).__await__()
+    assert iterator.send(None) == 'yielded'
+    with pytest.raises(TypeError, match='unsupported operand'):
+        iterator.send('x')
+
+    async def repr_function(a):
+        return f'{a}:{await Yield()!r}'
+
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema([core_schema.arguments_parameter('a', core_schema.int_schema())]),
+            repr_function,
+            return_schema=core_schema.str_schema(),
+        )
+    )
+    # the validated return value is raised with `StopIteration`, from both `__next__` and `send`
+    iterator = v.validate_python(('1',)).__await__()
+    assert next(iterator) == 'yielded'
+    with pytest.raises(StopIteration) as exc_info:
+        next(iterator)
+    assert exc_info.value.value == '1:None'
+
+    iterator = v.validate_python(('1',)).__await__()
+    assert next(iterator) == 'yielded'
+    with pytest.raises(StopIteration) as exc_info:
+        iterator.send('b')
+    assert exc_info.value.value == "1:'b'"
+
+
+def test_async_function_no_return_schema():
+    async def my_function(a):
+        return a
+
+    v = SchemaValidator(
+        core_schema.call_schema(
+            core_schema.arguments_schema([core_schema.arguments_parameter('a', core_schema.int_schema())]), my_function
+        )
+    )
+    coroutine = v.validate_python(('1',))
+    assert asyncio.iscoroutine(coroutine)
+    assert asyncio.run(wrap(coroutine)) == 1
+
+
+async def wrap(awaitable):
+    return await awaitable
@@ -1592,3 +1592,33 @@ def test_leak_dataclass(validator):
     gc.collect()
 
     assert ref() is None
+
+
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10')
+def test_validate_assignment_batch_slots():
+    @dataclasses.dataclass(slots=True)
+    class Foo:
+        a: int
+        b: str
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(name='b', schema=core_schema.str_schema()),
+            ],
+        ),
+        ['a', 'b'],
+        slots=True,
+    )
+    v = SchemaValidator(schema)
+    foo = v.validate_python({'a': 1, 'b': 'x'})
+
+    v.validate_assignment_batch(foo, {'a': '2', 'b': 'y'})
+    assert (foo.a, foo.b) == (2, 'y')
+
+    with pytest.raises(ValidationError):
+        v.validate_assignment_batch(foo, {'a': 3, 'b': 123})
+    assert (foo.a, foo.b) == (2, 'y')
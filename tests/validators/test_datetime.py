@@ -2,6 +2,7 @@ import copy
 import json
 import platform
 import re
+import sys
 from datetime import date, datetime, time, timedelta, timezone, tzinfo
 from decimal import Decimal
 from typing import Dict
@@ -394,9 +395,11 @@ def test_offset_too_large():
 def test_raises_schema_error_for_unknown_constraint_kind():
     with pytest.raises(
         SchemaError,
-        match=(r'Input should be \'aware\' or \'naive\' \[type=literal_error, input_value=\'foo\', input_type=str\]'),
+        match=(
+            r'Invalid tz_constraint "foo", should be \'aware\', \'naive\', an offset or an IANA timezone name'
+        ),
     ):
-        validate_core_schema({'type': 'datetime', 'tz_constraint': 'foo'})
+        SchemaValidator({'type': 'datetime', 'tz_constraint': 'foo'})
 
 
 def test_aware():
@@ -477,8 +480,74 @@ def test_tz_constraint_too_high():
 
 
 def test_tz_constraint_wrong():
-    with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive"):
-        validate_core_schema(core_schema.datetime_schema(tz_constraint='wrong'))
+    with pytest.raises(SchemaError, match="should be 'aware', 'naive'"):
+        SchemaValidator(core_schema.datetime_schema(tz_constraint='wrong'))
+    with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive'"):
+        validate_core_schema(core_schema.datetime_schema(tz_constraint=[]))
+
+
+@pytest.mark.skipif(sys.version_info < (3, 9), reason='zoneinfo requires python 3.9+')
+def test_tz_constraint_zone():
+    from zoneinfo import ZoneInfo
+
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint='Europe/London'))
+    value = datetime(2022, 6, 8, 12, 13, 14, tzinfo=ZoneInfo('Europe/London'))
+    assert value is v.validate_python(value)
+    assert v.validate_python('2022-06-08T12:13:14+01:00') == datetime(
+        2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=1))
+    )
+    assert v.validate_python('2022-01-08T12:13:14Z') == datetime(2022, 1, 8, 12, 13, 14, tzinfo=timezone.utc)
+
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python('2022-06-08T12:13:14')
+
+    with pytest.raises(ValidationError, match='Timezone offset of 3600 required, got 0') as exc_info:
+        v.validate_python('2022-06-08T12:13:14Z')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'timezone_offset',
+            'loc': (),
+            'msg': 'Timezone offset of 3600 required, got 0',
+            'input': '2022-06-08T12:13:14Z',
+            'ctx': {'tz_expected': 3600, 'tz_actual': 0},
+        }
+    ]
+
+
+def test_tz_constraint_zone_time():
+    with pytest.raises(SchemaError, match='IANA timezone names are not supported as a time `tz_constraint`'):
+        SchemaValidator(core_schema.time_schema(tz_constraint='UTC'))
+
+
+@pytest.mark.skipif(sys.version_info < (3, 9), reason='zoneinfo requires python 3.9+')
+def test_convert_to_tz_zone():
+    from zoneinfo import ZoneInfo
+
+    v = SchemaValidator(core_schema.datetime_schema(convert_to_tz='Europe/London'))
+    london = ZoneInfo('Europe/London')
+    assert v.validate_python('2022-06-08T12:13:14Z') == datetime(2022, 6, 8, 13, 13, 14, tzinfo=london)
+    output = v.validate_python(datetime(2022, 1, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=-2))))
+    assert output == datetime(2022, 1, 8, 14, 13, 14, tzinfo=london)
+    assert output.tzinfo is london
+    # naive datetimes are not converted
+    assert v.validate_python('2022-06-08T12:13:14') == datetime(2022, 6, 8, 12, 13, 14)
+
+
+def test_convert_to_tz_offset():
+    v = SchemaValidator(core_schema.datetime_schema(tz_constraint='aware', convert_to_tz=0))
+    output = v.validate_python(datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=2))))
+    assert output == datetime(2022, 6, 8, 10, 13, 14, tzinfo=timezone.utc)
+    assert output.utcoffset() == timedelta(0)
+    assert v.validate_json('"2022-06-08T12:13:14-05:00"') == datetime(2022, 6, 8, 17, 13, 14, tzinfo=timezone.utc)
+
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python('2022-06-08T12:13:14')
+
+
+def test_convert_to_tz_invalid():
+    with pytest.raises(SchemaError, match='Invalid timezone "Not/AZone"'):
+        SchemaValidator(core_schema.datetime_schema(convert_to_tz='Not/AZone'))
 
 
 def test_tz_hash() -> None:
@@ -9,7 +9,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -203,6 +203,43 @@ def test_decimal_multiple_of(py_and_json: PyAndJson, multiple_of: float, input_v
         assert isinstance(output, Decimal)
 
 
+@pytest.mark.parametrize(
+    'multiple_of,input_value,error',
+    [
+        (Decimal('0.1'), Decimal('0.3'), None),
+        (Decimal('0.1'), Decimal('-0.7'), None),
+        (Decimal('0.25'), Decimal('1.75'), None),
+        (Decimal('0.25'), '1.5000', None),
+        (Decimal('0.1'), Decimal('0'), None),
+        (7, Decimal('21'), None),
+        (Decimal('1E+3'), Decimal('2000'), None),
+        (Decimal('0.1'), Decimal('0.30000000000000000001'), Err('Input should be a multiple of 0.1')),
+        (Decimal('0.25'), Decimal('0.3'), Err('Input should be a multiple of 0.25')),
+        (Decimal('1E+3'), Decimal('2500'), Err('Input should be a multiple of 1E+3')),
+        # huge exponents don't build huge powers of ten
+        (Decimal('0.1'), Decimal('1e999999999'), None),
+        (7, Decimal('1e999999999'), Err('Input should be a multiple of 7')),
+        (1, Decimal('1e-999999999'), Err('Input should be a multiple of 1')),
+        (Decimal('1e999999999'), Decimal('1'), Err('Input should be a multiple of 1E+999999999')),
+    ],
+    ids=repr,
+)
+def test_decimal_multiple_of_exact(multiple_of, input_value, error: Err | None):
+    v = SchemaValidator(core_schema.decimal_schema(multiple_of=multiple_of, multiple_of_mode='exact'))
+    if error:
+        with pytest.raises(ValidationError, match=re.escape(error.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == Decimal(input_value)
+
+
+def test_decimal_multiple_of_mode_config():
+    v = SchemaValidator(core_schema.decimal_schema(multiple_of=Decimal('0.1')), {'multiple_of_mode': 'exact'})
+    assert v.validate_python(Decimal('0.3')) == Decimal('0.3')
+    with pytest.raises(ValidationError, match='Input should be a multiple of 0.1'):
+        v.validate_python(Decimal('0.35'))
+
+
 def test_union_decimal_py():
     v = SchemaValidator(
         {'type': 'union', 'choices': [{'type': 'decimal', 'strict': True}, {'type': 'decimal', 'multiple_of': 7}]}
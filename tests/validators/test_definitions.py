@@ -1,6 +1,6 @@
 import pytest
 
-from pydantic_core import SchemaError, SchemaValidator, core_schema, validate_core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
 
 from ..conftest import plain_repr
 
@@ -140,3 +140,283 @@ def test_use_after():
         )
     )
     assert v.validate_python(['1', '2']) == (1, 2)
+
+
+def test_required_self_reference():
+    with pytest.raises(
+        SchemaError, match='definition `Node` can never be validated, it always requires itself: Node -> Node'
+    ):
+        SchemaValidator(
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('Node'),
+                [
+                    core_schema.typed_dict_schema(
+                        {'child': core_schema.typed_dict_field(core_schema.definition_reference_schema('Node'))},
+                        ref='Node',
+                    )
+                ],
+            )
+        )
+
+
+def test_required_cycle_path():
+    with pytest.raises(SchemaError, match='it always requires itself: A -> B -> A'):
+        SchemaValidator(
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('A'),
+                [
+                    core_schema.typed_dict_schema(
+                        {
+                            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                            'b': core_schema.typed_dict_field(
+                                core_schema.no_info_after_validator_function(
+                                    lambda v: v, core_schema.definition_reference_schema('B')
+                                )
+                            ),
+                        },
+                        ref='A',
+                    ),
+                    core_schema.tuple_positional_schema(
+                        [core_schema.int_schema(), core_schema.definition_reference_schema('A')], ref='B'
+                    ),
+                ],
+            )
+        )
+
+
+@pytest.mark.parametrize(
+    'field_schema,field_kwargs,input_value',
+    [
+        (core_schema.nullable_schema(core_schema.definition_reference_schema('Node')), {}, {'child': None}),
+        (core_schema.list_schema(core_schema.definition_reference_schema('Node')), {}, {'child': []}),
+        (
+            core_schema.union_schema([core_schema.definition_reference_schema('Node'), core_schema.int_schema()]),
+            {},
+            {'child': 1},
+        ),
+        (core_schema.definition_reference_schema('Node'), {'required': False}, {}),
+    ],
+)
+def test_optional_self_reference(field_schema, field_kwargs, input_value):
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Node'),
+            [
+                core_schema.typed_dict_schema(
+                    {'child': core_schema.typed_dict_field(field_schema, **field_kwargs)}, ref='Node'
+                )
+            ],
+        )
+    )
+    assert v.validate_python(input_value) == input_value
+
+
+def test_ref_overrides():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'lax': core_schema.typed_dict_field(core_schema.definition_reference_schema('Point')),
+                    'strict': core_schema.typed_dict_field(
+                        core_schema.definition_reference_schema('Point', strict=True)
+                    ),
+                }
+            ),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'y': core_schema.typed_dict_field(core_schema.int_schema()),
+                    },
+                    ref='Point',
+                )
+            ],
+        )
+    )
+    assert v.validate_python({'lax': {'x': '1', 'y': 2}, 'strict': {'x': 1, 'y': 2}}) == {
+        'lax': {'x': 1, 'y': 2},
+        'strict': {'x': 1, 'y': 2},
+    }
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'lax': {'x': '1', 'y': 2}, 'strict': {'x': '1', 'y': 2}})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('strict', 'x'), 'msg': 'Input should be a valid integer', 'input': '1'}
+    ]
+
+
+def test_ref_overrides_shared_instances():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'lax': core_schema.typed_dict_field(core_schema.definition_reference_schema('Point')),
+                    'strict': core_schema.typed_dict_field(
+                        core_schema.definition_reference_schema('Point', strict=True)
+                    ),
+                }
+            ),
+            [core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.int_schema())}, ref='Point')],
+        ),
+        {'val_circular_ref': 'reuse'},
+    )
+    point = {'x': '1'}
+    # the output validated in lax mode isn't reused where the same input is validated strictly
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'lax': point, 'strict': point})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'int_type', 'loc': ('strict', 'x'), 'msg': 'Input should be a valid integer', 'input': '1'}
+    ]
+
+
+def test_ref_override_from_attributes():
+    class Point:
+        x = 1
+
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.tuple_positional_schema(
+                [
+                    core_schema.definition_reference_schema('Point'),
+                    core_schema.definition_reference_schema('Point', from_attributes=True),
+                ]
+            ),
+            [core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())}, ref='Point')],
+        )
+    )
+    assert v.validate_python(({'x': 1}, Point())) == (({'x': 1}, None, {'x'}), ({'x': 1}, None, {'x'}))
+    with pytest.raises(
+        ValidationError, match=r'0\n  Input should be a valid dictionary or instance of Model \[type=model_type'
+    ):
+        v.validate_python((Point(), Point()))
+
+
+def box_schema(*params: core_schema.CoreSchema) -> core_schema.CoreSchema:
+    return core_schema.definitions_schema(
+        core_schema.tuple_positional_schema(
+            [core_schema.definition_reference_schema('Box', params={'T': param}) for param in params]
+        ),
+        [
+            core_schema.definition_template_schema(
+                'Box', ['T'], core_schema.list_schema(core_schema.definition_param_schema('T'))
+            )
+        ],
+    )
+
+
+def test_definition_template():
+    v = SchemaValidator(box_schema(core_schema.int_schema(), core_schema.str_schema(), core_schema.int_schema()))
+    assert v.validate_python((['1'], ['a'], [2])) == ([1], ['a'], [2])
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(([1], [2], ['x']))
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('string_type', (1, 0)),
+        ('int_parsing', (2, 0)),
+    ]
+
+
+def test_definition_template_recursive():
+    node_ref = core_schema.definition_reference_schema('Node', params={'T': core_schema.definition_param_schema('T')})
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Node', params={'T': core_schema.int_schema()}),
+            [
+                core_schema.definition_template_schema(
+                    'Node',
+                    ['T'],
+                    core_schema.typed_dict_schema(
+                        {
+                            'value': core_schema.typed_dict_field(core_schema.definition_param_schema('T')),
+                            'children': core_schema.typed_dict_field(core_schema.list_schema(node_ref)),
+                        }
+                    ),
+                )
+            ],
+        )
+    )
+    assert v.validate_python({'value': '1', 'children': [{'value': 2, 'children': []}]}) == {
+        'value': 1,
+        'children': [{'value': 2, 'children': []}],
+    }
+    with pytest.raises(ValidationError, match=r'children\.0\.value\n  Input should be a valid integer'):
+        v.validate_python({'value': 1, 'children': [{'value': 'x', 'children': []}]})
+
+
+def test_definition_template_with_plain_definitions():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Pair'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(
+                            core_schema.definition_reference_schema(
+                                'Box', params={'T': core_schema.definition_reference_schema('Id')}
+                            )
+                        ),
+                        'b': core_schema.typed_dict_field(core_schema.definition_reference_schema('Id')),
+                    },
+                    ref='Pair',
+                ),
+                core_schema.int_schema(ref='Id'),
+                core_schema.definition_template_schema(
+                    'Box', ['T'], core_schema.list_schema(core_schema.definition_param_schema('T'))
+                ),
+            ],
+        )
+    )
+    assert v.validate_python({'a': ['1'], 'b': '2'}) == {'a': [1], 'b': 2}
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        (
+            core_schema.definitions_schema(
+                core_schema.definition_param_schema('T'),
+                [core_schema.definition_template_schema('Box', ['T'], core_schema.int_schema())],
+            ),
+            'Unknown definition param `T`',
+        ),
+        (
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('Box', params={'U': core_schema.int_schema()}),
+                [core_schema.definition_template_schema('Box', ['T'], core_schema.int_schema())],
+            ),
+            'Definition template `Box` is missing param `T`',
+        ),
+        (
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('Box', params={}),
+                [core_schema.definition_template_schema('Box', ['T'], core_schema.int_schema())],
+            ),
+            r'Definition template `Box` expects params \["T"\], got 0 params',
+        ),
+        (
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('Id', params={'T': core_schema.int_schema()}),
+                [core_schema.int_schema(ref='Id')],
+            ),
+            "Definition `Id` is not a definition template, it can't have params",
+        ),
+        (
+            core_schema.definitions_schema(
+                core_schema.definition_reference_schema('Node', params={'T': core_schema.int_schema()}),
+                [
+                    core_schema.definition_template_schema(
+                        'Node',
+                        ['T'],
+                        core_schema.nullable_schema(
+                            core_schema.definition_reference_schema(
+                                'Node', params={'T': core_schema.list_schema(core_schema.definition_param_schema('T'))}
+                            )
+                        ),
+                    )
+                ],
+            ),
+            'Definition template `Node` is nested too deeply',
+        ),
+    ],
+)
+def test_definition_template_errors(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
@@ -1104,3 +1104,141 @@ def test_no_exponential_blowup():
     )
 
     SchemaValidator(schema)
+
+
+class OrmNode:
+    def __init__(self, name, parent=None):
+        self.name = name
+        self.parent = parent
+        self.children = []
+        if parent is not None:
+            parent.children.append(self)
+
+
+def node_schema():
+    class Node:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    node_ref = core_schema.definition_reference_schema('Node')
+    return core_schema.definitions_schema(
+        node_ref,
+        [
+            core_schema.model_schema(
+                Node,
+                core_schema.model_fields_schema(
+                    {
+                        'name': core_schema.model_field(core_schema.str_schema()),
+                        'parent': core_schema.model_field(core_schema.nullable_schema(node_ref)),
+                        'children': core_schema.model_field(core_schema.list_schema(node_ref)),
+                    },
+                    from_attributes=True,
+                ),
+                ref='Node',
+            )
+        ],
+    )
+
+
+def test_circular_ref_error():
+    root = OrmNode('root')
+    OrmNode('child', root)
+    v = SchemaValidator(node_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(root)
+    assert exc_info.value.errors(include_url=False, include_input=False) == [
+        {
+            'type': 'recursion_loop',
+            'loc': ('children', 0, 'parent'),
+            'msg': 'Recursion error - cyclic reference detected',
+        }
+    ]
+
+
+def test_circular_ref_reuse():
+    root = OrmNode('root')
+    a = OrmNode('a', root)
+    OrmNode('b', root)
+    v = SchemaValidator(node_schema(), {'val_circular_ref': 'reuse'})
+
+    m = v.validate_python(root)
+    assert m.name == 'root'
+    assert m.parent is None
+    assert [c.name for c in m.children] == ['a', 'b']
+    assert m.children[0].parent is m
+    assert m.children[1].parent is m
+
+    # validating from a child links back up to the same instances
+    m_a = v.validate_python(a)
+    assert m_a.parent.children[0] is m_a
+
+    # instances aren't shared between calls
+    assert v.validate_python(root) is not m
+
+
+def test_circular_ref_reuse_repeated():
+    v = SchemaValidator(
+        core_schema.list_schema(node_schema()),
+        {'val_circular_ref': 'reuse'},
+    )
+    leaf = {'name': 'leaf', 'parent': None, 'children': []}
+    m1, m2, m3 = v.validate_python([leaf, leaf, {**leaf}])
+    assert m1 is m2
+    assert m1 is not m3
+    assert m1.__dict__ == m3.__dict__
+
+
+def test_circular_ref_reuse_invalid():
+    root = OrmNode('root')
+    OrmNode(123, root)
+    v = SchemaValidator(node_schema(), {'val_circular_ref': 'reuse'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(root)
+    assert exc_info.value.errors(include_url=False, include_input=False) == [
+        {'type': 'string_type', 'loc': ('children', 0, 'name'), 'msg': 'Input should be a valid string'}
+    ]
+
+
+def test_circular_ref_invalid_mode():
+    with pytest.raises(SchemaError, match='Invalid val_circular_ref: `link`, expected `error` or `reuse`'):
+        SchemaValidator(core_schema.int_schema(), {'val_circular_ref': 'link'})
+
+
+def test_recursion_limit():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('Branch'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'sub_branch': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                    ),
+                },
+                ref='Branch',
+            )
+        ],
+    )
+    # deeper than the default limit, but within the highest limit allowed
+    default_limit = pydantic_core._pydantic_core._recursion_limit
+    data = {'name': 'leaf', 'sub_branch': None}
+    for i in range(default_limit + default_limit // 2):
+        data = {'name': str(i), 'sub_branch': data}
+
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(schema).validate_python(data)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'recursion_loop'
+
+    assert SchemaValidator(schema, {'recursion_limit': default_limit * 2}).validate_python(data) == data
+
+    # very high limits are capped, so deep data is still an error rather than a stack overflow
+    deep_data = {'name': 'leaf', 'sub_branch': None}
+    for i in range(10_000):
+        deep_data = {'name': str(i), 'sub_branch': deep_data}
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(schema, {'recursion_limit': 65_535}).validate_python(deep_data)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'recursion_loop'
+
+    v = SchemaValidator(schema, {'recursion_limit': 5})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('sub_branch',) * 4
@@ -6,9 +6,9 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, core_schema
 
-from ..conftest import Err, PyAndJson
+from ..conftest import Err, PyAndJson, infinite_generator
 
 
 def test_dict(py_and_json: PyAndJson):
@@ -34,6 +34,8 @@ def test_dict(py_and_json: PyAndJson):
         ((), Err('Input should be a valid dictionary [type=dict_type,')),
         ((('x', 'y'),), Err('Input should be a valid dictionary [type=dict_type,')),
         ((type('Foobar', (), {'x': 1})()), Err('Input should be a valid dictionary [type=dict_type,')),
+        ({'a': b'1', 'b': '2'}.items(), Err('Input should be a valid dictionary [type=dict_type,')),
+        (iter([('x', 'y')]), Err('Input should be a valid dictionary [type=dict_type,')),
     ],
     ids=repr,
 )
@@ -196,6 +198,60 @@ def test_mapping_error_yield_1(mapping_items):
     ]
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'a': b'1', 'b': '2'}.items(), {'a': '1', 'b': '2'}),
+        ((p for p in [('a', b'1'), ('b', '2')]), {'a': '1', 'b': '2'}),
+        (iter([('x', 'y')]), {'x': 'y'}),
+        ([('x', 'y'), ('z', 'z')], {'x': 'y', 'z': 'z'}),
+        ((('x', 'y'),), {'x': 'y'}),
+        ({'a': 'b'}, {'a': 'b'}),
+        ('foobar', Err("Input should be a valid dictionary [type=dict_type, input_value='foobar', input_type=str]")),
+    ],
+    ids=repr,
+)
+def test_from_pairs(input_value, expected):
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'str'}, 'from_pairs': True}
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_pairs_not_consumed_in_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.dict_schema(), core_schema.list_schema()]))
+    assert v.validate_python(p for p in [('a', 1)]) == [('a', 1)]
+
+
+def test_pairs_strict():
+    v = SchemaValidator({'type': 'dict', 'strict': True, 'from_pairs': True})
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [type=dict_type,')):
+        v.validate_python({'a': 1}.items())
+
+
+@pytest.mark.parametrize('pairs', [[(1,)], ['foobar'], [(1, 2, 3)]])
+def test_pairs_error(pairs):
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}, 'from_pairs': True}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter(pairs))
+
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'mapping_type',
+            'loc': (),
+            'msg': 'Input should be a valid mapping, error: Items must be tuples of (key, value) pairs',
+            'input': HasRepr(IsStr(regex='<list_iterator object at.+')),
+            'ctx': {'error': 'Items must be tuples of (key, value) pairs'},
+        }
+    ]
+
+
 @pytest.mark.parametrize(
     'kwargs,input_value,expected',
     [
@@ -235,3 +291,27 @@ def test_json_dict():
     assert exc_info.value.errors(include_url=False) == [
         {'type': 'dict_type', 'loc': (), 'msg': 'Input should be an object', 'input': 1}
     ]
+
+
+def test_pairs_max_length_during_iteration():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'max_length': 3, 'from_pairs': True})
+    assert v.validate_python(iter([(1, 'a'), ('1', 'b'), (2, 'c')])) == {1: 'b', 2: 'c'}
+
+    pairs = ((i, i) for i in infinite_generator())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(pairs)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_long',
+            'loc': (),
+            'msg': 'Dictionary should have at most 3 items after validation, not more',
+            'input': HasRepr(IsStr(regex='<generator object.+')),
+            'ctx': {'field_type': 'Dictionary', 'max_length': 3, 'actual_length': None},
+        }
+    ]
+
+    # invalid pairs count towards the limit too
+    invalid_pairs = (('x', i) for i in infinite_generator())
+    with pytest.raises(ValidationError, match='Dictionary should have at most 3 items after validation, not more'):
+        v.validate_python(invalid_pairs)
@@ -0,0 +1,101 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('john@example.com', 'john@example.com'),
+        ('John.Doe@Example.COM', 'John.Doe@example.com'),
+        ("o'brien+tag@sub.example.co.uk", "o'brien+tag@sub.example.co.uk"),
+        ('a@MÜNCHEN.de', 'a@münchen.de'),
+        ('a@xn--mnchen-3ya.de', 'a@münchen.de'),
+        ('jörg@example.com', 'jörg@example.com'),
+        ('"john doe"@example.com', '"john doe"@example.com'),
+        ('"a\\"b@c"@example.com', '"a\\"b@c"@example.com'),
+        ('a@[192.168.0.1]', 'a@[192.168.0.1]'),
+        ('a@[IPv6:2001:db8::1]', 'a@[IPv6:2001:db8::1]'),
+        ('foo', Err('Input should be a valid email address, an email address must have an @-sign')),
+        ('@example.com', Err('there must be something before the @-sign')),
+        ('a@', Err('there must be something after the @-sign')),
+        ('.a@example.com', Err("the part before the @-sign can't start with a period")),
+        ('a.@example.com', Err("the part before the @-sign can't end with a period")),
+        ('a..b@example.com', Err("the part before the @-sign can't have two periods in a row")),
+        ('a b@example.com', Err("the part before the @-sign contains an invalid character: ' '")),
+        ('"a"b"@example.com', Err('the quoted part before the @-sign has an unescaped quote')),
+        ('a@example', Err('the part after the @-sign is not valid, it should have a period')),
+        ('a@example.123', Err("the part after the @-sign can't end with a number")),
+        ('a@example..com', Err('the part after the @-sign has an empty label')),
+        ('a@-example.com', Err("the domain label -example can't start or end with a hyphen")),
+        ('a@exa_mple.com', Err('the part after the @-sign is not a valid domain name: exa_mple.com')),
+        ('a@[300.1.1.1]', Err('the domain literal [300.1.1.1] is not a valid IP address')),
+        ('a' * 65 + '@example.com', Err('the part before the @-sign is too long (65 bytes, at most 64 are allowed)')),
+        (
+            'a' * 64 + '@' + 'b' * 60 + '.' + 'c' * 60 + '.' + 'd' * 60 + '.eee.com',
+            Err('the email address is too long (255 bytes, at most 254 are allowed)'),
+        ),
+        (123, Err('Input should be a valid string')),
+    ],
+)
+def test_email(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.email_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_email_error_details():
+    v = SchemaValidator(core_schema.email_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('john.example.com')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'email_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid email address, an email address must have an @-sign',
+            'input': 'john.example.com',
+            'ctx': {'reason': 'an email address must have an @-sign'},
+        }
+    ]
+
+
+def test_allow_smtputf8():
+    v = SchemaValidator(core_schema.email_schema(allow_smtputf8=False))
+    assert v.validate_python('joerg@münchen.de') == 'joerg@münchen.de'
+    with pytest.raises(ValidationError, match='contains non-ASCII characters, which requires SMTPUTF8'):
+        v.validate_python('jörg@example.com')
+
+
+def test_email_strict():
+    v = SchemaValidator(core_schema.email_schema(strict=True))
+    assert v.validate_python('john@example.com') == 'john@example.com'
+    with pytest.raises(ValidationError, match='Input should be a valid string'):
+        v.validate_python(b'john@example.com')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('John Doe <john@example.com>', ('John Doe', 'john@example.com')),
+        ('"Doe, John" <john@Example.com>', ('Doe, John', 'john@example.com')),
+        ('<john@example.com>', ('john', 'john@example.com')),
+        ('john@example.com', ('john', 'john@example.com')),
+        ('  John <john@example.com>  ', ('John', 'john@example.com')),
+        ('John john@example.com>', Err("the address has a `>` without a matching `<`")),
+        ('John <john>', Err('an email address must have an @-sign')),
+    ],
+)
+def test_name_email(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.name_email_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
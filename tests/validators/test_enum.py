@@ -0,0 +1,143 @@
+import re
+from enum import Enum, IntEnum
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class Color(Enum):
+    RED = 'red'
+    DARK_RED = 'dark-red'
+    LIGHT_BLUE = 'light-blue'
+    CRIMSON = 'dark-red'
+
+
+class Num(IntEnum):
+    ONE = 1
+    TWO = 2
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('red', Color.RED),
+        ('dark-red', Color.DARK_RED),
+        ('RED', Err("Input should be 'red', 'dark-red' or 'light-blue' [type=enum,")),
+        ('DARK_RED', Err("Input should be 'red', 'dark-red' or 'light-blue' [type=enum,")),
+        (1, Err("Input should be 'red', 'dark-red' or 'light-blue' [type=enum,")),
+    ],
+)
+def test_enum_exact(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.enum_schema(Color))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('red', Color.RED),
+        ('Red', Color.RED),
+        ('DARK_RED', Color.DARK_RED),
+        ('Dark-Red', Color.DARK_RED),
+        ('darkred', Color.DARK_RED),
+        ('crimson', Color.DARK_RED),
+        ('light_blue', Color.LIGHT_BLUE),
+        ('reed', Err("Input should be 'red', 'dark-red' or 'light-blue', did you mean 'red'? [type=enum_close_match,")),
+        ('light blue', Err("did you mean 'light-blue'? [type=enum_close_match,")),
+        ('darkrad', Err("did you mean 'dark-red'? [type=enum_close_match,")),
+        ('purple', Err("Input should be 'red', 'dark-red' or 'light-blue' [type=enum,")),
+        ('red' * 100_000, Err("Input should be 'red', 'dark-red' or 'light-blue' [type=enum,")),
+        (1, Err("Input should be 'red', 'dark-red' or 'light-blue' [type=enum,")),
+    ],
+)
+def test_enum_fuzzy(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.enum_schema(Color, match_mode='fuzzy'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
+def test_enum_instance():
+    v = SchemaValidator(core_schema.enum_schema(Color, strict=True))
+    assert v.validate_python(Color.LIGHT_BLUE) is Color.LIGHT_BLUE
+    assert v.validate_json('"light-blue"') is Color.LIGHT_BLUE
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('light-blue')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of Color',
+            'input': 'light-blue',
+            'ctx': {'class': 'Color'},
+        }
+    ]
+
+
+def test_enum_fuzzy_strict():
+    v = SchemaValidator(core_schema.enum_schema(Color, match_mode='fuzzy', strict=True))
+    assert v.validate_json('"red"') is Color.RED
+    with pytest.raises(ValidationError, match=r'\[type=enum,'):
+        v.validate_json('"RED"')
+    assert v.validate_json('"RED"', strict=False) is Color.RED
+
+
+def test_enum_close_match_error():
+    class Vehicle(Enum):
+        CAR = 'car'
+        CART = 'cart'
+        BUS = 'bus'
+
+    v = SchemaValidator(core_schema.enum_schema(Vehicle, match_mode='fuzzy'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('cat')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum_close_match',
+            'loc': (),
+            'msg': "Input should be 'car', 'cart' or 'bus', did you mean 'car' or 'cart'?",
+            'input': 'cat',
+            'ctx': {'expected': "'car', 'cart' or 'bus'", 'candidates': "'car' or 'cart'"},
+        }
+    ]
+
+
+def test_int_enum():
+    v = SchemaValidator(core_schema.enum_schema(Num, match_mode='fuzzy'))
+    assert v.validate_python(1) is Num.ONE
+    assert v.validate_json('2') is Num.TWO
+    assert v.validate_python('two') is Num.TWO
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum,'):
+        v.validate_python(3)
+
+
+def test_invalid_match_mode():
+    with pytest.raises(SchemaError, match='Invalid match_mode: `foo`, expected `exact` or `fuzzy`'):
+        SchemaValidator(core_schema.enum_schema(Color, match_mode='foo'))
+
+
+def test_fuzzy_ambiguous():
+    class Sep(Enum):
+        A = 'x-y'
+        B = 'X_Y'
+
+    assert SchemaValidator(core_schema.enum_schema(Sep)).validate_python('X_Y') is Sep.B
+    with pytest.raises(SchemaError, match="`x-y` and `X_Y` are different members but can't be told apart"):
+        SchemaValidator(core_schema.enum_schema(Sep, match_mode='fuzzy'))
+
+
+def test_empty_enum():
+    class Empty(Enum):
+        pass
+
+    with pytest.raises(SchemaError, match=r'`test_empty_enum.<locals>.Empty` should have at least one member'):
+        SchemaValidator(core_schema.enum_schema(Empty))
@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -136,6 +136,86 @@ def test_float_multiple_of(py_and_json: PyAndJson, multiple_of, input_value, err
         assert isinstance(output, float)
 
 
+@pytest.mark.parametrize(
+    'multiple_of,input_value,error',
+    [
+        (0.25, 0.5, None),
+        (0.25, -1.75, None),
+        (0.5, 0, None),
+        (2.0, 6, None),
+        (0.1, 0.2, None),
+        (0.1, 0.3, None),
+        (0.1, 1e300, None),
+        (0.1, 0.35, Err('Input should be a multiple of 0.1')),
+        (0.1, 0.30000000000000004, Err('Input should be a multiple of 0.1')),
+        (0.25, 0.3, Err('Input should be a multiple of 0.25')),
+        (7.0, 1e-300, Err('Input should be a multiple of 7')),
+    ],
+    ids=repr,
+)
+def test_float_multiple_of_exact(py_and_json: PyAndJson, multiple_of, input_value, error):
+    v = py_and_json({'type': 'float', 'multiple_of': multiple_of, 'multiple_of_mode': 'exact'})
+    if error:
+        with pytest.raises(ValidationError, match=re.escape(error.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == input_value
+
+
+def test_float_multiple_of_mode_config():
+    v = SchemaValidator(core_schema.float_schema(multiple_of=0.1), {'multiple_of_mode': 'exact'})
+    assert v.validate_python(0.3) == 0.3
+    with pytest.raises(ValidationError, match='Input should be a multiple of 0.1'):
+        v.validate_python(0.30000000000000004)
+
+    # the schema takes precedence over config
+    v = SchemaValidator(
+        core_schema.float_schema(multiple_of=0.1, multiple_of_mode='tolerant'), {'multiple_of_mode': 'exact'}
+    )
+    assert v.validate_python(0.30000000000000004) == 0.30000000000000004
+
+
+def test_float_multiple_of_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid multiple_of_mode: `foobar`, expected `tolerant` or `exact`'):
+        SchemaValidator({'type': 'float', 'multiple_of': 0.1, 'multiple_of_mode': 'foobar'})
+
+
+@pytest.mark.parametrize('input_value', [2**53 + 1, -(2**53) - 1])
+def test_float_number_precision_loss(py_and_json: PyAndJson, input_value):
+    # rounding is the default
+    v = py_and_json(core_schema.float_schema())
+    assert v.validate_test(input_value) == float(input_value)
+
+    v = py_and_json(core_schema.float_schema(number_precision_loss='error'))
+    with pytest.raises(ValidationError, match="got an integer which can't be represented exactly as a float"):
+        v.validate_test(input_value)
+
+    v = py_and_json(core_schema.float_schema(ge=0, number_precision_loss='warn'))
+    if input_value > 0:
+        with pytest.warns(UserWarning, match=f"{input_value} can't be represented exactly as a float"):
+            assert v.validate_test(input_value) == float(input_value)
+
+
+@pytest.mark.parametrize('input_value', [2**53, 2**60, -(2**1000), 2**53 - 1, 1.5, '9007199254740993'])
+def test_float_number_precision_loss_exact(input_value):
+    v = SchemaValidator(core_schema.float_schema(), {'number_precision_loss': 'error'})
+    assert v.validate_python(input_value) == float(input_value)
+
+
+def test_float_json_big_int():
+    v = SchemaValidator(core_schema.float_schema())
+    assert v.validate_python(2**64 + 1) == float(2**64 + 1)
+    with pytest.raises(ValidationError, match=r'Input should be a valid number \[type=float_type'):
+        v.validate_json(str(2**64 + 1))
+
+
+def test_number_precision_loss_invalid():
+    with pytest.raises(
+        SchemaError, match='Invalid number_precision_loss: `foobar`, expected `error`, `warn` or `truncate`'
+    ):
+        SchemaValidator(core_schema.float_schema(number_precision_loss='foobar'))
+
+
 def test_union_float(py_and_json: PyAndJson):
     v = py_and_json(
         {'type': 'union', 'choices': [{'type': 'float', 'strict': True}, {'type': 'float', 'multiple_of': 7}]}
@@ -182,12 +262,14 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[])'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,'
+        'precision_loss:Truncate}),definitions=[])'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[])'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,'
+        'precision_loss:Truncate}),definitions=[])'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -247,8 +247,8 @@ def test_repr():
         'SchemaValidator('
         'title="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
-        'name:"frozenset[any]"'
+        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,unique_items:false,'
+        'deterministic:false,name:"frozenset[any]"'
         '}),definitions=[])'
     )
 
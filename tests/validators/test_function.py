@@ -959,3 +959,69 @@ def test_function_after_doesnt_change_mode() -> None:
     assert v.validate_json(b'"2000-01-01"') == datetime.date(2000, 1, 1)
     with pytest.raises(ValidationError):
         v.validate_python(b'"2000-01-01"')
+
+
+def test_validation_info_state() -> None:
+    def count(value: Any, info: core_schema.ValidationInfo) -> Any:
+        info.state['count'] = info.state.get('count', 0) + 1
+        return value, info.state['count']
+
+    def wrap(value: Any, handler: core_schema.ValidatorFunctionWrapHandler, info: core_schema.ValidationInfo) -> Any:
+        info.state['wrapped'] = True
+        return handler(value)
+
+    item_schema = core_schema.with_info_after_validator_function(count, core_schema.int_schema())
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(item_schema),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_info_wrap_validator_function(wrap, core_schema.list_schema(item_schema))
+                ),
+            }
+        )
+    )
+
+    # the same dict is shared by every function, including those called from the wrap validator's handler
+    assert v.validate_python({'a': 1, 'b': [2, 3]}) == {'a': (1, 1), 'b': [(2, 2), (3, 3)]}
+    # and each call gets a new one
+    assert v.validate_json('{"a": 1, "b": [2]}') == {'a': (1, 1), 'b': [(2, 2)]}
+
+
+def test_validation_info_state_created_in_handler() -> None:
+    def count(value: Any, info: core_schema.ValidationInfo) -> Any:
+        info.state['count'] = info.state.get('count', 0) + 1
+        return value, info.state['count']
+
+    item_schema = core_schema.with_info_after_validator_function(count, core_schema.int_schema())
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.no_info_wrap_validator_function(
+                        lambda value, handler: handler(value), core_schema.list_schema(item_schema)
+                    )
+                ),
+                'b': core_schema.typed_dict_field(item_schema),
+            }
+        )
+    )
+    # the dict is first used within the handler, and is still shared with functions called after the handler
+    assert v.validate_python({'a': [1, 2], 'b': 3}) == {'a': [(1, 1), (2, 2)], 'b': (3, 3)}
+
+
+def test_validation_info_state_dedup() -> None:
+    def unique(value: str, info: core_schema.ValidationInfo) -> str:
+        seen = info.state.setdefault('seen', set())
+        if value in seen:
+            raise ValueError(f'duplicate value {value!r}')
+        seen.add(value)
+        return value
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.with_info_after_validator_function(unique, core_schema.str_schema()))
+    )
+    assert v.validate_python(['a', 'b']) == ['a', 'b']
+    with pytest.raises(ValidationError, match="Value error, duplicate value 'a'"):
+        v.validate_python(['a', 'b', 'a'])
+    assert v.validate_python(['a']) == ['a']
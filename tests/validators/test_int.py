@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -291,6 +291,249 @@ def test_int_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value,
         assert isinstance(output, int)
 
 
+@pytest.mark.parametrize(
+    'bit_width,signed,input_value,expected',
+    [
+        (8, None, 127, 127),
+        (8, None, -128, -128),
+        (8, None, 128, Err('Input should be between -128 and 127 [type=int_out_of_range, input_value=128')),
+        (8, None, -129, Err('Input should be between -128 and 127')),
+        (16, True, '32767', 32767),
+        (16, True, 32768, Err('Input should be between -32768 and 32767')),
+        (16, False, 65535, 65535),
+        (16, False, -1, Err('Input should be between 0 and 65535')),
+        (32, None, 2**31 - 1, 2**31 - 1),
+        (32, None, 2**31, Err('Input should be between -2147483648 and 2147483647')),
+        (64, None, -(2**63), -(2**63)),
+        (64, None, 2**63, Err('Input should be between -9223372036854775808 and 9223372036854775807')),
+        (64, False, 2**64 - 1, 2**64 - 1),
+        (64, False, 2**64, Err('Input should be between 0 and 18446744073709551615')),
+        (64, False, 2**100, Err('Input should be between 0 and 18446744073709551615')),
+    ],
+    ids=repr,
+)
+def test_int_bit_width(py_and_json: PyAndJson, bit_width, signed, input_value, expected):
+    v = py_and_json(core_schema.int_schema(bit_width=bit_width, signed=signed))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        error = exc_info.value.errors(include_url=False)[0]
+        assert error['type'] == 'int_out_of_range'
+        assert set(error['ctx']) == {'min', 'max'}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_bit_width_with_constraints():
+    v = SchemaValidator(core_schema.int_schema(bit_width=8, signed=False, multiple_of=2))
+    assert v.validate_python(254) == 254
+    with pytest.raises(ValidationError, match='Input should be a multiple of 2'):
+        v.validate_python(3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(256)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_out_of_range',
+            'loc': (),
+            'msg': 'Input should be between 0 and 255',
+            'input': 256,
+            'ctx': {'min': 0, 'max': 255},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        ({'type': 'int', 'bit_width': 12}, 'bit_width must be 8, 16, 32 or 64, got 12'),
+        ({'type': 'int', 'signed': False}, 'signed can only be used with bit_width'),
+    ],
+)
+def test_int_bit_width_invalid(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
+
+
+def test_json_big_int_lossless():
+    v = SchemaValidator(core_schema.int_schema())
+    big = 2**200 + 1
+    assert v.validate_json(str(big)) == big
+    assert v.validate_json(f'-{big}') == -big
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int64_schema(), 2**63 - 1, 2**63 - 1),
+        (core_schema.int64_schema(), -(2**63), -(2**63)),
+        (core_schema.int64_schema(), '42', 42),
+        (
+            core_schema.int64_schema(),
+            2**63,
+            Err('Input should be between -9223372036854775808 and 9223372036854775807'),
+        ),
+        (core_schema.int64_schema(), 2**100, Err('[type=int_out_of_range')),
+        (core_schema.uint64_schema(), 2**64 - 1, 2**64 - 1),
+        (core_schema.uint64_schema(), 2**64, Err('Input should be between 0 and 18446744073709551615')),
+        (core_schema.uint64_schema(), -1, Err('Input should be between 0 and 18446744073709551615')),
+        (core_schema.uint64_schema(gt=2**63), 2**64 - 1, 2**64 - 1),
+        (core_schema.uint64_schema(gt=2**63), 2**63, Err('Input should be greater than 9223372036854775808')),
+        (core_schema.int64_schema(multiple_of=3, ge=-3, lt=10), 9, 9),
+        (core_schema.int64_schema(multiple_of=3, ge=-3, lt=10), 10, Err('Input should be a multiple of 3')),
+        (core_schema.int64_schema(multiple_of=3, ge=-3, lt=10), 12, Err('Input should be less than 10')),
+        (core_schema.int64_schema(multiple_of=3, ge=-3, lt=10), -6, Err('Input should be greater than or equal to -3')),
+        (core_schema.int64_schema(le=5), 6, Err('Input should be less than or equal to 5')),
+        (core_schema.int64_schema(), 1.5, Err('Input should be a valid integer, got a number with a fractional part')),
+        (core_schema.int64_schema(), 'x', Err('Input should be a valid integer, unable to parse string as an integer')),
+    ],
+)
+def test_machine_int(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is int
+
+
+def test_machine_int_returns_input():
+    v = SchemaValidator(core_schema.uint64_schema())
+    value = 2**64 - 1
+    assert v.validate_python(value) is value
+
+
+def test_machine_int_precision_loss():
+    v = SchemaValidator(core_schema.int64_schema(number_precision_loss='truncate'))
+    assert v.validate_python(-2.5) == -2
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        (core_schema.uint64_schema(ge=-1), 'ge must fit in uint64, got -1'),
+        (core_schema.int64_schema(le=2**63), 'le must fit in int64, got 9223372036854775808'),
+        (core_schema.int64_schema(multiple_of=0), 'multiple_of must not be 0'),
+        (core_schema.uint64_schema(multiple_of=0), 'multiple_of must not be 0'),
+        (core_schema.int_schema(multiple_of=0), 'multiple_of must not be 0'),
+    ],
+)
+def test_machine_int_invalid_constraint(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
+
+
+@pytest.mark.parametrize('input_value', [1.5, -1.5, Decimal('2.75')])
+def test_int_number_precision_loss(input_value):
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError, match='got a number with a fractional part'):
+        v.validate_python(input_value)
+
+    v = SchemaValidator(core_schema.int_schema(number_precision_loss='truncate'))
+    assert v.validate_python(input_value) == int(input_value)
+
+    v = SchemaValidator(core_schema.int_schema(le=10), {'number_precision_loss': 'warn'})
+    with pytest.warns(UserWarning, match=f'{float(input_value)} has a fractional part, it was truncated to'):
+        assert v.validate_python(input_value) == int(input_value)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('123456789012345678901234567.9'), 123456789012345678901234567),
+        (Decimal('-123456789012345678901234567.9'), -123456789012345678901234567),
+        ('123456789012345678901234567.9', 123456789012345678901234567),
+        ('-2.5', -2),
+    ],
+)
+def test_int_number_precision_loss_exact(input_value, expected):
+    v = SchemaValidator(core_schema.int_schema(number_precision_loss='truncate'))
+    assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize('input_value', ['1e3', 'abc', 'inf', Decimal('NaN')])
+def test_int_number_precision_loss_invalid(input_value):
+    v = SchemaValidator(core_schema.int_schema(number_precision_loss='truncate'))
+    with pytest.raises(ValidationError):
+        v.validate_python(input_value)
+
+
+def test_int_number_precision_loss_json():
+    v = SchemaValidator(core_schema.int_schema(number_precision_loss='truncate'))
+    assert v.validate_json('2.9') == 2
+    assert v.validate_json('-2.9') == -2
+    assert v.validate_json('"2.9"') == 2
+    # strict mode never coerces floats
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_json('2.9', strict=True)
+
+
+@pytest.mark.parametrize(
+    'max_digits,input_value,expected',
+    [
+        (3, 999, 999),
+        (3, -999, -999),
+        (3, 0, 0),
+        (3, 1000, Err('Integer should have no more than 3 digits [type=int_max_digits')),
+        (1, -10, Err('Integer should have no more than 1 digit [type=int_max_digits')),
+        (30, 10**29, 10**29),
+        (30, -(10**30) + 1, -(10**30) + 1),
+        (30, 10**30, Err('Integer should have no more than 30 digits')),
+        (19, 2**63 - 1, 2**63 - 1),
+        (18, 2**63 - 1, Err('Integer should have no more than 18 digits')),
+    ],
+    ids=repr,
+)
+def test_int_max_digits(py_and_json: PyAndJson, max_digits, input_value, expected):
+    v = py_and_json(core_schema.int_schema(max_digits=max_digits))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('123', 123),
+        ('-0000123', -123),
+        ('1_2_3', 123),
+        ('123.0', 123),
+        ('1234', Err('Integer should have no more than 3 digits [type=int_max_digits')),
+        ('9' * 5000, Err('Integer should have no more than 3 digits [type=int_max_digits')),
+        ('12a', Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing')),
+    ],
+)
+def test_int_max_digits_str(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.int_schema(max_digits=3))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_int_max_digits_config():
+    v = SchemaValidator(core_schema.int_schema(), {'int_max_digits': 50})
+    assert v.validate_json('1' * 50) == int('1' * 50)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('1' * 51)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_max_digits',
+            'loc': (),
+            'msg': 'Integer should have no more than 50 digits',
+            'input': int('1' * 51),
+            'ctx': {'max_digits': 50},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.int_schema(max_digits=60), {'int_max_digits': 50})
+    assert v.validate_json('1' * 51) == int('1' * 51)
+
+
 def test_union_int(py_and_json: PyAndJson):
     v = py_and_json({'type': 'union', 'choices': [{'type': 'int', 'strict': True}, {'type': 'int', 'multiple_of': 7}]})
     assert v.validate_test('14') == 14
@@ -334,9 +577,15 @@ def test_union_int_simple(py_and_json: PyAndJson):
 
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
-    assert plain_repr(v) == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[])'
+    assert (
+        plain_repr(v)
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,precision_loss:Error}),definitions=[])'
+    )
     v = SchemaValidator({'type': 'int', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[])'
+    assert (
+        plain_repr(v)
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,precision_loss:Error}),definitions=[])'
+    )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
 
@@ -0,0 +1,127 @@
+import re
+from enum import IntFlag
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class Perm(IntFlag):
+    R = 4
+    W = 2
+    X = 1
+    RW = 6
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (6, Perm.R | Perm.W),
+        (0, Perm(0)),
+        (['R', 'X'], Perm.R | Perm.X),
+        (['RW', 'W'], Perm.R | Perm.W),
+        ([], Perm(0)),
+        (8, Err('Input should only have bits which are members of Perm, unknown bits: 8')),
+        (15, Err('Input should only have bits which are members of Perm, unknown bits: 8')),
+        (['R', 'Q'], Err('Input should be a member name of Perm [type=int_flag_name')),
+        ([1], Err('Input should be a member name of Perm [type=int_flag_name')),
+        (-1, Err('Input should be an integer or a list of Perm member names [type=int_flag_type')),
+        (2**64, Err('Input should be an integer or a list of Perm member names [type=int_flag_type')),
+        ('R', Err('Input should be an integer or a list of Perm member names [type=int_flag_type')),
+        ({'R': 1}, Err('Input should be an integer or a list of Perm member names [type=int_flag_type')),
+    ],
+)
+def test_int_flag(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.int_flag_schema(Perm))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is Perm
+
+
+def test_int_flag_instance():
+    v = SchemaValidator(core_schema.int_flag_schema(Perm))
+    assert v.validate_python(Perm.R) is Perm.R
+    assert v.validate_python((Perm.R, Perm.W)) == Perm.R | Perm.W
+    assert v.validate_python('6') == Perm.R | Perm.W
+    with pytest.raises(ValidationError, match='unknown bits: 8'):
+        v.validate_python(Perm(9))
+
+
+def test_int_flag_strict():
+    v = SchemaValidator(core_schema.int_flag_schema(Perm, strict=True))
+    assert v.validate_python(Perm.X) is Perm.X
+    assert v.validate_json('["X"]') == Perm.X
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of Perm',
+            'input': 1,
+            'ctx': {'class': 'Perm'},
+        }
+    ]
+
+
+def test_int_flag_name_error():
+    v = SchemaValidator(core_schema.int_flag_schema(Perm))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["R", "Q"]')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_flag_name',
+            'loc': (1,),
+            'msg': 'Input should be a member name of Perm',
+            'input': 'Q',
+            'ctx': {'class': 'Perm'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'unknown_bits,input_value,expected',
+    [
+        ('drop', 13, Perm.R | Perm.X),
+        ('drop', Perm(9), Perm.X),
+        ('keep', 13, 13),
+        ('keep', 8, 8),
+    ],
+)
+def test_int_flag_unknown_bits(unknown_bits, input_value, expected):
+    v = SchemaValidator(core_schema.int_flag_schema(Perm, unknown_bits=unknown_bits))
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert type(output) is Perm
+
+
+def test_int_flag_unknown_bits_details():
+    v = SchemaValidator(core_schema.int_flag_schema(Perm))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(24)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_flag_unknown_bits',
+            'loc': (),
+            'msg': 'Input should only have bits which are members of Perm, unknown bits: 24',
+            'input': 24,
+            'ctx': {'class': 'Perm', 'unknown_bits': 24},
+        }
+    ]
+
+
+def test_int_flag_invalid_schema():
+    with pytest.raises(SchemaError, match='Invalid unknown_bits: `ignore`, expected `error`, `drop` or `keep`'):
+        SchemaValidator(core_schema.int_flag_schema(Perm, unknown_bits='ignore'))
+
+    class Negative(IntFlag):
+        A = -1
+
+    with pytest.raises(SchemaError, match='int-flag member `A` must be a non-negative integer below 2\\*\\*64'):
+        SchemaValidator(core_schema.int_flag_schema(Negative))
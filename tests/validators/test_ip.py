@@ -0,0 +1,147 @@
+import re
+from ipaddress import IPv4Address, IPv4Network, IPv6Address, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.ip_v4_schema(), '192.168.0.1', IPv4Address('192.168.0.1')),
+        (core_schema.ip_v4_schema(), 3232235521, IPv4Address('192.168.0.1')),
+        (core_schema.ip_v4_schema(), '::1', Err('Input should be a valid IPv4 address')),
+        (core_schema.ip_v4_schema(), '192.168.0.256', Err('Input should be a valid IPv4 address')),
+        (core_schema.ip_v4_schema(), 2**32, Err('Input should be a valid IPv4 address')),
+        (core_schema.ip_v4_schema(), -1, Err('Input should be a valid IPv4 address')),
+        (core_schema.ip_v6_schema(), '2001:DB8::1', IPv6Address('2001:db8::1')),
+        (core_schema.ip_v6_schema(), 'fe80::1%eth0', IPv6Address('fe80::1%eth0')),
+        (core_schema.ip_v6_schema(), 1, IPv6Address('::1')),
+        (core_schema.ip_v6_schema(), '192.168.0.1', Err('Input should be a valid IPv6 address')),
+        (core_schema.ip_v6_schema(), 'fe80::1%', Err('Input should be a valid IPv6 address')),
+        (core_schema.ip_any_schema(), '192.168.0.1', IPv4Address('192.168.0.1')),
+        (core_schema.ip_any_schema(), '::ffff:192.168.0.1', IPv6Address('::ffff:192.168.0.1')),
+        (core_schema.ip_any_schema(), 1, IPv4Address('0.0.0.1')),
+        (core_schema.ip_any_schema(), 2**32, IPv6Address('::1:0:0')),
+        (core_schema.ip_any_schema(), 'foobar', Err('Input should be a valid IPv4 or IPv6 address')),
+        (core_schema.ip_any_schema(), [], Err('Input should be a valid IPv4 or IPv6 address')),
+    ],
+)
+def test_ip_address(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.ip_v4_schema(), b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        (core_schema.ip_v4_schema(), IPv4Address('10.0.0.1'), IPv4Address('10.0.0.1')),
+        (core_schema.ip_v4_schema(), IPv6Address('::1'), Err('Input should be a valid IPv4 address')),
+        (core_schema.ip_v4_schema(), True, Err('Input should be a valid IPv4 address')),
+        (core_schema.ip_v6_schema(), b'\x00' * 15 + b'\x01', IPv6Address('::1')),
+        (core_schema.ip_v6_schema(), b'\xc0\xa8\x00\x01', Err('Input should be a valid IPv6 address')),
+        (core_schema.ip_any_schema(), b'\x00' * 15 + b'\x01', IPv6Address('::1')),
+        (core_schema.ip_any_schema(), IPv6Address('::1'), IPv6Address('::1')),
+    ],
+)
+def test_ip_address_python(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_ip_address_instance_returned():
+    v = SchemaValidator(core_schema.ip_any_schema())
+    addr = IPv4Address('10.0.0.1')
+    assert v.validate_python(addr) is addr
+
+
+def test_ip_address_strict():
+    v = SchemaValidator(core_schema.ip_any_schema(strict=True))
+    assert v.validate_python(IPv4Address('10.0.0.1')) == IPv4Address('10.0.0.1')
+    assert v.validate_json('"10.0.0.1"') == IPv4Address('10.0.0.1')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('10.0.0.1')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of IPv4Address | IPv6Address',
+            'input': '10.0.0.1',
+            'ctx': {'class': 'IPv4Address | IPv6Address'},
+        }
+    ]
+
+
+def test_ip_address_error_details():
+    v = SchemaValidator(core_schema.ip_v6_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foobar')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'ip_address_parsing',
+            'loc': (),
+            'msg': 'Input should be a valid IPv6 address',
+            'input': 'foobar',
+            'ctx': {'expected': 'IPv6'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.ip_network_schema(), '192.168.0.0/24', IPv4Network('192.168.0.0/24')),
+        (core_schema.ip_network_schema(), '192.168.0.0/255.255.255.0', IPv4Network('192.168.0.0/24')),
+        (core_schema.ip_network_schema(), '192.168.0.1', IPv4Network('192.168.0.1/32')),
+        (core_schema.ip_network_schema(), '0.0.0.0/0', IPv4Network('0.0.0.0/0')),
+        (core_schema.ip_network_schema(), '2001:DB8::/32', IPv6Network('2001:db8::/32')),
+        (core_schema.ip_network_schema(), 1, IPv4Network('0.0.0.1/32')),
+        (core_schema.ip_network_schema(version=6), 1, IPv6Network('::1/128')),
+        (core_schema.ip_network_schema(version=6), '192.168.0.0/24', Err('valid IPv6 network, invalid address')),
+        (core_schema.ip_network_schema(), '192.168.0.1/24', Err('valid IPv4 or IPv6 network, host bits set')),
+        (core_schema.ip_network_schema(allow_host_bits=True), '192.168.0.1/24', IPv4Network('192.168.0.0/24')),
+        (core_schema.ip_network_schema(allow_host_bits=True), '2001:db8::1/32', IPv6Network('2001:db8::/32')),
+        (core_schema.ip_network_schema(), '192.168.0.0/33', Err('invalid prefix')),
+        (core_schema.ip_network_schema(), '192.168.0.0/', Err('invalid prefix')),
+        (core_schema.ip_network_schema(), '192.168.0.0/255.0.255.0', Err('invalid prefix')),
+        (core_schema.ip_network_schema(), '2001:db8::/ffff::', Err('invalid prefix')),
+        (core_schema.ip_network_schema(), 'foobar/24', Err('invalid address')),
+    ],
+)
+def test_ip_network(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+def test_ip_network_strict():
+    v = SchemaValidator(core_schema.ip_network_schema(version=4, strict=True))
+    assert v.validate_python(IPv4Network('10.0.0.0/8')) == IPv4Network('10.0.0.0/8')
+    with pytest.raises(ValidationError, match='Input should be an instance of IPv4Network'):
+        v.validate_python(IPv6Network('::/0'))
+    with pytest.raises(ValidationError, match='Input should be an instance of IPv4Network'):
+        v.validate_python('10.0.0.0/8')
+
+
+def test_ip_network_invalid_version():
+    with pytest.raises(SchemaError, match='Invalid IP version: 5, expected 4 or 6'):
+        SchemaValidator(core_schema.ip_network_schema(version=5))
@@ -1,3 +1,4 @@
+import json
 import re
 
 import pytest
@@ -159,3 +160,105 @@ def test_any_schema_no_schema():
     assert 'validator:None' in plain_repr(v)
     v = SchemaValidator(core_schema.json_schema(core_schema.int_schema()))
     assert 'validator:Some(' in plain_repr(v)
+
+
+@pytest.mark.parametrize('input_value', ['[1, 2, 3]', b'[1, 2, 3]', '[1,2]'])
+def test_max_length(input_value):
+    v = SchemaValidator(core_schema.json_schema(max_length=6))
+    if len(input_value) <= 6:
+        assert v.validate_python(input_value) == [1, 2]
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'json_too_long',
+                'loc': (),
+                'msg': 'JSON input should have at most 6 bytes',
+                'input': input_value,
+                'ctx': {'max_length': 6},
+            }
+        ]
+
+
+def test_max_length_bytes():
+    # the limit is in bytes, not characters
+    v = SchemaValidator(core_schema.json_schema(max_length=6))
+    assert v.validate_python('"éé"') == 'éé'
+    with pytest.raises(ValidationError, match='JSON input should have at most 6 bytes'):
+        v.validate_python('"ééé"')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1', 1),
+        ('[[1], {"a": 1}]', [[1], {'a': 1}]),
+        ('[[[1]]]', Err('', [(0, 0)])),
+        ('[1, [2, [3]]]', Err('', [(1, 1)])),
+        ('{"a": {"b": {"c": 1}}}', Err('', [('a', 'b')])),
+        ('{"a": 1, "b": [{"c": []}]}', Err('', [('b', 0)])),
+    ],
+)
+def test_max_depth(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.json_schema(max_depth=2))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match='JSON input should be nested at most 2 levels deep') as exc_info:
+            v.validate_test(input_value)
+        assert [e['loc'] for e in exc_info.value.errors()] == expected.errors
+        assert exc_info.value.errors()[0]['ctx'] == {'max_depth': 2}
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_max_depth_large():
+    v = SchemaValidator(core_schema.json_schema(max_depth=4))
+    value = {'items': [{'id': i, 'tags': ['a', 'b']} for i in range(1000)]}
+    assert v.validate_python(json.dumps(value)) == value
+    value['items'][-1]['tags'] = [[1]]
+    with pytest.raises(ValidationError, match='JSON input should be nested at most 4 levels deep') as exc_info:
+        v.validate_python(json.dumps(value))
+    assert exc_info.value.errors()[0]['loc'] == ('items', 999, 'tags', 0)
+
+
+def test_max_depth_nested_loc():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'payload': core_schema.typed_dict_field(core_schema.json_schema(core_schema.any_schema(), max_depth=1))}
+        )
+    )
+    assert v.validate_python({'payload': '{"a": 1}'}) == {'payload': {'a': 1}}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'payload': '{"a": [1]}'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_too_deep',
+            'loc': ('payload', 'a'),
+            'msg': 'JSON input should be nested at most 1 level deep',
+            'input': '{"a": [1]}',
+            'ctx': {'max_depth': 1},
+        }
+    ]
+
+
+@pytest.mark.parametrize('max_depth', [None, 10])
+@pytest.mark.parametrize(
+    'input_value,loc',
+    [
+        ('{1: 2}', ()),
+        ('[1, 2', (2,)),
+        ('[1, 2,]', (2,)),
+        ('{"a": [1, 2, tru]}', ('a', 2)),
+        ('{"a": {"b": 1,}}', ('a', 'b')),
+        ('[{"a": 1} 2]', (1,)),
+        ('[1] x', ()),
+    ],
+)
+def test_invalid_json_loc(max_depth, input_value, loc):
+    v = SchemaValidator(core_schema.json_schema(core_schema.list_schema(), max_depth=max_depth))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    errors = exc_info.value.errors(include_url=False)
+    assert len(errors) == 1
+    assert errors[0]['type'] == 'json_invalid'
+    assert errors[0]['loc'] == loc
@@ -7,7 +7,7 @@ from typing import Any, Dict, Iterator, List, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -502,3 +502,119 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.any_schema(), core_schema.int_schema()])
+def test_list_unique_items(py_and_json: PyAndJson, items_schema):
+    v = py_and_json(core_schema.list_schema(items_schema, unique_items=True))
+    assert v.validate_test([1, 2, 3]) == [1, 2, 3]
+    assert v.validate_test([]) == []
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 2, 3, 2, 1])
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_item',
+            'loc': (3,),
+            'msg': 'List items should be unique',
+            'input': [1, 2, 3, 2, 1],
+            'ctx': {'field_type': 'List'},
+        }
+    ]
+
+
+def test_list_unique_items_after_validation():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), unique_items=True))
+    with pytest.raises(ValidationError, match=r'List items should be unique \[type=duplicate_item'):
+        v.validate_python([1, '1'])
+
+
+def test_list_unique_items_unhashable():
+    v = SchemaValidator(core_schema.list_schema(unique_items=True))
+    assert v.validate_python([[1], [2], {'a': 1}, 1]) == [[1], [2], {'a': 1}, 1]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1], 1, {'a': 1}, [1]])
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (3,)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 1}, 1, {'a': 1}])
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (2,)
+
+
+def test_list_unique_items_unhashable_equal_to_hashable():
+    class Unhashable:
+        __hash__ = None
+
+        def __eq__(self, other):
+            return other == 1
+
+    v = SchemaValidator(core_schema.list_schema(unique_items=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, Unhashable()])
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (1,)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([Unhashable(), 2, 1])
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == (2,)
+
+
+def test_list_unique_items_eq_error():
+    class BadEq:
+        __hash__ = None
+
+        def __eq__(self, other):
+            raise RuntimeError('eq failed')
+
+    v = SchemaValidator(core_schema.list_schema(unique_items=True))
+    with pytest.raises(RuntimeError, match='eq failed'):
+        v.validate_python([1, BadEq()])
+def test_output_type_deque():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), output_type='deque'))
+    output = v.validate_python([1, '2'])
+    assert type(output) is deque
+    assert output == deque([1, 2])
+    assert output.maxlen is None
+    assert v.validate_json('[1, "2"]') == deque([1, 2])
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), output_type='deque', deque_maxlen=2))
+    output = v.validate_python((1, 2, 3))
+    assert output == deque([2, 3])
+    assert output.maxlen == 2
+    assert v.validate_json('[1, 2, 3]') == deque([2, 3], maxlen=2)
+
+
+class MyList(list):
+    pass
+
+
+class MyDeque(deque):
+    pass
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.int_schema()])
+@pytest.mark.parametrize(
+    'input_value,expected_type,expected_maxlen',
+    [
+        ([1, 2], list, None),
+        (MyList([1, 2]), MyList, None),
+        (deque([1, 2], maxlen=5), deque, 5),
+        (MyDeque([1, 2]), MyDeque, None),
+        ((1, 2), list, None),
+    ],
+)
+def test_output_type_input(items_schema, input_value, expected_type, expected_maxlen):
+    v = SchemaValidator(core_schema.list_schema(items_schema, output_type='input'))
+    output = v.validate_python(input_value)
+    assert type(output) is expected_type
+    assert list(output) == [1, 2]
+    assert output is not input_value
+    if expected_maxlen is not None:
+        assert output.maxlen == expected_maxlen
+    assert type(v.validate_json('[1, 2]')) is list
+
+
+def test_deque_maxlen_requires_deque():
+    with pytest.raises(SchemaError, match="'deque_maxlen' requires `output_type = 'deque'`"):
+        SchemaValidator(core_schema.list_schema(output_type='input', deque_maxlen=2))
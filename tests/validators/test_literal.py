@@ -378,3 +378,36 @@ def test_mix_str_enum_with_str(reverse: Callable[[List[Any]], List[Any]], err: A
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('bar_val')
     assert exc_info.value.errors(include_url=False) == err
+
+
+@pytest.mark.parametrize(
+    'expected,input_value,output',
+    [
+        ('cat', 'cat', 'cat'),
+        ('cat', 'dog', Err("Input should be 'cat' [type=literal_error, input_value='dog', input_type=str]")),
+        (1, 1, 1),
+        (1, '1', Err('Input should be 1 [type=literal_error')),
+        (None, None, None),
+        (True, True, True),
+    ],
+)
+def test_const(py_and_json: PyAndJson, expected, input_value, output):
+    v = py_and_json(core_schema.const_schema(expected))
+    if isinstance(output, Err):
+        with pytest.raises(ValidationError, match=re.escape(output.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == output
+
+
+def test_const_enum():
+    class Foo(str, Enum):
+        foo = 'foo_val'
+
+    v = SchemaValidator(core_schema.const_schema(Foo.foo))
+    assert v.validate_python(Foo.foo) is Foo.foo
+    assert v.validate_python('foo_val') is Foo.foo
+    assert v.validate_json('"foo_val"') is Foo.foo
+    with pytest.raises(ValidationError, match=re.escape("Input should be <Foo.foo: 'foo_val'> [type=literal_error")):
+        v.validate_python('bar_val')
+    assert plain_repr(v).startswith("SchemaValidator(title=\"const[<Foo.foo:'foo_val'>]\"")
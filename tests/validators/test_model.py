@@ -1156,6 +1156,188 @@ def test_validate_assignment_model_validator_function(function_schema: Any, call
     assert calls == [call1, call2]
 
 
+def test_revalidate_fields():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    order_calls: List[Any] = []
+
+    def check_order(values_and_fields_set: Any) -> Any:
+        values = values_and_fields_set[0]
+        order_calls.append(dict(values))
+        if values['start'] > values['end']:
+            raise ValueError('start must not be after end')
+        return values_and_fields_set
+
+    name_calls: List[Any] = []
+
+    def name_validator(v: Any) -> Any:
+        name_calls.append(v)
+        return v
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.no_info_after_validator_function(
+                check_order,
+                core_schema.model_fields_schema(
+                    {
+                        'start': core_schema.model_field(core_schema.int_schema()),
+                        'end': core_schema.model_field(core_schema.int_schema()),
+                        'name': core_schema.model_field(
+                            core_schema.no_info_after_validator_function(name_validator, core_schema.str_schema())
+                        ),
+                    }
+                ),
+            ),
+        )
+    )
+    m = v.validate_python({'start': 1, 'end': 5, 'name': 'x'})
+    assert name_calls == ['x']
+    m.__pydantic_fields_set__ = {'start'}
+    order_calls.clear()
+
+    m.start = '2'
+    m.end = 6
+    v.revalidate_fields(m, ['start', 'end'])
+    assert m.__dict__ == {'start': 2, 'end': 6, 'name': 'x'}
+    # fields which aren't named aren't validated again, the model validator runs once with all the values
+    assert name_calls == ['x']
+    assert order_calls == [{'start': 2, 'end': 6, 'name': 'x'}]
+    assert m.__pydantic_fields_set__ == {'start'}
+
+    m.start = 'wrong'
+    m.end = 'also wrong'
+    with pytest.raises(ValidationError) as exc_info:
+        v.revalidate_fields(m, ['start', 'end'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('start',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('end',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'also wrong',
+        },
+    ]
+
+    m.start = 3
+    m.end = 1
+    with pytest.raises(ValidationError, match='start must not be after end'):
+        v.revalidate_fields(m, ['end'])
+
+    with pytest.raises(AttributeError):
+        v.revalidate_fields(m, ['missing'])
+
+
+def test_revalidate_fields_frozen():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.list_schema(core_schema.int_schema()), frozen=True),
+                    'b': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+            frozen=True,
+        )
+    )
+    m = v.validate_python({'a': [1], 'b': 2})
+    m.a.append('2')
+    v.revalidate_fields(m, ['a', 'b'])
+    assert m.__dict__ == {'a': [1, 2], 'b': 2}
+
+    with pytest.raises(ValidationError, match='Instance is frozen'):
+        v.validate_assignment(m, 'b', 3)
+
+
+def test_validate_assignment_batch():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+                    'c': core_schema.model_field(core_schema.with_default_schema(core_schema.str_schema(), default='')),
+                }
+            ),
+        )
+    )
+    m = v.validate_python({'a': 1})
+    assert m.__pydantic_fields_set__ == {'a'}
+
+    assert v.validate_assignment_batch(m, {'a': '2', 'b': '3'}) is m
+    assert m.__dict__ == {'a': 2, 'b': 3, 'c': ''}
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment_batch(m, {'a': 4, 'b': 'wrong', 'c': 'x'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+    # none of the assignments were applied
+    assert m.__dict__ == {'a': 2, 'b': 3, 'c': ''}
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+
+def test_validate_assignment_batch_model_validator():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    calls: List[Any] = []
+
+    def check_order(m: Model) -> Model:
+        calls.append(dict(m.__dict__))
+        if m.start > m.end:
+            raise ValueError('start must not be after end')
+        return m
+
+    v = SchemaValidator(
+        core_schema.no_info_after_validator_function(
+            check_order,
+            core_schema.model_schema(
+                Model,
+                core_schema.model_fields_schema(
+                    {
+                        'start': core_schema.model_field(core_schema.int_schema()),
+                        'end': core_schema.model_field(core_schema.int_schema()),
+                    }
+                ),
+            ),
+        )
+    )
+    m = v.validate_python({'start': 1, 'end': 2})
+    calls.clear()
+
+    # assigning the fields one at a time would fail on the first assignment
+    v.validate_assignment_batch(m, {'start': 5, 'end': 6})
+    assert m.__dict__ == {'start': 5, 'end': 6}
+    assert calls == [{'start': 5, 'end': 6}]
+
+    with pytest.raises(ValidationError, match='start must not be after end'):
+        v.validate_assignment_batch(m, {'start': 7, 'end': 3})
+    # the model validator failed after the values were set, they're restored
+    assert m.__dict__ == {'start': 5, 'end': 6}
+    assert m.__pydantic_fields_set__ == {'start', 'end'}
+
+
 def test_model_error():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -0,0 +1,205 @@
+import re
+from datetime import date
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+def range_schema(*constraints, field_schema=None):
+    field_schema = field_schema or core_schema.int_schema()
+    return core_schema.model_constraint_schema(
+        core_schema.typed_dict_schema(
+            {
+                'start': core_schema.typed_dict_field(field_schema),
+                'end': core_schema.typed_dict_field(field_schema),
+            }
+        ),
+        list(constraints),
+    )
+
+
+@pytest.mark.parametrize(
+    'op,start,end,valid',
+    [
+        ('lt', 1, 2, True),
+        ('lt', 2, 2, False),
+        ('le', 2, 2, True),
+        ('le', 3, 2, False),
+        ('gt', 3, 2, True),
+        ('gt', 2, 2, False),
+        ('ge', 2, 2, True),
+        ('ge', 1, 2, False),
+        ('eq', 2, 2, True),
+        ('eq', 1, 2, False),
+        ('ne', 1, 2, True),
+        ('ne', 2, 2, False),
+    ],
+)
+def test_ops(op, start, end, valid):
+    v = SchemaValidator(range_schema(core_schema.model_constraint('start', op, 'end')))
+    if valid:
+        assert v.validate_python({'start': start, 'end': end}) == {'start': start, 'end': end}
+    else:
+        with pytest.raises(ValidationError, match=r'start should be .+ end \[type=model_constraint,'):
+            v.validate_python({'start': start, 'end': end})
+
+
+def test_error_details():
+    v = SchemaValidator(range_schema(core_schema.model_constraint('start', 'le', 'end')))
+    assert v.validate_json('{"start": 1, "end": "2"}') == {'start': 1, 'end': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 3, 'end': '2'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'model_constraint',
+            'loc': (),
+            'msg': 'start should be less than or equal to end',
+            'input': {'start': 3, 'end': '2'},
+            'ctx': {'left': 'start', 'op': 'le', 'right': 'end'},
+        }
+    ]
+
+
+@pytest.mark.parametrize('loc,expected_loc', [('end', ('end',)), (['end', 0], ('end', 0)), ([], ())])
+def test_loc(loc, expected_loc):
+    v = SchemaValidator(range_schema(core_schema.model_constraint('start', 'lt', 'end', loc=loc)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 2, 'end': 1})
+    assert [e['loc'] for e in exc_info.value.errors()] == [expected_loc]
+
+
+def test_nested_loc():
+    v = SchemaValidator(
+        core_schema.list_schema(range_schema(core_schema.model_constraint('start', 'lt', 'end', loc='end')))
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'start': 1, 'end': 2}, {'start': 2, 'end': 1}])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1, 'end')]
+
+
+def test_multiple_constraints():
+    v = SchemaValidator(
+        range_schema(
+            core_schema.model_constraint('start', 'le', 'end'),
+            core_schema.model_constraint('start', 'ne', 'end', loc='start'),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 3, 'end': 3})
+    assert [(e['loc'], e['msg']) for e in exc_info.value.errors()] == [(('start',), 'start should be not equal to end')]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 4, 'end': 3})
+    assert [e['ctx']['op'] for e in exc_info.value.errors()] == ['le']
+
+
+def test_dates():
+    v = SchemaValidator(
+        range_schema(core_schema.model_constraint('start', 'le', 'end'), field_schema=core_schema.date_schema())
+    )
+    assert v.validate_python({'start': '2024-01-01', 'end': '2024-01-02'}) == {
+        'start': date(2024, 1, 1),
+        'end': date(2024, 1, 2),
+    }
+    with pytest.raises(ValidationError, match='start should be less than or equal to end'):
+        v.validate_python({'start': '2024-01-03', 'end': '2024-01-02'})
+
+
+def test_missing_or_none_skipped():
+    v = SchemaValidator(
+        core_schema.model_constraint_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'start': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+                    'end': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+                }
+            ),
+            [core_schema.model_constraint('start', 'le', 'end')],
+        )
+    )
+    assert v.validate_python({'end': 1}) == {'end': 1}
+    assert v.validate_python({'start': 2, 'end': None}) == {'start': 2, 'end': None}
+    with pytest.raises(ValidationError, match=r'\[type=model_constraint,'):
+        v.validate_python({'start': 2, 'end': 1})
+
+
+def test_field_errors_first():
+    v = SchemaValidator(range_schema(core_schema.model_constraint('start', 'le', 'end')))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': 3, 'end': 'x'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('end',))]
+
+
+def test_incomparable():
+    v = SchemaValidator(
+        range_schema(core_schema.model_constraint('start', 'lt', 'end'), field_schema=core_schema.any_schema())
+    )
+    with pytest.raises(ValidationError, match=r'start should be less than end \[type=model_constraint,'):
+        v.validate_python({'start': 1, 'end': 'x'})
+
+
+def test_naive_and_aware_datetimes():
+    v = SchemaValidator(
+        range_schema(core_schema.model_constraint('start', 'le', 'end'), field_schema=core_schema.datetime_schema())
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'start': '2024-01-01T00:00', 'end': '2024-01-02T00:00Z'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'model_constraint',
+            'loc': (),
+            'msg': 'start should be less than or equal to end',
+            'input': {'start': '2024-01-01T00:00', 'end': '2024-01-02T00:00Z'},
+            'ctx': {'left': 'start', 'op': 'le', 'right': 'end'},
+        }
+    ]
+
+
+class MyModel:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture
+def model_validator():
+    return SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_constraint_schema(
+                core_schema.model_fields_schema(
+                    {
+                        'start': core_schema.model_field(core_schema.int_schema()),
+                        'end': core_schema.model_field(core_schema.int_schema()),
+                    }
+                ),
+                [core_schema.model_constraint('start', 'le', 'end', loc='end')],
+            ),
+        )
+    )
+
+
+def test_model(model_validator):
+    m = model_validator.validate_python({'start': 1, 'end': 2})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'start': 1, 'end': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        model_validator.validate_python({'start': 3, 'end': 2})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('model_constraint', ('end',))]
+
+
+def test_model_validate_assignment(model_validator):
+    m = model_validator.validate_python({'start': 1, 'end': 2})
+    model_validator.validate_assignment(m, 'end', 5)
+    assert m.__dict__ == {'start': 1, 'end': 5}
+    with pytest.raises(ValidationError, match=re.escape('start should be less than or equal to end')):
+        model_validator.validate_assignment(m, 'start', 6)
+    assert m.__dict__ == {'start': 1, 'end': 5}
+
+
+def test_invalid_op():
+    with pytest.raises(SchemaError, match='Invalid model constraint op: `lte`, expected `lt`, `le`, `gt`, `ge`'):
+        SchemaValidator(range_schema({'left': 'start', 'op': 'lte', 'right': 'end'}))
+
+
+def test_serialization():
+    s = SchemaSerializer(range_schema(core_schema.model_constraint('start', 'le', 'end')))
+    assert s.to_json({'start': 1, 'end': 2}) == b'{"start":1,"end":2}'
@@ -2,13 +2,21 @@ import math
 import re
 import sys
 from dataclasses import dataclass
-from datetime import datetime
+from datetime import date, datetime
 from typing import Any, Dict, List, Mapping, Union
 
 import pytest
 from dirty_equals import FunctionCheck, HasRepr, IsStr
 
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from pydantic_core import (
+    UNSET,
+    CoreConfig,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import Err, PyAndJson
 
@@ -216,6 +224,132 @@ def test_allow_extra_invalid():
         )
 
 
+@pytest.mark.parametrize(
+    'key,expected',
+    [
+        ('fooBar', 'foo_bar'),
+        ('FooBar', 'foo_bar'),
+        ('foo-bar', 'foo_bar'),
+        ('foo_bar', 'foo_bar'),
+        ('HTTPServer', 'http_server'),
+        ('userID2', 'user_id2'),
+        ('field2Name', 'field2_name'),
+    ],
+)
+def test_extras_key_transform_snake_case(key, expected):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_key_transform='snake_case',
+        )
+    )
+    assert v.validate_python({'field_a': 'test', key: 1}) == ({'field_a': 'test'}, {expected: 1}, {'field_a', expected})
+
+
+def test_extras_key_transform_function():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+            extras_key_transform=str.upper,
+        )
+    )
+    expected = ({'field_a': 'test'}, {'OTHER': 1}, {'field_a', 'OTHER'})
+    assert v.validate_python({'field_a': 'test', 'other': '1'}) == expected
+    assert v.validate_json('{"field_a": "test", "other": 1}') == expected
+
+    # errors are still reported at the original key
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test', 'other': 'x'})
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('other',)
+
+
+def test_extras_key_transform_collision():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_key_transform='snake_case',
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test', 'fieldA': 'x', 'otherKey': 1, 'other_key': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'extra_key_collision',
+            'loc': ('fieldA',),
+            'msg': "Extra key 'field_a' should not collide with a field or another extra key",
+            'input': 'x',
+            'ctx': {'key': 'field_a'},
+        },
+        {
+            'type': 'extra_key_collision',
+            'loc': ('other_key',),
+            'msg': "Extra key 'other_key' should not collide with a field or another extra key",
+            'input': 2,
+            'ctx': {'key': 'other_key'},
+        },
+    ]
+
+
+def test_extras_key_transform_invalid():
+    with pytest.raises(SchemaError, match='extras_key_transform can only be used if extra_behavior=allow'):
+        SchemaValidator(core_schema.model_fields_schema({}, extras_key_transform='snake_case'))
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema({}, extra_behavior='allow', extras_key_transform=lambda k: None)
+    )
+    with pytest.raises(TypeError):
+        v.validate_python({'other': 1})
+
+
+def test_extras_keys_schema():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_keys_schema=core_schema.str_schema(pattern='^[a-zA-Z]+$', to_lower=True),
+        )
+    )
+    expected = ({'field_a': 'test'}, {'other': 1}, {'field_a', 'other'})
+    assert v.validate_python({'field_a': 'test', 'Other': 1}) == expected
+    assert v.validate_json('{"field_a": "test", "Other": 1}') == expected
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test', 'other_1': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_pattern_mismatch',
+            'loc': ('other_1', '[key]'),
+            'msg': "String should match pattern '^[a-zA-Z]+$'",
+            'input': 'other_1',
+            'ctx': {'pattern': '^[a-zA-Z]+$'},
+        }
+    ]
+
+
+def test_extras_keys_schema_not_str():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {},
+            extra_behavior='allow',
+            extras_keys_schema=core_schema.no_info_plain_validator_function(len),
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'other': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': ('other', '[key]'), 'msg': 'Input should be a valid string', 'input': 5}
+    ]
+
+
+def test_extras_keys_schema_invalid():
+    with pytest.raises(SchemaError, match='extras_keys_schema can only be used if extra_behavior=allow'):
+        SchemaValidator(core_schema.model_fields_schema({}, extras_keys_schema=core_schema.str_schema()))
+
+
 def test_allow_extra_wrong():
     with pytest.raises(SchemaError, match='Invalid extra_behavior: `wrong`'):
         SchemaValidator({'type': 'model-fields', 'fields': {}}, {'extra_fields_behavior': 'wrong'})
@@ -1016,6 +1150,41 @@ def test_from_attributes_by_name():
     assert v.validate_python(Cls(a=1)) == ({'a': 1}, None, {'a'})
 
 
+def test_from_attributes_included_types():
+    schema = {
+        'type': 'model-fields',
+        'fields': {'year': {'type': 'model-field', 'schema': {'type': 'int'}}},
+        'from_attributes': True,
+    }
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError, match=r'\[type=model_attributes_type,'):
+        v.validate_python(date(2023, 1, 1))
+
+    v = SchemaValidator(schema, {'from_attributes_included_types': [date]})
+    assert v.validate_python(date(2023, 1, 1)) == ({'year': 2023}, None, {'year'})
+    assert v.validate_python(datetime(2022, 1, 1)) == ({'year': 2022}, None, {'year'})
+    assert v.validate_python(Cls(year=2021)) == ({'year': 2021}, None, {'year'})
+
+
+def test_from_attributes_excluded_modules():
+    schema = {
+        'type': 'model-fields',
+        'fields': {'a': {'type': 'model-field', 'schema': {'type': 'int'}}},
+        'from_attributes': True,
+    }
+    v = SchemaValidator(schema, {'from_attributes_excluded_modules': [__name__]})
+    for _ in range(2):
+        with pytest.raises(ValidationError, match=r'\[type=model_attributes_type,'):
+            v.validate_python(Cls(a=1))
+    # replaces the default list, so `builtins` types are now read from attributes
+    assert v.validate_python(type('Testing', (), {'a': 1})) == ({'a': 1}, None, {'a'})
+
+    v = SchemaValidator(
+        schema, {'from_attributes_excluded_modules': [__name__], 'from_attributes_included_types': [Cls]}
+    )
+    assert v.validate_python(Cls(a=1)) == ({'a': 1}, None, {'a'})
+
+
 def test_from_attributes_override_true():
     v = SchemaValidator(
         {
@@ -1781,3 +1950,194 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+def test_pairs_input():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.str_schema()),
+            },
+            extra_behavior='allow',
+            from_pairs=True,
+        )
+    )
+    expected = ({'a': 1, 'b': 'x'}, {'c': 2}, {'a', 'b', 'c'})
+    assert v.validate_python({'a': '1', 'b': 'x', 'c': 2}.items()) == expected
+    assert v.validate_python((p for p in [('a', '1'), ('b', 'x'), ('c', 2)])) == expected
+    assert v.validate_python([('b', 'x'), ('c', 1), ('a', '1'), ('c', 2)]) == expected
+
+    with pytest.raises(ValidationError, match=re.escape('[type=model_type,')):
+        v.validate_python({'a': '1', 'b': 'x'}.items(), strict=True)
+
+
+def test_pairs_input_requires_from_pairs():
+    v = SchemaValidator(core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}))
+    with pytest.raises(ValidationError, match=re.escape('[type=model_type,')):
+        v.validate_python({'a': 1}.items())
+
+
+def test_pairs_input_flatten():
+    inner = core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())})
+    with pytest.raises(SchemaError, match='Field "inner": flatten can\'t be used with from_pairs'):
+        SchemaValidator(
+            core_schema.model_fields_schema({'inner': core_schema.model_field(inner, flatten=True)}, from_pairs=True)
+        )
+
+
+def test_alias_generator():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'field_a': core_schema.model_field(core_schema.int_schema()),
+                'field_b': core_schema.model_field(core_schema.int_schema(), validation_alias='b'),
+            }
+        ),
+        core_schema.CoreConfig(alias_generator=str.upper, populate_by_name=True),
+    )
+    expected = ({'field_a': 1, 'field_b': 2}, None, {'field_a', 'field_b'})
+    assert v.validate_python({'FIELD_A': 1, 'b': 2}) == expected
+    assert v.validate_python({'field_a': 1, 'b': 2}) == expected
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'FIELD_B': 2})
+    assert [e['loc'] for e in exc_info.value.errors(include_url=False)] == [('FIELD_A',), ('b',)]
+
+
+def test_alias_generator_invalid():
+    with pytest.raises(SchemaError, match='alias_generator must return a str for field "field_a"'):
+        SchemaValidator(
+            core_schema.model_fields_schema({'field_a': core_schema.model_field(core_schema.int_schema())}),
+            core_schema.CoreConfig(alias_generator=lambda name: None),
+        )
+
+
+def test_flatten():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'address': core_schema.model_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'city': core_schema.typed_dict_field(core_schema.str_schema()),
+                            'zip': core_schema.typed_dict_field(core_schema.str_schema()),
+                        }
+                    ),
+                    flatten=True,
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'name': 'sam', 'city': 'London', 'zip': 'N1'}) == (
+        {'name': 'sam', 'address': {'city': 'London', 'zip': 'N1'}},
+        None,
+        {'name', 'address'},
+    )
+
+    # errors from the flattened field are reported at the keys in the parent's input
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'sam', 'city': 'London'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('zip',), 'msg': 'Field required', 'input': {'name': 'sam', 'city': 'London'}}
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'name': 'sam', 'city': 'London', 'zip': 1})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('string_type', ('zip',))]
+
+    # as are coercions
+    report = []
+    v.validate_python({'name': 'sam', 'city': 'London', 'zip': b'N1'}, coercion_report=report)
+    assert report == [{'loc': ('zip',), 'input_type': 'bytes', 'expected': 'str'}]
+
+
+def test_flatten_extra_behavior():
+    with pytest.raises(SchemaError, match='Field "address": flatten can only be used if extra_behavior=ignore'):
+        SchemaValidator(
+            core_schema.model_fields_schema(
+                {'address': core_schema.model_field(core_schema.dict_schema(), flatten=True)}, extra_behavior='forbid'
+            )
+        )
+
+
+def test_required_if():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'kind': core_schema.model_field(core_schema.str_schema()),
+                'card_number': core_schema.model_field(
+                    core_schema.with_default_schema(
+                        core_schema.nullable_schema(core_schema.str_schema()), default=None
+                    ),
+                    required_if={'kind': 'card'},
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'kind': 'cash'}) == ({'kind': 'cash', 'card_number': None}, None, {'kind'})
+    assert v.validate_python({'kind': 'card', 'card_number': '1234'}) == (
+        {'kind': 'card', 'card_number': '1234'},
+        None,
+        {'kind', 'card_number'},
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 'card'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('card_number',), 'msg': 'Field required', 'input': {'kind': 'card'}}
+    ]
+
+
+@pytest.mark.parametrize('extra_behavior', ['ignore', 'allow', 'forbid'])
+def test_exact_dict_keys(extra_behavior):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), on_error='omit')
+                ),
+                'c': core_schema.model_field(core_schema.any_schema()),
+            },
+            extra_behavior=extra_behavior,
+        )
+    )
+    expected_extra = {} if extra_behavior == 'allow' else None
+    model_dict, model_extra, fields_set = v.validate_python({'a': '1', 'b': 'x', 'c': None})
+    assert model_dict == {'a': 1, 'b': 'x', 'c': None}
+    assert model_extra == expected_extra
+    assert fields_set == {'a', 'b', 'c'}
+
+    # each result gets its own fields set
+    fields_set.add('d')
+    assert v.validate_python({'a': 1, 'b': 'x', 'c': None})[2] == {'a', 'b', 'c'}
+
+    # omitted and `UNSET` values aren't in the fields set
+    assert v.validate_python({'a': 1, 'b': 2, 'c': UNSET}) == ({'a': 1, 'c': UNSET}, expected_extra, {'a'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'x', 'c': None})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_exact_dict_keys_alias():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema(), validation_alias='A'),
+                'b': core_schema.model_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.validate_python({'A': 1, 'b': 2}) == ({'a': 1, 'b': 2}, None, {'a', 'b'})
+    with pytest.raises(ValidationError, match=r'A\n +Field required'):
+        v.validate_python({'a': 1, 'b': 2})
@@ -0,0 +1,26 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_secret():
+    v = SchemaValidator(core_schema.secret_schema(core_schema.str_schema(min_length=3)))
+    assert v.validate_python('hunter2') == 'hunter2'
+    assert v.validate_json('"hunter2"') == 'hunter2'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('ab')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': 'String should have at least 3 characters',
+            'input': 'ab',
+            'ctx': {'min_length': 3},
+        }
+    ]
+
+
+def test_bytes():
+    v = SchemaValidator(core_schema.secret_schema(core_schema.bytes_schema()))
+    assert v.validate_python(b'hunter2') == b'hunter2'
+    assert v.validate_python('hunter2') == b'hunter2'
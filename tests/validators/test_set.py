@@ -277,3 +277,54 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+@pytest.mark.parametrize('schema_type,field_type', [('set', 'Set'), ('frozenset', 'Frozenset')])
+def test_set_unique_items(py_and_json: PyAndJson, schema_type, field_type):
+    v = py_and_json({'type': schema_type, 'items_schema': {'type': 'int'}, 'unique_items': True})
+    assert v.validate_test([1, 2, 3]) == {1, 2, 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 2, 2, 3])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_item',
+            'loc': (2,),
+            'msg': f'{field_type} items should be unique',
+            'input': [1, 2, 2, 3],
+            'ctx': {'field_type': field_type},
+        }
+    ]
+
+
+def test_deterministic_error_locations():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}}, {'deterministic': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'d', 'b', 1, 'c', 'a'})
+    assert [(e['loc'], e['input']) for e in exc_info.value.errors()] == [
+        ((1,), 'a'),
+        ((2,), 'b'),
+        ((3,), 'c'),
+        ((4,), 'd'),
+    ]
+
+
+def test_deterministic_list_from_set():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'str'}}, {'deterministic': True})
+    assert v.validate_python({'c', 'a', 'b'}) == ['a', 'b', 'c']
+    assert v.validate_python(frozenset({'c', 'a', 'b'})) == ['a', 'b', 'c']
+
+    v = SchemaValidator({'type': 'tuple-variable', 'items_schema': {'type': 'str'}}, {'deterministic': True})
+    assert v.validate_python({'c', 'a', 'b'}) == ('a', 'b', 'c')
+
+
+def test_set_max_length_invalid_items():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}, 'max_length': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x', 2])
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'int_parsing'
+
+    # invalid items count towards the limit, so an endless input of invalid items still fails early
+    invalid_items = ('x' for _ in infinite_generator())
+    with pytest.raises(ValidationError, match='Set should have at most 3 items after validation, not more'):
+        v.validate_python(invalid_items)
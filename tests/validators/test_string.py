@@ -167,6 +167,143 @@ def test_str_constrained_config():
         v.validate_python('test long')
 
 
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'normalize': 'NFC'}, 'e\u0301', '\u00e9'),
+        ({'normalize': 'NFD'}, '\u00e9', 'e\u0301'),
+        ({'normalize': 'NFKC'}, '\ufb01le', 'file'),
+        ({'normalize': 'NFKD'}, '\u2460', '1'),
+        ({'normalize': 'NFC'}, 'plain', 'plain'),
+        ({'casefold': True}, 'Straße', 'strasse'),
+        ({'casefold': True, 'normalize': 'NFKC'}, '\ufb01LE', 'file'),
+        ({'normalize': 'NFC', 'max_length': 1}, 'e\u0301', '\u00e9'),
+        ({'casefold': True, 'max_length': 5}, 'Straße', Err('String should have at most 5 characters')),
+        ({'casefold': True, 'pattern': '^[a-z]+$'}, 'STRASSE', 'strasse'),
+        ({'normalize': 'NFC', 'to_upper': True}, 'e\u0301', '\u00c9'),
+    ],
+)
+def test_normalize_casefold(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'str', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_normalize_config():
+    v = SchemaValidator(core_schema.str_schema(), {'str_normalize': 'NFKC', 'str_casefold': True})
+    assert v.validate_python('\uff21\uff22') == 'ab'
+
+
+def test_normalize_invalid():
+    with pytest.raises(SchemaError, match='Invalid normalize form: `nfc`, expected `NFC`, `NFKC`, `NFD` or `NFKD`'):
+        SchemaValidator(core_schema.str_schema(normalize='nfc'))
+
+
+@pytest.mark.parametrize(
+    'length_unit,input_value,length',
+    [
+        ('codepoints', 'héllo', 5),
+        ('bytes', 'héllo', 6),
+        ('graphemes', 'héllo', 5),
+        ('codepoints', 'e\u0301', 2),
+        ('graphemes', 'e\u0301', 1),
+        ('bytes', '\U0001f600', 4),
+        ('graphemes', '\U0001f44d\U0001f3fd', 1),
+        ('graphemes', '\U0001f468\u200d\U0001f469\u200d\U0001f467', 1),
+        ('graphemes', '\U0001f1ec\U0001f1e7\U0001f1fa\U0001f1f8', 2),
+        ('graphemes', '\u1100\u1161\u11a8', 1),
+        ('graphemes', 'a\r\nb', 3),
+        ('graphemes', 'a\u200d\U0001f600', 2),
+    ],
+)
+def test_length_unit(length_unit, input_value, length):
+    v = SchemaValidator(core_schema.str_schema(min_length=length, max_length=length, length_unit=length_unit))
+    assert v.validate_python(input_value) == input_value
+
+    v = SchemaValidator(core_schema.str_schema(max_length=length - 1, length_unit=length_unit))
+    with pytest.raises(ValidationError, match='String should have at most'):
+        v.validate_python(input_value)
+
+
+def test_length_unit_config():
+    v = SchemaValidator(core_schema.str_schema(), {'str_max_length': 3, 'str_length_unit': 'bytes'})
+    assert v.validate_python('abc') == 'abc'
+    with pytest.raises(ValidationError, match=r'String should have at most 3 bytes \[type=string_units_too_long'):
+        v.validate_python('ab\u00e9')
+
+
+def test_length_unit_errors():
+    v = SchemaValidator(core_schema.str_schema(min_length=2, max_length=2, length_unit='graphemes'))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('e\u0301')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_units_too_short',
+            'loc': (),
+            'msg': 'String should have at least 2 graphemes',
+            'input': 'e\u0301',
+            'ctx': {'min_length': 2, 'unit': 'grapheme'},
+        }
+    ]
+    with pytest.raises(ValidationError, match=r'String should have at most 2 graphemes \[type=string_units_too_long'):
+        v.validate_python('abc')
+
+
+def test_length_unit_invalid():
+    with pytest.raises(
+        SchemaError, match='Invalid length_unit: `chars`, expected `codepoints`, `bytes` or `graphemes`'
+    ):
+        SchemaValidator(core_schema.str_schema(max_length=1, length_unit='chars'))
+
+
+@pytest.mark.parametrize(
+    'input_value,valid',
+    [
+        ('+14155552671', True),
+        ('+442071838750', True),
+        ('+12', True),
+        ('+123456789012345', True),
+        ('+1234567890123456', False),
+        ('+1', False),
+        ('+04155552671', False),
+        ('14155552671', False),
+        ('+1 415 555 2671', False),
+        ('+1-415-555-2671', False),
+        ('+١٢٣٤', False),
+        ('', False),
+        ('+', False),
+    ],
+)
+def test_format_e164(py_and_json: PyAndJson, input_value, valid):
+    v = py_and_json(core_schema.str_schema(format='e164'))
+    if valid:
+        assert v.validate_test(input_value) == input_value
+    else:
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False) == [
+            {
+                'type': 'string_e164_format',
+                'loc': (),
+                'msg': "String should be a phone number in E.164 format, e.g. '+14155552671'",
+                'input': input_value,
+            }
+        ]
+
+
+def test_format_e164_strip_whitespace():
+    v = SchemaValidator(core_schema.str_schema(format='e164', strip_whitespace=True))
+    assert v.validate_python(' +14155552671\n') == '+14155552671'
+
+
+def test_format_invalid():
+    with pytest.raises(SchemaError, match='Invalid str format: `phone`, expected `e164`'):
+        SchemaValidator(core_schema.str_schema(format='phone'))
+
+
 @pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
 def test_invalid_regex(engine):
     # TODO uncomment and fix once #150 is done
@@ -620,3 +620,69 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+@pytest.fixture(scope='module')
+def otherwise_schema():
+    return core_schema.tagged_union_schema(
+        choices={
+            'click': core_schema.typed_dict_schema(
+                {
+                    'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'x': core_schema.typed_dict_field(core_schema.int_schema()),
+                }
+            ),
+        },
+        discriminator='kind',
+        otherwise=core_schema.typed_dict_schema(
+            {'kind': core_schema.typed_dict_field(core_schema.str_schema())}, extra_behavior='allow'
+        ),
+    )
+
+
+def test_otherwise(py_and_json: PyAndJson, otherwise_schema):
+    v = py_and_json(otherwise_schema)
+    assert v.validate_test({'kind': 'click', 'x': '1'}) == {'kind': 'click', 'x': 1}
+    assert v.validate_test({'kind': 'scroll', 'y': 2}) == {'kind': 'scroll', 'y': 2}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'kind': 'click', 'x': 'a'})
+    assert exc_info.value.errors(include_url=False, include_input=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('click', 'x'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+        }
+    ]
+
+
+def test_otherwise_errors(otherwise_schema):
+    v = SchemaValidator(otherwise_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 123})
+    assert exc_info.value.errors(include_url=False, include_input=False) == [
+        {'type': 'string_type', 'loc': (123, 'kind'), 'msg': 'Input should be a valid string'}
+    ]
+
+    # a missing tag is still an error
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'y': 2})
+    assert exc_info.value.errors(include_url=False, include_input=False) == [
+        {
+            'type': 'union_tag_not_found',
+            'loc': (),
+            'msg': "Unable to extract tag using discriminator 'kind'",
+            'ctx': {'discriminator': "'kind'"},
+        }
+    ]
+
+
+def test_otherwise_coverage_stats(otherwise_schema):
+    v = SchemaValidator(otherwise_schema, {'coverage_stats': True})
+    v.validate_python({'kind': 'click', 'x': 1})
+    v.validate_python({'kind': 'scroll'})
+    v.validate_python({'kind': 'zoom'})
+    assert v.coverage_stats() == {
+        "tagged-union[typed-dict].'click'": 1,
+        'tagged-union[typed-dict].otherwise': 2,
+    }
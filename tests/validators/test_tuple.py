@@ -1,3 +1,4 @@
+import json
 import re
 from collections import deque
 from typing import Any, Dict, Type
@@ -5,7 +6,7 @@ from typing import Any, Dict, Type
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -497,3 +498,118 @@ def test_length_constraints_omit(input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+def test_tuple_variable_unique_items():
+    v = SchemaValidator({'type': 'tuple-variable', 'items_schema': {'type': 'int'}, 'unique_items': True})
+    assert v.validate_python((1, 2, 3)) == (1, 2, 3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 2, '1'))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'duplicate_item',
+            'loc': (2,),
+            'msg': 'Tuple items should be unique',
+            'input': (1, 2, '1'),
+            'ctx': {'field_type': 'Tuple'},
+        }
+    ]
+
+
+def test_positional_defaults_short_input():
+    v = SchemaValidator(
+        core_schema.tuple_positional_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.with_default_schema(core_schema.str_schema(), default='a'),
+                core_schema.with_default_schema(core_schema.int_schema(), default_factory=lambda: 0),
+            ],
+            extras_schema=core_schema.str_schema(),
+        )
+    )
+    assert v.validate_json('[1]') == (1, 'a', 0)
+    assert v.validate_json('[1, "b"]') == (1, 'b', 0)
+    assert v.validate_json('[1, "b", 2]') == (1, 'b', 2)
+    assert v.validate_json('[1, "b", 2, "c"]') == (1, 'b', 2, 'c')
+    assert v.validate_python([1]) == (1, 'a', 0)
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[]')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (0,), 'msg': 'Field required', 'input': []}
+    ]
+
+
+def test_positional_required_after_default():
+    v = SchemaValidator(
+        core_schema.tuple_positional_schema(
+            [core_schema.with_default_schema(core_schema.int_schema(), default=1), core_schema.int_schema()]
+        )
+    )
+    assert v.validate_python((2, 3)) == (2, 3)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(())
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': ()}
+    ]
+
+
+@pytest.fixture
+def variadic_validator():
+    return SchemaValidator(
+        core_schema.tuple_positional_schema(
+            [
+                core_schema.int_schema(),
+                core_schema.with_default_schema(core_schema.str_schema(), default='a'),
+                core_schema.int_schema(),
+                core_schema.with_default_schema(core_schema.float_schema(), default=0.5),
+            ],
+            variadic_item_index=2,
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1]', (1, 'a', 0.5)),
+        ('[1, "b"]', (1, 'b', 0.5)),
+        ('[1, "b", 2.5]', (1, 'b', 2.5)),
+        ('[1, "b", 2, 2.5]', (1, 'b', 2, 2.5)),
+        ('[1, "b", 2, 3, 4, 2.5]', (1, 'b', 2, 3, 4, 2.5)),
+    ],
+)
+def test_variadic_item_defaults(variadic_validator: SchemaValidator, input_value, expected):
+    assert variadic_validator.validate_json(input_value) == expected
+    assert variadic_validator.validate_python(tuple(json.loads(input_value))) == expected
+
+
+def test_variadic_item_errors(variadic_validator: SchemaValidator):
+    with pytest.raises(ValidationError) as exc_info:
+        variadic_validator.validate_python(())
+    assert exc_info.value.title == 'tuple[int, default[str], *tuple[int, ...], default[float]]'
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (0,), 'msg': 'Field required', 'input': ()}
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        variadic_validator.validate_python((1, 'b', 2, 'x', 2.5))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (3,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_variadic_item_index_invalid():
+    with pytest.raises(SchemaError, match='`variadic_item_index` 1 is out of range for 1 items'):
+        SchemaValidator(core_schema.tuple_positional_schema([core_schema.int_schema()], variadic_item_index=1))
+    with pytest.raises(SchemaError, match="`variadic_item_index` can't be combined with `extras_schema`"):
+        SchemaValidator(
+            core_schema.tuple_positional_schema(
+                [core_schema.int_schema()], variadic_item_index=0, extras_schema=core_schema.int_schema()
+            )
+        )
@@ -1,4 +1,5 @@
 import gc
+import json
 import math
 import platform
 import re
@@ -374,6 +375,55 @@ def test_alias(py_and_json: PyAndJson):
         assert v.validate_test({'field_a': '123'})
 
 
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({}, {'FieldA': 1}, {'field_a': 1}),
+        ({}, {'field_a': 1}, Err('FieldA\n  Field required')),
+        ({'by_name': True}, {'field_a': 1}, {'field_a': 1}),
+        ({'by_name': True}, {'FieldA': 1, 'field_a': 2}, {'field_a': 1}),
+        ({'by_alias': False}, {'field_a': 1}, {'field_a': 1}),
+        ({'by_alias': False}, {'FieldA': 1}, Err('field_a\n  Field required')),
+        ({'by_alias': False, 'by_name': True}, {'field_a': 1}, {'field_a': 1}),
+        ({'by_alias': True, 'by_name': False}, {'field_a': 1}, Err('FieldA\n  Field required')),
+    ],
+)
+@pytest.mark.parametrize('mode', ['python', 'json'])
+def test_by_alias_by_name(mode, kwargs, input_value, expected):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldA')}
+        )
+    )
+    validate = v.validate_python if mode == 'python' else v.validate_json
+    if mode == 'json':
+        input_value = json.dumps(input_value)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            validate(input_value, **kwargs)
+    else:
+        assert validate(input_value, **kwargs) == expected
+
+
+def test_by_name_overrides_populate_by_name():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='FieldA')},
+            populate_by_name=True,
+        )
+    )
+    assert v.validate_python({'field_a': 1}) == {'field_a': 1}
+    with pytest.raises(ValidationError, match='FieldA\n  Field required'):
+        v.validate_python({'field_a': 1}, by_name=False)
+    assert v.validate_strings({'FieldA': '1'}, by_name=False) == {'field_a': 1}
+
+
+def test_by_alias_and_by_name_false():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(ValueError, match='`by_alias` and `by_name` cannot both be False'):
+        v.validate_python({'a': 1}, by_alias=False, by_name=False)
+
 def test_empty_string_field_name(py_and_json: PyAndJson):
     v = py_and_json({'type': 'typed-dict', 'fields': {'': {'type': 'typed-dict-field', 'schema': {'type': 'int'}}}})
     assert v.validate_test({'': 123}) == {'': 123}
@@ -1196,3 +1246,245 @@ def test_leak_typed_dict():
     gc.collect()
 
     assert ref() is None
+
+
+def test_extras_key_transform():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_key_transform='snake_case',
+        )
+    )
+    assert v.validate_python({'field_a': 'test', 'otherField': 1}) == {'field_a': 'test', 'other_field': 1}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test', 'otherField': 1, 'fieldA': 2})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'extra_key_collision',
+            'loc': ('fieldA',),
+            'msg': "Extra key 'field_a' should not collide with a field or another extra key",
+            'input': 2,
+            'ctx': {'key': 'field_a'},
+        }
+    ]
+
+
+def test_extras_keys_schema(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_keys_schema=core_schema.str_schema(max_length=5),
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_test({'field_a': 'test', 'other': '1'}) == {'field_a': 'test', 'other': 1}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'field_a': 'test', 'too_long': 'x'})
+    # the value isn't validated if the key is invalid
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long',
+            'loc': ('too_long', '[key]'),
+            'msg': 'String should have at most 5 characters',
+            'input': 'too_long',
+            'ctx': {'max_length': 5},
+        }
+    ]
+
+
+def test_extras_keys_schema_collision():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.str_schema())},
+            extra_behavior='allow',
+            extras_keys_schema=core_schema.str_schema(to_lower=True),
+        )
+    )
+    assert v.validate_python({'field_a': 'test', 'Other': 1}) == {'field_a': 'test', 'other': 1}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'field_a': 'test', 'FIELD_A': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'extra_key_collision',
+            'loc': ('FIELD_A',),
+            'msg': "Extra key 'field_a' should not collide with a field or another extra key",
+            'input': 1,
+            'ctx': {'key': 'field_a'},
+        }
+    ]
+
+
+@pytest.mark.parametrize('config', [None, core_schema.CoreConfig(extra_fields_behavior='allow')])
+def test_closed(py_and_json: PyAndJson, config):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'f': core_schema.typed_dict_field(core_schema.str_schema())}, closed=True, config=config
+        )
+    )
+    assert v.validate_test({'f': 'x'}) == {'f': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'f': 'x', 'extra_field': 123})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'closed_typed_dict_extra',
+            'loc': ('extra_field',),
+            'msg': 'Extra keys are not permitted in a closed TypedDict',
+            'input': 123,
+        }
+    ]
+
+
+def test_closed_extra_items(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'f': core_schema.typed_dict_field(core_schema.str_schema())},
+            closed=True,
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.validate_test({'f': 'x', 'extra_field': '123'}) == {'f': 'x', 'extra_field': 123}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'f': 'x', 'extra_field': 'wrong'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('extra_field',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        }
+    ]
+
+
+def test_closed_extra_behavior_conflict():
+    with pytest.raises(SchemaError, match="extra_behavior can't be set on a closed typed dict"):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'f': core_schema.typed_dict_field(core_schema.str_schema())}, closed=True, extra_behavior='ignore'
+            )
+        )
+
+
+def test_pairs_input():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema()),
+            },
+            from_pairs=True,
+        )
+    )
+    assert v.validate_python({'a': '1', 'b': 'x'}.items()) == {'a': 1, 'b': 'x'}
+    assert v.validate_python((p for p in [('a', '1'), ('b', 'x')])) == {'a': 1, 'b': 'x'}
+    # later pairs replace earlier ones with the same key, like `dict(pairs)`
+    assert v.validate_python([('a', '1'), ('b', 'x'), ('a', '2')]) == {'a': 2, 'b': 'x'}
+
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [type=dict_type,')):
+        v.validate_python({'a': '1', 'b': 'x'}.items(), strict=True)
+
+
+def test_pairs_input_alias_extra():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=[['A', 0], ['a']]),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(), validation_alias='B'),
+            },
+            extra_behavior='forbid',
+            from_pairs=True,
+        )
+    )
+    assert v.validate_python(iter([('A', ['1']), ('B', 'x')])) == {'a': 1, 'b': 'x'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(iter([('a', '1'), ('B', 'x'), ('c', 2)]))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('c',), 'msg': 'Extra inputs are not permitted', 'input': 2}
+    ]
+
+
+def test_pairs_input_requires_from_pairs():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid dictionary [type=dict_type,')):
+        v.validate_python({'a': 1}.items())
+def test_required_if():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                'card_number': core_schema.typed_dict_field(
+                    core_schema.str_schema(), required=False, required_if={'kind': 'card'}
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'kind': 'cash'}) == {'kind': 'cash'}
+    assert v.validate_python({'kind': 'card', 'card_number': '1234'}) == {'kind': 'card', 'card_number': '1234'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 'card'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('card_number',), 'msg': 'Field required', 'input': {'kind': 'card'}}
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"kind": "card"}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('card_number',), 'msg': 'Field required', 'input': {'kind': 'card'}}
+    ]
+
+
+def test_required_if_conditions():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'kind': core_schema.typed_dict_field(core_schema.str_schema()),
+                'amount': core_schema.typed_dict_field(core_schema.int_schema()),
+                'reference': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default=''),
+                    required_if=[{'kind': 'transfer'}, {'kind': 'card', 'amount': 100}],
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'kind': 'card', 'amount': '99'}) == {'kind': 'card', 'amount': 99, 'reference': ''}
+    # values are compared after validation
+    with pytest.raises(ValidationError, match=r'reference\n  Field required \[type=missing'):
+        v.validate_python({'kind': 'card', 'amount': '100'})
+    with pytest.raises(ValidationError, match=r'reference\n  Field required \[type=missing'):
+        v.validate_python({'kind': 'transfer', 'amount': 1})
+
+    # fields which failed validation never match
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'kind': 'card', 'amount': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('amount',)]
+
+
+@pytest.mark.parametrize(
+    'required_if,msg',
+    [
+        ({'other': 1}, "Field 'b': required_if refers to unknown field 'other'"),
+        ({'b': 1}, "Field 'b': required_if can't refer to the field itself"),
+        ({}, "Field 'b': required_if conditions can't be empty"),
+        ([], "Field 'b': required_if conditions can't be empty"),
+    ],
+)
+def test_required_if_invalid(required_if, msg):
+    with pytest.raises(SchemaError, match=msg):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(
+                        core_schema.int_schema(), required=False, required_if=required_if
+                    ),
+                }
+            )
+        )
@@ -803,3 +803,128 @@ def test_model_and_literal_union() -> None:
     assert isinstance(m, ModelA)
     assert m.a == 42
     assert validator.validate_python(True) is True
+
+
+@pytest.fixture(scope='module')
+def union_errors_schema():
+    return core_schema.union_schema(
+        [
+            core_schema.int_schema(),
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.str_schema()),
+                }
+            ),
+            core_schema.list_schema(core_schema.int_schema()),
+        ]
+    )
+
+
+@pytest.mark.parametrize(
+    'union_errors,expected',
+    [
+        (
+            'all',
+            [
+                ('int_type', ('int',)),
+                ('int_parsing', ('typed-dict', 'a')),
+                ('list_type', ('list[int]',)),
+            ],
+        ),
+        ('best-match', [('int_parsing', ('typed-dict', 'a'))]),
+        ('first', [('int_type', ('int',))]),
+    ],
+)
+def test_union_errors(union_errors_schema, union_errors, expected):
+    v = SchemaValidator(union_errors_schema, {'union_errors': union_errors})
+    assert v.validate_python({'a': 1, 'b': 'x'}) == {'a': 1, 'b': 'x'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'y'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == expected
+
+
+def test_union_errors_best_match_tie():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()]),
+        {'union_errors': 'best-match'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('int',))]
+
+
+def test_union_errors_left_to_right():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.list_schema(core_schema.int_schema()), core_schema.str_schema()], mode='left_to_right'
+        ),
+        {'union_errors': 'best-match'},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 2, 'b'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('list[int]', 0)),
+        ('int_parsing', ('list[int]', 2)),
+    ]
+
+
+def test_union_errors_invalid():
+    with pytest.raises(SchemaError, match='Invalid union_errors: `some`, expected `all`, `best-match` or `first`'):
+        SchemaValidator(
+            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]), {'union_errors': 'some'}
+        )
+
+
+@pytest.fixture
+def tagged_int_choices():
+    return [
+        (core_schema.no_info_after_validator_function(lambda x: ('a', x), core_schema.int_schema()), 'a'),
+        (core_schema.no_info_after_validator_function(lambda x: ('b', x), core_schema.int_schema()), 'b'),
+        (core_schema.no_info_after_validator_function(lambda x: ('c', x), core_schema.int_schema()), 'c'),
+    ]
+
+
+@pytest.mark.parametrize(
+    'priorities,expected_title,expected',
+    [
+        (None, 'union[a,b,c]', 'a'),
+        ([0, 0, 0], 'union[a,b,c]', 'a'),
+        ([0, 1, 0], 'union[b,a,c]', 'b'),
+        ([-1, 2, 2], 'union[b,c,a]', 'b'),
+        ([1, 2, 3], 'union[c,b,a]', 'c'),
+    ],
+)
+def test_union_priorities(tagged_int_choices, priorities, expected_title, expected):
+    v = SchemaValidator(core_schema.union_schema(tagged_int_choices, priorities=priorities))
+    assert v.title == expected_title
+    # both exact and lax matches are ties between all the choices
+    assert v.validate_python(1) == (expected, 1)
+    assert v.validate_python('1') == (expected, 1)
+
+    choices = []
+    v.validate_python(1, union_choice_callback=lambda output, info: choices.append(info))
+    # `index` is still the position in `choices` as declared
+    assert [(c['index'], c['choice']) for c in choices] == [('abc'.index(expected), expected)]
+
+
+def test_union_priorities_exactness_first():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()], priorities=[0, 1])
+    )
+    assert v.title == 'union[float,int]'
+    # a better match still wins over a higher priority
+    assert v.validate_python(1) == 1
+    assert isinstance(v.validate_python(1), int)
+    assert isinstance(v.validate_python(1.0), float)
+
+
+def test_union_priorities_invalid():
+    with pytest.raises(SchemaError, match='`priorities` should have the same length as `choices`, got 1 and 2'):
+        SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], priorities=[1]))
+    with pytest.raises(SchemaError, match="`priorities` can only be used with mode='smart'"):
+        SchemaValidator(
+            core_schema.union_schema(
+                [core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right', priorities=[0, 1]
+            )
+        )
@@ -4,7 +4,7 @@ from uuid import UUID
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -119,6 +119,9 @@ def test_uuid_strict(input_value, expected):
         (UUID('0e7ac198-9acd-4c0c-b4b4-761974bf71d7'), 4, UUID('0e7ac198-9acd-4c0c-b4b4-761974bf71d7')),
         ('0e7ac198-9acd-4c0c-b4b4-761974bf71d7', 4, UUID('0e7ac198-9acd-4c0c-b4b4-761974bf71d7')),
         (UUID('0e7ac198-9acd-4c0c-b4b4-761974bf71d7'), 4, UUID('0e7ac198-9acd-4c0c-b4b4-761974bf71d7')),
+        ('018c2ffc-6b6e-7ac4-9b0d-2a0c6d4b7e55', 7, UUID('018c2ffc-6b6e-7ac4-9b0d-2a0c6d4b7e55')),
+        (UUID('018c2ffc-6b6e-7ac4-9b0d-2a0c6d4b7e55'), 7, UUID('018c2ffc-6b6e-7ac4-9b0d-2a0c6d4b7e55')),
+        ('0e7ac198-9acd-4c0c-b4b4-761974bf71d7', 7, Err('UUID version 7 expected')),
         # Cases from pydantic#7355 and pydantic#7537
         # `UUID.version` makes sense for RFC 4122 UUIDs only. For non RFC 4122 UUIDs Python uses `UUID.version=None`
         ('00000000-8000-4000-8000-000000000000', 4, UUID('00000000-8000-4000-8000-000000000000')),
@@ -183,6 +186,37 @@ def test_uuid_json(py_and_json: PyAndJson, input_value, expected):
         assert isinstance(output, UUID)
 
 
+def test_uuid_invalid_version():
+    with pytest.raises(SchemaError, match='Invalid UUID version: 2, expected one of 1, 3, 4, 5 or 7'):
+        SchemaValidator(core_schema.uuid_schema(version=2))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('a6cc5730-2261-11ee-9c43-2eb5a363657c', UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c')),
+        ('A6CC5730-2261-11EE-9C43-2EB5A363657C', UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c')),
+        (b'a6cc5730-2261-11ee-9c43-2eb5a363657c', UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c')),
+        (UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c'), UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c')),
+        (b'\x12\x34\x56\x78' * 4, UUID('12345678-1234-5678-1234-567812345678')),
+        ('a6cc5730226111ee9c432eb5a363657c', Err('UUID should be in the hyphenated format')),
+        ('{a6cc5730-2261-11ee-9c43-2eb5a363657c}', Err('UUID should be in the hyphenated format')),
+        ('urn:uuid:a6cc5730-2261-11ee-9c43-2eb5a363657c', Err('UUID should be in the hyphenated format')),
+        (b'a6cc5730226111ee9c432eb5a363657c', Err('UUID should be in the hyphenated format')),
+        ('a6cc5730-2261-11ee-9c43', Err('Input should be a valid UUID')),
+    ],
+)
+def test_uuid_strict_hyphenation(input_value, expected):
+    v = SchemaValidator(core_schema.uuid_schema(strict_hyphenation=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, UUID)
+
+
 def test_uuid_deepcopy():
     output = SchemaValidator({'type': 'uuid'}).validate_python('a6cc5730-2261-11ee-9c43-2eb5a363657c')
     c = copy.deepcopy(output)
@@ -1,5 +1,6 @@
 import gc
 import platform
+import re
 import sys
 import weakref
 from collections import deque
@@ -17,7 +18,7 @@ from pydantic_core import (
     core_schema,
 )
 
-from ..conftest import PyAndJson
+from ..conftest import Err, PyAndJson
 
 
 def test_typed_dict_default():
@@ -804,3 +805,226 @@ def test_validate_default_raises_dataclass(input_value: dict, expected: Any) ->
         v.validate_python(input_value)
 
     assert exc_info.value.errors(include_url=False, include_context=False) == expected
+
+
+@pytest.mark.parametrize(
+    'apply_on,input_value,expected',
+    [
+        (None, {'x': None}, Err('Input should be a valid integer')),
+        (None, {}, {'x': 0}),
+        ('missing', {}, {'x': 0}),
+        ('null', {'x': None}, {'x': 0}),
+        ('null', {}, Err('Field required [type=missing')),
+        ('null', {'x': 1}, {'x': 1}),
+        ('both', {'x': None}, {'x': 0}),
+        ('both', {}, {'x': 0}),
+    ],
+)
+def test_apply_on(py_and_json: PyAndJson, apply_on, input_value, expected):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=0, apply_on=apply_on)
+                )
+            }
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_apply_on_null_not_required():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.list_schema(core_schema.int_schema()), default_factory=list, apply_on='null'
+                    ),
+                    required=False,
+                )
+            }
+        )
+    )
+    assert v.validate_python({}) == {}
+    assert v.validate_python({'x': None}) == {'x': []}
+    assert v.validate_json('{"x": null}') == {'x': []}
+
+
+def test_apply_on_validate_default():
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.nullable_schema(core_schema.int_schema()), default=None, apply_on='null', validate_default=True
+        )
+    )
+    assert v.validate_python(None) is None
+    assert v.validate_python(1) == 1
+
+
+def test_apply_on_requires_default():
+    with pytest.raises(SchemaError, match="'apply_on = null' or 'both' requires a `default` or `default_factory`"):
+        SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), apply_on='null'))
+
+
+def test_arguments_apply_on_null():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter(
+                    'a', core_schema.with_default_schema(core_schema.int_schema(), default=1, apply_on='null')
+                )
+            ]
+        )
+    )
+    assert v.validate_python(ArgsKwargs((None,))) == ((1,), {})
+    with pytest.raises(ValidationError, match='Missing required argument'):
+        v.validate_python(ArgsKwargs(()))
+
+
+def test_validate_default_cached():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.nullable_schema(core_schema.int_schema()),
+                        default='123456789012345678901234567890',
+                        validate_default=True,
+                    )
+                )
+            }
+        )
+    )
+    first = v.validate_python({})['x']
+    assert first == 123456789012345678901234567890
+    assert v.validate_python({})['x'] is first
+
+    # validation settings which could change the result mean the default is validated again
+    assert v.validate_python({}, context={})['x'] is not first
+    assert v.validate_python({}, strict=False)['x'] is not first
+
+
+def test_validate_default_function_not_cached():
+    calls = []
+
+    def f(value, info):
+        calls.append(dict(info.data))
+        return value
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'x': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.with_info_after_validator_function(f, core_schema.int_schema(), field_name='x'),
+                        default='1',
+                        validate_default=True,
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 1}) == {'a': 1, 'x': 1}
+    assert v.validate_python({'a': 2}) == {'a': 2, 'x': 1}
+    # the function sees the data of each validation
+    assert calls == [{'a': 1}, {'a': 2}]
+
+
+def test_validate_default_not_cached():
+    calls = []
+
+    def f(value):
+        calls.append(value)
+        return value
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.no_info_after_validator_function(f, core_schema.list_schema(core_schema.int_schema())),
+            default=['1'],
+            validate_default=True,
+        )
+    )
+    assert v.get_default_value().value == [1]
+    assert v.get_default_value().value == [1]
+    assert calls == [[1], [1]]
+
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.no_info_after_validator_function(f, core_schema.int_schema()),
+            default_factory=lambda: '1',
+            validate_default=True,
+        )
+    )
+    calls.clear()
+    assert v.get_default_value().value == 1
+    assert v.get_default_value().value == 1
+    assert calls == [1, 1]
+
+
+def test_default_factory_takes_data():
+    calls = []
+
+    def slugify(data):
+        calls.append(dict(data))
+        return data.get('title', '').lower().replace(' ', '-')
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'title': core_schema.typed_dict_field(core_schema.str_schema()),
+                'slug': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(
+                        core_schema.str_schema(), default_factory=slugify, default_factory_takes_data=True
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'title': 'Hello World'}) == {'title': 'Hello World', 'slug': 'hello-world'}
+    assert v.validate_python({'title': 'Hello World', 'slug': 'x'}) == {'title': 'Hello World', 'slug': 'x'}
+    assert calls == [{'title': 'Hello World'}]
+
+    # fields which failed validation aren't in the data
+    with pytest.raises(ValidationError, match='string_type'):
+        v.validate_python({'title': 1})
+    assert calls == [{'title': 'Hello World'}, {}]
+
+
+def test_default_factory_takes_data_model_fields():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(
+                        core_schema.int_schema(),
+                        default_factory=lambda data: data['a'] * 2,
+                        default_factory_takes_data=True,
+                        validate_default=True,
+                    )
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'a': 3})[0] == {'a': 3, 'b': 6}
+
+
+def test_default_factory_takes_data_no_data():
+    v = SchemaValidator(
+        core_schema.with_default_schema(
+            core_schema.int_schema(), default_factory=lambda data: len(data), default_factory_takes_data=True
+        )
+    )
+    r = v.get_default_value()
+    assert r is not None
+    assert r.value == 0
+
+
+def test_default_factory_takes_data_invalid():
+    with pytest.raises(SchemaError, match="'default_factory_takes_data' requires a `default_factory`"):
+        SchemaValidator(core_schema.with_default_schema(core_schema.int_schema(), default_factory_takes_data=True))